@@ -21,12 +21,13 @@ use crate::conflict::ConflictId;
 ///
 /// This enum tracks the *consistency* of a belief, not its lifecycle.
 /// Lifecycle states (e.g., supersession) are tracked separately.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(tag = "status", rename_all = "snake_case")]
 pub enum ConsistencyStatus {
     /// Checked against patterns and other beliefs, passed all checks.
     Verified,
     /// Accepted but not yet checked for consistency.
+    #[default]
     Provisional,
     /// Conflicts with other beliefs or violates patterns.
     Contested {
@@ -52,12 +53,6 @@ impl ConsistencyStatus {
     }
 }
 
-impl Default for ConsistencyStatus {
-    fn default() -> Self {
-        Self::Provisional
-    }
-}
-
 impl fmt::Display for ConsistencyStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -118,18 +113,66 @@ pub struct Belief {
     pub reason: Option<String>,
     /// Current consistency status.
     pub consistency_status: ConsistencyStatus,
-    /// ID of the belief this one supersedes.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub supersedes: Option<BeliefId>,
+    /// IDs of the beliefs this one supersedes; several only for an ASSERT in `Replace` mode.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_belief_ids"
+    )]
+    pub supersedes: Vec<BeliefId>,
     /// ID of the belief that superseded this one.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub superseded_by: Option<BeliefId>,
     /// Optional embedding for semantic search.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub embedding: Option<Vec<f32>>,
+    /// Tenant namespace, copied from the subject entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Further sources that asserted the same value, in the order they did.
+    ///
+    /// Only filled by an engine that merges duplicate assertions (see
+    /// `KyroEngine::with_source_merging`); `source` stays the first asserter.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub corroborating_sources: Vec<Source>,
+    /// Arbitrary metadata about how the belief was obtained (document offsets, extraction
+    /// model, ...). Never compared by conflict detection.
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub metadata: serde_json::Value,
+    /// Idempotency key of the ASSERT that wrote this belief, if it carried one. Stored with the
+    /// belief so a persistent store recovers the key in the same write.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// Whether this belief is a RETRACT withdrawing the beliefs it `supersedes` rather than
+    /// asserting `value` (always `Null` for a retraction).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub retraction: bool,
+}
+
+/// Deserialize a list of belief IDs, also accepting the single ID written before a belief
+/// could supersede several.
+pub(crate) fn deserialize_belief_ids<'de, D>(deserializer: D) -> Result<Vec<BeliefId>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(BeliefId),
+        Many(Vec<BeliefId>),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(OneOrMany::One(id)) => vec![id],
+        Some(OneOrMany::Many(ids)) => ids,
+    })
 }
 
 impl Belief {
+    /// Bound on metadata size (serialized JSON bytes), the same as for derivation records.
+    pub const MAX_METADATA_BYTES: usize = crate::derivation::DerivationRecord::MAX_METADATA_BYTES;
+
     /// Creates a new builder for constructing a Belief.
     pub fn builder() -> BeliefBuilder {
         BeliefBuilder::new()
@@ -180,6 +223,11 @@ impl Belief {
         self.embedding = Some(embedding);
     }
 
+    /// Every source behind this belief: `source`, then the corroborating sources.
+    pub fn sources(&self) -> impl Iterator<Item = &Source> {
+        std::iter::once(&self.source).chain(&self.corroborating_sources)
+    }
+
     /// Marks this belief as superseded by another.
     pub fn mark_superseded(&mut self, by: BeliefId) {
         self.superseded_by = Some(by);
@@ -223,9 +271,11 @@ pub struct BeliefBuilder {
     confidence: Option<Confidence>,
     source: Option<Source>,
     valid_time: Option<TimeRange>,
-    supersedes: Option<BeliefId>,
+    supersedes: Vec<BeliefId>,
     reason: Option<String>,
     embedding: Option<Vec<f32>>,
+    namespace: Option<String>,
+    metadata: Option<serde_json::Value>,
 }
 
 impl BeliefBuilder {
@@ -291,10 +341,10 @@ impl BeliefBuilder {
         self
     }
 
-    /// Sets the belief this one supersedes.
+    /// Adds a belief this one supersedes.
     #[must_use]
     pub fn supersedes(mut self, supersedes: BeliefId) -> Self {
-        self.supersedes = Some(supersedes);
+        self.supersedes.push(supersedes);
         self
     }
 
@@ -305,6 +355,20 @@ impl BeliefBuilder {
         self
     }
 
+    /// Sets the tenant namespace (must match the subject entity's).
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Sets the attachment metadata (at most `Belief::MAX_METADATA_BYTES` once serialized).
+    #[must_use]
+    pub fn metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     /// Builds the Belief.
     /// Returns `ValidationError` if required fields are missing or invalid.
     pub fn build(self) -> Result<Belief, ValidationError> {
@@ -331,8 +395,20 @@ impl BeliefBuilder {
         let source = self.source.unwrap_or_default();
         let valid_time = self.valid_time.unwrap_or_else(TimeRange::from_now);
 
+        let metadata = self.metadata.unwrap_or_default();
+        let bytes = serde_json::to_vec(&metadata).map_err(|e| ValidationError::InvalidField {
+            field: "metadata".to_string(),
+            reason: format!("failed to serialize metadata: {e}"),
+        })?;
+        if bytes.len() > Belief::MAX_METADATA_BYTES {
+            return Err(ValidationError::FieldTooLong {
+                field: "metadata".to_string(),
+                max_length: Belief::MAX_METADATA_BYTES,
+            });
+        }
+
         Ok(Belief {
-            id: self.id.unwrap_or_else(BeliefId::new),
+            id: self.id.unwrap_or_default(),
             subject,
             predicate,
             value,
@@ -345,6 +421,11 @@ impl BeliefBuilder {
             supersedes: self.supersedes,
             superseded_by: None,
             embedding: self.embedding,
+            namespace: self.namespace,
+            corroborating_sources: Vec::new(),
+            metadata,
+            idempotency_key: None,
+            retraction: false,
         })
     }
 }
@@ -441,11 +522,23 @@ mod tests {
             .unwrap();
 
         assert_eq!(belief.subject, subject);
-        assert_eq!(belief.supersedes, Some(supersedes));
+        assert_eq!(belief.supersedes, vec![supersedes]);
         assert!(belief.has_embedding());
         assert_eq!(belief.embedding.as_ref().unwrap(), &embedding);
     }
 
+    #[test]
+    fn test_single_supersedes_id_deserializes_into_a_list() {
+        let belief = make_test_belief();
+        let old_id = BeliefId::new();
+        let mut json = serde_json::to_value(&belief).unwrap();
+        json["supersedes"] = serde_json::to_value(old_id).unwrap();
+
+        let restored: Belief = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.supersedes, vec![old_id]);
+        assert!(!serde_json::to_string(&belief).unwrap().contains("supersedes"));
+    }
+
     #[test]
     fn test_belief_is_active() {
         let mut belief = make_test_belief();
@@ -478,6 +571,27 @@ mod tests {
         assert!(belief.is_valid_now());
     }
 
+    #[test]
+    fn test_belief_is_valid_at_recurring() {
+        use chrono::TimeZone;
+
+        let at = |d, h| Utc.with_ymd_and_hms(2024, 1, d, h, 0, 0).unwrap();
+        let base = TimeRange::new(at(1, 9), at(1, 17)).unwrap();
+        let mut belief = Belief::builder()
+            .subject(EntityId::new())
+            .predicate("office_open")
+            .value(true)
+            .confidence(Confidence::from_agent(0.9, "test").unwrap())
+            .valid_time(TimeRange::recurring(base, "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR".parse().unwrap()).unwrap())
+            .build()
+            .unwrap();
+        belief.tx_time = at(1, 0);
+
+        assert!(belief.is_valid_at(at(3, 10))); // Wednesday
+        assert!(!belief.is_valid_at(at(3, 17)));
+        assert!(!belief.is_valid_at(at(6, 10))); // Saturday
+    }
+
     #[test]
     fn test_belief_equality() {
         let belief1 = make_test_belief();
@@ -540,4 +654,31 @@ mod tests {
         assert_eq!(belief.id, deserialized.id);
         assert_eq!(belief.predicate, deserialized.predicate);
     }
+
+    #[test]
+    fn test_belief_metadata_is_bounded_and_omitted_when_null() {
+        let plain = make_test_belief();
+        assert!(plain.metadata.is_null());
+        assert!(!serde_json::to_string(&plain).unwrap().contains("metadata"));
+
+        let builder = || {
+            Belief::builder()
+                .subject(EntityId::new())
+                .predicate("test")
+                .value(true)
+                .confidence(Confidence::from_agent(0.5, "test").unwrap())
+        };
+        let belief = builder()
+            .metadata(serde_json::json!({"offset": [120, 184], "extractor": "ner-v2"}))
+            .build()
+            .unwrap();
+        let decoded: Belief = serde_json::from_str(&serde_json::to_string(&belief).unwrap()).unwrap();
+        assert_eq!(decoded.metadata["extractor"], "ner-v2");
+
+        let oversized = serde_json::Value::String("x".repeat(Belief::MAX_METADATA_BYTES));
+        assert!(matches!(
+            builder().metadata(oversized).build(),
+            Err(ValidationError::FieldTooLong { .. })
+        ));
+    }
 }
@@ -19,7 +19,19 @@ impl BeliefId {
     /// Creates a new random belief ID.
     #[must_use]
     pub fn new() -> Self {
-        Self(uuid::Uuid::new_v4())
+        Self(crate::id::next_id())
+    }
+
+    /// Creates a belief ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: uuid::Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Returns the underlying UUID.
+    #[must_use]
+    pub const fn as_uuid(&self) -> &uuid::Uuid {
+        &self.0
     }
 }
 
@@ -50,7 +62,7 @@ impl SourceId {
     /// Creates a new random source ID.
     #[must_use]
     pub fn new() -> Self {
-        Self(uuid::Uuid::new_v4())
+        Self(crate::id::next_id())
     }
 
     /// Creates a source ID from a UUID.
@@ -70,13 +82,14 @@ impl Default for SourceId {
 ///
 /// This is critical: confidence without calibration is meaningless.
 /// A value of 0.8 means different things depending on the calibration mode.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum CalibrationMode {
     /// Historically, ~X% of claims with this confidence are true.
     Probability,
 
     /// Uncalibrated score. Prefer `Probability` where possible.
+    #[default]
     Heuristic,
 
     /// Normalized model log-probability.
@@ -86,12 +99,6 @@ pub enum CalibrationMode {
     SourceWeighted,
 }
 
-impl Default for CalibrationMode {
-    fn default() -> Self {
-        Self::Heuristic
-    }
-}
-
 impl fmt::Display for CalibrationMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -104,7 +111,7 @@ impl fmt::Display for CalibrationMode {
 }
 
 /// Provenance of the confidence assignment.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ConfidenceSource {
     /// Explicitly asserted by an agent.
@@ -150,15 +157,47 @@ pub enum ConfidenceSource {
     },
 
     /// Source is unknown.
+    #[default]
     Unknown,
 }
 
-impl Default for ConfidenceSource {
-    fn default() -> Self {
-        Self::Unknown
+impl ConfidenceSource {
+    /// The variant of this source, without its identifiers.
+    #[must_use]
+    pub const fn kind(&self) -> ConfidenceSourceKind {
+        match self {
+            Self::AssertedByAgent { .. } => ConfidenceSourceKind::Agent,
+            Self::AssertedByHuman { .. } => ConfidenceSourceKind::Human,
+            Self::AssertedBySensor { .. } => ConfidenceSourceKind::Sensor,
+            Self::ComputedByModel { .. } => ConfidenceSourceKind::Model,
+            Self::AggregatedFromSources { .. } => ConfidenceSourceKind::Aggregated,
+            Self::DerivedFromPremises { .. } => ConfidenceSourceKind::Derived,
+            Self::Unknown => ConfidenceSourceKind::Unknown,
+        }
     }
 }
 
+/// Kind of [`ConfidenceSource`], for settings that apply to every source of a kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfidenceSourceKind {
+    /// [`ConfidenceSource::AssertedByAgent`].
+    Agent,
+    /// [`ConfidenceSource::AssertedByHuman`].
+    Human,
+    /// [`ConfidenceSource::AssertedBySensor`].
+    Sensor,
+    /// [`ConfidenceSource::ComputedByModel`].
+    Model,
+    /// [`ConfidenceSource::AggregatedFromSources`].
+    Aggregated,
+    /// [`ConfidenceSource::DerivedFromPremises`].
+    Derived,
+    /// [`ConfidenceSource::Unknown`].
+    Unknown,
+}
+
+
 /// Formalized uncertainty.
 ///
 /// Confidence values must always have calibration and provenance.
@@ -334,7 +373,7 @@ impl Confidence {
 
     /// Validates that a confidence value is in the valid range.
     fn validate_value(value: f32) -> Result<(), ValidationError> {
-        if value.is_nan() {
+        if !value.is_finite() {
             return Err(ValidationError::ConfidenceOutOfRange { value });
         }
         if !(Self::MIN_VALUE..=Self::MAX_VALUE).contains(&value) {
@@ -412,6 +451,8 @@ mod tests {
         assert!(Confidence::from_agent(-0.1, "test").is_err());
         assert!(Confidence::from_agent(1.1, "test").is_err());
         assert!(Confidence::from_agent(f32::NAN, "test").is_err());
+        assert!(Confidence::from_agent(f32::INFINITY, "test").is_err());
+        assert!(Confidence::from_agent(f32::NEG_INFINITY, "test").is_err());
     }
 
     #[test]
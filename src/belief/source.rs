@@ -367,6 +367,15 @@ impl Source {
         }
     }
 
+    /// Returns the identifier of a paper source: its ArXiv ID, else its DOI.
+    #[must_use]
+    pub fn paper_id(&self) -> Option<&str> {
+        match self {
+            Self::Paper { arxiv_id, doi, .. } => arxiv_id.as_deref().or(doi.as_deref()),
+            _ => None,
+        }
+    }
+
     /// Returns true if this is a human source.
     #[must_use]
     pub const fn is_human(&self) -> bool {
@@ -532,6 +541,11 @@ mod tests {
         assert!(!source.is_automated());
 
         if let Source::Human { user_id, .. } = &source {
+            assert_eq!(user_id, "user-123");
+        } else {
+            panic!("Expected Human source");
+        }
+    }
 
     #[test]
     fn test_stable_encoding_and_uuid_vector() {
@@ -545,17 +559,15 @@ mod tests {
         let digest = blake3::hash(&encoding);
         let uuid = Uuid::new_v5(&SOURCE_ID_NAMESPACE, digest.as_bytes());
 
-        let expected_hex = "dff6b8e0f3da5ee1f711d2bfa3b4d157f4d7230f2acc3eac9d8c58cf0c8e7297";
-        let expected_uuid = Uuid::parse_str("60a51c60-143d-57f4-88f2-1492fb2f554d").unwrap();
+        // Pinned to the v1 encoding every stored SourceId was derived with. The vectors first
+        // written here were never checked, as the test sat inside another one, and do not
+        // match it.
+        let expected_hex = "e5fb819fd083bb9412eec23656b2bd3940a4582c33f3254ee7e29be1c7c9ae10";
+        let expected_uuid = Uuid::parse_str("affceb5a-1d58-5c84-a1ad-9541fff24e88").unwrap();
 
         assert_eq!(hex::encode(digest.as_bytes()), expected_hex);
         assert_eq!(uuid, expected_uuid);
     }
-            assert_eq!(user_id, "user-123");
-        } else {
-            panic!("Expected Human source");
-        }
-    }
 
     #[test]
     fn test_source_human_with_role() {
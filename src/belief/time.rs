@@ -4,15 +4,30 @@
 //! - **Valid Time**: When is this belief true in reality?
 //! - **Transaction Time**: When did the system learn this?
 
-use chrono::{DateTime, Duration, Utc};
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDateTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
 use crate::error::ValidationError;
 
+/// Maximum `INTERVAL` accepted in a recurrence rule.
+const MAX_RRULE_INTERVAL: u32 = 100;
+
+/// Maximum `COUNT` accepted in a recurrence rule.
+const MAX_RRULE_COUNT: u32 = 10_000;
+
+/// How far past the start of a shared window `overlaps` scans recurring occurrences.
+const RECURRENCE_HORIZON_DAYS: i64 = 366;
+
 /// A range of time (half-open interval: [from, to)).
 /// 
 /// Used to represent the valid time of a belief—when it is true in reality.
 ///
+/// A range may also recur (see [`TimeRange::recurring`]). It then only covers its
+/// occurrences, and `from`/`to` describe the envelope: the first occurrence start and
+/// the end of the last occurrence (or open-ended).
+///
 /// # Examples
 ///
 /// ```
@@ -34,12 +49,35 @@ pub struct TimeRange {
 
     /// End of the range (exclusive). None means open-ended.
     to: Option<DateTime<Utc>>,
+
+    /// Recurrence, if the range only covers repeated occurrences.
+    recurrence: Option<Recurrence>,
+}
+
+/// Occurrence schedule of a recurring [`TimeRange`].
+///
+/// Occurrences start at `from + n days` for every day `n` the rule selects and last `length`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Recurrence {
+    rule: RecurrenceRule,
+    length: Duration,
+    /// Start of the final occurrence when the rule has `COUNT` or `UNTIL`.
+    last_start: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TimeRangeRepr {
     from: DateTime<Utc>,
     to: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recurrence: Option<RecurrenceRepr>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecurrenceRepr {
+    rrule: RecurrenceRule,
+    /// End of the first occurrence.
+    base_to: DateTime<Utc>,
 }
 
 impl TryFrom<TimeRangeRepr> for TimeRange {
@@ -55,10 +93,20 @@ impl TryFrom<TimeRangeRepr> for TimeRange {
             }
         }
 
-        Ok(Self {
-            from: value.from,
-            to: value.to,
-        })
+        match value.recurrence {
+            None => Ok(Self {
+                from: value.from,
+                to: value.to,
+                recurrence: None,
+            }),
+            Some(rec) => {
+                let base = Self::new(value.from, rec.base_to)?;
+                let mut range = Self::recurring(base, rec.rrule)?;
+                // The envelope may have been closed since the rule was attached.
+                range.to = value.to;
+                Ok(range)
+            }
+        }
     }
 }
 
@@ -67,6 +115,10 @@ impl From<TimeRange> for TimeRangeRepr {
         Self {
             from: value.from,
             to: value.to,
+            recurrence: value.recurrence.map(|rec| RecurrenceRepr {
+                base_to: value.from + rec.length,
+                rrule: rec.rule,
+            }),
         }
     }
 }
@@ -104,22 +156,79 @@ impl TimeRange {
         if from >= to {
             return Err(ValidationError::InvalidTimeRange { from, to });
         }
-        Ok(Self { from, to: Some(to) })
+        Ok(Self {
+            from,
+            to: Some(to),
+            recurrence: None,
+        })
     }
 
     /// Creates an open-ended time range starting at the given time.
     #[must_use]
     pub const fn starting_at(from: DateTime<Utc>) -> Self {
-        Self { from, to: None }
+        Self {
+            from,
+            to: None,
+            recurrence: None,
+        }
+    }
+
+    /// Creates a time range from two timestamps carrying a UTC offset.
+    ///
+    /// Both ends are normalized to UTC, so the offsets may differ, as they do for local
+    /// times on either side of a daylight saving change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidTimeRange` if `from >= to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kyroql::TimeRange;
+    /// use chrono::DateTime;
+    ///
+    /// let from = DateTime::parse_from_rfc3339("2024-06-01T09:00:00+02:00").unwrap();
+    /// let to = DateTime::parse_from_rfc3339("2024-06-01T17:00:00+02:00").unwrap();
+    /// let range = TimeRange::from_offset(from, to).unwrap();
+    /// assert_eq!(range.from().to_rfc3339(), "2024-06-01T07:00:00+00:00");
+    /// ```
+    pub fn from_offset(from: DateTime<FixedOffset>, to: DateTime<FixedOffset>) -> Result<Self, ValidationError> {
+        Self::new(from.with_timezone(&Utc), to.with_timezone(&Utc))
+    }
+
+    /// Creates an open-ended time range starting at a timestamp carrying a UTC offset.
+    #[must_use]
+    pub fn starting_at_offset(from: DateTime<FixedOffset>) -> Self {
+        Self::starting_at(from.with_timezone(&Utc))
+    }
+
+    /// Parses a time range from RFC 3339 timestamps, e.g. `2024-06-01T09:00:00-04:00`.
+    ///
+    /// Each timestamp may carry its own offset; `to = None` gives an open-ended range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidField` if a timestamp does not parse, and
+    /// `ValidationError::InvalidTimeRange` if `from >= to`.
+    pub fn parse_rfc3339(from: &str, to: Option<&str>) -> Result<Self, ValidationError> {
+        let parse = |s: &str| {
+            DateTime::parse_from_rfc3339(s.trim()).map_err(|e| ValidationError::InvalidField {
+                field: "valid_time".to_string(),
+                reason: format!("invalid RFC 3339 timestamp '{s}': {e}"),
+            })
+        };
+        let from = parse(from)?;
+        match to {
+            Some(to) => Self::from_offset(from, parse(to)?),
+            None => Ok(Self::starting_at_offset(from)),
+        }
     }
 
     /// Creates an open-ended time range starting now.
     #[must_use]
     pub fn from_now() -> Self {
-        Self {
-            from: Utc::now(),
-            to: None,
-        }
+        Self::starting_at(Utc::now())
     }
 
     /// Creates a time range starting now with a specified duration.
@@ -134,6 +243,7 @@ impl TimeRange {
         Self {
             from,
             to: Some(from + duration),
+            recurrence: None,
         }
     }
 
@@ -143,16 +253,97 @@ impl TimeRange {
         Self {
             from: at,
             to: Some(at + Duration::microseconds(1)),
+            recurrence: None,
         }
     }
 
     /// Creates a time range representing "forever" (from epoch to open-ended).
     #[must_use]
     pub fn forever() -> Self {
-        Self {
-            from: DateTime::UNIX_EPOCH,
-            to: None,
+        Self::starting_at(DateTime::UNIX_EPOCH)
+    }
+
+    /// Creates a recurring time range: `base` repeated according to `rrule`.
+    ///
+    /// `base` is the first occurrence and must be bounded; its start has to be selected
+    /// by the rule. Every occurrence has the same length as `base`. The range is
+    /// open-ended unless the rule carries `COUNT` or `UNTIL`, in which case it ends
+    /// with the last occurrence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::InvalidField` if `base` is open-ended or itself recurring,
+    /// if its start is not an occurrence of `rrule`, or if `UNTIL` precedes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kyroql::TimeRange;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// // Mondays to Fridays, 09:00–17:00 UTC, starting Monday 2024-01-01.
+    /// let base = TimeRange::new(
+    ///     Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2024, 1, 1, 17, 0, 0).unwrap(),
+    /// )
+    /// .unwrap();
+    /// let office = TimeRange::recurring(base, "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR".parse().unwrap()).unwrap();
+    ///
+    /// assert!(office.contains(Utc.with_ymd_and_hms(2024, 1, 5, 12, 0, 0).unwrap()));
+    /// assert!(!office.contains(Utc.with_ymd_and_hms(2024, 1, 6, 12, 0, 0).unwrap()));
+    /// ```
+    pub fn recurring(base: TimeRange, rrule: RecurrenceRule) -> Result<Self, ValidationError> {
+        let invalid = |reason: String| ValidationError::InvalidField {
+            field: "valid_time".to_string(),
+            reason,
+        };
+
+        if base.recurrence.is_some() {
+            return Err(invalid("recurrence base must not itself recur".to_string()));
+        }
+        let Some(base_to) = base.to else {
+            return Err(invalid("recurrence base must be a bounded range".to_string()));
+        };
+        if !rrule.selects(base.from, 0) {
+            return Err(invalid(format!(
+                "recurrence base start {} is not an occurrence of {rrule}",
+                base.from
+            )));
+        }
+        if let Some(until) = rrule.until {
+            if until < base.from {
+                return Err(invalid(format!("UNTIL {until} precedes the first occurrence")));
+            }
         }
+
+        let mut recurrence = Recurrence {
+            length: base_to - base.from,
+            rule: rrule,
+            last_start: None,
+        };
+        recurrence.last_start = match (recurrence.rule.count, recurrence.rule.until) {
+            (Some(count), _) => Some(recurrence.nth_start(base.from, count)),
+            (None, Some(until)) => recurrence.latest_start(base.from, until),
+            (None, None) => None,
+        };
+
+        Ok(Self {
+            from: base.from,
+            to: recurrence.last_start.map(|start| start + recurrence.length),
+            recurrence: Some(recurrence),
+        })
+    }
+
+    /// Returns the recurrence rule, if this range recurs.
+    #[must_use]
+    pub fn recurrence(&self) -> Option<&RecurrenceRule> {
+        self.recurrence.as_ref().map(|rec| &rec.rule)
+    }
+
+    /// Returns the length of each occurrence, if this range recurs.
+    #[must_use]
+    pub fn occurrence_length(&self) -> Option<Duration> {
+        self.recurrence.as_ref().map(|rec| rec.length)
     }
 
     /// Returns `true` if this range has no end time.
@@ -174,19 +365,77 @@ impl TimeRange {
     }
 
     /// Check if a timestamp falls within this range [from, to).
+    ///
+    /// For a recurring range the timestamp must also fall within an occurrence, which is
+    /// itself half-open: its start is contained, its end is not.
     #[must_use]
     pub fn contains(&self, time: DateTime<Utc>) -> bool {
-        time >= self.from && self.to.map_or(true, |to| time < to)
+        if time < self.from || self.to.is_some_and(|to| time >= to) {
+            return false;
+        }
+        match &self.recurrence {
+            None => true,
+            Some(rec) => rec
+                .latest_start(self.from, time)
+                .is_some_and(|start| time < start + rec.length),
+        }
     }
 
     /// Returns `true` if this range overlaps with another.
+    ///
+    /// Recurring ranges are compared occurrence by occurrence, scanning at most one year
+    /// past the start of the shared window; overlaps only further out are not detected.
     pub fn overlaps(&self, other: &Self) -> bool {
         let self_end = self.to.unwrap_or(DateTime::<Utc>::MAX_UTC);
         let other_end = other.to.unwrap_or(DateTime::<Utc>::MAX_UTC);
-        self.from < other_end && other.from < self_end
+        if !(self.from < other_end && other.from < self_end) {
+            return false;
+        }
+
+        match (&self.recurrence, &other.recurrence) {
+            (None, None) => true,
+            (Some(rec), _) => self.any_occurrence_overlaps(rec, other),
+            (None, Some(rec)) => other.any_occurrence_overlaps(rec, self),
+        }
+    }
+
+    /// Check each occurrence of `self` inside the window shared with `other`.
+    fn any_occurrence_overlaps(&self, rec: &Recurrence, other: &Self) -> bool {
+        let self_end = self.to.unwrap_or(DateTime::<Utc>::MAX_UTC);
+        let window_start = self.from.max(other.from);
+        let window_end = other
+            .to
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+            .min(self_end)
+            .min(window_start + Duration::days(RECURRENCE_HORIZON_DAYS));
+
+        // The latest occurrence starting at or before the window is the only earlier one
+        // that can still reach into it, since all occurrences share the same length.
+        let first = rec
+            .latest_start(self.from, window_start)
+            .map_or(0, |start| (start - self.from).num_days());
+        let last = rec
+            .last_start
+            .map_or(i64::MAX, |start| (start - self.from).num_days());
+
+        (first..=last)
+            .map(|day| (day, self.from + Duration::days(day)))
+            .take_while(|(_, start)| *start < window_end)
+            .filter(|(day, _)| rec.rule.selects(self.from, *day))
+            .any(|(_, start)| {
+                let occurrence = Self {
+                    from: start,
+                    to: Some((start + rec.length).min(self_end)),
+                    recurrence: None,
+                };
+                other.overlaps(&occurrence)
+            })
     }
 
     /// Returns the intersection of two ranges, if any.
+    ///
+    /// For recurring ranges this is the bounding window of the two envelopes; the
+    /// recurrence itself is not carried over.
     #[must_use]
     pub fn intersection(&self, other: &Self) -> Option<Self> {
         if !self.overlaps(other) {
@@ -201,7 +450,11 @@ impl TimeRange {
             (None, None) => None,
         };
 
-        Some(Self { from, to })
+        Some(Self {
+            from,
+            to,
+            recurrence: None,
+        })
     }
 
     /// Returns the duration of this range, or `None` if open-ended.
@@ -215,7 +468,7 @@ impl TimeRange {
             return;
         }
         if let Some(to) = self.to.as_mut() {
-            *to = *to + duration;
+            *to += duration;
         }
     }
 
@@ -250,6 +503,18 @@ impl TimeRange {
         Ok(())
     }
 
+    /// This range with its bounds expressed at `offset`, for display or serialization.
+    ///
+    /// The range itself is always stored in UTC; this only changes how it is rendered.
+    #[must_use]
+    pub fn at_offset(&self, offset: FixedOffset) -> OffsetTimeRange {
+        OffsetTimeRange {
+            from: self.from.with_timezone(&offset),
+            to: self.to.map(|to| to.with_timezone(&offset)),
+            rrule: self.recurrence().cloned(),
+        }
+    }
+
     /// Sets the end time, clamping to ensure the interval remains valid.
     ///
     /// If `at <= from`, this sets `to = from + 1 microsecond`.
@@ -272,9 +537,310 @@ impl Default for TimeRange {
 impl std::fmt::Display for TimeRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.to {
-            Some(to) => write!(f, "[{} → {})", self.from, to),
-            None => write!(f, "[{} → ∞)", self.from),
+            Some(to) => write!(f, "[{} → {})", self.from, to)?,
+            None => write!(f, "[{} → ∞)", self.from)?,
+        }
+        if let Some(rec) = &self.recurrence {
+            write!(f, " RRULE:{} ({} each)", rec.rule, rec.length)?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`TimeRange`] rendered at a caller-chosen UTC offset; see [`TimeRange::at_offset`].
+///
+/// Serializes its bounds as RFC 3339 timestamps carrying that offset. Deserializing such
+/// timestamps into a [`TimeRange`] normalizes them back to UTC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OffsetTimeRange {
+    /// Start of the range (inclusive).
+    pub from: DateTime<FixedOffset>,
+    /// End of the range (exclusive); `None` means open-ended.
+    pub to: Option<DateTime<FixedOffset>>,
+    /// Recurrence rule, if the range recurs. Its weekdays are still evaluated in UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrule: Option<RecurrenceRule>,
+}
+
+impl std::fmt::Display for OffsetTimeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to {
+            Some(to) => write!(f, "[{} → {})", self.from, to)?,
+            None => write!(f, "[{} → ∞)", self.from)?,
         }
+        if let Some(rule) = &self.rrule {
+            write!(f, " RRULE:{rule}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Recurrence {
+    /// Latest occurrence start at or before `at` (`anchor` is the first occurrence).
+    fn latest_start(&self, anchor: DateTime<Utc>, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if at < anchor {
+            return None;
+        }
+        if let Some(last) = self.last_start {
+            if at >= last {
+                return Some(last);
+            }
+        }
+
+        // Every rule period (at most `7 * interval` days) contains an occurrence.
+        let day = (at - anchor).num_days();
+        let floor = day.saturating_sub(7 * i64::from(self.rule.interval)).max(0);
+        (floor..=day)
+            .rev()
+            .find(|&n| self.rule.selects(anchor, n))
+            .map(|n| anchor + Duration::days(n))
+    }
+
+    /// Start of the `count`-th occurrence (1-based).
+    fn nth_start(&self, anchor: DateTime<Utc>, count: u32) -> DateTime<Utc> {
+        let day = (0..)
+            .filter(|&n| self.rule.selects(anchor, n))
+            .nth(count as usize - 1)
+            .unwrap_or(0);
+        anchor + Duration::days(day)
+    }
+}
+
+/// Frequency of a [`RecurrenceRule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecurrenceFrequency {
+    /// Every `INTERVAL` days.
+    Daily,
+    /// Every `INTERVAL` weeks (weeks start on Monday).
+    Weekly,
+}
+
+/// A minimal subset of an RFC 5545 `RRULE`.
+///
+/// Supported parts are `FREQ` (`DAILY` or `WEEKLY`), `INTERVAL`, `BYDAY` (plain
+/// weekdays such as `MO,WE`), and either `COUNT` or `UNTIL` (UTC form,
+/// `YYYYMMDDTHHMMSSZ`). Weekdays are evaluated in UTC. An optional `RRULE:` prefix
+/// is accepted.
+///
+/// ```
+/// use kyroql::time::RecurrenceRule;
+///
+/// let rule: RecurrenceRule = "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR".parse().unwrap();
+/// assert_eq!(rule.to_string(), "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RecurrenceRule {
+    freq: RecurrenceFrequency,
+    interval: u32,
+    by_day: Vec<Weekday>,
+    count: Option<u32>,
+    until: Option<DateTime<Utc>>,
+}
+
+impl RecurrenceRule {
+    /// Returns the rule frequency.
+    #[must_use]
+    pub fn freq(&self) -> RecurrenceFrequency {
+        self.freq
+    }
+
+    /// Returns the interval between periods (at least 1).
+    #[must_use]
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns the selected weekdays; empty means every day (daily) or the start weekday (weekly).
+    #[must_use]
+    pub fn by_day(&self) -> &[Weekday] {
+        &self.by_day
+    }
+
+    /// Returns the total number of occurrences, if bounded by `COUNT`.
+    #[must_use]
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Returns the last allowed occurrence start, if bounded by `UNTIL`.
+    #[must_use]
+    pub fn until(&self) -> Option<DateTime<Utc>> {
+        self.until
+    }
+
+    /// Whether the rule selects the day `day` days after the first occurrence at `anchor`.
+    fn selects(&self, anchor: DateTime<Utc>, day: i64) -> bool {
+        let weekday = (anchor + Duration::days(day)).weekday();
+        let interval = i64::from(self.interval);
+        match self.freq {
+            RecurrenceFrequency::Daily => {
+                day % interval == 0 && (self.by_day.is_empty() || self.by_day.contains(&weekday))
+            }
+            RecurrenceFrequency::Weekly => {
+                let week = (i64::from(anchor.weekday().num_days_from_monday()) + day) / 7;
+                let weekday_selected = if self.by_day.is_empty() {
+                    weekday == anchor.weekday()
+                } else {
+                    self.by_day.contains(&weekday)
+                };
+                week % interval == 0 && weekday_selected
+            }
+        }
+    }
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    Some(match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+const fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| ValidationError::InvalidField {
+            field: "rrule".to_string(),
+            reason,
+        };
+
+        let upper = s.trim().to_ascii_uppercase();
+        let body = upper.strip_prefix("RRULE:").unwrap_or(&upper);
+
+        let mut freq = None;
+        let mut interval = None;
+        let mut by_day = None;
+        let mut count = None;
+        let mut until = None;
+
+        for part in body.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected KEY=VALUE, got '{part}'")))?;
+            let duplicate = match key {
+                "FREQ" => freq
+                    .replace(match value {
+                        "DAILY" => RecurrenceFrequency::Daily,
+                        "WEEKLY" => RecurrenceFrequency::Weekly,
+                        _ => return Err(invalid(format!("unsupported FREQ '{value}'"))),
+                    })
+                    .is_some(),
+                "INTERVAL" => interval
+                    .replace(
+                        value
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|n| (1..=MAX_RRULE_INTERVAL).contains(n))
+                            .ok_or_else(|| {
+                                invalid(format!("INTERVAL must be between 1 and {MAX_RRULE_INTERVAL}"))
+                            })?,
+                    )
+                    .is_some(),
+                "BYDAY" => {
+                    let mut days = Vec::new();
+                    for code in value.split(',') {
+                        let day = parse_weekday(code)
+                            .ok_or_else(|| invalid(format!("unsupported BYDAY value '{code}'")))?;
+                        if !days.contains(&day) {
+                            days.push(day);
+                        }
+                    }
+                    days.sort_by_key(Weekday::num_days_from_monday);
+                    by_day.replace(days).is_some()
+                }
+                "COUNT" => count
+                    .replace(
+                        value
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|n| (1..=MAX_RRULE_COUNT).contains(n))
+                            .ok_or_else(|| invalid(format!("COUNT must be between 1 and {MAX_RRULE_COUNT}")))?,
+                    )
+                    .is_some(),
+                "UNTIL" => until
+                    .replace(
+                        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+                            .map_err(|_| invalid(format!("UNTIL must be a UTC date-time, got '{value}'")))?
+                            .and_utc(),
+                    )
+                    .is_some(),
+                _ => return Err(invalid(format!("unsupported rule part '{key}'"))),
+            };
+            if duplicate {
+                return Err(invalid(format!("{key} given more than once")));
+            }
+        }
+
+        let freq = freq.ok_or_else(|| invalid("FREQ is required".to_string()))?;
+        if count.is_some() && until.is_some() {
+            return Err(invalid("COUNT and UNTIL are mutually exclusive".to_string()));
+        }
+
+        Ok(Self {
+            freq,
+            interval: interval.unwrap_or(1),
+            by_day: by_day.unwrap_or_default(),
+            count,
+            until,
+        })
+    }
+}
+
+impl TryFrom<String> for RecurrenceRule {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<RecurrenceRule> for String {
+    fn from(value: RecurrenceRule) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let freq = match self.freq {
+            RecurrenceFrequency::Daily => "DAILY",
+            RecurrenceFrequency::Weekly => "WEEKLY",
+        };
+        write!(f, "FREQ={freq}")?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<&str> = self.by_day.iter().map(|d| weekday_code(*d)).collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        Ok(())
     }
 }
 
@@ -477,4 +1043,147 @@ mod tests {
         assert_eq!(range.from, deserialized.from);
         assert_eq!(range.to, deserialized.to);
     }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        use chrono::TimeZone;
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    /// Mondays to Fridays, 09:00–17:00 UTC, starting Monday 2024-01-01.
+    fn office_hours() -> TimeRange {
+        let base = TimeRange::new(utc(2024, 1, 1, 9, 0), utc(2024, 1, 1, 17, 0)).unwrap();
+        TimeRange::recurring(base, "FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR".parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_recurring_weekdays_contains() {
+        let range = office_hours();
+        assert!(range.is_open_ended());
+
+        // Occurrence boundaries: start inclusive, end exclusive.
+        assert!(range.contains(utc(2024, 1, 1, 9, 0)));
+        assert!(range.contains(utc(2024, 1, 1, 17, 0) - Duration::microseconds(1)));
+        assert!(!range.contains(utc(2024, 1, 1, 17, 0)));
+        assert!(!range.contains(utc(2024, 1, 1, 9, 0) - Duration::microseconds(1)));
+
+        assert!(range.contains(utc(2024, 1, 5, 12, 0))); // Friday
+        assert!(!range.contains(utc(2024, 1, 6, 12, 0))); // Saturday
+        assert!(!range.contains(utc(2024, 1, 7, 12, 0))); // Sunday
+        assert!(range.contains(utc(2024, 1, 8, 9, 0))); // next Monday
+        assert!(range.contains(utc(2025, 6, 4, 16, 30))); // a Wednesday, much later
+
+        // Before the first occurrence, even on a weekday.
+        assert!(!range.contains(utc(2023, 12, 29, 12, 0)));
+    }
+
+    #[test]
+    fn test_recurring_occurrence_spanning_midnight() {
+        // Friday 22:00 to Saturday 02:00.
+        let base = TimeRange::new(utc(2024, 1, 5, 22, 0), utc(2024, 1, 6, 2, 0)).unwrap();
+        let range = TimeRange::recurring(base, "RRULE:FREQ=WEEKLY".parse().unwrap()).unwrap();
+
+        assert!(range.contains(utc(2024, 1, 13, 1, 0)));
+        assert!(!range.contains(utc(2024, 1, 13, 2, 0)));
+        assert!(!range.contains(utc(2024, 1, 6, 22, 0)));
+    }
+
+    #[test]
+    fn test_recurring_count_and_until_bound_the_range() {
+        let base = TimeRange::new(utc(2024, 1, 1, 9, 0), utc(2024, 1, 1, 10, 0)).unwrap();
+
+        let counted = TimeRange::recurring(base.clone(), "FREQ=DAILY;COUNT=3".parse().unwrap()).unwrap();
+        assert_eq!(counted.to(), Some(utc(2024, 1, 3, 10, 0)));
+        assert!(counted.contains(utc(2024, 1, 3, 9, 30)));
+        assert!(!counted.contains(utc(2024, 1, 4, 9, 30)));
+
+        let until = TimeRange::recurring(
+            base,
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=20240120T000000Z".parse().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(until.to(), Some(utc(2024, 1, 15, 10, 0)));
+        assert!(!until.contains(utc(2024, 1, 8, 9, 30))); // off week
+        assert!(until.contains(utc(2024, 1, 15, 9, 30)));
+    }
+
+    #[test]
+    fn test_recurring_overlaps() {
+        let range = office_hours();
+
+        let saturday = TimeRange::new(utc(2024, 1, 6, 0, 0), utc(2024, 1, 7, 0, 0)).unwrap();
+        assert!(!range.overlaps(&saturday));
+        assert!(!saturday.overlaps(&range));
+
+        let weekend_into_monday = TimeRange::new(utc(2024, 1, 6, 16, 0), utc(2024, 1, 8, 9, 30)).unwrap();
+        assert!(range.overlaps(&weekend_into_monday));
+        assert!(range.overlaps(&TimeRange::starting_at(utc(2024, 1, 6, 0, 0))));
+
+        let weekend_base = TimeRange::new(utc(2024, 1, 6, 0, 0), utc(2024, 1, 7, 0, 0)).unwrap();
+        let weekends = TimeRange::recurring(weekend_base, "FREQ=WEEKLY;BYDAY=SA,SU".parse().unwrap()).unwrap();
+        assert!(!range.overlaps(&weekends));
+
+        let friday_base = TimeRange::new(utc(2024, 1, 5, 16, 0), utc(2024, 1, 5, 18, 0)).unwrap();
+        let friday_drinks = TimeRange::recurring(friday_base, "FREQ=WEEKLY;BYDAY=FR".parse().unwrap()).unwrap();
+        assert!(range.overlaps(&friday_drinks));
+        assert!(friday_drinks.overlaps(&range));
+    }
+
+    #[test]
+    fn test_recurring_rejects_invalid_input() {
+        assert!("BYDAY=MO".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=MONTHLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;COUNT=2;UNTIL=20240120T000000Z".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;INTERVAL=0".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=WEEKLY;BYDAY=1MO".parse::<RecurrenceRule>().is_err());
+
+        let weekdays: RecurrenceRule = "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR".parse().unwrap();
+        let saturday = TimeRange::new(utc(2024, 1, 6, 9, 0), utc(2024, 1, 6, 17, 0)).unwrap();
+        assert!(TimeRange::recurring(saturday, weekdays.clone()).is_err());
+        assert!(TimeRange::recurring(TimeRange::starting_at(utc(2024, 1, 1, 9, 0)), weekdays.clone()).is_err());
+        assert!(TimeRange::recurring(office_hours(), weekdays).is_err());
+    }
+
+    #[test]
+    fn test_recurring_serialization_roundtrip() {
+        let mut range = office_hours();
+        range.close_at(utc(2024, 2, 1, 0, 0)).unwrap();
+
+        let json = serde_json::to_string(&range).unwrap();
+        let deserialized: TimeRange = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, range);
+        assert_eq!(
+            deserialized.recurrence().map(ToString::to_string).as_deref(),
+            Some("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR")
+        );
+        assert!(!deserialized.contains(utc(2024, 2, 1, 12, 0)));
+    }
+
+    #[test]
+    fn test_offset_timestamps_normalize_across_dst_change() {
+        // US daylight saving starts 2026-03-08 at 02:00 EST (-05:00), which becomes 03:00 EDT (-04:00).
+        let range = TimeRange::parse_rfc3339("2026-03-08T01:00:00-05:00", Some("2026-03-08T04:00:00-04:00")).unwrap();
+        assert_eq!(range.from(), utc(2026, 3, 8, 6, 0));
+        assert_eq!(range.duration(), Some(Duration::hours(2)));
+
+        let local = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        assert!(range.contains(local("2026-03-08T01:59:00-05:00")));
+        assert!(range.contains(local("2026-03-08T03:30:00-04:00")));
+        assert!(!range.contains(local("2026-03-08T04:00:00-04:00")));
+        assert!(!range.contains(local("2026-03-08T00:59:00-05:00")));
+
+        let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+        let shown = range.at_offset(edt);
+        assert_eq!(shown.to_string(), "[2026-03-08 02:00:00 -04:00 → 2026-03-08 04:00:00 -04:00)");
+        let json = serde_json::to_value(&shown).unwrap();
+        assert_eq!(json["from"], "2026-03-08T02:00:00-04:00");
+
+        // Offset timestamps deserialize into the same UTC range.
+        let parsed: TimeRange = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, range);
+
+        let open = TimeRange::starting_at_offset(DateTime::parse_from_rfc3339("2026-03-08T01:00:00-05:00").unwrap());
+        assert_eq!(open.from(), range.from());
+        assert!(TimeRange::parse_rfc3339("2026-03-08 01:00", None).is_err());
+        assert!(TimeRange::parse_rfc3339("2026-03-08T04:00:00-04:00", Some("2026-03-08T02:00:00-05:00")).is_err());
+    }
 }
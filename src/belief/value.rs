@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::entity::EntityId;
+use crate::error::ValidationError;
 
 /// Possible values a belief can hold.
 ///
@@ -18,14 +19,14 @@ use crate::entity::EntityId;
 /// use kyroql::Value;
 ///
 /// let bool_val = Value::Bool(true);
-/// let float_val = Value::Float(3.14);
+/// let float_val = Value::Float(2.5);
 /// let string_val = Value::String("hello".to_string());
 ///
 /// assert!(bool_val.is_bool());
 /// assert!(float_val.is_float());
 /// assert!(string_val.is_string());
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
 pub enum Value {
     /// Boolean value.
@@ -43,10 +44,171 @@ pub enum Value {
     /// Arbitrary JSON structure.
     Structured(serde_json::Value),
     /// Explicit null/missing value.
+    #[default]
     Null,
+    /// Denial of the wrapped value ("is not X"); build with [`Value::not`].
+    Not(Box<Value>),
+    /// Probability distribution over alternative values, as `(value, weight)` pairs whose
+    /// weights sum to ~1; build with [`Value::distribution`].
+    Distribution(Vec<(Value, f32)>),
+}
+
+/// How far the weights of a [`Value::Distribution`] may sum away from 1.
+pub const DISTRIBUTION_WEIGHT_TOLERANCE: f32 = 0.01;
+
+/// The type of a [`Value`], without its payload.
+///
+/// Used as the target of [`Value::try_into_typed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueType {
+    /// Boolean value.
+    Bool,
+    /// 64-bit signed integer.
+    Int,
+    /// 64-bit floating point.
+    Float,
+    /// UTF-8 string.
+    String,
+    /// Reference to an entity.
+    Entity,
+    /// Vector embedding.
+    Embedding,
+    /// Arbitrary JSON structure.
+    Structured,
+    /// Explicit null/missing value.
+    Null,
+    /// Denial of a value.
+    Not,
+    /// Probability distribution over values.
+    Distribution,
+}
+
+impl ValueType {
+    /// Returns the type name, matching [`Value::type_name`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Bool => "bool",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::String => "string",
+            Self::Entity => "entity",
+            Self::Embedding => "embedding",
+            Self::Structured => "structured",
+            Self::Null => "null",
+            Self::Not => "not",
+            Self::Distribution => "distribution",
+        }
+    }
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Numeric comparison operator for `ValueMatcher::Compare` and cross-predicate order
+/// patterns.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl ComparisonOp {
+    /// Evaluate `value <op> threshold`.
+    #[must_use]
+    pub fn holds(self, value: f64, threshold: f64) -> bool {
+        match self {
+            Self::Lt => value < threshold,
+            Self::Le => value <= threshold,
+            Self::Gt => value > threshold,
+            Self::Ge => value >= threshold,
+        }
+    }
+}
+
+impl std::fmt::Display for ComparisonOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lt => write!(f, "<"),
+            Self::Le => write!(f, "<="),
+            Self::Gt => write!(f, ">"),
+            Self::Ge => write!(f, ">="),
+        }
+    }
 }
 
 impl Value {
+    /// Returns the denial of `value`. Denying a denial yields the original value.
+    #[must_use]
+    pub fn not(value: impl Into<Self>) -> Self {
+        match value.into() {
+            Self::Not(inner) => *inner,
+            other => Self::Not(Box::new(other)),
+        }
+    }
+
+    /// Returns a distribution over `outcomes`, each a value and its probability.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidField` (field `value`) if the outcomes break the rules checked by
+    /// [`Value::validate`].
+    pub fn distribution(outcomes: Vec<(Self, f32)>) -> Result<Self, ValidationError> {
+        let value = Self::Distribution(outcomes);
+        value.validate()?;
+        Ok(value)
+    }
+
+    /// Checks the invariants of a distribution; every other value is valid.
+    ///
+    /// A distribution needs at least one outcome, distinct outcome values that are neither
+    /// distributions nor denials, finite weights in `[0.0, 1.0]`, and weights summing to 1
+    /// within [`DISTRIBUTION_WEIGHT_TOLERANCE`]. A distribution cannot itself be denied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidField` (field `value`) naming the broken rule.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let invalid = |reason: String| ValidationError::InvalidField {
+            field: "value".to_string(),
+            reason,
+        };
+        let outcomes = match self {
+            Self::Distribution(outcomes) => outcomes,
+            Self::Not(inner) if inner.is_distribution() => {
+                return Err(invalid("a distribution cannot be denied".to_string()));
+            }
+            _ => return Ok(()),
+        };
+        if outcomes.is_empty() {
+            return Err(invalid("a distribution needs at least one outcome".to_string()));
+        }
+        for (i, (value, weight)) in outcomes.iter().enumerate() {
+            if value.is_distribution() || value.is_denial() {
+                return Err(invalid(format!("distribution outcome {value} must be a plain value")));
+            }
+            if !weight.is_finite() || !(0.0..=1.0).contains(weight) {
+                return Err(invalid(format!("weight of outcome {value} must be within [0.0, 1.0], got {weight}")));
+            }
+            if outcomes[..i].iter().any(|(other, _)| other == value) {
+                return Err(invalid(format!("distribution lists outcome {value} twice")));
+            }
+        }
+        let total: f32 = outcomes.iter().map(|(_, w)| w).sum();
+        if (total - 1.0).abs() > DISTRIBUTION_WEIGHT_TOLERANCE {
+            return Err(invalid(format!("distribution weights must sum to 1, got {total}")));
+        }
+        Ok(())
+    }
+
     /// Returns `true` if this is a `Bool` variant.
     pub const fn is_bool(&self) -> bool {
         matches!(self, Self::Bool(_))
@@ -87,6 +249,63 @@ impl Value {
         matches!(self, Self::Null)
     }
 
+    /// Returns `true` if this is a `Not` variant.
+    pub const fn is_denial(&self) -> bool {
+        matches!(self, Self::Not(_))
+    }
+
+    /// Returns `true` if this is a `Distribution` variant.
+    pub const fn is_distribution(&self) -> bool {
+        matches!(self, Self::Distribution(_))
+    }
+
+    /// Extracts the outcomes, if this is a distribution.
+    pub fn as_distribution(&self) -> Option<&[(Self, f32)]> {
+        match self {
+            Self::Distribution(outcomes) => Some(outcomes),
+            _ => None,
+        }
+    }
+
+    /// Returns the most probable outcome of a distribution, or the value itself otherwise.
+    ///
+    /// Ties go to the outcome listed first.
+    #[must_use]
+    pub fn mode(&self) -> &Self {
+        match self {
+            Self::Distribution(outcomes) => outcomes
+                .iter()
+                .fold(None::<&(Self, f32)>, |best, outcome| match best {
+                    Some(b) if b.1 >= outcome.1 => Some(b),
+                    _ => Some(outcome),
+                })
+                .map_or(self, |(value, _)| value),
+            _ => self,
+        }
+    }
+
+    /// Extracts the denied value, if this is a denial.
+    pub fn as_denied(&self) -> Option<&Self> {
+        match self {
+            Self::Not(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if a belief holding `self` contradicts one holding `other`.
+    ///
+    /// Two affirmed values contradict when they differ, and a denial contradicts only an
+    /// affirmation of the value it denies. Denials never contradict each other: "not red"
+    /// and "not blue" can both hold. Distributions are compared by their [`Value::mode`].
+    #[must_use]
+    pub fn contradicts(&self, other: &Self) -> bool {
+        match (self.mode(), other.mode()) {
+            (Self::Not(_), Self::Not(_)) => false,
+            (Self::Not(denied), affirmed) | (affirmed, Self::Not(denied)) => **denied == *affirmed,
+            (a, b) => a != b,
+        }
+    }
+
     /// Extracts the boolean value, if present.
     pub const fn as_bool(&self) -> Option<bool> {
         match self {
@@ -103,6 +322,19 @@ impl Value {
         }
     }
 
+    /// Extracts an integer, accepting floats that hold an exact integer.
+    ///
+    /// `Float(3.0)` yields `Some(3)`; `Float(3.5)`, non-finite floats and floats outside
+    /// the `i64` range yield `None`.
+    #[must_use]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(v) => Some(*v),
+            Self::Float(v) => float_to_i64(*v),
+            _ => None,
+        }
+    }
+
     /// Extracts the float value, coercing integers if needed.
     pub const fn as_float(&self) -> Option<f64> {
         match self {
@@ -144,6 +376,85 @@ impl Value {
         }
     }
 
+    /// Extracts a float like [`Value::as_float`], additionally parsing numeric strings.
+    ///
+    /// Surrounding whitespace is ignored. Strings that parse to a non-finite number
+    /// (`"NaN"`, `"inf"`) are rejected.
+    #[must_use]
+    pub fn coerce_float(&self) -> Option<f64> {
+        match self {
+            Self::String(s) => parse_finite_f64(s),
+            _ => self.as_float(),
+        }
+    }
+
+    /// Returns the type of this value.
+    #[must_use]
+    pub const fn value_type(&self) -> ValueType {
+        match self {
+            Self::Bool(_) => ValueType::Bool,
+            Self::Int(_) => ValueType::Int,
+            Self::Float(_) => ValueType::Float,
+            Self::String(_) => ValueType::String,
+            Self::Entity(_) => ValueType::Entity,
+            Self::Embedding(_) => ValueType::Embedding,
+            Self::Structured(_) => ValueType::Structured,
+            Self::Null => ValueType::Null,
+            Self::Not(_) => ValueType::Not,
+            Self::Distribution(_) => ValueType::Distribution,
+        }
+    }
+
+    /// Converts this value to `target`, coercing where the conversion is lossless.
+    ///
+    /// Beyond the identity conversion, the supported coercions are:
+    /// - `Int`: integral floats and strings holding an integer (`"42"`, `"42.0"`)
+    /// - `Float`: integers and numeric strings, as in [`Value::coerce_float`]
+    /// - `Bool`: the strings `"true"` and `"false"` (case-insensitive)
+    /// - `String`: booleans and numbers via their display form, entity references as their UUID
+    /// - `Entity`: strings holding a UUID
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::ValueCoercionFailed` if no coercion applies.
+    pub fn try_into_typed(self, target: ValueType) -> Result<Self, ValidationError> {
+        if self.value_type() == target {
+            return Ok(self);
+        }
+        let coerced = match (&self, target) {
+            (Self::String(s), ValueType::Int) => s
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .or_else(|| parse_finite_f64(s).and_then(float_to_i64))
+                .map(Self::Int),
+            (_, ValueType::Int) => self.as_i64().map(Self::Int),
+            (_, ValueType::Float) => self.coerce_float().map(Self::Float),
+            (Self::String(s), ValueType::Bool) => {
+                let s = s.trim();
+                if s.eq_ignore_ascii_case("true") {
+                    Some(Self::Bool(true))
+                } else if s.eq_ignore_ascii_case("false") {
+                    Some(Self::Bool(false))
+                } else {
+                    None
+                }
+            }
+            (Self::Bool(_) | Self::Int(_) | Self::Float(_), ValueType::String) => {
+                Some(Self::String(self.to_string()))
+            }
+            (Self::Entity(id), ValueType::String) => Some(Self::String(id.as_uuid().to_string())),
+            (Self::String(s), ValueType::Entity) => {
+                s.trim().parse::<uuid::Uuid>().ok().map(|u| Self::Entity(EntityId::from(u)))
+            }
+            _ => None,
+        };
+        coerced.ok_or_else(|| ValidationError::ValueCoercionFailed {
+            expected: target.name().to_string(),
+            found: self.type_name().to_string(),
+        })
+    }
+
     /// Returns a human-readable type name.
     #[must_use]
     pub const fn type_name(&self) -> &'static str {
@@ -156,13 +467,140 @@ impl Value {
             Self::Embedding(_) => "embedding",
             Self::Structured(_) => "structured",
             Self::Null => "null",
+            Self::Not(_) => "not",
+            Self::Distribution(_) => "distribution",
+        }
+    }
+
+    /// Returns a canonical byte encoding of this value.
+    ///
+    /// Values that compare equal encode identically, with two deliberate normalizations:
+    /// `-0.0` encodes as `0.0`, and every NaN encodes as one canonical NaN. Structured
+    /// values are encoded with object keys sorted. `Int(1)` and `Float(1.0)` stay distinct.
+    #[must_use]
+    pub fn stable_encoding(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32);
+        match self {
+            Self::Bool(v) => {
+                out.push(0);
+                out.push(u8::from(*v));
+            }
+            Self::Int(v) => {
+                out.push(1);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            Self::Float(v) => {
+                out.push(2);
+                out.extend_from_slice(&canonical_f64_bits(*v).to_le_bytes());
+            }
+            Self::String(v) => {
+                out.push(3);
+                push_len_prefixed(&mut out, v.as_bytes());
+            }
+            Self::Entity(v) => {
+                out.push(4);
+                out.extend_from_slice(v.as_uuid().as_bytes());
+            }
+            Self::Embedding(v) => {
+                out.push(5);
+                out.extend_from_slice(&(v.len() as u64).to_le_bytes());
+                for x in v {
+                    out.extend_from_slice(&canonical_f64_bits(f64::from(*x)).to_le_bytes());
+                }
+            }
+            Self::Structured(v) => {
+                out.push(6);
+                push_json(&mut out, v);
+            }
+            Self::Null => out.push(7),
+            Self::Not(v) => {
+                out.push(8);
+                out.extend_from_slice(&v.stable_encoding());
+            }
+            Self::Distribution(outcomes) => {
+                out.push(9);
+                out.extend_from_slice(&(outcomes.len() as u64).to_le_bytes());
+                for (value, weight) in outcomes {
+                    push_len_prefixed(&mut out, &value.stable_encoding());
+                    out.extend_from_slice(&canonical_f64_bits(f64::from(*weight)).to_le_bytes());
+                }
+            }
         }
+        out
+    }
+
+    /// Returns a hash of [`Value::stable_encoding`] that is stable across processes and
+    /// versions, suitable for persisted or shared indexes.
+    #[must_use]
+    pub fn stable_hash(&self) -> u64 {
+        let digest = blake3::hash(&self.stable_encoding());
+        let mut bytes = [0_u8; 8];
+        bytes.copy_from_slice(&digest.as_bytes()[..8]);
+        u64::from_le_bytes(bytes)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn float_to_i64(v: f64) -> Option<i64> {
+    // `i64::MAX as f64` rounds up to 2^63, which is itself out of range.
+    if v.is_finite() && v.fract() == 0.0 && v >= i64::MIN as f64 && v < i64::MAX as f64 {
+        Some(v as i64)
+    } else {
+        None
+    }
+}
+
+fn parse_finite_f64(s: &str) -> Option<f64> {
+    s.trim().parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+fn canonical_f64_bits(v: f64) -> u64 {
+    if v.is_nan() {
+        f64::NAN.to_bits()
+    } else if v == 0.0 {
+        0.0_f64.to_bits()
+    } else {
+        v.to_bits()
     }
 }
 
-impl Default for Value {
-    fn default() -> Self {
-        Self::Null
+fn push_len_prefixed(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn push_json(out: &mut Vec<u8>, v: &serde_json::Value) {
+    match v {
+        serde_json::Value::Null => out.push(0),
+        serde_json::Value::Bool(b) => {
+            out.push(1);
+            out.push(u8::from(*b));
+        }
+        serde_json::Value::Number(n) => {
+            out.push(2);
+            push_len_prefixed(out, n.to_string().as_bytes());
+        }
+        serde_json::Value::String(s) => {
+            out.push(3);
+            push_len_prefixed(out, s.as_bytes());
+        }
+        serde_json::Value::Array(items) => {
+            out.push(4);
+            out.extend_from_slice(&(items.len() as u64).to_le_bytes());
+            for item in items {
+                push_json(out, item);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            out.push(5);
+            out.extend_from_slice(&(map.len() as u64).to_le_bytes());
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, value) in entries {
+                push_len_prefixed(out, key.as_bytes());
+                push_json(out, value);
+            }
+        }
     }
 }
 
@@ -177,6 +615,17 @@ impl std::fmt::Display for Value {
             Self::Embedding(v) => write!(f, "embedding[{}]", v.len()),
             Self::Structured(v) => write!(f, "{v}"),
             Self::Null => write!(f, "null"),
+            Self::Not(v) => write!(f, "not {v}"),
+            Self::Distribution(outcomes) => {
+                f.write_str("{")?;
+                for (i, (value, weight)) in outcomes.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}: {weight}")?;
+                }
+                f.write_str("}")
+            }
         }
     }
 }
@@ -265,9 +714,9 @@ mod tests {
 
     #[test]
     fn test_value_float() {
-        let val = Value::Float(3.14);
+        let val = Value::Float(2.5);
         assert!(val.is_float());
-        assert!((val.as_float().unwrap() - 3.14).abs() < f64::EPSILON);
+        assert!((val.as_float().unwrap() - 2.5).abs() < f64::EPSILON);
         assert_eq!(val.type_name(), "float");
     }
 
@@ -330,14 +779,36 @@ mod tests {
         let _: Value = true.into();
         let _: Value = 42i32.into();
         let _: Value = 42i64.into();
-        let _: Value = 3.14f32.into();
-        let _: Value = 3.14f64.into();
+        let _: Value = 2.5f32.into();
+        let _: Value = 2.5f64.into();
         let _: Value = "hello".into();
         let _: Value = String::from("hello").into();
         let _: Value = EntityId::new().into();
         let _: Value = vec![0.1f32, 0.2, 0.3].into();
     }
 
+    #[test]
+    fn test_value_denial() {
+        let red = Value::from("red");
+        let not_red = Value::not("red");
+        assert!(not_red.is_denial());
+        assert_eq!(not_red.as_denied(), Some(&red));
+        assert_eq!(not_red.type_name(), "not");
+        assert_eq!(Value::not(not_red.clone()), red);
+        assert_eq!(format!("{not_red}"), "not \"red\"");
+        assert_ne!(not_red.stable_hash(), red.stable_hash());
+
+        assert!(not_red.contradicts(&red));
+        assert!(red.contradicts(&not_red));
+        assert!(red.contradicts(&Value::from("blue")));
+        assert!(!red.contradicts(&Value::not("blue")));
+        assert!(!not_red.contradicts(&Value::not("blue")));
+        assert!(!red.contradicts(&red));
+
+        let json = serde_json::to_string(&not_red).unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), not_red);
+    }
+
     #[test]
     fn test_value_serialization() {
         let val = Value::String("test".into());
@@ -353,4 +824,97 @@ mod tests {
         assert!(val.as_float().is_none());
         assert!(val.as_string().is_none());
     }
+
+    #[test]
+    fn test_value_stable_hash_normalizes_floats() {
+        assert_eq!(Value::Float(0.0).stable_hash(), Value::Float(-0.0).stable_hash());
+        assert_eq!(Value::Float(f64::NAN).stable_hash(), Value::Float(-f64::NAN).stable_hash());
+        assert_ne!(Value::Float(1.0).stable_hash(), Value::Int(1).stable_hash());
+        assert_ne!(Value::from("a").stable_hash(), Value::from("b").stable_hash());
+
+        let a = Value::Structured(serde_json::json!({"x": 1, "y": [true, null]}));
+        let b = Value::Structured(serde_json::json!({"y": [true, null], "x": 1}));
+        assert_eq!(a.stable_encoding(), b.stable_encoding());
+    }
+
+    #[test]
+    fn test_value_coercion_helpers() {
+        assert_eq!(Value::Float(3.0).as_i64(), Some(3));
+        assert_eq!(Value::Float(3.5).as_i64(), None);
+        assert_eq!(Value::Float(f64::INFINITY).as_i64(), None);
+        assert_eq!(Value::Float(1e19).as_i64(), None);
+        assert_eq!(Value::String("3".to_string()).as_i64(), None);
+
+        assert_eq!(Value::String(" 42.5 ".to_string()).coerce_float(), Some(42.5));
+        assert_eq!(Value::Int(7).coerce_float(), Some(7.0));
+        assert_eq!(Value::String("NaN".to_string()).coerce_float(), None);
+        assert_eq!(Value::String("abc".to_string()).coerce_float(), None);
+        assert_eq!(Value::Bool(true).coerce_float(), None);
+    }
+
+    #[test]
+    fn test_try_into_typed_success() {
+        let s = |v: &str| Value::String(v.to_string());
+        assert_eq!(s("42").try_into_typed(ValueType::Int).unwrap(), Value::Int(42));
+        assert_eq!(s("42.0").try_into_typed(ValueType::Int).unwrap(), Value::Int(42));
+        assert_eq!(Value::Float(2.0).try_into_typed(ValueType::Int).unwrap(), Value::Int(2));
+        assert_eq!(s("-1.5").try_into_typed(ValueType::Float).unwrap(), Value::Float(-1.5));
+        assert_eq!(s("TRUE").try_into_typed(ValueType::Bool).unwrap(), Value::Bool(true));
+        assert_eq!(Value::Int(5).try_into_typed(ValueType::String).unwrap(), s("5"));
+        assert_eq!(s("x").try_into_typed(ValueType::String).unwrap(), s("x"));
+
+        let id = EntityId::new();
+        let as_string = Value::Entity(id).try_into_typed(ValueType::String).unwrap();
+        assert_eq!(as_string.try_into_typed(ValueType::Entity).unwrap(), Value::Entity(id));
+    }
+
+    #[test]
+    fn test_try_into_typed_failure() {
+        let s = |v: &str| Value::String(v.to_string());
+        let cases = [
+            (s("4.5"), ValueType::Int),
+            (s("forty"), ValueType::Float),
+            (s("yes"), ValueType::Bool),
+            (s("not-a-uuid"), ValueType::Entity),
+            (Value::Bool(true), ValueType::Int),
+            (Value::Null, ValueType::Float),
+            (Value::Embedding(vec![1.0]), ValueType::String),
+        ];
+        for (value, target) in cases {
+            let found = value.type_name();
+            let err = value.try_into_typed(target).unwrap_err();
+            let ValidationError::ValueCoercionFailed { expected, found: actual } = err else {
+                panic!("expected ValueCoercionFailed, got {err:?}");
+            };
+            assert_eq!(expected, target.name());
+            assert_eq!(actual, found);
+        }
+    }
+
+    #[test]
+    fn test_value_distribution() {
+        let weather = Value::distribution(vec![(Value::from("rain"), 0.3), (Value::from("sun"), 0.7)]).unwrap();
+        assert!(weather.is_distribution());
+        assert_eq!(weather.mode(), &Value::from("sun"));
+        assert_eq!(Value::from("sun").mode(), &Value::from("sun"));
+        assert_eq!(format!("{weather}"), "{\"rain\": 0.3, \"sun\": 0.7}");
+
+        // Compared by mode: agreeing on the most likely outcome is not a contradiction.
+        let other = Value::distribution(vec![(Value::from("sun"), 0.55), (Value::from("snow"), 0.45)]).unwrap();
+        assert!(!weather.contradicts(&other));
+        assert!(!weather.contradicts(&Value::from("sun")));
+        assert!(weather.contradicts(&Value::from("rain")));
+
+        for outcomes in [
+            vec![],
+            vec![(Value::from("rain"), 0.5)],
+            vec![(Value::from("rain"), 0.5), (Value::from("rain"), 0.5)],
+            vec![(Value::from("rain"), 1.5), (Value::from("sun"), -0.5)],
+            vec![(Value::from("rain"), f32::NAN)],
+            vec![(Value::not("rain"), 1.0)],
+        ] {
+            assert!(Value::distribution(outcomes).is_err());
+        }
+        assert!(Value::not(weather).validate().is_err());
+    }
 }
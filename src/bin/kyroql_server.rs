@@ -2,6 +2,7 @@
 //!
 //! A standalone server binary for running KyroQL over gRPC.
 
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -12,11 +13,11 @@ use tonic::transport::Server;
 use kyroql::engine::KyroEngine;
 use kyroql::storage::open_database;
 use kyroql::storage::PersistentStores;
-use kyroql::transport::KyroServiceImpl;
+use kyroql::transport::{AccessAuthorizer, ApiKeys, KyroServiceImpl, Principal};
 use kyroql::{
-    Belief, BeliefId, BeliefStore, Conflict, ConflictId, ConflictStore, DerivationId,
-    DerivationRecord, DerivationStore, Entity, EntityId, EntityStore, Pattern, PatternId,
-    PatternStore, StorageError, TimeRange,
+    AmendFields, Belief, BeliefId, BeliefStore, Conflict, ConflictId, ConflictStore, DerivationId,
+    DerivationRecord, DerivationStore, Entity, EntityId, EntityStore, EntityType, IdempotencyReservation,
+    IdempotencyStore, Pattern, PatternId, PatternStore, StorageError, StorageStats, TimeRange, ValidationLimits, Value,
 };
 use chrono::{DateTime, Utc};
 
@@ -26,6 +27,10 @@ struct Config {
     addr: SocketAddr,
     /// Data directory for persistent storage
     data_dir: PathBuf,
+    /// API key file; when set, every call must authenticate
+    api_keys: Option<PathBuf>,
+    /// Largest embedding a request may carry
+    max_embedding_dim: Option<usize>,
 }
 
 impl Default for Config {
@@ -33,6 +38,8 @@ impl Default for Config {
         Self {
             addr: "127.0.0.1:50051".parse().unwrap(),
             data_dir: PathBuf::from("./brain.kyro"),
+            api_keys: None,
+            max_embedding_dim: None,
         }
     }
 }
@@ -66,6 +73,28 @@ fn parse_args() -> Config {
                     std::process::exit(1);
                 }
             }
+            "--api-keys" => {
+                if i + 1 < args.len() {
+                    config.api_keys = Some(PathBuf::from(&args[i + 1]));
+                    i += 2;
+                } else {
+                    eprintln!("error: --api-keys requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--max-embedding-dim" => {
+                if i + 1 < args.len() {
+                    let dim: usize = args[i + 1].parse().ok().filter(|&d| d > 0).unwrap_or_else(|| {
+                        eprintln!("error: invalid embedding dimension: {}", args[i + 1]);
+                        std::process::exit(1);
+                    });
+                    config.max_embedding_dim = Some(dim);
+                    i += 2;
+                } else {
+                    eprintln!("error: --max-embedding-dim requires a value");
+                    std::process::exit(1);
+                }
+            }
             "--help" | "-h" => {
                 println!("kyroql-server - KyroQL gRPC Server");
                 println!();
@@ -75,6 +104,9 @@ fn parse_args() -> Config {
                 println!("OPTIONS:");
                 println!("    -p, --port <PORT>         Port to listen on [default: 50051]");
                 println!("    -d, --data-dir <DIR>      Data directory [default: ./brain.kyro]");
+                println!("        --api-keys <FILE>     Require API keys, one `<key> <principal> [read-only]` per line");
+                println!("        --max-embedding-dim <N>");
+                println!("                              Reject requests carrying longer embeddings [default: 8192]");
                 println!("    -h, --help                Print help information");
                 std::process::exit(0);
             }
@@ -88,6 +120,25 @@ fn parse_args() -> Config {
     config
 }
 
+/// Parse an API key file: one `<key> <principal> [read-only]` per line, `#` starts a comment.
+fn load_api_keys(path: &std::path::Path) -> Result<ApiKeys, Box<dyn std::error::Error>> {
+    let mut keys = ApiKeys::new();
+    for (n, line) in std::fs::read_to_string(path)?.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let principal = match fields.as_slice() {
+            [_, id] => Principal::new(*id),
+            [_, id, "read-only"] => Principal::read_only(*id),
+            _ => return Err(format!("{}:{}: expected `<key> <principal> [read-only]`", path.display(), n + 1).into()),
+        };
+        keys = keys.with_key(fields[0], principal);
+    }
+    Ok(keys)
+}
+
 struct EntityStoreProxy {
     stores: Arc<PersistentStores>,
 }
@@ -97,6 +148,14 @@ impl EntityStore for EntityStoreProxy {
         self.stores.entities.insert(entity)
     }
 
+    fn insert_many(
+        &self,
+        entities: Vec<Entity>,
+        dedup_by_name: bool,
+    ) -> Result<HashMap<EntityId, EntityId>, StorageError> {
+        self.stores.entities.insert_many(entities, dedup_by_name)
+    }
+
     fn get(&self, id: EntityId) -> Result<Option<Entity>, StorageError> {
         self.stores.entities.get(id)
     }
@@ -109,26 +168,48 @@ impl EntityStore for EntityStoreProxy {
         self.stores.entities.delete(id)
     }
 
-    fn find_by_name(&self, name: &str) -> Result<Vec<Entity>, StorageError> {
-        self.stores.entities.find_by_name(name)
+    fn find_by_name(&self, namespace: Option<&str>, name: &str) -> Result<Vec<Entity>, StorageError> {
+        self.stores.entities.find_by_name(namespace, name)
+    }
+
+    fn find_by_type(&self, entity_type: &EntityType, include_subtypes: bool) -> Result<Vec<Entity>, StorageError> {
+        self.stores.entities.find_by_type(entity_type, include_subtypes)
     }
 
-    fn find_by_name_fuzzy(&self, query: &str, limit: usize) -> Result<Vec<Entity>, StorageError> {
-        self.stores.entities.find_by_name_fuzzy(query, limit)
+    fn list(&self) -> Result<Vec<Entity>, StorageError> {
+        self.stores.entities.list()
+    }
+
+    fn find_by_name_fuzzy(
+        &self,
+        namespace: Option<&str>,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<Entity>, StorageError> {
+        self.stores.entities.find_by_name_fuzzy(namespace, query, limit)
     }
 
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
     ) -> Result<Vec<(Entity, f32)>, StorageError> {
-        self.stores.entities.find_by_embedding(embedding, limit)
+        self.stores.entities.find_by_embedding(namespace, embedding, limit)
     }
 
     fn merge(&self, primary: EntityId, secondary: EntityId) -> Result<Entity, StorageError> {
         self.stores.entities.merge(primary, secondary)
     }
 
+    fn unmerge(&self, primary: EntityId, secondary: EntityId) -> Result<(Entity, Entity), StorageError> {
+        self.stores.entities.unmerge(primary, secondary)
+    }
+
+    fn merged_ids(&self, id: EntityId) -> Result<Vec<EntityId>, StorageError> {
+        self.stores.entities.merged_ids(id)
+    }
+
     fn get_at_version(&self, id: EntityId, version: u64) -> Result<Option<Entity>, StorageError> {
         self.stores.entities.get_at_version(id, version)
     }
@@ -136,6 +217,10 @@ impl EntityStore for EntityStoreProxy {
     fn list_versions(&self, id: EntityId) -> Result<Vec<Entity>, StorageError> {
         self.stores.entities.list_versions(id)
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.stores.entities.stats()
+    }
 }
 
 struct BeliefStoreProxy {
@@ -151,8 +236,28 @@ impl BeliefStore for BeliefStoreProxy {
         self.stores.beliefs.get(id)
     }
 
-    fn supersede(&self, old_id: BeliefId, new_id: BeliefId) -> Result<(), StorageError> {
-        self.stores.beliefs.supersede(old_id, new_id)
+    fn supersede_all(&self, old_ids: &[BeliefId], new_id: BeliefId) -> Result<(), StorageError> {
+        self.stores.beliefs.supersede_all(old_ids, new_id)
+    }
+
+    fn insert_superseding(&self, belief: Belief, old_ids: &[BeliefId]) -> Result<(), StorageError> {
+        self.stores.beliefs.insert_superseding(belief, old_ids)
+    }
+
+    fn amend(&self, id: BeliefId, fields: AmendFields) -> Result<(), StorageError> {
+        self.stores.beliefs.amend(id, fields)
+    }
+
+    fn find_superseded(&self, before: DateTime<Utc>) -> Result<Vec<Belief>, StorageError> {
+        self.stores.beliefs.find_superseded(before)
+    }
+
+    fn delete(&self, id: BeliefId) -> Result<(), StorageError> {
+        self.stores.beliefs.delete(id)
+    }
+
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Belief>, StorageError> {
+        self.stores.beliefs.find_by_entity(entity_id)
     }
 
     fn find_by_entity_predicate(
@@ -165,6 +270,23 @@ impl BeliefStore for BeliefStoreProxy {
             .find_by_entity_predicate(entity_id, predicate)
     }
 
+    fn history(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError> {
+        self.stores.beliefs.history(entity_id, predicate)
+    }
+
+    fn coalesce(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+        pinned: &HashSet<BeliefId>,
+    ) -> Result<usize, StorageError> {
+        self.stores.beliefs.coalesce(entity_id, predicate, pinned)
+    }
+
+    fn reembed(&self, embed: &dyn Fn(&Belief) -> Result<Vec<f32>, StorageError>) -> Result<usize, StorageError> {
+        self.stores.beliefs.reembed(embed)
+    }
+
     fn find_as_of(
         &self,
         entity_id: EntityId,
@@ -174,24 +296,49 @@ impl BeliefStore for BeliefStoreProxy {
         self.stores.beliefs.find_as_of(entity_id, predicate, as_of)
     }
 
-    fn find_by_time_range(&self, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
-        self.stores.beliefs.find_by_time_range(range)
+    fn find_by_time_range(
+        &self,
+        namespace: Option<&str>,
+        range: &TimeRange,
+    ) -> Result<Vec<Belief>, StorageError> {
+        self.stores.beliefs.find_by_time_range(namespace, range)
     }
 
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
         min_confidence: Option<f32>,
     ) -> Result<Vec<(Belief, f32)>, StorageError> {
         self.stores
             .beliefs
-            .find_by_embedding(embedding, limit, min_confidence)
+            .find_by_embedding(namespace, embedding, limit, min_confidence)
+    }
+
+    fn find_entities_by_predicate_value(
+        &self,
+        namespace: Option<&str>,
+        predicate: &str,
+        value: &Value,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<EntityId>, StorageError> {
+        self.stores
+            .beliefs
+            .find_entities_by_predicate_value(namespace, predicate, value, as_of)
     }
 
     fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError> {
         self.stores.beliefs.count_by_entity(entity_id)
     }
+
+    fn subjects(&self) -> Result<Vec<EntityId>, StorageError> {
+        self.stores.beliefs.subjects()
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.stores.beliefs.stats()
+    }
 }
 
 struct PatternStoreProxy {
@@ -222,6 +369,10 @@ impl PatternStore for PatternStoreProxy {
     fn find_active(&self) -> Result<Vec<Pattern>, StorageError> {
         self.stores.patterns.find_active()
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.stores.patterns.stats()
+    }
 }
 
 struct ConflictStoreProxy {
@@ -241,6 +392,10 @@ impl ConflictStore for ConflictStoreProxy {
         self.stores.conflicts.update(conflict)
     }
 
+    fn update_if_open(&self, conflict: Conflict) -> Result<bool, StorageError> {
+        self.stores.conflicts.update_if_open(conflict)
+    }
+
     fn find_by_belief(&self, belief_id: BeliefId) -> Result<Vec<Conflict>, StorageError> {
         self.stores.conflicts.find_by_belief(belief_id)
     }
@@ -248,6 +403,40 @@ impl ConflictStore for ConflictStoreProxy {
     fn find_open(&self) -> Result<Vec<Conflict>, StorageError> {
         self.stores.conflicts.find_open()
     }
+
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Conflict>, StorageError> {
+        self.stores.conflicts.find_by_entity(entity_id)
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.stores.conflicts.stats()
+    }
+}
+
+struct IdempotencyStoreProxy {
+    stores: Arc<PersistentStores>,
+}
+
+impl IdempotencyStore for IdempotencyStoreProxy {
+    fn get(&self, namespace: Option<&str>, key: &str) -> Result<Option<BeliefId>, StorageError> {
+        self.stores.idempotency.get(namespace, key)
+    }
+
+    fn reserve(&self, namespace: Option<&str>, key: &str) -> Result<IdempotencyReservation, StorageError> {
+        self.stores.idempotency.reserve(namespace, key)
+    }
+
+    fn release(&self, namespace: Option<&str>, key: &str) -> Result<(), StorageError> {
+        self.stores.idempotency.release(namespace, key)
+    }
+
+    fn record(&self, namespace: Option<&str>, key: &str, belief_id: BeliefId) -> Result<(), StorageError> {
+        self.stores.idempotency.record(namespace, key, belief_id)
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.stores.idempotency.stats()
+    }
 }
 
 struct DerivationStoreProxy {
@@ -263,6 +452,10 @@ impl DerivationStore for DerivationStoreProxy {
         self.stores.derivations.get(id)
     }
 
+    fn update(&self, record: DerivationRecord) -> Result<(), StorageError> {
+        self.stores.derivations.update(record)
+    }
+
     fn find_by_premise(&self, premise_id: BeliefId) -> Result<Vec<DerivationRecord>, StorageError> {
         self.stores.derivations.find_by_premise(premise_id)
     }
@@ -273,6 +466,10 @@ impl DerivationStore for DerivationStoreProxy {
     ) -> Result<Vec<DerivationRecord>, StorageError> {
         self.stores.derivations.find_by_derived_belief(derived_belief_id)
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.stores.derivations.stats()
+    }
 }
 
 #[tokio::main]
@@ -301,22 +498,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         stores: Arc::clone(&stores),
     });
 
-    let engine = Arc::new(KyroEngine::new(
-        entities,
-        beliefs,
-        patterns,
-        conflicts,
-        derivations,
-    ));
+    let idempotency: Arc<dyn IdempotencyStore> = Arc::new(IdempotencyStoreProxy {
+        stores: Arc::clone(&stores),
+    });
 
-    let svc = KyroServiceImpl::new(engine).into_server();
+    let mut limits = ValidationLimits::default();
+    if let Some(dim) = config.max_embedding_dim {
+        limits = limits.with_max_embedding_dim(dim);
+    }
+    let engine = Arc::new(
+        KyroEngine::new(entities, beliefs, patterns, conflicts, derivations)
+            .with_idempotency_store(idempotency)
+            .with_validation_limits(limits),
+    );
+
+    let svc = KyroServiceImpl::new(engine);
+    let (open_svc, authed_svc) = match &config.api_keys {
+        Some(path) => {
+            let keys = load_api_keys(path)?;
+            println!("Requiring API keys from: {}", path.display());
+            (None, Some(svc.with_authorizer(Arc::new(AccessAuthorizer)).into_server_with_auth(keys)))
+        }
+        None => (Some(svc.into_server()), None),
+    };
 
     println!("Database opened successfully");
     println!("Starting gRPC server on {}", config.addr);
     println!("Press Ctrl+C to stop");
 
     Server::builder()
-        .add_service(svc)
+        .add_optional_service(open_svc)
+        .add_optional_service(authed_svc)
         .serve_with_shutdown(config.addr, async {
             let _ = signal::ctrl_c().await;
         })
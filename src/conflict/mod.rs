@@ -8,4 +8,5 @@ pub mod types;
 
 pub use types::{
     Conflict, ConflictId, ConflictResolution, ConflictStatus, ConflictType,
+    ResolutionAudit,
 };
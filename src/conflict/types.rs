@@ -12,6 +12,12 @@ use uuid::Uuid;
 
 use crate::confidence::BeliefId;
 use crate::entity::EntityId;
+use crate::inference::ConflictResolutionPolicy;
+
+/// Namespace for [`ConflictId::deterministic`] UUIDs.
+const CONFLICT_ID_NAMESPACE: Uuid = Uuid::from_bytes([
+    0x9a, 0x4d, 0x12, 0xe7, 0x3b, 0x60, 0x4c, 0x25, 0xb1, 0x8f, 0x57, 0xc2, 0x0e, 0x93, 0x6d, 0xa4,
+]);
 
 /// Unique identifier for a conflict.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -22,7 +28,24 @@ impl ConflictId {
     /// Creates a new random conflict ID.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(crate::id::next_id())
+    }
+
+    /// Returns the ID a conflict of `conflict_type` between `belief_ids` always gets.
+    ///
+    /// The belief IDs are sorted and deduplicated first, so their order does not matter.
+    #[must_use]
+    pub fn deterministic(conflict_type: &ConflictType, belief_ids: &[BeliefId]) -> Self {
+        let mut ids: Vec<&Uuid> = belief_ids.iter().map(BeliefId::as_uuid).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        // Conflict types only hold strings and integers, which always serialize.
+        let mut encoding = serde_json::to_vec(conflict_type).unwrap_or_default();
+        for id in ids {
+            encoding.extend_from_slice(id.as_bytes());
+        }
+        let digest = blake3::hash(&encoding);
+        Self(Uuid::new_v5(&CONFLICT_ID_NAMESPACE, digest.as_bytes()))
     }
 }
 
@@ -109,10 +132,11 @@ impl fmt::Display for ConflictType {
 }
 
 /// The status of a conflict.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ConflictStatus {
     /// Conflict is open and unresolved.
+    #[default]
     Open,
 
     /// Conflict is being analyzed.
@@ -125,12 +149,6 @@ pub enum ConflictStatus {
     Dismissed,
 }
 
-impl Default for ConflictStatus {
-    fn default() -> Self {
-        Self::Open
-    }
-}
-
 impl fmt::Display for ConflictStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -208,6 +226,41 @@ impl fmt::Display for ConflictResolution {
     }
 }
 
+/// Record of one resolution decision: which policy ran, who asked, and which belief won.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolutionAudit {
+    /// The policy that chose the winner.
+    pub policy: ConflictResolutionPolicy,
+    /// The belief the policy selected.
+    pub winner: BeliefId,
+    /// The other beliefs in the conflict.
+    pub losers: Vec<BeliefId>,
+    /// When the decision was made.
+    pub resolved_at: DateTime<Utc>,
+    /// Who requested the resolution, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal: Option<String>,
+}
+
+impl ResolutionAudit {
+    /// Audit `winner` prevailing over the rest of `belief_ids` under `policy`, as of now.
+    #[must_use]
+    pub fn new(
+        policy: ConflictResolutionPolicy,
+        winner: BeliefId,
+        belief_ids: &[BeliefId],
+        principal: Option<String>,
+    ) -> Self {
+        Self {
+            policy,
+            winner,
+            losers: belief_ids.iter().copied().filter(|id| *id != winner).collect(),
+            resolved_at: Utc::now(),
+            principal,
+        }
+    }
+}
+
 /// A conflict between beliefs.
 ///
 /// Conflicts are first-class objects in KyroQL. When beliefs contradict,
@@ -242,11 +295,18 @@ pub struct Conflict {
     pub resolved_at: Option<DateTime<Utc>>,
 
     /// Severity score (0.0 to 1.0, higher is more severe).
+    ///
+    /// The engine sets this to the weakest trust-weighted confidence among the involved
+    /// beliefs: a contradiction is only as serious as its least credible side.
     pub severity: f32,
 
     /// Arbitrary metadata.
     #[serde(default)]
     pub metadata: serde_json::Value,
+
+    /// Policy resolutions applied to this conflict, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audit: Vec<ResolutionAudit>,
 }
 
 impl Conflict {
@@ -268,6 +328,7 @@ impl Conflict {
             resolved_at: None,
             severity: 0.5, // Default to medium severity
             metadata: serde_json::Value::Null,
+            audit: Vec::new(),
         }
     }
 
@@ -305,6 +366,14 @@ impl Conflict {
         )
     }
 
+    /// Replaces the random ID with [`ConflictId::deterministic`] for this conflict's type
+    /// and beliefs, so detecting the same contradiction again yields the same ID.
+    #[must_use]
+    pub fn with_deterministic_id(mut self) -> Self {
+        self.id = ConflictId::deterministic(&self.conflict_type, &self.belief_ids);
+        self
+    }
+
     /// Returns true if the conflict is open.
     #[must_use]
     pub fn is_open(&self) -> bool {
@@ -331,6 +400,13 @@ impl Conflict {
         self.resolved_at = Some(Utc::now());
     }
 
+    /// Resolves the conflict and appends `audit` to its audit trail.
+    pub fn resolve_audited(&mut self, resolution: ConflictResolution, audit: ResolutionAudit) {
+        self.resolve(resolution);
+        self.resolved_at = Some(audit.resolved_at);
+        self.audit.push(audit);
+    }
+
     /// Dismisses the conflict.
     pub fn dismiss(&mut self) {
         self.status = ConflictStatus::Dismissed;
@@ -348,6 +424,28 @@ impl Conflict {
     pub fn involves_belief(&self, belief_id: BeliefId) -> bool {
         self.belief_ids.contains(&belief_id)
     }
+
+    /// Severity of a conflict between beliefs with the given trust-weighted confidences.
+    ///
+    /// Takes the minimum, so two confident sources disagreeing outrank a confident source
+    /// disagreeing with a weak one. Returns the default medium severity for no inputs.
+    #[must_use]
+    pub fn severity_from(weighted_confidences: impl IntoIterator<Item = f32>) -> f32 {
+        weighted_confidences
+            .into_iter()
+            .map(|c| c.clamp(0.0, 1.0))
+            .reduce(f32::min)
+            .unwrap_or(0.5)
+    }
+
+    /// Order conflicts most severe first; ties go to the earlier detection.
+    pub fn sort_by_severity(conflicts: &mut [Conflict]) {
+        conflicts.sort_by(|a, b| {
+            b.severity
+                .total_cmp(&a.severity)
+                .then_with(|| a.detected_at.cmp(&b.detected_at))
+        });
+    }
 }
 
 impl PartialEq for Conflict {
@@ -375,6 +473,21 @@ mod tests {
         assert_ne!(id1, id2);
     }
 
+    #[test]
+    fn test_deterministic_conflict_id() {
+        let (a, b) = (BeliefId::new(), BeliefId::new());
+        let entity = EntityId::new();
+        let first = Conflict::value_contradiction(vec![a, b], entity, "color").with_deterministic_id();
+        let again = Conflict::value_contradiction(vec![b, a], entity, "color").with_deterministic_id();
+        assert_eq!(first.id, again.id);
+
+        let other_predicate = Conflict::value_contradiction(vec![a, b], entity, "size").with_deterministic_id();
+        let other_beliefs =
+            Conflict::value_contradiction(vec![a, BeliefId::new()], entity, "color").with_deterministic_id();
+        assert_ne!(first.id, other_predicate.id);
+        assert_ne!(first.id, other_beliefs.id);
+    }
+
     #[test]
     fn test_conflict_creation() {
         let beliefs = vec![BeliefId::new(), BeliefId::new()];
@@ -463,6 +576,21 @@ mod tests {
         assert!((conflict.severity - 1.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_conflict_severity_ordering() {
+        let entity = EntityId::new();
+        assert!((Conflict::severity_from([0.99, 0.95]) - 0.95).abs() < 1e-6);
+        assert!((Conflict::severity_from([]) - 0.5).abs() < 1e-6);
+
+        let weak = Conflict::value_contradiction(vec![], entity, "p")
+            .with_severity(Conflict::severity_from([0.3, 0.9]));
+        let strong = Conflict::value_contradiction(vec![], entity, "p")
+            .with_severity(Conflict::severity_from([0.99, 0.98]));
+        let mut conflicts = vec![weak.clone(), strong.clone()];
+        Conflict::sort_by_severity(&mut conflicts);
+        assert_eq!(conflicts, vec![strong, weak]);
+    }
+
     #[test]
     fn test_conflict_type_display() {
         let ct = ConflictType::ValueContradiction {
@@ -2,7 +2,9 @@
 //!
 //! DERIVE is KyroQL's inference recorder: it stores explicit links between
 //! a derived belief and the premise beliefs and rules used to produce it.
-//! This enables audit trails and future re-evaluation when premises change.
+//! This enables audit trails and re-evaluation when premises change: records whose rule
+//! names a [`DerivationRule`] can have their propagated confidence recomputed from the
+//! premises' current confidences.
 
 use std::fmt;
 
@@ -21,7 +23,13 @@ impl DerivationId {
     /// Creates a new random derivation ID.
     #[must_use]
     pub fn new() -> Self {
-        Self(uuid::Uuid::new_v4())
+        Self(crate::id::next_id())
+    }
+
+    /// Creates a derivation ID from a UUID.
+    #[must_use]
+    pub const fn from_uuid(uuid: uuid::Uuid) -> Self {
+        Self(uuid)
     }
 }
 
@@ -37,6 +45,80 @@ impl fmt::Display for DerivationId {
     }
 }
 
+/// How a derivation combines its premises' confidences.
+///
+/// A [`DerivationRecord`] names its rule as free text; rules outside this set carry no
+/// propagation semantics of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DerivationRule {
+    /// The weakest premise bounds the conclusion (`"min"`).
+    Min,
+    /// The strongest premise carries the conclusion (`"max"`).
+    Max,
+    /// Independent premises that must all hold (`"product"`).
+    Product,
+    /// Independent premises any one of which suffices (`"noisy_or"`): `1 - Π(1 - c)`.
+    NoisyOr,
+}
+
+impl DerivationRule {
+    /// Look up the rule a record names, ignoring case and surrounding whitespace.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "min" => Some(Self::Min),
+            "max" => Some(Self::Max),
+            "product" => Some(Self::Product),
+            "noisy_or" => Some(Self::NoisyOr),
+            _ => None,
+        }
+    }
+
+    /// The name records use for this rule.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Product => "product",
+            Self::NoisyOr => "noisy_or",
+        }
+    }
+
+    /// Combine premise confidences into the conclusion's confidence, in `[0, 1]`.
+    ///
+    /// Returns `0.0` when there are no premises.
+    #[must_use]
+    pub fn propagate(self, confidences: impl IntoIterator<Item = f32>) -> f32 {
+        let mut confidences = confidences.into_iter().map(|c| c.clamp(0.0, 1.0)).peekable();
+        if confidences.peek().is_none() {
+            return 0.0;
+        }
+        match self {
+            Self::Min => confidences.fold(1.0, f32::min),
+            Self::Max => confidences.fold(0.0, f32::max),
+            Self::Product => confidences.product(),
+            Self::NoisyOr => 1.0 - confidences.map(|c| 1.0 - c).product::<f32>(),
+        }
+        .clamp(0.0, 1.0)
+    }
+}
+
+impl fmt::Display for DerivationRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Propagated confidence of a derivation before and after recomputing it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceRecomputation {
+    /// Confidence the record held, if any.
+    pub previous: Option<f32>,
+    /// Confidence recomputed from the premises' current confidences.
+    pub current: f32,
+}
+
 /// Immutable derivation record.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DerivationRecord {
@@ -84,6 +166,7 @@ impl DerivationRecord {
     pub const MAX_METADATA_BYTES: usize = 64 * 1024;
 
     /// Construct a new derivation record with validation.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         tx_time: DateTime<Utc>,
         derived_belief_id: Option<BeliefId>,
@@ -157,6 +240,12 @@ impl DerivationRecord {
             metadata,
         })
     }
+
+    /// The [`DerivationRule`] this record's rule names, if it names one.
+    #[must_use]
+    pub fn derivation_rule(&self) -> Option<DerivationRule> {
+        DerivationRule::parse(&self.rule)
+    }
 }
 
 #[cfg(test)]
@@ -203,5 +292,20 @@ mod tests {
         .unwrap();
         assert_eq!(ok.rule, "modus_ponens");
         assert_eq!(ok.premise_ids.len(), 1);
+        assert_eq!(ok.derivation_rule(), None);
+    }
+
+    #[test]
+    fn derivation_rules_propagate_premise_confidences() {
+        let premises = [0.9, 0.5];
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+        assert!(close(DerivationRule::Min.propagate(premises), 0.5));
+        assert!(close(DerivationRule::Max.propagate(premises), 0.9));
+        assert!(close(DerivationRule::Product.propagate(premises), 0.45));
+        assert!(close(DerivationRule::NoisyOr.propagate(premises), 0.95));
+        assert_eq!(DerivationRule::Product.propagate([]), 0.0);
+
+        assert_eq!(DerivationRule::parse(" Noisy_OR "), Some(DerivationRule::NoisyOr));
+        assert_eq!(DerivationRule::parse("modus_ponens"), None);
     }
 }
@@ -5,14 +5,117 @@
 //!
 //! It is *not* a neural embedding model. It provides a stable baseline using feature
 //! hashing over tokens, sufficient for top-k similarity search in embedded mode.
+//! Callers that need a real model can implement [`Embedder`] and inject it into the engine.
 
 use blake3::Hasher;
 
+use crate::entity::Entity;
+use crate::error::{KyroResult, ValidationError};
+use crate::ir::MAX_EMBEDDING_DIM;
+use crate::value::Value;
+
 /// Default embedding dimensionality for lexical embeddings.
 ///
 /// Keep this modest to control memory usage in embedded mode.
 pub const DEFAULT_EMBEDDING_DIM: usize = 64;
 
+/// Largest n-gram size accepted by [`EmbeddingConfig`].
+pub const MAX_NGRAM: usize = 8;
+
+/// Configuration for the lexical (feature-hashing) embedding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddingConfig {
+    /// Output dimensionality.
+    pub dim: usize,
+    /// Whether to L2-normalize the output vector.
+    pub normalize: bool,
+    /// Inclusive range of word n-gram sizes to hash, e.g. `(1, 2)` for unigrams and bigrams.
+    pub ngram_range: (usize, usize),
+}
+
+impl Default for EmbeddingConfig {
+    fn default() -> Self {
+        Self {
+            dim: DEFAULT_EMBEDDING_DIM,
+            normalize: true,
+            ngram_range: (1, 1),
+        }
+    }
+}
+
+impl EmbeddingConfig {
+    /// Validate the configuration.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.dim == 0 || self.dim > MAX_EMBEDDING_DIM {
+            return Err(ValidationError::InvalidField {
+                field: "dim".to_string(),
+                reason: format!("must be in 1..={MAX_EMBEDDING_DIM}"),
+            });
+        }
+        let (lo, hi) = self.ngram_range;
+        if lo == 0 || lo > hi || hi > MAX_NGRAM {
+            return Err(ValidationError::InvalidField {
+                field: "ngram_range".to_string(),
+                reason: format!("must satisfy 1 <= min <= max <= {MAX_NGRAM}"),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Produces embeddings for text.
+///
+/// The engine uses the configured embedder to generate belief embeddings when an ASSERT
+/// does not carry one. Implementations must always return vectors of length [`Embedder::dim`].
+pub trait Embedder: Send + Sync {
+    /// Dimensionality of every vector produced by this embedder.
+    fn dim(&self) -> usize;
+
+    /// Embed a piece of text.
+    fn embed(&self, text: &str) -> KyroResult<Vec<f32>>;
+}
+
+/// Builds the text the engine embeds for an ASSERT without an explicit embedding.
+///
+/// Called with the subject entity, the trimmed predicate and the asserted value.
+pub type EmbeddingTextFn = dyn Fn(&Entity, &str, &Value) -> String + Send + Sync;
+
+/// Default embedding text: `"{canonical_name} {predicate} {value}"`.
+#[must_use]
+pub fn default_embedding_text(entity: &Entity, predicate: &str, value: &Value) -> String {
+    format!("{} {} {}", entity.canonical_name, predicate, value)
+}
+
+/// Built-in embedder backed by [`lexical_embedding_with`].
+#[derive(Debug, Clone, Default)]
+pub struct LexicalEmbedder {
+    config: EmbeddingConfig,
+}
+
+impl LexicalEmbedder {
+    /// Create a lexical embedder with a validated configuration.
+    pub fn new(config: EmbeddingConfig) -> Result<Self, ValidationError> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+
+    /// The embedder configuration.
+    #[must_use]
+    pub fn config(&self) -> &EmbeddingConfig {
+        &self.config
+    }
+}
+
+impl Embedder for LexicalEmbedder {
+    fn dim(&self) -> usize {
+        self.config.dim
+    }
+
+    fn embed(&self, text: &str) -> KyroResult<Vec<f32>> {
+        Ok(lexical_embedding_with(text, &self.config))
+    }
+}
+
 fn tokenize(s: &str) -> impl Iterator<Item = &str> {
     s.split(|c: char| !c.is_ascii_alphanumeric())
         .filter(|t| !t.is_empty())
@@ -21,12 +124,29 @@ fn tokenize(s: &str) -> impl Iterator<Item = &str> {
 /// Create a deterministic lexical embedding for a piece of text.
 #[must_use]
 pub fn lexical_embedding(text: &str) -> Vec<f32> {
-    lexical_embedding_with_dim(text, DEFAULT_EMBEDDING_DIM)
+    lexical_embedding_with(text, &EmbeddingConfig::default())
 }
 
 /// Create a deterministic lexical embedding with a custom dimension.
 #[must_use]
 pub fn lexical_embedding_with_dim(text: &str, dim: usize) -> Vec<f32> {
+    lexical_embedding_with(
+        text,
+        &EmbeddingConfig {
+            dim,
+            ..EmbeddingConfig::default()
+        },
+    )
+}
+
+/// Create a deterministic lexical embedding using an explicit configuration.
+///
+/// Unigram output with `normalize = true` is identical to [`lexical_embedding_with_dim`].
+/// An invalid n-gram range is clamped rather than rejected; use
+/// [`EmbeddingConfig::validate`] to surface configuration errors.
+#[must_use]
+pub fn lexical_embedding_with(text: &str, config: &EmbeddingConfig) -> Vec<f32> {
+    let dim = config.dim;
     if dim == 0 {
         return Vec::new();
     }
@@ -34,30 +154,42 @@ pub fn lexical_embedding_with_dim(text: &str, dim: usize) -> Vec<f32> {
     let mut vec = vec![0.0f32; dim];
     let mut count = 0u32;
 
-    for token in tokenize(&text.to_ascii_lowercase()) {
-        let mut h = Hasher::new();
-        h.update(token.as_bytes());
-        let hash = h.finalize();
-
-        let bytes = hash.as_bytes();
-        // Deterministically map to a bucket.
-        let mut bucket = 0u64;
-        bucket |= u64::from(bytes[0]);
-        bucket |= u64::from(bytes[1]) << 8;
-        bucket |= u64::from(bytes[2]) << 16;
-        bucket |= u64::from(bytes[3]) << 24;
-        bucket |= u64::from(bytes[4]) << 32;
-        bucket |= u64::from(bytes[5]) << 40;
-        bucket |= u64::from(bytes[6]) << 48;
-        bucket |= u64::from(bytes[7]) << 56;
-
-        let idx = (bucket as usize) % dim;
-        let sign = if (bytes[8] & 1) == 0 { 1.0f32 } else { -1.0f32 };
-        vec[idx] += sign;
-        count = count.saturating_add(1);
-    }
-
-    if count == 0 {
+    let lowered = text.to_ascii_lowercase();
+    let tokens: Vec<&str> = tokenize(&lowered).collect();
+    let lo = config.ngram_range.0.clamp(1, MAX_NGRAM);
+    let hi = config.ngram_range.1.clamp(lo, MAX_NGRAM);
+
+    for n in lo..=hi {
+        for window in tokens.windows(n) {
+            let mut h = Hasher::new();
+            for (i, token) in window.iter().enumerate() {
+                if i > 0 {
+                    h.update(b" ");
+                }
+                h.update(token.as_bytes());
+            }
+            let hash = h.finalize();
+
+            let bytes = hash.as_bytes();
+            // Deterministically map to a bucket.
+            let mut bucket = 0u64;
+            bucket |= u64::from(bytes[0]);
+            bucket |= u64::from(bytes[1]) << 8;
+            bucket |= u64::from(bytes[2]) << 16;
+            bucket |= u64::from(bytes[3]) << 24;
+            bucket |= u64::from(bytes[4]) << 32;
+            bucket |= u64::from(bytes[5]) << 40;
+            bucket |= u64::from(bytes[6]) << 48;
+            bucket |= u64::from(bytes[7]) << 56;
+
+            let idx = (bucket as usize) % dim;
+            let sign = if (bytes[8] & 1) == 0 { 1.0f32 } else { -1.0f32 };
+            vec[idx] += sign;
+            count = count.saturating_add(1);
+        }
+    }
+
+    if count == 0 || !config.normalize {
         return vec;
     }
 
@@ -94,4 +226,42 @@ mod tests {
         let v = lexical_embedding_with_dim("x", 13);
         assert_eq!(v.len(), 13);
     }
+
+    #[test]
+    fn default_config_matches_legacy_embedding() {
+        let a = lexical_embedding_with("hello world", &EmbeddingConfig::default());
+        assert_eq!(a, lexical_embedding_with_dim("hello world", DEFAULT_EMBEDDING_DIM));
+    }
+
+    #[test]
+    fn bigrams_and_normalization_are_configurable() {
+        let unigrams = EmbeddingConfig {
+            dim: 32,
+            normalize: false,
+            ngram_range: (1, 1),
+        };
+        let bigrams = EmbeddingConfig {
+            ngram_range: (1, 2),
+            ..unigrams
+        };
+
+        let uni = lexical_embedding_with("alpha beta gamma", &unigrams);
+        let bi = lexical_embedding_with("alpha beta gamma", &bigrams);
+        assert_eq!(bi.len(), 32);
+        assert_ne!(uni, bi);
+
+        // Without normalization every bucket holds a signed token count.
+        assert!(bi.iter().all(|x| x.fract() == 0.0));
+        let mass: f32 = bi.iter().map(|x| x.abs()).sum();
+        assert!(mass <= 5.0);
+    }
+
+    #[test]
+    fn config_validation_rejects_bad_values() {
+        let zero = EmbeddingConfig { dim: 0, ..EmbeddingConfig::default() };
+        assert!(zero.validate().is_err());
+        let inverted = EmbeddingConfig { ngram_range: (3, 1), ..EmbeddingConfig::default() };
+        assert!(inverted.validate().is_err());
+        assert!(LexicalEmbedder::new(EmbeddingConfig::default()).is_ok());
+    }
 }
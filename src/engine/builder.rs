@@ -0,0 +1,354 @@
+//! Fluent construction of a [`KyroEngine`].
+//!
+//! [`KyroEngine::new`] takes the five stores positionally and leaves everything else at its
+//! default, to be adjusted with the engine's `with_*` methods. [`KyroEngineBuilder`]
+//! collects the stores and the optional settings by name and checks at [`build`] that every
+//! store was given.
+//!
+//! [`build`]: KyroEngineBuilder::build
+
+use std::sync::Arc;
+
+use crate::embedding::{Embedder, EmbeddingTextFn};
+use crate::entity::Entity;
+use crate::error::{KyroResult, ValidationError};
+use crate::ir::ValidationLimits;
+use crate::monitor::MonitorSystemConfig;
+use crate::storage::{BeliefStore, ConflictStore, DerivationStore, EntityStore, IdempotencyStore, PatternStore};
+use crate::trust::{CalibrationTracker, SimpleTrustModel, TrustModel};
+use crate::value::Value;
+
+use super::{KyroEngine, Metrics, OperationLog, RateLimiter};
+
+/// Builder for a [`KyroEngine`].
+///
+/// The five stores are required; every other setting defaults as in [`KyroEngine::new`].
+///
+/// ```
+/// use std::sync::Arc;
+/// use kyroql::{InMemoryStores, KyroEngine};
+///
+/// let stores = InMemoryStores::new();
+/// let engine = KyroEngine::builder()
+///     .entity_store(Arc::new(stores.entities))
+///     .belief_store(Arc::new(stores.beliefs))
+///     .pattern_store(Arc::new(stores.patterns))
+///     .conflict_store(Arc::new(stores.conflicts))
+///     .derivation_store(Arc::new(stores.derivations))
+///     .snapshot_reads(true)
+///     .build()
+///     .unwrap();
+/// # let _ = engine;
+/// ```
+#[derive(Clone, Default)]
+pub struct KyroEngineBuilder {
+    entities: Option<Arc<dyn EntityStore>>,
+    beliefs: Option<Arc<dyn BeliefStore>>,
+    patterns: Option<Arc<dyn PatternStore>>,
+    conflicts: Option<Arc<dyn ConflictStore>>,
+    derivations: Option<Arc<dyn DerivationStore>>,
+    trust: Option<Arc<dyn TrustModel>>,
+    calibration: Option<Arc<CalibrationTracker>>,
+    monitor_config: Option<MonitorSystemConfig>,
+    embedder: Option<Arc<dyn Embedder>>,
+    embedding_text: Option<Arc<EmbeddingTextFn>>,
+    idempotency: Option<Arc<dyn IdempotencyStore>>,
+    rate_limiter: Option<Arc<dyn RateLimiter>>,
+    operation_log: Option<Arc<dyn OperationLog>>,
+    metrics: Option<Arc<dyn Metrics>>,
+    validation_limits: Option<ValidationLimits>,
+    source_merging: bool,
+    snapshot_reads: bool,
+    deterministic_conflict_ids: bool,
+}
+
+impl KyroEngineBuilder {
+    /// A builder with no stores and every setting at its default.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store entities in `store`. Required.
+    #[must_use]
+    pub fn entity_store(mut self, store: Arc<dyn EntityStore>) -> Self {
+        self.entities = Some(store);
+        self
+    }
+
+    /// Store beliefs in `store`. Required.
+    #[must_use]
+    pub fn belief_store(mut self, store: Arc<dyn BeliefStore>) -> Self {
+        self.beliefs = Some(store);
+        self
+    }
+
+    /// Store patterns in `store`. Required.
+    #[must_use]
+    pub fn pattern_store(mut self, store: Arc<dyn PatternStore>) -> Self {
+        self.patterns = Some(store);
+        self
+    }
+
+    /// Store conflicts in `store`. Required.
+    #[must_use]
+    pub fn conflict_store(mut self, store: Arc<dyn ConflictStore>) -> Self {
+        self.conflicts = Some(store);
+        self
+    }
+
+    /// Store derivation records in `store`. Required.
+    #[must_use]
+    pub fn derivation_store(mut self, store: Arc<dyn DerivationStore>) -> Self {
+        self.derivations = Some(store);
+        self
+    }
+
+    /// Weigh sources with `trust` instead of a fresh [`SimpleTrustModel`].
+    #[must_use]
+    pub fn trust_model(mut self, trust: Arc<dyn TrustModel>) -> Self {
+        self.trust = Some(trust);
+        self
+    }
+
+    /// Run the monitor system with `cfg`; see [`KyroEngine::with_monitor_config`].
+    #[must_use]
+    pub fn monitor_config(mut self, cfg: MonitorSystemConfig) -> Self {
+        self.monitor_config = Some(cfg);
+        self
+    }
+
+    /// Embed beliefs with `embedder`; see [`KyroEngine::with_embedder`].
+    #[must_use]
+    pub fn embedder(mut self, embedder: Arc<dyn Embedder>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Build embedded text with `template`; see [`KyroEngine::with_embedding_text`].
+    #[must_use]
+    pub fn embedding_text<F>(mut self, template: F) -> Self
+    where
+        F: Fn(&Entity, &str, &Value) -> String + Send + Sync + 'static,
+    {
+        self.embedding_text = Some(Arc::new(template));
+        self
+    }
+
+    /// Remember idempotency keys in `store`; see [`KyroEngine::with_idempotency_store`].
+    #[must_use]
+    pub fn idempotency_store(mut self, store: Arc<dyn IdempotencyStore>) -> Self {
+        self.idempotency = Some(store);
+        self
+    }
+
+    /// Limit asserts per source with `limiter`; see [`KyroEngine::with_rate_limiter`].
+    #[must_use]
+    pub fn rate_limiter(mut self, limiter: Arc<dyn RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Learn from FEEDBACK into `calibration`; see [`KyroEngine::with_calibration`].
+    #[must_use]
+    pub fn calibration(mut self, calibration: Arc<CalibrationTracker>) -> Self {
+        self.calibration = Some(calibration);
+        self
+    }
+
+    /// Record executed operations to `log`; see [`KyroEngine::with_operation_log`].
+    #[must_use]
+    pub fn operation_log(mut self, log: Arc<dyn OperationLog>) -> Self {
+        self.operation_log = Some(log);
+        self
+    }
+
+    /// Report metrics to `metrics`; see [`KyroEngine::with_metrics`].
+    #[must_use]
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Validate requests against `limits`; see [`KyroEngine::with_validation_limits`].
+    #[must_use]
+    pub fn validation_limits(mut self, limits: ValidationLimits) -> Self {
+        self.validation_limits = Some(limits);
+        self
+    }
+
+    /// Merge repeated values into existing beliefs; see [`KyroEngine::with_source_merging`].
+    #[must_use]
+    pub fn source_merging(mut self, enabled: bool) -> Self {
+        self.source_merging = enabled;
+        self
+    }
+
+    /// Run RESOLVE on belief store snapshots; see [`KyroEngine::with_snapshot_reads`].
+    #[must_use]
+    pub fn snapshot_reads(mut self, enabled: bool) -> Self {
+        self.snapshot_reads = enabled;
+        self
+    }
+
+    /// Derive conflict IDs from their contents; see
+    /// [`KyroEngine::with_deterministic_conflict_ids`].
+    #[must_use]
+    pub fn deterministic_conflict_ids(mut self, enabled: bool) -> Self {
+        self.deterministic_conflict_ids = enabled;
+        self
+    }
+
+    /// Build the engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MissingField` naming the store that was not given, or `Multiple` listing
+    /// each of them if several are missing.
+    pub fn build(self) -> KyroResult<KyroEngine> {
+        let missing = |field: &str| ValidationError::MissingField {
+            field: field.to_string(),
+        };
+        let mut errors = Vec::new();
+        if self.entities.is_none() {
+            errors.push(missing("entity_store"));
+        }
+        if self.beliefs.is_none() {
+            errors.push(missing("belief_store"));
+        }
+        if self.patterns.is_none() {
+            errors.push(missing("pattern_store"));
+        }
+        if self.conflicts.is_none() {
+            errors.push(missing("conflict_store"));
+        }
+        if self.derivations.is_none() {
+            errors.push(missing("derivation_store"));
+        }
+        if let Some(error) = ValidationError::combine(errors) {
+            return Err(error.into());
+        }
+        let (Some(entities), Some(beliefs), Some(patterns), Some(conflicts), Some(derivations)) =
+            (self.entities, self.beliefs, self.patterns, self.conflicts, self.derivations)
+        else {
+            unreachable!("missing stores were reported above");
+        };
+
+        let mut engine = KyroEngine::assemble(
+            entities,
+            beliefs,
+            patterns,
+            conflicts,
+            derivations,
+            self.trust.unwrap_or_else(|| Arc::new(SimpleTrustModel::new())),
+            self.monitor_config.unwrap_or_default(),
+        );
+        if let Some(calibration) = self.calibration {
+            engine.calibration = calibration;
+        }
+        if let Some(embedder) = self.embedder {
+            engine.embedder = embedder;
+        }
+        if let Some(template) = self.embedding_text {
+            engine.embedding_text = template;
+        }
+        if let Some(store) = self.idempotency {
+            engine.idempotency = store;
+        }
+        if let Some(limits) = self.validation_limits {
+            engine.validation_limits = limits;
+        }
+        if let Some(metrics) = self.metrics {
+            engine.metrics = metrics;
+        }
+        engine.rate_limiter = self.rate_limiter;
+        engine.operation_log = self.operation_log;
+        engine.merge_duplicate_sources = self.source_merging;
+        engine.snapshot_reads = self.snapshot_reads;
+        engine.deterministic_conflict_ids = self.deterministic_conflict_ids;
+        Ok(engine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::confidence::Confidence;
+    use crate::engine::{EngineResponse, TokenBucketRateLimiter};
+    use crate::entity::EntityType;
+    use crate::error::KyroError;
+    use crate::ir::{AssertPayload, ConsistencyMode, KyroIR, Operation};
+    use crate::source::Source;
+    use crate::storage::InMemoryStores;
+    use crate::time::TimeRange;
+
+    fn with_stores(builder: KyroEngineBuilder) -> KyroEngineBuilder {
+        let stores = InMemoryStores::new();
+        builder
+            .entity_store(Arc::new(stores.entities))
+            .belief_store(Arc::new(stores.beliefs))
+            .pattern_store(Arc::new(stores.patterns))
+            .conflict_store(Arc::new(stores.conflicts))
+            .derivation_store(Arc::new(stores.derivations))
+    }
+
+    #[test]
+    fn builds_an_engine_with_a_custom_trust_model_and_config() {
+        let trust = Arc::new(SimpleTrustModel::new());
+        let engine = with_stores(KyroEngine::builder())
+            .trust_model(trust.clone())
+            .rate_limiter(Arc::new(TokenBucketRateLimiter::new(1, Duration::from_secs(3600)).unwrap()))
+            .validation_limits(ValidationLimits::default().with_max_embedding_dim(4))
+            .snapshot_reads(true)
+            .build()
+            .unwrap();
+
+        let trust_model: Arc<dyn TrustModel> = trust;
+        assert!(Arc::ptr_eq(engine.trust_model(), &trust_model));
+        assert_eq!(engine.validation_limits().max_embedding_dim, 4);
+        assert!(engine.rate_limiter().is_some());
+
+        let entity = Entity::new("sensor", EntityType::Concept);
+        let id = entity.id;
+        engine.entity_store().insert(entity).unwrap();
+        let assert = || {
+            engine.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                entity_id: id,
+                predicate: "reading".to_string(),
+                value: Value::Int(1),
+                confidence: Confidence::from_agent(0.9, "probe").unwrap(),
+                source: Source::agent("probe", Option::<String>::None),
+                valid_time: TimeRange::from_now(),
+                consistency_mode: ConsistencyMode::Force,
+                embedding: None,
+                idempotency_key: None,
+                namespace: None,
+            })))
+        };
+        assert!(matches!(assert(), Ok(EngineResponse::Assert { .. })));
+        // The one-token bucket is spent.
+        assert!(assert().is_err());
+    }
+
+    #[test]
+    fn build_reports_every_missing_store() {
+        let Err(KyroError::Validation(error)) = KyroEngine::builder().build() else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(error.errors().len(), 5);
+
+        let stores = InMemoryStores::new();
+        let Err(KyroError::Validation(error)) = KyroEngine::builder()
+            .entity_store(Arc::new(stores.entities))
+            .belief_store(Arc::new(stores.beliefs))
+            .pattern_store(Arc::new(stores.patterns))
+            .conflict_store(Arc::new(stores.conflicts))
+            .build()
+        else {
+            panic!("expected a validation error");
+        };
+        assert!(matches!(error, ValidationError::MissingField { ref field } if field == "derivation_store"));
+    }
+}
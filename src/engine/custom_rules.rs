@@ -0,0 +1,72 @@
+//! Registry of evaluators for `PatternRule::Custom`.
+//!
+//! Custom rules are persisted by name only; the evaluation logic lives in process and
+//! must be registered on the engine before a pattern referencing it can be defined.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Utc};
+
+use crate::belief::Belief;
+use crate::error::{KyroError, KyroResult};
+use crate::storage::BeliefStore;
+
+/// Evaluator for a custom pattern rule.
+///
+/// Receives the belief being asserted, the belief store, and the evaluation time. Returns a
+/// violation reason, or `None` when the belief satisfies the rule.
+pub type CustomRuleFn = dyn Fn(&Belief, &dyn BeliefStore, DateTime<Utc>) -> Option<String> + Send + Sync;
+
+/// Name → evaluator map shared by clones of an engine.
+#[derive(Default)]
+pub struct CustomRuleRegistry {
+    rules: RwLock<HashMap<String, Arc<CustomRuleFn>>>,
+}
+
+impl CustomRuleRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) the evaluator for `name`.
+    pub fn register(&self, name: impl Into<String>, rule: Arc<CustomRuleFn>) -> KyroResult<()> {
+        self.rules
+            .write()
+            .map_err(|_| KyroError::internal("custom rule registry lock poisoned"))?
+            .insert(name.into(), rule);
+        Ok(())
+    }
+
+    /// Look up the evaluator for `name`.
+    pub fn get(&self, name: &str) -> KyroResult<Option<Arc<CustomRuleFn>>> {
+        Ok(self
+            .rules
+            .read()
+            .map_err(|_| KyroError::internal("custom rule registry lock poisoned"))?
+            .get(name)
+            .cloned())
+    }
+
+    /// Returns `true` if no evaluators are registered.
+    pub fn is_empty(&self) -> KyroResult<bool> {
+        Ok(self
+            .rules
+            .read()
+            .map_err(|_| KyroError::internal("custom rule registry lock poisoned"))?
+            .is_empty())
+    }
+}
+
+impl std::fmt::Debug for CustomRuleRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = self
+            .rules
+            .read()
+            .map(|rules| rules.keys().cloned().collect())
+            .unwrap_or_default();
+        f.debug_struct("CustomRuleRegistry").field("rules", &names).finish()
+    }
+}
@@ -0,0 +1,52 @@
+//! Instrumentation hooks for the engine.
+//!
+//! The engine reports counters and histogram observations through a [`Metrics`]
+//! implementation, [`NoopMetrics`] by default. Names follow Prometheus conventions
+//! (`_total` for counters, `_seconds` for latency histograms), so an adapter can register
+//! one Prometheus collector per name and forward each call to it.
+
+/// ASSERT operations that completed, including idempotent replays.
+pub const ASSERTS_TOTAL: &str = "kyroql_asserts_total";
+
+/// ASSERT operations that returned an error.
+pub const ASSERT_ERRORS_TOTAL: &str = "kyroql_assert_errors_total";
+
+/// Latency of ASSERT operations, successful or not.
+pub const ASSERT_LATENCY_SECONDS: &str = "kyroql_assert_latency_seconds";
+
+/// ASSERTs answered from the idempotency store without writing.
+pub const IDEMPOTENT_REPLAYS_TOTAL: &str = "kyroql_idempotent_replays_total";
+
+/// Conflicts found by conflict detection on ASSERT, whether or not they were recorded.
+pub const CONFLICTS_DETECTED_TOTAL: &str = "kyroql_conflicts_detected_total";
+
+/// RESOLVE operations, multi-entity ones included, that completed.
+pub const RESOLVES_TOTAL: &str = "kyroql_resolves_total";
+
+/// RESOLVE operations that returned an error.
+pub const RESOLVE_ERRORS_TOTAL: &str = "kyroql_resolve_errors_total";
+
+/// Latency of RESOLVE operations, successful or not.
+pub const RESOLVE_LATENCY_SECONDS: &str = "kyroql_resolve_latency_seconds";
+
+/// Sink for engine metrics.
+///
+/// Calls happen on the executing thread, so implementations should be cheap and must not
+/// block on I/O.
+pub trait Metrics: Send + Sync {
+    /// Add `value` to the counter `name`.
+    fn increment_counter(&self, name: &'static str, value: u64);
+
+    /// Record one observation of `value` in the histogram `name`.
+    fn observe_histogram(&self, name: &'static str, value: f64);
+}
+
+/// Discards every metric.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {
+    fn increment_counter(&self, _name: &'static str, _value: u64) {}
+
+    fn observe_histogram(&self, _name: &'static str, _value: f64) {}
+}
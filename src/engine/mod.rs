@@ -5,44 +5,94 @@
 
 mod write_path;
 
+/// Evaluators for custom pattern rules.
+pub mod custom_rules;
+
+/// Per-source ASSERT rate limiting.
+pub mod rate_limit;
+
+/// Executed-operation capture and replay.
+pub mod operation_log;
+
 /// Routed runtime enforcing Reflex/Reflection isolation.
 pub mod runtime;
 
-use std::collections::HashMap;
+/// Retention pruning of superseded beliefs.
+pub mod retention;
+
+/// Vacuum of beliefs orphaned by entity deletion.
+pub mod vacuum;
+
+/// Atomic multi-operation transactions.
+mod transaction;
+
+/// Per-predicate cache of the patterns ASSERT checks.
+mod pattern_cache;
+
+/// Counters and histograms reported by the engine.
+pub mod metrics;
+
+/// Per-predicate value schemas checked on ASSERT.
+pub mod schema;
+
+/// Fluent construction of a configured engine.
+pub mod builder;
+
+pub use builder::KyroEngineBuilder;
+pub use custom_rules::{CustomRuleFn, CustomRuleRegistry};
+pub use metrics::{Metrics, NoopMetrics};
+pub use rate_limit::{RateLimitExceeded, RateLimiter, TokenBucketRateLimiter};
+pub use retention::{PruneReport, RetentionPolicy};
+pub use schema::{PredicateSchema, PredicateSchemaRegistry};
+pub use vacuum::{VacuumMode, VacuumReport};
+pub use operation_log::{
+    replay, InMemoryOperationLog, JsonLinesOperationLog, LoggedOperation, OperationLog,
+};
+
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::{OnceLock, RwLock};
+use std::time::Instant;
 
 use chrono::{DateTime, Utc};
 
 use crate::belief::{Belief, ConsistencyStatus};
 use crate::confidence::{BeliefId, Confidence};
-use crate::conflict::{Conflict, ConflictId};
-use crate::derivation::{DerivationId, DerivationRecord};
-use crate::entity::{EntityId};
+use crate::conflict::{Conflict, ConflictId, ConflictResolution, ConflictType, ResolutionAudit};
+use crate::derivation::{ConfidenceRecomputation, DerivationId, DerivationRecord, DerivationRule};
+use crate::embedding::{default_embedding_text, Embedder, EmbeddingTextFn, LexicalEmbedder};
+use crate::entity::{Entity, EntityId};
 use crate::error::{ExecutionError, KyroError, KyroResult, ValidationError};
-use crate::frame::{BeliefFrame, Evidence, KnowledgeGap, RankedClaim};
-use crate::inference::{ConflictResolutionPolicy, PolicyDecision};
+use crate::frame::{BeliefFrame, CompoundFrame, CompoundMatch, Evidence, KnowledgeGap, RankedClaim};
+use crate::inference::{
+    ConfidenceAggregation, ConflictResolutionPolicy, EvidenceCombination, PolicyDecision, TieBreak,
+};
 use crate::ir::{
-    ConsistencyMode, DefinePatternPayload, DerivePayload, KyroIR, MonitorPayload, Operation,
-    ResolvePayload, RetractPayload, SimulatePayload,
+    AggregateFunction, AssertPayload, ConsistencyMode, DefinePatternPayload, DerivePayload, FeedbackPayload, KyroIR,
+    MonitorPayload, Operation, ResolveCompoundPayload, ResolveMode, ResolveMultiPayload, ResolvePayload,
+    RetractPayload, SimulatePayload, UpdatePatternPayload, ValidationLimits,
 };
+use crate::monitor::ValueMatcher;
 use crate::monitor::{MonitorRegistration, MonitorSystem, MonitorSystemConfig};
 use crate::monitor::matcher::AssertObservation;
-use crate::pattern::{Pattern, PatternId, PatternRule};
+use crate::pattern::{find_contradictions, Pattern, PatternId, PatternRule, PatternSetWarning};
 use crate::simulation::{SimulateConstraints, SimulationBaseStores, SimulationContext};
 use crate::storage::{
-    BeliefStore, ConflictStore, DerivationStore, EntityStore, PatternStore, StorageError,
+    AmendFields, BeliefStore, ConflictStore, Corroboration, DerivationStore, EntityStore,
+    IdempotencyReservation, IdempotencyStore, InMemoryIdempotencyStore, PatternStore, StorageError,
 };
 use crate::time::TimeRange;
 use crate::value::Value;
-use crate::trust::{TrustModel, SimpleTrustModel};
+use crate::trust::{CalibrationTracker, SimpleTrustModel, SourceAccuracy, TrustModel};
 use crate::meta::MetaAnalyzer;
 
 const REGEX_CACHE_MAX: usize = 1024;
 
 static REGEX_CACHE: OnceLock<RwLock<HashMap<String, regex::Regex>>> = OnceLock::new();
 
-fn cached_regex(pattern: &str) -> KyroResult<regex::Regex> {
+/// Compile `pattern`, reusing the copy cached by an earlier call; shared by pattern
+/// rules, value matchers in RESOLVE and MONITOR triggers.
+pub(crate) fn cached_regex(pattern: &str) -> KyroResult<regex::Regex> {
     let cache = REGEX_CACHE.get_or_init(|| RwLock::new(HashMap::new()));
 
     {
@@ -78,6 +128,7 @@ fn cached_regex(pattern: &str) -> KyroResult<regex::Regex> {
 
 /// Result of executing a KyroQL operation.
 #[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum EngineResponse {
     /// Result of an ASSERT.
     Assert {
@@ -85,6 +136,14 @@ pub enum EngineResponse {
         belief_id: BeliefId,
         /// Any detected conflicts.
         conflict_ids: Vec<ConflictId>,
+        /// Conflicts detected but not recorded (`ConsistencyMode::Warn`), described.
+        conflict_warnings: Vec<String>,
+    },
+
+    /// Result of a compound RESOLVE.
+    ResolveCompound {
+        /// Entities satisfying every condition.
+        frame: CompoundFrame,
     },
 
     /// Result of a RESOLVE.
@@ -93,6 +152,12 @@ pub enum EngineResponse {
         frame: BeliefFrame,
     },
 
+    /// Result of a multi-entity RESOLVE.
+    ResolveMulti {
+        /// The belief frame produced for each requested entity.
+        frames: HashMap<EntityId, BeliefFrame>,
+    },
+
     /// Result of a RETRACT.
     Retract {
         /// The retraction belief ID.
@@ -105,6 +170,24 @@ pub enum EngineResponse {
         pattern_id: PatternId,
     },
 
+    /// Result of UPDATE_PATTERN.
+    UpdatePattern {
+        /// The updated pattern ID.
+        pattern_id: PatternId,
+    },
+
+    /// Result of DEACTIVATE_PATTERN.
+    DeactivatePattern {
+        /// The deactivated pattern ID.
+        pattern_id: PatternId,
+    },
+
+    /// Result of CHECK_PATTERN_SET.
+    CheckPatternSet {
+        /// Contradictions found among the predicate's active patterns.
+        warnings: Vec<PatternSetWarning>,
+    },
+
     /// Result of a SIMULATE.
     Simulate {
         /// The created simulation context.
@@ -122,6 +205,18 @@ pub enum EngineResponse {
         /// The stored derivation record ID.
         derivation_id: DerivationId,
     },
+
+    /// Result of a FEEDBACK.
+    Feedback {
+        /// The asserting source's tally after this outcome.
+        source_accuracy: SourceAccuracy,
+    },
+
+    /// Result of a TRANSACTION: one response per operation, in order.
+    Transaction {
+        /// Responses of the transaction's operations.
+        responses: Vec<EngineResponse>,
+    },
 }
 
 /// KyroQL execution engine.
@@ -134,10 +229,28 @@ pub struct KyroEngine {
     derivations: Arc<dyn DerivationStore>,
     monitor: Arc<MonitorSystem>,
     trust: Arc<dyn TrustModel>,
+    calibration: Arc<CalibrationTracker>,
+    embedder: Arc<dyn Embedder>,
+    embedding_text: Arc<EmbeddingTextFn>,
+    idempotency: Arc<dyn IdempotencyStore>,
+    custom_rules: Arc<CustomRuleRegistry>,
+    schemas: Arc<PredicateSchemaRegistry>,
+    rate_limiter: Option<Arc<dyn RateLimiter>>,
+    operation_log: Option<Arc<dyn OperationLog>>,
+    metrics: Arc<dyn Metrics>,
+    validation_limits: ValidationLimits,
+    merge_duplicate_sources: bool,
+    snapshot_reads: bool,
+    deterministic_conflict_ids: bool,
+    pattern_cache: Arc<pattern_cache::PatternCache>,
+    /// Set while staging a transaction: ASSERT observations wait here until it commits.
+    held_observations: Option<transaction::HeldObservations>,
 }
 
 impl KyroEngine {
     /// Create a new engine using the given stores.
+    ///
+    /// See [`KyroEngine::builder`] to configure everything else in one place.
     #[must_use]
     pub fn new(
         entities: Arc<dyn EntityStore>,
@@ -146,20 +259,8 @@ impl KyroEngine {
         conflicts: Arc<dyn ConflictStore>,
         derivations: Arc<dyn DerivationStore>,
     ) -> Self {
-        let monitor = Arc::new(MonitorSystem::new(
-            MonitorSystemConfig::default(),
-            Arc::clone(&beliefs),
-        ));
         let trust = Arc::new(SimpleTrustModel::new());
-        Self {
-            entities,
-            beliefs,
-            patterns,
-            conflicts,
-            derivations,
-            monitor,
-            trust,
-        }
+        Self::assemble(entities, beliefs, patterns, conflicts, derivations, trust, MonitorSystemConfig::default())
     }
 
     /// Create a new engine with an explicit trust model.
@@ -172,10 +273,26 @@ impl KyroEngine {
         derivations: Arc<dyn DerivationStore>,
         trust: Arc<dyn TrustModel>,
     ) -> Self {
-        let monitor = Arc::new(MonitorSystem::new(
-            MonitorSystemConfig::default(),
-            Arc::clone(&beliefs),
-        ));
+        Self::assemble(entities, beliefs, patterns, conflicts, derivations, trust, MonitorSystemConfig::default())
+    }
+
+    /// Start configuring an engine; see [`KyroEngineBuilder`].
+    #[must_use]
+    pub fn builder() -> KyroEngineBuilder {
+        KyroEngineBuilder::new()
+    }
+
+    /// An engine over the given stores with every other setting at its default.
+    fn assemble(
+        entities: Arc<dyn EntityStore>,
+        beliefs: Arc<dyn BeliefStore>,
+        patterns: Arc<dyn PatternStore>,
+        conflicts: Arc<dyn ConflictStore>,
+        derivations: Arc<dyn DerivationStore>,
+        trust: Arc<dyn TrustModel>,
+        monitor_config: MonitorSystemConfig,
+    ) -> Self {
+        let monitor = Arc::new(MonitorSystem::new(monitor_config, Arc::clone(&beliefs)));
         Self {
             entities,
             beliefs,
@@ -184,6 +301,21 @@ impl KyroEngine {
             derivations,
             monitor,
             trust,
+            calibration: Arc::new(CalibrationTracker::new()),
+            embedder: Arc::new(LexicalEmbedder::default()),
+            embedding_text: Arc::new(default_embedding_text),
+            idempotency: Arc::new(InMemoryIdempotencyStore::new()),
+            custom_rules: Arc::new(CustomRuleRegistry::new()),
+            schemas: Arc::new(PredicateSchemaRegistry::new()),
+            rate_limiter: None,
+            operation_log: None,
+            metrics: Arc::new(NoopMetrics),
+            validation_limits: ValidationLimits::default(),
+            merge_duplicate_sources: false,
+            snapshot_reads: false,
+            deterministic_conflict_ids: false,
+            pattern_cache: Arc::default(),
+            held_observations: None,
         }
     }
     
@@ -217,27 +349,532 @@ impl KyroEngine {
         &self.monitor
     }
 
+    /// Replace the monitor system with one built from `cfg`, e.g. for at-least-once delivery.
+    ///
+    /// Subscriptions registered with the previous monitor system keep using it.
+    #[must_use]
+    pub fn with_monitor_config(mut self, cfg: MonitorSystemConfig) -> Self {
+        self.monitor = Arc::new(MonitorSystem::new(cfg, Arc::clone(&self.beliefs)));
+        self
+    }
+
     /// Access the configured trust model.
     pub fn trust_model(&self) -> &Arc<dyn TrustModel> {
         &self.trust
     }
 
+    /// Learn per-source accuracy from FEEDBACK into `calibration` instead of a fresh tracker,
+    /// e.g. one restored by `PersistentStores::open_with_trust`.
+    #[must_use]
+    pub fn with_calibration(mut self, calibration: Arc<CalibrationTracker>) -> Self {
+        self.calibration = calibration;
+        self
+    }
+
+    /// Access the per-source accuracy learned from FEEDBACK.
+    pub fn calibration(&self) -> &Arc<CalibrationTracker> {
+        &self.calibration
+    }
+
+    /// Replace the embedder used to generate belief embeddings on ASSERT.
+    ///
+    /// Stores pin their embedding dimension on first insert, so an engine must keep using
+    /// embedders of the same dimensionality, or migrate the stored beliefs with
+    /// [`reembed_beliefs`](Self::reembed_beliefs) first.
+    #[must_use]
+    pub fn with_embedder(mut self, embedder: Arc<dyn Embedder>) -> Self {
+        self.embedder = embedder;
+        self
+    }
+
+    /// Access the configured embedder.
+    pub fn embedder(&self) -> &Arc<dyn Embedder> {
+        &self.embedder
+    }
+
+    /// Replace how ASSERT builds the text it embeds when no embedding is supplied.
+    ///
+    /// Defaults to [`default_embedding_text`]. Changing it alters which queries retrieve
+    /// existing beliefs, so keep it stable for the lifetime of the stores.
+    #[must_use]
+    pub fn with_embedding_text<F>(mut self, template: F) -> Self
+    where
+        F: Fn(&Entity, &str, &Value) -> String + Send + Sync + 'static,
+    {
+        self.embedding_text = Arc::new(template);
+        self
+    }
+
+    /// Replace the store that remembers ASSERT idempotency keys.
+    ///
+    /// Defaults to a bounded in-memory store; use a persistent store so retries
+    /// remain deduplicated across restarts.
+    #[must_use]
+    pub fn with_idempotency_store(mut self, store: Arc<dyn IdempotencyStore>) -> Self {
+        self.idempotency = store;
+        self
+    }
+
+    /// Access the configured idempotency key store.
+    pub fn idempotency_store(&self) -> &Arc<dyn IdempotencyStore> {
+        &self.idempotency
+    }
+
+    /// Limit how fast each source may assert.
+    ///
+    /// Checked per `Source::source_id()` before an ASSERT writes anything; excess asserts
+    /// fail with `ExecutionError::QueueFull`. Idempotent replays are not counted.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, limiter: Arc<dyn RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Access the configured rate limiter, if any.
+    pub fn rate_limiter(&self) -> Option<&Arc<dyn RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Record every successfully executed operation to `log`, for [`replay`].
+    #[must_use]
+    pub fn with_operation_log(mut self, log: Arc<dyn OperationLog>) -> Self {
+        self.operation_log = Some(log);
+        self
+    }
+
+    /// Access the configured operation log, if any.
+    pub fn operation_log(&self) -> Option<&Arc<dyn OperationLog>> {
+        self.operation_log.as_ref()
+    }
+
+    /// Report counters and latencies to `metrics`; see [`metrics`] for the names used.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Access the configured metrics sink.
+    pub fn metrics(&self) -> &Arc<dyn Metrics> {
+        &self.metrics
+    }
+
+    /// Validate requests against `limits` instead of the defaults.
+    ///
+    /// Embedding bounds only cap what a request may carry: the embedder's and the belief
+    /// store's dimension still decide the length actually stored.
+    #[must_use]
+    pub fn with_validation_limits(mut self, limits: ValidationLimits) -> Self {
+        self.validation_limits = limits;
+        self
+    }
+
+    /// Access the limits requests are validated against.
+    pub fn validation_limits(&self) -> &ValidationLimits {
+        &self.validation_limits
+    }
+
+    /// Merge an ASSERT repeating an active belief's value into that belief.
+    ///
+    /// When enabled, asserting the value an active belief already holds for the same
+    /// entity, predicate and valid time, from a source not yet behind it, adds the source
+    /// to the belief's `corroborating_sources` and raises its confidence to the noisy-OR of
+    /// both instead of storing a second belief. The ASSERT returns the existing belief's
+    /// ID. `Replace` mode never merges. Disabled by default.
+    #[must_use]
+    pub fn with_source_merging(mut self, enabled: bool) -> Self {
+        self.merge_duplicate_sources = enabled;
+        self
+    }
+
+    /// Run each RESOLVE on a snapshot of the belief store instead of the live store.
+    ///
+    /// The snapshot is taken once per RESOLVE (see `BeliefStore::snapshot`), so the query
+    /// holds the store's lock only for that moment rather than across each of its lookups,
+    /// and sees no ASSERT that lands while it runs. Stores without snapshot support are
+    /// read directly. Disabled by default.
+    #[must_use]
+    pub fn with_snapshot_reads(mut self, enabled: bool) -> Self {
+        self.snapshot_reads = enabled;
+        self
+    }
+
+    /// Derive conflict IDs from the conflict type and the sorted IDs of the beliefs
+    /// involved (see [`ConflictId::deterministic`]) instead of drawing random ones.
+    ///
+    /// The same contradiction then always maps to the same ID, and a conflict that is
+    /// detected again is not stored a second time. Disabled by default.
+    #[must_use]
+    pub fn with_deterministic_conflict_ids(mut self, enabled: bool) -> Self {
+        self.deterministic_conflict_ids = enabled;
+        self
+    }
+
+    /// Drop the patterns cached for conflict detection.
+    ///
+    /// ASSERT caches the patterns on each predicate until DEFINE/UPDATE/DEACTIVATE PATTERN
+    /// changes one through this engine (or one of its clones). Call this after writing to
+    /// the pattern store by other means, e.g. directly or from another engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an internal error if the cache lock is poisoned.
+    pub fn invalidate_pattern_cache(&self) -> KyroResult<()> {
+        self.pattern_cache.invalidate()
+    }
+
+    /// Register the evaluator for `PatternRule::Custom { name, .. }`.
+    ///
+    /// A custom pattern can only be defined once its evaluator is registered. The registry
+    /// is shared by clones of this engine; registering an existing name replaces it.
+    pub fn register_custom_rule<F>(&self, name: impl Into<String>, rule: F) -> KyroResult<()>
+    where
+        F: Fn(&Belief, &dyn BeliefStore, DateTime<Utc>) -> Option<String> + Send + Sync + 'static,
+    {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err(ValidationError::MissingField {
+                field: "name".to_string(),
+            }
+            .into());
+        }
+        self.custom_rules.register(name, Arc::new(rule))
+    }
+
+    /// Access the custom rule registry.
+    pub fn custom_rules(&self) -> &Arc<CustomRuleRegistry> {
+        &self.custom_rules
+    }
+
+    /// Require every value asserted for `predicate` to match `schema`.
+    ///
+    /// A mistyped ASSERT then fails with a `ValidationError` before anything is stored,
+    /// rather than being stored and flagged by a pattern. The registry is shared by clones
+    /// of this engine; registering a predicate again replaces its schema.
+    pub fn register_predicate_schema(&self, predicate: impl Into<String>, schema: PredicateSchema) -> KyroResult<()> {
+        let predicate = predicate.into().trim().to_string();
+        if predicate.is_empty() {
+            return Err(ValidationError::EmptyPredicate.into());
+        }
+        self.schemas.register(predicate, schema)
+    }
+
+    /// Access the predicate schema registry.
+    pub fn predicate_schemas(&self) -> &Arc<PredicateSchemaRegistry> {
+        &self.schemas
+    }
+
     /// Construct a meta-knowledge analyzer.
     pub fn meta_analyzer(&self) -> MetaAnalyzer {
         MetaAnalyzer::new(Arc::clone(&self.entities), Arc::clone(&self.beliefs))
     }
 
+    /// Recompute a derivation's propagated confidence from its premises' current confidences.
+    ///
+    /// Premise confidences change in place when a belief is amended, which leaves the
+    /// derivations built on them stale. The record's rule must name a [`DerivationRule`].
+    /// With `amend_derived`, the derived belief's confidence is amended to the new value
+    /// as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the derivation or one of its beliefs does not exist, or if its
+    /// rule has no confidence propagation.
+    pub fn recompute_derivation(
+        &self,
+        derivation_id: DerivationId,
+        amend_derived: bool,
+    ) -> KyroResult<ConfidenceRecomputation> {
+        let mut record = self
+            .derivations
+            .get(derivation_id)
+            .map_err(Self::storage_err)?
+            .ok_or_else(|| Self::storage_err(StorageError::DerivationNotFound(derivation_id)))?;
+        let rule = record.derivation_rule().ok_or_else(|| {
+            KyroError::Execution(ExecutionError::InvalidDerivation {
+                reason: format!("rule '{}' does not define confidence propagation", record.rule),
+            })
+        })?;
+
+        let mut confidences = Vec::with_capacity(record.premise_ids.len());
+        for &premise in &record.premise_ids {
+            let belief = self
+                .beliefs
+                .get(premise)
+                .map_err(Self::storage_err)?
+                .ok_or(KyroError::Execution(ExecutionError::BeliefNotFound { id: premise }))?;
+            confidences.push(belief.confidence.value());
+        }
+        let recomputed = ConfidenceRecomputation {
+            previous: record.propagated_confidence,
+            current: rule.propagate(confidences),
+        };
+
+        if amend_derived {
+            if let Some(derived_id) = record.derived_belief_id {
+                self.amend_derived_confidence(derived_id, derivation_id, rule, recomputed.current)?;
+            }
+        }
+
+        record.propagated_confidence = Some(recomputed.current);
+        self.derivations.update(record).map_err(Self::storage_err)?;
+        Ok(recomputed)
+    }
+
+    fn amend_derived_confidence(
+        &self,
+        derived_id: BeliefId,
+        derivation_id: DerivationId,
+        rule: DerivationRule,
+        value: f32,
+    ) -> KyroResult<()> {
+        let derived = self
+            .beliefs
+            .get(derived_id)
+            .map_err(Self::storage_err)?
+            .ok_or(KyroError::Execution(ExecutionError::BeliefNotFound { id: derived_id }))?;
+        if derived.confidence.value() == value {
+            return Ok(());
+        }
+        let confidence = Confidence::new(
+            value,
+            derived.confidence.calibration.clone(),
+            derived.confidence.source.clone(),
+        )?;
+        self.beliefs
+            .amend(
+                derived_id,
+                AmendFields {
+                    confidence: Some(confidence),
+                    audit_note: Some(format!("recomputed by derivation {derivation_id} ({rule})")),
+                    ..AmendFields::default()
+                },
+            )
+            .map_err(Self::storage_err)
+    }
+
+    /// Resolve an open conflict by `policy` and record the decision in its audit trail.
+    ///
+    /// The policy chooses among the conflict's beliefs as RESOLVE would, with trust scoped
+    /// to the contested predicate. `principal` names whoever asked for the resolution; the
+    /// resulting [`ResolutionAudit`] is kept on the stored conflict for later review.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the conflict or one of its beliefs does not exist, if the
+    /// conflict is not open, or if the policy does not select a winner.
+    pub fn resolve_conflict(
+        &self,
+        conflict_id: ConflictId,
+        policy: &ConflictResolutionPolicy,
+        principal: Option<&str>,
+    ) -> KyroResult<Conflict> {
+        let failed = |reason: String| {
+            KyroError::Execution(ExecutionError::ConflictResolutionFailed { reason })
+        };
+        let mut conflict = self
+            .conflicts
+            .get(conflict_id)
+            .map_err(Self::storage_err)?
+            .ok_or_else(|| Self::storage_err(StorageError::ConflictNotFound(conflict_id)))?;
+        if !conflict.is_open() {
+            return Err(failed(format!("conflict {conflict_id} is {}", conflict.status)));
+        }
+
+        let mut beliefs = Vec::with_capacity(conflict.belief_ids.len());
+        for &id in &conflict.belief_ids {
+            let belief = self
+                .beliefs
+                .get(id)
+                .map_err(Self::storage_err)?
+                .ok_or(KyroError::Execution(ExecutionError::BeliefNotFound { id }))?;
+            beliefs.push(belief);
+        }
+        let domain = match &conflict.conflict_type {
+            ConflictType::ValueContradiction { predicate } => Some(predicate.as_str()),
+            _ => None,
+        };
+
+        let decision = self.decide_with_trust(policy, TieBreak::default(), &beliefs, domain);
+        let winner = match decision {
+            PolicyDecision::Selected(id) => beliefs.iter().find(|b| b.id == id),
+            PolicyDecision::Unresolved => None,
+        };
+        let unresolved =
+            || failed(format!("policy did not select a winner for conflict {conflict_id}"));
+        let winner = winner.ok_or_else(unresolved)?;
+
+        let resolution = match policy {
+            ConflictResolutionPolicy::LatestWins => ConflictResolution::MoreRecent {
+                chosen_belief_id: winner.id,
+            },
+            ConflictResolutionPolicy::HighestConfidence => ConflictResolution::HigherConfidence {
+                chosen_belief_id: winner.id,
+                confidence: winner.confidence.value(),
+            },
+            ConflictResolutionPolicy::SourcePriority { priority } => {
+                let sid = winner.source.source_id();
+                let rank = priority.as_slice().iter().position(|p| *p == sid);
+                ConflictResolution::SourcePriority {
+                    chosen_belief_id: winner.id,
+                    source_priority: rank.map_or(u32::MAX, |r| u32::try_from(r).unwrap_or(u32::MAX)),
+                }
+            }
+            ConflictResolutionPolicy::ExplicitConflict => return Err(unresolved()),
+        };
+        let audit = ResolutionAudit::new(
+            policy.clone(),
+            winner.id,
+            &conflict.belief_ids,
+            principal.map(str::to_string),
+        );
+        conflict.resolve_audited(resolution, audit);
+        // Another resolution may have landed since the conflict was read; keep its audit entry.
+        if !self
+            .conflicts
+            .update_if_open(conflict.clone())
+            .map_err(Self::storage_err)?
+        {
+            return Err(failed(format!("conflict {conflict_id} was resolved concurrently")));
+        }
+        Ok(conflict)
+    }
+
     fn trust_weight(&self, source: &crate::source::Source, domain: Option<&str>) -> f32 {
-        self.trust.assess(source, domain).weight()
+        self.trust.assess(source, domain).weight() * self.calibration.weight(source.source_id())
     }
 
     fn trusted_confidence(&self, belief: &Belief, domain: Option<&str>) -> f32 {
-        belief.confidence.value().clamp(0.0, 1.0) * self.trust_weight(&belief.source, domain)
+        let reported = belief.confidence.value().clamp(0.0, 1.0);
+        let normalized = self.trust.normalize_confidence(&belief.source, reported).clamp(0.0, 1.0);
+        let bounded = self.trust.clamp_confidence(&belief.source, normalized);
+        let weighted = (bounded * self.trust.confidence_source_weight(&belief.confidence.source)).min(1.0);
+        weighted * self.trust_weight(&belief.source, domain)
+    }
+
+    /// Aggregate support for `winner` against the candidates that contradict it.
+    ///
+    /// Candidates consistent with `winner` without matching it, such as a denial of some
+    /// other value, count on neither side.
+    fn epistemic_confidence(
+        &self,
+        beliefs: &[Belief],
+        winner: &Value,
+        domain: Option<&str>,
+        combination: EvidenceCombination,
+    ) -> f32 {
+        let support = EvidenceCombination::aggregate(
+            beliefs
+                .iter()
+                .filter(|b| &b.value == winner)
+                .map(|b| self.trusted_confidence(b, domain)),
+        );
+        let counter = EvidenceCombination::aggregate(
+            beliefs
+                .iter()
+                .filter(|b| b.value.contradicts(winner))
+                .map(|b| self.trusted_confidence(b, domain)),
+        );
+        combination.combine(support, counter)
+    }
+
+    /// Uncertainty band around `point`, the epistemic confidence in `winner`.
+    ///
+    /// Each belief matching `winner` bounds it by its trusted confidence, each belief
+    /// contradicting it by one minus its trusted confidence; the band spans those bounds
+    /// and `point`.
+    fn confidence_interval(
+        &self,
+        beliefs: &[Belief],
+        winner: &Value,
+        domain: Option<&str>,
+        point: f32,
+    ) -> (f32, f32) {
+        beliefs
+            .iter()
+            .filter_map(|b| {
+                let conf = self.trusted_confidence(b, domain);
+                if &b.value == winner {
+                    Some(conf)
+                } else if b.value.contradicts(winner) {
+                    Some(1.0 - conf)
+                } else {
+                    None
+                }
+            })
+            .fold((point, point), |(lo, hi), bound| (lo.min(bound), hi.max(bound)))
+    }
+
+    /// Marginal distribution over the values of `beliefs`, or `None` if none of them
+    /// holds a `Value::Distribution`.
+    ///
+    /// Each belief's outcomes (a plain value is a point mass) are weighted by its trusted
+    /// confidence and the mixture renormalized. Denials assert no value and are skipped.
+    fn marginal_distribution(&self, beliefs: &[Belief], domain: Option<&str>) -> Option<Vec<(Value, f32)>> {
+        if !beliefs.iter().any(|b| b.value.is_distribution()) {
+            return None;
+        }
+        let mut outcomes: Vec<(Value, f32)> = Vec::new();
+        let mut total = 0.0_f32;
+        for b in beliefs.iter().filter(|b| !b.value.is_denial()) {
+            let weight = self.trusted_confidence(b, domain);
+            let point = [(b.value.clone(), 1.0)];
+            let parts = b.value.as_distribution().unwrap_or(&point);
+            for (value, p) in parts {
+                match outcomes.iter_mut().find(|(v, _)| v == value) {
+                    Some((_, mass)) => *mass += weight * p,
+                    None => outcomes.push((value.clone(), weight * p)),
+                }
+            }
+            total += weight;
+        }
+        if total <= 0.0 {
+            return None;
+        }
+        for (_, mass) in &mut outcomes {
+            *mass /= total;
+        }
+        outcomes.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Some(outcomes)
+    }
+
+    /// Confidence of the claim `winner` makes, given the competing `beliefs`.
+    fn claim_confidence(
+        &self,
+        beliefs: &[Belief],
+        winner: &Belief,
+        domain: Option<&str>,
+        aggregation: ConfidenceAggregation,
+    ) -> f32 {
+        match aggregation {
+            ConfidenceAggregation::Max => self.trusted_confidence(winner, domain),
+            ConfidenceAggregation::NoisyOr => {
+                let mut per_source = HashMap::new();
+                for b in beliefs.iter().filter(|b| b.value == winner.value) {
+                    let conf = self.trusted_confidence(b, domain);
+                    let best = per_source.entry(b.source.source_id()).or_insert(0.0f32);
+                    *best = best.max(conf);
+                }
+                EvidenceCombination::aggregate(per_source.into_values())
+            }
+        }
+    }
+
+    /// Order two equally scored beliefs under `tie_break`; `Less` prefers `a`.
+    fn tie_break_cmp(
+        &self,
+        tie_break: TieBreak,
+        a: &Belief,
+        b: &Belief,
+        domain: Option<&str>,
+    ) -> std::cmp::Ordering {
+        tie_break.compare(a, b, |x| self.trust_weight(&x.source, domain))
     }
 
     fn decide_with_trust(
         &self,
         policy: &ConflictResolutionPolicy,
+        tie_break: TieBreak,
         beliefs: &[Belief],
         domain: Option<&str>,
     ) -> PolicyDecision {
@@ -245,6 +882,10 @@ impl KyroEngine {
             return PolicyDecision::Unresolved;
         }
 
+        let preferred = |b: &Belief, best: &Belief| {
+            self.tie_break_cmp(tie_break, b, best, domain) == std::cmp::Ordering::Less
+        };
+
         match policy {
             ConflictResolutionPolicy::ExplicitConflict => PolicyDecision::Unresolved,
             ConflictResolutionPolicy::LatestWins => {
@@ -255,7 +896,7 @@ impl KyroEngine {
                     } else if b.tx_time == best.tx_time {
                         let tc = self.trusted_confidence(b, domain);
                         let bc = self.trusted_confidence(best, domain);
-                        if tc > bc || (tc == bc && b.id.to_string() < best.id.to_string()) {
+                        if tc > bc || (tc == bc && preferred(b, best)) {
                             best = b;
                         }
                     }
@@ -267,16 +908,9 @@ impl KyroEngine {
                 let mut best_score = self.trusted_confidence(best, domain);
                 for b in &beliefs[1..] {
                     let score = self.trusted_confidence(b, domain);
-                    if score > best_score {
+                    if score > best_score || (score == best_score && preferred(b, best)) {
                         best = b;
                         best_score = score;
-                    } else if score == best_score {
-                        if b.tx_time > best.tx_time
-                            || (b.tx_time == best.tx_time && b.id.to_string() < best.id.to_string())
-                        {
-                            best = b;
-                            best_score = score;
-                        }
                     }
                 }
                 PolicyDecision::Selected(best.id)
@@ -302,18 +936,11 @@ impl KyroEngine {
                         best = b;
                         best_rank = r;
                         best_score = score;
-                    } else if r == best_rank {
-                        if score > best_score {
-                            best = b;
-                            best_score = score;
-                        } else if score == best_score {
-                            if b.tx_time > best.tx_time
-                                || (b.tx_time == best.tx_time && b.id.to_string() < best.id.to_string())
-                            {
-                                best = b;
-                                best_score = score;
-                            }
-                        }
+                    } else if r == best_rank
+                        && (score > best_score || (score == best_score && preferred(b, best)))
+                    {
+                        best = b;
+                        best_score = score;
                     }
                 }
 
@@ -326,17 +953,185 @@ impl KyroEngine {
     pub fn execute(&self, ir: KyroIR) -> KyroResult<EngineResponse> {
         // Defensive validation for deserialized IR.
         // Builders already validate, but server/embedded execution must not trust inputs.
-        ir.operation.validate().map_err(KyroError::from)?;
+        ir.operation
+            .validate_with(&self.validation_limits)
+            .map_err(KyroError::from)?;
 
+        let Some(log) = &self.operation_log else {
+            return self.dispatch(ir);
+        };
+        log.begin(&ir)?;
+        let logged = ir.clone();
+        let response = self.dispatch(ir)?;
+        log.append(&LoggedOperation::new(logged, &response))?;
+        Ok(response)
+    }
+
+    fn dispatch(&self, ir: KyroIR) -> KyroResult<EngineResponse> {
         match ir.operation {
-            Operation::Assert(payload) => self.execute_assert(ir.timestamp, payload.consistency_mode, payload.entity_id, payload.predicate, payload.value, payload.confidence, payload.source, payload.valid_time, payload.embedding),
-            Operation::Resolve(payload) => self.execute_resolve(payload),
-            Operation::Simulate(payload) => self.execute_simulate(payload),
+            Operation::Assert(payload) => {
+                let started = Instant::now();
+                let response = self.execute_idempotent_assert(ir.timestamp, payload);
+                self.record_timed(
+                    &response,
+                    started,
+                    metrics::ASSERTS_TOTAL,
+                    metrics::ASSERT_ERRORS_TOTAL,
+                    metrics::ASSERT_LATENCY_SECONDS,
+                );
+                response
+            }
+            Operation::Resolve(payload) => {
+                let started = Instant::now();
+                let response = match self.belief_snapshot() {
+                    Ok(Some(beliefs)) => KyroEngine { beliefs, ..self.clone() }.execute_resolve(payload),
+                    Ok(None) => self.execute_resolve(payload),
+                    Err(err) => Err(err),
+                };
+                self.record_timed(
+                    &response,
+                    started,
+                    metrics::RESOLVES_TOTAL,
+                    metrics::RESOLVE_ERRORS_TOTAL,
+                    metrics::RESOLVE_LATENCY_SECONDS,
+                );
+                response
+            }
+            Operation::Simulate(payload) => self.execute_simulate(ir.request_id, payload),
             Operation::Monitor(payload) => self.execute_monitor(payload),
             Operation::Derive(payload) => self.execute_derive(ir.timestamp, payload),
             Operation::Retract(payload) => self.execute_retract(ir.timestamp, payload),
             Operation::DefinePattern(payload) => self.execute_define_pattern(payload),
+            Operation::UpdatePattern(payload) => self.execute_update_pattern(payload),
+            Operation::DeactivatePattern(pattern_id) => self.execute_deactivate_pattern(pattern_id),
+            Operation::CheckPatternSet(predicate) => Ok(EngineResponse::CheckPatternSet {
+                warnings: self.validate_pattern_set(&predicate)?,
+            }),
+            Operation::Feedback(payload) => self.execute_feedback(payload),
+            Operation::ResolveCompound(payload) => self.execute_resolve_compound(payload),
+            Operation::ResolveMulti(payload) => {
+                let started = Instant::now();
+                let response = match self.belief_snapshot() {
+                    Ok(Some(beliefs)) => KyroEngine { beliefs, ..self.clone() }.execute_resolve_multi(payload),
+                    Ok(None) => self.execute_resolve_multi(payload),
+                    Err(err) => Err(err),
+                };
+                self.record_timed(
+                    &response,
+                    started,
+                    metrics::RESOLVES_TOTAL,
+                    metrics::RESOLVE_ERRORS_TOTAL,
+                    metrics::RESOLVE_LATENCY_SECONDS,
+                );
+                response
+            }
+            Operation::Transaction(operations) => self.execute_transaction(ir.timestamp, operations),
+        }
+    }
+
+    /// The belief store snapshot RESOLVE reads from, if snapshot reads are enabled and supported.
+    fn belief_snapshot(&self) -> KyroResult<Option<Arc<dyn BeliefStore>>> {
+        if !self.snapshot_reads {
+            return Ok(None);
         }
+        self.beliefs.snapshot().map_err(Self::storage_err)
+    }
+
+    /// Count `result` under `ok` or `err`, and record its latency under `latency`.
+    fn record_timed<T>(
+        &self,
+        result: &KyroResult<T>,
+        started: Instant,
+        ok: &'static str,
+        err: &'static str,
+        latency: &'static str,
+    ) {
+        self.metrics
+            .increment_counter(if result.is_ok() { ok } else { err }, 1);
+        self.metrics
+            .observe_histogram(latency, started.elapsed().as_secs_f64());
+    }
+
+    /// Report an ASSERT to the monitor, or hold it back while staging a transaction.
+    fn observe_assert(&self, observation: AssertObservation) {
+        match &self.held_observations {
+            Some(held) => match held.lock() {
+                Ok(mut held) => held.push(observation),
+                Err(poisoned) => poisoned.into_inner().push(observation),
+            },
+            None => self.monitor.observe_assert(observation),
+        }
+    }
+
+    /// Run an ASSERT, short-circuiting retries that carry an already-seen idempotency key.
+    ///
+    /// The key is reserved before the write, so a concurrent retry fails instead of writing a
+    /// second belief. The belief carries the key, so a persistent store records both in one
+    /// write. A failed write or record releases the key, so retries are not rejected forever.
+    fn execute_idempotent_assert(
+        &self,
+        tx_time: DateTime<Utc>,
+        payload: AssertPayload,
+    ) -> KyroResult<EngineResponse> {
+        let key = payload.idempotency_key;
+        let namespace = payload.namespace;
+        if let Some(key) = key.as_deref() {
+            let reservation = self
+                .idempotency
+                .reserve(namespace.as_deref(), key)
+                .map_err(Self::storage_err)?;
+            if let IdempotencyReservation::Existing(belief_id) = reservation {
+                self.metrics
+                    .increment_counter(metrics::IDEMPOTENT_REPLAYS_TOTAL, 1);
+                return Ok(EngineResponse::Assert {
+                    belief_id,
+                    conflict_ids: Vec::new(),
+                    conflict_warnings: Vec::new(),
+                });
+            }
+        }
+
+        let result = self.execute_assert(
+            tx_time,
+            payload.consistency_mode,
+            namespace.as_deref(),
+            payload.entity_id,
+            payload.predicate,
+            payload.value,
+            payload.confidence,
+            payload.source,
+            payload.valid_time,
+            payload.embedding,
+            key.clone(),
+        );
+        let response = match (result, key.as_deref()) {
+            (Ok(response), _) => response,
+            (Err(err), Some(key)) => {
+                self.idempotency
+                    .release(namespace.as_deref(), key)
+                    .map_err(Self::storage_err)?;
+                return Err(err);
+            }
+            (Err(err), None) => return Err(err),
+        };
+
+        match (key.as_deref(), &response) {
+            (Some(key), EngineResponse::Assert { belief_id, .. }) => {
+                if let Err(err) = self.idempotency.record(namespace.as_deref(), key, *belief_id) {
+                    self.idempotency
+                        .release(namespace.as_deref(), key)
+                        .map_err(Self::storage_err)?;
+                    return Err(Self::storage_err(err));
+                }
+            }
+            (Some(key), _) => self
+                .idempotency
+                .release(namespace.as_deref(), key)
+                .map_err(Self::storage_err)?,
+            (None, _) => {}
+        }
+
+        Ok(response)
     }
 
     fn execute_derive(&self, tx_time: DateTime<Utc>, payload: DerivePayload) -> KyroResult<EngineResponse> {
@@ -364,31 +1159,17 @@ impl KyroEngine {
         }
 
         if let Some(derived) = payload.derived_belief_id {
-            if premise_ids.iter().any(|p| *p == derived) {
+            if premise_ids.contains(&derived) {
                 return Err(KyroError::Execution(ExecutionError::InvalidDerivation {
                     reason: "derived_belief_id must not appear in premise_ids".to_string(),
                 }));
             }
 
-            let exists = self
-                .beliefs
-                .get(derived)
-                .map_err(Self::storage_err)?
-                .is_some();
-            if !exists {
-                return Err(KyroError::Execution(ExecutionError::BeliefNotFound { id: derived }));
-            }
+            self.belief_in_namespace(derived, payload.namespace.as_deref())?;
         }
 
         for premise in &premise_ids {
-            let exists = self
-                .beliefs
-                .get(*premise)
-                .map_err(Self::storage_err)?
-                .is_some();
-            if !exists {
-                return Err(KyroError::Execution(ExecutionError::BeliefNotFound { id: *premise }));
-            }
+            self.belief_in_namespace(*premise, payload.namespace.as_deref())?;
         }
 
         let steps = payload.inference_steps.unwrap_or_default();
@@ -421,11 +1202,13 @@ impl KyroEngine {
             payload.pattern_filter.as_deref(),
         )?;
 
-        let registration = self.monitor.register(triggers, payload.expires_at)?;
+        let registration = self
+            .monitor
+            .register(triggers, payload.expires_at, payload.namespace.as_deref())?;
         Ok(EngineResponse::Monitor { registration })
     }
 
-    fn execute_simulate(&self, payload: SimulatePayload) -> KyroResult<EngineResponse> {
+    fn execute_simulate(&self, request_id: uuid::Uuid, payload: SimulatePayload) -> KyroResult<EngineResponse> {
         let constraints = match payload.constraints {
             None => SimulateConstraints::default(),
             Some(Value::Null) => SimulateConstraints::default(),
@@ -457,7 +1240,7 @@ impl KyroEngine {
             conflicts: Arc::clone(&self.conflicts),
         };
 
-        let ctx = SimulationContext::new(base, constraints)?;
+        let ctx = SimulationContext::for_request(base, constraints, request_id)?;
         Ok(EngineResponse::Simulate {
             simulation: Arc::new(ctx),
         })
@@ -476,11 +1259,68 @@ impl KyroEngine {
         }
     }
 
+    /// Fetch an entity visible from `namespace`.
+    ///
+    /// Entities of other namespaces are reported as not found so ids cannot be probed across
+    /// tenants.
+    fn entity_in_namespace(&self, id: EntityId, namespace: Option<&str>) -> KyroResult<Entity> {
+        match self.entities.get(id).map_err(Self::storage_err)? {
+            Some(entity) if entity.namespace.as_deref() == namespace => Ok(entity),
+            _ => Err(KyroError::Execution(ExecutionError::EntityNotFound { id })),
+        }
+    }
+
+    /// Fetch a belief visible from `namespace`; like entities, beliefs of other namespaces are
+    /// reported as not found.
+    fn belief_in_namespace(&self, id: BeliefId, namespace: Option<&str>) -> KyroResult<Belief> {
+        match self.beliefs.get(id).map_err(Self::storage_err)? {
+            Some(belief) if belief.namespace.as_deref() == namespace => Ok(belief),
+            _ => Err(KyroError::Execution(ExecutionError::BeliefNotFound { id })),
+        }
+    }
+
+    /// Beliefs about `entity_id`'s canonical entity for `predicate` valid at `as_of`, newest first.
+    ///
+    /// Beliefs asserted against entities that were later merged into it keep their original
+    /// subject, so every ID of the merge family is consulted.
+    fn find_as_of_merged(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+        as_of: DateTime<Utc>,
+    ) -> KyroResult<Vec<Belief>> {
+        let mut beliefs = Vec::new();
+        for subject in self.entities.merged_ids(entity_id).map_err(Self::storage_err)? {
+            beliefs.extend(
+                self.beliefs
+                    .find_as_of(subject, predicate, as_of)
+                    .map_err(Self::storage_err)?,
+            );
+        }
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
+        Ok(beliefs)
+    }
+
+    /// Every belief ever recorded for `predicate` across `entity_id`'s merge family, oldest first.
+    fn history_merged(&self, entity_id: EntityId, predicate: &str) -> KyroResult<Vec<Belief>> {
+        let mut beliefs = Vec::new();
+        for subject in self.entities.merged_ids(entity_id).map_err(Self::storage_err)? {
+            beliefs.extend(
+                self.beliefs
+                    .history(subject, predicate)
+                    .map_err(Self::storage_err)?,
+            );
+        }
+        crate::storage::sort_history(&mut beliefs);
+        Ok(beliefs)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn execute_assert(
         &self,
         tx_time: DateTime<Utc>,
         mode: ConsistencyMode,
+        namespace: Option<&str>,
         entity_id: EntityId,
         predicate: String,
         value: Value,
@@ -488,21 +1328,54 @@ impl KyroEngine {
         source: crate::source::Source,
         valid_time: TimeRange,
         embedding: Option<Vec<f32>>,
+        idempotency_key: Option<String>,
     ) -> KyroResult<EngineResponse> {
-        self.ensure_entity_exists(entity_id)?;
+        let entity = self.entity_in_namespace(entity_id, namespace)?;
+        self.schemas.check(predicate.trim(), &value)?;
+
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.try_acquire(source.source_id()).map_err(|exceeded| {
+                KyroError::Execution(ExecutionError::QueueFull {
+                    path: "assert rate limit".to_string(),
+                    capacity: exceeded.capacity,
+                })
+            })?;
+        }
+
+        if self.merge_duplicate_sources && !mode.is_replace() {
+            if let Some(belief_id) = self.merge_duplicate(
+                tx_time,
+                &entity,
+                predicate.trim(),
+                &value,
+                &confidence,
+                &source,
+                &valid_time,
+                idempotency_key.as_deref(),
+            )? {
+                return Ok(EngineResponse::Assert {
+                    belief_id,
+                    conflict_ids: Vec::new(),
+                    conflict_warnings: Vec::new(),
+                });
+            }
+        }
 
         // Deterministic embedding generation.
-        // If an embedding is not provided, generate one from the entity name + predicate + value.
+        // If an embedding is not provided, generate one from the configured text template.
         let embedding = match embedding {
             Some(v) => Some(v),
             None => {
-                let entity = self
-                    .entities
-                    .get(entity_id)
-                    .map_err(Self::storage_err)?
-                    .ok_or(KyroError::Execution(ExecutionError::EntityNotFound { id: entity_id }))?;
-                let text = format!("{} {} {}", entity.canonical_name, predicate.trim(), value);
-                Some(crate::embedding::lexical_embedding(&text))
+                let text = (self.embedding_text)(&entity, predicate.trim(), &value);
+                let generated = self.embedder.embed(&text)?;
+                if generated.len() != self.embedder.dim() {
+                    return Err(ValidationError::InvalidEmbeddingDimension {
+                        actual: generated.len(),
+                        expected: self.embedder.dim(),
+                    }
+                    .into());
+                }
+                Some(generated)
             }
         };
 
@@ -513,7 +1386,7 @@ impl KyroEngine {
 
         let mut belief = Belief {
             id: BeliefId::new(),
-            subject: entity_id,
+            subject: entity.id,
             predicate: predicate.clone(),
             value: value.clone(),
             confidence: confidence.clone(),
@@ -522,17 +1395,41 @@ impl KyroEngine {
             tx_time,
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding,
+            namespace: entity.namespace,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key,
+            retraction: false,
         };
 
         let belief_id = belief.id;
 
+        // Replace mode supersedes every active belief for (entity, predicate), newest first.
+        let replaced: Vec<Belief> = if mode.is_replace() {
+            let mut active = Vec::new();
+            for subject in self.entities.merged_ids(entity.id).map_err(Self::storage_err)? {
+                active.extend(
+                    self.beliefs
+                        .find_by_entity_predicate(subject, &predicate)
+                        .map_err(Self::storage_err)?
+                        .into_iter()
+                        .filter(Belief::is_active),
+                );
+            }
+            active.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
+            active
+        } else {
+            Vec::new()
+        };
+        let replaced_ids: HashSet<BeliefId> = replaced.iter().map(|b| b.id).collect();
+
         if mode.is_force() {
             self.beliefs.insert(belief).map_err(Self::storage_err)?;
 
-            self.monitor.observe_assert(AssertObservation {
+            self.observe_assert(AssertObservation {
                 tx_time,
                 belief_id,
                 entity_id,
@@ -540,17 +1437,23 @@ impl KyroEngine {
                 value: value.clone(),
                 confidence: confidence.value(),
                 conflict_types: Vec::new(),
+                namespace: namespace.map(str::to_string),
             });
 
             return Ok(EngineResponse::Assert {
                 belief_id,
                 conflict_ids: Vec::new(),
+                conflict_warnings: Vec::new(),
             });
         }
 
-        let conflicts = self.detect_conflicts(&belief, tx_time)?;
+        let conflicts = self.detect_conflicts(&belief, tx_time, &replaced_ids)?;
+        if !conflicts.is_empty() {
+            self.metrics
+                .increment_counter(metrics::CONFLICTS_DETECTED_TOTAL, conflicts.len() as u64);
+        }
 
-        if mode.is_strict() && !conflicts.is_empty() {
+        if (mode.is_strict() || mode.is_replace()) && !conflicts.is_empty() {
             return Err(KyroError::Execution(ExecutionError::ConflictsDetected {
                 conflicts: conflicts
                     .iter()
@@ -562,9 +1465,16 @@ impl KyroEngine {
         // When checks pass, mark the belief as verified.
         if conflicts.is_empty() {
             belief.consistency_status = ConsistencyStatus::Verified;
-            self.beliefs.insert(belief).map_err(Self::storage_err)?;
+            if replaced.is_empty() {
+                self.beliefs.insert(belief).map_err(Self::storage_err)?;
+            } else {
+                let replaced: Vec<BeliefId> = replaced.iter().map(|b| b.id).collect();
+                self.beliefs
+                    .insert_superseding(belief, &replaced)
+                    .map_err(Self::storage_err)?;
+            }
 
-            self.monitor.observe_assert(AssertObservation {
+            self.observe_assert(AssertObservation {
                 tx_time,
                 belief_id,
                 entity_id,
@@ -572,23 +1482,27 @@ impl KyroEngine {
                 value: value.clone(),
                 confidence: confidence.value(),
                 conflict_types: Vec::new(),
+                namespace: namespace.map(str::to_string),
             });
 
             return Ok(EngineResponse::Assert {
                 belief_id,
                 conflict_ids: Vec::new(),
+                conflict_warnings: Vec::new(),
             });
         }
 
-        // Eventual mode records conflicts and writes contested belief.
+        // Eventual mode records conflicts and writes contested belief; Warn only reports them.
         // Insert conflicts before the belief so the belief never points at missing conflicts.
         let mut conflict_ids: Vec<ConflictId> = Vec::new();
+        let conflict_warnings: Vec<String> = if mode.is_warn() {
+            conflicts.iter().map(|c| c.conflict_type.to_string()).collect()
+        } else {
+            Vec::new()
+        };
         if mode.is_eventual() {
             for conflict in &conflicts {
-                self.conflicts
-                    .insert(conflict.clone())
-                    .map_err(Self::storage_err)?;
-                conflict_ids.push(conflict.id);
+                conflict_ids.push(self.record_conflict(conflict)?);
             }
         }
 
@@ -597,10 +1511,10 @@ impl KyroEngine {
         };
         self.beliefs.insert(belief).map_err(Self::storage_err)?;
 
-        let conflict_types: Vec<crate::conflict::ConflictType> =
+        let conflict_types: Vec<ConflictType> =
             conflicts.iter().map(|c| c.conflict_type.clone()).collect();
 
-        self.monitor.observe_assert(AssertObservation {
+        self.observe_assert(AssertObservation {
             tx_time,
             belief_id,
             entity_id,
@@ -608,14 +1522,86 @@ impl KyroEngine {
             value: value.clone(),
             confidence: confidence.value(),
             conflict_types,
+            namespace: namespace.map(str::to_string),
         });
 
         Ok(EngineResponse::Assert {
             belief_id,
             conflict_ids,
+            conflict_warnings,
         })
     }
 
+    /// Fold an ASSERT into the active belief already holding `value`, if there is one that
+    /// `source` does not back yet. Returns the ID of the belief merged into.
+    #[allow(clippy::too_many_arguments)]
+    fn merge_duplicate(
+        &self,
+        tx_time: DateTime<Utc>,
+        entity: &Entity,
+        predicate: &str,
+        value: &Value,
+        confidence: &Confidence,
+        source: &crate::source::Source,
+        valid_time: &TimeRange,
+        idempotency_key: Option<&str>,
+    ) -> KyroResult<Option<BeliefId>> {
+        let mut existing = None;
+        for subject in self.entities.merged_ids(entity.id).map_err(Self::storage_err)? {
+            existing = self
+                .beliefs
+                .find_by_entity_predicate(subject, predicate)
+                .map_err(Self::storage_err)?
+                .into_iter()
+                .filter(|b| b.is_active() && &b.value == value && &b.valid_time == valid_time)
+                .max_by_key(|b| b.tx_time);
+            if existing.is_some() {
+                break;
+            }
+        }
+        let Some(existing) = existing else {
+            return Ok(None);
+        };
+        let source_id = source.source_id();
+        if existing.sources().any(|s| s.source_id() == source_id) {
+            return Ok(None);
+        }
+
+        // The store folds the source in under its own lock: two sources corroborating the same
+        // belief at once must both be kept.
+        self.beliefs
+            .amend(
+                existing.id,
+                AmendFields {
+                    corroboration: Some(Corroboration {
+                        source: source.clone(),
+                        confidence: confidence.value(),
+                        idempotency_key: idempotency_key.map(str::to_string),
+                    }),
+                    audit_note: Some(format!("corroborated by {source}")),
+                    ..AmendFields::default()
+                },
+            )
+            .map_err(Self::storage_err)?;
+        let merged_value = self
+            .beliefs
+            .get(existing.id)
+            .map_err(Self::storage_err)?
+            .map_or(existing.confidence.value(), |b| b.confidence.value());
+
+        self.observe_assert(AssertObservation {
+            tx_time,
+            belief_id: existing.id,
+            entity_id: existing.subject,
+            predicate: existing.predicate,
+            value: existing.value,
+            confidence: merged_value,
+            conflict_types: Vec::new(),
+            namespace: existing.namespace,
+        });
+        Ok(Some(existing.id))
+    }
+
     fn execute_define_pattern(&self, payload: DefinePatternPayload) -> KyroResult<EngineResponse> {
         let name = payload.name.trim();
         if name.is_empty() {
@@ -625,24 +1611,172 @@ impl KyroEngine {
             .into());
         }
 
+        self.check_pattern_rule(&payload.rule)?;
+
         let mut pattern = Pattern::new(name, payload.rule, payload.confidence);
         pattern.description = payload.description;
         pattern.valid_time = payload.valid_time;
         pattern.active = true;
 
         self.patterns.insert(pattern.clone()).map_err(Self::storage_err)?;
+        self.pattern_cache.invalidate()?;
 
         Ok(EngineResponse::DefinePattern {
             pattern_id: pattern.id,
         })
     }
 
-    fn execute_retract(&self, tx_time: DateTime<Utc>, payload: RetractPayload) -> KyroResult<EngineResponse> {
-        let Some(old) = self.beliefs.get(payload.belief_id).map_err(Self::storage_err)? else {
-            return Err(KyroError::Execution(ExecutionError::BeliefNotFound {
-                id: payload.belief_id,
-            }));
+    /// Reject rules the engine could not evaluate.
+    fn check_pattern_rule(&self, rule: &PatternRule) -> KyroResult<()> {
+        if let PatternRule::Custom { name: rule_name, .. } = rule {
+            if self.custom_rules.get(rule_name)?.is_none() {
+                return Err(ValidationError::InvalidPatternRule {
+                    reason: format!("custom rule '{rule_name}' is not registered"),
+                }
+                .into());
+            }
+        }
+        if let PatternRule::CrossPredicateOrder {
+            predicate,
+            other_predicate,
+            ..
+        } = rule
+        {
+            if predicate.trim() == other_predicate.trim() {
+                return Err(ValidationError::InvalidPatternRule {
+                    reason: format!("order rule compares '{}' with itself", predicate.trim()),
+                }
+                .into());
+            }
+        }
+        if let PatternRule::JsonPath { path, .. } = rule {
+            if !path.is_empty() && !path.starts_with('/') {
+                return Err(ValidationError::InvalidPatternRule {
+                    reason: format!("json path '{path}' must be a JSON pointer starting with '/'"),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn stored_pattern(&self, pattern_id: PatternId) -> KyroResult<Pattern> {
+        self.patterns
+            .get(pattern_id)
+            .map_err(Self::storage_err)?
+            .ok_or_else(|| Self::storage_err(StorageError::PatternNotFound(pattern_id)))
+    }
+
+    fn execute_update_pattern(&self, payload: UpdatePatternPayload) -> KyroResult<EngineResponse> {
+        let mut pattern = self.stored_pattern(payload.pattern_id)?;
+        if let Some(name) = payload.name {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(ValidationError::MissingField {
+                    field: "name".to_string(),
+                }
+                .into());
+            }
+            pattern.name = name.to_string();
+        }
+        if let Some(rule) = payload.rule {
+            self.check_pattern_rule(&rule)?;
+            pattern.rule = rule;
+        }
+        if let Some(description) = payload.description {
+            pattern.description = Some(description);
+        }
+        if let Some(confidence) = payload.confidence {
+            pattern.confidence = confidence;
+        }
+        if let Some(valid_time) = payload.valid_time {
+            pattern.valid_time = valid_time;
+        }
+
+        self.patterns.update(pattern).map_err(Self::storage_err)?;
+        self.pattern_cache.invalidate()?;
+        Ok(EngineResponse::UpdatePattern {
+            pattern_id: payload.pattern_id,
+        })
+    }
+
+    fn execute_deactivate_pattern(&self, pattern_id: PatternId) -> KyroResult<EngineResponse> {
+        let mut pattern = self.stored_pattern(pattern_id)?;
+        if pattern.active {
+            pattern.deactivate();
+            self.patterns.update(pattern).map_err(Self::storage_err)?;
+            self.pattern_cache.invalidate()?;
+        }
+        Ok(EngineResponse::DeactivatePattern { pattern_id })
+    }
+
+    /// Find active patterns on `predicate` that no value can satisfy together.
+    ///
+    /// See [`find_contradictions`] for what is detected. Defining such patterns is allowed;
+    /// this only reports them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern store fails.
+    pub fn validate_pattern_set(&self, predicate: &str) -> KyroResult<Vec<PatternSetWarning>> {
+        let mut patterns = self
+            .patterns
+            .find_by_predicate(predicate.trim())
+            .map_err(Self::storage_err)?;
+        patterns.retain(Pattern::is_active);
+        patterns.sort_by_key(|p| p.created_at);
+        Ok(find_contradictions(predicate, &patterns))
+    }
+
+    /// Regenerate the embedding of every stored belief that has one with `embedder`,
+    /// returning how many were replaced.
+    ///
+    /// This is the migration path to an embedder of another dimension: embeddings are built
+    /// from the engine's embedding text, as on ASSERT, and the store switches to the new
+    /// dimension in one step. Afterwards, use an engine built [`with_embedder`](Self::with_embedder)
+    /// the same embedder so new ASSERTs and semantic queries match the stored beliefs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the embedder's error, `InvalidEmbeddingDimension` if it returns a vector of
+    /// another length than its `dim()`, or a storage error. Nothing is changed on error.
+    pub fn reembed_beliefs(&self, embedder: &dyn Embedder) -> KyroResult<usize> {
+        // The store callback can only return a `StorageError`; keep the real cause here.
+        let failure = std::cell::RefCell::new(None);
+        let embed = |belief: &Belief| -> Result<Vec<f32>, StorageError> {
+            let embedded = self
+                .entities
+                .get(belief.subject)
+                .map_err(Self::storage_err)
+                .and_then(|entity| {
+                    let entity =
+                        entity.ok_or(KyroError::Execution(ExecutionError::EntityNotFound { id: belief.subject }))?;
+                    let text = (self.embedding_text)(&entity, &belief.predicate, &belief.value);
+                    let embedding = embedder.embed(&text)?;
+                    if embedding.len() != embedder.dim() {
+                        return Err(ValidationError::InvalidEmbeddingDimension {
+                            actual: embedding.len(),
+                            expected: embedder.dim(),
+                        }
+                        .into());
+                    }
+                    Ok(embedding)
+                });
+            embedded.map_err(|err| {
+                let message = err.to_string();
+                *failure.borrow_mut() = Some(err);
+                StorageError::BackendError(message)
+            })
         };
+        let result = self.beliefs.reembed(&embed);
+        match failure.into_inner() {
+            Some(err) => Err(err),
+            None => result.map_err(Self::storage_err),
+        }
+    }
+
+    fn execute_retract(&self, tx_time: DateTime<Utc>, payload: RetractPayload) -> KyroResult<EngineResponse> {
+        let old = self.belief_in_namespace(payload.belief_id, payload.namespace.as_deref())?;
 
         // Create a retraction belief that supersedes the old one.
         let retraction = Belief {
@@ -656,9 +1790,14 @@ impl KyroEngine {
             tx_time,
             reason: payload.reason.clone(),
             consistency_status: ConsistencyStatus::Verified,
-            supersedes: Some(old.id),
+            supersedes: vec![old.id],
             superseded_by: None,
             embedding: None,
+            namespace: old.namespace,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: true,
         };
 
         self.beliefs.insert(retraction.clone()).map_err(Self::storage_err)?;
@@ -671,39 +1810,253 @@ impl KyroEngine {
         })
     }
 
-    fn execute_resolve(&self, payload: ResolvePayload) -> KyroResult<EngineResponse> {
+    fn execute_resolve_compound(&self, payload: ResolveCompoundPayload) -> KyroResult<EngineResponse> {
         let as_of = payload.as_of.unwrap_or_else(Utc::now);
         let min_conf = payload.min_confidence.unwrap_or(0.0).clamp(0.0, 1.0);
-        let policy = payload
-            .conflict_policy
-            .clone()
-            .unwrap_or_else(ConflictResolutionPolicy::default);
-        let mut trust_domain = payload.trust_domain.as_deref();
+        let namespace = payload.namespace.as_deref();
+        let policy = ConflictResolutionPolicy::default();
+        let tie_break = TieBreak::default();
 
-        // Conservative entity resolution from query.
-        // We only auto-resolve if:
-        // - entity_id was not provided
-        // - query looks like an entity name (short, no '?')
-        // - fuzzy search yields exactly one candidate
-        let mut entity_id = payload.entity_id;
-        if entity_id.is_none() {
-            if let Some(q) = payload.query.as_deref() {
-                let q = q.trim();
-                let looks_like_name = !q.is_empty()
-                    && q.len() <= 80
-                    && !q.contains('?')
-                    && q.split_whitespace().count() <= 6;
-                if looks_like_name {
-                    let candidates = self
-                        .entities
-                        .find_by_name_fuzzy(q, 2)
-                        .map_err(Self::storage_err)?;
-                    if candidates.len() == 1 {
-                        entity_id = Some(candidates[0].id);
+        // One scan of the namespace, bucketed by (entity, predicate) for the predicates in play.
+        let predicates: HashSet<&str> = payload
+            .conditions
+            .iter()
+            .map(|c| c.predicate.trim())
+            .collect();
+        let mut candidates: HashMap<(EntityId, &str), Vec<Belief>> = HashMap::new();
+        let mut canonical: HashMap<EntityId, EntityId> = HashMap::new();
+        for belief in self
+            .beliefs
+            .find_by_time_range(namespace, &TimeRange::instant(as_of))
+            .map_err(Self::storage_err)?
+        {
+            if !belief.is_valid_at(as_of) || belief.confidence.value() < min_conf {
+                continue;
+            }
+            if let Some(predicate) = predicates.get(belief.predicate.as_str()) {
+                // Beliefs about merged-away entities count toward the entity they merged into.
+                let entity_id = match canonical.get(&belief.subject) {
+                    Some(id) => *id,
+                    None => {
+                        let id = self
+                            .entities
+                            .get(belief.subject)
+                            .map_err(Self::storage_err)?
+                            .map_or(belief.subject, |e| e.id);
+                        canonical.insert(belief.subject, id);
+                        id
                     }
+                };
+                candidates
+                    .entry((entity_id, predicate))
+                    .or_default()
+                    .push(belief);
+            }
+        }
+
+        let mut entities: Vec<EntityId> = candidates.keys().map(|(id, _)| *id).collect();
+        entities.sort_unstable_by_key(|id| *id.as_uuid());
+        entities.dedup();
+
+        let mut frame = CompoundFrame {
+            matches: Vec::new(),
+            as_of,
+            truncated: false,
+        };
+        'entities: for entity_id in entities {
+            let mut held = Vec::with_capacity(payload.conditions.len());
+            for condition in &payload.conditions {
+                let predicate = condition.predicate.trim();
+                let Some(beliefs) = candidates.get(&(entity_id, predicate)) else {
+                    continue 'entities;
+                };
+                let PolicyDecision::Selected(winner_id) =
+                    self.decide_with_trust(&policy, tie_break, beliefs, Some(predicate))
+                else {
+                    continue 'entities;
+                };
+                let Some(winner) = beliefs.iter().find(|b| b.id == winner_id) else {
+                    continue 'entities;
+                };
+                if !Self::value_matches(&condition.matcher, &winner.value)? {
+                    continue 'entities;
+                }
+                held.push(winner.clone());
+            }
+
+            if frame.matches.len() == payload.limit {
+                frame.truncated = true;
+                break;
+            }
+            frame.matches.push(CompoundMatch {
+                entity_id,
+                beliefs: held,
+            });
+        }
+
+        Ok(EngineResponse::ResolveCompound { frame })
+    }
+
+    /// The entities a compound RESOLVE returns, in the order it returns them.
+    #[cfg(feature = "transport-grpc")]
+    pub(crate) fn compound_matches(&self, payload: &ResolveCompoundPayload) -> KyroResult<Vec<EntityId>> {
+        let EngineResponse::ResolveCompound { frame } = self.execute_resolve_compound(payload.clone())? else {
+            return Err(KyroError::internal("compound RESOLVE produced a different response"));
+        };
+        Ok(frame.matches.into_iter().map(|m| m.entity_id).collect())
+    }
+
+    fn execute_resolve_multi(&self, payload: ResolveMultiPayload) -> KyroResult<EngineResponse> {
+        let mut frames = HashMap::with_capacity(payload.entity_ids.len());
+        for &entity_id in &payload.entity_ids {
+            let EngineResponse::Resolve { frame } = self.execute_resolve(payload.resolve_for(entity_id))? else {
+                return Err(KyroError::internal("RESOLVE produced a non-RESOLVE response"));
+            };
+            frames.insert(entity_id, frame);
+        }
+        Ok(EngineResponse::ResolveMulti { frames })
+    }
+
+    fn value_matches(matcher: &ValueMatcher, value: &Value) -> KyroResult<bool> {
+        Ok(match matcher {
+            ValueMatcher::Equals { value: expected } => value == expected,
+            ValueMatcher::Compare { cmp, threshold } => value
+                .as_float()
+                .is_some_and(|v| cmp.holds(v, *threshold)),
+            ValueMatcher::Regex { pattern } => match value.as_string() {
+                Some(s) => cached_regex(pattern)?.is_match(s),
+                None => false,
+            },
+        })
+    }
+
+    fn execute_feedback(&self, payload: FeedbackPayload) -> KyroResult<EngineResponse> {
+        let belief = self.belief_in_namespace(payload.belief_id, payload.namespace.as_deref())?;
+
+        let source_accuracy =
+            self.calibration
+                .record(belief.id, belief.source.source_id(), payload.outcome)?;
+        Ok(EngineResponse::Feedback { source_accuracy })
+    }
+
+    /// RESOLVE `payload.predicate`, then each of `payload.predicate_fallbacks` until one
+    /// yields beliefs. Gaps are only reported, for every predicate tried, if none does.
+    fn execute_resolve(&self, mut payload: ResolvePayload) -> KyroResult<EngineResponse> {
+        if payload.semantic && payload.query_embedding.is_none() {
+            let query = payload.query.as_deref().unwrap_or_default();
+            let embedding = self.embedder.embed(query)?;
+            if embedding.len() != self.embedder.dim() {
+                return Err(ValidationError::InvalidEmbeddingDimension {
+                    actual: embedding.len(),
+                    expected: self.embedder.dim(),
                 }
+                .into());
+            }
+            payload.query_embedding = Some(embedding);
+        }
+
+        let fallbacks = std::mem::take(&mut payload.predicate_fallbacks);
+        if fallbacks.is_empty() {
+            return self.execute_resolve_predicate(payload);
+        }
+
+        let candidates: Vec<Option<String>> = std::iter::once(payload.predicate.clone())
+            .chain(fallbacks.into_iter().map(Some))
+            .collect();
+        let mut gaps = Vec::new();
+        let mut last = None;
+        for predicate in candidates {
+            let response = self.execute_resolve_predicate(ResolvePayload {
+                predicate: predicate.clone(),
+                ..payload.clone()
+            })?;
+            let EngineResponse::Resolve { mut frame } = response else {
+                return Ok(response);
+            };
+            let answered = frame.has_answer()
+                || !frame.supporting_evidence.is_empty()
+                || !frame.history.is_empty();
+            if answered {
+                frame.answered_predicate = predicate;
+                return Ok(EngineResponse::Resolve { frame });
             }
+            gaps.append(&mut frame.gaps);
+            last = Some(frame);
+        }
+
+        let mut frame = last.unwrap_or_else(BeliefFrame::empty);
+        frame.gaps = gaps;
+        Ok(EngineResponse::Resolve { frame })
+    }
+
+    /// The entity a RESOLVE reads: its `entity_id`, or else the entity its query names.
+    ///
+    /// Entity resolution from the query is gated by `EntityResolutionConfig` (conservative by
+    /// default). We only auto-resolve if:
+    /// - entity_id was not provided
+    /// - the query is not semantic text to embed
+    /// - query looks like an entity name (short, few words, no '?' unless allowed)
+    /// - exactly one entity has that canonical name, or else fuzzy search yields at most
+    ///   `max_candidates` candidates scoring `min_score` (by default exactly one, so a
+    ///   near-miss typo cannot make an exact name ambiguous)
+    pub(crate) fn resolve_target(&self, payload: &ResolvePayload) -> KyroResult<Option<EntityId>> {
+        if payload.entity_id.is_some() || payload.semantic {
+            return Ok(payload.entity_id);
+        }
+        let Some(q) = payload.query.as_deref() else {
+            return Ok(None);
+        };
+        let namespace = payload.namespace.as_deref();
+        let gate = payload.entity_resolution.unwrap_or_default();
+        let q = q.trim();
+        let looks_like_name = !q.is_empty()
+            && q.len() <= gate.max_query_len
+            && (gate.allow_questions || !q.contains('?'))
+            && q.split_whitespace().count() <= gate.max_words;
+        if !looks_like_name {
+            return Ok(None);
+        }
+        let exact = self
+            .entities
+            .find_by_name(namespace, q)
+            .map_err(Self::storage_err)?;
+        if let [entity] = exact.as_slice() {
+            return Ok(Some(entity.id));
+        }
+        if gate.max_candidates == 0 {
+            return Ok(None);
         }
+        // Candidates come best first, so the first `max_candidates + 1` that clear
+        // `min_score` tell whether too many do.
+        let query_key = q.to_ascii_lowercase();
+        let candidates: Vec<Entity> = self
+            .entities
+            .find_by_name_fuzzy(namespace, q, gate.max_candidates.saturating_add(1))
+            .map_err(Self::storage_err)?
+            .into_iter()
+            .filter(|e| crate::storage::fuzzy_name_score(&query_key, e) >= gate.min_score)
+            .collect();
+        Ok((1..=gate.max_candidates)
+            .contains(&candidates.len())
+            .then(|| candidates[0].id))
+    }
+
+    fn execute_resolve_predicate(&self, payload: ResolvePayload) -> KyroResult<EngineResponse> {
+        let as_of = payload.as_of.unwrap_or_else(Utc::now);
+        let min_conf = payload.min_confidence.unwrap_or(0.0).clamp(0.0, 1.0);
+        let policy = payload
+            .conflict_policy
+            .clone()
+            .unwrap_or_default();
+        let combination = payload.evidence_combination.unwrap_or_default();
+        let aggregation = payload.confidence_aggregation.unwrap_or_default();
+        let tie_break = payload.tie_break.unwrap_or_default();
+        let relevance_weight = payload.relevance_weight.clamp(0.0, 1.0);
+        let value_filter = payload.value_filter.as_ref();
+        let mut trust_domain = payload.trust_domain.as_deref();
+        let namespace = payload.namespace.as_deref();
+
+        let entity_id = self.resolve_target(&payload)?;
 
         let mut frame = BeliefFrame::empty();
         frame.time_window = TimeRange::instant(as_of);
@@ -713,10 +2066,20 @@ impl KyroEngine {
         frame.query_assumptions.trust_model = self.trust.name().to_string();
 
         // Semantic path (top-k embedding retrieval) if a query embedding is present.
-        if let Some(query_embedding) = payload.query_embedding.as_deref() {
+        // Aggregates and interpolations summarize one predicate of one entity, so they never take it.
+        let aggregate = match payload.mode {
+            ResolveMode::Aggregate { function } => Some(function),
+            _ => None,
+        };
+        let interpolate = payload.mode == ResolveMode::Interpolate;
+        if let Some(query_embedding) = payload
+            .query_embedding
+            .as_deref()
+            .filter(|_| aggregate.is_none() && !interpolate)
+        {
             let mut matches = self
                 .beliefs
-                .find_by_embedding(query_embedding, payload.limit * 4, Some(min_conf))
+                .find_by_embedding(namespace, query_embedding, payload.limit * 4, Some(min_conf))
                 .map_err(Self::storage_err)?;
 
             // Apply AS_OF validity.
@@ -724,8 +2087,9 @@ impl KyroEngine {
 
             // Apply optional filters.
             if let Some(eid) = entity_id {
-                matches.retain(|(b, _)| b.subject == eid);
-                self.ensure_entity_exists(eid)?;
+                self.entity_in_namespace(eid, namespace)?;
+                let subjects = self.entities.merged_ids(eid).map_err(Self::storage_err)?;
+                matches.retain(|(b, _)| subjects.contains(&b.subject));
             }
 
             let predicate_filter = payload
@@ -738,6 +2102,40 @@ impl KyroEngine {
                 matches.retain(|(b, _)| b.predicate == pred);
             }
 
+            // Source filters drop beliefs outright, so they cannot act as counter-evidence either.
+            if Self::has_source_filters(&payload) {
+                let before = matches.len();
+                matches.retain(|(b, _)| Self::source_admitted(&payload, b));
+                if matches.is_empty() && before > 0 {
+                    if payload.include_gaps {
+                        frame.gaps.push(Self::source_filter_gap(entity_id, predicate_filter));
+                    }
+                    return Ok(EngineResponse::Resolve { frame });
+                }
+            }
+
+            // Weak matches are not evidence; if that leaves nothing, say so rather than answer.
+            if let Some(threshold) = payload.min_relevance {
+                let before = matches.len();
+                matches.retain(|(_, similarity)| *similarity >= threshold);
+                if matches.is_empty() && before > 0 {
+                    if payload.include_gaps {
+                        let mut gap = KnowledgeGap::new(
+                            crate::frame::GapType::InsufficientEvidence,
+                            format!("No semantic match reached min_relevance {threshold}"),
+                        );
+                        if let Some(eid) = entity_id {
+                            gap = gap.with_missing_entity(eid);
+                        }
+                        if let Some(pred) = predicate_filter {
+                            gap = gap.with_missing_predicate(pred.to_string());
+                        }
+                        frame.gaps.push(gap);
+                    }
+                    return Ok(EngineResponse::Resolve { frame });
+                }
+            }
+
             // If nothing matched, report gaps.
             if matches.is_empty() {
                 if payload.include_gaps {
@@ -774,27 +2172,51 @@ impl KyroEngine {
                 return Ok(EngineResponse::Resolve { frame });
             }
 
-            // Sort by similarity (descending), then by confidence.
+            if let Some(target) = value_filter {
+                if !matches.iter().any(|(b, _)| &b.value == target) {
+                    if payload.include_gaps {
+                        frame
+                            .gaps
+                            .push(Self::value_filter_gap(entity_id, predicate_filter));
+                    }
+                    return Ok(EngineResponse::Resolve { frame });
+                }
+            }
+
+            // Rank by the similarity/confidence blend (descending), then by confidence.
+            let blended = |b: &Belief, similarity: f32| {
+                relevance_weight * similarity.clamp(0.0, 1.0)
+                    + (1.0 - relevance_weight) * self.trusted_confidence(b, trust_scope)
+            };
             matches.sort_by(|(a, sa), (b, sb)| {
-                sb.total_cmp(sa)
+                blended(b, *sb)
+                    .total_cmp(&blended(a, *sa))
                     .then_with(|| {
                         let ba = self.trusted_confidence(a, trust_scope);
                         let bb = self.trusted_confidence(b, trust_scope);
                         bb.total_cmp(&ba)
                     })
-                    .then_with(|| b.tx_time.cmp(&a.tx_time))
-                    .then_with(|| b.id.to_string().cmp(&a.id.to_string()))
+                    .then_with(|| self.tie_break_cmp(tie_break, a, b, trust_scope))
             });
+            if let Some(target) = value_filter {
+                // Keep filtered candidates ahead of counter-evidence when truncating.
+                matches.sort_by_key(|(b, _)| &b.value != target);
+            }
             matches.truncate(payload.limit);
 
             // Convert to beliefs while keeping relevance.
-            let best_score = matches.first().map(|(_, s)| *s).unwrap_or(0.0).clamp(0.0, 1.0);
+            let best_score = matches
+                .first()
+                .map(|(b, s)| blended(b, *s))
+                .unwrap_or(0.0)
+                .clamp(0.0, 1.0);
 
             let mut beliefs: Vec<Belief> = matches.iter().map(|(b, _)| b.clone()).collect();
             beliefs.sort_by(|a, b| {
                 let ca = self.trusted_confidence(a, trust_scope);
                 let cb = self.trusted_confidence(b, trust_scope);
                 cb.total_cmp(&ca)
+                    .then_with(|| self.tie_break_cmp(tie_break, a, b, trust_scope))
             });
 
             // Conflict resolution for semantic results is still per-predicate/per-entity.
@@ -832,10 +2254,14 @@ impl KyroEngine {
                 }
             }
 
-            let (winner_id, decision) = if distinct_values.len() <= 1 {
+            let filtered_winner = value_filter
+                .and_then(|target| beliefs.iter().find(|b| &b.value == target));
+            let (winner_id, decision) = if let Some(w) = filtered_winner {
+                (w.id, PolicyDecision::Selected(w.id))
+            } else if distinct_values.len() <= 1 {
                 (beliefs[0].id, PolicyDecision::Selected(beliefs[0].id))
             } else {
-                let decision = self.decide_with_trust(&policy, &beliefs, trust_scope);
+                let decision = self.decide_with_trust(&policy, tie_break, &beliefs, trust_scope);
                 match decision {
                     PolicyDecision::Selected(id) => (id, decision),
                     PolicyDecision::Unresolved => {
@@ -860,7 +2286,7 @@ impl KyroEngine {
 
             let claim = RankedClaim::new(
                 winner.clone(),
-                self.trusted_confidence(winner, trust_scope),
+                self.claim_confidence(&beliefs, winner, trust_scope, aggregation),
                 best_score,
             );
 
@@ -875,7 +2301,7 @@ impl KyroEngine {
                         trusted_conf,
                         score.clamp(0.0, 1.0),
                     ));
-                } else if payload.include_counter_evidence {
+                } else if payload.include_counter_evidence && b.value.contradicts(&winner.value) {
                     frame.counter_evidence.push(Evidence::new(
                         b.id,
                         b.predicate.clone(),
@@ -890,15 +2316,25 @@ impl KyroEngine {
                     .find_by_belief(b.id)
                     .map_err(Self::storage_err)?;
                 for c in conflicts {
-                    if c.is_open() {
+                    if c.is_open() && !frame.conflicts.iter().any(|f| f.id == c.id) {
                         frame.conflicts.push(c);
                     }
                 }
             }
 
-            if !matches!(decision, PolicyDecision::Unresolved) {
+            frame.distribution = self.marginal_distribution(&beliefs, trust_scope);
+            if !matches!(decision, PolicyDecision::Unresolved)
+                && Self::check_min_support(&mut frame, &payload, entity_id, predicate_filter)
+            {
+                let confidence = self.epistemic_confidence(&beliefs, &winner.value, trust_scope, combination);
+                frame.epistemic_confidence = Some(confidence);
+                if payload.include_confidence_interval {
+                    frame.confidence_interval =
+                        Some(self.confidence_interval(&beliefs, &winner.value, trust_scope, confidence));
+                }
                 frame.best_supported_claim = Some(claim);
             }
+            Conflict::sort_by_severity(&mut frame.conflicts);
 
             return Ok(EngineResponse::Resolve { frame });
         }
@@ -918,13 +2354,16 @@ impl KyroEngine {
             return Ok(EngineResponse::Resolve { frame });
         };
 
-        self.ensure_entity_exists(entity_id)?;
+        self.entity_in_namespace(entity_id, namespace)?;
 
         // If predicate is missing, return a structured gap rather than hard error.
         let Some(predicate) = predicate else {
             if payload.include_gaps {
                 // If the entity has no beliefs at all, report that; otherwise we still need a predicate.
-                let count = self.beliefs.count_by_entity(entity_id).map_err(Self::storage_err)?;
+                let mut count = 0;
+                for subject in self.entities.merged_ids(entity_id).map_err(Self::storage_err)? {
+                    count += self.beliefs.count_by_entity(subject).map_err(Self::storage_err)?;
+                }
                 if count == 0 {
                     frame.gaps.push(
                         KnowledgeGap::new(
@@ -950,14 +2389,35 @@ impl KyroEngine {
             return Ok(EngineResponse::Resolve { frame });
         };
 
+        if payload.mode == ResolveMode::History {
+            frame.history = self.history_merged(entity_id, predicate)?;
+        }
+
         if trust_domain.is_none() {
             trust_domain = Some(predicate);
         }
 
-        let all = self
-            .beliefs
-            .find_as_of(entity_id, predicate, as_of)
-            .map_err(Self::storage_err)?;
+        // A pinned value asks which belief holds it, which an interpolated value cannot answer.
+        if interpolate
+            && value_filter.is_none()
+            && self.interpolate_claim(&mut frame, entity_id, predicate, as_of, min_conf, &payload, trust_domain)?
+        {
+            return Ok(EngineResponse::Resolve { frame });
+        }
+
+        let mut all = self.find_as_of_merged(entity_id, predicate, as_of)?;
+        if Self::has_source_filters(&payload) {
+            let before = all.len();
+            all.retain(|b| Self::source_admitted(&payload, b));
+            if all.is_empty() && before > 0 {
+                if payload.include_gaps {
+                    frame
+                        .gaps
+                        .push(Self::source_filter_gap(Some(entity_id), Some(predicate)));
+                }
+                return Ok(EngineResponse::Resolve { frame });
+            }
+        }
 
         let max_conf = all
             .iter()
@@ -973,8 +2433,16 @@ impl KyroEngine {
             let ca = self.trusted_confidence(a, trust_scope);
             let cb = self.trusted_confidence(b, trust_scope);
             cb.total_cmp(&ca)
+                .then_with(|| self.tie_break_cmp(tie_break, a, b, trust_scope))
         });
-        beliefs.truncate(payload.limit);
+        if let Some(target) = value_filter {
+            // Keep filtered candidates ahead of counter-evidence when truncating.
+            beliefs.sort_by_key(|b| &b.value != target);
+        }
+        // Aggregates summarize every matching belief, not just the top `limit`.
+        if aggregate.is_none() {
+            beliefs.truncate(payload.limit);
+        }
 
         if beliefs.is_empty() {
             if payload.include_gaps {
@@ -1003,6 +2471,22 @@ impl KyroEngine {
             return Ok(EngineResponse::Resolve { frame });
         }
 
+        if let Some(target) = value_filter {
+            if !beliefs.iter().any(|b| &b.value == target) {
+                if payload.include_gaps {
+                    frame
+                        .gaps
+                        .push(Self::value_filter_gap(Some(entity_id), Some(predicate)));
+                }
+                return Ok(EngineResponse::Resolve { frame });
+            }
+        }
+
+        if let Some(function) = aggregate {
+            self.aggregate_claim(&mut frame, &beliefs, function, as_of, trust_scope)?;
+            return Ok(EngineResponse::Resolve { frame });
+        }
+
         // Resolve competing beliefs if necessary.
 
         // Detect whether we have multiple distinct values.
@@ -1014,11 +2498,16 @@ impl KyroEngine {
             }
         }
 
-        let (winner_id, decision) = if distinct_values.len() <= 1 {
+        let filtered_winner =
+            value_filter.and_then(|target| beliefs.iter().find(|b| &b.value == target));
+        let (winner_id, decision) = if let Some(w) = filtered_winner {
+            // The caller pinned the value; its best-ranked belief is the answer.
+            (w.id, PolicyDecision::Selected(w.id))
+        } else if distinct_values.len() <= 1 {
             // No conflict; treat the best-ranked belief as selected.
             (beliefs[0].id, PolicyDecision::Selected(beliefs[0].id))
         } else {
-            let decision = self.decide_with_trust(&policy, &beliefs, trust_scope);
+            let decision = self.decide_with_trust(&policy, tie_break, &beliefs, trust_scope);
             match decision {
                 PolicyDecision::Selected(id) => (id, decision),
                 PolicyDecision::Unresolved => {
@@ -1046,7 +2535,11 @@ impl KyroEngine {
             .find(|b| b.id == winner_id)
             .unwrap_or(&beliefs[0]);
 
-        let claim = RankedClaim::new(winner.clone(), self.trusted_confidence(winner, trust_scope), 1.0);
+        let claim = RankedClaim::new(
+            winner.clone(),
+            self.claim_confidence(&beliefs, winner, trust_scope, aggregation),
+            1.0,
+        );
 
         for b in &beliefs {
             if b.value == winner.value {
@@ -1057,7 +2550,7 @@ impl KyroEngine {
                     self.trusted_confidence(b, trust_scope),
                     1.0,
                 ));
-            } else if payload.include_counter_evidence {
+            } else if payload.include_counter_evidence && b.value.contradicts(&winner.value) {
                 frame.counter_evidence.push(Evidence::new(
                     b.id,
                     b.predicate.clone(),
@@ -1072,64 +2565,408 @@ impl KyroEngine {
                 .conflicts
                 .find_by_belief(b.id)
                 .map_err(Self::storage_err)?;
+            // A clustered conflict involves several of these beliefs; attach it once.
             for c in conflicts {
-                if c.is_open() {
+                if c.is_open() && !frame.conflicts.iter().any(|f| f.id == c.id) {
                     frame.conflicts.push(c);
                 }
             }
         }
 
-        // Only set the answer if the policy selected a winner (or there was no conflict).
-        if !matches!(decision, PolicyDecision::Unresolved) {
+        frame.distribution = self.marginal_distribution(&beliefs, trust_scope);
+        // Only set the answer if the policy selected a winner (or there was no conflict) that
+        // enough beliefs back.
+        if !matches!(decision, PolicyDecision::Unresolved)
+            && Self::check_min_support(&mut frame, &payload, Some(entity_id), Some(predicate))
+        {
+            let confidence = self.epistemic_confidence(&beliefs, &winner.value, trust_scope, combination);
+            frame.epistemic_confidence = Some(confidence);
+            if payload.include_confidence_interval {
+                frame.confidence_interval =
+                    Some(self.confidence_interval(&beliefs, &winner.value, trust_scope, confidence));
+            }
             frame.best_supported_claim = Some(claim);
         }
+        Conflict::sort_by_severity(&mut frame.conflicts);
 
         Ok(EngineResponse::Resolve { frame })
     }
 
-    fn detect_conflicts(&self, belief: &Belief, as_of: DateTime<Utc>) -> KyroResult<Vec<Conflict>> {
-        let mut conflicts = Vec::new();
-
-        // Value contradiction detection: other active beliefs with different value.
-        let existing = self
-            .beliefs
-            .find_as_of(belief.subject, &belief.predicate, as_of)
-            .map_err(Self::storage_err)?;
-        for other in existing {
-            if other.id == belief.id {
-                continue;
-            }
-            // Both beliefs are already filtered by `find_as_of` at `as_of`.
-            if other.value != belief.value {
-                conflicts.push(Conflict::value_contradiction(
-                    vec![other.id, belief.id],
-                    belief.subject,
-                    &belief.predicate,
-                ));
-            }
+    /// Answer an aggregate RESOLVE: a synthetic claim holding `function` over the numeric
+    /// values of `beliefs`, which all become supporting evidence.
+    ///
+    /// Denials carry no value and are skipped. The claim's confidence is the mean trusted
+    /// confidence of the beliefs aggregated.
+    fn aggregate_claim(
+        &self,
+        frame: &mut BeliefFrame,
+        beliefs: &[Belief],
+        function: AggregateFunction,
+        as_of: DateTime<Utc>,
+        trust_scope: Option<&str>,
+    ) -> KyroResult<()> {
+        let inputs: Vec<&Belief> = beliefs.iter().filter(|b| !b.value.is_denial()).collect();
+        let mut values = Vec::with_capacity(inputs.len());
+        for b in &inputs {
+            let Some(v) = b.value.as_float() else {
+                return Err(ValidationError::InvalidField {
+                    field: "mode".to_string(),
+                    reason: format!(
+                        "aggregate {function} requires numeric values, but belief {} holds a {} value",
+                        b.id,
+                        b.value.type_name()
+                    ),
+                }
+                .into());
+            };
+            values.push(v);
         }
+        let Some(value) = function.apply(&values) else {
+            return Ok(());
+        };
 
-        // Pattern checks.
-        let patterns = self
-            .patterns
-            .find_by_predicate(&belief.predicate)
-            .map_err(Self::storage_err)?;
-
-        for pattern in patterns {
-            if !pattern.active {
-                continue;
-            }
-            if !pattern.valid_time.contains(as_of) {
-                continue;
+        let confidences: Vec<f32> = inputs
+            .iter()
+            .map(|b| self.trusted_confidence(b, trust_scope))
+            .collect();
+        let confidence = confidences.iter().sum::<f32>() / confidences.len() as f32;
+        let premise_ids: Vec<BeliefId> = inputs.iter().map(|b| b.id).collect();
+        let mut source_ids = Vec::new();
+        for id in inputs.iter().map(|b| b.source.source_id()) {
+            if !source_ids.contains(&id) {
+                source_ids.push(id);
             }
+        }
 
-            if let Some(reason) = check_pattern(&pattern.rule, belief, &self.beliefs, as_of)? {
-                conflicts.push(Conflict::pattern_violation(
-                    vec![belief.id],
-                    belief.subject,
-                    pattern.id.to_string(),
-                    pattern.name,
-                ));
+        let first = inputs[0];
+        let mut claim = Belief::builder()
+            .subject(first.subject)
+            .predicate(first.predicate.clone())
+            .value(value)
+            .confidence(Confidence::new(
+                confidence,
+                crate::confidence::CalibrationMode::Heuristic,
+                crate::confidence::ConfidenceSource::AggregatedFromSources {
+                    source_ids,
+                    aggregation_method: function.name().to_string(),
+                },
+            )?)
+            .source(crate::source::Source::derived(premise_ids, function.name()))
+            .valid_time(TimeRange::instant(as_of))
+            .build()?;
+        claim.namespace = first.namespace.clone();
+
+        for (b, conf) in inputs.iter().zip(&confidences) {
+            frame.supporting_evidence.push(Evidence::new(
+                b.id,
+                b.predicate.clone(),
+                b.source.clone(),
+                *conf,
+                1.0,
+            ));
+        }
+        frame.epistemic_confidence = Some(confidence);
+        frame.best_supported_claim = Some(RankedClaim::new(claim, confidence, 1.0));
+        frame.debug_summary = Some(format!("{function} over {} beliefs", inputs.len()));
+        Ok(())
+    }
+
+    /// Answer an interpolating RESOLVE: a synthetic claim holding the value of the numeric
+    /// series at `as_of`, linearly interpolated between the nearest samples on either side.
+    ///
+    /// Returns `false`, leaving `frame` untouched, when a sample was taken exactly at `as_of`
+    /// or there is none on one side of it. Among samples taken at the same time, the most
+    /// trusted one is used.
+    #[allow(clippy::too_many_arguments)]
+    fn interpolate_claim(
+        &self,
+        frame: &mut BeliefFrame,
+        entity_id: EntityId,
+        predicate: &str,
+        as_of: DateTime<Utc>,
+        min_conf: f32,
+        payload: &ResolvePayload,
+        trust_scope: Option<&str>,
+    ) -> KyroResult<bool> {
+        let history = self.history_merged(entity_id, predicate)?;
+        let retracted: HashSet<BeliefId> = history
+            .iter()
+            .filter(|b| b.retraction)
+            .flat_map(|b| b.supersedes.iter().copied())
+            .collect();
+        let samples: Vec<(&Belief, f64, f32)> = history
+            .iter()
+            .filter(|b| !retracted.contains(&b.id))
+            .filter(|b| b.confidence.value() >= min_conf && Self::source_admitted(payload, b))
+            .filter_map(|b| Some((b, b.value.as_float()?, self.trusted_confidence(b, trust_scope))))
+            .collect();
+
+        let at = |(b, _, _): &&(&Belief, f64, f32)| b.valid_time.from();
+        // A sample taken at `as_of` is the answer itself.
+        if samples.iter().any(|s| at(&s) == as_of) {
+            return Ok(false);
+        }
+        let before = samples
+            .iter()
+            .filter(|s| at(s) < as_of)
+            .max_by(|a, b| at(a).cmp(&at(b)).then(a.2.total_cmp(&b.2)));
+        let after = samples
+            .iter()
+            .filter(|s| at(s) > as_of)
+            .min_by(|a, b| at(a).cmp(&at(b)).then(b.2.total_cmp(&a.2)));
+        let (Some(&(start, v0, c0)), Some(&(end, v1, c1))) = (before, after) else {
+            return Ok(false);
+        };
+        let span = (end.valid_time.from() - start.valid_time.from()).num_milliseconds() as f64;
+        let elapsed = (as_of - start.valid_time.from()).num_milliseconds() as f64;
+        let weight = (elapsed / span).clamp(0.0, 1.0);
+        let value = v0 + (v1 - v0) * weight;
+        #[allow(clippy::cast_possible_truncation)]
+        let weight = weight as f32;
+        let confidence = (c0 * (1.0 - weight) + c1 * weight) * (1.0 - weight.min(1.0 - weight));
+
+        let mut source_ids = vec![start.source.source_id()];
+        if end.source.source_id() != source_ids[0] {
+            source_ids.push(end.source.source_id());
+        }
+        let mut claim = Belief::builder()
+            .subject(start.subject)
+            .predicate(start.predicate.clone())
+            .value(value)
+            .confidence(Confidence::new(
+                confidence,
+                crate::confidence::CalibrationMode::Heuristic,
+                crate::confidence::ConfidenceSource::AggregatedFromSources {
+                    source_ids,
+                    aggregation_method: "interpolate".to_string(),
+                },
+            )?)
+            .source(crate::source::Source::derived(vec![start.id, end.id], "interpolate"))
+            .valid_time(TimeRange::instant(as_of))
+            .build()?;
+        claim.namespace = start.namespace.clone();
+
+        for (b, conf, relevance) in [(start, c0, 1.0 - weight), (end, c1, weight)] {
+            frame
+                .supporting_evidence
+                .push(Evidence::new(b.id, b.predicate.clone(), b.source.clone(), conf, relevance));
+        }
+        frame.epistemic_confidence = Some(confidence);
+        frame.best_supported_claim = Some(RankedClaim::new(claim, confidence, 1.0));
+        frame.debug_summary = Some(format!(
+            "interpolated between samples at {} and {}",
+            start.valid_time.from(),
+            end.valid_time.from()
+        ));
+        Ok(true)
+    }
+
+    fn has_source_filters(payload: &ResolvePayload) -> bool {
+        !payload.include_sources.is_empty() || !payload.exclude_sources.is_empty()
+    }
+
+    /// Whether RESOLVE's source filters let `belief` through.
+    fn source_admitted(payload: &ResolvePayload, belief: &Belief) -> bool {
+        let source_id = belief.source.source_id();
+        !payload.exclude_sources.contains(&source_id)
+            && (payload.include_sources.is_empty() || payload.include_sources.contains(&source_id))
+    }
+
+    fn source_filter_gap(entity_id: Option<EntityId>, predicate: Option<&str>) -> KnowledgeGap {
+        let mut gap = KnowledgeGap::new(
+            crate::frame::GapType::InsufficientEvidence,
+            "Source filters excluded every matching belief",
+        );
+        if let Some(eid) = entity_id {
+            gap = gap.with_missing_entity(eid);
+        }
+        if let Some(pred) = predicate {
+            gap = gap.with_missing_predicate(pred.to_string());
+        }
+        gap
+    }
+
+    /// Whether the winner's supporting evidence in `frame` meets `payload.min_support`.
+    ///
+    /// Records an `InsufficientEvidence` gap when it does not.
+    fn check_min_support(
+        frame: &mut BeliefFrame,
+        payload: &ResolvePayload,
+        entity_id: Option<EntityId>,
+        predicate: Option<&str>,
+    ) -> bool {
+        let supporting = frame.supporting_evidence.len();
+        if supporting >= payload.min_support {
+            return true;
+        }
+        let description =
+            format!("Only {supporting} belief(s) support the answer; {} required", payload.min_support);
+        frame.debug_summary = Some(description.clone());
+        if payload.include_gaps {
+            let mut gap = KnowledgeGap::new(crate::frame::GapType::InsufficientEvidence, description);
+            if let Some(eid) = entity_id {
+                gap = gap.with_missing_entity(eid);
+            }
+            if let Some(pred) = predicate {
+                gap = gap.with_missing_predicate(pred.to_string());
+            }
+            frame.gaps.push(gap);
+        }
+        false
+    }
+
+    fn value_filter_gap(entity_id: Option<EntityId>, predicate: Option<&str>) -> KnowledgeGap {
+        let description = match predicate {
+            Some(pred) => format!("No beliefs for '{pred}' match the requested value"),
+            None => "No beliefs match the requested value".to_string(),
+        };
+        let mut gap = KnowledgeGap::new(crate::frame::GapType::NoDataFound, description);
+        if let Some(eid) = entity_id {
+            gap = gap.with_missing_entity(eid);
+        }
+        if let Some(pred) = predicate {
+            gap = gap.with_missing_predicate(pred.to_string());
+        }
+        gap
+    }
+
+    /// Store `conflict`, folding a value contradiction into an open cluster for the same
+    /// entity and predicate when one already involves any of its beliefs.
+    ///
+    /// Returns the id of the conflict that now records the contradiction. With
+    /// deterministic conflict IDs, a conflict already stored under its ID is left as is.
+    fn record_conflict(&self, conflict: &Conflict) -> KyroResult<ConflictId> {
+        if self.deterministic_conflict_ids
+            && self.conflicts.get(conflict.id).map_err(Self::storage_err)?.is_some()
+        {
+            return Ok(conflict.id);
+        }
+        if matches!(conflict.conflict_type, ConflictType::ValueContradiction { .. }) {
+            let mut clusters = Vec::new();
+            for belief_id in &conflict.belief_ids {
+                clusters.extend(
+                    self.conflicts
+                        .find_by_belief(*belief_id)
+                        .map_err(Self::storage_err)?
+                        .into_iter()
+                        .filter(|c| {
+                            c.is_open()
+                                && c.entity_id == conflict.entity_id
+                                && c.conflict_type == conflict.conflict_type
+                        }),
+                );
+            }
+            if let Some(mut cluster) = clusters.into_iter().min_by_key(|c| c.detected_at) {
+                for belief_id in &conflict.belief_ids {
+                    if !cluster.involves_belief(*belief_id) {
+                        cluster.belief_ids.push(*belief_id);
+                    }
+                }
+                cluster.severity = cluster.severity.min(conflict.severity);
+                let id = cluster.id;
+                self.conflicts.update(cluster).map_err(Self::storage_err)?;
+                return Ok(id);
+            }
+        }
+        self.conflicts
+            .insert(conflict.clone())
+            .map_err(Self::storage_err)?;
+        Ok(conflict.id)
+    }
+
+    /// Detect conflicts for `belief`, ignoring beliefs in `replaced` (about to be superseded by it).
+    fn detect_conflicts(
+        &self,
+        belief: &Belief,
+        as_of: DateTime<Utc>,
+        replaced: &HashSet<BeliefId>,
+    ) -> KyroResult<Vec<Conflict>> {
+        let mut conflicts = Vec::new();
+        let identify = |conflict: Conflict| {
+            if self.deterministic_conflict_ids {
+                conflict.with_deterministic_id()
+            } else {
+                conflict
+            }
+        };
+
+        // Value contradiction detection: other active beliefs whose value contradicts this
+        // one (see `Value::contradicts`). All of them are clustered into a single conflict
+        // rather than one per pair.
+        let existing = self.find_as_of_merged(belief.subject, &belief.predicate, as_of)?;
+        let contradicting: Vec<Belief> = existing
+            .into_iter()
+            .filter(|other| other.id != belief.id && !replaced.contains(&other.id))
+            // Beliefs about one entity share its namespace; this guards against beliefs written
+            // before namespaces were tracked.
+            .filter(|other| other.namespace == belief.namespace)
+            // Both beliefs are already filtered by `find_as_of` at `as_of`.
+            .filter(|other| other.value.contradicts(&belief.value))
+            .collect();
+        if !contradicting.is_empty() {
+            let severity = Conflict::severity_from(
+                contradicting
+                    .iter()
+                    .chain(std::iter::once(belief))
+                    .map(|b| self.trusted_confidence(b, Some(&belief.predicate))),
+            );
+            let belief_ids = contradicting
+                .iter()
+                .map(|b| b.id)
+                .chain(std::iter::once(belief.id))
+                .collect();
+            conflicts.push(identify(
+                Conflict::value_contradiction(belief_ids, belief.subject, &belief.predicate)
+                    .with_severity(severity),
+            ));
+        }
+
+        // A denial says nothing about what the value is, so patterns do not apply to it.
+        if belief.value.is_denial() {
+            return Ok(conflicts);
+        }
+
+        // Pattern checks. The patterns on a predicate come from the pattern cache. Custom
+        // rules are not indexed by predicate, so they are fetched separately, and only when
+        // an evaluator could run.
+        let indexed = self.pattern_cache.get_or_load(&belief.predicate, || {
+            self.patterns
+                .find_by_predicate(&belief.predicate)
+                .map_err(Self::storage_err)
+        })?;
+        let mut custom = Vec::new();
+        if !self.custom_rules.is_empty()? {
+            custom.extend(
+                self.patterns
+                    .find_active()
+                    .map_err(Self::storage_err)?
+                    .into_iter()
+                    .filter(|p| matches!(p.rule, PatternRule::Custom { .. })),
+            );
+        }
+
+        for pattern in indexed.iter().chain(&custom) {
+            if !pattern.active {
+                continue;
+            }
+            if !pattern.valid_time.contains(as_of) {
+                continue;
+            }
+
+            if let Some(reason) =
+                check_pattern(&pattern.rule, belief, &self.beliefs, &self.entities, as_of, replaced, &self.custom_rules)?
+            {
+                let severity =
+                    Conflict::severity_from([self.trusted_confidence(belief, Some(&belief.predicate))]);
+                conflicts.push(identify(
+                    Conflict::pattern_violation(
+                        vec![belief.id],
+                        belief.subject,
+                        pattern.id.to_string(),
+                        pattern.name.clone(),
+                    )
+                    .with_severity(severity),
+                ));
 
                 // Encode more detail in metadata for debugging.
                 // Avoid large payloads; keep it simple.
@@ -1143,15 +2980,28 @@ impl KyroEngine {
     }
 }
 
+/// Evaluate `rule` for `belief`.
+///
+/// Beliefs in `replaced` do not count towards `Unique` and `Cardinality`; rules that compare
+/// against a previous belief (e.g. `Monotonic`, `MonotonicConfidence`) still see them.
+/// Denials are ignored by all of these.
 fn check_pattern(
     rule: &PatternRule,
     belief: &Belief,
     belief_store: &Arc<dyn BeliefStore>,
+    entity_store: &Arc<dyn EntityStore>,
     as_of: DateTime<Utc>,
+    replaced: &HashSet<BeliefId>,
+    custom_rules: &CustomRuleRegistry,
 ) -> KyroResult<Option<String>> {
     match rule {
-        PatternRule::Range { min, max, .. } => {
-            let Some(v) = belief.value.as_float() else {
+        PatternRule::Range { min, max, coerce, .. } => {
+            let v = if *coerce {
+                belief.value.coerce_float()
+            } else {
+                belief.value.as_float()
+            };
+            let Some(v) = v else {
                 return Ok(Some(format!(
                     "range rule requires numeric value, got {}",
                     belief.value.type_name()
@@ -1171,15 +3021,19 @@ fn check_pattern(
             Ok(None)
         }
         PatternRule::Unique { .. } => {
+            // Uniqueness holds per instant: only beliefs that are still in force (neither
+            // superseded nor about to be) and valid at some time the new belief is valid
+            // count. A value may come back after a gap in its validity.
             let existing = belief_store
-                .find_as_of(belief.subject, &belief.predicate, as_of)
+                .find_by_entity_predicate(belief.subject, &belief.predicate)
                 .map_err(|e| KyroError::Execution(ExecutionError::Storage {
                     message: e.to_string(),
                 }))?;
 
             let active_count = existing
                 .into_iter()
-                .filter(|b| b.id != belief.id && b.is_valid_at(as_of))
+                .filter(|b| b.id != belief.id && b.is_active() && !replaced.contains(&b.id))
+                .filter(|b| !b.value.is_denial() && b.valid_time.overlaps(&belief.valid_time))
                 .count();
 
             if active_count > 0 {
@@ -1197,7 +3051,8 @@ fn check_pattern(
 
             let count = existing
                 .into_iter()
-                .filter(|b| b.id != belief.id && b.is_valid_at(as_of))
+                .filter(|b| b.id != belief.id && !replaced.contains(&b.id) && b.is_valid_at(as_of))
+                .filter(|b| !b.value.is_denial())
                 .count()
                 + 1;
 
@@ -1243,11 +3098,11 @@ fn check_pattern(
                 .map_err(|e| KyroError::Execution(ExecutionError::Storage {
                     message: e.to_string(),
                 }))?;
-            existing.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+            existing.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
 
             let Some(prev) = existing
                 .into_iter()
-                .find(|b| b.id != belief.id && b.is_valid_at(as_of))
+                .find(|b| b.id != belief.id && b.is_valid_at(as_of) && !b.value.is_denial())
             else {
                 return Ok(None);
             };
@@ -1276,6 +3131,32 @@ fn check_pattern(
                 }
             }
         }
+        PatternRule::MonotonicConfidence { direction, .. } => {
+            let mut existing = belief_store
+                .find_as_of(belief.subject, &belief.predicate, as_of)
+                .map_err(|e| KyroError::Execution(ExecutionError::Storage {
+                    message: e.to_string(),
+                }))?;
+            existing.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
+
+            let Some(prev) = existing
+                .into_iter()
+                .find(|b| b.id != belief.id && b.is_valid_at(as_of) && !b.value.is_denial())
+            else {
+                return Ok(None);
+            };
+
+            let (prev_c, new_c) = (prev.confidence.value(), belief.confidence.value());
+            match direction {
+                crate::pattern::MonotonicDirection::Increasing if new_c < prev_c => Ok(Some(format!(
+                    "confidence {new_c} decreased from {prev_c}"
+                ))),
+                crate::pattern::MonotonicDirection::Decreasing if new_c > prev_c => Ok(Some(format!(
+                    "confidence {new_c} increased from {prev_c}"
+                ))),
+                _ => Ok(None),
+            }
+        }
         PatternRule::Implication { if_predicate, then_predicate } => {
             if belief.predicate != if_predicate.trim() {
                 return Ok(None);
@@ -1326,7 +3207,94 @@ fn check_pattern(
             }
             Ok(None)
         }
-        PatternRule::Custom { .. } => Ok(None),
+        PatternRule::CrossPredicateOrder {
+            predicate,
+            other_predicate,
+            relation,
+        } => {
+            let (predicate, other_predicate) = (predicate.trim(), other_predicate.trim());
+            let counterpart = if belief.predicate == predicate {
+                other_predicate
+            } else if belief.predicate == other_predicate {
+                predicate
+            } else {
+                return Ok(None);
+            };
+
+            let Some(v) = belief.value.as_float() else {
+                return Ok(Some(format!(
+                    "order rule requires numeric value, got {}",
+                    belief.value.type_name()
+                )));
+            };
+
+            let latest = belief_store
+                .find_as_of(belief.subject, counterpart, as_of)
+                .map_err(|e| KyroError::Execution(ExecutionError::Storage {
+                    message: e.to_string(),
+                }))?
+                .into_iter()
+                .filter(|b| b.id != belief.id && b.is_valid_at(as_of))
+                .max_by_key(|b| b.tx_time);
+            let Some(other_v) = latest.and_then(|b| b.value.as_float()) else {
+                return Ok(None);
+            };
+
+            let (lhs, rhs) = if counterpart == other_predicate {
+                (v, other_v)
+            } else {
+                (other_v, v)
+            };
+            if relation.holds(lhs, rhs) {
+                Ok(None)
+            } else {
+                Ok(Some(format!(
+                    "'{predicate}' ({lhs}) is not {relation} '{other_predicate}' ({rhs})"
+                )))
+            }
+        }
+        PatternRule::JsonPath {
+            path,
+            expected,
+            missing_is_violation,
+            ..
+        } => {
+            let Some(json) = belief.value.as_structured() else {
+                return Ok(Some(format!(
+                    "json_path rule requires structured value, got {}",
+                    belief.value.type_name()
+                )));
+            };
+            match json.pointer(path) {
+                Some(actual) => Ok(expected.check(actual).map(|reason| format!("'{path}': {reason}"))),
+                None if *missing_is_violation => Ok(Some(format!("'{path}' is missing"))),
+                None => Ok(None),
+            }
+        }
+        PatternRule::ReferentialIntegrity { .. } => {
+            let Some(target) = belief.value.as_entity() else {
+                return Ok(Some(format!(
+                    "referential integrity rule requires an entity reference, got {}",
+                    belief.value.type_name()
+                )));
+            };
+            let exists = entity_store
+                .get(target)
+                .map_err(|e| KyroError::Execution(ExecutionError::Storage {
+                    message: e.to_string(),
+                }))?
+                .is_some();
+            if exists {
+                Ok(None)
+            } else {
+                Ok(Some(format!("referenced entity {target} does not exist")))
+            }
+        }
+        // Names are validated at define time; a pattern whose evaluator is no longer
+        // registered (e.g. after a restart) stays inert until it is registered again.
+        PatternRule::Custom { name, .. } => Ok(custom_rules
+            .get(name)?
+            .and_then(|evaluate| evaluate(belief, belief_store.as_ref(), as_of))),
     }
 }
 
@@ -1338,10 +3306,11 @@ mod tests {
 
     use crate::entity::{Entity, EntityType};
     use crate::inference::ConflictResolutionPolicy;
+    use crate::ir::EntityResolutionConfig;
     use crate::ir::AssertPayload;
     use crate::source::Source;
     use crate::storage::memory::InMemoryStores;
-    use crate::trust::{SimpleTrustModel, TrustModel};
+    use crate::trust::{FeedbackOutcome, SimpleTrustModel, TrustModel};
 
     fn engine() -> (KyroEngine, EntityId) {
         let stores = InMemoryStores::new();
@@ -1423,43 +3392,28 @@ mod tests {
     fn derive_persists_record_and_indexes_by_premise_and_derived() {
         let (eng, id, _beliefs, derivations) = engine_with_backing_stores();
 
-        let p1 = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "premise_a".to_string(),
-            value: Value::Bool(true),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let p1 = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(id, "premise_a", Value::Bool(true), "a", ConsistencyMode::Force)
         }));
         let EngineResponse::Assert { belief_id: b1, .. } = eng.execute(p1).unwrap() else {
             panic!("expected assert");
         };
 
-        let p2 = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "premise_b".to_string(),
-            value: Value::Bool(true),
+        let p2 = KyroIR::new(Operation::Assert(AssertPayload {
             confidence: Confidence::from_agent(0.8, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(id, "premise_b", Value::Bool(true), "a", ConsistencyMode::Force)
         }));
         let EngineResponse::Assert { belief_id: b2, .. } = eng.execute(p2).unwrap() else {
             panic!("expected assert");
         };
 
-        let derived_assert = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "conclusion".to_string(),
-            value: Value::Bool(true),
-            confidence: Confidence::from_agent(0.7, "a").unwrap(),
+        let derived_assert = KyroIR::new(Operation::Assert(AssertPayload {
             source: Source::derived(vec![b1, b2], "modus_ponens"),
+            confidence: Confidence::from_agent(0.7, "a").unwrap(),
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(id, "conclusion", Value::Bool(true), "a", ConsistencyMode::Force)
         }));
         let EngineResponse::Assert {
             belief_id: derived_id,
@@ -1476,6 +3430,7 @@ mod tests {
             confidence: Some(0.7),
             justification: Some("A is true; therefore B".to_string()),
             metadata: Some(serde_json::json!({"engine": "test"})),
+            namespace: None,
         }));
 
         let EngineResponse::Derive { derivation_id } = eng.execute(derive_ir).unwrap() else {
@@ -1494,19 +3449,51 @@ mod tests {
         assert!(by_derived.iter().any(|r| r.id == derivation_id));
     }
 
+    #[test]
+    fn derive_rejects_premises_of_another_namespace() {
+        let (eng, _) = engine();
+        let mut premises = Vec::new();
+        for ns in ["tenant-a", "tenant-b"] {
+            let entity = Entity::new("Acme", EntityType::Organization).with_namespace(ns);
+            let id = entity.id;
+            eng.entity_store().insert(entity).unwrap();
+            let EngineResponse::Assert { belief_id, .. } = eng
+                .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    namespace: Some(ns.to_string()),
+                    ..assert_payload(id, "status", "active", "a", ConsistencyMode::Force)
+                })))
+                .unwrap()
+            else {
+                panic!("expected assert");
+            };
+            premises.push(belief_id);
+        }
+        let derive = |sources: Vec<BeliefId>| {
+            eng.execute(
+                crate::DeriveBuilder::new()
+                    .rule("r")
+                    .sources(sources)
+                    .namespace("tenant-a")
+                    .build()
+                    .unwrap(),
+            )
+        };
+
+        let err = derive(premises.clone()).unwrap_err();
+        assert!(matches!(
+            err,
+            KyroError::Execution(ExecutionError::BeliefNotFound { id }) if id == premises[1]
+        ));
+        assert!(matches!(derive(vec![premises[0]]).unwrap(), EngineResponse::Derive { .. }));
+    }
+
     #[test]
     fn assert_then_resolve_returns_answer() {
         let (eng, id) = engine();
 
-        let ir = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "temperature".to_string(),
-            value: Value::Float(25.0),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let ir = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(id, "temperature", Value::Float(25.0), "a", ConsistencyMode::Force)
         }));
 
         let resp = eng.execute(ir).unwrap();
@@ -1527,58 +3514,119 @@ mod tests {
     fn eventual_mode_records_value_contradiction_conflict() {
         let (eng, id) = engine();
 
-        let first = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "is_superconductor".to_string(),
-            value: Value::Bool(false),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let first = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(
+                id, "is_superconductor", Value::Bool(false), "a", ConsistencyMode::Force,
+            )
         }));
         eng.execute(first).unwrap();
 
-        let second = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "is_superconductor".to_string(),
-            value: Value::Bool(true),
+        let second = KyroIR::new(Operation::Assert(AssertPayload {
             confidence: Confidence::from_agent(0.8, "b").unwrap(),
-            source: Source::agent("b", Option::<String>::None),
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            ..assert_payload(
+                id, "is_superconductor", Value::Bool(true), "b", ConsistencyMode::Eventual,
+            )
         }));
 
         let EngineResponse::Assert { conflict_ids, .. } = eng.execute(second).unwrap() else { panic!("expected assert"); };
         assert!(!conflict_ids.is_empty());
     }
 
+    #[test]
+    fn warn_mode_reports_conflicts_without_recording_them() {
+        let (eng, id) = engine();
+
+        let assert = |value: bool, agent: &str, consistency_mode: ConsistencyMode| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                confidence: Confidence::from_agent(0.8, agent).unwrap(),
+                valid_time: TimeRange::from_now(),
+                ..assert_payload(
+                    id, "is_superconductor", Value::Bool(value), agent, consistency_mode,
+                )
+            })))
+            .unwrap()
+        };
+        assert(false, "a", ConsistencyMode::Force);
+
+        let EngineResponse::Assert { belief_id, conflict_ids, conflict_warnings } =
+            assert(true, "b", ConsistencyMode::Warn)
+        else {
+            panic!("expected assert");
+        };
+        assert!(conflict_ids.is_empty());
+        assert!(!conflict_warnings.is_empty());
+        assert!(eng.conflict_store().find_open().unwrap().is_empty());
+        let stored = eng.belief_store().get(belief_id).unwrap().unwrap();
+        assert_eq!(
+            stored.consistency_status,
+            ConsistencyStatus::Contested { conflict_ids: Vec::new() }
+        );
+
+        let EngineResponse::Assert { conflict_ids, conflict_warnings, .. } =
+            assert(true, "c", ConsistencyMode::Eventual)
+        else {
+            panic!("expected assert");
+        };
+        assert!(!conflict_ids.is_empty());
+        assert!(conflict_warnings.is_empty());
+        assert_eq!(eng.conflict_store().find_open().unwrap().len(), conflict_ids.len());
+    }
+
+    #[test]
+    fn contradicting_asserts_share_one_clustered_conflict() {
+        let (eng, id) = engine();
+
+        let assert = |value: i64, agent: &str| {
+            let EngineResponse::Assert { belief_id, conflict_ids, .. } = eng
+                .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    confidence: Confidence::from_agent(0.8, agent).unwrap(),
+                    valid_time: TimeRange::from_now(),
+                    ..assert_payload(
+                        id, "boiling_point", Value::Int(value), agent, ConsistencyMode::Eventual,
+                    )
+                })))
+                .unwrap()
+            else {
+                panic!("expected assert");
+            };
+            (belief_id, conflict_ids)
+        };
+        let (a, _) = assert(100, "a");
+        let (b, b_conflicts) = assert(99, "b");
+        let (c, c_conflicts) = assert(101, "c");
+
+        assert_eq!(b_conflicts.len(), 1);
+        assert_eq!(c_conflicts, b_conflicts);
+
+        let open = eng.conflict_store().find_open().unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].id, b_conflicts[0]);
+        assert_eq!(open[0].belief_ids.len(), 3);
+        for belief in [a, b, c] {
+            assert!(open[0].involves_belief(belief));
+        }
+    }
+
     #[test]
     fn strict_mode_rejects_value_contradictions() {
         let (eng, id) = engine();
 
-        let first = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "is_superconductor".to_string(),
-            value: Value::Bool(false),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let first = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(
+                id, "is_superconductor", Value::Bool(false), "a", ConsistencyMode::Force,
+            )
         }));
         eng.execute(first).unwrap();
 
-        let strict = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "is_superconductor".to_string(),
-            value: Value::Bool(true),
+        let strict = KyroIR::new(Operation::Assert(AssertPayload {
             confidence: Confidence::from_agent(0.8, "b").unwrap(),
-            source: Source::agent("b", Option::<String>::None),
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Strict,
-            embedding: None,
+            ..assert_payload(
+                id, "is_superconductor", Value::Bool(true), "b", ConsistencyMode::Strict,
+            )
         }));
 
         let err = eng.execute(strict).unwrap_err();
@@ -1603,27 +3651,15 @@ mod tests {
         }));
         eng.execute(define).unwrap();
 
-        let a1 = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "p1".to_string(),
-            value: Value::Bool(true),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let a1 = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(id, "p1", Value::Bool(true), "a", ConsistencyMode::Force)
         }));
         eng.execute(a1).unwrap();
 
-        let a2 = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "p2".to_string(),
-            value: Value::Bool(true),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let a2 = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            ..assert_payload(id, "p2", Value::Bool(true), "a", ConsistencyMode::Eventual)
         }));
         let EngineResponse::Assert { conflict_ids, .. } = eng.execute(a2).unwrap() else {
             panic!("expected assert");
@@ -1632,65 +3668,245 @@ mod tests {
     }
 
     #[test]
-    fn strict_mode_rejects_range_pattern_violation() {
+    fn cross_predicate_order_flags_temperature_above_boiling_point() {
         let (eng, id) = engine();
 
-        let define = KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
-            name: "temp_range".to_string(),
+        eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "below_boiling".to_string(),
             description: None,
-            rule: PatternRule::Range {
-                predicate: "temperature".to_string(),
-                min: Some(0.0),
-                max: Some(100.0),
-            },
+            rule: PatternRule::cross_predicate_order(
+                "temperature",
+                crate::monitor::ComparisonOp::Lt,
+                "boiling_point",
+            ),
             confidence: Confidence::from_agent(0.9, "a").unwrap(),
             valid_time: TimeRange::forever(),
-        }));
-        eng.execute(define).unwrap();
-
-        let bad = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "temperature".to_string(),
-            value: Value::Float(-5.0),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
-            valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Strict,
-            embedding: None,
-        }));
+        })))
+        .unwrap();
 
-        let err = eng.execute(bad).unwrap_err();
-        let KyroError::Execution(ExecutionError::ConflictsDetected { conflicts }) = err else {
-            panic!("expected ConflictsDetected, got {err:?}");
+        let assert_num = |predicate: &str, v: f64| {
+            let ir = KyroIR::new(Operation::Assert(assert_payload(
+                id, predicate, Value::Float(v), "a", ConsistencyMode::Eventual,
+            )));
+            let EngineResponse::Assert { conflict_ids, .. } = eng.execute(ir).unwrap() else {
+                panic!("expected assert");
+            };
+            conflict_ids
         };
-        assert!(conflicts.iter().any(|c| c.starts_with("pattern_violation")));
+
+        // No boiling point known yet: nothing to compare against.
+        assert!(assert_num("temperature", 50.0).is_empty());
+        // Asserting the other side checks it too.
+        assert!(assert_num("boiling_point", 100.0).is_empty());
+
+        // The new reading also contradicts the earlier one; pick out the pattern violation.
+        let reasons: Vec<String> = assert_num("temperature", 120.0)
+            .into_iter()
+            .filter_map(|cid| eng.conflicts.get(cid).unwrap())
+            .filter_map(|c| c.metadata["reason"].as_str().map(str::to_string))
+            .collect();
+        assert_eq!(reasons, vec!["'temperature' (120) is not < 'boiling_point' (100)".to_string()]);
     }
 
     #[test]
-    fn eventual_mode_records_regex_pattern_violation() {
-        let (eng, id) = engine();
+    fn cross_predicate_order_rejects_a_predicate_compared_with_itself() {
+        let (eng, _) = engine();
+        let err = eng
+            .execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                name: "self_order".to_string(),
+                description: None,
+                rule: PatternRule::cross_predicate_order("temperature", crate::monitor::ComparisonOp::Lt, " temperature"),
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            })))
+            .unwrap_err();
+        assert!(matches!(err, KyroError::Validation(ValidationError::InvalidPatternRule { .. })), "{err:?}");
+    }
 
-        let define = KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
-            name: "email_format".to_string(),
+    #[test]
+    fn json_path_pattern_checks_nested_structured_field() {
+        let (eng, _) = engine();
+        let define = |name: &str, predicate: &str, missing_is_violation: bool| {
+            eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                name: name.to_string(),
+                description: None,
+                rule: PatternRule::JsonPath {
+                    predicate: predicate.to_string(),
+                    path: "/location/zip".to_string(),
+                    expected: crate::pattern::JsonExpectation::Range {
+                        min: Some(10_000.0),
+                        max: Some(99_999.0),
+                    },
+                    missing_is_violation,
+                },
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            })))
+        };
+        define("zip_range", "address", false).unwrap();
+        define("zip_required", "shipping", true).unwrap();
+
+        // Each assert targets a fresh entity so only the pattern can reject it.
+        let assert_json = |predicate: &str, value: Value| {
+            let entity = Entity::new("site", EntityType::Concept);
+            let entity_id = entity.id;
+            eng.entity_store().insert(entity).unwrap();
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                entity_id, predicate, value, "a", ConsistencyMode::Strict,
+            ))))
+        };
+        let json = |v: serde_json::Value| Value::Structured(v);
+
+        assert!(assert_json("address", json(serde_json::json!({"location": {"zip": 94107}}))).is_ok());
+        assert!(assert_json("address", json(serde_json::json!({"location": {"zip": 123}}))).is_err());
+        assert!(assert_json("address", json(serde_json::json!({"location": {"zip": "94107"}}))).is_err());
+        assert!(assert_json("address", Value::String("94107".to_string())).is_err());
+
+        // Missing fields pass unless the pattern says otherwise.
+        assert!(assert_json("address", json(serde_json::json!({"location": {}}))).is_ok());
+        assert!(assert_json("shipping", json(serde_json::json!({"location": {}}))).is_err());
+        assert!(assert_json("shipping", json(serde_json::json!({"location": {"zip": 10001}}))).is_ok());
+
+        let bad_path = eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "bad".to_string(),
             description: None,
-            rule: PatternRule::Regex {
-                predicate: "email".to_string(),
-                pattern: r"^[^@]+@[^@]+\.[^@]+$".to_string(),
+            rule: PatternRule::json_path(
+                "address",
+                "location.zip",
+                crate::pattern::JsonExpectation::Equals { value: serde_json::json!(1) },
+            ),
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            valid_time: TimeRange::forever(),
+        })));
+        assert!(matches!(
+            bad_path,
+            Err(KyroError::Validation(ValidationError::InvalidPatternRule { .. }))
+        ));
+    }
+
+    #[test]
+    fn referential_integrity_pattern_rejects_dangling_entity_refs() {
+        let (eng, id) = engine();
+        eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "employer_exists".to_string(),
+            description: None,
+            rule: PatternRule::referential_integrity("employed_by"),
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            valid_time: TimeRange::forever(),
+        })))
+        .unwrap();
+        let employer = Entity::new("Acme", EntityType::Organization);
+        let employer_id = employer.id;
+        eng.entity_store().insert(employer).unwrap();
+
+        let assert_employer = |value: Value, mode: ConsistencyMode| {
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                id, "employed_by", value, "a", mode,
+            ))))
+        };
+
+        assert!(assert_employer(Value::Entity(employer_id), ConsistencyMode::Strict).is_ok());
+        assert!(assert_employer(Value::Entity(EntityId::new()), ConsistencyMode::Strict).is_err());
+        assert!(assert_employer(Value::from("Acme"), ConsistencyMode::Strict).is_err());
+
+        let dangling = EntityId::new();
+        let EngineResponse::Assert { conflict_ids, .. } =
+            assert_employer(Value::Entity(dangling), ConsistencyMode::Eventual).unwrap()
+        else {
+            panic!("expected assert");
+        };
+        // It also contradicts the stored employer, which is a separate value conflict.
+        let reasons: Vec<_> = conflict_ids
+            .iter()
+            .map(|c| eng.conflict_store().get(*c).unwrap().unwrap().metadata["reason"].clone())
+            .collect();
+        assert!(reasons.contains(&serde_json::json!(format!("referenced entity {dangling} does not exist"))));
+    }
+
+    #[test]
+    fn monotonic_confidence_pattern_flags_confidence_moving_the_wrong_way() {
+        let (eng, id) = engine();
+        let define = |predicate: &str, direction: crate::pattern::MonotonicDirection| {
+            eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                name: format!("{predicate}_confidence"),
+                description: None,
+                rule: PatternRule::monotonic_confidence(predicate, direction),
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            })))
+            .unwrap();
+        };
+        define("total_downloads", crate::pattern::MonotonicDirection::Increasing);
+        define("error_rate", crate::pattern::MonotonicDirection::Decreasing);
+
+        let assert = |predicate: &str, value: i64, confidence: f32, mode: ConsistencyMode| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                confidence: Confidence::from_agent(confidence, "a").unwrap(),
+                ..assert_payload(id, predicate, Value::Int(value), "a", mode)
+            })))
+        };
+
+        // Replace supersedes the previous reading, so only the pattern can reject a write.
+        assert("total_downloads", 100, 0.6, ConsistencyMode::Replace).unwrap();
+        assert("total_downloads", 200, 0.8, ConsistencyMode::Replace).unwrap();
+        assert("total_downloads", 300, 0.8, ConsistencyMode::Replace).unwrap();
+        let err = assert("total_downloads", 400, 0.5, ConsistencyMode::Replace).unwrap_err();
+        assert!(matches!(err, KyroError::Execution(ExecutionError::ConflictsDetected { .. })), "{err:?}");
+
+        assert("error_rate", 5, 0.4, ConsistencyMode::Replace).unwrap();
+        assert("error_rate", 3, 0.3, ConsistencyMode::Replace).unwrap();
+        assert!(assert("error_rate", 2, 0.9, ConsistencyMode::Replace).is_err());
+    }
+
+    #[test]
+    fn strict_mode_rejects_range_pattern_violation() {
+        let (eng, id) = engine();
+
+        let define = KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "temp_range".to_string(),
+            description: None,
+            rule: PatternRule::Range {
+                predicate: "temperature".to_string(),
+                min: Some(0.0),
+                max: Some(100.0),
+                coerce: false,
             },
             confidence: Confidence::from_agent(0.9, "a").unwrap(),
             valid_time: TimeRange::forever(),
         }));
         eng.execute(define).unwrap();
 
-        let bad = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "email".to_string(),
-            value: Value::String("not-an-email".to_string()),
+        let bad = KyroIR::new(Operation::Assert(AssertPayload {
+            valid_time: TimeRange::from_now(),
+            ..assert_payload(id, "temperature", Value::Float(-5.0), "a", ConsistencyMode::Strict)
+        }));
+
+        let err = eng.execute(bad).unwrap_err();
+        let KyroError::Execution(ExecutionError::ConflictsDetected { conflicts }) = err else {
+            panic!("expected ConflictsDetected, got {err:?}");
+        };
+        assert!(conflicts.iter().any(|c| c.starts_with("pattern_violation")));
+    }
+
+    #[test]
+    fn eventual_mode_records_regex_pattern_violation() {
+        let (eng, id) = engine();
+
+        let define = KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "email_format".to_string(),
+            description: None,
+            rule: PatternRule::Regex {
+                predicate: "email".to_string(),
+                pattern: r"^[^@]+@[^@]+\.[^@]+$".to_string(),
+            },
             confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+            valid_time: TimeRange::forever(),
+        }));
+        eng.execute(define).unwrap();
+
+        let bad = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            ..assert_payload(id, "email", "not-an-email", "a", ConsistencyMode::Eventual)
         }));
 
         let EngineResponse::Assert { conflict_ids, .. } = eng.execute(bad).unwrap() else {
@@ -1699,6 +3915,53 @@ mod tests {
         assert!(!conflict_ids.is_empty());
     }
 
+    #[test]
+    fn custom_rule_evaluator_reports_pattern_violation() {
+        let (eng, id) = engine();
+        let custom = |name: &str| {
+            KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                name: "no_forbidden".to_string(),
+                description: None,
+                rule: PatternRule::Custom {
+                    name: name.to_string(),
+                    description: "rejects the string 'forbidden'".to_string(),
+                    expression: None,
+                },
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            }))
+        };
+
+        let err = eng.execute(custom("forbid_value")).unwrap_err();
+        assert!(matches!(
+            err,
+            KyroError::Validation(ValidationError::InvalidPatternRule { .. })
+        ));
+
+        eng.register_custom_rule("forbid_value", |belief: &Belief, _: &dyn BeliefStore, _| {
+            (belief.value == Value::String("forbidden".to_string()))
+                .then(|| format!("'{}' may not be 'forbidden'", belief.predicate))
+        })
+        .unwrap();
+        eng.execute(custom("forbid_value")).unwrap();
+
+        let assert = |value: &str| {
+            KyroIR::new(Operation::Assert(AssertPayload {
+                valid_time: TimeRange::from_now(),
+                embedding: Some(vec![0.0, 1.0, 0.0]),
+                ..assert_payload(id, "codename", value, "a", ConsistencyMode::Strict)
+            }))
+        };
+
+        let err = eng.execute(assert("forbidden")).unwrap_err();
+        let KyroError::Execution(ExecutionError::ConflictsDetected { conflicts }) = err else {
+            panic!("expected ConflictsDetected, got {err:?}");
+        };
+        assert!(conflicts.iter().any(|c| c.starts_with("pattern_violation")));
+
+        eng.execute(assert("allowed")).unwrap();
+    }
+
     #[test]
     fn strict_mode_rejects_unique_violation() {
         let (eng, id) = engine();
@@ -1714,27 +3977,15 @@ mod tests {
         }));
         eng.execute(define).unwrap();
 
-        let first = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "ssn".to_string(),
-            value: Value::String("123-45-6789".to_string()),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let first = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Force,
-            embedding: None,
+            ..assert_payload(id, "ssn", "123-45-6789", "a", ConsistencyMode::Force)
         }));
         eng.execute(first).unwrap();
 
-        let second = KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
-            entity_id: id,
-            predicate: "ssn".to_string(),
-            value: Value::String("123-45-6789".to_string()),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
+        let second = KyroIR::new(Operation::Assert(AssertPayload {
             valid_time: TimeRange::from_now(),
-            consistency_mode: ConsistencyMode::Strict,
-            embedding: None,
+            ..assert_payload(id, "ssn", "123-45-6789", "a", ConsistencyMode::Strict)
         }));
 
         let err = eng.execute(second).unwrap_err();
@@ -1758,15 +4009,9 @@ mod tests {
             version: KyroIR::CURRENT_VERSION.to_string(),
             request_id: Uuid::new_v4(),
             timestamp: t1,
-            operation: Operation::Assert(crate::ir::AssertPayload {
-                entity_id: id,
-                predicate: "status".to_string(),
-                value: Value::String("active".to_string()),
-                confidence: Confidence::from_agent(0.9, "a").unwrap(),
-                source: Source::agent("a", Option::<String>::None),
+            operation: Operation::Assert(AssertPayload {
                 valid_time: TimeRange::starting_at(t0),
-                consistency_mode: ConsistencyMode::Force,
-                embedding: None,
+                ..assert_payload(id, "status", "active", "a", ConsistencyMode::Force)
             }),
         };
 
@@ -1782,6 +4027,7 @@ mod tests {
                 belief_id,
                 reason: Some("no longer true".to_string()),
                 authorized_by: Source::agent("system", Option::<String>::None),
+                namespace: None,
             }),
         };
 
@@ -1843,26 +4089,14 @@ mod tests {
         let (eng, id) = engine();
 
         eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
-            entity_id: id,
-            predicate: "status".to_string(),
-            value: Value::String("on".to_string()),
             confidence: Confidence::from_agent(0.7, "a").unwrap(),
-            source: Source::agent("a", Option::<String>::None),
-            valid_time: TimeRange::forever(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            ..assert_payload(id, "status", "on", "a", ConsistencyMode::Eventual)
         })))
         .unwrap();
 
         eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
-            entity_id: id,
-            predicate: "status".to_string(),
-            value: Value::String("off".to_string()),
             confidence: Confidence::from_agent(0.8, "b").unwrap(),
-            source: Source::agent("b", Option::<String>::None),
-            valid_time: TimeRange::forever(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            ..assert_payload(id, "status", "off", "b", ConsistencyMode::Eventual)
         })))
         .unwrap();
 
@@ -1899,9 +4133,14 @@ mod tests {
             tx_time: t0,
             reason: None,
             consistency_status: ConsistencyStatus::Verified,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         let new = Belief {
@@ -1915,9 +4154,14 @@ mod tests {
             tx_time: t0 + chrono::Duration::seconds(5),
             reason: None,
             consistency_status: ConsistencyStatus::Verified,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         belief_store.insert(old).unwrap();
@@ -1949,34 +4193,24 @@ mod tests {
         let source_b = Source::agent("b", Option::<String>::None);
 
         // In the "status" domain, downweight A and leave B at 1.0.
-        model.set_domain("status", source_a.source_id(), 0.0);
-        model.set_domain("status", source_b.source_id(), 1.0);
+        model.set_domain("status", source_a.source_id(), 0.0).unwrap();
+        model.set_domain("status", source_b.source_id(), 1.0).unwrap();
 
         let (eng, id) = engine_with_trust_model(model);
 
         // Without trust weighting, A would win (0.9 > 0.2).
         // With trust weighting in the default scope (predicate "status"), B should win.
         eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
-            entity_id: id,
-            predicate: "status".to_string(),
-            value: Value::String("off".to_string()),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
             source: source_a.clone(),
-            valid_time: TimeRange::forever(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            ..assert_payload(id, "status", "off", "a", ConsistencyMode::Eventual)
         })))
         .unwrap();
 
         eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
-            entity_id: id,
-            predicate: "status".to_string(),
-            value: Value::String("on".to_string()),
-            confidence: Confidence::from_agent(0.2, "b").unwrap(),
             source: source_b.clone(),
-            valid_time: TimeRange::forever(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            confidence: Confidence::from_agent(0.2, "b").unwrap(),
+            ..assert_payload(id, "status", "on", "a", ConsistencyMode::Eventual)
         })))
         .unwrap();
 
@@ -2004,32 +4238,22 @@ mod tests {
         let source_b = Source::agent("b", Option::<String>::None);
 
         // Domain overrides apply only when that domain is selected.
-        model.set_domain("status", source_a.source_id(), 0.0);
-        model.set_domain("status", source_b.source_id(), 1.0);
+        model.set_domain("status", source_a.source_id(), 0.0).unwrap();
+        model.set_domain("status", source_b.source_id(), 1.0).unwrap();
 
         let (eng, id) = engine_with_trust_model(model);
 
         eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
-            entity_id: id,
-            predicate: "status".to_string(),
-            value: Value::String("off".to_string()),
-            confidence: Confidence::from_agent(0.9, "a").unwrap(),
             source: source_a.clone(),
-            valid_time: TimeRange::forever(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            ..assert_payload(id, "status", "off", "a", ConsistencyMode::Eventual)
         })))
         .unwrap();
 
         eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
-            entity_id: id,
-            predicate: "status".to_string(),
-            value: Value::String("on".to_string()),
-            confidence: Confidence::from_agent(0.2, "b").unwrap(),
             source: source_b.clone(),
-            valid_time: TimeRange::forever(),
-            consistency_mode: ConsistencyMode::Eventual,
-            embedding: None,
+            confidence: Confidence::from_agent(0.2, "b").unwrap(),
+            ..assert_payload(id, "status", "on", "a", ConsistencyMode::Eventual)
         })))
         .unwrap();
 
@@ -2050,4 +4274,3036 @@ mod tests {
             Value::String("off".to_string())
         );
     }
+
+    #[test]
+    fn embedders_with_different_dims_cannot_share_a_store() {
+        use crate::embedding::{EmbeddingConfig, LexicalEmbedder};
+
+        let stores = InMemoryStores::new();
+        let entities: Arc<dyn EntityStore> = Arc::new(stores.entities);
+        let beliefs: Arc<dyn BeliefStore> = Arc::new(stores.beliefs);
+        let patterns: Arc<dyn PatternStore> = Arc::new(stores.patterns);
+        let conflicts: Arc<dyn ConflictStore> = Arc::new(stores.conflicts);
+        let derivations: Arc<dyn DerivationStore> = Arc::new(stores.derivations);
+
+        let embedder = |dim| {
+            Arc::new(
+                LexicalEmbedder::new(EmbeddingConfig {
+                    dim,
+                    ..EmbeddingConfig::default()
+                })
+                .unwrap(),
+            )
+        };
+
+        let small = KyroEngine::new(
+            Arc::clone(&entities),
+            Arc::clone(&beliefs),
+            Arc::clone(&patterns),
+            Arc::clone(&conflicts),
+            Arc::clone(&derivations),
+        )
+        .with_embedder(embedder(16));
+        let large = KyroEngine::new(entities.clone(), beliefs.clone(), patterns, conflicts, derivations)
+            .with_embedder(embedder(32));
+        assert_eq!(small.embedder().dim(), 16);
+
+        let entity = Entity::new("LK-99", EntityType::Concept);
+        let id = entity.id;
+        entities.insert(entity).unwrap();
+
+        let assert_ir = |predicate: &str| {
+            KyroIR::new(Operation::Assert(AssertPayload {
+                valid_time: TimeRange::from_now(),
+                ..assert_payload(id, predicate, Value::Bool(true), "a", ConsistencyMode::Force)
+            }))
+        };
+
+        let EngineResponse::Assert { belief_id, .. } = small.execute(assert_ir("p1")).unwrap() else {
+            panic!("expected assert");
+        };
+        assert_eq!(beliefs.get(belief_id).unwrap().unwrap().embedding.unwrap().len(), 16);
+
+        let err = large.execute(assert_ir("p2")).unwrap_err();
+        assert!(
+            matches!(err, KyroError::Execution(ExecutionError::Storage { ref message }) if message.contains("dimension mismatch")),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn semantic_resolve_embeds_text_queries_with_the_engine_embedder() {
+        let (eng, id) = engine();
+        for (predicate, value) in [
+            ("headquarters", "Berlin Germany"),
+            ("chief_executive", "Jane Doe"),
+            ("founded", "nineteen ninety nine"),
+        ] {
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                id, predicate, value, "a", ConsistencyMode::Force,
+            ))))
+            .unwrap();
+        }
+        let top = |query: &str| {
+            let ir = crate::operations::ResolveBuilder::new()
+                .semantic_query(query)
+                .build()
+                .unwrap();
+            let Operation::Resolve(payload) = &ir.operation else {
+                panic!("expected resolve");
+            };
+            assert!(payload.query_embedding.is_none());
+            let EngineResponse::Resolve { frame } = eng.execute(ir).unwrap() else {
+                panic!("expected resolve");
+            };
+            let beliefs: Vec<_> = frame
+                .supporting_evidence
+                .iter()
+                .map(|e| eng.belief_store().get(e.belief_id).unwrap().unwrap().predicate)
+                .collect();
+            beliefs
+        };
+
+        assert_eq!(top("where are the headquarters, Berlin?")[0], "headquarters");
+        assert_eq!(top("chief executive Jane Doe")[0], "chief_executive");
+
+        // Without text there is nothing to embed.
+        let mut payload = ResolvePayload {
+            semantic: true,
+            ..ResolvePayload::default()
+        };
+        assert!(eng.execute(KyroIR::new(Operation::Resolve(payload.clone()))).is_err());
+        payload.query = Some("   ".to_string());
+        assert!(eng.execute(KyroIR::new(Operation::Resolve(payload))).is_err());
+    }
+
+    #[test]
+    fn reembedding_moves_beliefs_to_a_new_embedder_dimension() {
+        use crate::embedding::{EmbeddingConfig, LexicalEmbedder};
+
+        let embedder = |dim| {
+            Arc::new(
+                LexicalEmbedder::new(EmbeddingConfig {
+                    dim,
+                    ..EmbeddingConfig::default()
+                })
+                .unwrap(),
+            )
+        };
+        let (eng, id) = engine();
+        let small = eng.with_embedder(embedder(16));
+        let assert_ir = |predicate: &str, value: &str| {
+            KyroIR::new(Operation::Assert(assert_payload(
+                id, predicate, value, "a", ConsistencyMode::Force,
+            )))
+        };
+        let EngineResponse::Assert { belief_id: color, .. } =
+            small.execute(assert_ir("color", "crimson red")).unwrap()
+        else {
+            panic!("expected assert");
+        };
+        small.execute(assert_ir("weight", "twelve kilograms")).unwrap();
+
+        let new_embedder = embedder(32);
+        assert_eq!(small.reembed_beliefs(new_embedder.as_ref()).unwrap(), 2);
+        let large = small.with_embedder(new_embedder.clone());
+        assert_eq!(large.belief_store().get(color).unwrap().unwrap().embedding.unwrap().len(), 32);
+        large.execute(assert_ir("shape", "round")).unwrap();
+
+        let entity = large.entity_store().get(id).unwrap().unwrap();
+        let query = new_embedder
+            .embed(&default_embedding_text(&entity, "color", &Value::from("crimson red")))
+            .unwrap();
+        let EngineResponse::Resolve { frame } = large
+            .execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                entity_id: Some(id),
+                query_embedding: Some(query),
+                ..ResolvePayload::default()
+            })))
+            .unwrap()
+        else {
+            panic!("expected resolve");
+        };
+        assert_eq!(frame.supporting_evidence[0].belief_id, color);
+
+        // An embedder that lies about its dimension is rejected before anything changes.
+        struct Lying;
+        impl Embedder for Lying {
+            fn dim(&self) -> usize {
+                8
+            }
+            fn embed(&self, _text: &str) -> KyroResult<Vec<f32>> {
+                Ok(vec![1.0; 4])
+            }
+        }
+        assert!(matches!(
+            large.reembed_beliefs(&Lying),
+            Err(KyroError::Validation(ValidationError::InvalidEmbeddingDimension { actual: 4, expected: 8 }))
+        ));
+        assert_eq!(large.belief_store().get(color).unwrap().unwrap().embedding.unwrap().len(), 32);
+    }
+
+    fn assert_status(eng: &KyroEngine, id: EntityId, value: &str, conf: f32, agent: &str) {
+        eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+            confidence: Confidence::from_agent(conf, agent).unwrap(),
+            embedding: Some(vec![1.0, 0.0, 0.0]),
+            ..assert_payload(id, "status", value, agent, ConsistencyMode::Eventual)
+        })))
+        .unwrap();
+    }
+
+    /// An ASSERT of `predicate = value` on `id` by agent `source` at confidence 0.9, valid
+    /// forever. Tests override any other field with struct update syntax.
+    fn assert_payload(
+        id: EntityId,
+        predicate: &str,
+        value: impl Into<Value>,
+        source: &str,
+        mode: ConsistencyMode,
+    ) -> AssertPayload {
+        AssertPayload {
+            entity_id: id,
+            predicate: predicate.to_string(),
+            value: value.into(),
+            confidence: Confidence::from_agent(0.9, source).unwrap(),
+            source: Source::agent(source, Option::<String>::None),
+            valid_time: TimeRange::forever(),
+            consistency_mode: mode,
+            embedding: None,
+            idempotency_key: None,
+            namespace: None,
+        }
+    }
+
+    fn resolve_status(eng: &KyroEngine, id: EntityId, semantic: bool) -> BeliefFrame {
+        let payload = ResolvePayload {
+            entity_id: Some(id),
+            predicate: Some("status".to_string()),
+            query_embedding: semantic.then(|| vec![1.0, 0.0, 0.0]),
+            ..ResolvePayload::default()
+        };
+        let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+            panic!("expected resolve");
+        };
+        frame
+    }
+
+    #[test]
+    fn replace_assert_supersedes_prior_beliefs_and_wins_resolve() {
+        let (eng, id) = engine();
+        eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "single_status".to_string(),
+            description: None,
+            rule: PatternRule::unique("status"),
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            valid_time: TimeRange::forever(),
+        })))
+        .unwrap();
+
+        let assert = |value: &str, mode: ConsistencyMode| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, "status", value, "a", mode)
+            })))
+        };
+        let belief_id = |resp: EngineResponse| match resp {
+            EngineResponse::Assert { belief_id, .. } => belief_id,
+            other => panic!("expected assert, got {other:?}"),
+        };
+
+        let draft = belief_id(assert("draft", ConsistencyMode::Strict).unwrap());
+        let review = belief_id(assert("review", ConsistencyMode::Eventual).unwrap());
+        assert!(assert("final", ConsistencyMode::Strict).is_err());
+
+        let EngineResponse::Assert { belief_id: final_id, conflict_ids, .. } =
+            assert("final", ConsistencyMode::Replace).unwrap()
+        else {
+            panic!("expected assert");
+        };
+        assert!(conflict_ids.is_empty());
+
+        let new = eng.beliefs.get(final_id).unwrap().unwrap();
+        assert_eq!(new.supersedes, vec![review, draft]);
+        for old_id in [draft, review] {
+            let old = eng.beliefs.get(old_id).unwrap().unwrap();
+            assert_eq!(old.superseded_by, Some(final_id));
+            assert!(old.valid_time.to().is_some_and(|to| to <= new.tx_time));
+        }
+
+        let frame = resolve_status(&eng, id, false);
+        assert_eq!(
+            frame.best_supported_claim.unwrap().belief.value,
+            Value::String("final".to_string())
+        );
+        assert!(frame.counter_evidence.is_empty());
+    }
+
+    #[test]
+    fn concurrent_replace_asserts_leave_one_active_belief() {
+        let (eng, id) = engine();
+        let assert = |value: String| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, "status", value, "a", ConsistencyMode::Replace)
+            })))
+        };
+        assert("v0".to_string()).unwrap();
+
+        let barrier = std::sync::Barrier::new(8);
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (1..=8)
+                .map(|i| {
+                    let (assert, barrier) = (&assert, &barrier);
+                    scope.spawn(move || {
+                        barrier.wait();
+                        assert(format!("v{i}"))
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        // A Replace that lost the race is not stored, so only winners' beliefs exist.
+        let stored = eng.beliefs.find_by_entity_predicate(id, "status").unwrap();
+        let winners = results.iter().filter(|r| r.is_ok()).count();
+        assert!(winners >= 1);
+        assert_eq!(stored.len(), 1 + winners);
+        let active: Vec<_> = stored.iter().filter(|b| b.superseded_by.is_none()).collect();
+        assert_eq!(active.len(), 1);
+    }
+
+    #[test]
+    fn rate_limiter_rejects_source_over_budget_without_writing() {
+        let (eng, id) = engine();
+        let limiter = TokenBucketRateLimiter::new(2, std::time::Duration::from_secs(3600)).unwrap();
+        let eng = eng.with_rate_limiter(Arc::new(limiter));
+        let assert = |agent: &str, key: Option<&str>| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                idempotency_key: key.map(str::to_string),
+                ..assert_payload(id, "status", "replicated", agent, ConsistencyMode::Eventual)
+            })))
+        };
+
+        assert("flooder", Some("k1")).unwrap();
+        assert("flooder", None).unwrap();
+        let err = assert("flooder", None).unwrap_err();
+        assert!(matches!(
+            err,
+            KyroError::Execution(ExecutionError::QueueFull { capacity: 2, .. })
+        ));
+        // Replaying an idempotent assert writes nothing and so costs nothing.
+        assert("flooder", Some("k1")).unwrap();
+
+        assert("polite", None).unwrap();
+        assert_eq!(eng.belief_store().stats().unwrap().records, 3);
+    }
+
+    #[test]
+    fn conflict_severity_ranks_confident_contradictions_first() {
+        let (eng, id) = engine();
+        let weak = Entity::new("weak", EntityType::Concept);
+        let weak_id = weak.id;
+        eng.entity_store().insert(weak).unwrap();
+
+        assert_status(&eng, id, "replicated", 0.99, "a");
+        assert_status(&eng, id, "retracted", 0.95, "b");
+        // A weak claim contradicts a confident one.
+        assert_status(&eng, weak_id, "replicated", 0.99, "a");
+        assert_status(&eng, weak_id, "disputed", 0.3, "c");
+
+        let open = eng.conflict_store().find_open().unwrap();
+        let severities: Vec<f32> = open.iter().map(|c| c.severity).collect();
+        assert_eq!(open.len(), 2);
+        assert_eq!(open[0].entity_id, id);
+        assert!((severities[0] - 0.95).abs() < 1e-6, "{severities:?}");
+        assert!((severities[1] - 0.3).abs() < 1e-6, "{severities:?}");
+
+        let frame = resolve_status(&eng, id, false);
+        assert_eq!(frame.conflicts.len(), 1);
+        assert!((frame.conflicts[0].severity - 0.95).abs() < 1e-6);
+
+        // A weak claim joining a cluster lowers the cluster's severity.
+        assert_status(&eng, id, "disputed", 0.3, "c");
+        let frame = resolve_status(&eng, id, false);
+        assert_eq!(frame.conflicts.len(), 1);
+        assert!((frame.conflicts[0].severity - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn namespaces_with_identical_names_resolve_independently() {
+        let (eng, _) = engine();
+        let mut ids = Vec::new();
+        for ns in ["tenant-a", "tenant-b"] {
+            let entity = Entity::new("Acme", EntityType::Organization).with_namespace(ns);
+            ids.push(entity.id);
+            eng.entity_store().insert(entity).unwrap();
+        }
+        let (a, b) = (ids[0], ids[1]);
+
+        let assert_in = |ns: &str, id: EntityId, value: &str| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                namespace: Some(ns.to_string()),
+                ..assert_payload(id, "status", value, "a", ConsistencyMode::Eventual)
+            })))
+        };
+        assert_in("tenant-a", a, "active").unwrap();
+        assert_in("tenant-b", b, "dissolved").unwrap();
+
+        // An entity of another namespace is indistinguishable from a missing one.
+        let err = assert_in("tenant-a", b, "active").unwrap_err();
+        assert!(matches!(
+            err,
+            KyroError::Execution(ExecutionError::EntityNotFound { id }) if id == b
+        ));
+
+        // Same name and predicate, different values, no cross-namespace conflict.
+        assert!(eng.conflict_store().find_open().unwrap().is_empty());
+
+        let resolve_in = |ns: &str, query_embedding: Option<Vec<f32>>| {
+            let payload = ResolvePayload {
+                query: Some("Acme".to_string()),
+                predicate: Some("status".to_string()),
+                query_embedding,
+                namespace: Some(ns.to_string()),
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+        for semantic in [None, Some(vec![1.0, 0.0, 0.0])] {
+            let frame = resolve_in("tenant-a", semantic.clone());
+            let claim = frame.best_supported_claim.expect("tenant-a answer");
+            assert_eq!(claim.belief.subject, a);
+            assert_eq!(claim.belief.value, Value::String("active".to_string()));
+
+            let frame = resolve_in("tenant-b", semantic);
+            let claim = frame.best_supported_claim.expect("tenant-b answer");
+            assert_eq!(claim.belief.subject, b);
+            assert_eq!(claim.belief.value, Value::String("dissolved".to_string()));
+        }
+
+        // The default namespace cannot reach tenant entities by id.
+        let payload = ResolvePayload {
+            entity_id: Some(a),
+            predicate: Some("status".to_string()),
+            ..ResolvePayload::default()
+        };
+        assert!(eng.execute(KyroIR::new(Operation::Resolve(payload))).is_err());
+    }
+
+    #[test]
+    fn store_stats_track_asserts_and_retracts() {
+        let (eng, id) = engine();
+        let other = Entity::new("Graphene", EntityType::Concept);
+        let other_id = other.id;
+        eng.entity_store().insert(other).unwrap();
+
+        assert_status(&eng, id, "replicated", 0.9, "a");
+        assert_status(&eng, id, "replicated", 0.8, "b");
+        let EngineResponse::Assert { belief_id, .. } = eng
+            .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![0.0, 1.0, 0.0]),
+                ..assert_payload(
+                    other_id, "conductivity", Value::Float(5000.0), "c", ConsistencyMode::Eventual,
+                )
+            })))
+            .unwrap()
+        else {
+            panic!("expected assert");
+        };
+
+        eng.execute(KyroIR::new(Operation::Retract(RetractPayload {
+            belief_id,
+            reason: None,
+            authorized_by: Source::agent("system", Option::<String>::None),
+            namespace: None,
+        })))
+        .unwrap();
+
+        // The retraction is itself a belief, superseding the retracted one.
+        let beliefs = eng.belief_store().stats().unwrap();
+        assert_eq!(beliefs.records, 4);
+        assert_eq!(beliefs.distinct_entities, Some(2));
+        assert_eq!(beliefs.distinct_predicates, Some(2));
+
+        assert_eq!(eng.entity_store().stats().unwrap().records, 2);
+        assert_eq!(eng.conflict_store().stats().unwrap().records, 0);
+        assert_eq!(eng.entity_store().stats().unwrap().distinct_entities, None);
+    }
+
+    #[test]
+    fn epistemic_confidence_is_penalized_by_counter_evidence() {
+        for semantic in [false, true] {
+            let (clean, clean_id) = engine();
+            assert_status(&clean, clean_id, "on", 0.9, "a");
+            let unopposed = resolve_status(&clean, clean_id, semantic);
+
+            let (contested, contested_id) = engine();
+            assert_status(&contested, contested_id, "on", 0.9, "a");
+            assert_status(&contested, contested_id, "off", 0.85, "b");
+            let opposed = resolve_status(&contested, contested_id, semantic);
+
+            let unopposed_conf = unopposed.epistemic_confidence.unwrap();
+            let opposed_conf = opposed.epistemic_confidence.unwrap();
+            assert!((unopposed_conf - 0.9).abs() < 1e-6, "semantic={semantic}");
+            assert!(opposed_conf < unopposed_conf, "semantic={semantic}");
+            assert!((0.0..=1.0).contains(&opposed_conf));
+
+            // The winning claim itself still reports its own confidence.
+            let claim = opposed.best_supported_claim.unwrap();
+            assert!((claim.epistemic_confidence - 0.9).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn epistemic_confidence_respects_combination_function() {
+        let (eng, id) = engine();
+        assert_status(&eng, id, "on", 0.9, "a");
+        assert_status(&eng, id, "off", 0.85, "b");
+
+        let resolve = |combination| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                evidence_combination: Some(combination),
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.epistemic_confidence.unwrap()
+        };
+
+        let subtract = resolve(EvidenceCombination::Subtract);
+        let ratio = resolve(EvidenceCombination::Ratio);
+        assert!((subtract - 0.05).abs() < 1e-5);
+        assert!((ratio - 0.9 / 1.75).abs() < 1e-5);
+    }
+
+    fn resolve_status_filtered(eng: &KyroEngine, id: EntityId, semantic: bool, value: &str) -> BeliefFrame {
+        let payload = ResolvePayload {
+            entity_id: Some(id),
+            predicate: Some("status".to_string()),
+            query_embedding: semantic.then(|| vec![1.0, 0.0, 0.0]),
+            include_counter_evidence: true,
+            value_filter: Some(Value::String(value.to_string())),
+            ..ResolvePayload::default()
+        };
+        let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+            panic!("expected resolve");
+        };
+        frame
+    }
+
+    #[test]
+    fn value_filter_isolates_one_side_of_a_contested_predicate() {
+        for semantic in [false, true] {
+            let (eng, id) = engine();
+            assert_status(&eng, id, "on", 0.9, "a");
+            assert_status(&eng, id, "on", 0.6, "c");
+            assert_status(&eng, id, "off", 0.85, "b");
+
+            let unfiltered = resolve_status(&eng, id, semantic);
+            let claim = unfiltered.best_supported_claim.unwrap();
+            assert_eq!(claim.belief.value, Value::String("on".to_string()));
+
+            let frame = resolve_status_filtered(&eng, id, semantic, "off");
+            let claim = frame.best_supported_claim.as_ref().unwrap();
+            assert_eq!(claim.belief.value, Value::String("off".to_string()), "semantic={semantic}");
+            assert_eq!(frame.supporting_evidence.len(), 1, "semantic={semantic}");
+            assert_eq!(frame.counter_evidence.len(), 2, "semantic={semantic}");
+
+            // Support for "off" is discounted by both "on" beliefs.
+            let conf = frame.epistemic_confidence.unwrap();
+            assert!(conf < 0.85, "semantic={semantic}");
+        }
+    }
+
+    #[test]
+    fn value_filter_without_matches_reports_gap() {
+        for semantic in [false, true] {
+            let (eng, id) = engine();
+            assert_status(&eng, id, "on", 0.9, "a");
+            assert_status(&eng, id, "off", 0.85, "b");
+
+            let frame = resolve_status_filtered(&eng, id, semantic, "standby");
+            assert!(frame.best_supported_claim.is_none(), "semantic={semantic}");
+            assert!(frame.epistemic_confidence.is_none());
+            assert!(frame
+                .gaps
+                .iter()
+                .any(|g| g.gap_type == crate::frame::GapType::NoDataFound
+                    && g.missing_entity == Some(id)
+                    && g.missing_predicate.as_deref() == Some("status")));
+        }
+    }
+
+    #[test]
+    fn tie_break_strategies_order_equally_confident_beliefs() {
+        // Every belief scores 0.4 after trust weighting; only the tie-break separates them.
+        let model = Arc::new(SimpleTrustModel::new());
+        let trusted = Source::agent("trusted", Option::<String>::None);
+        let other = Source::agent("other", Option::<String>::None);
+        model.set_global(trusted.source_id(), 0.8).unwrap();
+        model.set_global(other.source_id(), 0.5).unwrap();
+        let (eng, id) = engine_with_trust_model(model);
+
+        let t0 = Utc::now() - chrono::Duration::minutes(1);
+        let fixture = [
+            // (id suffix, value, confidence, source, tx offset)
+            (4, "oldest", 0.8, &other, 0),
+            (3, "trusted", 0.5, &trusted, 5),
+            (1, "lowest_id", 0.8, &other, 3),
+            (2, "newest", 0.8, &other, 10),
+        ];
+        for (n, value, conf, source, offset) in fixture {
+            eng.beliefs
+                .insert(Belief {
+                    id: BeliefId::from(Uuid::from_u128(n)),
+                    subject: id,
+                    predicate: "status".to_string(),
+                    value: Value::String(value.to_string()),
+                    confidence: Confidence::from_agent(conf, "a").unwrap(),
+                    source: source.clone(),
+                    valid_time: TimeRange::forever(),
+                    tx_time: t0 + chrono::Duration::seconds(offset),
+                    reason: None,
+                    consistency_status: ConsistencyStatus::Verified,
+                    supersedes: Vec::new(),
+                    superseded_by: None,
+                    embedding: Some(vec![1.0, 0.0, 0.0]),
+                    namespace: None,
+                    corroborating_sources: Vec::new(),
+                    metadata: serde_json::Value::Null,
+                    idempotency_key: None,
+                    retraction: false,
+                })
+                .unwrap();
+        }
+
+        let winner = |tie_break: Option<TieBreak>, semantic: bool| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                query_embedding: semantic.then(|| vec![1.0, 0.0, 0.0]),
+                tie_break,
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.best_supported_claim.unwrap().belief.value
+        };
+
+        for semantic in [false, true] {
+            for (tie_break, expected) in [
+                (None, "newest"),
+                (Some(TieBreak::NewestTx), "newest"),
+                (Some(TieBreak::OldestTx), "oldest"),
+                (Some(TieBreak::HighestSourceTrust), "trusted"),
+                (Some(TieBreak::Lexical), "lowest_id"),
+            ] {
+                assert_eq!(
+                    winner(tie_break, semantic),
+                    Value::String(expected.to_string()),
+                    "tie_break={tie_break:?} semantic={semantic}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn relevance_weight_blends_similarity_with_confidence() {
+        let (eng, id) = engine();
+        let assert_with = |value: &str, conf: f32, embedding: Vec<f32>| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                confidence: Confidence::from_agent(conf, "a").unwrap(),
+                embedding: Some(embedding),
+                ..assert_payload(id, "status", value, "a", ConsistencyMode::Eventual)
+            })))
+            .unwrap();
+        };
+        // "close" is the nearest match but weakly supported; "solid" is slightly
+        // less similar (cos ≈ 0.8) and strongly supported.
+        assert_with("close", 0.3, vec![1.0, 0.0, 0.0]);
+        assert_with("solid", 0.95, vec![0.8, 0.6, 0.0]);
+
+        let top = |relevance_weight: f32| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                query_embedding: Some(vec![1.0, 0.0, 0.0]),
+                limit: 1,
+                relevance_weight,
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.best_supported_claim.unwrap()
+        };
+
+        let by_similarity = top(1.0);
+        assert_eq!(by_similarity.belief.value, Value::String("close".to_string()));
+        assert!((by_similarity.retrieval_relevance - 1.0).abs() < 1e-4);
+
+        // close: 0.9*1.0 + 0.1*0.3 = 0.93; solid: 0.9*0.8 + 0.1*0.95 = 0.815.
+        let mostly_similarity = top(0.9);
+        assert_eq!(mostly_similarity.belief.value, Value::String("close".to_string()));
+
+        // close: 0.5*1.0 + 0.5*0.3 = 0.65; solid: 0.5*0.8 + 0.5*0.95 = 0.875.
+        let balanced = top(0.5);
+        assert_eq!(balanced.belief.value, Value::String("solid".to_string()));
+        assert!((balanced.retrieval_relevance - 0.875).abs() < 1e-3);
+
+        let by_confidence = top(0.0);
+        assert_eq!(by_confidence.belief.value, Value::String("solid".to_string()));
+        assert!((by_confidence.retrieval_relevance - 0.95).abs() < 1e-4);
+
+        let invalid = ResolvePayload {
+            entity_id: Some(id),
+            predicate: Some("status".to_string()),
+            relevance_weight: 1.5,
+            ..ResolvePayload::default()
+        };
+        assert!(eng.execute(KyroIR::new(Operation::Resolve(invalid))).is_err());
+    }
+
+    #[test]
+    fn min_relevance_turns_weak_semantic_matches_into_a_gap() {
+        let (eng, id) = engine();
+        eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+            entity_id: id,
+            predicate: "status".to_string(),
+            value: Value::String("tangential".to_string()),
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            source: Source::agent("a", Option::<String>::None),
+            valid_time: TimeRange::forever(),
+            consistency_mode: ConsistencyMode::Eventual,
+            // cos ≈ 0.6 against the query below.
+            embedding: Some(vec![0.6, 0.8, 0.0]),
+            idempotency_key: None,
+            namespace: None,
+        })))
+        .unwrap();
+
+        let resolve = |min_relevance: f32| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                query_embedding: Some(vec![1.0, 0.0, 0.0]),
+                min_relevance: Some(min_relevance),
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+
+        let strict = resolve(0.9);
+        assert!(strict.best_supported_claim.is_none());
+        assert!(strict.supporting_evidence.is_empty());
+        assert!(strict
+            .gaps
+            .iter()
+            .any(|g| g.gap_type == crate::frame::GapType::InsufficientEvidence));
+
+        let lenient = resolve(0.5);
+        assert_eq!(
+            lenient.best_supported_claim.unwrap().belief.value,
+            Value::String("tangential".to_string())
+        );
+        assert!(lenient.gaps.is_empty());
+    }
+
+    #[test]
+    fn repeated_idempotency_key_returns_original_belief() {
+        let (eng, id) = engine();
+        let assert_with_key = |value: &str| {
+            let ir = KyroIR::new(Operation::Assert(AssertPayload {
+                idempotency_key: Some("retry-1".to_string()),
+                ..assert_payload(id, "status", value, "a", ConsistencyMode::Eventual)
+            }));
+            match eng.execute(ir).unwrap() {
+                EngineResponse::Assert { belief_id, conflict_ids, .. } => (belief_id, conflict_ids),
+                other => panic!("expected assert, got {other:?}"),
+            }
+        };
+
+        let (first, _) = assert_with_key("on");
+        // A retry (even with a drifted payload) must not insert or raise conflicts.
+        let (second, conflicts) = assert_with_key("off");
+
+        assert_eq!(first, second);
+        assert!(conflicts.is_empty());
+        assert_eq!(eng.belief_store().count_by_entity(id).unwrap(), 1);
+        assert_eq!(eng.idempotency_store().get(None, "retry-1").unwrap(), Some(first));
+    }
+
+    #[test]
+    fn concurrent_asserts_with_one_idempotency_key_write_one_belief() {
+        const THREADS: usize = 8;
+        let (eng, id) = engine();
+        let assert_with_key = |agent: &str| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                idempotency_key: Some("race".to_string()),
+                ..assert_payload(id, "status", "on", agent, ConsistencyMode::Eventual)
+            })))
+        };
+
+        let written: Vec<BeliefId> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|n| {
+                    let assert_with_key = &assert_with_key;
+                    scope.spawn(move || assert_with_key(&format!("agent-{n}")))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .filter_map(|h| match h.join().unwrap() {
+                    Ok(EngineResponse::Assert { belief_id, .. }) => Some(belief_id),
+                    Ok(other) => panic!("expected assert, got {other:?}"),
+                    // Losing the race reports the key as in flight rather than writing.
+                    Err(_) => None,
+                })
+                .collect()
+        });
+
+        assert_eq!(eng.belief_store().count_by_entity(id).unwrap(), 1);
+        let EngineResponse::Assert { belief_id, .. } = assert_with_key("late").unwrap() else {
+            panic!("expected assert");
+        };
+        assert!(!written.is_empty());
+        assert!(written.iter().all(|w| *w == belief_id));
+        assert_eq!(eng.belief_store().count_by_entity(id).unwrap(), 1);
+    }
+
+    #[test]
+    fn failed_idempotency_record_releases_the_key() {
+        use crate::storage::{IdempotencyReservation, InMemoryIdempotencyStore, StorageStats};
+
+        struct FailingRecord(InMemoryIdempotencyStore);
+        impl IdempotencyStore for FailingRecord {
+            fn get(&self, namespace: Option<&str>, key: &str) -> Result<Option<BeliefId>, StorageError> {
+                self.0.get(namespace, key)
+            }
+            fn reserve(&self, namespace: Option<&str>, key: &str) -> Result<IdempotencyReservation, StorageError> {
+                self.0.reserve(namespace, key)
+            }
+            fn release(&self, namespace: Option<&str>, key: &str) -> Result<(), StorageError> {
+                self.0.release(namespace, key)
+            }
+            fn record(&self, _: Option<&str>, _: &str, _: BeliefId) -> Result<(), StorageError> {
+                Err(StorageError::BackendError("disk full".to_string()))
+            }
+            fn stats(&self) -> Result<StorageStats, StorageError> {
+                self.0.stats()
+            }
+        }
+
+        let (eng, id) = engine();
+        let eng = eng.with_idempotency_store(Arc::new(FailingRecord(InMemoryIdempotencyStore::new())));
+        let result = eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+            idempotency_key: Some("retry-1".to_string()),
+            ..assert_payload(id, "status", "on", "a", ConsistencyMode::Eventual)
+        })));
+
+        assert!(result.is_err());
+        // The key is free again rather than held in flight forever.
+        assert_eq!(
+            eng.idempotency_store().reserve(None, "retry-1").unwrap(),
+            IdempotencyReservation::Reserved
+        );
+    }
+
+    #[test]
+    fn idempotency_keys_and_retractions_are_scoped_to_the_namespace() {
+        let (eng, _) = engine();
+        let mut ids = Vec::new();
+        for ns in ["tenant-a", "tenant-b"] {
+            let entity = Entity::new("Acme", EntityType::Organization).with_namespace(ns);
+            ids.push(entity.id);
+            eng.entity_store().insert(entity).unwrap();
+        }
+        let assert_in = |ns: &str, id: EntityId| {
+            let ir = KyroIR::new(Operation::Assert(AssertPayload {
+                idempotency_key: Some("shared".to_string()),
+                namespace: Some(ns.to_string()),
+                ..assert_payload(id, "status", "active", "a", ConsistencyMode::Eventual)
+            }));
+            let EngineResponse::Assert { belief_id, .. } = eng.execute(ir).unwrap() else {
+                panic!("expected assert");
+            };
+            belief_id
+        };
+
+        // The same key in another namespace is a different request.
+        let in_a = assert_in("tenant-a", ids[0]);
+        let in_b = assert_in("tenant-b", ids[1]);
+        assert_ne!(in_a, in_b);
+        assert_eq!(assert_in("tenant-a", ids[0]), in_a);
+
+        let retract_in = |ns: Option<&str>| {
+            eng.execute(KyroIR::new(Operation::Retract(RetractPayload {
+                belief_id: in_a,
+                reason: None,
+                authorized_by: Source::agent("a", Option::<String>::None),
+                namespace: ns.map(str::to_string),
+            })))
+        };
+        for ns in [None, Some("tenant-b")] {
+            let err = retract_in(ns).unwrap_err();
+            assert!(matches!(
+                err,
+                KyroError::Execution(ExecutionError::BeliefNotFound { id }) if id == in_a
+            ));
+        }
+        retract_in(Some("tenant-a")).unwrap();
+    }
+
+    #[test]
+    fn feedback_downweights_a_consistently_wrong_source() {
+        let (eng, id) = engine();
+        assert_status(&eng, id, "up", 0.9, "flaky");
+        assert_status(&eng, id, "down", 0.8, "steady");
+
+        let before = resolve_status(&eng, id, false).best_supported_claim.unwrap();
+        assert_eq!(before.belief.value, Value::String("up".to_string()));
+
+        for i in 0..5 {
+            let EngineResponse::Assert { belief_id, .. } = eng
+                .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    embedding: Some(vec![1.0, 0.0, 0.0]),
+                    ..assert_payload(
+                        id,
+                        &format!("reading_{i}"),
+                        Value::Int(i),
+                        "flaky",
+                        ConsistencyMode::Eventual,
+                    )
+                })))
+                .unwrap()
+            else {
+                panic!("expected assert");
+            };
+            eng.execute(KyroIR::new(Operation::Feedback(FeedbackPayload {
+                belief_id,
+                outcome: FeedbackOutcome::Incorrect,
+                namespace: None,
+            })))
+            .unwrap();
+        }
+
+        let flaky = Source::agent("flaky", Option::<String>::None).source_id();
+        assert_eq!(eng.calibration().accuracy(flaky).incorrect, 5);
+        assert!(eng.calibration().weight(flaky) < 0.5);
+
+        let after = resolve_status(&eng, id, false).best_supported_claim.unwrap();
+        assert_eq!(after.belief.value, Value::String("down".to_string()));
+    }
+
+    #[test]
+    fn feedback_for_unknown_belief_is_rejected() {
+        let (eng, _) = engine();
+        let result = eng.execute(KyroIR::new(Operation::Feedback(FeedbackPayload {
+            belief_id: BeliefId::new(),
+            outcome: FeedbackOutcome::Correct,
+            namespace: None,
+        })));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn feedback_is_scoped_to_the_namespace() {
+        let (eng, _) = engine();
+        let entity = Entity::new("Acme", EntityType::Organization).with_namespace("tenant-a");
+        let id = entity.id;
+        eng.entity_store().insert(entity).unwrap();
+        let EngineResponse::Assert { belief_id, .. } = eng
+            .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                namespace: Some("tenant-a".to_string()),
+                ..assert_payload(id, "status", "active", "a", ConsistencyMode::Eventual)
+            })))
+            .unwrap()
+        else {
+            panic!("expected assert");
+        };
+        let feedback_in = |ns: Option<&str>| {
+            eng.execute(KyroIR::new(Operation::Feedback(FeedbackPayload {
+                belief_id,
+                outcome: FeedbackOutcome::Incorrect,
+                namespace: ns.map(str::to_string),
+            })))
+        };
+
+        // Another tenant cannot touch the source's calibration.
+        let source = Source::agent("a", Option::<String>::None).source_id();
+        for ns in [None, Some("tenant-b")] {
+            let err = feedback_in(ns).unwrap_err();
+            assert!(matches!(
+                err,
+                KyroError::Execution(ExecutionError::BeliefNotFound { id }) if id == belief_id
+            ));
+        }
+        assert_eq!(eng.calibration().accuracy(source).incorrect, 0);
+
+        feedback_in(Some("tenant-a")).unwrap();
+        assert_eq!(eng.calibration().accuracy(source).incorrect, 1);
+    }
+
+    #[test]
+    fn compound_resolve_returns_only_entities_matching_every_condition() {
+        let (eng, _) = engine();
+        let assert = |id: EntityId, predicate: &str, value: Value| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, predicate, value, "sensor", ConsistencyMode::Eventual)
+            })))
+            .unwrap();
+        };
+        let mut ids = Vec::new();
+        for (name, status, temperature) in [("hot", "active", 35), ("cool", "active", 20), ("idle", "idle", 40)] {
+            let entity = Entity::new(name, EntityType::Concept);
+            ids.push(entity.id);
+            eng.entity_store().insert(entity).unwrap();
+            assert(ids[ids.len() - 1], "status", Value::String(status.to_string()));
+            assert(ids[ids.len() - 1], "temperature", Value::Int(temperature));
+        }
+
+        let ir = crate::operations::CompoundResolveBuilder::new()
+            .where_eq("status", "active")
+            .where_compare("temperature", crate::monitor::ComparisonOp::Gt, 30.0)
+            .build()
+            .unwrap();
+        let EngineResponse::ResolveCompound { frame } = eng.execute(ir).unwrap() else {
+            panic!("expected compound resolve");
+        };
+
+        assert_eq!(frame.matches.len(), 1);
+        assert!(!frame.truncated);
+        let hit = &frame.matches[0];
+        assert_eq!(hit.entity_id, ids[0]);
+        assert_eq!(hit.beliefs[0].value, Value::String("active".to_string()));
+        assert_eq!(hit.beliefs[1].value, Value::Int(35));
+
+        let ir = crate::operations::CompoundResolveBuilder::new()
+            .where_eq("status", "active")
+            .limit(1)
+            .build()
+            .unwrap();
+        let EngineResponse::ResolveCompound { frame } = eng.execute(ir).unwrap() else {
+            panic!("expected compound resolve");
+        };
+        assert_eq!(frame.matches.len(), 1);
+        assert!(frame.truncated);
+    }
+
+    #[test]
+    fn resolve_multi_resolves_one_predicate_per_entity() {
+        let (eng, _) = engine();
+        let mut ids = Vec::new();
+        for (name, melting_point) in [("iron", 1538.0), ("copper", 1085.0), ("tin", 232.0)] {
+            let entity = Entity::new(name, EntityType::Concept);
+            ids.push(entity.id);
+            eng.entity_store().insert(entity).unwrap();
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                ids[ids.len() - 1],
+                "melting_point",
+                Value::Float(melting_point),
+                "handbook",
+                ConsistencyMode::Force,
+            ))))
+            .unwrap();
+        }
+        let unknown = EntityId::new();
+
+        let payload = ResolveMultiPayload {
+            entity_ids: ids.clone(),
+            predicate: "melting_point".to_string(),
+            options: ResolvePayload {
+                min_confidence: Some(0.5),
+                ..ResolvePayload::default()
+            },
+        };
+        let EngineResponse::ResolveMulti { frames } = eng.execute(KyroIR::new(Operation::ResolveMulti(payload))).unwrap()
+        else {
+            panic!("expected multi-entity resolve");
+        };
+
+        assert_eq!(frames.len(), 3);
+        for (id, expected) in ids.iter().zip([1538.0, 1085.0, 232.0]) {
+            let claim = frames[id].best_supported_claim.as_ref().unwrap();
+            assert_eq!(claim.belief.subject, *id);
+            assert_eq!(claim.belief.value, Value::Float(expected));
+        }
+
+        // Like a single-entity RESOLVE, an unknown entity fails the call.
+        let with_unknown = ResolveMultiPayload {
+            entity_ids: vec![ids[0], unknown],
+            predicate: "melting_point".to_string(),
+            options: ResolvePayload::default(),
+        };
+        assert!(eng.execute(KyroIR::new(Operation::ResolveMulti(with_unknown))).is_err());
+        // Entity and predicate come from the payload, not the shared options.
+        let invalid = ResolveMultiPayload {
+            entity_ids: ids.clone(),
+            predicate: "melting_point".to_string(),
+            options: ResolvePayload {
+                entity_id: Some(ids[0]),
+                ..ResolvePayload::default()
+            },
+        };
+        assert!(eng.execute(KyroIR::new(Operation::ResolveMulti(invalid))).is_err());
+        let duplicated = ResolveMultiPayload {
+            entity_ids: vec![ids[0], ids[0]],
+            predicate: "melting_point".to_string(),
+            options: ResolvePayload::default(),
+        };
+        assert!(eng.execute(KyroIR::new(Operation::ResolveMulti(duplicated))).is_err());
+    }
+
+    #[test]
+    fn replayed_operation_log_reproduces_resolve_results() {
+        let (eng, id) = engine();
+        let log = Arc::new(InMemoryOperationLog::new());
+        let eng = eng.with_operation_log(log.clone());
+
+        assert_status(&eng, id, "superconducting", 0.9, "lab_a");
+        assert_status(&eng, id, "normal", 0.6, "lab_b");
+        let frame = resolve_status(&eng, id, false);
+        let first = frame.best_supported_claim.unwrap().belief.id;
+        eng.execute(KyroIR::new(Operation::Feedback(FeedbackPayload {
+            belief_id: first,
+            outcome: FeedbackOutcome::Incorrect,
+            namespace: None,
+        })))
+        .unwrap();
+        eng.execute(KyroIR::new(Operation::Retract(RetractPayload {
+            belief_id: first,
+            reason: Some("failed replication".to_string()),
+            authorized_by: Source::agent("lab_a", Option::<String>::None),
+            namespace: None,
+        })))
+        .unwrap();
+        assert_status(&eng, id, "normal", 0.7, "lab_c");
+
+        // Round-trip through the JSON lines format.
+        let entries = log.entries().unwrap();
+        assert_eq!(entries.len(), 6);
+        let jsonl = JsonLinesOperationLog::new(Vec::new());
+        for entry in &entries {
+            jsonl.append(entry).unwrap();
+        }
+        let bytes = jsonl.into_inner().unwrap();
+        let restored = operation_log::read_json_lines(bytes.as_slice()).unwrap();
+        assert_eq!(restored, entries);
+
+        let stores = InMemoryStores::new();
+        let fresh = KyroEngine::new(
+            Arc::new(stores.entities),
+            Arc::new(stores.beliefs),
+            Arc::new(stores.patterns),
+            Arc::new(stores.conflicts),
+            Arc::new(stores.derivations),
+        );
+        fresh
+            .entity_store()
+            .insert(Entity::with_id(id, "LK-99", EntityType::Concept))
+            .unwrap();
+        assert_eq!(replay(restored, &fresh).unwrap(), 6);
+
+        let original = resolve_status(&eng, id, false);
+        let replayed = resolve_status(&fresh, id, false);
+        let (a, b) = (
+            original.best_supported_claim.unwrap(),
+            replayed.best_supported_claim.unwrap(),
+        );
+        assert_eq!(a.belief.value, b.belief.value);
+        assert_eq!(a.belief.tx_time, b.belief.tx_time);
+        assert_eq!(a.epistemic_confidence, b.epistemic_confidence);
+        assert_eq!(original.epistemic_confidence, replayed.epistemic_confidence);
+        assert_eq!(original.counter_evidence.len(), replayed.counter_evidence.len());
+        assert_eq!(
+            fresh.calibration().accuracy(Source::agent("lab_a", Option::<String>::None).source_id()),
+            eng.calibration().accuracy(Source::agent("lab_a", Option::<String>::None).source_id()),
+        );
+    }
+
+    #[test]
+    fn resolve_on_canonical_id_includes_beliefs_of_merged_entities() {
+        let (eng, primary) = engine();
+        let duplicate = Entity::new("LK99", EntityType::Concept);
+        let secondary = duplicate.id;
+        eng.entity_store().insert(duplicate).unwrap();
+
+        // Only the duplicate carries the belief before the merge.
+        assert_status(&eng, secondary, "superconductor", 0.9, "lab");
+        assert!(resolve_status(&eng, primary, false).best_supported_claim.is_none());
+
+        eng.entity_store().merge(primary, secondary).unwrap();
+
+        for semantic in [false, true] {
+            let frame = resolve_status(&eng, primary, semantic);
+            let claim = frame.best_supported_claim.expect("merged belief resolves");
+            assert_eq!(claim.belief.value, Value::String("superconductor".to_string()));
+            assert_eq!(claim.belief.subject, secondary);
+        }
+
+        // Asserting through the merged-away id lands on the canonical entity and
+        // contradicts the earlier belief.
+        assert_status(&eng, secondary, "not a superconductor", 0.95, "replicator");
+        let frame = resolve_status(&eng, primary, false);
+        let claim = frame.best_supported_claim.unwrap();
+        assert_eq!(claim.belief.subject, primary);
+        assert!(!frame.conflicts.is_empty());
+    }
+
+    #[test]
+    fn future_dated_pattern_only_flags_asserts_after_it_starts() {
+        let (eng, id) = engine();
+        let start = Utc::now() + chrono::Duration::hours(1);
+        eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "temperature_range".to_string(),
+            description: None,
+            rule: PatternRule::Range {
+                predicate: "temperature".to_string(),
+                min: Some(-50.0),
+                max: Some(150.0),
+                coerce: false,
+            },
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            valid_time: TimeRange::starting_at(start),
+        })))
+        .unwrap();
+        assert!(eng.patterns.find_active().unwrap().is_empty());
+
+        let assert_at = |timestamp: DateTime<Utc>| {
+            let mut ir = KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(
+                    id, "temperature", Value::Float(500.0), "a", ConsistencyMode::Eventual,
+                )
+            }));
+            ir.timestamp = timestamp;
+            match eng.execute(ir).unwrap() {
+                EngineResponse::Assert { conflict_ids, .. } => conflict_ids,
+                other => panic!("expected assert, got {other:?}"),
+            }
+        };
+
+        assert!(assert_at(Utc::now()).is_empty());
+        assert_eq!(assert_at(start + chrono::Duration::minutes(1)).len(), 1);
+    }
+
+    #[test]
+    fn noisy_or_aggregation_boosts_agreeing_independent_sources() {
+        let (eng, id) = engine();
+        for agent in ["a", "b", "c"] {
+            assert_status(&eng, id, "on", 0.5, agent);
+        }
+        // A source repeating itself is not independent corroboration.
+        assert_status(&eng, id, "on", 0.5, "a");
+
+        let claim_confidence = |aggregation: Option<ConfidenceAggregation>, semantic: bool| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                query_embedding: semantic.then(|| vec![1.0, 0.0, 0.0]),
+                confidence_aggregation: aggregation,
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.best_supported_claim.unwrap().epistemic_confidence
+        };
+
+        for semantic in [false, true] {
+            assert!((claim_confidence(None, semantic) - 0.5).abs() < 1e-6);
+            let combined = claim_confidence(Some(ConfidenceAggregation::NoisyOr), semantic);
+            assert!((combined - 0.875).abs() < 1e-5, "semantic={semantic}: {combined}");
+        }
+    }
+
+    #[test]
+    fn prune_deletes_old_superseded_versions_but_keeps_referenced_ones() {
+        let (eng, id, beliefs, derivations) = engine_with_backing_stores();
+        let t0 = Utc::now() - chrono::Duration::days(30);
+        let day = |n: i64| t0 + chrono::Duration::days(n);
+
+        // Each chain is a list of (value, tx_time) versions, each superseding the previous.
+        let chain = |predicate: &str, versions: &[(&str, DateTime<Utc>)]| -> Vec<BeliefId> {
+            let mut ids: Vec<BeliefId> = Vec::new();
+            for (value, tx_time) in versions {
+                let mut belief = Belief::builder()
+                    .subject(id)
+                    .predicate(predicate)
+                    .value(*value)
+                    .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                    .valid_time(TimeRange::starting_at(*tx_time))
+                    .build()
+                    .unwrap();
+                belief.tx_time = *tx_time;
+                beliefs.insert(belief.clone()).unwrap();
+                if let Some(prev) = ids.last() {
+                    beliefs.supersede(*prev, belief.id).unwrap();
+                }
+                ids.push(belief.id);
+            }
+            ids
+        };
+        let status = chain("status", &[("v1", day(0)), ("v2", day(10)), ("v3", day(20))]);
+        let owner = chain("owner", &[("alice", day(0)), ("bob", day(1))]);
+        let score = chain("score", &[("low", day(0)), ("high", day(1))]);
+
+        eng.conflict_store()
+            .insert(Conflict::value_contradiction(vec![owner[0], owner[1]], id, "owner"))
+            .unwrap();
+        derivations
+            .insert(DerivationRecord::new(day(0), None, vec![score[0]], "r", Vec::new(), None, None, None).unwrap())
+            .unwrap();
+
+        let status_as_of = |as_of: DateTime<Utc>| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                as_of: Some(as_of),
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+        let claimed = |frame: BeliefFrame| frame.best_supported_claim.map(|c| c.belief.value);
+        assert_eq!(claimed(status_as_of(day(5))), Some(Value::from("v1")));
+
+        let report = eng.prune(RetentionPolicy::superseded_before(day(15))).unwrap();
+        assert_eq!(
+            report,
+            PruneReport {
+                eligible: 3,
+                deleted: 1,
+                kept_for_conflicts: 1,
+                kept_for_derivations: 1,
+            }
+        );
+        assert!(beliefs.get(status[0]).unwrap().is_none());
+        // The successor no longer points back at the deleted version.
+        assert!(beliefs.get(status[1]).unwrap().unwrap().supersedes.is_empty());
+        assert!(beliefs.get(owner[0]).unwrap().is_some());
+        assert!(beliefs.get(score[0]).unwrap().is_some());
+        assert_eq!(beliefs.count_by_entity(id).unwrap(), 6);
+
+        // History before the cutoff is gone: no answer, but a gap rather than an error.
+        let pruned = status_as_of(day(5));
+        assert!(pruned.best_supported_claim.is_none());
+        assert!(!pruned.gaps.is_empty());
+
+        // Versions superseded after the cutoff, and the current one, are intact.
+        assert_eq!(claimed(status_as_of(day(15))), Some(Value::from("v2")));
+        assert_eq!(claimed(status_as_of(Utc::now())), Some(Value::from("v3")));
+
+        let report = eng.prune(RetentionPolicy::superseded_before(Utc::now())).unwrap();
+        assert_eq!(report.deleted, 1);
+        assert!(beliefs.get(status[1]).unwrap().is_none());
+        assert!(beliefs.get(status[2]).unwrap().unwrap().supersedes.is_empty());
+        assert_eq!(claimed(status_as_of(Utc::now())), Some(Value::from("v3")));
+    }
+
+    #[test]
+    fn vacuum_removes_beliefs_of_deleted_entities_from_resolve() {
+        let (eng, kept) = engine();
+        let doomed = Entity::new("doomed", EntityType::Concept);
+        let doomed_id = doomed.id;
+        eng.entity_store().insert(doomed).unwrap();
+        for id in [kept, doomed_id] {
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                id, "status", "active", "sensor", ConsistencyMode::Force,
+            ))))
+            .unwrap();
+        }
+        let active = || {
+            let ir = crate::operations::CompoundResolveBuilder::new()
+                .where_eq("status", "active")
+                .build()
+                .unwrap();
+            let EngineResponse::ResolveCompound { frame } = eng.execute(ir).unwrap() else {
+                panic!("expected compound resolve");
+            };
+            frame.matches.into_iter().map(|m| m.entity_id).collect::<Vec<_>>()
+        };
+
+        eng.entity_store().delete(doomed_id).unwrap();
+        assert!(eng.find_orphaned_beliefs().unwrap().iter().all(|b| b.subject == doomed_id));
+        assert_eq!(eng.find_orphaned_beliefs().unwrap().len(), 1);
+        // The orphan still answers namespace scans.
+        assert_eq!(active().len(), 2);
+
+        let authorized_by = Source::agent("admin", Option::<String>::None);
+        let report = eng
+            .vacuum_orphans(VacuumMode::Retract {
+                authorized_by: authorized_by.clone(),
+            })
+            .unwrap();
+        assert_eq!(report, VacuumReport { orphaned: 1, retracted: 1, deleted: 0 });
+        assert_eq!(active(), vec![kept]);
+        // The retraction is itself an orphan but is not retracted again.
+        let report = eng.vacuum_orphans(VacuumMode::Retract { authorized_by }).unwrap();
+        assert_eq!(report, VacuumReport { orphaned: 2, retracted: 0, deleted: 0 });
+
+        let report = eng.vacuum_orphans(VacuumMode::Delete).unwrap();
+        assert_eq!(report, VacuumReport { orphaned: 2, retracted: 0, deleted: 2 });
+        assert!(eng.find_orphaned_beliefs().unwrap().is_empty());
+        assert_eq!(eng.belief_store().count_by_entity(doomed_id).unwrap(), 0);
+        assert_eq!(active(), vec![kept]);
+    }
+
+    #[test]
+    fn vacuum_logs_its_retractions_and_retracts_null_valued_orphans() {
+        let (eng, _) = engine();
+        let log = Arc::new(InMemoryOperationLog::new());
+        let eng = eng.with_operation_log(log.clone());
+        let doomed = EntityId::new();
+        let belief = |value: Value, supersedes: Option<BeliefId>| {
+            let mut builder = Belief::builder()
+                .subject(doomed)
+                .predicate("status")
+                .value(value)
+                .confidence(Confidence::from_agent(0.9, "sensor").unwrap());
+            if let Some(id) = supersedes {
+                builder = builder.supersedes(id);
+            }
+            builder.build().unwrap()
+        };
+        // An unknown reading replacing a known one: a `Null` successor, but no retraction.
+        let known = belief(Value::from("active"), None);
+        let unknown = belief(Value::Null, Some(known.id));
+        let unknown_id = unknown.id;
+        eng.belief_store().insert(known.clone()).unwrap();
+        eng.belief_store().insert(unknown).unwrap();
+        eng.belief_store().supersede(known.id, unknown_id).unwrap();
+
+        let report = eng
+            .vacuum_orphans(VacuumMode::Retract {
+                authorized_by: Source::agent("admin", Option::<String>::None),
+            })
+            .unwrap();
+        assert_eq!(report, VacuumReport { orphaned: 2, retracted: 1, deleted: 0 });
+        let successor = eng.belief_store().get(unknown_id).unwrap().unwrap().superseded_by.unwrap();
+        assert!(eng.belief_store().get(successor).unwrap().unwrap().retraction);
+
+        let entries = log.entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(matches!(&entries[0].ir.operation, Operation::Retract(p) if p.belief_id == unknown_id));
+        assert_eq!(entries[0].created_belief, Some(successor));
+    }
+
+    #[test]
+    fn confidence_bounds_cap_an_overconfident_source_in_resolve() {
+        let model = Arc::new(SimpleTrustModel::new());
+        let loud = Source::agent("loud", Option::<String>::None);
+        let calm = Source::agent("calm", Option::<String>::None);
+        let (eng, id) = engine_with_trust_model(Arc::clone(&model) as Arc<dyn TrustModel>);
+
+        let mut loud_id = None;
+        for (value, conf, source) in [("loud", 0.99, &loud), ("calm", 0.8, &calm)] {
+            let belief = Belief::builder()
+                .subject(id)
+                .predicate("status")
+                .value(value)
+                .confidence(Confidence::from_agent(conf, "a").unwrap())
+                .source(source.clone())
+                .build()
+                .unwrap();
+            if value == "loud" {
+                loud_id = Some(belief.id);
+            }
+            eng.beliefs.insert(belief).unwrap();
+        }
+
+        let winner = || {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.best_supported_claim.unwrap().belief.value
+        };
+
+        assert_eq!(winner(), Value::from("loud"));
+        model.set_confidence_bounds(loud.source_id(), 0.0, 0.7).unwrap();
+        assert_eq!(winner(), Value::from("calm"));
+
+        // The stored confidence is untouched.
+        let stored = eng.beliefs.get(loud_id.unwrap()).unwrap().unwrap();
+        assert!((stored.confidence.value() - 0.99).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn source_filters_drop_beliefs_before_ranking() {
+        let (eng, id) = engine();
+        let retracted = Source::agent("retracted-paper", Option::<String>::None);
+        let other = Source::agent("other", Option::<String>::None);
+        for (value, conf, source) in [("a", 0.9, &retracted), ("b", 0.6, &other)] {
+            eng.beliefs
+                .insert(
+                    Belief::builder()
+                        .subject(id)
+                        .predicate("status")
+                        .value(value)
+                        .confidence(Confidence::from_agent(conf, "a").unwrap())
+                        .source(source.clone())
+                        .embedding(vec![1.0, 0.0, 0.0])
+                        .build()
+                        .unwrap(),
+                )
+                .unwrap();
+        }
+
+        let resolve = |semantic: bool, include: Vec<crate::confidence::SourceId>, exclude: Vec<crate::confidence::SourceId>| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                query_embedding: semantic.then(|| vec![1.0, 0.0, 0.0]),
+                include_counter_evidence: true,
+                include_sources: include,
+                exclude_sources: exclude,
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+
+        for semantic in [false, true] {
+            let frame = resolve(semantic, Vec::new(), Vec::new());
+            assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::from("a"));
+            assert_eq!(frame.counter_evidence.len(), 1);
+
+            let frame = resolve(semantic, Vec::new(), vec![retracted.source_id()]);
+            assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::from("b"));
+            assert!(frame.counter_evidence.is_empty(), "semantic={semantic}");
+            assert!(frame
+                .supporting_evidence
+                .iter()
+                .chain(&frame.counter_evidence)
+                .all(|e| e.source != retracted));
+
+            let frame = resolve(semantic, vec![other.source_id()], Vec::new());
+            assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::from("b"));
+
+            // Exclusion wins over inclusion; nothing left is a gap, not an answer.
+            let frame = resolve(semantic, vec![other.source_id()], vec![other.source_id()]);
+            assert!(frame.best_supported_claim.is_none());
+            assert!(frame
+                .gaps
+                .iter()
+                .any(|g| g.gap_type == crate::frame::GapType::InsufficientEvidence),
+                "semantic={semantic}");
+        }
+    }
+
+    #[test]
+    fn coerced_range_pattern_checks_numeric_strings() {
+        let assert_temp = |eng: &KyroEngine, id: EntityId, value: &str| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                valid_time: TimeRange::from_now(),
+                ..assert_payload(id, "temperature", value, "a", ConsistencyMode::Strict)
+            })))
+        };
+        let define = |eng: &KyroEngine, rule: PatternRule| {
+            eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                name: "temp_range".to_string(),
+                description: None,
+                rule,
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            })))
+            .unwrap();
+        };
+
+        let (strict, id) = engine();
+        define(&strict, PatternRule::range("temperature", Some(0.0), Some(100.0)));
+        assert!(assert_temp(&strict, id, "42.5").is_err());
+
+        let (coerced, id) = engine();
+        define(&coerced, PatternRule::range_coerced("temperature", Some(0.0), Some(100.0)));
+        assert!(assert_temp(&coerced, id, "42.5").is_ok());
+        let err = assert_temp(&coerced, id, "140").unwrap_err();
+        let KyroError::Execution(ExecutionError::ConflictsDetected { conflicts }) = err else {
+            panic!("expected ConflictsDetected, got {err:?}");
+        };
+        assert!(conflicts.iter().any(|c| c.starts_with("pattern_violation")));
+        assert!(assert_temp(&coerced, id, "hot").is_err());
+    }
+
+    #[test]
+    fn sequential_id_generator_makes_assert_ids_predictable() {
+        let run = || {
+            let _ids = crate::id::install(crate::id::SequentialIdGenerator::new());
+            let (eng, id) = engine();
+            let mut ids = vec![id.to_string()];
+            for (value, consistency_mode) in [(1, ConsistencyMode::Eventual), (2, ConsistencyMode::Eventual)] {
+                let EngineResponse::Assert { belief_id, conflict_ids, .. } = eng
+                    .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                        valid_time: TimeRange::from_now(),
+                        ..assert_payload(id, "floor", Value::Int(value), "a", consistency_mode)
+                    })))
+                    .unwrap()
+                else {
+                    panic!("expected assert");
+                };
+                ids.push(belief_id.to_string());
+                ids.extend(conflict_ids.iter().map(ToString::to_string));
+            }
+            ids
+        };
+
+        let first = run();
+        assert_eq!(first.len(), 4);
+        assert_eq!(first[0], uuid::Uuid::from_u128(1).to_string());
+        assert!(first.iter().all(|u| u.starts_with("00000000-0000-0000-0000-")), "{first:?}");
+        assert_eq!(run(), first);
+    }
+
+    #[test]
+    fn history_resolve_lists_every_revision_of_a_supersede_chain() {
+        let (eng, id) = engine();
+        let assert = |value: &str, mode: ConsistencyMode| {
+            let resp = eng
+                .execute(KyroIR::new(Operation::Assert(assert_payload(
+                    id, "status", value, "a", mode,
+                ))))
+                .unwrap();
+            let EngineResponse::Assert { belief_id, .. } = resp else {
+                panic!("expected assert");
+            };
+            belief_id
+        };
+        let ids = vec![
+            assert("draft", ConsistencyMode::Eventual),
+            assert("review", ConsistencyMode::Replace),
+            assert("final", ConsistencyMode::Replace),
+        ];
+
+        let resolve = |mode: ResolveMode| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                mode,
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap()
+            else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+
+        let frame = resolve(ResolveMode::History);
+        assert_eq!(frame.history.iter().map(|b| b.id).collect::<Vec<_>>(), ids);
+        assert!(frame.history.windows(2).all(|w| w[0].tx_time <= w[1].tx_time));
+        assert_eq!(frame.history[0].superseded_by, Some(ids[1]));
+        assert_eq!(frame.history[1].superseded_by, Some(ids[2]));
+        assert_eq!(
+            frame.best_supported_claim.unwrap().belief.value,
+            Value::String("final".to_string())
+        );
+
+        assert!(resolve(ResolveMode::Simple).history.is_empty());
+    }
+
+    #[test]
+    fn embedding_text_template_changes_generated_embeddings() {
+        let (eng, id) = engine();
+        let mut entity = eng.entities.get(id).unwrap().unwrap();
+        entity.add_alias("Lee-Kim-99");
+        entity.add_alias("copper apatite");
+        eng.entities.update(entity).unwrap();
+
+        let with_aliases = eng.clone().with_embedding_text(|entity, predicate, value| {
+            format!("{} {} {predicate} {value}", entity.canonical_name, entity.aliases.join(" "))
+        });
+        let embedding_of = |eng: &KyroEngine| {
+            let resp = eng
+                .execute(KyroIR::new(Operation::Assert(assert_payload(
+                    id, "status", "superconductor", "a", ConsistencyMode::Force,
+                ))))
+                .unwrap();
+            let EngineResponse::Assert { belief_id, .. } = resp else {
+                panic!("expected assert");
+            };
+            eng.beliefs.get(belief_id).unwrap().unwrap().embedding.unwrap()
+        };
+
+        let default = embedding_of(&eng);
+        let custom = embedding_of(&with_aliases);
+        assert_eq!(default, eng.embedder().embed("LK-99 status superconductor").unwrap());
+        assert_eq!(
+            custom,
+            eng.embedder()
+                .embed("LK-99 Lee-Kim-99 copper apatite status superconductor")
+                .unwrap()
+        );
+        assert_ne!(default, custom);
+    }
+
+    fn transaction_ops(id: EntityId, asserts: &[(&str, ConsistencyMode)]) -> Vec<Operation> {
+        let mut operations = vec![Operation::DefinePattern(DefinePatternPayload {
+            name: "single_status".to_string(),
+            description: None,
+            rule: PatternRule::unique("status"),
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            valid_time: TimeRange::forever(),
+        })];
+        operations.extend(asserts.iter().map(|(value, mode)| {
+            Operation::Assert(assert_payload(id, "status", *value, "a", *mode))
+        }));
+        operations
+    }
+
+    #[test]
+    fn failed_transaction_leaves_stores_unchanged() {
+        let (eng, id) = engine();
+        let operations = transaction_ops(
+            id,
+            &[
+                ("draft", ConsistencyMode::Eventual),
+                ("review", ConsistencyMode::Eventual),
+                ("final", ConsistencyMode::Strict),
+            ],
+        );
+
+        let err = eng
+            .execute(KyroIR::new(Operation::Transaction(operations)))
+            .unwrap_err();
+        let KyroError::Execution(ExecutionError::TransactionAborted { index, cause }) = err else {
+            panic!("expected an aborted transaction, got {err:?}");
+        };
+        assert_eq!(index, 3);
+        assert_eq!(cause.error_code(), crate::error::ErrorCode::ConflictsDetected);
+
+        assert!(eng.beliefs.find_by_entity(id).unwrap().is_empty());
+        assert!(eng.patterns.find_active().unwrap().is_empty());
+        assert!(eng.conflicts.find_open().unwrap().is_empty());
+    }
+
+    #[test]
+    fn committed_transaction_applies_every_write() {
+        let (eng, id) = engine();
+        let operations = transaction_ops(
+            id,
+            &[("draft", ConsistencyMode::Eventual), ("review", ConsistencyMode::Eventual)],
+        );
+
+        let EngineResponse::Transaction { responses } = eng
+            .execute(KyroIR::new(Operation::Transaction(operations)))
+            .unwrap()
+        else {
+            panic!("expected transaction");
+        };
+        assert_eq!(responses.len(), 3);
+        let EngineResponse::Assert { belief_id, conflict_ids, .. } = &responses[2] else {
+            panic!("expected assert");
+        };
+        // The second assert saw the pattern and belief staged before it.
+        assert!(!conflict_ids.is_empty());
+
+        assert_eq!(eng.patterns.find_active().unwrap().len(), 1);
+        assert_eq!(eng.beliefs.find_by_entity(id).unwrap().len(), 2);
+        let open = eng.conflicts.find_open().unwrap();
+        assert_eq!(open.len(), conflict_ids.len());
+        assert!(open.iter().all(|c| c.involves_belief(*belief_id)));
+    }
+
+    #[test]
+    fn transactions_reject_nested_and_unbufferable_operations() {
+        let (eng, id) = engine();
+        let inner = Operation::Transaction(transaction_ops(id, &[]));
+        for operation in [
+            inner,
+            Operation::Simulate(SimulatePayload::default()),
+            Operation::Feedback(FeedbackPayload {
+                belief_id: BeliefId::new(),
+                outcome: FeedbackOutcome::Correct,
+                namespace: None,
+            }),
+        ] {
+            let err = eng
+                .execute(KyroIR::new(Operation::Transaction(vec![operation])))
+                .unwrap_err();
+            assert!(matches!(err, KyroError::Validation(_)), "{err:?}");
+        }
+        assert!(eng
+            .execute(KyroIR::new(Operation::Transaction(Vec::new())))
+            .is_err());
+    }
+
+    #[test]
+    fn transaction_validation_reports_every_invalid_operation() {
+        let (eng, id) = engine();
+        let mut operations = transaction_ops(id, &[("draft", ConsistencyMode::Eventual)]);
+        let Operation::Assert(mut blank) = operations[1].clone() else {
+            panic!("expected assert");
+        };
+        blank.predicate = "  ".to_string();
+        operations.push(Operation::Assert(blank));
+        operations.push(Operation::Simulate(SimulatePayload::default()));
+        operations.push(Operation::CheckPatternSet(String::new()));
+
+        let err = eng
+            .execute(KyroIR::new(Operation::Transaction(operations)))
+            .unwrap_err();
+        let KyroError::Validation(ValidationError::Multiple(errors)) = &err else {
+            panic!("expected every failure, got {err:?}");
+        };
+        let codes: Vec<_> = errors.iter().map(ValidationError::error_code).collect();
+        assert_eq!(
+            codes,
+            [
+                crate::error::ErrorCode::MissingField,
+                crate::error::ErrorCode::InvalidField,
+                crate::error::ErrorCode::MissingField,
+            ]
+        );
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["error_code"], "MULTIPLE_VALIDATION_ERRORS");
+        assert_eq!(json["errors"][2]["error_code"], "MISSING_FIELD");
+        assert!(eng.beliefs.find_by_entity(id).unwrap().is_empty());
+
+        // A single failure is reported as itself.
+        let mut operations = transaction_ops(id, &[("draft", ConsistencyMode::Eventual)]);
+        operations.push(Operation::CheckPatternSet(String::new()));
+        let err = eng
+            .execute(KyroIR::new(Operation::Transaction(operations)))
+            .unwrap_err();
+        assert!(matches!(err, KyroError::Validation(ValidationError::MissingField { .. })), "{err:?}");
+    }
+
+    #[derive(Default)]
+    struct RecordingMetrics {
+        counters: std::sync::Mutex<HashMap<&'static str, u64>>,
+        observations: std::sync::Mutex<HashMap<&'static str, usize>>,
+    }
+
+    impl Metrics for RecordingMetrics {
+        fn increment_counter(&self, name: &'static str, value: u64) {
+            *self.counters.lock().unwrap().entry(name).or_default() += value;
+        }
+
+        fn observe_histogram(&self, name: &'static str, _value: f64) {
+            *self.observations.lock().unwrap().entry(name).or_default() += 1;
+        }
+    }
+
+    #[test]
+    fn metrics_count_asserts_resolves_and_conflicts() {
+        let (eng, id) = engine();
+        let recorder = Arc::new(RecordingMetrics::default());
+        let eng = eng.with_metrics(recorder.clone());
+
+        assert_status(&eng, id, "active", 0.9, "a");
+        assert_status(&eng, id, "inactive", 0.8, "b");
+        let keyed = || {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(vec![0.0, 1.0, 0.0]),
+                idempotency_key: Some("owner-1".to_string()),
+                ..assert_payload(id, "owner", "ops", "a", ConsistencyMode::Eventual)
+            })))
+            .unwrap()
+        };
+        keyed();
+        keyed();
+        let missing = KyroIR::new(Operation::Assert(assert_payload(
+            EntityId::new(), "status", "active", "a", ConsistencyMode::Eventual,
+        )));
+        assert!(eng.execute(missing).is_err());
+        resolve_status(&eng, id, false);
+        resolve_status(&eng, id, true);
+        eng.execute(KyroIR::new(Operation::ResolveMulti(ResolveMultiPayload {
+            entity_ids: vec![id],
+            predicate: "status".to_string(),
+            options: ResolvePayload::default(),
+        })))
+        .unwrap();
+
+        let counters = recorder.counters.lock().unwrap().clone();
+        assert_eq!(counters.get(metrics::ASSERTS_TOTAL), Some(&4));
+        assert_eq!(counters.get(metrics::ASSERT_ERRORS_TOTAL), Some(&1));
+        assert_eq!(counters.get(metrics::IDEMPOTENT_REPLAYS_TOTAL), Some(&1));
+        assert_eq!(counters.get(metrics::CONFLICTS_DETECTED_TOTAL), Some(&1));
+        assert_eq!(counters.get(metrics::RESOLVES_TOTAL), Some(&3));
+        assert_eq!(counters.get(metrics::RESOLVE_ERRORS_TOTAL), None);
+
+        let observations = recorder.observations.lock().unwrap().clone();
+        assert_eq!(observations.get(metrics::ASSERT_LATENCY_SECONDS), Some(&5));
+        assert_eq!(observations.get(metrics::RESOLVE_LATENCY_SECONDS), Some(&3));
+    }
+
+    #[test]
+    fn credible_paper_outweighs_obscure_paper_at_equal_confidence() {
+        let (eng, id) = engine();
+        for (value, source) in [
+            ("superconductor", Source::paper("2307.12008", "LK-99 Initial Report")),
+            ("insulator", Source::paper("2308.99999", "Obscure Rebuttal")),
+        ] {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                source,
+                confidence: Confidence::from_agent(0.8, "a").unwrap(),
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, "status", value, "a", ConsistencyMode::Eventual)
+            })))
+            .unwrap();
+        }
+        let winner = |eng: &KyroEngine| resolve_status(eng, id, false).best_supported_claim.unwrap().belief.value;
+
+        eng.trust_model().set_paper_credibility("2307.12008", 0.9).unwrap();
+        eng.trust_model().set_paper_credibility("2308.99999", 0.2).unwrap();
+        assert_eq!(winner(&eng), Value::String("superconductor".to_string()));
+
+        eng.trust_model().set_paper_credibility("2307.12008", 0.1).unwrap();
+        assert_eq!(winner(&eng), Value::String("insulator".to_string()));
+    }
+
+    fn assert_color(eng: &KyroEngine, id: EntityId, value: Value, conf: f32, agent: &str) -> Vec<ConflictId> {
+        let EngineResponse::Assert { conflict_ids, .. } = eng
+            .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                confidence: Confidence::from_agent(conf, agent).unwrap(),
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, "color", value, agent, ConsistencyMode::Eventual)
+            })))
+            .unwrap()
+        else {
+            panic!("expected assert");
+        };
+        conflict_ids
+    }
+
+    #[test]
+    fn denials_contradict_only_the_value_they_deny() {
+        let (eng, id) = engine();
+        assert!(assert_color(&eng, id, Value::from("red"), 0.9, "a").is_empty());
+        assert!(assert_color(&eng, id, Value::not("blue"), 0.8, "b").is_empty());
+        assert!(assert_color(&eng, id, Value::not("green"), 0.7, "c").is_empty());
+        assert!(!assert_color(&eng, id, Value::not("red"), 0.5, "d").is_empty());
+
+        let EngineResponse::Resolve { frame } = eng
+            .execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("color".to_string()),
+                include_counter_evidence: true,
+                ..ResolvePayload::default()
+            })))
+            .unwrap()
+        else {
+            panic!("expected resolve");
+        };
+        let claim = frame.best_supported_claim.as_ref().unwrap();
+        assert_eq!(claim.belief.value, Value::from("red"));
+        assert_eq!(frame.supporting_evidence.len(), 1);
+        assert_eq!(frame.counter_evidence.len(), 1);
+        let denial = eng.beliefs.get(frame.counter_evidence[0].belief_id).unwrap().unwrap();
+        assert_eq!(denial.value, Value::not("red"));
+    }
+
+    #[test]
+    fn denials_are_exempt_from_value_patterns() {
+        let (eng, id) = engine();
+        let rules = [
+            ("single_color", PatternRule::unique("color")),
+            ("known_color", PatternRule::enumerated("color", vec!["red".to_string(), "blue".to_string()])),
+        ];
+        for (name, rule) in rules {
+            eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                name: name.to_string(),
+                description: None,
+                rule,
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            })))
+            .unwrap();
+        }
+
+        assert!(assert_color(&eng, id, Value::not("blue"), 0.8, "b").is_empty());
+        assert!(assert_color(&eng, id, Value::from("red"), 0.9, "a").is_empty());
+        assert!(!assert_color(&eng, id, Value::from("blue"), 0.9, "c").is_empty());
+    }
+
+    #[test]
+    fn coalescing_preserves_resolve_answers_at_every_as_of_point() {
+        let (eng, id) = engine();
+        let base = Utc::now() - chrono::Duration::minutes(5);
+        let at = |s: i64| base + chrono::Duration::seconds(s);
+        let belief = |value: &str, conf: f32, agent: &str, from: i64, to: Option<i64>| {
+            let mut belief = Belief::builder()
+                .subject(id)
+                .predicate("status")
+                .value(value)
+                .confidence(Confidence::from_agent(conf, agent).unwrap())
+                .source(Source::agent(agent, Option::<String>::None))
+                .valid_time(match to {
+                    Some(to) => TimeRange::new(at(from), at(to)).unwrap(),
+                    None => TimeRange::starting_at(at(from)),
+                })
+                .build()
+                .unwrap();
+            belief.tx_time = at(from);
+            belief
+        };
+
+        // Agent "a" revises its reading every 10s; agent "b" reports twice, back to back.
+        let mut previous: Option<BeliefId> = None;
+        for (i, value) in ["on", "on", "off", "off"].iter().enumerate() {
+            let b = belief(value, 0.9, "a", i as i64 * 10, None);
+            let b_id = b.id;
+            eng.beliefs.insert(b).unwrap();
+            if let Some(prev) = previous {
+                eng.beliefs.supersede(prev, b_id).unwrap();
+            }
+            previous = Some(b_id);
+        }
+        eng.beliefs.insert(belief("off", 0.6, "b", 5, Some(25))).unwrap();
+        eng.beliefs.insert(belief("off", 0.6, "b", 25, Some(40))).unwrap();
+
+        let answers = || {
+            (0..50)
+                .map(|s| {
+                    let payload = ResolvePayload {
+                        entity_id: Some(id),
+                        predicate: Some("status".to_string()),
+                        as_of: Some(at(s)),
+                        include_counter_evidence: true,
+                        ..ResolvePayload::default()
+                    };
+                    let EngineResponse::Resolve { frame } =
+                        eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap()
+                    else {
+                        panic!("expected resolve");
+                    };
+                    let claim = frame.best_supported_claim.map(|c| (c.belief.value, c.epistemic_confidence));
+                    (claim, frame.supporting_evidence.len(), frame.counter_evidence.len())
+                })
+                .collect::<Vec<_>>()
+        };
+        let before = answers();
+
+        assert_eq!(eng.coalesce(id, "status").unwrap(), 3);
+        assert_eq!(eng.beliefs.history(id, "status").unwrap().len(), 3);
+        assert_eq!(answers(), before);
+    }
+
+    #[test]
+    fn coalescing_keeps_beliefs_that_conflicts_or_derivations_reference() {
+        let (eng, id) = engine();
+        let base = Utc::now() - chrono::Duration::minutes(5);
+        let at = |s: i64| base + chrono::Duration::seconds(s);
+
+        // Five identical back-to-back versions of one reading.
+        let mut ids: Vec<BeliefId> = Vec::new();
+        for i in 0..5 {
+            let mut belief = Belief::builder()
+                .subject(id)
+                .predicate("status")
+                .value("on")
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .source(Source::agent("a", Option::<String>::None))
+                .valid_time(TimeRange::starting_at(at(i * 10)))
+                .build()
+                .unwrap();
+            belief.tx_time = at(i * 10);
+            let belief_id = belief.id;
+            eng.beliefs.insert(belief).unwrap();
+            if let Some(prev) = ids.last() {
+                eng.beliefs.supersede(*prev, belief_id).unwrap();
+            }
+            ids.push(belief_id);
+        }
+        eng.conflict_store()
+            .insert(Conflict::value_contradiction(vec![ids[1]], id, "status"))
+            .unwrap();
+        eng.derivation_store()
+            .insert(DerivationRecord::new(at(0), None, vec![ids[3]], "r", Vec::new(), None, None, None).unwrap())
+            .unwrap();
+
+        // ids[2] folds into ids[1] and ids[4] into ids[3]; the referenced ones survive.
+        assert_eq!(eng.coalesce(id, "status").unwrap(), 2);
+        let left: Vec<BeliefId> = eng.beliefs.history(id, "status").unwrap().iter().map(|b| b.id).collect();
+        assert_eq!(left, vec![ids[0], ids[1], ids[3]]);
+        assert_eq!(eng.beliefs.get(ids[1]).unwrap().unwrap().superseded_by, Some(ids[3]));
+        assert_eq!(eng.beliefs.get(ids[3]).unwrap().unwrap().supersedes, vec![ids[1]]);
+    }
+
+    #[test]
+    fn query_prefers_an_exact_name_over_near_miss_typos() {
+        let (eng, id) = engine();
+        eng.entity_store()
+            .insert(Entity::new("LK-98", EntityType::Concept))
+            .unwrap();
+        assert_status(&eng, id, "disputed", 0.9, "a");
+
+        let resolve_query = |query: &str| {
+            let payload = ResolvePayload {
+                query: Some(query.to_string()),
+                predicate: Some("status".to_string()),
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.best_supported_claim.map(|claim| claim.belief.subject)
+        };
+
+        // "LK-98" is one edit away, but the exact name wins.
+        assert_eq!(resolve_query("LK-99"), Some(id));
+        assert_eq!(resolve_query("lk-99 "), Some(id));
+    }
+
+    #[test]
+    fn entity_resolution_config_relaxes_or_tightens_query_gating() {
+        let (eng, id) = engine();
+        eng.entity_store()
+            .insert(Entity::new("LK-99 film", EntityType::Concept))
+            .unwrap();
+        assert_status(&eng, id, "disputed", 0.9, "a");
+
+        let resolve_query = |query: &str, gate: Option<EntityResolutionConfig>| {
+            let payload = ResolvePayload {
+                query: Some(query.to_string()),
+                predicate: Some("status".to_string()),
+                entity_resolution: gate,
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.best_supported_claim.map(|claim| claim.belief.subject)
+        };
+        let relaxed = EntityResolutionConfig {
+            allow_questions: true,
+            max_candidates: 2,
+            ..EntityResolutionConfig::default()
+        };
+        let strict = EntityResolutionConfig {
+            min_score: 2,
+            ..EntityResolutionConfig::default()
+        };
+
+        // Questions and ambiguous prefixes are rejected by default.
+        assert_eq!(resolve_query("LK-99?", None), None);
+        assert_eq!(resolve_query("LK", None), None);
+        assert_eq!(resolve_query("LK-99?", Some(relaxed)), Some(id));
+        // Both entities share the prefix; the best-ranked one is taken.
+        assert_eq!(resolve_query("LK", Some(relaxed)), Some(id));
+
+        // A one-edit typo resolves by default, but scores below the strict minimum.
+        assert_eq!(resolve_query("LK-90", None), Some(id));
+        assert_eq!(resolve_query("LK-90", Some(strict)), None);
+        assert_eq!(resolve_query("LK-99", Some(EntityResolutionConfig::disabled())), None);
+    }
+
+    #[test]
+    fn unique_allows_temporal_updates_but_flags_overlapping_values() {
+        use chrono::Duration;
+
+        let (eng, id) = engine();
+        eng.execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+            name: "single_status".to_string(),
+            description: None,
+            rule: PatternRule::unique("status"),
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            valid_time: TimeRange::forever(),
+        })))
+        .unwrap();
+
+        let assert = |value: &str, valid_time: TimeRange, mode: ConsistencyMode| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                valid_time,
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, "status", value, "a", mode)
+            })))
+        };
+        let day = |n: i64| Utc::now() + Duration::days(n);
+
+        // The same value may come back once the first window has closed.
+        assert("open", TimeRange::new(day(1), day(2)).unwrap(), ConsistencyMode::Strict).unwrap();
+        assert("open", TimeRange::starting_at(day(3)), ConsistencyMode::Strict).unwrap();
+
+        // A genuine duplicate overlaps an existing belief even though neither is valid yet.
+        let err = assert("closed", TimeRange::new(day(1), day(4)).unwrap(), ConsistencyMode::Strict)
+            .unwrap_err();
+        let KyroError::Execution(ExecutionError::ConflictsDetected { conflicts }) = err else {
+            panic!("expected ConflictsDetected, got {err:?}");
+        };
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].starts_with("pattern_violation"));
+
+        // Replacing the current values is an update, not a duplicate, and the superseded
+        // beliefs no longer count towards later updates.
+        assert("closed", TimeRange::forever(), ConsistencyMode::Replace).unwrap();
+        assert("archived", TimeRange::forever(), ConsistencyMode::Replace).unwrap();
+        assert("archived", TimeRange::forever(), ConsistencyMode::Strict).unwrap_err();
+    }
+
+    #[test]
+    fn recompute_derivation_follows_amended_premise_confidences() {
+        let (eng, id) = engine();
+        let assert = |predicate: &str, conf: f32, source: Source| {
+            let EngineResponse::Assert { belief_id, .. } = eng
+                .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    source,
+                    confidence: Confidence::from_agent(conf, "a").unwrap(),
+                    embedding: Some(vec![1.0, 0.0, 0.0]),
+                    ..assert_payload(id, predicate, Value::Bool(true), "a", ConsistencyMode::Force)
+                })))
+                .unwrap()
+            else {
+                panic!("expected assert");
+            };
+            belief_id
+        };
+        let derive = |rule: &str, derived: BeliefId, premises: Vec<BeliefId>| {
+            let EngineResponse::Derive { derivation_id } = eng
+                .execute(KyroIR::new(Operation::Derive(DerivePayload {
+                    rule: Some(rule.to_string()),
+                    derived_belief_id: Some(derived),
+                    sources: Some(premises),
+                    inference_steps: None,
+                    confidence: Some(0.72),
+                    justification: None,
+                    metadata: None,
+                    namespace: None,
+                })))
+                .unwrap()
+            else {
+                panic!("expected derive");
+            };
+            derivation_id
+        };
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+
+        let agent = || Source::agent("a", Option::<String>::None);
+        let a = assert("premise_a", 0.9, agent());
+        let b = assert("premise_b", 0.8, agent());
+        let conclusion = assert("conclusion", 0.72, Source::derived(vec![a, b], "product"));
+        let derivation_id = derive("product", conclusion, vec![a, b]);
+
+        eng.belief_store()
+            .amend(
+                b,
+                AmendFields {
+                    confidence: Some(Confidence::from_agent(0.5, "a").unwrap()),
+                    audit_note: Some("sensor recalibrated".to_string()),
+                    ..AmendFields::default()
+                },
+            )
+            .unwrap();
+
+        // Recomputing without amending only refreshes the record.
+        let recomputed = eng.recompute_derivation(derivation_id, false).unwrap();
+        assert_eq!(recomputed.previous, Some(0.72));
+        assert!(close(recomputed.current, 0.45));
+        let record = eng.derivation_store().get(derivation_id).unwrap().unwrap();
+        assert_eq!(record.propagated_confidence, Some(recomputed.current));
+        let derived = eng.belief_store().get(conclusion).unwrap().unwrap();
+        assert!(close(derived.confidence.value(), 0.72));
+
+        let recomputed = eng.recompute_derivation(derivation_id, true).unwrap();
+        assert!(close(recomputed.previous.unwrap(), 0.45));
+        let derived = eng.belief_store().get(conclusion).unwrap().unwrap();
+        assert!(close(derived.confidence.value(), 0.45));
+
+        // A rule without propagation semantics cannot be recomputed.
+        let opaque = derive("modus_ponens", conclusion, vec![a]);
+        let err = eng.recompute_derivation(opaque, false).unwrap_err();
+        assert!(matches!(err, KyroError::Execution(ExecutionError::InvalidDerivation { .. })));
+    }
+
+    #[test]
+    fn validation_limits_bound_request_embeddings() {
+        let (eng, id) = engine();
+        let eng = eng.with_validation_limits(ValidationLimits::default().with_max_embedding_dim(2));
+        let assert = |embedding: Vec<f32>| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                embedding: Some(embedding),
+                ..assert_payload(id, "status", "ok", "a", ConsistencyMode::Eventual)
+            })))
+        };
+
+        let err = assert(vec![1.0, 0.0, 0.0]).unwrap_err();
+        assert!(matches!(
+            err,
+            KyroError::Validation(ValidationError::FieldTooLong { max_length: 2, .. })
+        ));
+
+        assert(vec![1.0, 0.0]).unwrap();
+
+        let resolve = |eng: &KyroEngine, query_embedding: Vec<f32>| {
+            eng.execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                query_embedding: Some(query_embedding),
+                ..ResolvePayload::default()
+            })))
+        };
+        assert!(resolve(&eng, vec![1.0, 0.0]).is_ok());
+        let tighter = eng.clone().with_validation_limits(ValidationLimits::default().with_max_embedding_dim(1));
+        assert!(matches!(
+            resolve(&tighter, vec![1.0, 0.0]),
+            Err(KyroError::Validation(ValidationError::FieldTooLong { max_length: 1, .. }))
+        ));
+    }
+
+    #[test]
+    fn aggregate_resolve_averages_numeric_readings() {
+        let (eng, id) = engine();
+        let assert = |predicate: &str, value: Value, agent: &str| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                confidence: Confidence::from_agent(0.8, agent).unwrap(),
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, predicate, value, agent, ConsistencyMode::Force)
+            })))
+            .unwrap();
+        };
+        let resolve = |predicate: &str, function: AggregateFunction| {
+            eng.execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                mode: ResolveMode::Aggregate { function },
+                entity_id: Some(id),
+                predicate: Some(predicate.to_string()),
+                limit: 1,
+                ..ResolvePayload::default()
+            })))
+        };
+
+        assert("temperature", Value::Float(20.0), "s1");
+        assert("temperature", Value::Float(22.5), "s2");
+        assert("temperature", Value::Int(24), "s3");
+
+        let EngineResponse::Resolve { frame } = resolve("temperature", AggregateFunction::Avg).unwrap() else {
+            panic!("expected resolve");
+        };
+        let claim = frame.best_supported_claim.unwrap();
+        assert_eq!(claim.belief.value, Value::Float(22.166_666_666_666_668));
+        assert!(matches!(claim.belief.source, Source::Derived { ref premise_ids, .. } if premise_ids.len() == 3));
+        // Every reading counts even though `limit` is 1.
+        assert_eq!(frame.supporting_evidence.len(), 3);
+        assert!(frame.epistemic_confidence.is_some_and(|c| c > 0.0));
+
+        for (function, expected) in [
+            (AggregateFunction::Min, 20.0),
+            (AggregateFunction::Max, 24.0),
+            (AggregateFunction::Median, 22.5),
+        ] {
+            let EngineResponse::Resolve { frame } = resolve("temperature", function).unwrap() else {
+                panic!("expected resolve");
+            };
+            assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::Float(expected));
+        }
+
+        assert("status", Value::from("ok"), "s1");
+        let err = resolve("status", AggregateFunction::Avg).unwrap_err();
+        assert!(matches!(err, KyroError::Validation(ValidationError::InvalidField { ref reason, .. })
+            if reason.contains("requires numeric values")));
+    }
+
+    #[test]
+    fn interpolate_resolve_blends_the_bracketing_readings() {
+        let (eng, id) = engine();
+        // A forecast series, so every reading is already recorded at each `as_of`.
+        let start = Utc::now() + chrono::Duration::hours(1);
+        let at = |minutes: i64| start + chrono::Duration::minutes(minutes);
+        let assert = |predicate: &str, value: Value, minutes: i64| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                confidence: Confidence::from_agent(0.8, "sensor").unwrap(),
+                valid_time: TimeRange::starting_at(at(minutes)),
+                embedding: Some(vec![1.0, 0.0, 0.0]),
+                ..assert_payload(id, predicate, value, "sensor", ConsistencyMode::Force)
+            })))
+            .unwrap();
+        };
+        let resolve = |predicate: &str, mode: ResolveMode, minutes: i64| {
+            let EngineResponse::Resolve { frame } = eng
+                .execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                    entity_id: Some(id),
+                    predicate: Some(predicate.to_string()),
+                    mode,
+                    as_of: Some(at(minutes)),
+                    ..ResolvePayload::default()
+                })))
+                .unwrap()
+            else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+
+        assert("temperature", Value::Float(20.0), 0);
+        assert("temperature", Value::Int(30), 40);
+
+        // A snapshot returns the last reading; interpolation blends both.
+        let snapshot = resolve("temperature", ResolveMode::Simple, 10);
+        assert_eq!(snapshot.best_supported_claim.unwrap().belief.value, Value::Float(20.0));
+        let snapshot_conf = snapshot.epistemic_confidence.unwrap();
+        let quarter = resolve("temperature", ResolveMode::Interpolate, 10);
+        let claim = quarter.best_supported_claim.unwrap();
+        assert_eq!(claim.belief.value, Value::Float(22.5));
+        assert!(matches!(claim.belief.source, Source::Derived { ref premise_ids, .. } if premise_ids.len() == 2));
+        assert_eq!(quarter.supporting_evidence.len(), 2);
+
+        // Confidence shrinks with the distance to the nearer reading.
+        let midpoint = resolve("temperature", ResolveMode::Interpolate, 20);
+        assert_eq!(midpoint.best_supported_claim.as_ref().unwrap().belief.value, Value::Float(25.0));
+        let (quarter_conf, midpoint_conf) = (quarter.epistemic_confidence.unwrap(), midpoint.epistemic_confidence.unwrap());
+        assert!((quarter_conf / midpoint_conf - 1.5).abs() < 1e-4, "{quarter_conf} vs {midpoint_conf}");
+        assert!(quarter_conf < snapshot_conf);
+
+        // Readings on one side only, or exactly at `as_of`, fall back to the snapshot.
+        for minutes in [0, 50] {
+            let frame = resolve("temperature", ResolveMode::Interpolate, minutes);
+            assert!(!matches!(frame.best_supported_claim.unwrap().belief.source, Source::Derived { .. }));
+        }
+        assert("status", Value::from("cold"), 0);
+        assert("status", Value::from("warm"), 40);
+        let frame = resolve("status", ResolveMode::Interpolate, 10);
+        assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::from("cold"));
+    }
+
+    #[test]
+    fn resolve_conflict_records_the_policy_winner_and_principal() {
+        let (eng, id) = engine();
+        assert_status(&eng, id, "replicated", 0.6, "a");
+        assert_status(&eng, id, "retracted", 0.9, "b");
+        let open = eng.conflict_store().find_open().unwrap();
+        assert_eq!(open.len(), 1);
+        let conflict_id = open[0].id;
+        let beliefs = eng.belief_store().find_by_entity_predicate(id, "status").unwrap();
+        let winner = beliefs.iter().find(|b| b.value == Value::from("retracted")).unwrap().id;
+        let loser = beliefs.iter().find(|b| b.value == Value::from("replicated")).unwrap().id;
+
+        let explicit = eng.resolve_conflict(conflict_id, &ConflictResolutionPolicy::ExplicitConflict, None);
+        assert!(matches!(explicit, Err(KyroError::Execution(ExecutionError::ConflictResolutionFailed { .. }))));
+
+        let resolved = eng
+            .resolve_conflict(conflict_id, &ConflictResolutionPolicy::HighestConfidence, Some("reviewer"))
+            .unwrap();
+        assert!(resolved.is_resolved());
+
+        let stored = eng.conflict_store().get(conflict_id).unwrap().unwrap();
+        assert_eq!(stored.audit.len(), 1);
+        let entry = &stored.audit[0];
+        assert_eq!(entry.policy, ConflictResolutionPolicy::HighestConfidence);
+        assert_eq!(entry.winner, winner);
+        assert_eq!(entry.losers, vec![loser]);
+        assert_eq!(entry.principal.as_deref(), Some("reviewer"));
+        assert_eq!(stored.resolved_at, Some(entry.resolved_at));
+        assert!(matches!(
+            stored.resolution,
+            Some(ConflictResolution::HigherConfidence { chosen_belief_id, .. }) if chosen_belief_id == winner
+        ));
+
+        // Only open conflicts can be resolved.
+        assert!(eng
+            .resolve_conflict(conflict_id, &ConflictResolutionPolicy::LatestWins, None)
+            .is_err());
+    }
+
+    #[test]
+    fn concurrent_conflict_resolutions_keep_one_audit_entry() {
+        const THREADS: usize = 8;
+        let (eng, id) = engine();
+        assert_status(&eng, id, "replicated", 0.6, "a");
+        assert_status(&eng, id, "retracted", 0.9, "b");
+        let stale = eng.conflict_store().find_open().unwrap().remove(0);
+        let conflict_id = stale.id;
+
+        let barrier = std::sync::Barrier::new(THREADS);
+        let results: Vec<KyroResult<Conflict>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|n| {
+                    let (eng, barrier) = (&eng, &barrier);
+                    scope.spawn(move || {
+                        barrier.wait();
+                        let principal = format!("reviewer-{n}");
+                        eng.resolve_conflict(conflict_id, &ConflictResolutionPolicy::HighestConfidence, Some(&principal))
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let won: Vec<&Conflict> = results.iter().filter_map(|r| r.as_ref().ok()).collect();
+        assert_eq!(won.len(), 1);
+        assert!(results.iter().filter(|r| r.is_err()).all(|r| matches!(
+            r,
+            Err(KyroError::Execution(ExecutionError::ConflictResolutionFailed { .. }))
+        )));
+        let stored = eng.conflict_store().get(conflict_id).unwrap().unwrap();
+        assert_eq!(stored.audit, won[0].audit);
+        assert_eq!(stored.audit.len(), 1);
+
+        // A write based on a read from before the resolution loses.
+        assert!(!eng.conflict_store().update_if_open(stale).unwrap());
+        assert_eq!(eng.conflict_store().get(conflict_id).unwrap().unwrap().audit, stored.audit);
+    }
+
+    #[test]
+    fn confidence_source_weight_lets_human_confidence_outrank_model_confidence() {
+        use crate::confidence::{ConfidenceSource, ConfidenceSourceKind};
+
+        let resolve_with = |favoured: ConfidenceSourceKind| {
+            let model = Arc::new(SimpleTrustModel::new());
+            model.set_confidence_source_weight(favoured, 1.2).unwrap();
+            let (eng, id) = engine_with_trust_model(model);
+            let assert = |value: &str, source: ConfidenceSource| {
+                eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    confidence: Confidence::probability(0.8, source).unwrap(),
+                    ..assert_payload(id, "status", value, "a", ConsistencyMode::Eventual)
+                })))
+                .unwrap();
+            };
+            assert("curated", ConfidenceSource::AssertedByHuman { user_id: "u".to_string() });
+            assert(
+                "estimated",
+                ConfidenceSource::ComputedByModel {
+                    model_id: "m".to_string(),
+                    model_version: "1".to_string(),
+                },
+            );
+            let claim = resolve_status(&eng, id, false).best_supported_claim.unwrap();
+            (claim.belief.value, claim.epistemic_confidence)
+        };
+
+        let (value, confidence) = resolve_with(ConfidenceSourceKind::Human);
+        assert_eq!(value, Value::from("curated"));
+        assert!((confidence - 0.96).abs() < 1e-6, "{confidence}");
+        let (value, _) = resolve_with(ConfidenceSourceKind::Model);
+        assert_eq!(value, Value::from("estimated"));
+    }
+
+    #[test]
+    fn deactivated_and_updated_patterns_stop_flagging_asserts() {
+        let (eng, id) = engine();
+        let define = |rule: PatternRule| {
+            let EngineResponse::DefinePattern { pattern_id } = eng
+                .execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                    name: "single_status".to_string(),
+                    description: None,
+                    rule,
+                    confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                    valid_time: TimeRange::forever(),
+                })))
+                .unwrap()
+            else {
+                panic!("expected define_pattern");
+            };
+            pattern_id
+        };
+        let strict = |value: &str| {
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                id, "status", value, "a", ConsistencyMode::Strict,
+            ))))
+        };
+
+        let allowed = || vec!["open".to_string(), "closed".to_string()];
+        let pattern_id = define(PatternRule::enumerated("status", allowed()));
+        assert!(matches!(
+            strict("bogus"),
+            Err(KyroError::Execution(ExecutionError::ConflictsDetected { .. }))
+        ));
+
+        let response = eng
+            .execute(KyroIR::new(Operation::DeactivatePattern(pattern_id)))
+            .unwrap();
+        assert!(matches!(response, EngineResponse::DeactivatePattern { pattern_id: p } if p == pattern_id));
+        assert!(!eng.pattern_store().get(pattern_id).unwrap().unwrap().active);
+        strict("bogus").unwrap();
+
+        // Moving an active pattern's rule to another predicate stops it flagging this one.
+        let other = define(PatternRule::enumerated("status", allowed()));
+        assert!(strict("bogus").is_err());
+        eng.execute(KyroIR::new(Operation::UpdatePattern(UpdatePatternPayload {
+            pattern_id: other,
+            rule: Some(PatternRule::enumerated("phase", allowed())),
+            ..UpdatePatternPayload::default()
+        })))
+        .unwrap();
+        strict("bogus").unwrap();
+
+        let missing = eng.execute(KyroIR::new(Operation::DeactivatePattern(PatternId::new())));
+        assert!(missing.is_err());
+        let empty = eng.execute(KyroIR::new(Operation::UpdatePattern(UpdatePatternPayload {
+            pattern_id: other,
+            ..UpdatePatternPayload::default()
+        })));
+        assert!(matches!(empty, Err(KyroError::Validation(ValidationError::MissingField { .. }))));
+    }
+
+    #[test]
+    fn transaction_deactivates_a_pattern_before_a_violating_assert() {
+        let (eng, id) = engine();
+        let EngineResponse::DefinePattern { pattern_id } = eng
+            .execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                name: "single_status".to_string(),
+                description: None,
+                rule: PatternRule::enumerated("status", vec!["open".to_string()]),
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            })))
+            .unwrap()
+        else {
+            panic!("expected define_pattern");
+        };
+        let assert = |value: &str| {
+            Operation::Assert(assert_payload(id, "status", value, "a", ConsistencyMode::Strict))
+        };
+        assert!(eng.execute(KyroIR::new(assert("bogus"))).is_err());
+
+        eng.execute(KyroIR::new(Operation::Transaction(vec![
+            Operation::DeactivatePattern(pattern_id),
+            assert("bogus"),
+        ])))
+        .unwrap();
+        assert!(!eng.pattern_store().get(pattern_id).unwrap().unwrap().active);
+        assert!(eng.pattern_store().find_active().unwrap().is_empty());
+    }
+
+    fn resolve_status_interval(eng: &KyroEngine, id: EntityId, semantic: bool) -> BeliefFrame {
+        let payload = ResolvePayload {
+            entity_id: Some(id),
+            predicate: Some("status".to_string()),
+            query_embedding: semantic.then(|| vec![1.0, 0.0, 0.0]),
+            include_confidence_interval: true,
+            ..ResolvePayload::default()
+        };
+        let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+            panic!("expected resolve");
+        };
+        frame
+    }
+
+    #[test]
+    fn confidence_interval_widens_when_evidence_disagrees() {
+        for semantic in [false, true] {
+            let (unanimous, id) = engine();
+            assert_status(&unanimous, id, "on", 0.9, "a");
+            assert_status(&unanimous, id, "on", 0.85, "b");
+            let frame = resolve_status_interval(&unanimous, id, semantic);
+            let point = frame.epistemic_confidence.unwrap();
+            let (lo, hi) = frame.confidence_interval.unwrap();
+            assert!(lo <= point && point <= hi);
+            let narrow = hi - lo;
+
+            let (contested, id) = engine();
+            assert_status(&contested, id, "on", 0.9, "a");
+            assert_status(&contested, id, "off", 0.8, "b");
+            let frame = resolve_status_interval(&contested, id, semantic);
+            let point = frame.epistemic_confidence.unwrap();
+            let (lo, hi) = frame.confidence_interval.unwrap();
+            assert!(lo <= point && point <= hi);
+            let wide = hi - lo;
+
+            assert!(narrow < 0.2, "unanimous interval too wide: {narrow}");
+            assert!(wide > 0.5, "contested interval too narrow: {wide}");
+
+            assert!(resolve_status(&contested, id, semantic).confidence_interval.is_none());
+        }
+    }
+
+    #[test]
+    fn check_pattern_set_reports_contradictory_active_patterns() {
+        let (eng, _) = engine();
+        let define = |rule: PatternRule| {
+            let EngineResponse::DefinePattern { pattern_id } = eng
+                .execute(KyroIR::new(Operation::DefinePattern(DefinePatternPayload {
+                    name: "status_rule".to_string(),
+                    description: None,
+                    rule,
+                    confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                    valid_time: TimeRange::forever(),
+                })))
+                .unwrap()
+            else {
+                panic!("expected define_pattern");
+            };
+            pattern_id
+        };
+        let check = || {
+            let EngineResponse::CheckPatternSet { warnings } = eng
+                .execute(KyroIR::new(Operation::CheckPatternSet("status".to_string())))
+                .unwrap()
+            else {
+                panic!("expected check_pattern_set");
+            };
+            warnings
+        };
+
+        let allowed = define(PatternRule::enumerated("status", vec!["open".to_string(), "closed".to_string()]));
+        define(PatternRule::unique("status"));
+        assert!(check().is_empty());
+
+        // Enumerated values are strings, which a non-coercing range never accepts.
+        let range = define(PatternRule::range("status", Some(0.0), Some(1.0)));
+        let warnings = check();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].pattern_ids, vec![allowed, range]);
+        assert_eq!(eng.validate_pattern_set("status").unwrap(), warnings);
+
+        eng.execute(KyroIR::new(Operation::DeactivatePattern(range))).unwrap();
+        assert!(check().is_empty());
+
+        assert!(eng
+            .execute(KyroIR::new(Operation::CheckPatternSet(" ".to_string())))
+            .is_err());
+    }
+
+    #[test]
+    fn source_merging_folds_duplicate_values_into_one_belief() {
+        let (eng, id) = engine();
+        let eng = eng.with_source_merging(true);
+        assert_status(&eng, id, "on", 0.6, "a");
+        assert_status(&eng, id, "on", 0.5, "b");
+
+        let beliefs = eng.belief_store().find_by_entity_predicate(id, "status").unwrap();
+        assert_eq!(beliefs.len(), 1);
+        let merged = &beliefs[0];
+        assert_eq!(merged.sources().count(), 2);
+        assert_eq!(merged.source, Source::agent("a", Option::<String>::None));
+        assert_eq!(merged.corroborating_sources, vec![Source::agent("b", Option::<String>::None)]);
+        assert!((merged.confidence.value() - 0.8).abs() < 1e-6);
+
+        // A different value still gets its own belief.
+        assert_status(&eng, id, "off", 0.7, "c");
+        assert_eq!(eng.belief_store().find_by_entity_predicate(id, "status").unwrap().len(), 2);
+
+        let (plain, id) = engine();
+        assert_status(&plain, id, "on", 0.6, "a");
+        assert_status(&plain, id, "on", 0.5, "b");
+        assert_eq!(plain.belief_store().find_by_entity_predicate(id, "status").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn concurrent_corroborations_keep_every_source() {
+        const CORROBORATORS: usize = 8;
+
+        let (eng, id) = engine();
+        let eng = eng.with_source_merging(true);
+        assert_status(&eng, id, "on", 0.5, "origin");
+        std::thread::scope(|scope| {
+            for i in 0..CORROBORATORS {
+                let eng = &eng;
+                scope.spawn(move || assert_status(eng, id, "on", 0.5, &format!("agent-{i}")));
+            }
+        });
+
+        let beliefs = eng.belief_store().find_by_entity_predicate(id, "status").unwrap();
+        assert_eq!(beliefs.len(), 1);
+        assert_eq!(beliefs[0].corroborating_sources.len(), CORROBORATORS);
+        let expected = 1.0 - 0.5f32.powi(CORROBORATORS as i32 + 1);
+        assert!((beliefs[0].confidence.value() - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn snapshot_reads_serve_concurrent_resolves_during_asserts() {
+        const WRITERS: usize = 4;
+        const PER_WRITER: usize = 25;
+
+        let (eng, id) = engine();
+        let eng = eng.with_snapshot_reads(true);
+        let resolve = |eng: &KyroEngine| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                limit: 500,
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap() else {
+                panic!("expected resolve");
+            };
+            frame.supporting_evidence.len()
+        };
+
+        std::thread::scope(|scope| {
+            for writer in 0..WRITERS {
+                let eng = &eng;
+                scope.spawn(move || {
+                    for n in 0..PER_WRITER {
+                        assert_status(eng, id, "on", 0.8, &format!("agent-{writer}-{n}"));
+                    }
+                });
+            }
+            for _ in 0..WRITERS {
+                let eng = &eng;
+                scope.spawn(move || {
+                    // Each snapshot holds at least what the previous one did.
+                    let mut seen = 0;
+                    while seen < WRITERS * PER_WRITER {
+                        let supporting = resolve(eng);
+                        assert!(supporting >= seen, "snapshot went back from {seen} to {supporting}");
+                        seen = supporting;
+                    }
+                });
+            }
+        });
+
+        assert_eq!(resolve(&eng), WRITERS * PER_WRITER);
+    }
+
+    #[test]
+    fn confidence_remaps_let_a_conservative_source_outrank_an_inflated_one() {
+        let resolve_with = |remap: bool| {
+            let model = Arc::new(SimpleTrustModel::new());
+            if remap {
+                // "timid" reports 0.6 for what others call 0.9; "eager" rates everything high.
+                model
+                    .set_confidence_remap(Source::agent("timid", Option::<String>::None).source_id(), 1.5, 0.0)
+                    .unwrap();
+                model
+                    .set_confidence_remap(Source::agent("eager", Option::<String>::None).source_id(), 0.5, 0.2)
+                    .unwrap();
+            }
+            let (eng, id) = engine_with_trust_model(model);
+            assert_status(&eng, id, "measured", 0.6, "timid");
+            assert_status(&eng, id, "guessed", 0.8, "eager");
+            let claim = resolve_status(&eng, id, false).best_supported_claim.unwrap();
+            (claim.belief.value, claim.epistemic_confidence)
+        };
+
+        assert_eq!(resolve_with(false).0, Value::from("guessed"));
+        let (value, confidence) = resolve_with(true);
+        assert_eq!(value, Value::from("measured"));
+        assert!(confidence > 0.8, "{confidence}");
+    }
+
+    #[test]
+    fn predicate_fallbacks_answer_when_the_primary_predicate_is_empty() {
+        let (eng, id) = engine();
+        assert_status(&eng, id, "active", 0.8, "a");
+        let resolve = |predicate: &str, fallbacks: &[&str]| {
+            let payload = ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some(predicate.to_string()),
+                predicate_fallbacks: fallbacks.iter().map(ToString::to_string).collect(),
+                ..ResolvePayload::default()
+            };
+            let EngineResponse::Resolve { frame } = eng.execute(KyroIR::new(Operation::Resolve(payload))).unwrap()
+            else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+
+        let frame = resolve("full_status", &["state", "status", "label"]);
+        assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::from("active"));
+        assert_eq!(frame.answered_predicate.as_deref(), Some("status"));
+        assert!(frame.gaps.is_empty(), "{:?}", frame.gaps);
+
+        let frame = resolve("status", &["label"]);
+        assert_eq!(frame.answered_predicate.as_deref(), Some("status"));
+
+        let frame = resolve("full_status", &["label"]);
+        assert!(!frame.has_answer());
+        assert!(frame.answered_predicate.is_none());
+        let missing: Vec<_> = frame.gaps.iter().filter_map(|g| g.missing_predicate.as_deref()).collect();
+        assert_eq!(missing, ["full_status", "label"]);
+    }
+
+    #[test]
+    fn predicate_schema_rejects_mistyped_asserts_before_insert() {
+        let (eng, id) = engine();
+        eng.register_predicate_schema(
+            "temperature",
+            PredicateSchema::new(crate::value::ValueType::Float).with_unit("kelvin"),
+        )
+        .unwrap();
+        let assert = |value: Value| {
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                id, "temperature", value, "sensor", ConsistencyMode::Eventual,
+            ))))
+        };
+
+        let err = assert(Value::from("warm")).unwrap_err();
+        assert!(matches!(err, KyroError::Validation(ValidationError::InvalidField { ref field, .. }) if field == "value"));
+        assert!(err.to_string().contains("float (kelvin)"), "{err}");
+        assert!(eng.belief_store().find_by_entity(id).unwrap().is_empty());
+
+        assert(Value::Float(293.15)).unwrap();
+        assert(Value::not(Value::Float(0.0))).unwrap();
+        assert_eq!(eng.belief_store().find_by_entity(id).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn resolve_marginalizes_categorical_distributions_across_sources() {
+        let (eng, id) = engine();
+        let assert = |agent: &str, value: Value| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                confidence: Confidence::from_agent(0.8, agent).unwrap(),
+                valid_time: TimeRange::from_now(),
+                ..assert_payload(id, "weather", value, agent, ConsistencyMode::Strict)
+            })))
+        };
+        let dist = |outcomes: &[(&str, f32)]| {
+            Value::distribution(outcomes.iter().map(|(v, w)| (Value::from(*v), *w)).collect()).unwrap()
+        };
+
+        assert("forecaster-a", dist(&[("sun", 0.6), ("rain", 0.4)])).unwrap();
+        // Both sources favour sun, so the differing weights are not a conflict.
+        assert("forecaster-b", dist(&[("sun", 0.8), ("snow", 0.2)])).unwrap();
+        assert!(matches!(
+            assert("forecaster-c", Value::Distribution(vec![(Value::from("sun"), 0.2)])),
+            Err(KyroError::Validation(_))
+        ));
+
+        let EngineResponse::Resolve { frame } = eng
+            .execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("weather".to_string()),
+                ..ResolvePayload::default()
+            })))
+            .unwrap()
+        else {
+            panic!("expected resolve");
+        };
+        assert!(frame.conflicts.is_empty());
+        let combined = frame.distribution.unwrap();
+        let values: Vec<_> = combined.iter().map(|(v, _)| v.clone()).collect();
+        assert_eq!(values, vec![Value::from("sun"), Value::from("rain"), Value::from("snow")]);
+        for ((_, got), want) in combined.iter().zip([0.7, 0.2, 0.1]) {
+            assert!((got - want).abs() < 1e-5, "expected {want}, got {got}");
+        }
+    }
+
+    #[test]
+    fn deterministic_conflict_ids_are_stable_when_redetected() {
+        let (eng, id) = engine();
+        let eng = eng.with_deterministic_conflict_ids(true);
+        let assert = |value: &str| {
+            let EngineResponse::Assert { belief_id, conflict_ids, .. } = eng
+                .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    confidence: Confidence::from_agent(0.8, "observer").unwrap(),
+                    valid_time: TimeRange::from_now(),
+                    ..assert_payload(id, "color", value, "observer", ConsistencyMode::Eventual)
+                })))
+                .unwrap()
+            else {
+                panic!("expected assert");
+            };
+            (belief_id, conflict_ids)
+        };
+
+        let (red, _) = assert("red");
+        let (blue, conflict_ids) = assert("blue");
+        let expected = ConflictId::deterministic(
+            &ConflictType::ValueContradiction {
+                predicate: "color".to_string(),
+            },
+            &[blue, red],
+        );
+        assert_eq!(conflict_ids, vec![expected]);
+
+        // Once dismissed, the conflict no longer absorbs new detections; detecting the same
+        // contradiction again maps to the stored conflict instead of a second record.
+        let mut stored = eng.conflict_store().get(expected).unwrap().unwrap();
+        stored.dismiss();
+        eng.conflict_store().update(stored).unwrap();
+        let belief = eng.belief_store().get(blue).unwrap().unwrap();
+        let redetected = eng.detect_conflicts(&belief, Utc::now(), &HashSet::new()).unwrap();
+        assert_eq!(redetected.len(), 1);
+        assert_eq!(redetected[0].id, expected);
+        assert_eq!(eng.record_conflict(&redetected[0]).unwrap(), expected);
+
+        let conflicts = eng.conflict_store().find_by_entity(id).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert!(!conflicts[0].is_open());
+    }
+
+    #[test]
+    fn min_support_requires_enough_agreeing_beliefs() {
+        let (eng, id) = engine();
+        let assert = |agent: &str| {
+            eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                valid_time: TimeRange::from_now(),
+                ..assert_payload(id, "status", "online", agent, ConsistencyMode::Force)
+            })))
+            .unwrap();
+        };
+        let resolve = || {
+            let EngineResponse::Resolve { frame } = eng
+                .execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                    entity_id: Some(id),
+                    predicate: Some("status".to_string()),
+                    min_support: 2,
+                    ..ResolvePayload::default()
+                })))
+                .unwrap()
+            else {
+                panic!("expected resolve");
+            };
+            frame
+        };
+
+        assert("probe-a");
+        let frame = resolve();
+        assert!(frame.best_supported_claim.is_none());
+        assert!(frame.epistemic_confidence.is_none());
+        assert_eq!(frame.supporting_evidence.len(), 1);
+        assert!(frame
+            .gaps
+            .iter()
+            .any(|g| g.gap_type == crate::frame::GapType::InsufficientEvidence));
+
+        assert("probe-b");
+        let frame = resolve();
+        assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::from("online"));
+        assert_eq!(frame.supporting_evidence.len(), 2);
+        assert!(frame.gaps.is_empty());
+    }
+
+    #[test]
+    fn pattern_cache_reflects_pattern_changes_after_invalidation() {
+        let (eng, id) = engine();
+        let level = |value: i64| {
+            eng.execute(KyroIR::new(Operation::Assert(assert_payload(
+                id, "level", Value::Int(value), "a", ConsistencyMode::Replace,
+            ))))
+        };
+        let define = |max: f64| {
+            Operation::DefinePattern(DefinePatternPayload {
+                name: format!("level_below_{max}"),
+                description: None,
+                rule: PatternRule::range("level", None, Some(max)),
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                valid_time: TimeRange::forever(),
+            })
+        };
+
+        // The empty pattern list for `level` is now cached.
+        level(50).unwrap();
+
+        // A pattern defined inside a transaction invalidates the cache once it commits.
+        eng.execute(KyroIR::new(Operation::Transaction(vec![define(10.0)]))).unwrap();
+        assert!(level(50).is_err());
+
+        // Writing to the store directly bypasses the engine: the cached list stays in use
+        // until the cache is invalidated.
+        let mut pattern = eng.pattern_store().find_by_predicate("level").unwrap().remove(0);
+        pattern.deactivate();
+        eng.pattern_store().update(pattern).unwrap();
+        assert!(level(50).is_err());
+        eng.invalidate_pattern_cache().unwrap();
+        level(50).unwrap();
+
+        // Clones share the cache, so a pattern defined through one applies to all.
+        let clone = eng.clone();
+        clone.execute(KyroIR::new(define(20.0))).unwrap();
+        assert!(level(50).is_err());
+    }
 }
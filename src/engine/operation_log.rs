@@ -0,0 +1,250 @@
+//! Capture of executed operations for deterministic replay.
+//!
+//! An engine configured with an [`OperationLog`] appends every successfully executed
+//! `KyroIR` in execution order. [`replay`] re-executes such a log against another engine.
+//! The IR carries its own `timestamp`, which becomes the belief `tx_time`, so replayed
+//! beliefs land at the same points in time as the originals.
+//!
+//! Entities are not created through IR; the replay target must already hold them.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::confidence::BeliefId;
+use crate::error::{KyroError, KyroResult};
+use crate::ir::{KyroIR, Operation};
+
+use super::{EngineResponse, KyroEngine};
+
+/// One executed operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoggedOperation {
+    /// The IR as submitted, including its request id and timestamp.
+    pub ir: KyroIR,
+
+    /// Belief the operation created (ASSERT, RETRACT).
+    ///
+    /// Belief IDs are random, so replay maps this to the ID created on the target and
+    /// rewrites later references to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_belief: Option<BeliefId>,
+
+    /// For a TRANSACTION, the belief each of its operations created, in order.
+    ///
+    /// References between operations of the same transaction are not rewritten on replay.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transaction_beliefs: Vec<Option<BeliefId>>,
+}
+
+impl LoggedOperation {
+    /// Record `ir` together with what it produced.
+    #[must_use]
+    pub fn new(ir: KyroIR, response: &EngineResponse) -> Self {
+        Self {
+            ir,
+            created_belief: created_belief(response),
+            transaction_beliefs: transaction_beliefs(response),
+        }
+    }
+}
+
+fn created_belief(response: &EngineResponse) -> Option<BeliefId> {
+    match response {
+        EngineResponse::Assert { belief_id, .. } => Some(*belief_id),
+        EngineResponse::Retract {
+            retraction_belief_id,
+        } => Some(*retraction_belief_id),
+        _ => None,
+    }
+}
+
+fn transaction_beliefs(response: &EngineResponse) -> Vec<Option<BeliefId>> {
+    match response {
+        EngineResponse::Transaction { responses } => responses.iter().map(created_belief).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Sink for executed operations.
+///
+/// `append` runs after the operation has been applied; an error is returned to the
+/// caller of `KyroEngine::execute` even though the operation took effect.
+pub trait OperationLog: Send + Sync {
+    /// Record one executed operation.
+    fn append(&self, entry: &LoggedOperation) -> KyroResult<()>;
+
+    /// Called with each validated operation before it runs, e.g. to record it ahead of
+    /// the writes it makes. An error aborts the operation. Does nothing by default.
+    fn begin(&self, _ir: &KyroIR) -> KyroResult<()> {
+        Ok(())
+    }
+}
+
+/// Operation log kept in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryOperationLog {
+    entries: Mutex<Vec<LoggedOperation>>,
+}
+
+impl InMemoryOperationLog {
+    /// Create an empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of the recorded operations, oldest first.
+    pub fn entries(&self) -> KyroResult<Vec<LoggedOperation>> {
+        Ok(self
+            .entries
+            .lock()
+            .map_err(|_| KyroError::internal("operation log lock poisoned"))?
+            .clone())
+    }
+}
+
+impl OperationLog for InMemoryOperationLog {
+    fn append(&self, entry: &LoggedOperation) -> KyroResult<()> {
+        self.entries
+            .lock()
+            .map_err(|_| KyroError::internal("operation log lock poisoned"))?
+            .push(entry.clone());
+        Ok(())
+    }
+}
+
+/// Operation log written as one JSON object per line.
+#[derive(Debug)]
+pub struct JsonLinesOperationLog<W> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonLinesOperationLog<W> {
+    /// Log to `writer`; each entry is flushed as it is written.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Recover the underlying writer.
+    pub fn into_inner(self) -> KyroResult<W> {
+        self.writer
+            .into_inner()
+            .map_err(|_| KyroError::internal("operation log lock poisoned"))
+    }
+}
+
+impl<W: Write + Send> OperationLog for JsonLinesOperationLog<W> {
+    fn append(&self, entry: &LoggedOperation) -> KyroResult<()> {
+        let mut line = serde_json::to_vec(entry)
+            .map_err(|e| KyroError::internal(format!("serialize operation log entry: {e}")))?;
+        line.push(b'\n');
+
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| KyroError::internal("operation log lock poisoned"))?;
+        writer
+            .write_all(&line)
+            .and_then(|()| writer.flush())
+            .map_err(|e| KyroError::internal(format!("write operation log: {e}")))
+    }
+}
+
+/// Writes each operation that can change state into the store's WAL ahead of its effects.
+///
+/// The entries are skipped on replay and read back with
+/// [`PersistentStores::iter_operations`](crate::storage::PersistentStores::iter_operations).
+/// Reads are not logged, and nothing is recorded after execution.
+#[cfg(feature = "persistent")]
+impl OperationLog for crate::storage::PersistentStores {
+    fn append(&self, _entry: &LoggedOperation) -> KyroResult<()> {
+        Ok(())
+    }
+
+    fn begin(&self, ir: &KyroIR) -> KyroResult<()> {
+        if ir.operation.writes() {
+            self.log_operation(ir).map_err(KyroEngine::storage_err)?;
+        }
+        Ok(())
+    }
+}
+
+/// Read a log written by [`JsonLinesOperationLog`]. Blank lines are skipped.
+pub fn read_json_lines(reader: impl BufRead) -> KyroResult<Vec<LoggedOperation>> {
+    let mut entries = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| KyroError::internal(format!("read operation log: {e}")))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).map_err(|e| {
+            KyroError::internal(format!("operation log line {}: {e}", index + 1))
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Re-execute logged operations against `engine`, in order.
+///
+/// Stops at the first operation that fails and returns its error. Returns the number of
+/// operations executed.
+pub fn replay(
+    log: impl IntoIterator<Item = LoggedOperation>,
+    engine: &KyroEngine,
+) -> KyroResult<usize> {
+    let mut belief_ids: HashMap<BeliefId, BeliefId> = HashMap::new();
+    let mut executed = 0;
+    for entry in log {
+        let mut ir = entry.ir;
+        remap_beliefs(&mut ir.operation, &belief_ids);
+        let response = engine.execute(ir)?;
+        if let (Some(original), Some(replayed)) = (entry.created_belief, created_belief(&response)) {
+            belief_ids.insert(original, replayed);
+        }
+        let replayed = transaction_beliefs(&response);
+        for (original, replayed) in entry.transaction_beliefs.iter().zip(&replayed) {
+            if let (Some(original), Some(replayed)) = (original, replayed) {
+                belief_ids.insert(*original, *replayed);
+            }
+        }
+        executed += 1;
+    }
+    Ok(executed)
+}
+
+fn remap_beliefs(operation: &mut Operation, ids: &HashMap<BeliefId, BeliefId>) {
+    let remap = |id: &mut BeliefId| {
+        if let Some(mapped) = ids.get(id) {
+            *id = *mapped;
+        }
+    };
+    match operation {
+        Operation::Retract(payload) => remap(&mut payload.belief_id),
+        Operation::Feedback(payload) => remap(&mut payload.belief_id),
+        Operation::Derive(payload) => {
+            payload.derived_belief_id.iter_mut().for_each(remap);
+            payload.sources.iter_mut().flatten().for_each(remap);
+        }
+        Operation::Transaction(operations) => {
+            for operation in operations {
+                remap_beliefs(operation, ids);
+            }
+        }
+        Operation::Assert(_)
+        | Operation::Resolve(_)
+        | Operation::ResolveCompound(_)
+        | Operation::ResolveMulti(_)
+        | Operation::Simulate(_)
+        | Operation::Monitor(_)
+        | Operation::DefinePattern(_)
+        | Operation::UpdatePattern(_)
+        | Operation::DeactivatePattern(_)
+        | Operation::CheckPatternSet(_) => {}
+    }
+}
@@ -0,0 +1,59 @@
+//! Per-predicate cache of the patterns ASSERT checks.
+//!
+//! Conflict detection looks up the patterns on a belief's predicate for every ASSERT.
+//! [`PatternCache`] keeps each lookup's result until the engine changes a pattern, so a
+//! stream of asserts on one predicate scans the pattern store once.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::error::{KyroError, KyroResult};
+use crate::pattern::Pattern;
+
+/// Predicate → patterns map shared by clones of an engine.
+#[derive(Debug, Default)]
+pub(crate) struct PatternCache {
+    inner: RwLock<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    by_predicate: HashMap<String, Arc<Vec<Pattern>>>,
+    /// Bumped by every invalidation, so a load that raced one is not cached.
+    generation: u64,
+}
+
+impl PatternCache {
+    /// The patterns on `predicate`, from the cache or else from `load`.
+    pub(crate) fn get_or_load(
+        &self,
+        predicate: &str,
+        load: impl FnOnce() -> KyroResult<Vec<Pattern>>,
+    ) -> KyroResult<Arc<Vec<Pattern>>> {
+        let generation = {
+            let inner = self.inner.read().map_err(|_| poisoned())?;
+            if let Some(patterns) = inner.by_predicate.get(predicate) {
+                return Ok(Arc::clone(patterns));
+            }
+            inner.generation
+        };
+        let patterns = Arc::new(load()?);
+        let mut inner = self.inner.write().map_err(|_| poisoned())?;
+        if inner.generation == generation {
+            inner.by_predicate.insert(predicate.to_string(), Arc::clone(&patterns));
+        }
+        Ok(patterns)
+    }
+
+    /// Forget every cached lookup.
+    pub(crate) fn invalidate(&self) -> KyroResult<()> {
+        let mut inner = self.inner.write().map_err(|_| poisoned())?;
+        inner.by_predicate.clear();
+        inner.generation += 1;
+        Ok(())
+    }
+}
+
+fn poisoned() -> KyroError {
+    KyroError::internal("pattern cache lock poisoned")
+}
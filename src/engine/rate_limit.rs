@@ -0,0 +1,148 @@
+//! Per-source admission control for ASSERT.
+//!
+//! The engine consults an optional [`RateLimiter`] before an assert touches any store, keyed by
+//! `Source::source_id()`. Rejected asserts surface as `ExecutionError::QueueFull`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::confidence::SourceId;
+use crate::error::{KyroError, KyroResult, ValidationError};
+
+/// Upper bound on sources tracked by [`TokenBucketRateLimiter`] before idle buckets are dropped.
+const MAX_TRACKED_SOURCES: usize = 65_536;
+
+/// Returned by a [`RateLimiter`] when a source has exhausted its budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitExceeded {
+    /// Asserts the source may make per window.
+    pub capacity: usize,
+}
+
+/// Decides whether a source may assert right now.
+pub trait RateLimiter: Send + Sync {
+    /// Take one assert from `source`'s budget.
+    fn try_acquire(&self, source: SourceId) -> Result<(), RateLimitExceeded>;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token bucket per source: up to `capacity` asserts at once, refilled evenly over `window`.
+#[derive(Debug)]
+pub struct TokenBucketRateLimiter {
+    capacity: u32,
+    window: Duration,
+    buckets: Mutex<HashMap<SourceId, Bucket>>,
+}
+
+impl TokenBucketRateLimiter {
+    /// Allow each source `capacity` asserts per `window`.
+    pub fn new(capacity: u32, window: Duration) -> KyroResult<Self> {
+        if capacity == 0 {
+            return Err(ValidationError::InvalidField {
+                field: "capacity".to_string(),
+                reason: "must be at least 1".to_string(),
+            }
+            .into());
+        }
+        if window.is_zero() {
+            return Err(ValidationError::InvalidField {
+                field: "window".to_string(),
+                reason: "must be non-zero".to_string(),
+            }
+            .into());
+        }
+        Ok(Self {
+            capacity,
+            window,
+            buckets: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Asserts allowed per window.
+    #[must_use]
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Length of the refill window.
+    #[must_use]
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// [`RateLimiter::try_acquire`] against an explicit clock reading.
+    pub fn try_acquire_at(&self, source: SourceId, now: Instant) -> KyroResult<bool> {
+        let capacity = f64::from(self.capacity);
+        let per_second = capacity / self.window.as_secs_f64();
+        let refill = |bucket: &Bucket| {
+            let elapsed = now.saturating_duration_since(bucket.refilled_at).as_secs_f64();
+            (bucket.tokens + elapsed * per_second).min(capacity)
+        };
+
+        let mut buckets = self
+            .buckets
+            .lock()
+            .map_err(|_| KyroError::internal("rate limiter lock poisoned"))?;
+        if buckets.len() >= MAX_TRACKED_SOURCES && !buckets.contains_key(&source) {
+            // A full bucket carries no state beyond "never seen", so it is safe to forget.
+            buckets.retain(|_, bucket| refill(bucket) < capacity);
+        }
+
+        let bucket = buckets.entry(source).or_insert(Bucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+        bucket.tokens = refill(bucket);
+        bucket.refilled_at = now;
+        if bucket.tokens < 1.0 {
+            return Ok(false);
+        }
+        bucket.tokens -= 1.0;
+        Ok(true)
+    }
+}
+
+impl RateLimiter for TokenBucketRateLimiter {
+    fn try_acquire(&self, source: SourceId) -> Result<(), RateLimitExceeded> {
+        // A poisoned lock only means another thread panicked mid-update; fail open rather
+        // than blocking every source.
+        match self.try_acquire_at(source, Instant::now()) {
+            Ok(false) => Err(RateLimitExceeded {
+                capacity: self.capacity as usize,
+            }),
+            Ok(true) | Err(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket_refills_over_the_window() {
+        let limiter = TokenBucketRateLimiter::new(2, Duration::from_secs(10)).unwrap();
+        let source = SourceId::new();
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire_at(source, start).unwrap());
+        assert!(limiter.try_acquire_at(source, start).unwrap());
+        assert!(!limiter.try_acquire_at(source, start).unwrap());
+
+        // One token comes back every window / capacity.
+        assert!(limiter.try_acquire_at(source, start + Duration::from_secs(5)).unwrap());
+        assert!(!limiter.try_acquire_at(source, start + Duration::from_secs(5)).unwrap());
+    }
+
+    #[test]
+    fn token_bucket_rejects_empty_budgets() {
+        assert!(TokenBucketRateLimiter::new(0, Duration::from_secs(1)).is_err());
+        assert!(TokenBucketRateLimiter::new(1, Duration::ZERO).is_err());
+    }
+}
@@ -0,0 +1,133 @@
+//! Retention pruning of superseded beliefs.
+//!
+//! Superseding never deletes: every earlier version stays queryable via `as_of`. [`KyroEngine::prune`]
+//! trades that history for space by deleting versions superseded before a cutoff. The current
+//! version of each chain is never superseded and so never pruned. [`KyroEngine::coalesce`] keeps
+//! every instant answerable and only folds back-to-back identical versions together.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+use crate::confidence::BeliefId;
+use crate::entity::EntityId;
+use crate::error::KyroResult;
+
+use super::KyroEngine;
+
+/// Which superseded beliefs [`KyroEngine::prune`] may delete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Beliefs whose superseding belief was recorded before this instant are eligible.
+    pub superseded_before: DateTime<Utc>,
+}
+
+impl RetentionPolicy {
+    /// Prune versions superseded before `cutoff`.
+    #[must_use]
+    pub const fn superseded_before(cutoff: DateTime<Utc>) -> Self {
+        Self {
+            superseded_before: cutoff,
+        }
+    }
+}
+
+/// Counts reported by [`KyroEngine::prune`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    /// Superseded beliefs that were eligible under the policy.
+    pub eligible: usize,
+    /// Beliefs deleted.
+    pub deleted: usize,
+    /// Eligible beliefs kept because an open conflict references them.
+    pub kept_for_conflicts: usize,
+    /// Eligible beliefs kept because a derivation used or produced them.
+    pub kept_for_derivations: usize,
+}
+
+impl KyroEngine {
+    /// Delete beliefs superseded before the policy's cutoff.
+    ///
+    /// Beliefs referenced by an open conflict or by any derivation record are kept. RESOLVE
+    /// `as_of` a pruned period finds nothing and reports a gap. Persistent stores drop deleted
+    /// beliefs from the segment written by the next compaction.
+    ///
+    /// Pruning is not an operation: an operation log does not record it, so replaying the log
+    /// brings the pruned versions back.
+    pub fn prune(&self, policy: RetentionPolicy) -> KyroResult<PruneReport> {
+        let candidates = self
+            .beliefs
+            .find_superseded(policy.superseded_before)
+            .map_err(Self::storage_err)?;
+
+        let in_open_conflicts: HashSet<BeliefId> = self
+            .conflicts
+            .find_open()
+            .map_err(Self::storage_err)?
+            .into_iter()
+            .flat_map(|c| c.belief_ids)
+            .collect();
+
+        let mut report = PruneReport {
+            eligible: candidates.len(),
+            ..PruneReport::default()
+        };
+        for belief in candidates {
+            if in_open_conflicts.contains(&belief.id) {
+                report.kept_for_conflicts += 1;
+                continue;
+            }
+            let derived = self
+                .derivations
+                .find_by_derived_belief(belief.id)
+                .map_err(Self::storage_err)?;
+            let used_as_premise = self
+                .derivations
+                .find_by_premise(belief.id)
+                .map_err(Self::storage_err)?;
+            if !derived.is_empty() || !used_as_premise.is_empty() {
+                report.kept_for_derivations += 1;
+                continue;
+            }
+            self.beliefs.delete(belief.id).map_err(Self::storage_err)?;
+            report.deleted += 1;
+        }
+        Ok(report)
+    }
+
+    /// Merge back-to-back identical versions of `predicate` for `entity_id`, returning how many
+    /// beliefs were removed.
+    ///
+    /// See [`BeliefStore::coalesce`](crate::storage::BeliefStore::coalesce). Beliefs that a
+    /// conflict (of any status) or a derivation record refers to are pinned, so no reference
+    /// is left dangling.
+    pub fn coalesce(&self, entity_id: EntityId, predicate: &str) -> KyroResult<usize> {
+        let mut pinned: HashSet<BeliefId> = self
+            .conflicts
+            .find_by_entity(entity_id)
+            .map_err(Self::storage_err)?
+            .into_iter()
+            .flat_map(|c| c.belief_ids)
+            .collect();
+        for belief in self
+            .beliefs
+            .history(entity_id, predicate)
+            .map_err(Self::storage_err)?
+        {
+            let derived = self
+                .derivations
+                .find_by_derived_belief(belief.id)
+                .map_err(Self::storage_err)?;
+            let used_as_premise = self
+                .derivations
+                .find_by_premise(belief.id)
+                .map_err(Self::storage_err)?;
+            if !derived.is_empty() || !used_as_premise.is_empty() {
+                pinned.insert(belief.id);
+            }
+        }
+        self.beliefs
+            .coalesce(entity_id, predicate, &pinned)
+            .map_err(Self::storage_err)
+    }
+}
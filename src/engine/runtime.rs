@@ -33,10 +33,11 @@ pub trait OperationRouter: Send + Sync {
 ///
 /// Policy:
 /// - `Resolve(Simple)` is Reflex.
-/// - `Resolve(Aggregate|Temporal)` is Reflection.
+/// - `Resolve(Aggregate|Temporal|History|Interpolate)`, `ResolveCompound` and `ResolveMulti`
+///   are Reflection.
 /// - `Assert(Force)` is Reflex; all other consistency modes are Reflection.
-/// - `Retract` is Reflex.
-/// - `DefinePattern`, `Simulate`, `Monitor`, `Derive` are Reflection.
+/// - `Retract` and `Feedback` are Reflex.
+/// - Pattern definitions and updates, `Simulate`, `Monitor`, `Derive` are Reflection.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct DefaultRouter;
 
@@ -45,17 +46,30 @@ impl OperationRouter for DefaultRouter {
         match op {
             Operation::Resolve(payload) => match payload.mode {
                 ResolveMode::Simple => ExecutionPath::Reflex,
-                ResolveMode::Aggregate | ResolveMode::Temporal => ExecutionPath::Reflection,
+                ResolveMode::Aggregate { .. }
+                | ResolveMode::Temporal
+                | ResolveMode::History
+                | ResolveMode::Interpolate => ExecutionPath::Reflection,
             },
             Operation::Assert(payload) => match payload.consistency_mode {
                 ConsistencyMode::Force => ExecutionPath::Reflex,
-                ConsistencyMode::Strict | ConsistencyMode::Eventual => ExecutionPath::Reflection,
+                ConsistencyMode::Strict
+                | ConsistencyMode::Eventual
+                | ConsistencyMode::Replace
+                | ConsistencyMode::Warn => {
+                    ExecutionPath::Reflection
+                }
             },
-            Operation::Retract(_) => ExecutionPath::Reflex,
-            Operation::DefinePattern(_) => ExecutionPath::Reflection,
-            Operation::Simulate(_) | Operation::Monitor(_) | Operation::Derive(_) => {
-                ExecutionPath::Reflection
-            }
+            Operation::ResolveCompound(_) | Operation::ResolveMulti(_) => ExecutionPath::Reflection,
+            Operation::Retract(_) | Operation::Feedback(_) => ExecutionPath::Reflex,
+            Operation::DefinePattern(_)
+            | Operation::UpdatePattern(_)
+            | Operation::DeactivatePattern(_)
+            | Operation::CheckPatternSet(_) => ExecutionPath::Reflection,
+            Operation::Simulate(_)
+            | Operation::Monitor(_)
+            | Operation::Derive(_)
+            | Operation::Transaction(_) => ExecutionPath::Reflection,
         }
     }
 }
@@ -81,6 +95,7 @@ impl Default for KyroRuntimeConfig {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 enum Job {
     Execute {
         ir: KyroIR,
@@ -113,18 +128,19 @@ impl WorkerPool {
             let thread_name = format!("kyroql-{name}-{idx}");
             let handle = thread::Builder::new()
                 .name(thread_name)
-                .spawn(move || loop {
-                    match rx.recv() {
-                        Ok(Job::Execute { ir, reply }) => {
-                            let result = engine.execute(ir);
-                            let _ = reply.send(result);
-                        }
-                        Err(_) => break,
-
-                        #[cfg(test)]
-                        Ok(Job::Sleep { duration, reply }) => {
-                            thread::sleep(duration);
-                            let _ = reply.send(());
+                .spawn(move || {
+                    while let Ok(job) = rx.recv() {
+                        match job {
+                            Job::Execute { ir, reply } => {
+                                let result = engine.execute(ir);
+                                let _ = reply.send(result);
+                            }
+
+                            #[cfg(test)]
+                            Job::Sleep { duration, reply } => {
+                                thread::sleep(duration);
+                                let _ = reply.send(());
+                            }
                         }
                     }
                 })
@@ -351,6 +367,8 @@ mod tests {
                     valid_time: belief.valid_time,
                     consistency_mode: ConsistencyMode::Force,
                     embedding: belief.embedding,
+                    idempotency_key: None,
+                    namespace: None,
                 },
             )))
             .unwrap();
@@ -0,0 +1,107 @@
+//! Per-predicate value schemas checked on ASSERT.
+//!
+//! A [`PredicateSchema`] declares the type every value of a predicate must have, e.g. that
+//! `temperature` is always a float in kelvin. Unlike a `Range` pattern, which only flags a
+//! violation once the belief is stored, a schema rejects a mistyped ASSERT outright.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{KyroError, KyroResult, ValidationError};
+use crate::value::{Value, ValueType};
+
+/// Expected shape of a predicate's values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredicateSchema {
+    /// Type every asserted value must have. A denial (`Value::Not`) is checked by the
+    /// value it denies, a distribution by each of its outcomes.
+    pub value_type: ValueType,
+    /// Unit the values are expressed in, for documentation; values carry no unit to check.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+impl PredicateSchema {
+    /// A schema requiring values of `value_type`.
+    #[must_use]
+    pub fn new(value_type: ValueType) -> Self {
+        Self { value_type, unit: None }
+    }
+
+    /// Record the unit the values are expressed in.
+    #[must_use]
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Check `value`, asserted for `predicate`, against this schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidField` (field `value`) if the value has another type.
+    pub fn check(&self, predicate: &str, value: &Value) -> Result<(), ValidationError> {
+        if let Value::Distribution(outcomes) = value {
+            return outcomes.iter().try_for_each(|(outcome, _)| self.check(predicate, outcome));
+        }
+        let mut checked = value;
+        while let Value::Not(inner) = checked {
+            checked = inner;
+        }
+        if checked.value_type() == self.value_type {
+            return Ok(());
+        }
+        let unit = self.unit.as_deref().map(|u| format!(" ({u})")).unwrap_or_default();
+        Err(ValidationError::InvalidField {
+            field: "value".to_string(),
+            reason: format!(
+                "predicate '{predicate}' expects {}{unit} values, got {}",
+                self.value_type,
+                checked.value_type()
+            ),
+        })
+    }
+}
+
+/// Predicate → schema map shared by clones of an engine.
+#[derive(Debug, Default)]
+pub struct PredicateSchemaRegistry {
+    schemas: RwLock<HashMap<String, PredicateSchema>>,
+}
+
+impl PredicateSchemaRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) the schema for `predicate`.
+    pub fn register(&self, predicate: impl Into<String>, schema: PredicateSchema) -> KyroResult<()> {
+        self.schemas
+            .write()
+            .map_err(|_| KyroError::internal("predicate schema registry lock poisoned"))?
+            .insert(predicate.into(), schema);
+        Ok(())
+    }
+
+    /// Look up the schema for `predicate`.
+    pub fn get(&self, predicate: &str) -> KyroResult<Option<PredicateSchema>> {
+        Ok(self
+            .schemas
+            .read()
+            .map_err(|_| KyroError::internal("predicate schema registry lock poisoned"))?
+            .get(predicate)
+            .cloned())
+    }
+
+    /// Check `value` against the schema registered for `predicate`, if any.
+    pub fn check(&self, predicate: &str, value: &Value) -> KyroResult<()> {
+        match self.get(predicate)? {
+            Some(schema) => schema.check(predicate, value).map_err(KyroError::from),
+            None => Ok(()),
+        }
+    }
+}
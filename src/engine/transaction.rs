@@ -0,0 +1,589 @@
+//! Atomic multi-operation transactions.
+//!
+//! `Operation::Transaction` runs its operations on a staging copy of the engine. The staging
+//! stores buffer every write in memory and read through to the engine's own stores, so each
+//! operation sees the writes of the ones before it. Only once every operation has succeeded
+//! are the buffered writes applied to the real stores, in the order they were made; a failure
+//! discards them. Monitor observations are held back the same way.
+//!
+//! Transactions are atomic but not isolated: other writers are not blocked while the
+//! operations run, and the buffered writes are applied without re-checking them against
+//! writes that landed in the meantime. A storage failure while applying can leave a prefix
+//! of the writes in place.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, RwLock};
+
+use chrono::{DateTime, Utc};
+
+use crate::belief::Belief;
+use crate::confidence::BeliefId;
+use crate::conflict::{Conflict, ConflictId};
+use crate::derivation::{DerivationId, DerivationRecord};
+use crate::entity::EntityId;
+use crate::error::{ExecutionError, KyroError, KyroResult};
+use crate::ir::{KyroIR, Operation};
+use crate::monitor::matcher::AssertObservation;
+use crate::pattern::{Pattern, PatternId};
+use crate::simulation::delta_store::DeltaStore;
+use crate::simulation::{SimulateConstraints, SimulationBaseStores};
+use crate::storage::{
+    AmendFields, BeliefStore, ConflictStore, DerivationStore, IdempotencyReservation, IdempotencyStore,
+    PatternStore, StorageError, StorageStats,
+};
+use crate::time::TimeRange;
+use crate::value::Value;
+
+use super::{EngineResponse, KyroEngine};
+
+/// A write made inside a transaction, applied to the real stores on commit.
+enum StagedWrite {
+    InsertBelief(Belief),
+    Supersede(Vec<BeliefId>, BeliefId),
+    InsertSuperseding(Belief, Vec<BeliefId>),
+    AmendBelief(BeliefId, AmendFields),
+    InsertConflict(Conflict),
+    UpdateConflict(Conflict),
+    InsertPattern(Pattern),
+    UpdatePattern(Pattern),
+    InsertDerivation(DerivationRecord),
+    RecordIdempotencyKey(Option<String>, String, BeliefId),
+}
+
+/// Writes shared by all staging stores of one transaction, oldest first.
+type Journal = Arc<Mutex<Vec<StagedWrite>>>;
+
+/// Observations held back from the monitor until the transaction commits.
+pub(super) type HeldObservations = Arc<Mutex<Vec<AssertObservation>>>;
+
+fn lock_err(op: &'static str) -> StorageError {
+    StorageError::BackendError(format!("poisoned lock: transaction.{op}"))
+}
+
+fn stage(journal: &Journal, write: StagedWrite) -> Result<(), StorageError> {
+    journal.lock().map_err(|_| lock_err("journal"))?.push(write);
+    Ok(())
+}
+
+impl KyroEngine {
+    /// Run `operations` on staging stores, then apply their writes if all of them succeeded.
+    pub(super) fn execute_transaction(
+        &self,
+        timestamp: DateTime<Utc>,
+        operations: Vec<Operation>,
+    ) -> KyroResult<EngineResponse> {
+        let journal = Journal::default();
+        let held = HeldObservations::default();
+        let staging = self.staging_engine(&journal, &held);
+
+        let mut responses = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.into_iter().enumerate() {
+            // Every operation shares the transaction's timestamp, and so its tx_time.
+            let ir = KyroIR {
+                version: KyroIR::CURRENT_VERSION.to_string(),
+                request_id: crate::id::next_id(),
+                timestamp,
+                operation,
+            };
+            let response = staging.dispatch(ir).map_err(|cause| {
+                KyroError::Execution(ExecutionError::TransactionAborted {
+                    index,
+                    cause: Box::new(cause),
+                })
+            })?;
+            responses.push(response);
+        }
+        drop(staging);
+
+        let writes = std::mem::take(&mut *journal.lock().map_err(|_| Self::storage_err(lock_err("journal")))?);
+        self.apply_staged(writes)?;
+
+        let observations = std::mem::take(&mut *held.lock().map_err(|_| Self::storage_err(lock_err("held")))?);
+        for observation in observations {
+            self.observe_assert(observation);
+        }
+
+        Ok(EngineResponse::Transaction { responses })
+    }
+
+    /// A copy of this engine whose writes land in `journal` instead of its stores.
+    fn staging_engine(&self, journal: &Journal, held: &HeldObservations) -> KyroEngine {
+        let delta = DeltaStore::new(
+            SimulationBaseStores {
+                entities: Arc::clone(&self.entities),
+                beliefs: Arc::clone(&self.beliefs),
+                patterns: Arc::clone(&self.patterns),
+                conflicts: Arc::clone(&self.conflicts),
+            },
+            SimulateConstraints {
+                max_affected_entities: usize::MAX,
+                ..SimulateConstraints::default()
+            },
+        );
+
+        KyroEngine {
+            entities: delta.entities(),
+            beliefs: Arc::new(StagedBeliefStore {
+                overlay: delta.beliefs(),
+                journal: Arc::clone(journal),
+            }),
+            patterns: Arc::new(StagedPatternStore {
+                base: Arc::clone(&self.patterns),
+                staged: RwLock::default(),
+                journal: Arc::clone(journal),
+            }),
+            conflicts: Arc::new(StagedConflictStore {
+                base: Arc::clone(&self.conflicts),
+                staged: RwLock::default(),
+                journal: Arc::clone(journal),
+            }),
+            derivations: Arc::new(StagedDerivationStore {
+                base: Arc::clone(&self.derivations),
+                staged: RwLock::default(),
+                journal: Arc::clone(journal),
+            }),
+            idempotency: Arc::new(StagedIdempotencyStore {
+                base: Arc::clone(&self.idempotency),
+                staged: RwLock::default(),
+                journal: Arc::clone(journal),
+            }),
+            operation_log: None,
+            // Staged patterns must not leak into the shared cache before they commit.
+            pattern_cache: Arc::default(),
+            held_observations: Some(Arc::clone(held)),
+            ..self.clone()
+        }
+    }
+
+    /// Apply staged writes to this engine's stores, in the order they were made.
+    fn apply_staged(&self, writes: Vec<StagedWrite>) -> KyroResult<()> {
+        let patterns_changed = writes
+            .iter()
+            .any(|w| matches!(w, StagedWrite::InsertPattern(_) | StagedWrite::UpdatePattern(_)));
+        let applied = writes.into_iter().try_for_each(|write| {
+            match write {
+                StagedWrite::InsertBelief(belief) => self.beliefs.insert(belief),
+                StagedWrite::Supersede(old_ids, new_id) => self.beliefs.supersede_all(&old_ids, new_id),
+                StagedWrite::InsertSuperseding(belief, old_ids) => self.beliefs.insert_superseding(belief, &old_ids),
+                StagedWrite::AmendBelief(id, fields) => self.beliefs.amend(id, fields),
+                StagedWrite::InsertConflict(conflict) => self.conflicts.insert(conflict),
+                StagedWrite::UpdateConflict(conflict) => self.conflicts.update(conflict),
+                StagedWrite::InsertPattern(pattern) => self.patterns.insert(pattern),
+                StagedWrite::UpdatePattern(pattern) => self.patterns.update(pattern),
+                StagedWrite::InsertDerivation(record) => self.derivations.insert(record),
+                StagedWrite::RecordIdempotencyKey(namespace, key, belief_id) => {
+                    self.idempotency.record(namespace.as_deref(), &key, belief_id)
+                }
+            }
+            .map_err(Self::storage_err)
+        });
+        // Invalidate even after a failed write: the writes before it did land.
+        if patterns_changed {
+            self.pattern_cache.invalidate()?;
+        }
+        applied
+    }
+}
+
+/// Belief overlay (the simulation delta store) that also journals its writes.
+struct StagedBeliefStore {
+    overlay: Arc<dyn BeliefStore>,
+    journal: Journal,
+}
+
+impl BeliefStore for StagedBeliefStore {
+    fn insert(&self, belief: Belief) -> Result<(), StorageError> {
+        self.overlay.insert(belief.clone())?;
+        stage(&self.journal, StagedWrite::InsertBelief(belief))
+    }
+
+    fn get(&self, id: BeliefId) -> Result<Option<Belief>, StorageError> {
+        self.overlay.get(id)
+    }
+
+    fn supersede_all(&self, old_ids: &[BeliefId], new_id: BeliefId) -> Result<(), StorageError> {
+        self.overlay.supersede_all(old_ids, new_id)?;
+        stage(&self.journal, StagedWrite::Supersede(old_ids.to_vec(), new_id))
+    }
+
+    fn insert_superseding(&self, belief: Belief, old_ids: &[BeliefId]) -> Result<(), StorageError> {
+        self.overlay.insert_superseding(belief.clone(), old_ids)?;
+        stage(&self.journal, StagedWrite::InsertSuperseding(belief, old_ids.to_vec()))
+    }
+
+    fn amend(&self, id: BeliefId, fields: AmendFields) -> Result<(), StorageError> {
+        self.overlay.amend(id, fields.clone())?;
+        stage(&self.journal, StagedWrite::AmendBelief(id, fields))
+    }
+
+    fn find_superseded(&self, before: DateTime<Utc>) -> Result<Vec<Belief>, StorageError> {
+        self.overlay.find_superseded(before)
+    }
+
+    fn delete(&self, id: BeliefId) -> Result<(), StorageError> {
+        self.overlay.delete(id)
+    }
+
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Belief>, StorageError> {
+        self.overlay.find_by_entity(entity_id)
+    }
+
+    fn find_by_entity_predicate(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+    ) -> Result<Vec<Belief>, StorageError> {
+        self.overlay.find_by_entity_predicate(entity_id, predicate)
+    }
+
+    fn history(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError> {
+        self.overlay.history(entity_id, predicate)
+    }
+
+    fn coalesce(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+        pinned: &HashSet<BeliefId>,
+    ) -> Result<usize, StorageError> {
+        self.overlay.coalesce(entity_id, predicate, pinned)
+    }
+
+    fn reembed(&self, embed: &dyn Fn(&Belief) -> Result<Vec<f32>, StorageError>) -> Result<usize, StorageError> {
+        self.overlay.reembed(embed)
+    }
+
+    fn find_as_of(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<Belief>, StorageError> {
+        self.overlay.find_as_of(entity_id, predicate, as_of)
+    }
+
+    fn find_by_time_range(
+        &self,
+        namespace: Option<&str>,
+        range: &TimeRange,
+    ) -> Result<Vec<Belief>, StorageError> {
+        self.overlay.find_by_time_range(namespace, range)
+    }
+
+    fn find_by_embedding(
+        &self,
+        namespace: Option<&str>,
+        embedding: &[f32],
+        limit: usize,
+        min_confidence: Option<f32>,
+    ) -> Result<Vec<(Belief, f32)>, StorageError> {
+        self.overlay
+            .find_by_embedding(namespace, embedding, limit, min_confidence)
+    }
+
+    fn find_entities_by_predicate_value(
+        &self,
+        namespace: Option<&str>,
+        predicate: &str,
+        value: &Value,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<EntityId>, StorageError> {
+        self.overlay
+            .find_entities_by_predicate_value(namespace, predicate, value, as_of)
+    }
+
+    fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError> {
+        self.overlay.count_by_entity(entity_id)
+    }
+
+    fn subjects(&self) -> Result<Vec<EntityId>, StorageError> {
+        self.overlay.subjects()
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.overlay.stats()
+    }
+}
+
+/// Conflict overlay: staged inserts and updates shadow the base by ID.
+struct StagedConflictStore {
+    base: Arc<dyn ConflictStore>,
+    staged: RwLock<HashMap<ConflictId, Conflict>>,
+    journal: Journal,
+}
+
+impl StagedConflictStore {
+    /// `base` results with staged versions swapped in, plus staged conflicts matching `filter`.
+    fn merge(&self, base: Vec<Conflict>, filter: impl Fn(&Conflict) -> bool) -> Result<Vec<Conflict>, StorageError> {
+        let staged = self.staged.read().map_err(|_| lock_err("conflict.merge"))?;
+        let mut out: Vec<Conflict> = base
+            .into_iter()
+            .filter(|c| !staged.contains_key(&c.id))
+            .collect();
+        out.extend(staged.values().filter(|c| filter(c)).cloned());
+        Ok(out)
+    }
+}
+
+impl ConflictStore for StagedConflictStore {
+    fn insert(&self, conflict: Conflict) -> Result<(), StorageError> {
+        let mut staged = self.staged.write().map_err(|_| lock_err("conflict.insert"))?;
+        if staged.contains_key(&conflict.id) || self.base.get(conflict.id)?.is_some() {
+            return Err(StorageError::DuplicateKey(conflict.id.to_string()));
+        }
+        staged.insert(conflict.id, conflict.clone());
+        stage(&self.journal, StagedWrite::InsertConflict(conflict))
+    }
+
+    fn get(&self, id: ConflictId) -> Result<Option<Conflict>, StorageError> {
+        let staged = self.staged.read().map_err(|_| lock_err("conflict.get"))?;
+        match staged.get(&id) {
+            Some(conflict) => Ok(Some(conflict.clone())),
+            None => self.base.get(id),
+        }
+    }
+
+    fn update(&self, conflict: Conflict) -> Result<(), StorageError> {
+        let mut staged = self.staged.write().map_err(|_| lock_err("conflict.update"))?;
+        if !staged.contains_key(&conflict.id) && self.base.get(conflict.id)?.is_none() {
+            return Err(StorageError::ConflictNotFound(conflict.id));
+        }
+        staged.insert(conflict.id, conflict.clone());
+        stage(&self.journal, StagedWrite::UpdateConflict(conflict))
+    }
+
+    fn update_if_open(&self, conflict: Conflict) -> Result<bool, StorageError> {
+        let mut staged = self.staged.write().map_err(|_| lock_err("conflict.update_if_open"))?;
+        let current = match staged.get(&conflict.id) {
+            Some(current) => current.clone(),
+            None => self
+                .base
+                .get(conflict.id)?
+                .ok_or(StorageError::ConflictNotFound(conflict.id))?,
+        };
+        if !current.is_open() {
+            return Ok(false);
+        }
+        staged.insert(conflict.id, conflict.clone());
+        stage(&self.journal, StagedWrite::UpdateConflict(conflict))?;
+        Ok(true)
+    }
+
+    fn find_by_belief(&self, belief_id: BeliefId) -> Result<Vec<Conflict>, StorageError> {
+        let base = self.base.find_by_belief(belief_id)?;
+        self.merge(base, |c| c.involves_belief(belief_id))
+    }
+
+    fn find_open(&self) -> Result<Vec<Conflict>, StorageError> {
+        let base = self.base.find_open()?;
+        self.merge(base, Conflict::is_open)
+    }
+
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Conflict>, StorageError> {
+        let base = self.base.find_by_entity(entity_id)?;
+        self.merge(base, |c| c.entity_id == entity_id)
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let mut stats = self.base.stats()?;
+        let staged = self.staged.read().map_err(|_| lock_err("conflict.stats"))?;
+        for id in staged.keys() {
+            if self.base.get(*id)?.is_none() {
+                stats.records += 1;
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// Pattern overlay; staged patterns shadow their base versions.
+struct StagedPatternStore {
+    base: Arc<dyn PatternStore>,
+    staged: RwLock<HashMap<PatternId, Pattern>>,
+    journal: Journal,
+}
+
+impl StagedPatternStore {
+    /// `base` results with staged versions swapped in, plus staged patterns matching `filter`.
+    fn merge(&self, base: Vec<Pattern>, filter: impl Fn(&Pattern) -> bool) -> Result<Vec<Pattern>, StorageError> {
+        let staged = self.staged.read().map_err(|_| lock_err("pattern.merge"))?;
+        let mut out: Vec<Pattern> = base
+            .into_iter()
+            .filter(|p| !staged.contains_key(&p.id))
+            .collect();
+        out.extend(staged.values().filter(|p| filter(p)).cloned());
+        Ok(out)
+    }
+}
+
+impl PatternStore for StagedPatternStore {
+    fn insert(&self, pattern: Pattern) -> Result<(), StorageError> {
+        let mut staged = self.staged.write().map_err(|_| lock_err("pattern.insert"))?;
+        if staged.contains_key(&pattern.id) || self.base.get(pattern.id)?.is_some() {
+            return Err(StorageError::DuplicateKey(pattern.id.to_string()));
+        }
+        staged.insert(pattern.id, pattern.clone());
+        stage(&self.journal, StagedWrite::InsertPattern(pattern))
+    }
+
+    fn get(&self, id: PatternId) -> Result<Option<Pattern>, StorageError> {
+        let staged = self.staged.read().map_err(|_| lock_err("pattern.get"))?;
+        match staged.get(&id) {
+            Some(pattern) => Ok(Some(pattern.clone())),
+            None => self.base.get(id),
+        }
+    }
+
+    fn update(&self, pattern: Pattern) -> Result<(), StorageError> {
+        let mut staged = self.staged.write().map_err(|_| lock_err("pattern.update"))?;
+        if !staged.contains_key(&pattern.id) && self.base.get(pattern.id)?.is_none() {
+            return Err(StorageError::PatternNotFound(pattern.id));
+        }
+        staged.insert(pattern.id, pattern.clone());
+        stage(&self.journal, StagedWrite::UpdatePattern(pattern))
+    }
+
+    fn delete(&self, _id: PatternId) -> Result<(), StorageError> {
+        Err(StorageError::BackendError(
+            "pattern.delete is not supported inside a transaction".to_string(),
+        ))
+    }
+
+    fn find_by_predicate(&self, predicate: &str) -> Result<Vec<Pattern>, StorageError> {
+        let base = self.base.find_by_predicate(predicate)?;
+        let predicate = predicate.trim();
+        self.merge(base, |p| p.rule.indexed_predicates().iter().any(|p| p.trim() == predicate))
+    }
+
+    fn find_active(&self) -> Result<Vec<Pattern>, StorageError> {
+        let base = self.base.find_active()?;
+        self.merge(base, Pattern::is_active)
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let mut stats = self.base.stats()?;
+        let staged = self.staged.read().map_err(|_| lock_err("pattern.stats"))?;
+        for id in staged.keys() {
+            if self.base.get(*id)?.is_none() {
+                stats.records += 1;
+            }
+        }
+        Ok(stats)
+    }
+}
+
+/// Derivation overlay; derivation records are insert-only.
+struct StagedDerivationStore {
+    base: Arc<dyn DerivationStore>,
+    staged: RwLock<HashMap<DerivationId, DerivationRecord>>,
+    journal: Journal,
+}
+
+impl DerivationStore for StagedDerivationStore {
+    fn insert(&self, record: DerivationRecord) -> Result<(), StorageError> {
+        let mut staged = self.staged.write().map_err(|_| lock_err("derivation.insert"))?;
+        if staged.contains_key(&record.id) || self.base.get(record.id)?.is_some() {
+            return Err(StorageError::DuplicateKey(record.id.to_string()));
+        }
+        staged.insert(record.id, record.clone());
+        stage(&self.journal, StagedWrite::InsertDerivation(record))
+    }
+
+    fn get(&self, id: DerivationId) -> Result<Option<DerivationRecord>, StorageError> {
+        let staged = self.staged.read().map_err(|_| lock_err("derivation.get"))?;
+        match staged.get(&id) {
+            Some(r) => Ok(Some(r.clone())),
+            None => self.base.get(id),
+        }
+    }
+
+    fn update(&self, _record: DerivationRecord) -> Result<(), StorageError> {
+        Err(StorageError::BackendError(
+            "derivation.update is not supported inside a transaction".to_string(),
+        ))
+    }
+
+    fn find_by_premise(&self, premise_id: BeliefId) -> Result<Vec<DerivationRecord>, StorageError> {
+        let mut out = self.base.find_by_premise(premise_id)?;
+        let staged = self.staged.read().map_err(|_| lock_err("derivation.find_by_premise"))?;
+        out.extend(
+            staged
+                .values()
+                .filter(|r| r.premise_ids.contains(&premise_id))
+                .cloned(),
+        );
+        Ok(out)
+    }
+
+    fn find_by_derived_belief(
+        &self,
+        derived_belief_id: BeliefId,
+    ) -> Result<Vec<DerivationRecord>, StorageError> {
+        let mut out = self.base.find_by_derived_belief(derived_belief_id)?;
+        let staged = self
+            .staged
+            .read()
+            .map_err(|_| lock_err("derivation.find_by_derived_belief"))?;
+        out.extend(
+            staged
+                .values()
+                .filter(|r| r.derived_belief_id == Some(derived_belief_id))
+                .cloned(),
+        );
+        Ok(out)
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let mut stats = self.base.stats()?;
+        stats.records += self.staged.read().map_err(|_| lock_err("derivation.stats"))?.len();
+        Ok(stats)
+    }
+}
+
+/// Idempotency overlay, so a key reused later in the same transaction is deduplicated.
+struct StagedIdempotencyStore {
+    base: Arc<dyn IdempotencyStore>,
+    staged: RwLock<HashMap<(Option<String>, String), BeliefId>>,
+    journal: Journal,
+}
+
+impl IdempotencyStore for StagedIdempotencyStore {
+    fn get(&self, namespace: Option<&str>, key: &str) -> Result<Option<BeliefId>, StorageError> {
+        let staged = self.staged.read().map_err(|_| lock_err("idempotency.get"))?;
+        match staged.get(&(namespace.map(str::to_string), key.to_string())) {
+            Some(id) => Ok(Some(*id)),
+            None => self.base.get(namespace, key),
+        }
+    }
+
+    /// Operations of one transaction run in order, so staged keys need no in-flight claim;
+    /// the base store sees the key when the transaction commits.
+    fn reserve(&self, namespace: Option<&str>, key: &str) -> Result<IdempotencyReservation, StorageError> {
+        Ok(match self.get(namespace, key)? {
+            Some(id) => IdempotencyReservation::Existing(id),
+            None => IdempotencyReservation::Reserved,
+        })
+    }
+
+    fn release(&self, _namespace: Option<&str>, _key: &str) -> Result<(), StorageError> {
+        Ok(())
+    }
+
+    fn record(&self, namespace: Option<&str>, key: &str, belief_id: BeliefId) -> Result<(), StorageError> {
+        let namespace = namespace.map(str::to_string);
+        self.staged
+            .write()
+            .map_err(|_| lock_err("idempotency.record"))?
+            .insert((namespace.clone(), key.to_string()), belief_id);
+        stage(
+            &self.journal,
+            StagedWrite::RecordIdempotencyKey(namespace, key.to_string(), belief_id),
+        )
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let mut stats = self.base.stats()?;
+        stats.records += self.staged.read().map_err(|_| lock_err("idempotency.stats"))?.len();
+        Ok(stats)
+    }
+}
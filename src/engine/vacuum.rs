@@ -0,0 +1,95 @@
+//! Vacuum of beliefs orphaned by entity deletion.
+//!
+//! Deleting an entity leaves its beliefs behind, naming a subject that no longer resolves.
+//! RESOLVE by entity fails for such a subject, but scans over a namespace (compound and
+//! semantic RESOLVE) still return its beliefs. [`KyroEngine::find_orphaned_beliefs`] lists
+//! them and [`KyroEngine::vacuum_orphans`] withdraws or removes them.
+
+use crate::belief::Belief;
+use crate::error::KyroResult;
+use crate::ir::{KyroIR, Operation, RetractPayload};
+use crate::source::Source;
+
+use super::KyroEngine;
+
+/// How [`KyroEngine::vacuum_orphans`] disposes of orphaned beliefs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VacuumMode {
+    /// Retract every current orphan, keeping the history queryable `as_of`.
+    ///
+    /// The retractions are orphans too; a later [`Delete`](Self::Delete) removes them.
+    Retract {
+        /// Source recorded on each retraction.
+        authorized_by: Source,
+    },
+    /// Permanently delete every orphan, superseded versions included.
+    Delete,
+}
+
+/// Counts reported by [`KyroEngine::vacuum_orphans`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VacuumReport {
+    /// Orphaned beliefs found.
+    pub orphaned: usize,
+    /// Orphans retracted.
+    pub retracted: usize,
+    /// Orphans deleted.
+    pub deleted: usize,
+}
+
+impl KyroEngine {
+    /// Every belief whose subject no longer resolves to an entity.
+    ///
+    /// Beliefs about a merged-away entity are not orphans: the subject resolves to the entity
+    /// it merged into. Scans every subject in the belief store.
+    pub fn find_orphaned_beliefs(&self) -> KyroResult<Vec<Belief>> {
+        let mut orphans = Vec::new();
+        for subject in self.beliefs.subjects().map_err(Self::storage_err)? {
+            if self.entities.get(subject).map_err(Self::storage_err)?.is_none() {
+                orphans.extend(self.beliefs.find_by_entity(subject).map_err(Self::storage_err)?);
+            }
+        }
+        Ok(orphans)
+    }
+
+    /// Retract or delete the beliefs [`find_orphaned_beliefs`](Self::find_orphaned_beliefs)
+    /// reports.
+    ///
+    /// Retracting skips superseded orphans and earlier retractions, so it can be repeated.
+    /// Each retraction runs as a RETRACT operation, so an operation log records it; deletions
+    /// are not logged.
+    /// Unlike [`prune`](Self::prune), deleting does not spare beliefs that conflicts or
+    /// derivations reference; those references are left dangling. Persistent stores drop
+    /// deleted beliefs from the segment written by the next compaction.
+    pub fn vacuum_orphans(&self, mode: VacuumMode) -> KyroResult<VacuumReport> {
+        let orphans = self.find_orphaned_beliefs()?;
+        let mut report = VacuumReport {
+            orphaned: orphans.len(),
+            ..VacuumReport::default()
+        };
+        match mode {
+            VacuumMode::Retract { authorized_by } => {
+                for belief in orphans {
+                    if belief.superseded_by.is_some() || belief.retraction {
+                        continue;
+                    }
+                    // Through `execute`, so an operation log records each retraction.
+                    self.execute(KyroIR::new(Operation::Retract(RetractPayload {
+                        belief_id: belief.id,
+                        reason: Some("subject entity no longer exists".to_string()),
+                        authorized_by: authorized_by.clone(),
+                        namespace: belief.namespace.clone(),
+                    })))?;
+                    report.retracted += 1;
+                }
+            }
+            VacuumMode::Delete => {
+                for belief in orphans {
+                    self.beliefs.delete(belief.id).map_err(Self::storage_err)?;
+                    report.deleted += 1;
+                }
+            }
+        }
+        Ok(report)
+    }
+}
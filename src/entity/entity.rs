@@ -31,7 +31,7 @@ impl EntityId {
     /// Creates a new random entity ID.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(crate::id::next_id())
     }
 
     /// Creates an entity ID from an existing UUID.
@@ -230,6 +230,12 @@ pub struct Entity {
     /// Arbitrary metadata key-values.
     #[serde(default)]
     pub metadata: serde_json::Value,
+
+    /// Tenant namespace; `None` is the default namespace.
+    ///
+    /// Name, fuzzy and embedding lookups only see entities of the requested namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
 }
 
 impl Entity {
@@ -256,6 +262,7 @@ impl Entity {
             embedding: None,
             version: 1,
             metadata: serde_json::Value::Null,
+            namespace: None,
         }
     }
 
@@ -276,9 +283,17 @@ impl Entity {
             embedding: None,
             version: 1,
             metadata: serde_json::Value::Null,
+            namespace: None,
         }
     }
 
+    /// Places the entity in a tenant namespace.
+    #[must_use]
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
     /// Updates the canonical name for this entity.
     ///
     /// If the name changes, this increments the entity version and updates `updated_at`.
@@ -304,7 +319,7 @@ impl Entity {
         let is_same = self
             .embedding
             .as_ref()
-            .map_or(false, |current| current.len() == embedding.len()
+            .is_some_and(|current| current.len() == embedding.len()
                 && current
                     .iter()
                     .zip(&embedding)
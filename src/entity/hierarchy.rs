@@ -0,0 +1,120 @@
+//! Subtype relations between entity types.
+//!
+//! [`EntityType`] itself is flat. An [`EntityTypeHierarchy`] records which types are
+//! subtypes of which, so a query for `Organization` can also match a custom `Company`
+//! type. Each type has at most one parent, which keeps the hierarchy a forest.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ValidationError;
+
+use super::EntityType;
+
+/// Parent→children relations between entity types.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntityTypeHierarchy {
+    children: HashMap<EntityType, HashSet<EntityType>>,
+    parents: HashMap<EntityType, EntityType>,
+}
+
+impl EntityTypeHierarchy {
+    /// An empty hierarchy, in which every type only matches itself.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare `child` a subtype of `parent`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidField` if `child` already has a parent, or if `parent` is `child`
+    /// or one of its subtypes.
+    pub fn with_subtype(mut self, parent: EntityType, child: EntityType) -> Result<Self, ValidationError> {
+        if let Some(existing) = self.parents.get(&child) {
+            return Err(invalid(format!("{child} is already a subtype of {existing}")));
+        }
+        if self.is_subtype_of(&parent, &child) {
+            return Err(invalid(format!("making {child} a subtype of {parent} would form a cycle")));
+        }
+        self.children.entry(parent.clone()).or_default().insert(child.clone());
+        self.parents.insert(child, parent);
+        Ok(self)
+    }
+
+    /// The direct parent of `entity_type`, if it has one.
+    #[must_use]
+    pub fn parent(&self, entity_type: &EntityType) -> Option<&EntityType> {
+        self.parents.get(entity_type)
+    }
+
+    /// Returns `true` if `entity_type` is `ancestor` or a (transitive) subtype of it.
+    #[must_use]
+    pub fn is_subtype_of(&self, entity_type: &EntityType, ancestor: &EntityType) -> bool {
+        let mut current = Some(entity_type);
+        while let Some(t) = current {
+            if t == ancestor {
+                return true;
+            }
+            current = self.parents.get(t);
+        }
+        false
+    }
+
+    /// `entity_type` followed by all of its (transitive) subtypes.
+    #[must_use]
+    pub fn subtypes(&self, entity_type: &EntityType) -> Vec<EntityType> {
+        let mut out = vec![entity_type.clone()];
+        let mut next = 0;
+        while let Some(t) = out.get(next) {
+            if let Some(children) = self.children.get(t) {
+                let mut children: Vec<_> = children.iter().cloned().collect();
+                children.sort_by_key(ToString::to_string);
+                out.extend(children);
+            }
+            next += 1;
+        }
+        out
+    }
+}
+
+fn invalid(reason: String) -> ValidationError {
+    ValidationError::InvalidField {
+        field: "type_hierarchy".to_string(),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(name: &str) -> EntityType {
+        EntityType::Custom(name.to_string())
+    }
+
+    #[test]
+    fn subtypes_are_transitive_and_cycles_are_rejected() {
+        let hierarchy = EntityTypeHierarchy::new()
+            .with_subtype(EntityType::Organization, custom("company"))
+            .unwrap()
+            .with_subtype(custom("company"), custom("startup"))
+            .unwrap();
+
+        assert_eq!(
+            hierarchy.subtypes(&EntityType::Organization),
+            vec![EntityType::Organization, custom("company"), custom("startup")]
+        );
+        assert!(hierarchy.is_subtype_of(&custom("startup"), &EntityType::Organization));
+        assert!(!hierarchy.is_subtype_of(&EntityType::Organization, &custom("startup")));
+        assert_eq!(hierarchy.parent(&custom("company")), Some(&EntityType::Organization));
+
+        assert!(hierarchy
+            .clone()
+            .with_subtype(custom("startup"), EntityType::Organization)
+            .is_err());
+        assert!(hierarchy
+            .with_subtype(EntityType::Concept, custom("company"))
+            .is_err());
+    }
+}
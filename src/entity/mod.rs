@@ -1,10 +1,13 @@
 //! Entity layer modules.
 //!
-//! This module groups entity, resolution, store, and versioning.
+//! This module groups entity, type hierarchy, resolution, store, and versioning.
 
 pub mod entity;
+pub mod hierarchy;
 pub mod resolution;
 pub mod store;
 pub mod versioning;
 
 pub use entity::{Entity, EntityId, EntityType};
+pub use hierarchy::EntityTypeHierarchy;
+pub use versioning::MergeProvenance;
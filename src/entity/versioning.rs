@@ -3,4 +3,103 @@
 //! Version history is provided by storage backends that implement
 //! `EntityStore::{get_at_version,list_versions}`.
 
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entity::{Entity, EntityId};
+
 pub use crate::storage::EntityStore;
+
+/// Record of what a merge contributed to the primary entity.
+///
+/// Merging folds the secondary's names, metadata, and embedding into the
+/// primary. Backends capture this record at merge time so that
+/// `EntityStore::unmerge` can strip exactly those contributions and restore
+/// the secondary from its preserved version history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeProvenance {
+    /// Entity that absorbed the secondary.
+    pub primary: EntityId,
+    /// Entity that was merged away.
+    pub secondary: EntityId,
+    /// Last version of the secondary before the merge.
+    pub secondary_version: u64,
+    /// Primary version produced by the merge.
+    pub merged_version: u64,
+    /// Aliases added to the primary by the merge.
+    pub added_aliases: Vec<String>,
+    /// Metadata keys added to the primary by the merge.
+    pub added_metadata_keys: Vec<String>,
+    /// Whether the primary had no metadata before the merge.
+    pub primary_metadata_was_null: bool,
+    /// Primary embedding before the merge.
+    pub primary_embedding: Option<Vec<f32>>,
+    /// Primary embedding produced by the merge.
+    pub merged_embedding: Option<Vec<f32>>,
+    /// When the merge happened.
+    pub merged_at: DateTime<Utc>,
+}
+
+impl MergeProvenance {
+    /// Capture the contributions of `secondary` given the primary before and after the merge.
+    #[must_use]
+    pub fn capture(before: &Entity, merged: &Entity, secondary: &Entity) -> Self {
+        let added_aliases = merged
+            .aliases
+            .iter()
+            .filter(|a| !before.aliases.iter().any(|b| b.eq_ignore_ascii_case(a)))
+            .cloned()
+            .collect();
+
+        let added_metadata_keys = match (&before.metadata, &merged.metadata) {
+            (serde_json::Value::Object(prev), serde_json::Value::Object(next)) => next
+                .keys()
+                .filter(|k| !prev.contains_key(*k))
+                .cloned()
+                .collect(),
+            (serde_json::Value::Null, serde_json::Value::Object(next)) => next.keys().cloned().collect(),
+            _ => Vec::new(),
+        };
+
+        Self {
+            primary: merged.id,
+            secondary: secondary.id,
+            secondary_version: secondary.version,
+            merged_version: merged.version,
+            added_aliases,
+            added_metadata_keys,
+            primary_metadata_was_null: before.metadata.is_null(),
+            primary_embedding: before.embedding.clone(),
+            merged_embedding: merged.embedding.clone(),
+            merged_at: merged.updated_at,
+        }
+    }
+
+    /// Strip the recorded contributions from `primary`.
+    ///
+    /// Aliases and metadata keys added by the merge are removed even if the primary changed
+    /// since. The pre-merge embedding is only restored when the primary still carries the
+    /// embedding produced by the merge; a later explicit update wins.
+    pub fn revert(&self, primary: &mut Entity) {
+        primary
+            .aliases
+            .retain(|a| !self.added_aliases.iter().any(|x| x.eq_ignore_ascii_case(a)));
+
+        match &mut primary.metadata {
+            serde_json::Value::Object(map) => {
+                for key in &self.added_metadata_keys {
+                    map.remove(key);
+                }
+                if self.primary_metadata_was_null && map.is_empty() {
+                    primary.metadata = serde_json::Value::Null;
+                }
+            }
+            _ if self.primary_metadata_was_null => primary.metadata = serde_json::Value::Null,
+            _ => {}
+        }
+
+        if primary.embedding == self.merged_embedding {
+            primary.embedding = self.primary_embedding.clone();
+        }
+    }
+}
@@ -3,13 +3,144 @@
 //! All errors in KyroQL are strongly typed using thiserror.
 //! This enables pattern matching on specific error conditions
 //! and provides clear error messages.
+//!
+//! Every error also carries a stable [`ErrorCode`]. Errors serialize as
+//! `{"error_code": "ENTITY_NOT_FOUND", "message": "..."}` so clients can branch
+//! on the code without matching message text.
+
+use std::fmt;
 
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use thiserror::Error;
 use chrono::{DateTime, Utc};
 
 use crate::confidence::BeliefId;
 use crate::conflict::ConflictId;
 use crate::entity::EntityId;
+use crate::monitor::MonitorEventError;
+
+/// Stable, machine-readable identifier of an error condition.
+///
+/// Codes serialize as `SCREAMING_SNAKE_CASE` strings and never change once published;
+/// new error variants get new codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(missing_docs)]
+pub enum ErrorCode {
+    ConfidenceOutOfRange,
+    InvalidTimeRange,
+    EmptyEntityName,
+    EmptyPredicate,
+    MissingField,
+    FieldTooLong,
+    InvalidEmbeddingDimension,
+    InvalidPatternRule,
+    InvalidConflictResolutionPolicy,
+    InvalidSimulationConstraints,
+    InvalidField,
+    InvalidTrigger,
+    ValueCoercionFailed,
+    MultipleValidationErrors,
+    EntityNotFound,
+    BeliefNotFound,
+    SimulationNotFound,
+    SimulationLimitExceeded,
+    SimulationCommitNotAllowed,
+    Timeout,
+    MonitorExpired,
+    MonitorOverflow,
+    MonitorNotFound,
+    Disconnected,
+    QueueFull,
+    InvalidOperation,
+    NotImplemented,
+    StorageError,
+    IndexError,
+    ConflictResolutionFailed,
+    ConflictsDetected,
+    PatternViolation,
+    InvalidDerivation,
+    SimulationPartialCommit,
+    TransactionAborted,
+    ConnectionFailed,
+    SerializationFailed,
+    DeserializationFailed,
+    ServerError,
+    Internal,
+}
+
+impl ErrorCode {
+    /// The code as it appears on the wire.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ConfidenceOutOfRange => "CONFIDENCE_OUT_OF_RANGE",
+            Self::InvalidTimeRange => "INVALID_TIME_RANGE",
+            Self::EmptyEntityName => "EMPTY_ENTITY_NAME",
+            Self::EmptyPredicate => "EMPTY_PREDICATE",
+            Self::MissingField => "MISSING_FIELD",
+            Self::FieldTooLong => "FIELD_TOO_LONG",
+            Self::InvalidEmbeddingDimension => "INVALID_EMBEDDING_DIMENSION",
+            Self::InvalidPatternRule => "INVALID_PATTERN_RULE",
+            Self::InvalidConflictResolutionPolicy => "INVALID_CONFLICT_RESOLUTION_POLICY",
+            Self::InvalidSimulationConstraints => "INVALID_SIMULATION_CONSTRAINTS",
+            Self::InvalidField => "INVALID_FIELD",
+            Self::InvalidTrigger => "INVALID_TRIGGER",
+            Self::ValueCoercionFailed => "VALUE_COERCION_FAILED",
+            Self::MultipleValidationErrors => "MULTIPLE_VALIDATION_ERRORS",
+            Self::EntityNotFound => "ENTITY_NOT_FOUND",
+            Self::BeliefNotFound => "BELIEF_NOT_FOUND",
+            Self::SimulationNotFound => "SIMULATION_NOT_FOUND",
+            Self::SimulationLimitExceeded => "SIMULATION_LIMIT_EXCEEDED",
+            Self::SimulationCommitNotAllowed => "SIMULATION_COMMIT_NOT_ALLOWED",
+            Self::Timeout => "TIMEOUT",
+            Self::MonitorExpired => "MONITOR_EXPIRED",
+            Self::MonitorOverflow => "MONITOR_OVERFLOW",
+            Self::MonitorNotFound => "MONITOR_NOT_FOUND",
+            Self::Disconnected => "DISCONNECTED",
+            Self::QueueFull => "QUEUE_FULL",
+            Self::InvalidOperation => "INVALID_OPERATION",
+            Self::NotImplemented => "NOT_IMPLEMENTED",
+            Self::StorageError => "STORAGE_ERROR",
+            Self::IndexError => "INDEX_ERROR",
+            Self::ConflictResolutionFailed => "CONFLICT_RESOLUTION_FAILED",
+            Self::ConflictsDetected => "CONFLICTS_DETECTED",
+            Self::PatternViolation => "PATTERN_VIOLATION",
+            Self::InvalidDerivation => "INVALID_DERIVATION",
+            Self::SimulationPartialCommit => "SIMULATION_PARTIAL_COMMIT",
+            Self::TransactionAborted => "TRANSACTION_ABORTED",
+            Self::ConnectionFailed => "CONNECTION_FAILED",
+            Self::SerializationFailed => "SERIALIZATION_FAILED",
+            Self::DeserializationFailed => "DESERIALIZATION_FAILED",
+            Self::ServerError => "SERVER_ERROR",
+            Self::Internal => "INTERNAL",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Serialize an error as its code plus its human-readable message, and the individual
+/// failures (`errors`) of a [`ValidationError::Multiple`].
+fn serialize_error<S: Serializer>(
+    code: ErrorCode,
+    message: &impl fmt::Display,
+    errors: &[ValidationError],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Error", if errors.is_empty() { 2 } else { 3 })?;
+    state.serialize_field("error_code", &code)?;
+    state.serialize_field("message", &message.to_string())?;
+    if !errors.is_empty() {
+        state.serialize_field("errors", errors)?;
+    }
+    state.end()
+}
 
 /// Validation errors that occur during input validation.
 #[derive(Debug, Error)]
@@ -92,6 +223,76 @@ pub enum ValidationError {
         /// Reason the field is invalid.
         reason: String,
     },
+
+    /// MONITOR trigger cannot be evaluated, e.g. its regex does not compile.
+    #[error("Invalid trigger: {0}")]
+    InvalidTrigger(Box<MonitorEventError>),
+
+    /// Value cannot be coerced to the requested type.
+    #[error("Cannot coerce {found} value to {expected}")]
+    ValueCoercionFailed {
+        /// Requested type.
+        expected: String,
+        /// Type of the value that was supplied.
+        found: String,
+    },
+
+    /// Several independent failures, reported together, e.g. one per invalid operation of
+    /// a TRANSACTION, in operation order.
+    #[error("{} validation errors: {}", .0.len(), join_messages(.0))]
+    Multiple(Vec<ValidationError>),
+}
+
+fn join_messages(errors: &[ValidationError]) -> String {
+    errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
+impl ValidationError {
+    /// Stable code identifying this error.
+    #[must_use]
+    pub const fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::ConfidenceOutOfRange { .. } => ErrorCode::ConfidenceOutOfRange,
+            Self::InvalidTimeRange { .. } => ErrorCode::InvalidTimeRange,
+            Self::EmptyEntityName => ErrorCode::EmptyEntityName,
+            Self::EmptyPredicate => ErrorCode::EmptyPredicate,
+            Self::MissingField { .. } => ErrorCode::MissingField,
+            Self::FieldTooLong { .. } => ErrorCode::FieldTooLong,
+            Self::InvalidEmbeddingDimension { .. } => ErrorCode::InvalidEmbeddingDimension,
+            Self::InvalidPatternRule { .. } => ErrorCode::InvalidPatternRule,
+            Self::InvalidConflictResolutionPolicy { .. } => ErrorCode::InvalidConflictResolutionPolicy,
+            Self::InvalidSimulationConstraints { .. } => ErrorCode::InvalidSimulationConstraints,
+            Self::InvalidField { .. } => ErrorCode::InvalidField,
+            Self::InvalidTrigger(_) => ErrorCode::InvalidTrigger,
+            Self::ValueCoercionFailed { .. } => ErrorCode::ValueCoercionFailed,
+            Self::Multiple(_) => ErrorCode::MultipleValidationErrors,
+        }
+    }
+
+    /// Combine `errors` into one: `None` if there are none, the error itself if there is
+    /// one, and [`Multiple`](Self::Multiple) otherwise.
+    #[must_use]
+    pub fn combine(mut errors: Vec<ValidationError>) -> Option<Self> {
+        match errors.len() {
+            0 | 1 => errors.pop(),
+            _ => Some(Self::Multiple(errors)),
+        }
+    }
+
+    /// The individual failures of a [`Multiple`](Self::Multiple) error; empty otherwise.
+    #[must_use]
+    pub fn errors(&self) -> &[ValidationError] {
+        match self {
+            Self::Multiple(errors) => errors,
+            _ => &[],
+        }
+    }
+}
+
+impl Serialize for ValidationError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_error(self.error_code(), self, self.errors(), serializer)
+    }
 }
 
 /// Execution errors that occur during operation execution.
@@ -143,6 +344,32 @@ pub enum ExecutionError {
         duration_ms: u64,
     },
 
+    /// A monitor subscription reached its `expires_at` and was closed by the dispatcher.
+    #[error("Monitor subscription {subscription_id} expired at {expired_at}")]
+    MonitorExpired {
+        /// Expired subscription ID.
+        subscription_id: String,
+        /// The subscription's `expires_at`.
+        expired_at: DateTime<Utc>,
+    },
+
+    /// A monitor subscriber fell behind and was disconnected by the dispatcher.
+    #[error("Monitor subscription {subscription_id} disconnected after dropping {dropped_events} events")]
+    MonitorOverflow {
+        /// Disconnected subscription ID.
+        subscription_id: String,
+        /// Events this subscription lost to a full buffer.
+        dropped_events: u64,
+    },
+
+    /// A monitor subscription cannot be resumed: it never existed, was unsubscribed or
+    /// expired, or was detached for longer than its resume window.
+    #[error("Monitor subscription {subscription_id} not found")]
+    MonitorNotFound {
+        /// Unknown subscription ID.
+        subscription_id: String,
+    },
+
     /// Runtime worker pool disconnected before producing a reply.
     #[error("Runtime worker pool disconnected for {path} path")]
     Disconnected {
@@ -235,6 +462,51 @@ pub enum ExecutionError {
         /// Underlying error.
         cause: Box<KyroError>,
     },
+
+    /// An operation inside a TRANSACTION failed, so none of its writes were applied.
+    #[error("Transaction aborted at operation {index}: {cause}")]
+    TransactionAborted {
+        /// Position of the failed operation in the transaction.
+        index: usize,
+        /// Underlying error.
+        cause: Box<KyroError>,
+    },
+}
+
+impl ExecutionError {
+    /// Stable code identifying this error.
+    #[must_use]
+    pub const fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::EntityNotFound { .. } => ErrorCode::EntityNotFound,
+            Self::BeliefNotFound { .. } => ErrorCode::BeliefNotFound,
+            Self::SimulationNotFound { .. } => ErrorCode::SimulationNotFound,
+            Self::SimulationLimitExceeded { .. } => ErrorCode::SimulationLimitExceeded,
+            Self::SimulationCommitNotAllowed { .. } => ErrorCode::SimulationCommitNotAllowed,
+            Self::Timeout { .. } => ErrorCode::Timeout,
+            Self::MonitorExpired { .. } => ErrorCode::MonitorExpired,
+            Self::MonitorOverflow { .. } => ErrorCode::MonitorOverflow,
+            Self::MonitorNotFound { .. } => ErrorCode::MonitorNotFound,
+            Self::Disconnected { .. } => ErrorCode::Disconnected,
+            Self::QueueFull { .. } => ErrorCode::QueueFull,
+            Self::InvalidOperation { .. } => ErrorCode::InvalidOperation,
+            Self::NotImplemented { .. } => ErrorCode::NotImplemented,
+            Self::Storage { .. } => ErrorCode::StorageError,
+            Self::Index { .. } => ErrorCode::IndexError,
+            Self::ConflictResolutionFailed { .. } => ErrorCode::ConflictResolutionFailed,
+            Self::ConflictsDetected { .. } => ErrorCode::ConflictsDetected,
+            Self::PatternViolation { .. } => ErrorCode::PatternViolation,
+            Self::InvalidDerivation { .. } => ErrorCode::InvalidDerivation,
+            Self::SimulationPartialCommit { .. } => ErrorCode::SimulationPartialCommit,
+            Self::TransactionAborted { .. } => ErrorCode::TransactionAborted,
+        }
+    }
+}
+
+impl Serialize for ExecutionError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_error(self.error_code(), self, &[], serializer)
+    }
 }
 
 /// Transport errors for client-server communication.
@@ -271,6 +543,25 @@ pub enum TransportError {
     },
 }
 
+impl TransportError {
+    /// Stable code identifying this error.
+    #[must_use]
+    pub const fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::ConnectionFailed { .. } => ErrorCode::ConnectionFailed,
+            Self::SerializationFailed { .. } => ErrorCode::SerializationFailed,
+            Self::DeserializationFailed { .. } => ErrorCode::DeserializationFailed,
+            Self::ServerError { .. } => ErrorCode::ServerError,
+        }
+    }
+}
+
+impl Serialize for TransportError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_error(self.error_code(), self, &[], serializer)
+    }
+}
+
 /// Top-level error type for KyroQL.
 ///
 /// This enum encompasses all possible errors that can occur
@@ -306,6 +597,17 @@ impl KyroError {
         }
     }
 
+    /// Stable code of the underlying error.
+    #[must_use]
+    pub const fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::Validation(e) => e.error_code(),
+            Self::Execution(e) => e.error_code(),
+            Self::Transport(e) => e.error_code(),
+            Self::Internal { .. } => ErrorCode::Internal,
+        }
+    }
+
     /// Returns true if this is a validation error.
     #[must_use]
     pub const fn is_validation(&self) -> bool {
@@ -346,6 +648,16 @@ impl KyroError {
     }
 }
 
+impl Serialize for KyroError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let errors = match self {
+            Self::Validation(v) => v.errors(),
+            _ => &[],
+        };
+        serialize_error(self.error_code(), self, errors, serializer)
+    }
+}
+
 /// Result type alias for KyroQL operations.
 pub type KyroResult<T> = Result<T, KyroError>;
 
@@ -459,4 +771,126 @@ mod tests {
         .into();
         assert!(err3.is_retryable());
     }
+
+    #[test]
+    fn every_error_variant_serializes_a_stable_code() {
+        let now = Utc::now();
+        let text = || "x".to_string();
+        let cases: Vec<(KyroError, &str)> = vec![
+            (ValidationError::ConfidenceOutOfRange { value: 2.0 }.into(), "CONFIDENCE_OUT_OF_RANGE"),
+            (ValidationError::InvalidTimeRange { from: now, to: now }.into(), "INVALID_TIME_RANGE"),
+            (ValidationError::EmptyEntityName.into(), "EMPTY_ENTITY_NAME"),
+            (ValidationError::EmptyPredicate.into(), "EMPTY_PREDICATE"),
+            (ValidationError::MissingField { field: text() }.into(), "MISSING_FIELD"),
+            (ValidationError::FieldTooLong { field: text(), max_length: 1 }.into(), "FIELD_TOO_LONG"),
+            (
+                ValidationError::InvalidEmbeddingDimension { actual: 1, expected: 2 }.into(),
+                "INVALID_EMBEDDING_DIMENSION",
+            ),
+            (ValidationError::InvalidPatternRule { reason: text() }.into(), "INVALID_PATTERN_RULE"),
+            (
+                ValidationError::InvalidConflictResolutionPolicy { reason: text() }.into(),
+                "INVALID_CONFLICT_RESOLUTION_POLICY",
+            ),
+            (
+                ValidationError::InvalidSimulationConstraints { reason: text() }.into(),
+                "INVALID_SIMULATION_CONSTRAINTS",
+            ),
+            (ValidationError::InvalidField { field: text(), reason: text() }.into(), "INVALID_FIELD"),
+            (
+                ValidationError::InvalidTrigger(Box::new(MonitorEventError::InvalidMatcher { reason: text() })).into(),
+                "INVALID_TRIGGER",
+            ),
+            (
+                ValidationError::ValueCoercionFailed { expected: text(), found: text() }.into(),
+                "VALUE_COERCION_FAILED",
+            ),
+            (
+                ValidationError::Multiple(vec![ValidationError::EmptyPredicate]).into(),
+                "MULTIPLE_VALIDATION_ERRORS",
+            ),
+            (ExecutionError::EntityNotFound { id: EntityId::new() }.into(), "ENTITY_NOT_FOUND"),
+            (ExecutionError::BeliefNotFound { id: BeliefId::new() }.into(), "BELIEF_NOT_FOUND"),
+            (ExecutionError::SimulationNotFound { id: text() }.into(), "SIMULATION_NOT_FOUND"),
+            (
+                ExecutionError::SimulationLimitExceeded { limit_type: text(), max_value: 1, actual_value: 2 }.into(),
+                "SIMULATION_LIMIT_EXCEEDED",
+            ),
+            (
+                ExecutionError::SimulationCommitNotAllowed { reason: text() }.into(),
+                "SIMULATION_COMMIT_NOT_ALLOWED",
+            ),
+            (ExecutionError::Timeout { duration_ms: 1 }.into(), "TIMEOUT"),
+            (
+                ExecutionError::MonitorExpired { subscription_id: text(), expired_at: now }.into(),
+                "MONITOR_EXPIRED",
+            ),
+            (
+                ExecutionError::MonitorOverflow { subscription_id: text(), dropped_events: 1 }.into(),
+                "MONITOR_OVERFLOW",
+            ),
+            (ExecutionError::MonitorNotFound { subscription_id: text() }.into(), "MONITOR_NOT_FOUND"),
+            (ExecutionError::Disconnected { path: text() }.into(), "DISCONNECTED"),
+            (ExecutionError::QueueFull { path: text(), capacity: 1 }.into(), "QUEUE_FULL"),
+            (
+                ExecutionError::InvalidOperation { expected: text(), actual: text() }.into(),
+                "INVALID_OPERATION",
+            ),
+            (ExecutionError::NotImplemented { operation: text() }.into(), "NOT_IMPLEMENTED"),
+            (ExecutionError::Storage { message: text() }.into(), "STORAGE_ERROR"),
+            (ExecutionError::Index { message: text() }.into(), "INDEX_ERROR"),
+            (
+                ExecutionError::ConflictResolutionFailed { reason: text() }.into(),
+                "CONFLICT_RESOLUTION_FAILED",
+            ),
+            (ExecutionError::ConflictsDetected { conflicts: vec![text()] }.into(), "CONFLICTS_DETECTED"),
+            (
+                ExecutionError::PatternViolation { pattern_name: text(), reason: text() }.into(),
+                "PATTERN_VIOLATION",
+            ),
+            (ExecutionError::InvalidDerivation { reason: text() }.into(), "INVALID_DERIVATION"),
+            (
+                ExecutionError::SimulationPartialCommit {
+                    committed_len: 0,
+                    committed: Vec::new(),
+                    conflict_ids: Vec::new(),
+                    failed_belief_id: BeliefId::new(),
+                    cause: Box::new(KyroError::internal("boom")),
+                }
+                .into(),
+                "SIMULATION_PARTIAL_COMMIT",
+            ),
+            (
+                ExecutionError::TransactionAborted {
+                    index: 1,
+                    cause: Box::new(KyroError::internal("boom")),
+                }
+                .into(),
+                "TRANSACTION_ABORTED",
+            ),
+            (TransportError::ConnectionFailed { message: text() }.into(), "CONNECTION_FAILED"),
+            (TransportError::SerializationFailed { message: text() }.into(), "SERIALIZATION_FAILED"),
+            (TransportError::DeserializationFailed { message: text() }.into(), "DESERIALIZATION_FAILED"),
+            (TransportError::ServerError { code: 500, message: text() }.into(), "SERVER_ERROR"),
+            (KyroError::internal("boom"), "INTERNAL"),
+        ];
+
+        for (err, code) in cases {
+            let json = serde_json::to_value(&err).unwrap();
+            assert_eq!(json["error_code"], code, "{err}");
+            assert_eq!(json["message"], err.to_string());
+            assert_eq!(err.error_code().as_str(), code);
+            assert_eq!(err.error_code().to_string(), code);
+            let parsed: ErrorCode = serde_json::from_value(json["error_code"].clone()).unwrap();
+            assert_eq!(parsed, err.error_code());
+        }
+    }
+
+    #[test]
+    fn inner_errors_serialize_the_same_code_as_the_wrapper() {
+        let err = ExecutionError::EntityNotFound { id: EntityId::new() };
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["error_code"], "ENTITY_NOT_FOUND");
+        assert!(json["message"].as_str().unwrap().starts_with("Entity not found"));
+    }
 }
@@ -0,0 +1,133 @@
+//! Generation of the UUIDs behind KyroQL identifiers.
+//!
+//! Every `*Id::new` constructor, and the request ID assigned by `KyroIR::new`, draws from
+//! the calling thread's generator. By default that is [`RandomIdGenerator`] (UUID v4).
+//! Tests can [`install`] a [`SequentialIdGenerator`] to get predictable IDs instead of
+//! capturing the ones an operation returns.
+//!
+//! The generator is thread-local: IDs minted on other threads (e.g. by a server's worker
+//! pool) are unaffected by an installation on the test thread.
+
+use std::cell::RefCell;
+
+use uuid::Uuid;
+
+/// Source of UUIDs for new identifiers.
+pub trait IdGenerator {
+    /// Returns the next UUID.
+    fn next_id(&mut self) -> Uuid;
+}
+
+/// Random UUID v4 generator used in production.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn next_id(&mut self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Deterministic generator yielding `00000000-0000-0000-0000-000000000001`, `...0002`, ...
+///
+/// Counting starts at 1 so the nil UUID, which some IDs treat as "unset", is never produced.
+#[derive(Debug, Clone)]
+pub struct SequentialIdGenerator {
+    next: u128,
+}
+
+impl SequentialIdGenerator {
+    /// Creates a generator whose first UUID is `1`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    /// Creates a generator whose first UUID is `first`.
+    #[must_use]
+    pub const fn starting_at(first: u128) -> Self {
+        Self { next: first }
+    }
+}
+
+impl Default for SequentialIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&mut self) -> Uuid {
+        let id = Uuid::from_u128(self.next);
+        self.next = self.next.wrapping_add(1);
+        id
+    }
+}
+
+thread_local! {
+    static GENERATOR: RefCell<Option<Box<dyn IdGenerator>>> = const { RefCell::new(None) };
+}
+
+/// Returns a new UUID from the current thread's generator.
+#[must_use]
+pub fn next_id() -> Uuid {
+    GENERATOR.with(|cell| match cell.borrow_mut().as_mut() {
+        Some(generator) => generator.next_id(),
+        None => Uuid::new_v4(),
+    })
+}
+
+/// Installs `generator` for the current thread until the returned guard is dropped.
+///
+/// Dropping the guard restores whichever generator was installed before, so installations
+/// nest.
+#[must_use = "the generator is uninstalled when the guard is dropped"]
+pub fn install(generator: impl IdGenerator + 'static) -> IdGeneratorGuard {
+    let previous = GENERATOR.with(|cell| cell.borrow_mut().replace(Box::new(generator)));
+    IdGeneratorGuard { previous }
+}
+
+/// Restores the previously installed generator on drop. See [`install`].
+pub struct IdGeneratorGuard {
+    previous: Option<Box<dyn IdGenerator>>,
+}
+
+impl Drop for IdGeneratorGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        GENERATOR.with(|cell| *cell.borrow_mut() = previous);
+    }
+}
+
+impl std::fmt::Debug for IdGeneratorGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IdGeneratorGuard").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequential_generator_is_scoped_to_its_guard() {
+        let outer = install(SequentialIdGenerator::new());
+        assert_eq!(next_id(), Uuid::from_u128(1));
+        {
+            let _inner = install(SequentialIdGenerator::starting_at(100));
+            assert_eq!(next_id(), Uuid::from_u128(100));
+        }
+        assert_eq!(next_id(), Uuid::from_u128(2));
+        drop(outer);
+
+        assert_eq!(next_id().get_version_num(), 4);
+    }
+
+    #[test]
+    fn installation_does_not_leak_to_other_threads() {
+        let _guard = install(SequentialIdGenerator::new());
+        let other = std::thread::spawn(next_id).join().unwrap();
+        assert_eq!(other.get_version_num(), 4);
+        assert_eq!(next_id(), Uuid::from_u128(1));
+    }
+}
@@ -6,5 +6,5 @@
 mod policies;
 mod resolver;
 
-pub use policies::ConflictResolutionPolicy;
-pub use resolver::{apply_conflict_policy, PolicyDecision};
+pub use policies::{ConfidenceAggregation, ConflictResolutionPolicy, EvidenceCombination, TieBreak};
+pub use resolver::{apply_conflict_policy, apply_conflict_policy_with, PolicyDecision};
@@ -1,7 +1,9 @@
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
 
+use crate::belief::Belief;
 use crate::confidence::SourceId;
 use crate::error::ValidationError;
 
@@ -64,13 +66,14 @@ impl<'de> Deserialize<'de> for SourcePriorityList {
 ///
 /// Policies are intentionally *pure* (no I/O) so a RESOLVE result can be
 /// reproduced deterministically given the same belief set.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum ConflictResolutionPolicy {
     /// Select the newest claim (by `tx_time`).
     LatestWins,
 
     /// Select the claim with the highest belief confidence.
+    #[default]
     HighestConfidence,
 
     /// Select based on a source-trust priority list.
@@ -89,12 +92,6 @@ pub enum ConflictResolutionPolicy {
     ExplicitConflict,
 }
 
-impl Default for ConflictResolutionPolicy {
-    fn default() -> Self {
-        Self::HighestConfidence
-    }
-}
-
 impl ConflictResolutionPolicy {
     /// Create a validated `SourcePriority` policy.
     ///
@@ -125,3 +122,134 @@ impl ConflictResolutionPolicy {
         }
     }
 }
+
+/// How supporting and opposing evidence combine into a frame-level epistemic confidence.
+///
+/// Both sides are first aggregated with a noisy-OR over trusted confidences
+/// (`1 - Π(1 - c)`), so several weak sources can add up without ever exceeding 1.0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceCombination {
+    /// `support * (1 - counter)`: opposition scales support down proportionally.
+    #[default]
+    Discount,
+
+    /// `support - counter`: opposition is subtracted outright.
+    Subtract,
+
+    /// `support / (support + counter)`: relative share of the total evidence mass.
+    Ratio,
+}
+
+impl EvidenceCombination {
+    /// Aggregate independent confidences with a noisy-OR.
+    #[must_use]
+    pub fn aggregate(confidences: impl IntoIterator<Item = f32>) -> f32 {
+        let miss = confidences
+            .into_iter()
+            .fold(1.0f32, |acc, c| acc * (1.0 - c.clamp(0.0, 1.0)));
+        (1.0 - miss).clamp(0.0, 1.0)
+    }
+
+    /// Combine aggregate support and counter-evidence into a value in `[0, 1]`.
+    #[must_use]
+    pub fn combine(self, support: f32, counter: f32) -> f32 {
+        let support = support.clamp(0.0, 1.0);
+        let counter = counter.clamp(0.0, 1.0);
+        let out = match self {
+            Self::Discount => support * (1.0 - counter),
+            Self::Subtract => support - counter,
+            Self::Ratio => {
+                let total = support + counter;
+                if total > 0.0 {
+                    support / total
+                } else {
+                    0.0
+                }
+            }
+        };
+        out.clamp(0.0, 1.0)
+    }
+}
+
+/// How agreeing evidence sets the winning claim's confidence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfidenceAggregation {
+    /// The winning belief's own trusted confidence.
+    #[default]
+    Max,
+
+    /// Noisy-OR (`1 - Π(1 - c)`) over the beliefs agreeing with the winner.
+    ///
+    /// Sources are treated as the independent unit: each contributes only its most
+    /// confident agreeing belief, so one source repeating itself adds nothing.
+    NoisyOr,
+}
+
+/// Deterministic order between beliefs a policy scores equally.
+///
+/// Ties are common after data migrations or bulk imports where confidences and
+/// timestamps collide; picking the strategy explicitly keeps RESOLVE reproducible
+/// even when belief ids change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreak {
+    /// Newest `tx_time` first, then the lexically lower `BeliefId`.
+    #[default]
+    NewestTx,
+
+    /// Oldest `tx_time` first, then the lexically lower `BeliefId`.
+    OldestTx,
+
+    /// Most trusted source first, then as `NewestTx`.
+    HighestSourceTrust,
+
+    /// Lexically lower `BeliefId` first, ignoring time.
+    Lexical,
+}
+
+impl TieBreak {
+    /// Order two equally scored beliefs; `Ordering::Less` means `a` is preferred.
+    ///
+    /// `trust` maps a belief to its source trust weight and is only consulted by
+    /// `HighestSourceTrust`.
+    pub fn compare(self, a: &Belief, b: &Belief, trust: impl Fn(&Belief) -> f32) -> Ordering {
+        let lexical = || a.id.to_string().cmp(&b.id.to_string());
+        let newest = || b.tx_time.cmp(&a.tx_time).then_with(lexical);
+        match self {
+            Self::NewestTx => newest(),
+            Self::OldestTx => a.tx_time.cmp(&b.tx_time).then_with(lexical),
+            Self::HighestSourceTrust => trust(b).total_cmp(&trust(a)).then_with(newest),
+            Self::Lexical => lexical(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evidence_combination_penalizes_counter_evidence() {
+        for combo in [
+            EvidenceCombination::Discount,
+            EvidenceCombination::Subtract,
+            EvidenceCombination::Ratio,
+        ] {
+            let clean = combo.combine(0.9, 0.0);
+            let opposed = combo.combine(0.9, 0.85);
+            assert!(opposed < clean, "{combo:?} did not penalize opposition");
+            assert!((0.0..=1.0).contains(&opposed));
+        }
+        assert_eq!(EvidenceCombination::Subtract.combine(0.2, 0.9), 0.0);
+    }
+
+    #[test]
+    fn noisy_or_aggregation_is_bounded() {
+        assert_eq!(EvidenceCombination::aggregate([]), 0.0);
+        let agg = EvidenceCombination::aggregate([0.5, 0.5]);
+        assert!((agg - 0.75).abs() < 1e-6);
+        assert!(EvidenceCombination::aggregate([1.0, 0.3]) <= 1.0);
+    }
+}
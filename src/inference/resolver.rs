@@ -1,6 +1,8 @@
+use std::cmp::Ordering;
+
 use crate::belief::Belief;
 use crate::confidence::BeliefId;
-use crate::inference::ConflictResolutionPolicy;
+use crate::inference::{ConflictResolutionPolicy, TieBreak};
 
 /// Decision produced by applying a policy.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,27 +17,37 @@ pub enum PolicyDecision {
 /// Apply a conflict-resolution policy to a non-empty belief set.
 ///
 /// `beliefs` should already be filtered to the relevant scope (e.g. same
-/// `(entity, predicate)` and correct `as_of`).
+/// `(entity, predicate)` and correct `as_of`). Ties are broken by `TieBreak::default()`.
 #[must_use]
 pub fn apply_conflict_policy(policy: &ConflictResolutionPolicy, beliefs: &[Belief]) -> PolicyDecision {
+    apply_conflict_policy_with(policy, TieBreak::default(), beliefs)
+}
+
+/// Like [`apply_conflict_policy`], breaking ties between equally scored beliefs by
+/// `tie_break`.
+///
+/// There is no trust model here, so `TieBreak::HighestSourceTrust` weighs every source
+/// equally and falls back to its newest-first order.
+#[must_use]
+pub fn apply_conflict_policy_with(
+    policy: &ConflictResolutionPolicy,
+    tie_break: TieBreak,
+    beliefs: &[Belief],
+) -> PolicyDecision {
     if beliefs.is_empty() {
         return PolicyDecision::Unresolved;
     }
 
+    let preferred = |b: &Belief, best: &Belief| tie_break.compare(b, best, |_| 1.0) == Ordering::Less;
+
     match policy {
         ConflictResolutionPolicy::ExplicitConflict => PolicyDecision::Unresolved,
         ConflictResolutionPolicy::HighestConfidence => {
             let mut best = &beliefs[0];
             for b in &beliefs[1..] {
-                if b.confidence.value() > best.confidence.value() {
+                let (bc, cc) = (b.confidence.value(), best.confidence.value());
+                if bc > cc || (bc == cc && preferred(b, best)) {
                     best = b;
-                } else if b.confidence.value() == best.confidence.value() {
-                    // Deterministic tie-breaker: newest tx_time, then BeliefId.
-                    if b.tx_time > best.tx_time {
-                        best = b;
-                    } else if b.tx_time == best.tx_time && b.id.to_string() < best.id.to_string() {
-                        best = b;
-                    }
                 }
             }
             PolicyDecision::Selected(best.id)
@@ -43,9 +55,7 @@ pub fn apply_conflict_policy(policy: &ConflictResolutionPolicy, beliefs: &[Belie
         ConflictResolutionPolicy::LatestWins => {
             let mut best = &beliefs[0];
             for b in &beliefs[1..] {
-                if b.tx_time > best.tx_time {
-                    best = b;
-                } else if b.tx_time == best.tx_time && b.id.to_string() < best.id.to_string() {
+                if b.tx_time > best.tx_time || (b.tx_time == best.tx_time && preferred(b, best)) {
                     best = b;
                 }
             }
@@ -70,15 +80,10 @@ pub fn apply_conflict_policy(policy: &ConflictResolutionPolicy, beliefs: &[Belie
                     best = b;
                     best_rank = r;
                 } else if r == best_rank {
-                    // Tie-breaker: higher confidence, then newest tx_time, then BeliefId.
-                    if b.confidence.value() > best.confidence.value() {
+                    // Higher confidence first, then `tie_break`.
+                    let (bc, cc) = (b.confidence.value(), best.confidence.value());
+                    if bc > cc || (bc == cc && preferred(b, best)) {
                         best = b;
-                    } else if b.confidence.value() == best.confidence.value() {
-                        if b.tx_time > best.tx_time {
-                            best = b;
-                        } else if b.tx_time == best.tx_time && b.id.to_string() < best.id.to_string() {
-                            best = b;
-                        }
                     }
                 }
             }
@@ -112,9 +117,14 @@ mod tests {
             tx_time,
             reason: None,
             consistency_status: crate::belief::ConsistencyStatus::Verified,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         }
     }
 
@@ -135,6 +145,26 @@ mod tests {
         assert_eq!(decision, PolicyDecision::Selected(b2.id));
     }
 
+    #[test]
+    fn ties_follow_the_requested_tie_break() {
+        let now = Utc::now();
+        let older = belief_with(0.7, now, Source::agent("a", None::<String>));
+        let newer = belief_with(0.7, now + chrono::Duration::seconds(5), Source::agent("b", None::<String>));
+        let beliefs = [older.clone(), newer.clone()];
+        let policy = ConflictResolutionPolicy::HighestConfidence;
+
+        assert_eq!(apply_conflict_policy(&policy, &beliefs), PolicyDecision::Selected(newer.id));
+        assert_eq!(
+            apply_conflict_policy_with(&policy, TieBreak::OldestTx, &beliefs),
+            PolicyDecision::Selected(older.id)
+        );
+        let lexical = if older.id.to_string() < newer.id.to_string() { older.id } else { newer.id };
+        assert_eq!(
+            apply_conflict_policy_with(&policy, TieBreak::Lexical, &beliefs),
+            PolicyDecision::Selected(lexical)
+        );
+    }
+
     #[test]
     fn explicit_conflict_never_picks() {
         let b = belief_with(0.9, Utc::now(), Source::agent("a", None::<String>));
@@ -18,6 +18,16 @@ pub enum ConsistencyMode {
     /// Override existing conflicts. Use with extreme caution.
     /// This should only be used for administrative corrections.
     Force,
+
+    /// Last-writer-wins update: supersede every active belief for the same
+    /// (entity, predicate) with the new one, closing their valid time at the
+    /// assert's transaction time. Patterns are still checked, ignoring the
+    /// beliefs being replaced; any remaining conflict rejects the write.
+    Replace,
+
+    /// Advisory check: detect conflicts and report them in the response, but record
+    /// nothing. The belief is written as `Contested` with no conflict IDs.
+    Warn,
 }
 
 impl ConsistencyMode {
@@ -35,6 +45,16 @@ impl ConsistencyMode {
     pub const fn is_force(&self) -> bool {
         matches!(self, Self::Force)
     }
+
+    /// Returns `true` if this is `Replace` mode.
+    pub const fn is_replace(&self) -> bool {
+        matches!(self, Self::Replace)
+    }
+
+    /// Returns `true` if this is `Warn` mode.
+    pub const fn is_warn(&self) -> bool {
+        matches!(self, Self::Warn)
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +71,8 @@ mod tests {
         assert!(ConsistencyMode::Strict.is_strict());
         assert!(ConsistencyMode::Eventual.is_eventual());
         assert!(ConsistencyMode::Force.is_force());
+        assert!(ConsistencyMode::Replace.is_replace());
+        assert!(ConsistencyMode::Warn.is_warn());
     }
 
     #[test]
@@ -68,5 +90,11 @@ mod tests {
     fn test_deserialization() {
         let mode: ConsistencyMode = serde_json::from_str("\"eventual\"").unwrap();
         assert_eq!(mode, ConsistencyMode::Eventual);
+
+        let mode: ConsistencyMode = serde_json::from_str("\"replace\"").unwrap();
+        assert_eq!(mode, ConsistencyMode::Replace);
+
+        let mode: ConsistencyMode = serde_json::from_str("\"warn\"").unwrap();
+        assert_eq!(mode, ConsistencyMode::Warn);
     }
 }
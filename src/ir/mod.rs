@@ -13,9 +13,14 @@ mod validation;
 
 pub use consistency::ConsistencyMode;
 pub use operations::{
-    AssertPayload, DefinePatternPayload, DerivePayload, KyroIR, MonitorPayload, Operation,
-    ResolveMode, ResolvePayload, RetractPayload, SimulatePayload,
+    AggregateFunction, AssertPayload, CompoundCondition, DefinePatternPayload, DerivePayload, EntityResolutionConfig,
+    FeedbackPayload, KyroIR,
+    MonitorPayload, Operation, ResolveCompoundPayload, ResolveMode, ResolveMultiPayload, ResolvePayload,
+    RetractPayload, SimulatePayload, UpdatePatternPayload,
 };
 
 pub use serialization::{from_json, to_json_pretty};
-pub use validation::{MAX_EMBEDDING_DIM, MAX_TEXT_LEN};
+pub use validation::{
+    MAX_COMPOUND_CONDITIONS, MAX_EMBEDDING_DIM, MAX_NAMESPACE_LEN, MAX_RESOLVE_MULTI_ENTITIES, MAX_TEXT_LEN,
+    MAX_TRANSACTION_OPERATIONS, ValidationLimits,
+};
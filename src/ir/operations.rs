@@ -5,12 +5,14 @@ use serde::{Deserialize, Serialize};
 use serde_json;
 use uuid::Uuid;
 
-use crate::confidence::{BeliefId, Confidence};
+use crate::confidence::{BeliefId, Confidence, SourceId};
 use crate::entity::EntityId;
-use crate::inference::ConflictResolutionPolicy;
+use crate::inference::{ConfidenceAggregation, ConflictResolutionPolicy, EvidenceCombination, TieBreak};
+use crate::monitor::ValueMatcher;
 use crate::pattern::{PatternId, PatternRule};
 use crate::source::Source;
 use crate::time::TimeRange;
+use crate::trust::FeedbackOutcome;
 use crate::value::Value;
 
 use super::ConsistencyMode;
@@ -44,7 +46,7 @@ impl KyroIR {
     pub fn new(operation: Operation) -> Self {
         Self {
             version: Self::CURRENT_VERSION.to_string(),
-            request_id: Uuid::new_v4(),
+            request_id: crate::id::next_id(),
             timestamp: Utc::now(),
             operation,
         }
@@ -67,6 +69,12 @@ pub enum Operation {
     /// Resolve/query beliefs from the knowledge base.
     Resolve(ResolvePayload),
 
+    /// Find entities whose beliefs satisfy every one of several conditions.
+    ResolveCompound(ResolveCompoundPayload),
+
+    /// Resolve one predicate for each of several entities, e.g. to compare them.
+    ResolveMulti(ResolveMultiPayload),
+
     /// Create a simulation context for counterfactual reasoning.
     Simulate(SimulatePayload),
 
@@ -81,6 +89,49 @@ pub enum Operation {
 
     /// Define a new pattern/constraint.
     DefinePattern(DefinePatternPayload),
+
+    /// Change the rule, validity or other fields of an existing pattern.
+    UpdatePattern(UpdatePatternPayload),
+
+    /// Stop enforcing a pattern. The pattern is kept, marked inactive.
+    DeactivatePattern(PatternId),
+
+    /// Report active patterns on a predicate that no value can satisfy together.
+    CheckPatternSet(String),
+
+    /// Report whether a previously asserted belief turned out true.
+    Feedback(FeedbackPayload),
+
+    /// Run several operations as one unit: either every write they make lands, or none does.
+    ///
+    /// Operations run in order and see the writes of earlier ones. Nested transactions,
+    /// SIMULATE, MONITOR and FEEDBACK are not allowed inside.
+    Transaction(Vec<Operation>),
+}
+
+impl Operation {
+    /// Returns `true` if this operation can change stored state.
+    ///
+    /// SIMULATE and MONITOR only read; committing a simulation happens outside IR.
+    #[must_use]
+    pub fn writes(&self) -> bool {
+        match self {
+            Self::Resolve(_)
+            | Self::ResolveCompound(_)
+            | Self::ResolveMulti(_)
+            | Self::Simulate(_)
+            | Self::Monitor(_)
+            | Self::CheckPatternSet(_) => false,
+            Self::Assert(_)
+            | Self::Derive(_)
+            | Self::Retract(_)
+            | Self::DefinePattern(_)
+            | Self::UpdatePattern(_)
+            | Self::DeactivatePattern(_)
+            | Self::Feedback(_) => true,
+            Self::Transaction(operations) => operations.iter().any(Self::writes),
+        }
+    }
 }
 
 /// Payload for ASSERT operations.
@@ -111,6 +162,16 @@ pub struct AssertPayload {
     /// Optional pre-computed embedding for semantic search.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub embedding: Option<Vec<f32>>,
+
+    /// Optional client-chosen key that makes retries of this ASSERT safe.
+    ///
+    /// A repeated key returns the originally inserted belief instead of inserting again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+
+    /// Tenant namespace; must match the entity's namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
 }
 
 /// Payload for RESOLVE operations.
@@ -155,6 +216,17 @@ pub struct ResolvePayload {
     #[serde(default = "default_true")]
     pub include_gaps: bool,
 
+    /// Whether to report `BeliefFrame::confidence_interval` alongside the point confidence.
+    #[serde(default)]
+    pub include_confidence_interval: bool,
+
+    /// Predicates to try, in order, when `predicate` has no beliefs for the entity.
+    ///
+    /// The first one that yields beliefs answers and is recorded in
+    /// `BeliefFrame::answered_predicate`; gaps are only reported once every one is exhausted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub predicate_fallbacks: Vec<String>,
+
     /// Policy for resolving conflicts when multiple competing beliefs exist.
     ///
     /// If not provided, the engine uses its default policy.
@@ -170,12 +242,87 @@ pub struct ResolvePayload {
     /// If omitted and `query` is present, the engine may fall back to lexical matching.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub query_embedding: Option<Vec<f32>>,
+
+    /// Take the semantic path even without `query_embedding`, embedding `query` with the
+    /// engine's embedder (the one used for ASSERT) instead.
+    ///
+    /// The query is then only text to match, never resolved as an entity name.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub semantic: bool,
+
+    /// How supporting and counter-evidence combine into `BeliefFrame::epistemic_confidence`.
+    ///
+    /// If not provided, the engine uses `EvidenceCombination::default()`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence_combination: Option<EvidenceCombination>,
+
+    /// How agreeing beliefs combine into `best_supported_claim.epistemic_confidence`.
+    ///
+    /// If not provided, the engine uses `ConfidenceAggregation::default()` (`Max`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence_aggregation: Option<ConfidenceAggregation>,
+
+    /// Restrict the answer to beliefs asserting exactly this value.
+    ///
+    /// Beliefs with other values are reported as counter-evidence instead of
+    /// competing for the answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_filter: Option<Value>,
+
+    /// How to order beliefs that score equally under the conflict policy.
+    ///
+    /// If not provided, the engine uses `TieBreak::default()` (newest `tx_time`, then id).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tie_break: Option<TieBreak>,
+
+    /// Weight of embedding similarity versus trusted confidence when ranking semantic matches.
+    ///
+    /// Matches are ranked by `relevance_weight * similarity + (1 - relevance_weight) *
+    /// trusted_confidence`. `1.0` (the default) ranks purely by similarity; lower values let a
+    /// slightly less similar but better-supported belief outrank it. Must be within `[0.0, 1.0]`.
+    #[serde(default = "default_relevance_weight")]
+    pub relevance_weight: f32,
+
+    /// Minimum embedding similarity for a semantic match to count as evidence.
+    ///
+    /// Matches below the threshold are dropped before ranking; if none remain, the frame
+    /// reports an `InsufficientEvidence` gap instead of an answer. Must be within `[0.0, 1.0]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_relevance: Option<f32>,
+
+    /// Minimum number of beliefs that must back the winning value for it to be the answer.
+    ///
+    /// With fewer, the frame keeps its evidence but reports an `InsufficientEvidence` gap
+    /// instead of `best_supported_claim`. `0` and `1` impose no requirement.
+    #[serde(default)]
+    pub min_support: usize,
+
+    /// Only consider beliefs from these sources; empty means every source.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_sources: Vec<SourceId>,
+
+    /// Ignore beliefs from these sources, even as counter-evidence.
+    ///
+    /// Takes precedence over `include_sources`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_sources: Vec<SourceId>,
+
+    /// When to resolve `query` to an entity if `entity_id` is unset; `None` uses
+    /// [`EntityResolutionConfig::default`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_resolution: Option<EntityResolutionConfig>,
+
+    /// Tenant namespace to resolve in; `None` is the default namespace.
+    ///
+    /// Beliefs and entities of other namespaces are invisible to the query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
 }
 
 /// Routing hint for RESOLVE.
 ///
 /// - `Simple` is intended for Reflex execution (fast, bounded work).
-/// - `Aggregate` and `Temporal` are intended for Reflection execution.
+/// - `Aggregate`, `Temporal` and `History` are intended for Reflection execution.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ResolveMode {
@@ -183,11 +330,85 @@ pub enum ResolveMode {
     #[default]
     Simple,
 
-    /// Synthesis RESOLVE (evidence/counter-evidence, richer frame).
-    Aggregate,
+    /// Summarize the numeric values of every matching belief at `as_of` with `function`
+    /// instead of selecting one of them. The answer is a synthetic claim citing each
+    /// belief as supporting evidence. Needs an entity and predicate.
+    Aggregate {
+        /// How the values are combined.
+        function: AggregateFunction,
+    },
 
     /// Temporal RESOLVE (as-of, diffs, trajectories).
     Temporal,
+
+    /// Like `Simple`, plus every revision of the claim (superseded beliefs included) in
+    /// `BeliefFrame::history`. Needs an entity and predicate.
+    History,
+
+    /// Linearly interpolate a numeric series at `as_of` between the samples immediately
+    /// before and after it, instead of returning the last sample. A sample is a belief,
+    /// superseded or not, placed at the start of its valid time; retracted ones are skipped.
+    /// The claim's confidence shrinks the further `as_of` lies from the nearer sample, to
+    /// half at the midpoint. Without numeric samples on both sides this behaves like
+    /// `Simple`. Needs an entity and predicate.
+    Interpolate,
+}
+
+/// Summary computed by [`ResolveMode::Aggregate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateFunction {
+    /// Arithmetic mean.
+    Avg,
+    /// Smallest value.
+    Min,
+    /// Largest value.
+    Max,
+    /// Middle value; the mean of the two middle values for an even count.
+    Median,
+}
+
+impl AggregateFunction {
+    /// Lowercase name, as serialized.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Avg => "avg",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Median => "median",
+        }
+    }
+
+    /// Apply this function to `values`, or `None` if there are none.
+    #[must_use]
+    pub fn apply(self, values: &[f64]) -> Option<f64> {
+        if values.is_empty() {
+            return None;
+        }
+        let out = match self {
+            Self::Avg => values.iter().sum::<f64>() / values.len() as f64,
+            Self::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Self::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            Self::Median => {
+                let mut sorted = values.to_vec();
+                sorted.sort_by(f64::total_cmp);
+                let mid = sorted.len() / 2;
+                if sorted.len().is_multiple_of(2) {
+                    (sorted[mid - 1] + sorted[mid]) / 2.0
+                } else {
+                    sorted[mid]
+                }
+            }
+        };
+        Some(out)
+    }
+}
+
+impl std::fmt::Display for AggregateFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
 }
 
 // NOTE: IR equality is used primarily for tests/roundtrips/debug assertions.
@@ -250,6 +471,8 @@ impl PartialEq for AssertPayload {
             && self.valid_time == other.valid_time
             && self.consistency_mode == other.consistency_mode
             && opt_vec_f32_approx_eq(&self.embedding, &other.embedding)
+            && self.idempotency_key == other.idempotency_key
+            && self.namespace == other.namespace
     }
 }
 
@@ -264,9 +487,23 @@ impl PartialEq for ResolvePayload {
             && self.limit == other.limit
             && self.include_counter_evidence == other.include_counter_evidence
             && self.include_gaps == other.include_gaps
+            && self.include_confidence_interval == other.include_confidence_interval
+            && self.predicate_fallbacks == other.predicate_fallbacks
             && self.conflict_policy == other.conflict_policy
             && self.trust_domain == other.trust_domain
             && opt_vec_f32_approx_eq(&self.query_embedding, &other.query_embedding)
+            && self.semantic == other.semantic
+            && self.evidence_combination == other.evidence_combination
+            && self.confidence_aggregation == other.confidence_aggregation
+            && self.value_filter == other.value_filter
+            && self.tie_break == other.tie_break
+            && f32_approx_eq(self.relevance_weight, other.relevance_weight)
+            && opt_f32_approx_eq(&self.min_relevance, &other.min_relevance)
+            && self.min_support == other.min_support
+            && self.include_sources == other.include_sources
+            && self.exclude_sources == other.exclude_sources
+            && self.entity_resolution == other.entity_resolution
+            && self.namespace == other.namespace
     }
 }
 
@@ -278,6 +515,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_relevance_weight() -> f32 {
+    1.0
+}
+
 impl Default for ResolvePayload {
     fn default() -> Self {
         Self {
@@ -290,9 +531,167 @@ impl Default for ResolvePayload {
             limit: default_limit(),
             include_counter_evidence: false,
             include_gaps: true,
+            include_confidence_interval: false,
+            predicate_fallbacks: Vec::new(),
             conflict_policy: None,
             trust_domain: None,
             query_embedding: None,
+            semantic: false,
+            evidence_combination: None,
+            confidence_aggregation: None,
+            value_filter: None,
+            tie_break: None,
+            relevance_weight: default_relevance_weight(),
+            min_relevance: None,
+            min_support: 0,
+            include_sources: Vec::new(),
+            exclude_sources: Vec::new(),
+            entity_resolution: None,
+            namespace: None,
+        }
+    }
+}
+
+/// Gating of RESOLVE's auto-resolution of a `query` to the entity it names.
+///
+/// Only a query that looks like a name is looked up: non-empty, at most `max_query_len`
+/// bytes and `max_words` words, and without a `?` unless `allow_questions`. A unique exact
+/// canonical-name match wins; otherwise the fuzzy candidates scoring at least `min_score`
+/// are counted, and the best one is taken if there are at most `max_candidates`.
+///
+/// Fuzzy scores rank matches: 6 for a canonical-name prefix, 4 for a canonical-name
+/// substring or alias prefix, 2 for an alias substring and 1 for a near-miss typo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityResolutionConfig {
+    /// Longest query, in bytes, still treated as a name.
+    #[serde(default = "default_max_query_len")]
+    pub max_query_len: usize,
+
+    /// Most whitespace-separated words in a query still treated as a name.
+    #[serde(default = "default_max_words")]
+    pub max_words: usize,
+
+    /// Treat queries containing `?` as names too.
+    #[serde(default)]
+    pub allow_questions: bool,
+
+    /// Most fuzzy candidates to still pick the best of; `0` disables fuzzy resolution.
+    #[serde(default = "default_max_candidates")]
+    pub max_candidates: usize,
+
+    /// Lowest fuzzy score a candidate needs to count.
+    #[serde(default = "default_min_score")]
+    pub min_score: u8,
+}
+
+impl EntityResolutionConfig {
+    /// A config that never resolves a query to an entity.
+    #[must_use]
+    pub const fn disabled() -> Self {
+        Self {
+            max_query_len: 0,
+            max_words: 0,
+            allow_questions: false,
+            max_candidates: 0,
+            min_score: u8::MAX,
+        }
+    }
+}
+
+impl Default for EntityResolutionConfig {
+    /// Conservative gating: short, question-free queries of up to six words, resolved only
+    /// to a unique fuzzy candidate.
+    fn default() -> Self {
+        Self {
+            max_query_len: default_max_query_len(),
+            max_words: default_max_words(),
+            allow_questions: false,
+            max_candidates: default_max_candidates(),
+            min_score: default_min_score(),
+        }
+    }
+}
+
+fn default_max_query_len() -> usize {
+    80
+}
+
+fn default_max_words() -> usize {
+    6
+}
+
+fn default_max_candidates() -> usize {
+    1
+}
+
+fn default_min_score() -> u8 {
+    1
+}
+
+/// One condition of a compound RESOLVE: the entity's current value for `predicate` must
+/// satisfy `matcher`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompoundCondition {
+    /// Predicate whose value is tested.
+    pub predicate: String,
+
+    /// Test applied to the winning belief's value.
+    pub matcher: ValueMatcher,
+}
+
+/// Payload for compound RESOLVE operations.
+///
+/// Conditions are joined by AND. For each entity and condition predicate, the belief
+/// selected by the default conflict policy at `as_of` is tested against the matcher.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResolveCompoundPayload {
+    /// Conditions every returned entity must satisfy.
+    pub conditions: Vec<CompoundCondition>,
+
+    /// Evaluate as of a specific point in time (default: now).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_of: Option<DateTime<Utc>>,
+
+    /// Ignore beliefs below this confidence (0.0 to 1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_confidence: Option<f32>,
+
+    /// Maximum number of entities to return.
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+
+    /// Tenant namespace to search; `None` is the default namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+/// Payload for multi-entity RESOLVE operations.
+///
+/// Each entity is resolved as a single-entity RESOLVE of `predicate` with the shared
+/// `options`, so an unknown entity fails the whole operation. Every RESOLVE reads the same
+/// belief store snapshot when snapshot reads are on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResolveMultiPayload {
+    /// Entities to resolve `predicate` for. Must be distinct.
+    pub entity_ids: Vec<EntityId>,
+
+    /// Predicate resolved for every entity.
+    pub predicate: String,
+
+    /// Options shared by every per-entity RESOLVE. Their `entity_id` and `predicate` must
+    /// be unset.
+    #[serde(default)]
+    pub options: ResolvePayload,
+}
+
+impl ResolveMultiPayload {
+    /// The single-entity RESOLVE run for `entity_id`.
+    #[must_use]
+    pub fn resolve_for(&self, entity_id: EntityId) -> ResolvePayload {
+        ResolvePayload {
+            entity_id: Some(entity_id),
+            predicate: Some(self.predicate.clone()),
+            ..self.options.clone()
         }
     }
 }
@@ -363,6 +762,10 @@ pub struct MonitorPayload {
     /// Optional callback or notification configuration.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub callback: Option<Value>,
+
+    /// Tenant namespace; only changes to this namespace's beliefs are delivered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
 }
 
 /// Payload for DERIVE operations.
@@ -397,6 +800,10 @@ pub struct DerivePayload {
     /// Optional extensible metadata.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+
+    /// Tenant namespace; must match the namespace of every belief involved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
 }
 
 impl PartialEq for DerivePayload {
@@ -408,6 +815,7 @@ impl PartialEq for DerivePayload {
             && opt_f32_approx_eq(&self.confidence, &other.confidence)
             && self.justification == other.justification
             && self.metadata == other.metadata
+            && self.namespace == other.namespace
     }
 }
 
@@ -423,6 +831,24 @@ pub struct RetractPayload {
 
     /// Source authorizing the retraction.
     pub authorized_by: Source,
+
+    /// Tenant namespace; must match the belief's namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+/// Payload for FEEDBACK operations.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeedbackPayload {
+    /// The belief whose truth is now known.
+    pub belief_id: BeliefId,
+
+    /// Whether the belief was correct.
+    pub outcome: FeedbackOutcome,
+
+    /// Tenant namespace; must match the belief's namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
 }
 
 /// Payload for DEFINE_PATTERN operations.
@@ -445,6 +871,35 @@ pub struct DefinePatternPayload {
     pub valid_time: TimeRange,
 }
 
+/// Payload for UPDATE_PATTERN operations.
+///
+/// Fields left `None` keep their current value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdatePatternPayload {
+    /// The pattern to update.
+    pub pattern_id: PatternId,
+
+    /// New human-readable name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// New description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// New rule to enforce.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<PatternRule>,
+
+    /// New confidence in the pattern.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<Confidence>,
+
+    /// New validity window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_time: Option<TimeRange>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -464,6 +919,8 @@ mod tests {
             valid_time: TimeRange::from_now(),
             consistency_mode: ConsistencyMode::Strict,
             embedding: None,
+            idempotency_key: None,
+            namespace: None,
         }
     }
 
@@ -515,13 +972,30 @@ mod tests {
             entity_id: Some(EntityId::new()),
             predicate: Some("temperature".to_string()),
             query_embedding: None,
+            semantic: false,
             as_of: None,
             min_confidence: Some(0.5),
             limit: 5,
             include_counter_evidence: true,
             include_gaps: true,
+            include_confidence_interval: false,
+            predicate_fallbacks: Vec::new(),
             conflict_policy: None,
             trust_domain: None,
+            evidence_combination: None,
+            confidence_aggregation: Some(ConfidenceAggregation::NoisyOr),
+            value_filter: Some(Value::Bool(true)),
+            tie_break: Some(TieBreak::OldestTx),
+            relevance_weight: 0.25,
+            min_relevance: Some(0.6),
+            min_support: 2,
+            include_sources: Vec::new(),
+            exclude_sources: vec![SourceId::new()],
+            entity_resolution: Some(EntityResolutionConfig {
+                max_candidates: 3,
+                ..EntityResolutionConfig::default()
+            }),
+            namespace: None,
         };
 
         let json = serde_json::to_string(&payload).unwrap();
@@ -529,6 +1003,14 @@ mod tests {
 
         assert_eq!(payload.query, deserialized.query);
         assert_eq!(payload.min_confidence, deserialized.min_confidence);
+        assert_eq!(payload.value_filter, deserialized.value_filter);
+        assert_eq!(payload.tie_break, deserialized.tie_break);
+        assert_eq!(payload.relevance_weight, deserialized.relevance_weight);
+        assert_eq!(payload.min_relevance, deserialized.min_relevance);
+        assert_eq!(payload.min_support, deserialized.min_support);
+        assert_eq!(payload.exclude_sources, deserialized.exclude_sources);
+        assert_eq!(payload.entity_resolution, deserialized.entity_resolution);
+        assert!(!json.contains("include_sources"));
     }
 
     #[test]
@@ -540,6 +1022,7 @@ mod tests {
                 user_id: "admin".to_string(),
                 role: Some("administrator".to_string()),
             },
+            namespace: None,
         };
 
         let json = serde_json::to_string(&payload).unwrap();
@@ -562,4 +1045,26 @@ mod tests {
 
         assert_eq!(payload.name, deserialized.name);
     }
+
+    #[test]
+    fn test_assert_embedding_bound_follows_validation_limits() {
+        use crate::error::ValidationError;
+        use crate::ir::ValidationLimits;
+
+        let mut payload = sample_assert_payload();
+        payload.embedding = Some(vec![0.1; 3072]);
+        assert!(payload.validate().is_ok());
+
+        let tight = ValidationLimits::default().with_max_embedding_dim(1024);
+        assert!(matches!(
+            payload.validate_with(&tight),
+            Err(ValidationError::FieldTooLong { max_length: 1024, .. })
+        ));
+        assert!(Operation::Transaction(vec![Operation::Assert(payload.clone())])
+            .validate_with(&tight)
+            .is_err());
+
+        payload.embedding = Some(vec![0.1; 1024]);
+        assert!(payload.validate_with(&tight).is_ok());
+    }
 }
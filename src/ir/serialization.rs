@@ -40,6 +40,8 @@ mod tests {
             valid_time: TimeRange::from_now(),
             consistency_mode: crate::ir::ConsistencyMode::Strict,
             embedding: Some(vec![0.1, 0.2]),
+            idempotency_key: None,
+            namespace: None,
         }));
 
         let json = to_json_pretty(&ir).unwrap();
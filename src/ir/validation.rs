@@ -6,23 +6,67 @@
 
 use crate::error::ValidationError;
 use crate::ir::operations::{
-    AssertPayload, DefinePatternPayload, DerivePayload, MonitorPayload, Operation, ResolvePayload,
-    RetractPayload, SimulatePayload,
+    AssertPayload, DefinePatternPayload, DerivePayload, FeedbackPayload, MonitorPayload, Operation,
+    ResolveCompoundPayload, ResolveMultiPayload, ResolvePayload, RetractPayload, SimulatePayload,
+    UpdatePatternPayload,
 };
 
 /// Conservative upper bound for embedding vector sizes.
 ///
-/// This is a safety limit to prevent memory/CPU abuse via unbounded vectors.
+/// This is a safety limit to prevent memory/CPU abuse via unbounded vectors. It is the
+/// default for [`ValidationLimits::max_embedding_dim`].
 pub const MAX_EMBEDDING_DIM: usize = 8192;
 
 /// Conservative upper bound for free-form text fields.
 pub const MAX_TEXT_LEN: usize = 16 * 1024;
 
+/// Upper bound for conditions in a compound RESOLVE.
+pub const MAX_COMPOUND_CONDITIONS: usize = 32;
+
+/// Upper bound for entities in a multi-entity RESOLVE.
+pub const MAX_RESOLVE_MULTI_ENTITIES: usize = 256;
+
+/// Upper bound for tenant namespace names.
+pub const MAX_NAMESPACE_LEN: usize = 256;
+
+/// Upper bound for operations in one TRANSACTION.
+pub const MAX_TRANSACTION_OPERATIONS: usize = 256;
+
 /// Conservative upper bounds for DERIVE payloads.
 pub const MAX_DERIVATION_SOURCES: usize = 1024;
 pub const MAX_DERIVATION_STEPS: usize = 256;
 pub const MAX_DERIVATION_METADATA_BYTES: usize = 64 * 1024;
 
+/// Bounds IR validation enforces that a deployment may tune.
+///
+/// [`Operation::validate`] applies the defaults; an engine validates with its own limits
+/// (see `KyroEngine::with_validation_limits`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationLimits {
+    /// Largest embedding accepted in ASSERT and RESOLVE payloads.
+    ///
+    /// This only bounds what a request may carry; the belief store's embedding dimension
+    /// still decides which length is actually stored.
+    pub max_embedding_dim: usize,
+}
+
+impl ValidationLimits {
+    /// Limits with `max_embedding_dim` replaced.
+    #[must_use]
+    pub const fn with_max_embedding_dim(mut self, max_embedding_dim: usize) -> Self {
+        self.max_embedding_dim = max_embedding_dim;
+        self
+    }
+}
+
+impl Default for ValidationLimits {
+    fn default() -> Self {
+        Self {
+            max_embedding_dim: MAX_EMBEDDING_DIM,
+        }
+    }
+}
+
 /// Validate a non-empty trimmed string field.
 fn validate_non_empty(field: &'static str, value: &str) -> Result<(), ValidationError> {
     let v = value.trim();
@@ -58,7 +102,33 @@ fn validate_optional_text(field: &'static str, value: &Option<String>) -> Result
     Ok(())
 }
 
-fn validate_embedding(field: &'static str, embedding: &Option<Vec<f32>>) -> Result<(), ValidationError> {
+/// Namespaces are compared verbatim, so reject anything that would not round-trip as typed.
+fn validate_namespace(value: &Option<String>) -> Result<(), ValidationError> {
+    let Some(ns) = value else { return Ok(()); };
+    let invalid = |reason: &str| ValidationError::InvalidField {
+        field: "namespace".to_string(),
+        reason: reason.to_string(),
+    };
+    if ns.is_empty() {
+        return Err(invalid("must not be empty (omit it for the default namespace)"));
+    }
+    if ns.len() > MAX_NAMESPACE_LEN {
+        return Err(ValidationError::FieldTooLong {
+            field: "namespace".to_string(),
+            max_length: MAX_NAMESPACE_LEN,
+        });
+    }
+    if ns.trim() != ns || ns.chars().any(char::is_control) {
+        return Err(invalid("must not contain control characters or surrounding whitespace"));
+    }
+    Ok(())
+}
+
+fn validate_embedding(
+    field: &'static str,
+    embedding: &Option<Vec<f32>>,
+    limits: &ValidationLimits,
+) -> Result<(), ValidationError> {
     let Some(v) = embedding else { return Ok(()); };
     if v.is_empty() {
         return Err(ValidationError::InvalidEmbeddingDimension {
@@ -66,10 +136,10 @@ fn validate_embedding(field: &'static str, embedding: &Option<Vec<f32>>) -> Resu
             expected: 1,
         });
     }
-    if v.len() > MAX_EMBEDDING_DIM {
+    if v.len() > limits.max_embedding_dim {
         return Err(ValidationError::FieldTooLong {
             field: field.to_string(),
-            max_length: MAX_EMBEDDING_DIM,
+            max_length: limits.max_embedding_dim,
         });
     }
     Ok(())
@@ -85,31 +155,155 @@ fn validate_confidence_range(opt: &Option<f32>) -> Result<(), ValidationError> {
 }
 
 impl AssertPayload {
-    /// Validates this payload.
+    /// Validates this payload against the default [`ValidationLimits`].
     pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationLimits::default())
+    }
+
+    /// Validates this payload against `limits`.
+    pub fn validate_with(&self, limits: &ValidationLimits) -> Result<(), ValidationError> {
         validate_non_empty("predicate", &self.predicate)?;
-        validate_embedding("embedding", &self.embedding)?;
+        self.value.validate()?;
+        validate_embedding("embedding", &self.embedding, limits)?;
+        validate_optional_text("idempotency_key", &self.idempotency_key)?;
+        validate_namespace(&self.namespace)?;
         Ok(())
     }
 }
 
 impl ResolvePayload {
-    /// Validates this payload.
+    /// Validates this payload against the default [`ValidationLimits`].
     pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationLimits::default())
+    }
+
+    /// Validates this payload against `limits`.
+    pub fn validate_with(&self, limits: &ValidationLimits) -> Result<(), ValidationError> {
         validate_optional_text("query", &self.query)?;
         if let Some(p) = &self.predicate {
             validate_non_empty("predicate", p)?;
         }
+        if !self.predicate_fallbacks.is_empty() && self.predicate.is_none() {
+            return Err(ValidationError::MissingField {
+                field: "predicate".to_string(),
+            });
+        }
+        for fallback in &self.predicate_fallbacks {
+            validate_non_empty("predicate_fallbacks", fallback)?;
+        }
         validate_confidence_range(&self.min_confidence)?;
-        validate_embedding("query_embedding", &self.query_embedding)?;
+        validate_embedding("query_embedding", &self.query_embedding, limits)?;
+        if self.semantic && self.query_embedding.is_none() {
+            validate_non_empty("query", self.query.as_deref().unwrap_or_default())?;
+        }
+        if !(0.0..=1.0).contains(&self.relevance_weight) {
+            return Err(ValidationError::InvalidField {
+                field: "relevance_weight".to_string(),
+                reason: format!("must be within [0.0, 1.0], got {}", self.relevance_weight),
+            });
+        }
+        if let Some(min_relevance) = self.min_relevance {
+            if !(0.0..=1.0).contains(&min_relevance) {
+                return Err(ValidationError::InvalidField {
+                    field: "min_relevance".to_string(),
+                    reason: format!("must be within [0.0, 1.0], got {min_relevance}"),
+                });
+            }
+        }
+        validate_namespace(&self.namespace)?;
         Ok(())
     }
 }
 
+impl ResolveCompoundPayload {
+    /// Validates this payload.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.conditions.is_empty() {
+            return Err(ValidationError::MissingField {
+                field: "conditions".to_string(),
+            });
+        }
+        if self.conditions.len() > MAX_COMPOUND_CONDITIONS {
+            return Err(ValidationError::FieldTooLong {
+                field: "conditions".to_string(),
+                max_length: MAX_COMPOUND_CONDITIONS,
+            });
+        }
+        for condition in &self.conditions {
+            validate_non_empty("predicate", &condition.predicate)?;
+            condition
+                .matcher
+                .validate()
+                .map_err(|e| ValidationError::InvalidField {
+                    field: "matcher".to_string(),
+                    reason: e.to_string(),
+                })?;
+        }
+        validate_confidence_range(&self.min_confidence)?;
+        if self.limit == 0 {
+            return Err(ValidationError::InvalidField {
+                field: "limit".to_string(),
+                reason: "must be at least 1".to_string(),
+            });
+        }
+        validate_namespace(&self.namespace)?;
+        Ok(())
+    }
+}
+
+impl ResolveMultiPayload {
+    /// Validates this payload against `limits`.
+    pub fn validate_with(&self, limits: &ValidationLimits) -> Result<(), ValidationError> {
+        let Some(&first) = self.entity_ids.first() else {
+            return Err(ValidationError::MissingField {
+                field: "entity_ids".to_string(),
+            });
+        };
+        if self.entity_ids.len() > MAX_RESOLVE_MULTI_ENTITIES {
+            return Err(ValidationError::FieldTooLong {
+                field: "entity_ids".to_string(),
+                max_length: MAX_RESOLVE_MULTI_ENTITIES,
+            });
+        }
+        let mut seen = std::collections::HashSet::with_capacity(self.entity_ids.len());
+        if let Some(duplicate) = self.entity_ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(ValidationError::InvalidField {
+                field: "entity_ids".to_string(),
+                reason: format!("entity {duplicate} is listed more than once"),
+            });
+        }
+        validate_non_empty("predicate", &self.predicate)?;
+        for (field, set) in [
+            ("options.entity_id", self.options.entity_id.is_some()),
+            ("options.predicate", self.options.predicate.is_some()),
+        ] {
+            if set {
+                return Err(ValidationError::InvalidField {
+                    field: field.to_string(),
+                    reason: "is set per entity and must be left unset".to_string(),
+                });
+            }
+        }
+        // The per-entity payloads differ only in the entity.
+        self.resolve_for(first).validate_with(limits)
+    }
+}
+
 impl RetractPayload {
     /// Validates this payload.
     pub fn validate(&self) -> Result<(), ValidationError> {
         validate_optional_text("reason", &self.reason)?;
+        validate_namespace(&self.namespace)?;
+        Ok(())
+    }
+}
+
+impl FeedbackPayload {
+    /// Validates this payload.
+    ///
+    /// Whether the belief exists is checked at execution.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_namespace(&self.namespace)?;
         Ok(())
     }
 }
@@ -123,6 +317,27 @@ impl DefinePatternPayload {
     }
 }
 
+impl UpdatePatternPayload {
+    /// Validates this payload.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.name.is_none()
+            && self.description.is_none()
+            && self.rule.is_none()
+            && self.confidence.is_none()
+            && self.valid_time.is_none()
+        {
+            return Err(ValidationError::MissingField {
+                field: "name, description, rule, confidence or valid_time".to_string(),
+            });
+        }
+        if let Some(name) = &self.name {
+            validate_non_empty("name", name)?;
+        }
+        validate_optional_text("description", &self.description)?;
+        Ok(())
+    }
+}
+
 impl SimulatePayload {
     /// Validates this payload.
     pub fn validate(&self) -> Result<(), ValidationError> {
@@ -135,6 +350,7 @@ impl MonitorPayload {
     /// Validates this payload.
     pub fn validate(&self) -> Result<(), ValidationError> {
         validate_optional_text("description", &self.description)?;
+        validate_namespace(&self.namespace)?;
         Ok(())
     }
 }
@@ -167,7 +383,7 @@ impl DerivePayload {
         }
 
         if let Some(derived) = self.derived_belief_id {
-            if sources.iter().any(|id| *id == derived) {
+            if sources.contains(&derived) {
                 return Err(ValidationError::InvalidField {
                     field: "derived_belief_id".to_string(),
                     reason: "must not appear in sources".to_string(),
@@ -201,21 +417,77 @@ impl DerivePayload {
                 });
             }
         }
+        validate_namespace(&self.namespace)?;
         Ok(())
     }
 }
 
 impl Operation {
-    /// Validate the operation payload.
+    /// Validate the operation payload against the default [`ValidationLimits`].
     pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationLimits::default())
+    }
+
+    /// Validate the operation payload against `limits`.
+    pub fn validate_with(&self, limits: &ValidationLimits) -> Result<(), ValidationError> {
         match self {
-            Self::Assert(p) => p.validate(),
-            Self::Resolve(p) => p.validate(),
+            Self::Assert(p) => p.validate_with(limits),
+            Self::Resolve(p) => p.validate_with(limits),
+            Self::ResolveCompound(p) => p.validate(),
+            Self::ResolveMulti(p) => p.validate_with(limits),
             Self::Retract(p) => p.validate(),
             Self::DefinePattern(p) => p.validate(),
+            Self::UpdatePattern(p) => p.validate(),
+            Self::DeactivatePattern(_) => Ok(()),
+            Self::CheckPatternSet(predicate) => {
+                if predicate.trim().is_empty() {
+                    return Err(ValidationError::MissingField {
+                        field: "predicate".to_string(),
+                    });
+                }
+                Ok(())
+            }
+            Self::Feedback(p) => p.validate(),
             Self::Simulate(p) => p.validate(),
             Self::Monitor(p) => p.validate(),
             Self::Derive(p) => p.validate(),
+            Self::Transaction(operations) => validate_transaction(operations, limits),
+        }
+    }
+}
+
+/// Validate the operations of a TRANSACTION, which may only contain buffered writes and reads.
+fn validate_transaction(operations: &[Operation], limits: &ValidationLimits) -> Result<(), ValidationError> {
+    if operations.is_empty() {
+        return Err(ValidationError::MissingField {
+            field: "operations".to_string(),
+        });
+    }
+    if operations.len() > MAX_TRANSACTION_OPERATIONS {
+        return Err(ValidationError::FieldTooLong {
+            field: "operations".to_string(),
+            max_length: MAX_TRANSACTION_OPERATIONS,
+        });
+    }
+    // Report every invalid operation at once rather than one per round trip.
+    let mut errors = Vec::new();
+    for operation in operations {
+        let disallowed = match operation {
+            Operation::Transaction(_) => Some("nested transactions"),
+            Operation::Simulate(_) => Some("simulate"),
+            Operation::Monitor(_) => Some("monitor"),
+            // Calibration updates cannot be buffered and rolled back.
+            Operation::Feedback(_) => Some("feedback"),
+            _ => None,
+        };
+        if let Some(what) = disallowed {
+            errors.push(ValidationError::InvalidField {
+                field: "operations".to_string(),
+                reason: format!("{what} is not allowed inside a transaction"),
+            });
+        } else if let Err(err) = operation.validate_with(limits) {
+            errors.push(err);
         }
     }
+    ValidationError::combine(errors).map_or(Ok(()), Err)
 }
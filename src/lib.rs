@@ -33,6 +33,7 @@
 
 #![warn(clippy::all)]
 #![allow(clippy::module_name_repetitions)]
+#![allow(clippy::module_inception)]
 
 pub mod belief;
 pub mod conflict;
@@ -40,6 +41,7 @@ pub mod derivation;
 pub mod entity;
 pub mod embedding;
 pub mod error;
+pub mod id;
 pub mod pattern;
 pub mod inference; // Exposing the inference module
 
@@ -64,40 +66,59 @@ pub use operations::belief_frame as frame;
 
 // Re-export primary types at crate root for convenience
 pub use belief::{Belief, ConsistencyStatus};
-pub use confidence::{BeliefId, CalibrationMode, Confidence, ConfidenceSource, SourceId};
-pub use conflict::{Conflict, ConflictId, ConflictStatus, ConflictType};
-pub use derivation::{DerivationId, DerivationRecord};
-pub use entity::{Entity, EntityId, EntityType};
-pub use embedding::{lexical_embedding, DEFAULT_EMBEDDING_DIM};
-pub use error::{KyroError, ValidationError};
-pub use frame::{BeliefFrame, Evidence, GapType, KnowledgeGap, RankedClaim};
-pub use pattern::{Pattern, PatternId, PatternRule};
+pub use confidence::{BeliefId, CalibrationMode, Confidence, ConfidenceSource, ConfidenceSourceKind, SourceId};
+pub use conflict::{Conflict, ConflictId, ConflictStatus, ConflictType, ResolutionAudit};
+pub use derivation::{ConfidenceRecomputation, DerivationId, DerivationRecord, DerivationRule};
+pub use entity::{Entity, EntityId, EntityType, EntityTypeHierarchy, MergeProvenance};
+pub use embedding::{
+    default_embedding_text, lexical_embedding, lexical_embedding_with, Embedder, EmbeddingConfig,
+    EmbeddingTextFn, LexicalEmbedder, DEFAULT_EMBEDDING_DIM,
+};
+pub use error::{ErrorCode, KyroError, ValidationError};
+pub use frame::{
+    BeliefFrame, CompoundFrame, CompoundMatch, Evidence, GapType, KnowledgeGap, RankedClaim,
+    SummaryVerbosity,
+};
+pub use pattern::{JsonExpectation, Pattern, PatternId, PatternRule, PatternSetWarning};
 pub use source::Source;
-pub use time::TimeRange;
-pub use value::Value;
+pub use time::{OffsetTimeRange, RecurrenceRule, TimeRange};
+pub use value::{Value, ValueType};
 
 pub use ir::{
-	AssertPayload, ConsistencyMode, DefinePatternPayload, DerivePayload, KyroIR, Operation,
-	ResolvePayload, ResolveMode, RetractPayload,
+	AggregateFunction, AssertPayload, CompoundCondition, ConsistencyMode, DefinePatternPayload, DerivePayload,
+	EntityResolutionConfig, FeedbackPayload, KyroIR, Operation, ResolveCompoundPayload, ResolveMultiPayload, ResolvePayload,
+	ResolveMode, RetractPayload, UpdatePatternPayload, ValidationLimits,
 };
-pub use operations::{AssertBuilder, DeriveBuilder, ResolveBuilder};
+pub use operations::{AssertBuilder, CompoundResolveBuilder, DeriveBuilder, ResolveBuilder};
 pub use operations::SimulateBuilder;
 pub use storage::{
-    BeliefStore, ConflictStore, DerivationStore, EntityStore, PatternStore, StorageError,
+    AmendFields, BeliefStore, ConflictStore, Corroboration, DerivationStore, EntityStore,
+    IdempotencyReservation, IdempotencyStore, PatternStore, StorageError, StorageStats,
 };
 pub use storage::{
 	InMemoryBeliefStore, InMemoryConflictStore, InMemoryDerivationStore, InMemoryEntityStore,
-	InMemoryPatternStore, InMemoryStores,
+	InMemoryIdempotencyStore, InMemoryPatternStore, InMemoryStores,
 };
+pub use storage::{EmbeddingStorage, QuantizedEmbedding};
 
-pub use engine::{EngineResponse, KyroEngine};
+pub use engine::{
+    CustomRuleRegistry, EngineResponse, InMemoryOperationLog, JsonLinesOperationLog, KyroEngine,
+    KyroEngineBuilder, LoggedOperation, Metrics, NoopMetrics, OperationLog, PredicateSchema,
+    PruneReport, RateLimiter, RetentionPolicy, TokenBucketRateLimiter, VacuumMode, VacuumReport,
+};
 pub use engine::runtime::{DefaultRouter, ExecutionHandle, ExecutionPath, KyroRuntime, KyroRuntimeConfig};
-pub use inference::ConflictResolutionPolicy; // Exposing ConflictResolutionPolicy from inference module
+pub use inference::{ConfidenceAggregation, ConflictResolutionPolicy, EvidenceCombination, TieBreak}; // Exposing inference policies
 
-pub use simulation::{SimulateConstraints, SimulationContext, SimulationId, SimulationImpact};
+pub use simulation::{
+    SimulateConstraints, SimulationCommitMode, SimulationCommitRejection, SimulationCommitResult,
+    SimulationContext, SimulationId, SimulationImpact, SimulationRng,
+};
 
-pub use trust::{TrustModel, SimpleTrustModel, TrustAssessment};
-pub use meta::{MetaAnalyzer, CoverageReport, PredicateCoverage, GapAnalysisResult, CalibrationSummary};
+pub use trust::{
+    CalibrationSnapshot, CalibrationTracker, FeedbackHook, FeedbackOutcome, FeedbackRecord,
+    SimpleTrustModel, SourceAccuracy, TrustAssessment, TrustChangeHook, TrustModel, TrustSnapshot,
+};
+pub use meta::{MetaAnalyzer, CoverageReport, PredicateCoverage, GapAnalysisResult, CalibrationSummary, MergeSuggestion};
 
-pub use monitor::{EventPayload, MonitorEvent, MonitorEventError, MonitorRegistration, MonitorStream, MonitorSystem, MonitorSystemConfig, SubscriptionId, Trigger, TriggerId};
+pub use monitor::{ComparisonOp, EventPayload, MonitorEvent, MonitorDelivery, MonitorEventError, MonitorOverflowPolicy, MonitorRegistration, MonitorStream, MonitorSystem, MonitorSystemConfig, SubscriptionId, Trigger, TriggerId, ValueMatcher};
 
@@ -1,11 +1,12 @@
-//! Meta-knowledge utilities: coverage maps, gap analysis, and calibration summaries.
+//! Meta-knowledge utilities: coverage maps, gap analysis, calibration summaries, and
+//! duplicate-entity detection.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::entity::EntityId;
-use crate::error::{ExecutionError, KyroError, KyroResult};
-use crate::storage::{BeliefStore, EntityStore};
+use crate::entity::{Entity, EntityId};
+use crate::error::{ExecutionError, KyroError, KyroResult, ValidationError};
+use crate::storage::{bounded_edit_distance, BeliefStore, EntityStore};
 
 /// Coverage statistics for an entity.
 #[derive(Debug, Clone)]
@@ -38,6 +39,21 @@ pub struct CalibrationSummary {
     pub count: usize,
 }
 
+/// Two entities that look like duplicates, proposed by [`MetaAnalyzer::suggest_merges`].
+#[derive(Debug, Clone)]
+pub struct MergeSuggestion {
+    /// The older entity, suggested as the `primary` of [`EntityStore::merge`].
+    pub primary: EntityId,
+    pub secondary: EntityId,
+    /// Combined similarity in `[0, 1]` that suggestions are ranked by: the mean of the
+    /// name and embedding similarities, or the name similarity alone without embeddings.
+    pub score: f32,
+    /// Best similarity between a name or alias of one entity and one of the other.
+    pub name_similarity: f32,
+    /// Cosine similarity of the two embeddings, if both entities have one of the same size.
+    pub embedding_similarity: Option<f32>,
+}
+
 /// Meta-knowledge analyzer backed by Kyro stores.
 #[derive(Clone)]
 pub struct MetaAnalyzer {
@@ -163,6 +179,120 @@ impl MetaAnalyzer {
             count,
         })
     }
+
+    /// Propose pairs of entities that are likely duplicates, best match first.
+    ///
+    /// Only entities of the same namespace and type are compared. Names are compared after
+    /// dropping case, punctuation and whitespace, so "IBM" and "I.B.M." match exactly;
+    /// names longer than 64 characters only match exactly.
+    /// Nothing is merged; pass a suggestion to [`EntityStore::merge`] to act on it.
+    ///
+    /// Every pair within a namespace and type is scored, so this is meant for offline scans.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidField` if `threshold` is outside `[0, 1]`.
+    pub fn suggest_merges(&self, threshold: f32) -> KyroResult<Vec<MergeSuggestion>> {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(KyroError::Validation(ValidationError::InvalidField {
+                field: "threshold".to_string(),
+                reason: format!("must be within [0, 1], got {threshold}"),
+            }));
+        }
+
+        let entities = self
+            .entities
+            .list()
+            .map_err(|e| KyroError::Execution(ExecutionError::Storage {
+                message: e.to_string(),
+            }))?;
+
+        let mut groups: HashMap<_, Vec<(&Entity, Vec<String>)>> = HashMap::new();
+        for entity in &entities {
+            let keys = name_keys(entity);
+            if !keys.is_empty() {
+                groups
+                    .entry((entity.namespace.as_deref(), &entity.entity_type))
+                    .or_default()
+                    .push((entity, keys));
+            }
+        }
+
+        let mut suggestions = Vec::new();
+        for group in groups.values() {
+            for (i, (a, a_keys)) in group.iter().enumerate() {
+                for (b, b_keys) in &group[i + 1..] {
+                    let name_similarity = a_keys
+                        .iter()
+                        .flat_map(|x| b_keys.iter().map(move |y| name_similarity(x, y)))
+                        .fold(0.0_f32, f32::max);
+                    let embedding_similarity = match (&a.embedding, &b.embedding) {
+                        (Some(x), Some(y)) => cosine_similarity(x, y),
+                        _ => None,
+                    };
+                    let score = embedding_similarity.map_or(name_similarity, |e| (name_similarity + e) / 2.0);
+                    if score < threshold {
+                        continue;
+                    }
+                    let (primary, secondary) = if (b.created_at, b.id.as_uuid()) < (a.created_at, a.id.as_uuid()) {
+                        (b, a)
+                    } else {
+                        (a, b)
+                    };
+                    suggestions.push(MergeSuggestion {
+                        primary: primary.id,
+                        secondary: secondary.id,
+                        score,
+                        name_similarity,
+                        embedding_similarity,
+                    });
+                }
+            }
+        }
+
+        suggestions.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.primary.as_uuid().cmp(b.primary.as_uuid()))
+                .then_with(|| a.secondary.as_uuid().cmp(b.secondary.as_uuid()))
+        });
+        Ok(suggestions)
+    }
+}
+
+/// The canonical name and aliases of `entity`, lowercased and stripped to letters and digits.
+fn name_keys(entity: &Entity) -> Vec<String> {
+    let mut keys: Vec<String> = std::iter::once(&entity.canonical_name)
+        .chain(&entity.aliases)
+        .map(|name| name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect::<String>())
+        .filter(|key| !key.is_empty())
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// One minus the edit distance between two name keys, relative to the longer one.
+fn name_similarity(a: &str, b: &str) -> f32 {
+    if a == b {
+        return 1.0;
+    }
+    let longest = a.chars().count().max(b.chars().count());
+    bounded_edit_distance(a, b, longest).map_or(0.0, |d| 1.0 - d as f32 / longest as f32)
+}
+
+/// Cosine similarity clamped to `[0, 1]`, or `None` for embeddings of different sizes.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> Option<f32> {
+    if a.len() != b.len() || a.is_empty() {
+        return None;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return Some(0.0);
+    }
+    Some((dot / (norm_a * norm_b)).clamp(0.0, 1.0))
 }
 
 #[cfg(test)]
@@ -267,7 +397,7 @@ mod tests {
         assert!((p2.avg_confidence - 0.25).abs() < 1e-6);
 
         let gap = analyzer
-            .gap_analysis(id, &vec!["p1".to_string(), "p3".to_string()])
+            .gap_analysis(id, &["p1".to_string(), "p3".to_string()])
             .unwrap();
         assert_eq!(gap.covered_predicates, vec!["p1".to_string()]);
         assert_eq!(gap.missing_predicates, vec!["p3".to_string()]);
@@ -279,6 +409,44 @@ mod tests {
         assert!((calib.max - 1.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn suggest_merges_ranks_duplicates_and_skips_distinct_entities() {
+        let stores = InMemoryStores::new();
+        let entities: Arc<dyn EntityStore> = Arc::new(stores.entities);
+        let analyzer = MetaAnalyzer::new(Arc::clone(&entities), Arc::new(stores.beliefs));
+
+        let with_embedding = |name: &str, embedding: Vec<f32>| {
+            let mut entity = Entity::new(name, EntityType::Organization);
+            entity.embedding = Some(embedding);
+            entity
+        };
+        let ibm = with_embedding("IBM", vec![1.0, 0.0, 0.0]);
+        let ibm_dotted = with_embedding("I.B.M.", vec![0.9, 0.1, 0.0]);
+        let apple = with_embedding("Apple", vec![0.0, 1.0, 0.0]);
+        let msft = Entity::new("Microsoft Corp", EntityType::Organization);
+        let msft_typo = Entity::new("Microsfot Corp.", EntityType::Organization);
+        // Same name, but a different type or namespace: never a merge candidate.
+        let ibm_person = Entity::new("IBM", EntityType::Person);
+        let mut ibm_elsewhere = Entity::new("IBM", EntityType::Organization);
+        ibm_elsewhere.namespace = Some("other".to_string());
+        for entity in [&ibm, &ibm_dotted, &apple, &msft, &msft_typo, &ibm_person, &ibm_elsewhere] {
+            entities.insert(entity.clone()).unwrap();
+        }
+
+        let suggestions = analyzer.suggest_merges(0.8).unwrap();
+        let pairs: Vec<_> = suggestions.iter().map(|s| (s.primary, s.secondary)).collect();
+        assert_eq!(pairs, vec![(ibm.id, ibm_dotted.id), (msft.id, msft_typo.id)]);
+
+        let top = &suggestions[0];
+        assert_eq!(top.name_similarity, 1.0);
+        assert!(top.embedding_similarity.unwrap() > 0.95);
+        assert!(top.score > suggestions[1].score);
+        assert_eq!(suggestions[1].embedding_similarity, None);
+        assert_eq!(suggestions[1].score, suggestions[1].name_similarity);
+
+        assert!(analyzer.suggest_merges(1.5).is_err());
+    }
+
     #[cfg(feature = "persistent")]
     #[test]
     fn persistent_meta_analyzer_smoke_test() {
@@ -4,11 +4,11 @@
 //! per-subscription streams. ASSERT commits enqueue observations using a bounded
 //! channel and never block the caller.
 
-use std::collections::HashMap;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
 use crossbeam_channel::{bounded, select, Receiver, Sender, TrySendError};
@@ -18,10 +18,54 @@ use crate::error::{ExecutionError, KyroError, KyroResult, ValidationError};
 use crate::storage::BeliefStore;
 use crate::value::Value;
 
-use super::matcher::{AssertObservation, MatchOutput, TriggerMatcher};
-use super::stream::MonitorStream;
+use super::matcher::{AssertObservation, MatchOutput, RateWindow, TriggerMatcher};
+use super::stream::{MonitorStream, OverflowState};
 use super::triggers::{MonitorEvent, SubscriptionId, Trigger, TriggerId};
 
+/// How often the worker drops subscriptions past their `expires_at`.
+const EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_millis(50);
+
+/// What the dispatcher does when a subscriber's stream buffer is full.
+///
+/// Every dropped event increments both `MonitorSystem::dropped_events` and the subscriber's
+/// `MonitorStream::dropped_events`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonitorOverflowPolicy {
+    /// Drop the new event; the subscriber keeps what it has buffered.
+    #[default]
+    DropNewest,
+    /// Evict the oldest buffered event to make room for the new one.
+    DropOldest,
+    /// Wait for the subscriber to make room.
+    ///
+    /// Nothing is dropped, but one slow subscriber stalls dispatch to every subscriber
+    /// until it reads or drops its stream.
+    Block,
+    /// Drop the subscription. Its stream drains what is buffered, then reports
+    /// `ExecutionError::MonitorOverflow`.
+    Disconnect,
+}
+
+/// Delivery guarantee for monitor events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonitorDelivery {
+    /// Send each event once. Events still buffered when a stream goes away are lost.
+    #[default]
+    AtMostOnce,
+    /// Keep each event until the subscriber acknowledges its sequence number.
+    ///
+    /// Dropping the stream detaches the subscription instead of removing it, and
+    /// `MonitorSystem::resume` redelivers the unacknowledged events on a new stream.
+    /// A subscription detached for longer than `resume_window` is dropped. Only the latest
+    /// `unacked_capacity` unacknowledged events are kept for redelivery.
+    AtLeastOnce {
+        /// Unacknowledged events kept per subscription.
+        unacked_capacity: usize,
+        /// How long a detached subscription waits to be resumed.
+        resume_window: Duration,
+    },
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone)]
 pub struct MonitorSystemConfig {
@@ -31,6 +75,10 @@ pub struct MonitorSystemConfig {
     pub control_queue_capacity: usize,
     /// Per-subscription stream buffer capacity.
     pub stream_capacity: usize,
+    /// Handling of events for a subscriber whose stream buffer is full.
+    pub overflow_policy: MonitorOverflowPolicy,
+    /// Whether events are redelivered until acknowledged.
+    pub delivery: MonitorDelivery,
 }
 
 impl Default for MonitorSystemConfig {
@@ -39,6 +87,8 @@ impl Default for MonitorSystemConfig {
             observation_queue_capacity: 4096,
             control_queue_capacity: 1024,
             stream_capacity: 1024,
+            overflow_policy: MonitorOverflowPolicy::default(),
+            delivery: MonitorDelivery::default(),
         }
     }
 }
@@ -57,12 +107,44 @@ pub(crate) enum ControlMsg {
         subscription_id: SubscriptionId,
         triggers: Vec<(TriggerId, Trigger)>,
         expires_at: Option<DateTime<Utc>>,
+        namespace: Option<String>,
         stream_tx: Sender<MonitorEvent>,
+        /// Held by the dispatcher only under `DropOldest`, to evict buffered events.
+        stream_rx: Option<Receiver<MonitorEvent>>,
+        overflow: Arc<OverflowState>,
         reply: Sender<KyroResult<()>>,
     },
     Unregister {
         subscription_id: SubscriptionId,
     },
+    /// The stream of an at-least-once subscription went away; keep the subscription for
+    /// `resume`. Ignored if the subscription has been resumed on a newer attachment since.
+    Detach {
+        subscription_id: SubscriptionId,
+        attachment: u64,
+    },
+    Ack {
+        subscription_id: SubscriptionId,
+        sequence: u64,
+    },
+    Resume {
+        subscription_id: SubscriptionId,
+        stream_tx: Sender<MonitorEvent>,
+        stream_rx: Option<Receiver<MonitorEvent>>,
+        reply: Sender<KyroResult<Resumed>>,
+    },
+    Subscriptions {
+        reply: Sender<HashSet<SubscriptionId>>,
+    },
+}
+
+/// What `MonitorSystem::resume` needs to build the new stream.
+#[derive(Debug)]
+pub(crate) struct Resumed {
+    attachment: u64,
+    replay: Vec<MonitorEvent>,
+    expires_at: Option<DateTime<Utc>>,
+    overflow: Arc<OverflowState>,
 }
 
 #[derive(Debug, Clone)]
@@ -74,13 +156,101 @@ pub(crate) struct ObserveMsg {
 struct TriggerEntry {
     id: TriggerId,
     trigger: Trigger,
-    expires_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug)]
 struct SubscriptionEntry {
     tx: Sender<MonitorEvent>,
+    rx: Option<Receiver<MonitorEvent>>,
+    overflow: Arc<OverflowState>,
     triggers: Vec<TriggerEntry>,
+    /// Observations counted so far by each `Trigger::Rate`.
+    rates: HashMap<TriggerId, RateWindow>,
+    expires_at: Option<DateTime<Utc>>,
+    /// Tenant namespace whose observations the triggers are evaluated against.
+    namespace: Option<String>,
+    /// Sequence number of the last event delivered.
+    sequence: u64,
+    /// Delivered events awaiting acknowledgement, under at-least-once delivery.
+    unacked: Option<UnackedEvents>,
+}
+
+#[derive(Debug)]
+struct UnackedEvents {
+    events: VecDeque<MonitorEvent>,
+    capacity: usize,
+    /// Bumped on every resume, so a stale stream's detach is ignored.
+    attachment: u64,
+    detached_at: Option<Instant>,
+}
+
+impl SubscriptionEntry {
+    fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|e| e <= now)
+    }
+
+    fn is_abandoned(&self, resume_window: Duration) -> bool {
+        self.unacked
+            .as_ref()
+            .and_then(|u| u.detached_at)
+            .is_some_and(|at| at.elapsed() >= resume_window)
+    }
+
+    /// Number `event` and deliver it under `policy`. Returns `false` if the subscription
+    /// must be dropped.
+    fn deliver(&mut self, mut event: MonitorEvent, policy: MonitorOverflowPolicy, dropped_events: &AtomicU64) -> bool {
+        self.sequence += 1;
+        event.sequence = self.sequence;
+        if let Some(unacked) = &mut self.unacked {
+            if unacked.events.len() >= unacked.capacity {
+                unacked.events.pop_front();
+            }
+            unacked.events.push_back(event.clone());
+            // A detached subscriber receives the event when it resumes.
+            if unacked.detached_at.is_some() {
+                return true;
+            }
+        }
+
+        let mut event = match self.tx.try_send(event) {
+            Ok(()) => return true,
+            Err(TrySendError::Disconnected(_)) => {
+                dropped_events.fetch_add(1, Ordering::Relaxed);
+                return true;
+            }
+            Err(TrySendError::Full(event)) => event,
+        };
+
+        match policy {
+            MonitorOverflowPolicy::DropNewest => {}
+            MonitorOverflowPolicy::DropOldest => {
+                // The subscriber may drain concurrently, so retry until the event fits.
+                while let Some(rx) = &self.rx {
+                    let _ = rx.try_recv();
+                    match self.tx.try_send(event) {
+                        Ok(()) => break,
+                        Err(TrySendError::Full(returned)) => event = returned,
+                        Err(TrySendError::Disconnected(_)) => break,
+                    }
+                }
+            }
+            MonitorOverflowPolicy::Block => {
+                if self.tx.send(event).is_ok() {
+                    return true;
+                }
+            }
+            MonitorOverflowPolicy::Disconnect => {
+                self.overflow.record_drop();
+                self.overflow.mark_disconnected();
+                dropped_events.fetch_add(1, Ordering::Relaxed);
+                return false;
+            }
+        }
+
+        self.overflow.record_drop();
+        dropped_events.fetch_add(1, Ordering::Relaxed);
+        true
+    }
 }
 
 /// Monitor system: owns trigger registrations and dispatches events.
@@ -95,6 +265,7 @@ pub struct MonitorSystem {
     observe_tx: Sender<ObserveMsg>,
     dropped_observations: AtomicU64,
     dropped_events: Arc<AtomicU64>,
+    active_subscriptions: Arc<AtomicUsize>,
     join: Mutex<Option<JoinHandle<()>>>,
 }
 
@@ -108,14 +279,25 @@ impl MonitorSystem {
 
         let dropped_observations = AtomicU64::new(0);
         let dropped_events = Arc::new(AtomicU64::new(0));
+        let active_subscriptions = Arc::new(AtomicUsize::new(0));
 
         let matcher = TriggerMatcher::new(Arc::clone(&beliefs));
 
         let thread_cfg = cfg.clone();
         let thread_dropped_events = Arc::clone(&dropped_events);
+        let thread_active_subscriptions = Arc::clone(&active_subscriptions);
         let join = thread::Builder::new()
             .name("kyroql-monitor".to_string())
-            .spawn(move || worker_loop(thread_cfg, matcher, thread_dropped_events, control_rx, observe_rx))
+            .spawn(move || {
+                worker_loop(
+                    thread_cfg,
+                    matcher,
+                    thread_dropped_events,
+                    thread_active_subscriptions,
+                    control_rx,
+                    observe_rx,
+                )
+            })
             .expect("failed to spawn kyroql monitor worker");
 
         Self {
@@ -124,12 +306,21 @@ impl MonitorSystem {
             observe_tx,
             dropped_observations,
             dropped_events,
+            active_subscriptions,
             join: Mutex::new(Some(join)),
         }
     }
 
     /// Register triggers and obtain a stream for matching events.
-    pub fn register(&self, triggers: Vec<Trigger>, expires_at: Option<DateTime<Utc>>) -> KyroResult<MonitorRegistration> {
+    ///
+    /// Only observations of beliefs in `namespace` (`None` is the default namespace) are
+    /// evaluated, so a subscription never sees another tenant's changes.
+    pub fn register(
+        &self,
+        triggers: Vec<Trigger>,
+        expires_at: Option<DateTime<Utc>>,
+        namespace: Option<&str>,
+    ) -> KyroResult<MonitorRegistration> {
         if triggers.is_empty() {
             return Err(KyroError::Validation(ValidationError::MissingField {
                 field: "trigger".to_string(),
@@ -144,9 +335,14 @@ impl MonitorSystem {
             }
         }
 
+        for t in &triggers {
+            t.validate().map_err(|e| KyroError::Validation(ValidationError::InvalidTrigger(Box::new(e))))?;
+        }
+
         let subscription_id = SubscriptionId::new();
 
         let (stream_tx, stream_rx) = bounded::<MonitorEvent>(self.cfg.stream_capacity.max(1));
+        let overflow = Arc::new(OverflowState::default());
 
         let mut trigger_ids = Vec::with_capacity(triggers.len());
         let mut trigger_pairs = Vec::with_capacity(triggers.len());
@@ -156,7 +352,17 @@ impl MonitorSystem {
             trigger_pairs.push((id, t));
         }
 
-        let stream = MonitorStream::new(subscription_id, stream_rx, self.control_tx.clone());
+        let evict_rx = (self.cfg.overflow_policy == MonitorOverflowPolicy::DropOldest).then(|| stream_rx.clone());
+        let mut stream = MonitorStream::new(
+            subscription_id,
+            expires_at,
+            stream_rx,
+            self.control_tx.clone(),
+            Arc::clone(&overflow),
+        );
+        if matches!(self.cfg.delivery, MonitorDelivery::AtLeastOnce { .. }) {
+            stream = stream.with_attachment(0, Vec::new());
+        }
         let reg = MonitorRegistration {
             subscription_id,
             trigger_ids,
@@ -169,7 +375,10 @@ impl MonitorSystem {
                 subscription_id,
                 triggers: trigger_pairs,
                 expires_at,
+                namespace: namespace.map(str::to_string),
                 stream_tx,
+                stream_rx: evict_rx,
+                overflow,
                 reply: reply_tx,
             })
             .map_err(|_| {
@@ -188,6 +397,80 @@ impl MonitorSystem {
         Ok(reg)
     }
 
+    /// Reattach to an at-least-once subscription whose stream was dropped.
+    ///
+    /// The returned stream first yields every event not yet acknowledged, in sequence order,
+    /// then new events. Resuming a subscription that still has a stream closes that stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MonitorNotFound` if the subscription is unknown, was unsubscribed, expired or
+    /// stayed detached past its resume window, or if delivery is at-most-once.
+    pub fn resume(&self, subscription_id: SubscriptionId) -> KyroResult<MonitorStream> {
+        let (stream_tx, stream_rx) = bounded::<MonitorEvent>(self.cfg.stream_capacity.max(1));
+        let evict_rx = (self.cfg.overflow_policy == MonitorOverflowPolicy::DropOldest).then(|| stream_rx.clone());
+
+        let (reply_tx, reply_rx) = bounded::<KyroResult<Resumed>>(1);
+        self.control_tx
+            .send(ControlMsg::Resume {
+                subscription_id,
+                stream_tx,
+                stream_rx: evict_rx,
+                reply: reply_tx,
+            })
+            .map_err(|_| {
+                KyroError::Execution(ExecutionError::Disconnected {
+                    path: "monitor_control".to_string(),
+                })
+            })?;
+        let resumed = reply_rx.recv().map_err(|_| {
+            KyroError::Execution(ExecutionError::Disconnected {
+                path: "monitor_control".to_string(),
+            })
+        })??;
+
+        Ok(MonitorStream::new(
+            subscription_id,
+            resumed.expires_at,
+            stream_rx,
+            self.control_tx.clone(),
+            resumed.overflow,
+        )
+        .with_attachment(resumed.attachment, resumed.replay))
+    }
+
+    /// Acknowledge every event of `subscription_id` up to and including `sequence`.
+    ///
+    /// Equivalent to `MonitorStream::ack` on the subscription's stream, for callers that
+    /// only hold the id. Unknown subscriptions are ignored.
+    pub fn ack(&self, subscription_id: SubscriptionId, sequence: u64) -> KyroResult<()> {
+        self.control_tx
+            .send(ControlMsg::Ack {
+                subscription_id,
+                sequence,
+            })
+            .map_err(|_| {
+                KyroError::Execution(ExecutionError::Disconnected {
+                    path: "monitor_control".to_string(),
+                })
+            })
+    }
+
+    /// Ids of every subscription the dispatcher currently holds, including detached
+    /// at-least-once subscriptions that can still be resumed.
+    pub fn subscription_ids(&self) -> KyroResult<HashSet<SubscriptionId>> {
+        let disconnected = || {
+            KyroError::Execution(ExecutionError::Disconnected {
+                path: "monitor_control".to_string(),
+            })
+        };
+        let (reply_tx, reply_rx) = bounded::<HashSet<SubscriptionId>>(1);
+        self.control_tx
+            .send(ControlMsg::Subscriptions { reply: reply_tx })
+            .map_err(|_| disconnected())?;
+        reply_rx.recv().map_err(|_| disconnected())
+    }
+
     /// Non-blocking observation enqueue.
     pub fn observe_assert(&self, obs: AssertObservation) {
         match self.observe_tx.try_send(ObserveMsg { obs }) {
@@ -208,6 +491,14 @@ impl MonitorSystem {
         self.dropped_events.load(Ordering::Relaxed)
     }
 
+    /// Number of subscriptions currently held by the dispatcher.
+    ///
+    /// Updated by the worker thread, so it lags register/unsubscribe/expiry slightly.
+    #[must_use]
+    pub fn active_subscriptions(&self) -> usize {
+        self.active_subscriptions.load(Ordering::Relaxed)
+    }
+
     /// Translate a `Value::Structured` trigger specification to concrete triggers.
     pub fn triggers_from_threshold_value(
         &self,
@@ -296,14 +587,12 @@ fn build_confidence_shift_triggers(
     let mut out = Vec::new();
 
     let entities: Vec<Option<crate::entity::EntityId>> = match entity_filters {
-        None => vec![None],
-        Some(v) if v.is_empty() => vec![None],
+        None | Some([]) => vec![None],
         Some(v) => v.iter().copied().map(Some).collect(),
     };
 
     let predicates: Vec<Option<String>> = match predicate_filters {
-        None => vec![None],
-        Some(v) if v.is_empty() => vec![None],
+        None | Some([]) => vec![None],
         Some(v) => v
             .iter()
             .map(|s| {
@@ -336,10 +625,25 @@ fn build_confidence_shift_triggers(
     out
 }
 
+/// Drop subscriptions past their `expires_at`.
+///
+/// Dropping an entry drops its sender, which closes the subscriber's `MonitorStream`; the stream
+/// then reports `ExecutionError::MonitorExpired` once its buffer drains.
+///
+/// Detached at-least-once subscriptions are dropped once their resume window passes.
+fn sweep_expired(subs: &mut HashMap<SubscriptionId, SubscriptionEntry>, now: DateTime<Utc>, delivery: MonitorDelivery) {
+    let resume_window = match delivery {
+        MonitorDelivery::AtMostOnce => Duration::MAX,
+        MonitorDelivery::AtLeastOnce { resume_window, .. } => resume_window,
+    };
+    subs.retain(|_, sub| !sub.is_expired(now) && !sub.is_abandoned(resume_window));
+}
+
 fn worker_loop(
-    _cfg: MonitorSystemConfig,
+    cfg: MonitorSystemConfig,
     matcher: TriggerMatcher,
     dropped_events: Arc<AtomicU64>,
+    active_subscriptions: Arc<AtomicUsize>,
     control_rx: Receiver<ControlMsg>,
     observe_rx: Receiver<ObserveMsg>,
 ) {
@@ -347,24 +651,104 @@ fn worker_loop(
 
     let mut control_closed = false;
     let mut observe_closed = false;
+    let mut last_sweep = Instant::now();
 
     loop {
         select! {
             recv(control_rx) -> msg => {
                 match msg {
-                    Ok(ControlMsg::Register { subscription_id, triggers, expires_at, stream_tx, reply }) => {
+                    Ok(ControlMsg::Register {
+                        subscription_id,
+                        triggers,
+                        expires_at,
+                        namespace,
+                        stream_tx,
+                        stream_rx,
+                        overflow,
+                        reply,
+                    }) => {
                         let trigger_entries: Vec<TriggerEntry> = triggers
                             .into_iter()
-                            .map(|(id, trigger)| TriggerEntry { id, trigger, expires_at })
+                            .map(|(id, trigger)| TriggerEntry { id, trigger })
+                            .collect();
+                        let rates = trigger_entries
+                            .iter()
+                            .filter_map(|t| match t.trigger {
+                                Trigger::Rate { count, window_seconds, .. } => {
+                                    Some((t.id, RateWindow::new(count, window_seconds)))
+                                }
+                                _ => None,
+                            })
                             .collect();
 
-                        subs.insert(subscription_id, SubscriptionEntry { tx: stream_tx, triggers: trigger_entries });
+                        let unacked = match cfg.delivery {
+                            MonitorDelivery::AtMostOnce => None,
+                            MonitorDelivery::AtLeastOnce { unacked_capacity, .. } => Some(UnackedEvents {
+                                events: VecDeque::new(),
+                                capacity: unacked_capacity.max(1),
+                                attachment: 0,
+                                detached_at: None,
+                            }),
+                        };
+                        subs.insert(
+                            subscription_id,
+                            SubscriptionEntry {
+                                tx: stream_tx,
+                                rx: stream_rx,
+                                overflow,
+                                triggers: trigger_entries,
+                                rates,
+                                expires_at,
+                                namespace,
+                                sequence: 0,
+                                unacked,
+                            },
+                        );
 
                         let _ = reply.send(Ok(()));
                     }
                     Ok(ControlMsg::Unregister { subscription_id }) => {
                         subs.remove(&subscription_id);
                     }
+                    Ok(ControlMsg::Detach { subscription_id, attachment }) => {
+                        if let Some(unacked) = subs.get_mut(&subscription_id).and_then(|s| s.unacked.as_mut()) {
+                            if unacked.attachment == attachment {
+                                unacked.detached_at = Some(Instant::now());
+                            }
+                        }
+                    }
+                    Ok(ControlMsg::Ack { subscription_id, sequence }) => {
+                        if let Some(unacked) = subs.get_mut(&subscription_id).and_then(|s| s.unacked.as_mut()) {
+                            unacked.events.retain(|e| e.sequence > sequence);
+                        }
+                    }
+                    Ok(ControlMsg::Resume { subscription_id, stream_tx, stream_rx, reply }) => {
+                        let now = Utc::now();
+                        let resumed = match subs.get_mut(&subscription_id) {
+                            Some(SubscriptionEntry { tx, rx, overflow, expires_at, unacked: Some(unacked), .. })
+                                if !expires_at.is_some_and(|e| e <= now) =>
+                            {
+                                *tx = stream_tx;
+                                *rx = stream_rx;
+                                unacked.attachment += 1;
+                                unacked.detached_at = None;
+                                Ok(Resumed {
+                                    attachment: unacked.attachment,
+                                    replay: unacked.events.iter().cloned().collect(),
+                                    expires_at: *expires_at,
+                                    overflow: Arc::clone(overflow),
+                                })
+                            }
+                            _ => Err(KyroError::Execution(ExecutionError::MonitorNotFound {
+                                subscription_id: subscription_id.to_string(),
+                            })),
+                        };
+                        let _ = reply.send(resumed);
+                    }
+                    Ok(ControlMsg::Subscriptions { reply }) => {
+                        sweep_expired(&mut subs, Utc::now(), cfg.delivery);
+                        let _ = reply.send(subs.keys().copied().collect());
+                    }
                     Err(_) => {
                         control_closed = true;
                     }
@@ -373,16 +757,24 @@ fn worker_loop(
             recv(observe_rx) -> msg => {
                 match msg {
                     Ok(ObserveMsg { obs }) => {
-                        // Dispatch observation to matching triggers.
-                        let now = Utc::now();
-                        for sub in subs.values_mut() {
-                            // Filter expired triggers in-place.
-                            sub.triggers.retain(|t| t.expires_at.map(|e| e > now).unwrap_or(true));
+                        // Expired subscriptions never see another event, even between sweeps.
+                        sweep_expired(&mut subs, Utc::now(), cfg.delivery);
 
+                        // Dispatch observation to matching triggers.
+                        let mut overflowed = Vec::new();
+                        for (id, sub) in &mut subs {
+                            if sub.namespace != obs.namespace {
+                                continue;
+                            }
+                            let mut fired = Vec::new();
                             for t in &sub.triggers {
                                 match matcher.evaluate(&t.trigger, &obs) {
                                     Ok(MatchOutput::NoMatch) => {}
                                     Ok(MatchOutput::Match(payload)) => {
+                                        // A rate trigger fires only once its window fills.
+                                        if sub.rates.get_mut(&t.id).is_some_and(|w| !w.observe(obs.tx_time)) {
+                                            continue;
+                                        }
                                         let Ok(event) = MonitorEvent::new(t.id, t.trigger.clone(), payload) else {
                                             // Internal invariant violation (matcher produced payload inconsistent with trigger).
                                             // Fail closed by dropping the event.
@@ -390,19 +782,22 @@ fn worker_loop(
                                             continue;
                                         };
 
-                                        // Never block monitor thread: drop if subscriber is slow.
-                                        match sub.tx.try_send(event) {
-                                            Ok(()) => {}
-                                            Err(TrySendError::Full(_)) | Err(TrySendError::Disconnected(_)) => {
-                                                dropped_events.fetch_add(1, Ordering::Relaxed);
-                                            }
-                                        }
+                                        fired.push(event);
                                     }
                                     Err(_) => {
                                         // Storage/matcher error: fail closed (no event).
                                     }
                                 }
                             }
+                            for event in fired {
+                                if !sub.deliver(event, cfg.overflow_policy, &dropped_events) {
+                                    overflowed.push(*id);
+                                    break;
+                                }
+                            }
+                        }
+                        for id in overflowed {
+                            subs.remove(&id);
                         }
                     }
                     Err(_) => {
@@ -410,15 +805,16 @@ fn worker_loop(
                     }
                 }
             }
-            default(Duration::from_millis(50)) => {
-                // Periodic cleanup: drop fully expired subscriptions.
-                let now = Utc::now();
-                subs.retain(|_, sub| {
-                    sub.triggers.retain(|t| t.expires_at.map(|e| e > now).unwrap_or(true));
-                    !sub.triggers.is_empty()
-                });
-            }
+            default(EXPIRY_SWEEP_INTERVAL) => {}
+        }
+
+        // Sweep on a clock rather than only when idle, so a busy dispatcher still
+        // closes expired streams.
+        if last_sweep.elapsed() >= EXPIRY_SWEEP_INTERVAL {
+            sweep_expired(&mut subs, Utc::now(), cfg.delivery);
+            last_sweep = Instant::now();
         }
+        active_subscriptions.store(subs.len(), Ordering::Relaxed);
 
         if control_closed && observe_closed {
             break;
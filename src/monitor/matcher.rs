@@ -3,18 +3,19 @@
 //! The matcher evaluates triggers against committed ASSERT observations.
 //! Expensive lookups are performed off the ASSERT path.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use chrono::{DateTime, Utc};
 
 use crate::confidence::BeliefId;
 use crate::conflict::ConflictType;
+use crate::engine::cached_regex;
 use crate::error::{ExecutionError, KyroError, KyroResult};
 use crate::pattern::PatternId;
 use crate::storage::BeliefStore;
 use crate::value::Value;
 
-use super::triggers::{EventPayload, Trigger};
+use super::triggers::{EventPayload, Trigger, ValueMatcher};
 
 #[allow(missing_docs)]
 #[derive(Debug, Clone)]
@@ -26,6 +27,8 @@ pub struct AssertObservation {
     pub value: Value,
     pub confidence: f32,
     pub conflict_types: Vec<ConflictType>,
+    /// Tenant namespace of the belief; only subscriptions of the same namespace see it.
+    pub namespace: Option<String>,
 }
 
 #[allow(missing_docs)]
@@ -43,9 +46,15 @@ pub struct TriggerMatcher {
 impl TriggerMatcher {
     #[must_use]
     pub fn new(beliefs: std::sync::Arc<dyn BeliefStore>) -> Self {
-        Self { beliefs }
+        Self {
+            beliefs,
+        }
     }
 
+    /// Evaluate `trigger` against one observation.
+    ///
+    /// For [`Trigger::Rate`] a match only means the observation counts toward the rate; the
+    /// caller feeds matches into a [`RateWindow`] to decide when the trigger fires.
     pub fn evaluate(&self, trigger: &Trigger, obs: &AssertObservation) -> KyroResult<MatchOutput> {
         match trigger {
             Trigger::ConfidenceShift {
@@ -70,6 +79,70 @@ impl TriggerMatcher {
             Trigger::GapFilled { entity_id, predicate } => {
                 self.match_gap_filled(*entity_id, predicate, obs)
             }
+
+            Trigger::ValueMatch {
+                entity_filter,
+                predicate,
+                matcher,
+            } => self.match_value(*entity_filter, predicate, matcher, obs),
+
+            Trigger::Rate {
+                predicate,
+                count,
+                window_seconds,
+            } => {
+                if predicate.trim() != obs.predicate {
+                    return Ok(MatchOutput::NoMatch);
+                }
+                Ok(MatchOutput::Match(EventPayload::Rate {
+                    belief_id: obs.belief_id,
+                    entity_id: obs.entity_id,
+                    predicate: obs.predicate.clone(),
+                    count: *count,
+                    window_seconds: *window_seconds,
+                }))
+            }
+        }
+    }
+
+    fn match_value(
+        &self,
+        entity_filter: Option<crate::entity::EntityId>,
+        predicate: &str,
+        matcher: &ValueMatcher,
+        obs: &AssertObservation,
+    ) -> KyroResult<MatchOutput> {
+        if let Some(eid) = entity_filter {
+            if eid != obs.entity_id {
+                return Ok(MatchOutput::NoMatch);
+            }
+        }
+        if predicate.trim() != obs.predicate {
+            return Ok(MatchOutput::NoMatch);
+        }
+
+        let matched = match matcher {
+            ValueMatcher::Equals { value } => &obs.value == value,
+            ValueMatcher::Compare { cmp, threshold } => match obs.value.as_float() {
+                Some(v) => cmp.holds(v, *threshold),
+                None => false,
+            },
+            ValueMatcher::Regex { pattern } => match obs.value.as_string() {
+                // Registration validates patterns, so this only fails for a trigger that bypassed it.
+                Some(s) => cached_regex(pattern)?.is_match(s),
+                None => false,
+            },
+        };
+
+        if matched {
+            Ok(MatchOutput::Match(EventPayload::ValueMatch {
+                belief_id: obs.belief_id,
+                entity_id: obs.entity_id,
+                predicate: obs.predicate.clone(),
+                value: obs.value.clone(),
+            }))
+        } else {
+            Ok(MatchOutput::NoMatch)
         }
     }
 
@@ -335,6 +408,56 @@ impl TriggerMatcher {
     }
 }
 
+/// Sliding window of the observations counted by one [`Trigger::Rate`].
+#[derive(Debug)]
+pub struct RateWindow {
+    count: usize,
+    window: chrono::Duration,
+    /// Transaction times of the latest (at most `count`) counted observations.
+    times: VecDeque<DateTime<Utc>>,
+    armed: bool,
+}
+
+impl RateWindow {
+    /// Window for a `count` of observations within `window_seconds`.
+    #[must_use]
+    pub fn new(count: u32, window_seconds: u64) -> Self {
+        let seconds = i64::try_from(window_seconds).unwrap_or(i64::MAX);
+        Self {
+            count: count.max(1) as usize,
+            window: chrono::Duration::try_seconds(seconds).unwrap_or(chrono::Duration::MAX),
+            times: VecDeque::new(),
+            armed: true,
+        }
+    }
+
+    /// Count an observation made at `tx_time`. Returns `true` if the rate fires.
+    ///
+    /// Observations are expected in transaction-time order.
+    pub fn observe(&mut self, tx_time: DateTime<Utc>) -> bool {
+        while self
+            .times
+            .front()
+            .is_some_and(|&t| tx_time.signed_duration_since(t) >= self.window)
+        {
+            self.times.pop_front();
+        }
+        if self.times.is_empty() {
+            self.armed = true;
+        }
+
+        self.times.push_back(tx_time);
+        if self.times.len() > self.count {
+            self.times.pop_front();
+        }
+        if self.armed && self.times.len() == self.count {
+            self.armed = false;
+            return true;
+        }
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,6 +468,7 @@ mod tests {
     use crate::source::Source;
     use crate::storage::InMemoryBeliefStore;
     use crate::time::TimeRange;
+    use crate::monitor::triggers::ComparisonOp;
 
     fn belief_with(
         id: BeliefId,
@@ -365,9 +489,14 @@ mod tests {
             tx_time,
             reason: None,
             consistency_status: crate::belief::ConsistencyStatus::Verified,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         }
     }
 
@@ -386,6 +515,7 @@ mod tests {
             value: Value::Int(1),
             confidence: 0.9,
             conflict_types: Vec::new(),
+            namespace: None,
         };
 
         let out = matcher
@@ -432,6 +562,7 @@ mod tests {
             value: Value::Int(1),
             confidence: 0.9,
             conflict_types: Vec::new(),
+            namespace: None,
         };
 
         let out = matcher
@@ -452,4 +583,103 @@ mod tests {
             other => panic!("expected match, got {other:?}"),
         }
     }
+
+    fn value_obs(entity_id: crate::entity::EntityId, predicate: &str, value: Value) -> AssertObservation {
+        AssertObservation {
+            tx_time: Utc::now(),
+            belief_id: BeliefId::new(),
+            entity_id,
+            predicate: predicate.to_string(),
+            value,
+            confidence: 0.9,
+            conflict_types: Vec::new(),
+            namespace: None,
+        }
+    }
+
+    #[test]
+    fn rate_window_fires_once_per_burst_and_rearms_after_the_window_clears() {
+        let t0 = Utc::now();
+        let at = |secs: i64| t0 + chrono::Duration::seconds(secs);
+
+        let mut window = RateWindow::new(3, 60);
+        let fired: Vec<bool> = (0..6).map(|i| window.observe(at(i))).collect();
+        assert_eq!(fired, [false, false, true, false, false, false]);
+
+        // Still inside the window of the burst: stays disarmed.
+        assert!(!window.observe(at(64)));
+        // The window empties before this burst, so it fires again.
+        assert!(!window.observe(at(200)));
+        assert!(!window.observe(at(201)));
+        assert!(window.observe(at(202)));
+
+        let mut trickle = RateWindow::new(3, 60);
+        assert!((0..10).all(|i| !trickle.observe(at(i * 31))));
+    }
+
+    fn fires(matcher: &TriggerMatcher, trigger: &Trigger, obs: &AssertObservation) -> bool {
+        matches!(
+            matcher.evaluate(trigger, obs).unwrap(),
+            MatchOutput::Match(EventPayload::ValueMatch { .. })
+        )
+    }
+
+    #[test]
+    fn value_match_equality_on_bool() {
+        let matcher = TriggerMatcher::new(Arc::new(InMemoryBeliefStore::new()));
+        let eid = crate::entity::EntityId::new();
+        let trigger = Trigger::ValueMatch {
+            entity_filter: Some(eid),
+            predicate: "online".to_string(),
+            matcher: ValueMatcher::Equals { value: Value::Bool(false) },
+        };
+
+        assert!(fires(&matcher, &trigger, &value_obs(eid, "online", Value::Bool(false))));
+        assert!(!fires(&matcher, &trigger, &value_obs(eid, "online", Value::Bool(true))));
+        // Entity and predicate filters still apply.
+        let other = crate::entity::EntityId::new();
+        assert!(!fires(&matcher, &trigger, &value_obs(other, "online", Value::Bool(false))));
+        assert!(!fires(&matcher, &trigger, &value_obs(eid, "enabled", Value::Bool(false))));
+    }
+
+    #[test]
+    fn value_match_numeric_comparison_on_float_and_int() {
+        let matcher = TriggerMatcher::new(Arc::new(InMemoryBeliefStore::new()));
+        let eid = crate::entity::EntityId::new();
+        let trigger = Trigger::ValueMatch {
+            entity_filter: None,
+            predicate: "temperature".to_string(),
+            matcher: ValueMatcher::Compare {
+                cmp: ComparisonOp::Ge,
+                threshold: 30.0,
+            },
+        };
+
+        assert!(fires(&matcher, &trigger, &value_obs(eid, "temperature", Value::Float(31.5))));
+        assert!(fires(&matcher, &trigger, &value_obs(eid, "temperature", Value::Int(30))));
+        assert!(!fires(&matcher, &trigger, &value_obs(eid, "temperature", Value::Float(29.9))));
+        assert!(!fires(
+            &matcher,
+            &trigger,
+            &value_obs(eid, "temperature", Value::String("hot".to_string()))
+        ));
+    }
+
+    #[test]
+    fn value_match_regex_on_string() {
+        let matcher = TriggerMatcher::new(Arc::new(InMemoryBeliefStore::new()));
+        let eid = crate::entity::EntityId::new();
+        let trigger = Trigger::ValueMatch {
+            entity_filter: None,
+            predicate: "status".to_string(),
+            matcher: ValueMatcher::Regex {
+                pattern: "^fail(ed|ing)$".to_string(),
+            },
+        };
+
+        assert!(fires(&matcher, &trigger, &value_obs(eid, "status", Value::String("failed".to_string()))));
+        assert!(fires(&matcher, &trigger, &value_obs(eid, "status", Value::String("failing".to_string()))));
+        assert!(!fires(&matcher, &trigger, &value_obs(eid, "status", Value::String("ok".to_string()))));
+        assert!(!fires(&matcher, &trigger, &value_obs(eid, "status", Value::Bool(false))));
+    }
 }
@@ -14,6 +14,9 @@ pub mod stream;
 /// Trigger and event type definitions.
 pub mod triggers;
 
-pub use dispatcher::{MonitorRegistration, MonitorSystem, MonitorSystemConfig};
+pub use dispatcher::{MonitorDelivery, MonitorOverflowPolicy, MonitorRegistration, MonitorSystem, MonitorSystemConfig};
 pub use stream::MonitorStream;
-pub use triggers::{EventPayload, MonitorEvent, MonitorEventError, SubscriptionId, Trigger, TriggerId};
+pub use triggers::{
+    ComparisonOp, EventPayload, MonitorEvent, MonitorEventError, SubscriptionId, Trigger,
+    TriggerId, ValueMatcher,
+};
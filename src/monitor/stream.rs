@@ -1,6 +1,9 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
 
 use crate::error::{ExecutionError, KyroError, KyroResult};
@@ -8,39 +11,97 @@ use crate::error::{ExecutionError, KyroError, KyroResult};
 use super::dispatcher::ControlMsg;
 use super::triggers::{MonitorEvent, SubscriptionId};
 
+/// Overflow bookkeeping shared between the dispatcher and one subscriber's stream.
+#[derive(Debug, Default)]
+pub(crate) struct OverflowState {
+    dropped_events: AtomicU64,
+    disconnected: AtomicBool,
+}
+
+impl OverflowState {
+    pub(crate) fn record_drop(&self) {
+        self.dropped_events.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn mark_disconnected(&self) {
+        self.disconnected.store(true, Ordering::Release);
+    }
+}
+
 /// A subscription stream for monitor events.
 ///
 /// Dropping this stream attempts best-effort unregistration.
 ///
-
+/// Once the subscription passes its `expires_at`, the dispatcher drops it; buffered events
+/// are still delivered, after which `recv` fails with `ExecutionError::MonitorExpired`.
+/// A subscriber disconnected for falling behind (`MonitorOverflowPolicy::Disconnect`) likewise
+/// drains its buffer and then fails with `ExecutionError::MonitorOverflow`.
+///
+/// Under `MonitorDelivery::AtLeastOnce`, dropping the stream detaches the subscription
+/// instead: the dispatcher keeps its unacknowledged events, and
+/// [`MonitorSystem::resume`](super::MonitorSystem::resume) returns a new stream that yields
+/// them again before any new event.
 #[derive(Debug)]
 pub struct MonitorStream {
     subscription_id: SubscriptionId,
+    expires_at: Option<DateTime<Utc>>,
     rx: Receiver<MonitorEvent>,
     control_tx: Sender<ControlMsg>,
     unregistered: AtomicBool,
+    overflow: Arc<OverflowState>,
+    /// Unacknowledged events being redelivered after a resume.
+    replay: Mutex<VecDeque<MonitorEvent>>,
+    /// Which attachment of an at-least-once subscription this stream is; `None` under
+    /// at-most-once delivery.
+    attachment: Option<u64>,
 }
 
 impl MonitorStream {
     pub(crate) fn new(
         subscription_id: SubscriptionId,
+        expires_at: Option<DateTime<Utc>>,
         rx: Receiver<MonitorEvent>,
         control_tx: Sender<ControlMsg>,
+        overflow: Arc<OverflowState>,
     ) -> Self {
         Self {
             subscription_id,
+            expires_at,
             rx,
             control_tx,
             unregistered: AtomicBool::new(false),
+            overflow,
+            replay: Mutex::new(VecDeque::new()),
+            attachment: None,
         }
     }
 
+    /// Make this stream an attachment of an at-least-once subscription, yielding `replay`
+    /// before anything it receives.
+    pub(crate) fn with_attachment(mut self, attachment: u64, replay: Vec<MonitorEvent>) -> Self {
+        self.attachment = Some(attachment);
+        self.replay = Mutex::new(replay.into());
+        self
+    }
+
     /// The subscription id backing this stream.
     #[must_use]
     pub const fn subscription_id(&self) -> SubscriptionId {
         self.subscription_id
     }
 
+    /// When the subscription expires, if it was registered with an expiry.
+    #[must_use]
+    pub const fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+
+    /// Events this subscription lost because its buffer was full.
+    #[must_use]
+    pub fn dropped_events(&self) -> u64 {
+        self.overflow.dropped_events.load(Ordering::Relaxed)
+    }
+
     /// Best-effort explicit unregistration.
     ///
     /// This is non-blocking and idempotent. After the subscription is removed on the
@@ -55,35 +116,79 @@ impl MonitorStream {
         });
     }
 
+    /// Acknowledge every event up to and including `sequence`, so it is not redelivered.
+    ///
+    /// Best-effort and non-blocking like [`unsubscribe`](Self::unsubscribe): a lost ack only
+    /// means the events are delivered again. A no-op under at-most-once delivery.
+    pub fn ack(&self, sequence: u64) {
+        if self.attachment.is_some() {
+            let _ = self.control_tx.try_send(ControlMsg::Ack {
+                subscription_id: self.subscription_id,
+                sequence,
+            });
+        }
+    }
+
     /// Receive the next event (blocking).
     pub fn recv(&self) -> KyroResult<MonitorEvent> {
-        self.rx.recv().map_err(|_| {
-            KyroError::Execution(ExecutionError::Disconnected {
-                path: "monitor_stream".to_string(),
-            })
-        })
+        if let Some(event) = self.next_replayed() {
+            return Ok(event);
+        }
+        self.rx.recv().map_err(|_| self.disconnected())
     }
 
     /// Receive the next event with a timeout.
     pub fn recv_timeout(&self, timeout: Duration) -> KyroResult<MonitorEvent> {
+        if let Some(event) = self.next_replayed() {
+            return Ok(event);
+        }
         self.rx.recv_timeout(timeout).map_err(|err| match err {
             RecvTimeoutError::Timeout => KyroError::Execution(ExecutionError::Timeout {
                 duration_ms: timeout.as_millis().min(u128::from(u64::MAX)) as u64,
             }),
-            RecvTimeoutError::Disconnected => KyroError::Execution(ExecutionError::Disconnected {
+            RecvTimeoutError::Disconnected => self.disconnected(),
+        })
+    }
+
+    fn next_replayed(&self) -> Option<MonitorEvent> {
+        self.replay.lock().ok()?.pop_front()
+    }
+
+    /// Why the dispatcher closed this stream.
+    fn disconnected(&self) -> KyroError {
+        if self.overflow.disconnected.load(Ordering::Acquire) {
+            return KyroError::Execution(ExecutionError::MonitorOverflow {
+                subscription_id: self.subscription_id.to_string(),
+                dropped_events: self.dropped_events(),
+            });
+        }
+        match self.expires_at {
+            Some(expired_at) if expired_at <= Utc::now() => KyroError::Execution(ExecutionError::MonitorExpired {
+                subscription_id: self.subscription_id.to_string(),
+                expired_at,
+            }),
+            _ => KyroError::Execution(ExecutionError::Disconnected {
                 path: "monitor_stream".to_string(),
             }),
-        })
+        }
     }
 }
 
 impl Drop for MonitorStream {
     fn drop(&mut self) {
         // Best-effort: do not block on shutdown.
-        if !self.unregistered.swap(true, Ordering::AcqRel) {
-            let _ = self.control_tx.try_send(ControlMsg::Unregister {
-                subscription_id: self.subscription_id,
-            });
+        if self.unregistered.swap(true, Ordering::AcqRel) {
+            return;
         }
+        let msg = match self.attachment {
+            Some(attachment) => ControlMsg::Detach {
+                subscription_id: self.subscription_id,
+                attachment,
+            },
+            None => ControlMsg::Unregister {
+                subscription_id: self.subscription_id,
+            },
+        };
+        let _ = self.control_tx.try_send(msg);
     }
 }
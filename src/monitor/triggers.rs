@@ -12,6 +12,7 @@ use crate::confidence::BeliefId;
 use crate::conflict::ConflictType;
 use crate::entity::EntityId;
 use crate::pattern::PatternId;
+pub use crate::value::ComparisonOp;
 use crate::value::Value;
 
 /// Unique identifier for a trigger.
@@ -24,7 +25,7 @@ impl TriggerId {
     /// Create a new random trigger id.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(crate::id::next_id())
     }
 
     /// Wrap an existing UUID.
@@ -50,7 +51,7 @@ impl SubscriptionId {
     /// Create a new random subscription id.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(crate::id::next_id())
     }
 
     /// Wrap an existing UUID.
@@ -66,6 +67,12 @@ impl Default for SubscriptionId {
     }
 }
 
+impl std::fmt::Display for SubscriptionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Monitoring trigger definitions.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -108,6 +115,107 @@ pub enum Trigger {
         entity_id: EntityId,
         predicate: String,
     },
+
+    /// Asserted value satisfies a matcher.
+    ValueMatch {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        entity_filter: Option<EntityId>,
+        predicate: String,
+        matcher: ValueMatcher,
+    },
+
+    /// At least `count` asserts on `predicate` within `window_seconds` of transaction time.
+    ///
+    /// Fires once, on the assert that reaches `count`, and re-arms only after the window
+    /// has emptied of asserts on the predicate.
+    Rate {
+        predicate: String,
+        count: u32,
+        window_seconds: u64,
+    },
+}
+
+impl Trigger {
+    /// Check that the trigger can be evaluated (e.g. its regex compiles).
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> Result<(), MonitorEventError> {
+        match self {
+            Self::ValueMatch { predicate, matcher, .. } => {
+                if predicate.trim().is_empty() {
+                    return Err(MonitorEventError::InvalidMatcher {
+                        reason: "value_match predicate must not be empty".to_string(),
+                    });
+                }
+                matcher.validate()
+            }
+            Self::Rate {
+                predicate,
+                count,
+                window_seconds,
+            } => {
+                let reason = if predicate.trim().is_empty() {
+                    "rate predicate must not be empty"
+                } else if *count == 0 {
+                    "rate count must be at least 1"
+                } else if *window_seconds == 0 {
+                    "rate window_seconds must be at least 1"
+                } else {
+                    return Ok(());
+                };
+                Err(MonitorEventError::InvalidTrigger {
+                    reason: reason.to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Condition evaluated against an asserted value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ValueMatcher {
+    /// Value equals `value` exactly.
+    Equals {
+        /// Expected value.
+        value: Value,
+    },
+    /// Numeric value (int or float) compares against `threshold`.
+    Compare {
+        /// Comparison operator, applied as `value <op> threshold`.
+        cmp: ComparisonOp,
+        /// Right-hand side of the comparison.
+        threshold: f64,
+    },
+    /// String value matches a regular expression.
+    Regex {
+        /// Regex pattern (Rust `regex` syntax).
+        pattern: String,
+    },
+}
+
+impl ValueMatcher {
+    /// Check that the matcher is well-formed.
+    #[allow(clippy::result_large_err)]
+    pub fn validate(&self) -> Result<(), MonitorEventError> {
+        match self {
+            Self::Equals { .. } => Ok(()),
+            Self::Compare { threshold, .. } => {
+                if threshold.is_finite() {
+                    Ok(())
+                } else {
+                    Err(MonitorEventError::InvalidMatcher {
+                        reason: format!("comparison threshold must be finite, got {threshold}"),
+                    })
+                }
+            }
+            Self::Regex { pattern } => regex::Regex::new(pattern).map(|_| ()).map_err(|e| {
+                MonitorEventError::InvalidMatcher {
+                    reason: format!("invalid regex '{pattern}': {e}"),
+                }
+            }),
+        }
+    }
 }
 
 /// Event payload emitted when a trigger fires.
@@ -165,6 +273,23 @@ pub enum EventPayload {
         entity_id: EntityId,
         predicate: String,
     },
+
+    /// Value match details.
+    ValueMatch {
+        belief_id: BeliefId,
+        entity_id: EntityId,
+        predicate: String,
+        value: Value,
+    },
+
+    /// Rate details; `belief_id` is the assert that reached the count.
+    Rate {
+        belief_id: BeliefId,
+        entity_id: EntityId,
+        predicate: String,
+        count: u32,
+        window_seconds: u64,
+    },
 }
 
 /// A fired monitoring event.
@@ -176,6 +301,10 @@ pub struct MonitorEvent {
     pub trigger_type: Trigger,
     pub timestamp: DateTime<Utc>,
     pub payload: EventPayload,
+    /// Position of this event in its subscription, starting at 1; acknowledge it with
+    /// `MonitorStream::ack`. Zero until the dispatcher delivers the event.
+    #[serde(default)]
+    pub sequence: u64,
 }
 
 /// Errors constructing monitor events.
@@ -184,23 +313,33 @@ pub enum MonitorEventError {
     /// The trigger type and event payload are inconsistent.
     #[error("trigger/payload mismatch: trigger={trigger:?} payload={payload:?}")]
     TriggerPayloadMismatch { trigger: Trigger, payload: EventPayload },
+
+    /// A trigger's value matcher cannot be evaluated.
+    #[error("invalid value matcher: {reason}")]
+    InvalidMatcher { reason: String },
+
+    /// A trigger's parameters are out of range.
+    #[error("invalid trigger: {reason}")]
+    InvalidTrigger { reason: String },
 }
 
 impl MonitorEvent {
-    #[must_use]
+    #[allow(clippy::result_large_err)]
     pub fn new(
         trigger_id: TriggerId,
         trigger_type: Trigger,
         payload: EventPayload,
     ) -> Result<Self, MonitorEventError> {
-        let ok = match (&trigger_type, &payload) {
-            (Trigger::ConfidenceShift { .. }, EventPayload::ConfidenceShift { .. }) => true,
-            (Trigger::ConflictCreated { .. }, EventPayload::ConflictCreated { .. }) => true,
-            (Trigger::PatternViolation { .. }, EventPayload::PatternViolation { .. }) => true,
-            (Trigger::EntropySpike { .. }, EventPayload::EntropySpike { .. }) => true,
-            (Trigger::GapFilled { .. }, EventPayload::GapFilled { .. }) => true,
-            _ => false,
-        };
+        let ok = matches!(
+            (&trigger_type, &payload),
+            (Trigger::ConfidenceShift { .. }, EventPayload::ConfidenceShift { .. })
+                | (Trigger::ConflictCreated { .. }, EventPayload::ConflictCreated { .. })
+                | (Trigger::PatternViolation { .. }, EventPayload::PatternViolation { .. })
+                | (Trigger::EntropySpike { .. }, EventPayload::EntropySpike { .. })
+                | (Trigger::GapFilled { .. }, EventPayload::GapFilled { .. })
+                | (Trigger::ValueMatch { .. }, EventPayload::ValueMatch { .. })
+                | (Trigger::Rate { .. }, EventPayload::Rate { .. })
+        );
 
         if !ok {
             return Err(MonitorEventError::TriggerPayloadMismatch {
@@ -210,11 +349,12 @@ impl MonitorEvent {
         }
 
         Ok(Self {
-            event_id: Uuid::new_v4(),
+            event_id: crate::id::next_id(),
             trigger_id,
             trigger_type,
             timestamp: Utc::now(),
             payload,
+            sequence: 0,
         })
     }
 }
@@ -267,4 +407,52 @@ mod tests {
         assert_eq!(ev.trigger_type, trigger);
         assert_eq!(ev.payload, payload);
     }
+
+    #[test]
+    fn value_match_validation_rejects_bad_regex_and_empty_predicate() {
+        let bad_regex = Trigger::ValueMatch {
+            entity_filter: None,
+            predicate: "status".to_string(),
+            matcher: ValueMatcher::Regex {
+                pattern: "fail(".to_string(),
+            },
+        };
+        assert!(matches!(
+            bad_regex.validate(),
+            Err(MonitorEventError::InvalidMatcher { .. })
+        ));
+
+        let empty_predicate = Trigger::ValueMatch {
+            entity_filter: None,
+            predicate: " ".to_string(),
+            matcher: ValueMatcher::Equals { value: Value::Bool(true) },
+        };
+        assert!(empty_predicate.validate().is_err());
+
+        let ok = Trigger::ValueMatch {
+            entity_filter: None,
+            predicate: "status".to_string(),
+            matcher: ValueMatcher::Regex {
+                pattern: "^fail".to_string(),
+            },
+        };
+        assert!(ok.validate().is_ok());
+    }
+
+    #[test]
+    fn value_match_trigger_round_trips_through_json() {
+        let trigger = Trigger::ValueMatch {
+            entity_filter: Some(EntityId::new()),
+            predicate: "temperature".to_string(),
+            matcher: ValueMatcher::Compare {
+                cmp: ComparisonOp::Gt,
+                threshold: 30.0,
+            },
+        };
+        let json = serde_json::to_value(&trigger).unwrap();
+        assert_eq!(json["type"], "value_match");
+        assert_eq!(json["matcher"]["op"], "compare");
+        let back: Trigger = serde_json::from_value(json).unwrap();
+        assert_eq!(back, trigger);
+    }
 }
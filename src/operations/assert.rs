@@ -34,6 +34,8 @@ pub struct AssertBuilder {
     valid_time: Option<TimeRange>,
     consistency_mode: ConsistencyMode,
     embedding: Option<Vec<f32>>,
+    idempotency_key: Option<String>,
+    namespace: Option<String>,
 }
 
 impl AssertBuilder {
@@ -98,6 +100,20 @@ impl AssertBuilder {
         self
     }
 
+    /// Set a client-chosen key so retries of this ASSERT are applied once (optional).
+    #[must_use]
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Set the tenant namespace the entity belongs to (optional; default namespace otherwise).
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
     /// Build the ASSERT IR.
     ///
     /// Returns `ValidationError::MissingField` if any required field is not set.
@@ -149,6 +165,8 @@ impl AssertBuilder {
             valid_time,
             consistency_mode: self.consistency_mode,
             embedding: self.embedding,
+            idempotency_key: self.idempotency_key,
+            namespace: self.namespace,
         };
 
         Ok(KyroIR::new(Operation::Assert(payload)))
@@ -287,4 +305,15 @@ mod tests {
             panic!("Expected Assert operation");
         }
     }
+
+    #[test]
+    fn test_with_idempotency_key() {
+        let ir = valid_builder().idempotency_key("req-42").build().unwrap();
+
+        if let Operation::Assert(payload) = ir.operation {
+            assert_eq!(payload.idempotency_key.as_deref(), Some("req-42"));
+        } else {
+            panic!("Expected Assert operation");
+        }
+    }
 }
@@ -1,5 +1,7 @@
 //! Structured response types for RESOLVE.
 
+use std::fmt::Write as _;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -7,9 +9,11 @@ use crate::belief::Belief;
 use crate::confidence::BeliefId;
 use crate::conflict::Conflict;
 use crate::entity::EntityId;
+use crate::error::{KyroResult, ValidationError};
 use crate::inference::ConflictResolutionPolicy;
 use crate::source::Source;
 use crate::time::TimeRange;
+use crate::value::Value;
 
 /// A ranked claim with separate confidence and relevance scores.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +25,10 @@ pub struct RankedClaim {
     pub epistemic_confidence: f32,
 
     /// Retrieval relevance: Is this relevant to the query? (0.0 - 1.0)
+    ///
+    /// Semantic RESOLVE reports the ranking score of the top match,
+    /// `relevance_weight * similarity + (1 - relevance_weight) * trusted_confidence`
+    /// (plain similarity at the default weight of 1.0). Strict RESOLVE reports 1.0.
     pub retrieval_relevance: f32,
 
     /// Combined score (arithmetic mean of epistemic confidence and retrieval relevance)
@@ -95,6 +103,20 @@ pub enum GapType {
     InsufficientEvidence,
 }
 
+impl GapType {
+    /// Snake-case label, matching the serialized form.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoDataFound => "no_data_found",
+            Self::LowConfidenceOnly => "low_confidence_only",
+            Self::ExpiredData => "expired_data",
+            Self::MissingEntity => "missing_entity",
+            Self::InsufficientEvidence => "insufficient_evidence",
+        }
+    }
+}
+
 /// A detected gap in knowledge with actionable metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnowledgeGap {
@@ -187,10 +209,40 @@ impl Default for QueryAssumptions {
     }
 }
 
+/// How much detail `BeliefFrame::to_summary_with` includes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryVerbosity {
+    /// The answer, the strongest source, and conflict and gap counts.
+    Brief,
+    /// The answer, the top three sources, conflicts and gaps.
+    #[default]
+    Standard,
+    /// Everything in `Standard` without limits, plus counter-evidence and query assumptions.
+    Detailed,
+}
+
+impl SummaryVerbosity {
+    /// Maximum number of supporting sources and conflicts listed.
+    const fn item_limit(self) -> usize {
+        match self {
+            Self::Brief => 1,
+            Self::Standard => 3,
+            Self::Detailed => usize::MAX,
+        }
+    }
+}
+
 /// The structured response type for RESOLVE operations.
 /// Contains answer, evidence, conflicts, and gaps.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BeliefFrame {
+    /// Shape version of the serialized frame (see `BeliefFrame::SCHEMA_VERSION`).
+    ///
+    /// Frames serialized before versioning carry no field and read as version 1.
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+
     /// Primary answer (structured, not prose)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub best_supported_claim: Option<RankedClaim>,
@@ -201,7 +253,7 @@ pub struct BeliefFrame {
     /// Counter-evidence
     pub counter_evidence: Vec<Evidence>,
 
-    /// Detected conflicts
+    /// Detected conflicts, most severe first
     pub conflicts: Vec<Conflict>,
 
     /// Knowledge gaps
@@ -213,16 +265,62 @@ pub struct BeliefFrame {
     /// Assumptions made during execution
     pub query_assumptions: QueryAssumptions,
 
+    /// Confidence in the answer after discounting counter-evidence (0.0 - 1.0).
+    ///
+    /// Unlike `best_supported_claim.epistemic_confidence`, this aggregates every belief
+    /// backing the winning value and penalizes it by the opposing beliefs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epistemic_confidence: Option<f32>,
+
+    /// Range the answer's confidence plausibly lies in, as `(low, high)`.
+    ///
+    /// Each supporting belief bounds it by its trusted confidence and each contradicting
+    /// belief by one minus its trusted confidence; the band spans those bounds and
+    /// `epistemic_confidence`. Unanimous evidence gives a narrow band, disagreement a wide
+    /// one. Only set when requested with `ResolvePayload::include_confidence_interval`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence_interval: Option<(f32, f32)>,
+
+    /// The predicate whose beliefs answered, when `ResolvePayload::predicate_fallbacks`
+    /// were given: the primary predicate or the first fallback that had beliefs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answered_predicate: Option<String>,
+
+    /// Every revision of the queried claim, oldest first (`ResolveMode::History` only).
+    ///
+    /// Includes superseded beliefs, so this is the full record rather than what holds now.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<Belief>,
+
+    /// Probability of each candidate value, marginalized across the resolved beliefs.
+    ///
+    /// Set when at least one belief holds a `Value::Distribution`. Each belief contributes
+    /// its outcomes, or its value as a point mass, weighted by its trusted confidence;
+    /// outcomes are sorted most probable first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<Vec<(Value, f32)>>,
+
     /// For debugging only (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub debug_summary: Option<String>,
 }
 
+fn legacy_schema_version() -> u32 {
+    1
+}
+
 impl BeliefFrame {
+    /// Current frame schema version.
+    ///
+    /// - 1: unversioned frames, before `epistemic_confidence`
+    /// - 2: adds `schema_version` and `epistemic_confidence`
+    pub const SCHEMA_VERSION: u32 = 2;
+
     /// Create an empty belief frame.
     #[must_use]
     pub fn empty() -> Self {
         Self {
+            schema_version: Self::SCHEMA_VERSION,
             best_supported_claim: None,
             supporting_evidence: Vec::new(),
             counter_evidence: Vec::new(),
@@ -230,6 +328,11 @@ impl BeliefFrame {
             gaps: Vec::new(),
             time_window: TimeRange::from_now(),
             query_assumptions: QueryAssumptions::default(),
+            epistemic_confidence: None,
+            confidence_interval: None,
+            answered_predicate: None,
+            history: Vec::new(),
+            distribution: None,
             debug_summary: None,
         }
     }
@@ -251,6 +354,102 @@ impl BeliefFrame {
     pub fn has_gaps(&self) -> bool {
         !self.gaps.is_empty()
     }
+
+    /// Renders the frame as plain text at `SummaryVerbosity::Standard`.
+    ///
+    /// See [`BeliefFrame::to_summary_with`].
+    #[must_use]
+    pub fn to_summary(&self) -> String {
+        self.to_summary_with(SummaryVerbosity::Standard)
+    }
+
+    /// Renders the frame as compact, line-oriented text for LLM prompts.
+    ///
+    /// Output is deterministic for a given frame: sources are ordered by confidence (ties
+    /// keep frame order), conflicts keep their severity order, and numbers use two decimals.
+    #[must_use]
+    pub fn to_summary_with(&self, verbosity: SummaryVerbosity) -> String {
+        let mut out = String::new();
+        let limit = verbosity.item_limit();
+
+        match &self.best_supported_claim {
+            Some(claim) => {
+                let confidence = self.epistemic_confidence.unwrap_or(claim.epistemic_confidence);
+                let _ = writeln!(
+                    out,
+                    "Answer: {} = {} (confidence {confidence:.2})",
+                    claim.belief.predicate, claim.belief.value
+                );
+            }
+            None if self.has_conflicts() => {
+                let _ = writeln!(out, "Answer: unresolved; the evidence conflicts");
+            }
+            None => {
+                let _ = writeln!(out, "Answer: none");
+            }
+        }
+
+        let mut sources: Vec<&Evidence> = self.supporting_evidence.iter().collect();
+        sources.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        write_evidence(&mut out, "Supporting sources", &sources, limit);
+        if verbosity == SummaryVerbosity::Detailed {
+            let counter: Vec<&Evidence> = self.counter_evidence.iter().collect();
+            write_evidence(&mut out, "Counter-evidence", &counter, limit);
+        }
+
+        if self.has_conflicts() {
+            let _ = writeln!(out, "Conflicts: {}", self.conflicts.len());
+            if verbosity != SummaryVerbosity::Brief {
+                for conflict in self.conflicts.iter().take(limit) {
+                    let _ = writeln!(
+                        out,
+                        "- {} across {} beliefs (severity {:.2})",
+                        conflict.conflict_type,
+                        conflict.belief_count(),
+                        conflict.severity
+                    );
+                }
+            }
+        }
+
+        if self.has_gaps() {
+            let _ = writeln!(out, "Gaps: {}", self.gaps.len());
+            if verbosity != SummaryVerbosity::Brief {
+                for gap in &self.gaps {
+                    let _ = writeln!(out, "- {}: {}", gap.gap_type.as_str(), gap.description);
+                }
+            }
+        }
+
+        if verbosity == SummaryVerbosity::Detailed {
+            let assumptions = &self.query_assumptions;
+            let _ = writeln!(
+                out,
+                "Assumptions: as of {}, trust model {}, min confidence {}",
+                assumptions.as_of_time.to_rfc3339(),
+                assumptions.trust_model,
+                assumptions
+                    .min_confidence
+                    .map_or_else(|| "none".to_string(), |c| format!("{c:.2}"))
+            );
+        }
+
+        out.truncate(out.trim_end().len());
+        out
+    }
+}
+
+fn write_evidence(out: &mut String, heading: &str, evidence: &[&Evidence], limit: usize) {
+    if evidence.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "{heading}:");
+    for e in evidence.iter().take(limit) {
+        let _ = writeln!(out, "- {} (confidence {:.2})", e.source, e.confidence);
+    }
+    if evidence.len() > limit {
+        let _ = writeln!(out, "- and {} more", evidence.len() - limit);
+    }
 }
 
 impl Default for BeliefFrame {
@@ -259,6 +458,92 @@ impl Default for BeliefFrame {
     }
 }
 
+/// An entity that satisfied every condition of a compound RESOLVE.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompoundMatch {
+    /// The matching entity.
+    pub entity_id: EntityId,
+
+    /// The belief that satisfied each condition, in condition order.
+    pub beliefs: Vec<Belief>,
+}
+
+/// The structured response type for compound RESOLVE operations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompoundFrame {
+    /// Matching entities, ordered by entity ID.
+    pub matches: Vec<CompoundMatch>,
+
+    /// Point in time the conditions were evaluated at.
+    pub as_of: DateTime<Utc>,
+
+    /// True if more entities matched than `limit` allowed.
+    pub truncated: bool,
+}
+
+/// Read the schema version of a serialized frame without decoding the rest of it.
+///
+/// A missing field means version 1.
+pub fn schema_version_of(frame: &serde_json::Value) -> KyroResult<u32> {
+    let object = frame.as_object().ok_or_else(|| ValidationError::InvalidField {
+        field: "frame".to_string(),
+        reason: "expected a JSON object".to_string(),
+    })?;
+    match object.get("schema_version") {
+        None => Ok(legacy_schema_version()),
+        Some(version) => version
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| {
+                ValidationError::InvalidField {
+                    field: "schema_version".to_string(),
+                    reason: format!("expected an unsigned integer, got {version}"),
+                }
+                .into()
+            }),
+    }
+}
+
+/// Upgrade a serialized frame of any supported version to the current shape.
+///
+/// Intended for frames kept in audit logs. Each step upgrades one version, so a frame is
+/// walked forward until it reaches `BeliefFrame::SCHEMA_VERSION`. Frames from a newer
+/// schema are rejected rather than guessed at.
+pub fn migrate(mut frame: serde_json::Value) -> KyroResult<BeliefFrame> {
+    let mut version = schema_version_of(&frame)?;
+    if version == 0 || version > BeliefFrame::SCHEMA_VERSION {
+        return Err(ValidationError::InvalidField {
+            field: "schema_version".to_string(),
+            reason: format!(
+                "unsupported version {version} (current is {})",
+                BeliefFrame::SCHEMA_VERSION
+            ),
+        }
+        .into());
+    }
+
+    // `schema_version_of` has already rejected anything but an object.
+    if let serde_json::Value::Object(object) = &mut frame {
+        while version < BeliefFrame::SCHEMA_VERSION {
+            if version == 1 {
+                // v1 frames predate counter-evidence discounting; it cannot be recomputed
+                // from the serialized evidence, so the answer confidence stays unset.
+                object.remove("epistemic_confidence");
+            }
+            version += 1;
+            object.insert("schema_version".to_string(), version.into());
+        }
+    }
+
+    serde_json::from_value(frame).map_err(|e| {
+        ValidationError::InvalidField {
+            field: "frame".to_string(),
+            reason: e.to_string(),
+        }
+        .into()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,4 +595,118 @@ mod tests {
         assert!(back.has_answer());
         assert_eq!(back.supporting_evidence.len(), 1);
     }
+
+    /// A frame as serialized before schema versioning.
+    const V1_FRAME: &str = r#"{
+        "supporting_evidence": [{
+            "belief_id": "20c936e6-d14d-4b8d-9200-f3a8950faa71",
+            "summary": "status",
+            "source": {"type": "agent", "agent_id": "a"},
+            "confidence": 0.5,
+            "relevance": 1.0
+        }],
+        "counter_evidence": [],
+        "conflicts": [],
+        "gaps": [{"gap_type": "low_confidence_only", "description": "weak"}],
+        "time_window": {"from": "2025-01-01T00:00:00Z", "to": null},
+        "query_assumptions": {
+            "conflict_policy": {"type": "highest_confidence"},
+            "min_confidence": 0.3,
+            "trust_model": "default",
+            "as_of_time": "2025-01-01T00:00:00Z"
+        }
+    }"#;
+
+    #[test]
+    fn migrate_upgrades_v1_frame_to_current_version() {
+        let v1: serde_json::Value = serde_json::from_str(V1_FRAME).unwrap();
+        assert_eq!(schema_version_of(&v1).unwrap(), 1);
+
+        let frame = migrate(v1).unwrap();
+        assert_eq!(frame.schema_version, BeliefFrame::SCHEMA_VERSION);
+        assert_eq!(frame.supporting_evidence.len(), 1);
+        assert_eq!(frame.gaps[0].gap_type, GapType::LowConfidenceOnly);
+        assert_eq!(frame.query_assumptions.min_confidence, Some(0.3));
+        assert!(frame.epistemic_confidence.is_none());
+
+        // The migrated frame round-trips as a current frame, and migrating it again is a no-op.
+        let current = serde_json::to_value(&frame).unwrap();
+        assert_eq!(current["schema_version"], BeliefFrame::SCHEMA_VERSION);
+        let again = migrate(current).unwrap();
+        assert_eq!(again.schema_version, BeliefFrame::SCHEMA_VERSION);
+        assert_eq!(again.supporting_evidence[0].belief_id, frame.supporting_evidence[0].belief_id);
+    }
+
+    #[test]
+    fn migrate_rejects_unknown_versions() {
+        let mut frame = serde_json::to_value(BeliefFrame::empty()).unwrap();
+        for bad in [serde_json::json!(0), serde_json::json!(3), serde_json::json!("2")] {
+            frame["schema_version"] = bad;
+            assert!(migrate(frame.clone()).is_err());
+        }
+        assert!(migrate(serde_json::json!([])).is_err());
+    }
+
+    fn seeded_belief(value: Value, agent: &str, confidence: f32) -> Belief {
+        crate::belief::Belief::builder()
+            .subject(EntityId::new())
+            .predicate("status")
+            .value(value)
+            .confidence(Confidence::from_agent(confidence, agent).unwrap())
+            .source(Source::agent(agent, Option::<String>::None))
+            .valid_time(TimeRange::from_now())
+            .build()
+            .unwrap()
+    }
+
+    fn evidence(belief: &Belief) -> Evidence {
+        Evidence::new(belief.id, "status", belief.source.clone(), belief.confidence.value(), 1.0)
+    }
+
+    #[test]
+    fn summary_lists_answer_sources_conflicts_and_gaps() {
+        let weak = seeded_belief(Value::String("active".to_string()), "a", 0.6);
+        let strong = seeded_belief(Value::String("active".to_string()), "b", 0.9);
+        let other = seeded_belief(Value::String("dissolved".to_string()), "c", 0.4);
+
+        let mut frame = BeliefFrame::empty();
+        frame.best_supported_claim = Some(RankedClaim::new(strong.clone(), 0.9, 1.0));
+        frame.epistemic_confidence = Some(0.75);
+        frame.supporting_evidence = vec![evidence(&weak), evidence(&strong)];
+        frame.counter_evidence = vec![evidence(&other)];
+        frame.conflicts.push(
+            Conflict::value_contradiction(vec![strong.id, other.id], strong.subject, "status").with_severity(0.4),
+        );
+        frame.gaps.push(KnowledgeGap::new(GapType::LowConfidenceOnly, "c is weak"));
+
+        let summary = frame.to_summary();
+        assert_eq!(summary, frame.to_summary());
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines[0], "Answer: status = \"active\" (confidence 0.75)");
+        assert_eq!(lines[1], "Supporting sources:");
+        assert!(lines[2].contains("0.90") && lines[3].contains("0.60"), "{summary}");
+        assert!(summary.contains("Conflicts: 1\n- value_contradiction(status) across 2 beliefs (severity 0.40)"));
+        assert!(summary.contains("- low_confidence_only: c is weak"));
+        assert!(!summary.contains("Counter-evidence"));
+
+        let brief = frame.to_summary_with(SummaryVerbosity::Brief);
+        assert!(brief.contains("and 1 more") && brief.contains("Conflicts: 1"));
+        assert!(!brief.contains("value_contradiction"));
+
+        let detailed = frame.to_summary_with(SummaryVerbosity::Detailed);
+        assert!(detailed.contains("Counter-evidence:") && detailed.contains("Assumptions:"));
+    }
+
+    #[test]
+    fn summary_handles_frames_without_an_answer() {
+        assert_eq!(BeliefFrame::empty().to_summary(), "Answer: none");
+
+        let a = seeded_belief(Value::Bool(true), "a", 0.8);
+        let b = seeded_belief(Value::Bool(false), "b", 0.8);
+        let mut frame = BeliefFrame::empty();
+        frame.conflicts.push(Conflict::value_contradiction(vec![a.id, b.id], a.subject, "status"));
+        let summary = frame.to_summary();
+        assert!(summary.starts_with("Answer: unresolved"));
+        assert!(summary.contains("value_contradiction(status)"));
+    }
 }
@@ -17,6 +17,7 @@ pub struct DeriveBuilder {
     confidence: Option<f32>,
     justification: Option<String>,
     metadata: Option<serde_json::Value>,
+    namespace: Option<String>,
 }
 
 impl DeriveBuilder {
@@ -89,6 +90,13 @@ impl DeriveBuilder {
         self
     }
 
+    /// Set the tenant namespace the beliefs belong to (optional; default namespace otherwise).
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
     /// Build the DERIVE IR.
     pub fn build(self) -> Result<KyroIR, ValidationError> {
         let payload = DerivePayload {
@@ -103,6 +111,7 @@ impl DeriveBuilder {
             confidence: self.confidence,
             justification: self.justification,
             metadata: self.metadata,
+            namespace: self.namespace,
         };
 
         // Match the runtime contract: builders must validate before producing IR.
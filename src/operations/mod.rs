@@ -7,6 +7,7 @@ mod assert;
 mod assert_builder;
 mod resolve;
 mod resolve_builder;
+mod resolve_compound;
 mod simulate;
 mod simulate_builder;
 mod derive;
@@ -16,5 +17,6 @@ pub mod belief_frame;
 
 pub use assert_builder::AssertBuilder;
 pub use resolve_builder::ResolveBuilder;
+pub use resolve_compound::CompoundResolveBuilder;
 pub use simulate_builder::SimulateBuilder;
 pub use derive_builder::DeriveBuilder;
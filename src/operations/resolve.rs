@@ -5,10 +5,12 @@
 
 use chrono::{DateTime, Utc};
 
+use crate::confidence::SourceId;
 use crate::entity::EntityId;
 use crate::error::ValidationError;
-use crate::inference::ConflictResolutionPolicy;
-use crate::ir::{KyroIR, Operation, ResolveMode, ResolvePayload};
+use crate::inference::{ConfidenceAggregation, ConflictResolutionPolicy, EvidenceCombination, TieBreak};
+use crate::ir::{EntityResolutionConfig, KyroIR, Operation, ResolveMode, ResolvePayload};
+use crate::value::Value;
 
 /// Builder for RESOLVE operations.
 ///
@@ -25,6 +27,7 @@ use crate::ir::{KyroIR, Operation, ResolveMode, ResolvePayload};
 pub struct ResolveBuilder {
     query: Option<String>,
     query_embedding: Option<Vec<f32>>,
+    semantic: bool,
     entity_id: Option<EntityId>,
     predicate: Option<String>,
     mode: ResolveMode,
@@ -33,8 +36,21 @@ pub struct ResolveBuilder {
     limit: Option<usize>,
     include_counter_evidence: bool,
     include_gaps: bool,
+    include_confidence_interval: bool,
+    predicate_fallbacks: Vec<String>,
     conflict_policy: Option<ConflictResolutionPolicy>,
     trust_domain: Option<String>,
+    evidence_combination: Option<EvidenceCombination>,
+    confidence_aggregation: Option<ConfidenceAggregation>,
+    value_filter: Option<Value>,
+    tie_break: Option<TieBreak>,
+    relevance_weight: f32,
+    min_relevance: Option<f32>,
+    min_support: usize,
+    include_sources: Vec<SourceId>,
+    exclude_sources: Vec<SourceId>,
+    entity_resolution: Option<EntityResolutionConfig>,
+    namespace: Option<String>,
 }
 
 impl Default for ResolveBuilder {
@@ -42,6 +58,7 @@ impl Default for ResolveBuilder {
         Self {
             query: None,
             query_embedding: None,
+            semantic: false,
             entity_id: None,
             predicate: None,
             mode: ResolveMode::Simple,
@@ -50,8 +67,21 @@ impl Default for ResolveBuilder {
             limit: None,
             include_counter_evidence: false,
             include_gaps: true,
+            include_confidence_interval: false,
+            predicate_fallbacks: Vec::new(),
             conflict_policy: None,
             trust_domain: None,
+            evidence_combination: None,
+            confidence_aggregation: None,
+            value_filter: None,
+            tie_break: None,
+            relevance_weight: 1.0,
+            min_relevance: None,
+            min_support: 0,
+            include_sources: Vec::new(),
+            exclude_sources: Vec::new(),
+            entity_resolution: None,
+            namespace: None,
         }
     }
 }
@@ -76,6 +106,16 @@ impl ResolveBuilder {
         self
     }
 
+    /// Search semantically for `query`, which the engine embeds with its own embedder.
+    ///
+    /// Without this, a query lacking an explicit embedding gets a client-side lexical one.
+    #[must_use]
+    pub fn semantic_query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self.semantic = true;
+        self
+    }
+
     /// Filter results to a specific entity (optional).
     #[must_use]
     pub fn entity(mut self, id: EntityId) -> Self {
@@ -90,6 +130,15 @@ impl ResolveBuilder {
         self
     }
 
+    /// Try `predicate` next if the ones before it have no beliefs for the entity.
+    ///
+    /// Fallbacks are tried in the order they were added, after the primary predicate.
+    #[must_use]
+    pub fn predicate_fallback(mut self, predicate: impl Into<String>) -> Self {
+        self.predicate_fallbacks.push(predicate.into());
+        self
+    }
+
     /// Select the RESOLVE mode.
     ///
     /// This is a routing hint for execution-path selection (Reflex vs Reflection).
@@ -128,6 +177,13 @@ impl ResolveBuilder {
         self
     }
 
+    /// Report an uncertainty band around the answer's confidence (default: false).
+    #[must_use]
+    pub fn include_confidence_interval(mut self) -> Self {
+        self.include_confidence_interval = true;
+        self
+    }
+
     /// Exclude knowledge gaps from the response (default: include).
     #[must_use]
     pub fn exclude_gaps(mut self) -> Self {
@@ -149,11 +205,92 @@ impl ResolveBuilder {
         self
     }
 
+    /// Select how counter-evidence discounts the frame's epistemic confidence.
+    #[must_use]
+    pub fn evidence_combination(mut self, combination: EvidenceCombination) -> Self {
+        self.evidence_combination = Some(combination);
+        self
+    }
+
+    /// Select how agreeing beliefs combine into the winning claim's confidence.
+    #[must_use]
+    pub fn confidence_aggregation(mut self, aggregation: ConfidenceAggregation) -> Self {
+        self.confidence_aggregation = Some(aggregation);
+        self
+    }
+
+    /// Only gather support for this value; other values become counter-evidence.
+    #[must_use]
+    pub fn value_filter(mut self, value: impl Into<Value>) -> Self {
+        self.value_filter = Some(value.into());
+        self
+    }
+
+    /// Select how equally scored beliefs are ordered.
+    #[must_use]
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = Some(tie_break);
+        self
+    }
+
+    /// Blend similarity with trusted confidence when ranking semantic matches.
+    ///
+    /// `1.0` ranks by similarity only; `0.0` ranks by trusted confidence only.
+    #[must_use]
+    pub fn relevance_weight(mut self, weight: f32) -> Self {
+        self.relevance_weight = weight;
+        self
+    }
+
+    /// Ignore semantic matches less similar to the query than `threshold`.
+    #[must_use]
+    pub fn min_relevance(mut self, threshold: f32) -> Self {
+        self.min_relevance = Some(threshold);
+        self
+    }
+
+    /// Only answer when at least `count` beliefs back the winning value.
+    #[must_use]
+    pub fn min_support(mut self, count: usize) -> Self {
+        self.min_support = count;
+        self
+    }
+
+    /// Only consider beliefs from this source (repeatable).
+    #[must_use]
+    pub fn include_source(mut self, source: SourceId) -> Self {
+        self.include_sources.push(source);
+        self
+    }
+
+    /// Ignore beliefs from this source entirely (repeatable).
+    #[must_use]
+    pub fn exclude_source(mut self, source: SourceId) -> Self {
+        self.exclude_sources.push(source);
+        self
+    }
+
+    /// Gate the resolution of `query` to an entity with `config` instead of the defaults.
+    #[must_use]
+    pub fn entity_resolution(mut self, config: EntityResolutionConfig) -> Self {
+        self.entity_resolution = Some(config);
+        self
+    }
+
+    /// Only see entities and beliefs of this tenant namespace (optional; default namespace otherwise).
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
     /// Build the RESOLVE IR.
     ///
     /// Returns `ValidationError` if:
     /// - No query, entity, or predicate is specified (at least one required)
     /// - min_confidence is out of range [0.0, 1.0]
+    /// - relevance_weight is out of range [0.0, 1.0]
+    /// - min_relevance is out of range [0.0, 1.0]
     pub fn build(self) -> Result<KyroIR, ValidationError> {
         // At least one filter must be specified
         if self.query.is_none()
@@ -174,10 +311,28 @@ impl ResolveBuilder {
             }
         }
 
+        if !(0.0..=1.0).contains(&self.relevance_weight) {
+            return Err(ValidationError::InvalidField {
+                field: "relevance_weight".to_string(),
+                reason: format!("must be within [0.0, 1.0], got {}", self.relevance_weight),
+            });
+        }
+
+        if let Some(min_relevance) = self.min_relevance {
+            if !(0.0..=1.0).contains(&min_relevance) {
+                return Err(ValidationError::InvalidField {
+                    field: "min_relevance".to_string(),
+                    reason: format!("must be within [0.0, 1.0], got {min_relevance}"),
+                });
+            }
+        }
+
         // If the caller provided a query but no embedding, generate a deterministic lexical embedding.
         let query_embedding = match (self.query.as_deref(), self.query_embedding) {
             (_, Some(v)) => Some(v),
-            (Some(q), None) if !q.trim().is_empty() => Some(crate::embedding::lexical_embedding(q)),
+            (Some(q), None) if !self.semantic && !q.trim().is_empty() => {
+                Some(crate::embedding::lexical_embedding(q))
+            }
             _ => None,
         };
 
@@ -185,6 +340,7 @@ impl ResolveBuilder {
             mode: self.mode,
             query: self.query,
             query_embedding,
+            semantic: self.semantic,
             entity_id: self.entity_id,
             predicate: self.predicate,
             as_of: self.as_of,
@@ -192,8 +348,21 @@ impl ResolveBuilder {
             limit: self.limit.unwrap_or(10),
             include_counter_evidence: self.include_counter_evidence,
             include_gaps: self.include_gaps,
+            include_confidence_interval: self.include_confidence_interval,
+            predicate_fallbacks: self.predicate_fallbacks,
             conflict_policy: self.conflict_policy,
             trust_domain: self.trust_domain,
+            evidence_combination: self.evidence_combination,
+            confidence_aggregation: self.confidence_aggregation,
+            value_filter: self.value_filter,
+            tie_break: self.tie_break,
+            relevance_weight: self.relevance_weight,
+            min_relevance: self.min_relevance,
+            min_support: self.min_support,
+            include_sources: self.include_sources,
+            exclude_sources: self.exclude_sources,
+            entity_resolution: self.entity_resolution,
+            namespace: self.namespace,
         };
 
         Ok(KyroIR::new(Operation::Resolve(payload)))
@@ -308,6 +477,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_value_filter() {
+        let ir = ResolveBuilder::new()
+            .predicate("is_superconductor")
+            .value_filter(true)
+            .build()
+            .unwrap();
+
+        if let Operation::Resolve(payload) = ir.operation {
+            assert_eq!(payload.value_filter, Some(Value::Bool(true)));
+        } else {
+            panic!("Expected Resolve operation");
+        }
+    }
+
+    #[test]
+    fn test_tie_break() {
+        let ir = ResolveBuilder::new()
+            .predicate("status")
+            .tie_break(TieBreak::Lexical)
+            .build()
+            .unwrap();
+
+        if let Operation::Resolve(payload) = ir.operation {
+            assert_eq!(payload.tie_break, Some(TieBreak::Lexical));
+        } else {
+            panic!("Expected Resolve operation");
+        }
+    }
+
+    #[test]
+    fn test_relevance_weight_range() {
+        let ir = ResolveBuilder::new()
+            .predicate("status")
+            .relevance_weight(0.3)
+            .build()
+            .unwrap();
+        if let Operation::Resolve(payload) = ir.operation {
+            assert_eq!(payload.relevance_weight, 0.3);
+        } else {
+            panic!("Expected Resolve operation");
+        }
+
+        let result = ResolveBuilder::new()
+            .predicate("status")
+            .relevance_weight(1.5)
+            .build();
+        assert!(matches!(result, Err(ValidationError::InvalidField { .. })));
+    }
+
+    #[test]
+    fn test_min_relevance_range() {
+        let ir = ResolveBuilder::new()
+            .query("status")
+            .min_relevance(0.7)
+            .build()
+            .unwrap();
+        if let Operation::Resolve(payload) = ir.operation {
+            assert_eq!(payload.min_relevance, Some(0.7));
+        } else {
+            panic!("Expected Resolve operation");
+        }
+
+        let result = ResolveBuilder::new().query("status").min_relevance(-0.1).build();
+        assert!(matches!(result, Err(ValidationError::InvalidField { .. })));
+    }
+
+    #[test]
+    fn test_source_filters() {
+        let (kept, dropped) = (SourceId::new(), SourceId::new());
+        let ir = ResolveBuilder::new()
+            .query("status")
+            .include_source(kept)
+            .exclude_source(dropped)
+            .build()
+            .unwrap();
+
+        if let Operation::Resolve(payload) = ir.operation {
+            assert_eq!(payload.include_sources, vec![kept]);
+            assert_eq!(payload.exclude_sources, vec![dropped]);
+        } else {
+            panic!("Expected Resolve operation");
+        }
+    }
+
     #[test]
     fn test_exclude_gaps() {
         let ir = ResolveBuilder::new()
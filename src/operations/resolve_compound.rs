@@ -0,0 +1,156 @@
+//! Compound RESOLVE operation builder.
+//!
+//! The CompoundResolveBuilder expresses "entities where predicate_a = x AND predicate_b > y".
+//! Each condition tests the belief the default conflict policy selects for that predicate.
+
+use chrono::{DateTime, Utc};
+
+use crate::error::ValidationError;
+use crate::ir::{CompoundCondition, KyroIR, Operation, ResolveCompoundPayload};
+use crate::monitor::{ComparisonOp, ValueMatcher};
+use crate::value::Value;
+
+/// Builder for compound RESOLVE operations.
+///
+/// # Example
+/// ```rust,ignore
+/// let ir = CompoundResolveBuilder::new()
+///     .where_eq("status", "active")
+///     .where_compare("temperature", ComparisonOp::Gt, 30.0)
+///     .limit(20)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CompoundResolveBuilder {
+    conditions: Vec<CompoundCondition>,
+    as_of: Option<DateTime<Utc>>,
+    min_confidence: Option<f32>,
+    limit: Option<usize>,
+    namespace: Option<String>,
+}
+
+impl CompoundResolveBuilder {
+    /// Creates a new builder with no conditions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require `predicate` to satisfy `matcher`.
+    #[must_use]
+    pub fn condition(mut self, predicate: impl Into<String>, matcher: ValueMatcher) -> Self {
+        self.conditions.push(CompoundCondition {
+            predicate: predicate.into(),
+            matcher,
+        });
+        self
+    }
+
+    /// Require `predicate` to equal `value`.
+    #[must_use]
+    pub fn where_eq(self, predicate: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.condition(predicate, ValueMatcher::Equals { value: value.into() })
+    }
+
+    /// Require the numeric value of `predicate` to compare against `threshold`.
+    #[must_use]
+    pub fn where_compare(self, predicate: impl Into<String>, cmp: ComparisonOp, threshold: f64) -> Self {
+        self.condition(predicate, ValueMatcher::Compare { cmp, threshold })
+    }
+
+    /// Require the string value of `predicate` to match a regular expression.
+    #[must_use]
+    pub fn where_matches(self, predicate: impl Into<String>, pattern: impl Into<String>) -> Self {
+        self.condition(predicate, ValueMatcher::Regex { pattern: pattern.into() })
+    }
+
+    /// Evaluate as of a specific point in time (optional).
+    #[must_use]
+    pub fn as_of(mut self, time: DateTime<Utc>) -> Self {
+        self.as_of = Some(time);
+        self
+    }
+
+    /// Ignore beliefs below this confidence (0.0 to 1.0).
+    #[must_use]
+    pub fn min_confidence(mut self, confidence: f32) -> Self {
+        self.min_confidence = Some(confidence);
+        self
+    }
+
+    /// Set maximum number of entities returned (default: 10).
+    #[must_use]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only see entities and beliefs of this tenant namespace (optional; default namespace otherwise).
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Build the compound RESOLVE IR.
+    ///
+    /// Returns `ValidationError` if there are no conditions, a condition is malformed,
+    /// or `min_confidence`/`limit` is out of range.
+    pub fn build(self) -> Result<KyroIR, ValidationError> {
+        let payload = ResolveCompoundPayload {
+            conditions: self.conditions,
+            as_of: self.as_of,
+            min_confidence: self.min_confidence,
+            limit: self.limit.unwrap_or(10),
+            namespace: self.namespace,
+        };
+
+        payload.validate()?;
+
+        Ok(KyroIR::new(Operation::ResolveCompound(payload)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compound_builder_requires_a_condition() {
+        assert!(matches!(
+            CompoundResolveBuilder::new().build(),
+            Err(ValidationError::MissingField { .. })
+        ));
+    }
+
+    #[test]
+    fn compound_builder_collects_conditions_in_order() {
+        let ir = CompoundResolveBuilder::new()
+            .where_eq("status", "active")
+            .where_compare("temperature", ComparisonOp::Gt, 30.0)
+            .limit(5)
+            .build()
+            .unwrap();
+
+        let Operation::ResolveCompound(payload) = ir.operation else {
+            panic!("Expected ResolveCompound operation");
+        };
+        assert_eq!(payload.limit, 5);
+        assert_eq!(payload.conditions.len(), 2);
+        assert_eq!(payload.conditions[0].predicate, "status");
+        assert_eq!(
+            payload.conditions[1].matcher,
+            ValueMatcher::Compare {
+                cmp: ComparisonOp::Gt,
+                threshold: 30.0
+            }
+        );
+    }
+
+    #[test]
+    fn compound_builder_rejects_bad_regex() {
+        let result = CompoundResolveBuilder::new()
+            .where_matches("name", "(unclosed")
+            .build();
+        assert!(matches!(result, Err(ValidationError::InvalidField { .. })));
+    }
+}
@@ -126,6 +126,7 @@ mod tests {
                 max_affected_entities: 5,
                 max_depth: 1,
                 max_duration_ms: 10,
+                seed: None,
             })
             .build()
             .unwrap();
@@ -12,6 +12,7 @@ use uuid::Uuid;
 use crate::confidence::Confidence;
 use crate::entity::EntityType;
 use crate::time::TimeRange;
+use crate::value::ComparisonOp;
 
 /// Unique identifier for a pattern.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -22,7 +23,7 @@ impl PatternId {
     /// Creates a new random pattern ID.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(crate::id::next_id())
     }
 }
 
@@ -50,9 +51,15 @@ pub enum PatternRule {
         min: Option<f64>,
         /// Maximum value (inclusive).
         max: Option<f64>,
+        /// Whether numeric strings are parsed before checking (see `Value::coerce_float`).
+        #[serde(default)]
+        coerce: bool,
     },
 
-    /// Only one value allowed per entity.
+    /// Only one value allowed per entity at any instant.
+    ///
+    /// Beliefs whose valid times do not overlap do not violate the rule, and neither do
+    /// superseded ones, including those an ASSERT in `Replace` mode supersedes.
     Unique {
         /// Predicate that must be unique.
         predicate: String,
@@ -76,6 +83,17 @@ pub enum PatternRule {
         direction: MonotonicDirection,
     },
 
+    /// Confidence must change monotonically, e.g. never drop for a cumulative counter.
+    ///
+    /// Compares each new belief's confidence with that of the latest prior belief on the
+    /// predicate, whatever their values.
+    MonotonicConfidence {
+        /// Predicate to check.
+        predicate: String,
+        /// Required direction.
+        direction: MonotonicDirection,
+    },
+
     /// Value must be one of allowed set.
     Enumerated {
         /// Predicate to check.
@@ -106,9 +124,48 @@ pub enum PatternRule {
         predicates: Vec<String>,
     },
 
-    /// Custom rule with expression.
+    /// Numeric value must stand in `relation` to the entity's latest `other_predicate` value.
+    ///
+    /// For example `temperature < boiling_point`. No-op when the other side has no belief.
+    CrossPredicateOrder {
+        /// Left-hand predicate.
+        predicate: String,
+        /// Right-hand predicate on the same entity.
+        other_predicate: String,
+        /// Required relation `predicate <relation> other_predicate`.
+        relation: ComparisonOp,
+    },
+
+    /// A field inside a structured (JSON) value must meet `expected`.
+    ///
+    /// Non-structured values violate the rule. A value without the field violates it only
+    /// when `missing_is_violation` is set.
+    JsonPath {
+        /// Predicate to check.
+        predicate: String,
+        /// JSON pointer (RFC 6901) to the field, e.g. `/address/zip`.
+        path: String,
+        /// Requirement on the field's value.
+        expected: JsonExpectation,
+        /// Whether a value lacking the field is a violation (default: it passes).
+        #[serde(default)]
+        missing_is_violation: bool,
+    },
+
+    /// Value must be a reference to an entity that exists.
+    ///
+    /// Non-reference values violate the rule. A reference to an entity merged into another
+    /// one still resolves and passes.
+    ReferentialIntegrity {
+        /// Predicate to check, e.g. `employed_by`.
+        predicate: String,
+    },
+
+    /// Custom rule evaluated by the closure registered under `name`.
+    ///
+    /// See `KyroEngine::register_custom_rule`; defining a pattern for an unregistered name fails.
     Custom {
-        /// Rule name.
+        /// Name of the registered evaluator.
         name: String,
         /// Rule description.
         description: String,
@@ -125,6 +182,18 @@ impl PatternRule {
             predicate: predicate.into(),
             min,
             max,
+            coerce: false,
+        }
+    }
+
+    /// Creates a range pattern that also accepts numeric strings such as `"42.5"`.
+    #[must_use]
+    pub fn range_coerced(predicate: impl Into<String>, min: Option<f64>, max: Option<f64>) -> Self {
+        Self::Range {
+            predicate: predicate.into(),
+            min,
+            max,
+            coerce: true,
         }
     }
 
@@ -164,6 +233,15 @@ impl PatternRule {
         }
     }
 
+    /// Creates a pattern requiring confidence to move in `direction`.
+    #[must_use]
+    pub fn monotonic_confidence(predicate: impl Into<String>, direction: MonotonicDirection) -> Self {
+        Self::MonotonicConfidence {
+            predicate: predicate.into(),
+            direction,
+        }
+    }
+
     /// Creates an enumerated pattern.
     #[must_use]
     pub fn enumerated(predicate: impl Into<String>, allowed_values: Vec<String>) -> Self {
@@ -200,6 +278,43 @@ impl PatternRule {
         Self::MutuallyExclusive { predicates }
     }
 
+    /// Creates a cross-predicate order pattern (`predicate <relation> other_predicate`).
+    #[must_use]
+    pub fn cross_predicate_order(
+        predicate: impl Into<String>,
+        relation: ComparisonOp,
+        other_predicate: impl Into<String>,
+    ) -> Self {
+        Self::CrossPredicateOrder {
+            predicate: predicate.into(),
+            other_predicate: other_predicate.into(),
+            relation,
+        }
+    }
+
+    /// Creates a JSON path pattern; values missing the field pass.
+    #[must_use]
+    pub fn json_path(
+        predicate: impl Into<String>,
+        path: impl Into<String>,
+        expected: JsonExpectation,
+    ) -> Self {
+        Self::JsonPath {
+            predicate: predicate.into(),
+            path: path.into(),
+            expected,
+            missing_is_violation: false,
+        }
+    }
+
+    /// Creates a referential integrity pattern.
+    #[must_use]
+    pub fn referential_integrity(predicate: impl Into<String>) -> Self {
+        Self::ReferentialIntegrity {
+            predicate: predicate.into(),
+        }
+    }
+
     /// Returns the primary predicate this pattern applies to (if any).
     #[must_use]
     pub fn primary_predicate(&self) -> Option<&str> {
@@ -208,8 +323,12 @@ impl PatternRule {
             | Self::Unique { predicate }
             | Self::Cardinality { predicate, .. }
             | Self::Monotonic { predicate, .. }
+            | Self::MonotonicConfidence { predicate, .. }
             | Self::Enumerated { predicate, .. }
-            | Self::Regex { predicate, .. } => Some(predicate),
+            | Self::Regex { predicate, .. }
+            | Self::JsonPath { predicate, .. }
+            | Self::ReferentialIntegrity { predicate }
+            | Self::CrossPredicateOrder { predicate, .. } => Some(predicate),
             Self::Implication { if_predicate, .. } => Some(if_predicate),
             Self::MutuallyExclusive { predicates } => predicates.first().map(String::as_str),
             Self::Custom { .. } => None,
@@ -229,12 +348,20 @@ impl PatternRule {
             | Self::Unique { predicate }
             | Self::Cardinality { predicate, .. }
             | Self::Monotonic { predicate, .. }
+            | Self::MonotonicConfidence { predicate, .. }
             | Self::Enumerated { predicate, .. }
-            | Self::Regex { predicate, .. } => vec![predicate.as_str()],
+            | Self::Regex { predicate, .. }
+            | Self::JsonPath { predicate, .. }
+            | Self::ReferentialIntegrity { predicate } => vec![predicate.as_str()],
             Self::Implication {
                 if_predicate,
                 then_predicate,
             } => vec![if_predicate.as_str(), then_predicate.as_str()],
+            Self::CrossPredicateOrder {
+                predicate,
+                other_predicate,
+                ..
+            } => vec![predicate.as_str(), other_predicate.as_str()],
             Self::MutuallyExclusive { predicates } => predicates.iter().map(String::as_str).collect(),
             Self::Custom { .. } => Vec::new(),
         }
@@ -243,14 +370,14 @@ impl PatternRule {
     /// Returns true if this rule applies to the given predicate.
     #[must_use]
     pub fn matches_predicate(&self, predicate: &str) -> bool {
-        self.indexed_predicates().iter().any(|&p| p == predicate)
+        self.indexed_predicates().contains(&predicate)
     }
 }
 
 impl fmt::Display for PatternRule {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Range { predicate, min, max } => {
+            Self::Range { predicate, min, max, .. } => {
                 let min_str = min.map(|v| format!("{v}")).unwrap_or_else(|| "-∞".to_string());
                 let max_str = max.map(|v| format!("{v}")).unwrap_or_else(|| "∞".to_string());
                 write!(f, "range({predicate}: [{min_str}, {max_str}])")
@@ -265,6 +392,10 @@ impl fmt::Display for PatternRule {
                 predicate,
                 direction,
             } => write!(f, "monotonic({predicate}, {direction})"),
+            Self::MonotonicConfidence {
+                predicate,
+                direction,
+            } => write!(f, "monotonic_confidence({predicate}, {direction})"),
             Self::Enumerated {
                 predicate,
                 allowed_values,
@@ -277,11 +408,82 @@ impl fmt::Display for PatternRule {
             Self::MutuallyExclusive { predicates } => {
                 write!(f, "mutually_exclusive({:?})", predicates)
             }
+            Self::CrossPredicateOrder {
+                predicate,
+                other_predicate,
+                relation,
+            } => write!(f, "order({predicate} {relation} {other_predicate})"),
+            Self::JsonPath {
+                predicate,
+                path,
+                expected,
+                ..
+            } => write!(f, "json_path({predicate}{path}: {expected})"),
+            Self::ReferentialIntegrity { predicate } => write!(f, "references({predicate})"),
             Self::Custom { name, .. } => write!(f, "custom({name})"),
         }
     }
 }
 
+/// Requirement on the field selected by a `PatternRule::JsonPath`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JsonExpectation {
+    /// Field equals `value`; numbers compare by numeric value, so `1` equals `1.0`.
+    Equals {
+        /// Expected JSON value.
+        value: serde_json::Value,
+    },
+    /// Field is a number within the inclusive bounds.
+    Range {
+        /// Minimum value (inclusive).
+        min: Option<f64>,
+        /// Maximum value (inclusive).
+        max: Option<f64>,
+    },
+}
+
+impl JsonExpectation {
+    /// Returns a violation reason if `actual` does not meet this expectation.
+    #[must_use]
+    pub fn check(&self, actual: &serde_json::Value) -> Option<String> {
+        match self {
+            Self::Equals { value } => {
+                let equal = match (actual.as_f64(), value.as_f64()) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => actual == value,
+                };
+                (!equal).then(|| format!("{actual} does not equal {value}"))
+            }
+            Self::Range { min, max } => {
+                let Some(v) = actual.as_f64() else {
+                    return Some(format!("{actual} is not a number"));
+                };
+                if let Some(min) = min.filter(|min| v < *min) {
+                    return Some(format!("{v} is below min {min}"));
+                }
+                if let Some(max) = max.filter(|max| v > *max) {
+                    return Some(format!("{v} is above max {max}"));
+                }
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Display for JsonExpectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Equals { value } => write!(f, "= {value}"),
+            Self::Range { min, max } => {
+                let min_str = min.map(|v| format!("{v}")).unwrap_or_else(|| "-∞".to_string());
+                let max_str = max.map(|v| format!("{v}")).unwrap_or_else(|| "∞".to_string());
+                write!(f, "[{min_str}, {max_str}]")
+            }
+        }
+    }
+}
+
 /// Direction for monotonic patterns.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -401,6 +603,115 @@ impl PartialEq for Pattern {
     }
 }
 
+/// Patterns on one predicate that no value can satisfy together.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternSetWarning {
+    /// The patterns involved; a single pattern if its rule is unsatisfiable on its own.
+    pub pattern_ids: Vec<PatternId>,
+    /// Why they cannot all hold.
+    pub reason: String,
+}
+
+impl PatternSetWarning {
+    fn new(patterns: &[&Pattern], reason: String) -> Self {
+        Self {
+            pattern_ids: patterns.iter().map(|p| p.id).collect(),
+            reason,
+        }
+    }
+}
+
+/// Find obviously contradictory rules among `patterns` that apply to `predicate`.
+///
+/// This is a conservative check: it reports rules that are unsatisfiable on their own
+/// (empty bounds or allowed sets), `Range` and `Enumerated` rules whose accepted values do
+/// not overlap, and implications whose two sides are declared mutually exclusive. Patterns
+/// restricted to different entity types are never compared.
+#[must_use]
+pub fn find_contradictions(predicate: &str, patterns: &[Pattern]) -> Vec<PatternSetWarning> {
+    let predicate = predicate.trim();
+    let patterns: Vec<&Pattern> = patterns
+        .iter()
+        .filter(|p| p.rule.indexed_predicates().iter().any(|ip| ip.trim() == predicate))
+        .collect();
+
+    let mut warnings = Vec::new();
+    for (i, a) in patterns.iter().enumerate() {
+        if let Some(reason) = unsatisfiable(&a.rule) {
+            warnings.push(PatternSetWarning::new(&[a], reason));
+        }
+        for b in &patterns[i + 1..] {
+            let disjoint_domains = matches!((&a.domain, &b.domain), (Some(x), Some(y)) if x != y);
+            if disjoint_domains {
+                continue;
+            }
+            if let Some(reason) = contradiction(&a.rule, &b.rule).or_else(|| contradiction(&b.rule, &a.rule)) {
+                warnings.push(PatternSetWarning::new(&[a, b], reason));
+            }
+        }
+    }
+    warnings
+}
+
+/// Why `rule` rejects every value, if it does.
+fn unsatisfiable(rule: &PatternRule) -> Option<String> {
+    match rule {
+        PatternRule::Range {
+            min: Some(min),
+            max: Some(max),
+            ..
+        } if min > max => Some(format!("{rule} has min above max")),
+        PatternRule::Cardinality { min, max, .. } if min > max => Some(format!("{rule} has min above max")),
+        PatternRule::Enumerated { allowed_values, .. } if allowed_values.is_empty() => {
+            Some(format!("{rule} allows no values"))
+        }
+        _ => None,
+    }
+}
+
+/// Why no value satisfies both `a` and `b`, if none does. Not symmetric; try both orders.
+fn contradiction(a: &PatternRule, b: &PatternRule) -> Option<String> {
+    match (a, b) {
+        (
+            PatternRule::Range { min: a_min, max: a_max, .. },
+            PatternRule::Range { min: b_min, max: b_max, .. },
+        ) => {
+            let below = |max: &Option<f64>, min: &Option<f64>| matches!((max, min), (Some(max), Some(min)) if max < min);
+            (below(a_max, b_min) || below(b_max, a_min)).then(|| format!("{a} and {b} do not overlap"))
+        }
+        (
+            PatternRule::Enumerated { allowed_values: a_values, .. },
+            PatternRule::Enumerated { allowed_values: b_values, .. },
+        ) => (!a_values.is_empty() && !a_values.iter().any(|v| b_values.contains(v)))
+            .then(|| format!("{a} and {b} share no allowed value")),
+        (
+            PatternRule::Enumerated { allowed_values, .. },
+            PatternRule::Range { min, max, coerce, .. },
+        ) => {
+            // Enumerated only accepts strings, which a range accepts only when coercing.
+            let in_range = |v: f64| min.is_none_or(|min| v >= min) && max.is_none_or(|max| v <= max);
+            let any_accepted = *coerce
+                && allowed_values
+                    .iter()
+                    .filter_map(|v| v.trim().parse::<f64>().ok())
+                    .any(in_range);
+            (!allowed_values.is_empty() && !any_accepted).then(|| format!("no value allowed by {a} is within {b}"))
+        }
+        (
+            PatternRule::Implication {
+                if_predicate,
+                then_predicate,
+            },
+            PatternRule::MutuallyExclusive { predicates },
+        ) => {
+            let excluded = |p: &str| predicates.iter().any(|q| q.trim() == p.trim());
+            (excluded(if_predicate) && excluded(then_predicate))
+                .then(|| format!("{a} and {b} mean '{if_predicate}' can never be true"))
+        }
+        _ => None,
+    }
+}
+
 impl Eq for Pattern {}
 
 impl std::hash::Hash for Pattern {
@@ -433,6 +744,15 @@ mod tests {
         assert_eq!(pattern.primary_predicate(), Some("temperature"));
     }
 
+    #[test]
+    fn test_cross_predicate_order_indexes_both_predicates() {
+        let rule = PatternRule::cross_predicate_order("temperature", ComparisonOp::Lt, "boiling_point");
+        assert_eq!(rule.indexed_predicates(), vec!["temperature", "boiling_point"]);
+        assert_eq!(rule.to_string(), "order(temperature < boiling_point)");
+        assert!(ComparisonOp::Le.holds(1.0, 1.0));
+        assert!(!ComparisonOp::Gt.holds(1.0, 1.0));
+    }
+
     #[test]
     fn test_pattern_with_description() {
         let pattern = Pattern::new(
@@ -480,6 +800,22 @@ mod tests {
         assert!(display.contains("temp"));
     }
 
+    #[test]
+    fn test_json_expectation_compares_numbers_by_value() {
+        let eq = JsonExpectation::Equals { value: serde_json::json!(1) };
+        assert!(eq.check(&serde_json::json!(1.0)).is_none());
+        assert!(eq.check(&serde_json::json!("1")).is_some());
+
+        let range = JsonExpectation::Range { min: Some(0.0), max: Some(10.0) };
+        assert!(range.check(&serde_json::json!(10)).is_none());
+        assert!(range.check(&serde_json::json!(10.5)).is_some());
+        assert!(range.check(&serde_json::json!(null)).is_some());
+
+        let rule = PatternRule::json_path("address", "/zip", eq);
+        assert_eq!(rule.indexed_predicates(), vec!["address"]);
+        assert_eq!(format!("{rule}"), "json_path(address/zip: = 1)");
+    }
+
     #[test]
     fn test_pattern_rule_unique() {
         let rule = PatternRule::unique("email");
@@ -542,6 +878,36 @@ mod tests {
         assert_eq!(format!("{}", MonotonicDirection::Decreasing), "decreasing");
     }
 
+    #[test]
+    fn test_find_contradictions_flags_unsatisfiable_combinations() {
+        let conf = Confidence::from_agent(0.9, "test").unwrap();
+        let pattern = |rule| Pattern::new("p", rule, conf.clone());
+        let statuses = pattern(PatternRule::enumerated("level", vec!["low".to_string(), "high".to_string()]));
+        let numeric = pattern(PatternRule::range_coerced("level", Some(0.0), Some(10.0)));
+        let narrow = pattern(PatternRule::range("level", Some(20.0), None));
+        let implies = pattern(PatternRule::implication("level", "alarm"));
+        let exclusive = pattern(PatternRule::mutually_exclusive(vec!["alarm".to_string(), "level".to_string()]));
+
+        let warnings = find_contradictions(
+            "level",
+            &[statuses.clone(), numeric.clone(), narrow.clone(), implies.clone(), exclusive.clone()],
+        );
+        let pairs: Vec<_> = warnings.iter().map(|w| w.pattern_ids.clone()).collect();
+        assert_eq!(
+            pairs,
+            vec![
+                vec![statuses.id, numeric.id],
+                vec![statuses.id, narrow.id],
+                vec![numeric.id, narrow.id],
+                vec![implies.id, exclusive.id],
+            ]
+        );
+
+        let digits = pattern(PatternRule::enumerated("level", vec!["3".to_string(), "high".to_string()]));
+        assert!(find_contradictions("level", &[digits, numeric.clone()]).is_empty());
+        assert!(find_contradictions("other", &[statuses, numeric, narrow]).is_empty());
+    }
+
     #[test]
     fn test_pattern_serialization() {
         let pattern = Pattern::new(
@@ -13,6 +13,11 @@ pub struct SimulateConstraints {
     pub max_depth: usize,
     /// Maximum wall-clock duration for the simulation.
     pub max_duration_ms: u64,
+    /// Seed for the simulation's random source and identifier.
+    ///
+    /// Two simulations with the same seed, request ID and operations behave identically.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
 }
 
 impl Default for SimulateConstraints {
@@ -21,6 +26,7 @@ impl Default for SimulateConstraints {
             max_affected_entities: 1000,
             max_depth: 2,
             max_duration_ms: 500,
+            seed: None,
         }
     }
 }
@@ -60,16 +66,22 @@ mod tests {
 
     #[test]
     fn constraints_reject_zero_limits() {
-        let mut c = SimulateConstraints::default();
-        c.max_affected_entities = 0;
+        let c = SimulateConstraints {
+            max_affected_entities: 0,
+            ..SimulateConstraints::default()
+        };
         assert!(c.validate().is_err());
 
-        let mut c = SimulateConstraints::default();
-        c.max_depth = 0;
+        let c = SimulateConstraints {
+            max_depth: 0,
+            ..SimulateConstraints::default()
+        };
         assert!(c.validate().is_err());
 
-        let mut c = SimulateConstraints::default();
-        c.max_duration_ms = 0;
+        let c = SimulateConstraints {
+            max_duration_ms: 0,
+            ..SimulateConstraints::default()
+        };
         assert!(c.validate().is_err());
     }
 }
@@ -2,18 +2,19 @@
 
 use std::fmt;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::belief::Belief;
-use crate::confidence::BeliefId;
+use crate::belief::{Belief, ConsistencyStatus};
+use crate::confidence::{BeliefId, Confidence};
 use crate::derivation::{DerivationId};
 use crate::engine::{EngineResponse, KyroEngine};
-use crate::error::{ExecutionError, KyroError, KyroResult};
+use crate::error::{ErrorCode, ExecutionError, KyroError, KyroResult};
 use crate::frame::BeliefFrame;
-use crate::ir::{ConsistencyMode, DerivePayload, KyroIR, Operation, ResolvePayload};
+use crate::ir::{ConsistencyMode, DerivePayload, KyroIR, Operation, ResolvePayload, RetractPayload};
 use crate::storage::StorageError;
 use crate::storage::DerivationStore;
 
@@ -23,14 +24,36 @@ use crate::conflict::ConflictId;
 use super::constraints::SimulateConstraints;
 use super::delta_index::DeltaVectorIndex;
 use super::delta_store::DeltaStore;
+use super::rng::SimulationRng;
 use super::SimulationBaseStores;
 
+/// Namespace for simulation IDs derived from a seed.
+const SIMULATION_ID_NAMESPACE: Uuid = Uuid::from_bytes([
+    0x3c, 0x8e, 0x21, 0xd4, 0x7a, 0x55, 0x4f, 0x0b, 0x9d, 0x62, 0x1e, 0xa7, 0xc3, 0x48, 0xf9, 0x06,
+]);
+
 fn storage_err(err: StorageError) -> KyroError {
     KyroError::Execution(ExecutionError::Storage {
         message: err.to_string(),
     })
 }
 
+/// The ASSERT that commits the hypothetical `belief` into base storage.
+fn commit_payload(belief: Belief, mode: ConsistencyMode) -> crate::ir::AssertPayload {
+    crate::ir::AssertPayload {
+        entity_id: belief.subject,
+        predicate: belief.predicate,
+        value: belief.value,
+        confidence: belief.confidence,
+        source: belief.source,
+        valid_time: belief.valid_time,
+        consistency_mode: mode,
+        embedding: belief.embedding,
+        idempotency_key: None,
+        namespace: belief.namespace,
+    }
+}
+
 /// Summary of changes within a simulation overlay.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SimulationImpact {
@@ -55,6 +78,31 @@ pub struct SimulationCommitResult {
     pub belief_id_map: Vec<(BeliefId, BeliefId)>,
     /// All conflict IDs produced during commit (may be empty in strict/force modes).
     pub conflict_ids: Vec<ConflictId>,
+    /// Hypotheticals left uncommitted (`SimulationCommitMode::BestEffort` only).
+    #[serde(default)]
+    pub rejected: Vec<SimulationCommitRejection>,
+}
+
+/// A hypothetical belief that a best-effort commit could not write.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationCommitRejection {
+    /// Hypothetical belief ID (overlay).
+    pub belief_id: BeliefId,
+    /// Stable code of the error that rejected it.
+    pub error_code: ErrorCode,
+    /// Human-readable error message.
+    pub message: String,
+}
+
+/// How `SimulationContext::commit_overlay` handles a hypothetical that fails to commit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimulationCommitMode {
+    /// Stop at the first failure with `ExecutionError::SimulationPartialCommit`.
+    #[default]
+    AllOrNothing,
+    /// Commit every hypothetical that can be written and report the rest as rejected.
+    BestEffort,
 }
 
 /// Stable identifier for a simulation.
@@ -65,7 +113,16 @@ impl SimulationId {
     /// Create a new random simulation ID.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(crate::id::next_id())
+    }
+
+    /// Derive a simulation ID from a seed and the request that created the simulation.
+    #[must_use]
+    pub fn from_seed(seed: u64, request_id: Uuid) -> Self {
+        let mut name = [0_u8; 24];
+        name[..8].copy_from_slice(&seed.to_le_bytes());
+        name[8..].copy_from_slice(request_id.as_bytes());
+        Self(Uuid::new_v5(&SIMULATION_ID_NAMESPACE, &name))
     }
 }
 
@@ -98,6 +155,7 @@ pub struct SimulationContext {
     hypothetical_count: AtomicUsize,
     is_dropped: AtomicBool,
     is_committed: AtomicBool,
+    rng: Mutex<SimulationRng>,
 
     // Only root simulations retain a writable commit base.
     // Nested simulations are layered on top of parent overlays and must not be able to
@@ -133,16 +191,37 @@ impl fmt::Debug for SimulationContext {
 
 impl SimulationContext {
     /// Create a new simulation context.
+    ///
+    /// A seeded simulation derives its ID from the seed and a nil request ID.
     pub fn new(base: SimulationBaseStores, constraints: SimulateConstraints) -> KyroResult<Self> {
-        Self::new_internal(base, constraints, 0, None, true)
+        Self::for_request(base, constraints, Uuid::nil())
     }
 
+    /// Create a new simulation context on behalf of the request `request_id`.
+    ///
+    /// With `constraints.seed` set, the simulation ID is derived from the seed and `request_id`,
+    /// and the random source is seeded deterministically.
+    pub fn for_request(
+        base: SimulationBaseStores,
+        constraints: SimulateConstraints,
+        request_id: Uuid,
+    ) -> KyroResult<Self> {
+        let (id, rng) = match constraints.seed {
+            Some(seed) => (SimulationId::from_seed(seed, request_id), SimulationRng::from_seed(seed)),
+            None => (SimulationId::new(), SimulationRng::from_entropy()),
+        };
+        Self::new_internal(base, constraints, 0, None, true, id, rng)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn new_internal(
         base: SimulationBaseStores,
         constraints: SimulateConstraints,
         nesting_level: usize,
         deadline_cap: Option<Instant>,
         retain_commit_base: bool,
+        id: SimulationId,
+        rng: SimulationRng,
     ) -> KyroResult<Self> {
         constraints.validate().map_err(KyroError::from)?;
 
@@ -168,7 +247,7 @@ impl SimulationContext {
         };
 
         Ok(Self {
-            id: SimulationId::new(),
+            id,
             constraints,
             nesting_level,
             remaining_depth,
@@ -177,6 +256,7 @@ impl SimulationContext {
             hypothetical_count: AtomicUsize::new(0),
             is_dropped: AtomicBool::new(false),
             is_committed: AtomicBool::new(false),
+            rng: Mutex::new(rng),
             commit_base,
             delta_store: DeltaStore::new(base, constraints),
             delta_index: DeltaVectorIndex::new(),
@@ -213,7 +293,57 @@ impl SimulationContext {
             conflicts: self.delta_store.conflicts(),
         };
 
-        Self::new_internal(base, self.constraints, self.nesting_level + 1, Some(self.deadline), false)
+        // Children of a seeded simulation are seeded from the parent's random source.
+        let child_seed = self.next_random_u64();
+        let (id, rng) = match self.constraints.seed {
+            Some(_) => (SimulationId::from_seed(child_seed, self.id.0), SimulationRng::from_seed(child_seed)),
+            None => (SimulationId::new(), SimulationRng::from_entropy()),
+        };
+
+        Self::new_internal(
+            base,
+            self.constraints,
+            self.nesting_level + 1,
+            Some(self.deadline),
+            false,
+            id,
+            rng,
+        )
+    }
+
+    /// Fork a sibling simulation from this simulation's current state.
+    ///
+    /// Unlike [`SimulationContext::spawn_child`], which layers a live view over this
+    /// overlay, a fork copies the overlay: it starts with every hypothetical recorded here,
+    /// and from then on writes to either simulation are invisible to the other. The fork
+    /// sits at the same nesting level, shares this simulation's deadline and carries over
+    /// its hypothetical count, so branching cannot be used to exceed the constraints. A fork
+    /// of a root simulation can be committed on its own. Derivation records are not copied.
+    pub fn fork(&self) -> KyroResult<Self> {
+        self.ensure_not_expired()?;
+
+        let fork_seed = self.next_random_u64();
+        let (id, rng) = match self.constraints.seed {
+            Some(_) => (SimulationId::from_seed(fork_seed, self.id.0), SimulationRng::from_seed(fork_seed)),
+            None => (SimulationId::new(), SimulationRng::from_entropy()),
+        };
+
+        Ok(Self {
+            id,
+            constraints: self.constraints,
+            nesting_level: self.nesting_level,
+            remaining_depth: self.remaining_depth,
+            created_at: Instant::now(),
+            deadline: self.deadline,
+            hypothetical_count: AtomicUsize::new(self.hypothetical_count.load(Ordering::Acquire)),
+            is_dropped: AtomicBool::new(false),
+            is_committed: AtomicBool::new(false),
+            rng: Mutex::new(rng),
+            commit_base: self.commit_base.clone(),
+            delta_store: self.delta_store.fork(),
+            delta_index: self.delta_index.clone(),
+            derivations: std::sync::Arc::new(crate::storage::InMemoryDerivationStore::default()),
+        })
     }
 
     /// Commit this simulation's overlay into base storage.
@@ -226,10 +356,18 @@ impl SimulationContext {
     ///   A mapping is returned.
     /// - The commit is not transactional across multiple beliefs (the current storage traits
     ///   do not support rollback). The method preflights invariants to reduce partial-commit risk.
-    ///   If a write fails mid-stream, a `SimulationPartialCommit` error is returned containing the
-    ///   belief ID mapping and conflict IDs accumulated so far so callers can reconcile/roll forward
-    ///   or compensate.
-    pub fn commit_overlay(&self, engine: &KyroEngine, mode: ConsistencyMode) -> KyroResult<SimulationCommitResult> {
+    ///   Under `SimulationCommitMode::AllOrNothing`, if a write fails mid-stream, a
+    ///   `SimulationPartialCommit` error is returned containing the belief ID mapping and conflict
+    ///   IDs accumulated so far so callers can reconcile/roll forward or compensate.
+    /// - Under `SimulationCommitMode::BestEffort`, failed writes (e.g. strict-mode conflicts) are
+    ///   listed in `SimulationCommitResult::rejected` and the remaining hypotheticals are still
+    ///   committed. Supersede pairs involving a rejected hypothetical are skipped.
+    pub fn commit_overlay(
+        &self,
+        engine: &KyroEngine,
+        mode: ConsistencyMode,
+        commit_mode: SimulationCommitMode,
+    ) -> KyroResult<SimulationCommitResult> {
         self.ensure_not_expired()?;
 
         if self.nesting_level != 0 || self.commit_base.is_none() {
@@ -252,6 +390,7 @@ impl SimulationContext {
                 committed_beliefs: 0,
                 belief_id_map: Vec::new(),
                 conflict_ids: Vec::new(),
+                rejected: Vec::new(),
             });
         }
 
@@ -286,30 +425,28 @@ impl SimulationContext {
 
         let mut belief_id_map: Vec<(BeliefId, BeliefId)> = Vec::with_capacity(overlay_beliefs.len());
         let mut conflict_ids: Vec<ConflictId> = Vec::new();
+        let mut rejected: Vec<SimulationCommitRejection> = Vec::new();
 
         for belief in overlay_beliefs {
             let old_id = belief.id;
 
-            let payload = crate::ir::AssertPayload {
-                entity_id: belief.subject,
-                predicate: belief.predicate,
-                value: belief.value,
-                confidence: belief.confidence,
-                source: belief.source,
-                valid_time: belief.valid_time,
-                consistency_mode: mode,
-                embedding: belief.embedding,
-            };
-
             let ir = KyroIR {
                 version: KyroIR::CURRENT_VERSION.to_string(),
-                request_id: uuid::Uuid::new_v4(),
+                request_id: crate::id::next_id(),
                 timestamp: tx_time,
-                operation: Operation::Assert(payload),
+                operation: Operation::Assert(commit_payload(belief, mode)),
             };
 
             let resp = match engine.execute(ir) {
                 Ok(resp) => resp,
+                Err(err) if commit_mode == SimulationCommitMode::BestEffort => {
+                    rejected.push(SimulationCommitRejection {
+                        belief_id: old_id,
+                        error_code: err.error_code(),
+                        message: err.to_string(),
+                    });
+                    continue;
+                }
                 Err(err) => {
                     return Err(KyroError::Execution(ExecutionError::SimulationPartialCommit {
                         committed_len: belief_id_map.len(),
@@ -320,7 +457,7 @@ impl SimulationContext {
                     }));
                 }
             };
-            let EngineResponse::Assert { belief_id: new_id, conflict_ids: ids } = resp else {
+            let EngineResponse::Assert { belief_id: new_id, conflict_ids: ids, .. } = resp else {
                 return Err(KyroError::Execution(ExecutionError::InvalidOperation {
                     expected: "engine_response.assert".to_string(),
                     actual: format!("{resp:?}"),
@@ -334,7 +471,13 @@ impl SimulationContext {
         // Apply supersede relations in base storage.
         // Note: the engine assigns fresh IDs, so we remap pairs using the returned mapping.
         let map: std::collections::HashMap<BeliefId, BeliefId> = belief_id_map.iter().copied().collect();
+        let rejected_ids: std::collections::HashSet<BeliefId> = rejected.iter().map(|r| r.belief_id).collect();
+        // A belief's predecessors are linked in one call, so group the edges by successor.
+        let mut predecessors: Vec<(BeliefId, Vec<BeliefId>)> = Vec::new();
         for (old_id, new_id) in supersedes {
+            if rejected_ids.contains(&old_id) || rejected_ids.contains(&new_id) {
+                continue;
+            }
             let mapped_new = map
                 .get(&new_id)
                 .copied()
@@ -343,22 +486,60 @@ impl SimulationContext {
                 }))?;
 
             let mapped_old = map.get(&old_id).copied().unwrap_or(old_id);
-            base.beliefs.supersede(mapped_old, mapped_new).map_err(storage_err)?;
+            match predecessors.iter_mut().find(|(new, _)| *new == mapped_new) {
+                Some((_, olds)) => olds.push(mapped_old),
+                None => predecessors.push((mapped_new, vec![mapped_old])),
+            }
+        }
+        for (new_id, old_ids) in predecessors {
+            base.beliefs.supersede_all(&old_ids, new_id).map_err(storage_err)?;
         }
 
         Ok(SimulationCommitResult {
             committed_beliefs: belief_id_map.len(),
             belief_id_map,
             conflict_ids,
+            rejected,
         })
     }
 
+    /// The ASSERTs [`commit_overlay`](Self::commit_overlay) would run with `mode`, one per
+    /// hypothetical belief, so a caller can authorize them before committing.
+    pub fn commit_operations(&self, mode: ConsistencyMode) -> KyroResult<Vec<Operation>> {
+        let (overlay_beliefs, _) = self.delta_store.overlay_snapshot().map_err(storage_err)?;
+        Ok(overlay_beliefs
+            .into_iter()
+            .map(|belief| Operation::Assert(commit_payload(belief, mode)))
+            .collect())
+    }
+
     /// Returns the constraints for this simulation.
     #[must_use]
     pub const fn constraints(&self) -> SimulateConstraints {
         self.constraints
     }
 
+    /// Next 64 bits from this simulation's random source.
+    ///
+    /// Deterministic for seeded simulations.
+    pub fn next_random_u64(&self) -> u64 {
+        match self.rng.lock() {
+            Ok(mut rng) => rng.next_u64(),
+            Err(poisoned) => poisoned.into_inner().next_u64(),
+        }
+    }
+
+    /// A fresh belief ID for a hypothetical, drawn from this simulation's random source.
+    ///
+    /// Seeded simulations hand out the same sequence of IDs on every run.
+    pub fn hypothetical_belief_id(&self) -> BeliefId {
+        let uuid = match self.rng.lock() {
+            Ok(mut rng) => rng.next_uuid(),
+            Err(poisoned) => poisoned.into_inner().next_uuid(),
+        };
+        BeliefId::from_uuid(uuid)
+    }
+
     /// Returns the time elapsed since creation.
     #[must_use]
     pub fn elapsed(&self) -> Duration {
@@ -435,6 +616,61 @@ impl SimulationContext {
         Ok(id)
     }
 
+    /// Execute a RETRACT operation inside the simulation.
+    ///
+    /// Like a real RETRACT, this records a `Null` retraction belief that supersedes the
+    /// retracted one, so later hypothetical RESOLVEs no longer see it. Both the retraction and
+    /// the supersession live in the overlay; the base stores are never mutated.
+    pub fn retract_payload(&self, payload: RetractPayload) -> KyroResult<BeliefId> {
+        self.retract_ir(KyroIR::new(Operation::Retract(payload)))
+    }
+
+    /// Execute a RETRACT IR inside the simulation. Returns the retraction belief's ID.
+    pub fn retract_ir(&self, ir: KyroIR) -> KyroResult<BeliefId> {
+        let Operation::Retract(payload) = ir.operation else {
+            return Err(KyroError::Execution(ExecutionError::InvalidOperation {
+                expected: "retract".to_string(),
+                actual: format!("{:?}", ir.operation),
+            }));
+        };
+        self.register_hypothetical()?;
+
+        let beliefs = self.delta_store.beliefs();
+        let old = match beliefs.get(payload.belief_id).map_err(storage_err)? {
+            Some(old) if old.namespace == payload.namespace => old,
+            _ => {
+                return Err(KyroError::Execution(ExecutionError::BeliefNotFound {
+                    id: payload.belief_id,
+                }))
+            }
+        };
+
+        let retraction = Belief {
+            id: self.hypothetical_belief_id(),
+            subject: old.subject,
+            predicate: old.predicate,
+            value: crate::value::Value::Null,
+            confidence: Confidence::from_agent(1.0, "system").map_err(KyroError::from)?,
+            source: payload.authorized_by,
+            valid_time: crate::time::TimeRange::starting_at(ir.timestamp),
+            tx_time: ir.timestamp,
+            reason: payload.reason,
+            consistency_status: ConsistencyStatus::Verified,
+            supersedes: vec![old.id],
+            superseded_by: None,
+            embedding: None,
+            namespace: old.namespace,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: true,
+        };
+        let retraction_id = retraction.id;
+        beliefs.insert(retraction).map_err(storage_err)?;
+        beliefs.supersede(old.id, retraction_id).map_err(storage_err)?;
+        Ok(retraction_id)
+    }
+
     /// Return an impact summary for the current overlay state.
     pub fn query_impact(&self) -> KyroResult<SimulationImpact> {
         self.ensure_not_expired()?;
@@ -636,6 +872,7 @@ mod tests {
                 max_affected_entities: 10,
                 max_depth: 1,
                 max_duration_ms: 500,
+                seed: None,
             },
         )
         .unwrap();
@@ -652,9 +889,14 @@ mod tests {
             tx_time: t0,
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         ctx.assert_hypothetical(hypo.clone()).unwrap();
@@ -665,7 +907,7 @@ mod tests {
             .len();
         assert_eq!(before, 0);
 
-        let res = ctx.commit_overlay(&engine, ConsistencyMode::Eventual).unwrap();
+        let res = ctx.commit_overlay(&engine, ConsistencyMode::Eventual, SimulationCommitMode::AllOrNothing).unwrap();
         assert_eq!(res.committed_beliefs, 1);
         assert_eq!(res.belief_id_map.len(), 1);
         assert_eq!(res.belief_id_map[0].0, hypo.id);
@@ -710,6 +952,8 @@ mod tests {
             valid_time: TimeRange::from_now(),
             consistency_mode: ConsistencyMode::Eventual,
             embedding: None,
+            idempotency_key: None,
+            namespace: None,
         }));
         let EngineResponse::Assert { belief_id: old_id, .. } = engine.execute(seed).unwrap() else {
             panic!("expected assert");
@@ -728,6 +972,7 @@ mod tests {
                 max_affected_entities: 10,
                 max_depth: 1,
                 max_duration_ms: 500,
+                seed: None,
             },
         )
         .unwrap();
@@ -744,9 +989,14 @@ mod tests {
             tx_time: t0,
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         ctx.assert_hypothetical(hypo.clone()).unwrap();
@@ -757,7 +1007,7 @@ mod tests {
             .supersede(old_id, hypo.id)
             .unwrap();
 
-        let res = ctx.commit_overlay(&engine, ConsistencyMode::Eventual).unwrap();
+        let res = ctx.commit_overlay(&engine, ConsistencyMode::Eventual, SimulationCommitMode::AllOrNothing).unwrap();
         let committed_new = res
             .belief_id_map
             .iter()
@@ -785,6 +1035,7 @@ mod tests {
             max_affected_entities: 10,
             max_depth: 1,
             max_duration_ms: 1,
+            seed: None,
             },
         )
         .unwrap();
@@ -814,6 +1065,7 @@ mod tests {
             max_affected_entities: 2,
             max_depth: 2,
             max_duration_ms: 500,
+            seed: None,
             },
         )
         .unwrap();
@@ -849,6 +1101,7 @@ mod tests {
                 max_affected_entities: 10,
                 max_depth: 3,
                 max_duration_ms: 500,
+                seed: None,
             },
         )
         .unwrap();
@@ -865,9 +1118,14 @@ mod tests {
             tx_time: t0,
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         parent.assert_hypothetical(b_parent.clone()).unwrap();
@@ -891,9 +1149,14 @@ mod tests {
             tx_time: t1,
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         child.assert_hypothetical(b_child.clone()).unwrap();
@@ -901,6 +1164,57 @@ mod tests {
         assert!(parent.delta_store.beliefs().get(b_child.id).unwrap().is_none());
     }
 
+    #[test]
+    fn forks_copy_parent_hypotheticals_and_diverge_afterwards() {
+        let stores = crate::storage::InMemoryStores::default();
+        let entity = Entity::new("e", EntityType::Concept);
+        let entity_id = entity.id;
+        stores.entities.insert(entity).unwrap();
+        let base = SimulationBaseStores {
+            entities: Arc::new(stores.entities),
+            beliefs: Arc::new(stores.beliefs),
+            patterns: Arc::new(stores.patterns),
+            conflicts: Arc::new(stores.conflicts),
+        };
+        let parent = SimulationContext::new(
+            base,
+            SimulateConstraints {
+                max_affected_entities: 10,
+                max_depth: 3,
+                max_duration_ms: 500,
+                seed: None,
+            },
+        )
+        .unwrap();
+
+        let hypothetical = |predicate: &str| {
+            Belief::builder()
+                .subject(entity_id)
+                .predicate(predicate)
+                .value(Value::Int(1))
+                .confidence(Confidence::from_agent(0.9, "sim").unwrap())
+                .source(Source::Unknown { description: None })
+                .valid_time(TimeRange::from_now())
+                .build()
+                .unwrap()
+        };
+
+        let before = parent.assert_hypothetical(hypothetical("before")).unwrap();
+        let fork = parent.fork().unwrap();
+        assert_ne!(fork.id, parent.id);
+        assert!(fork.delta_store.beliefs().get(before).unwrap().is_some());
+
+        let in_fork = fork.assert_hypothetical(hypothetical("in_fork")).unwrap();
+        let after = parent.assert_hypothetical(hypothetical("after")).unwrap();
+        assert!(parent.delta_store.beliefs().get(in_fork).unwrap().is_none());
+        assert!(fork.delta_store.beliefs().get(after).unwrap().is_none());
+        assert_eq!(fork.query_impact().unwrap().inserted_beliefs, 2);
+
+        // The fork keeps its copy when the parent is torn down.
+        drop(parent);
+        assert!(fork.delta_store.beliefs().get(before).unwrap().is_some());
+    }
+
     #[test]
     fn child_op_budget_shrinks_with_depth() {
         let stores = crate::storage::InMemoryStores::default();
@@ -921,6 +1235,7 @@ mod tests {
                 max_affected_entities: 1,
                 max_depth: 2,
                 max_duration_ms: 500,
+                seed: None,
             },
         )
         .unwrap();
@@ -954,6 +1269,7 @@ mod tests {
                 max_affected_entities: 10,
                 max_depth: 3,
                 max_duration_ms: 20,
+                seed: None,
             },
         )
         .unwrap();
@@ -968,4 +1284,229 @@ mod tests {
         let child_err = child.ensure_not_expired().unwrap_err();
         assert!(matches!(child_err, KyroError::Execution(ExecutionError::Timeout { .. })));
     }
+
+    #[test]
+    fn seeded_simulations_with_same_operations_have_identical_impacts() {
+        let stores = crate::storage::InMemoryStores::default();
+        let entity = Entity::new("e", EntityType::Concept);
+        let entity_id = entity.id;
+        stores.entities.insert(entity).unwrap();
+
+        let base = SimulationBaseStores {
+            entities: Arc::new(stores.entities),
+            beliefs: Arc::new(stores.beliefs),
+            patterns: Arc::new(stores.patterns),
+            conflicts: Arc::new(stores.conflicts),
+        };
+        let request_id = Uuid::new_v4();
+        let t0 = Utc::now();
+
+        let run = |seed: u64| {
+            let ctx = SimulationContext::for_request(
+                base.clone(),
+                SimulateConstraints {
+                    seed: Some(seed),
+                    ..SimulateConstraints::default()
+                },
+                request_id,
+            )
+            .unwrap();
+
+            let mut ids = Vec::new();
+            for value in 0..3 {
+                let mut belief = Belief::builder()
+                    .subject(entity_id)
+                    .predicate("p")
+                    .value(Value::Int(value))
+                    .confidence(Confidence::from_agent(0.8, "sim").unwrap())
+                    .source(Source::Unknown { description: None })
+                    .valid_time(TimeRange::starting_at(t0))
+                    .build()
+                    .unwrap();
+                belief.id = ctx.hypothetical_belief_id();
+                ids.push(ctx.assert_hypothetical(belief).unwrap());
+            }
+            ctx.delta_store.beliefs().supersede(ids[0], ids[1]).unwrap();
+
+            let child_id = ctx.spawn_child().unwrap().id;
+            (ctx.id, child_id, ctx.query_impact().unwrap())
+        };
+
+        let (id_a, child_a, impact_a) = run(7);
+        let (id_b, child_b, impact_b) = run(7);
+        assert_eq!(id_a, id_b);
+        assert_eq!(id_a, SimulationId::from_seed(7, request_id));
+        assert_eq!(child_a, child_b);
+        assert_eq!(impact_a, impact_b);
+        assert_eq!(impact_a.inserted_beliefs, 3);
+
+        let (id_c, _, impact_c) = run(8);
+        assert_ne!(id_a, id_c);
+        assert_ne!(impact_a.inserted_belief_ids, impact_c.inserted_belief_ids);
+    }
+
+    #[test]
+    fn commit_modes_handle_a_conflicting_hypothetical() {
+        let stores = crate::storage::InMemoryStores::default();
+        let entity = Entity::new("e", EntityType::Concept);
+        let entity_id = entity.id;
+        stores.entities.insert(entity).unwrap();
+
+        let entities: Arc<dyn EntityStore> = Arc::new(stores.entities);
+        let beliefs: Arc<dyn BeliefStore> = Arc::new(stores.beliefs);
+        let patterns: Arc<dyn PatternStore> = Arc::new(stores.patterns);
+        let conflicts: Arc<dyn ConflictStore> = Arc::new(stores.conflicts);
+        let engine = KyroEngine::new(
+            Arc::clone(&entities),
+            Arc::clone(&beliefs),
+            Arc::clone(&patterns),
+            Arc::clone(&conflicts),
+            Arc::new(stores.derivations),
+        );
+
+        engine
+            .execute(KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
+                entity_id,
+                predicate: "p".to_string(),
+                value: Value::Int(1),
+                confidence: Confidence::from_agent(0.8, "base").unwrap(),
+                source: Source::Unknown { description: None },
+                valid_time: TimeRange::from_now(),
+                consistency_mode: ConsistencyMode::Force,
+                embedding: None,
+                idempotency_key: None,
+                namespace: None,
+            })))
+            .unwrap();
+
+        // Overlay beliefs commit in ID order, so the conflicting one (`p = 2`) goes first.
+        let conflicting = BeliefId::from_uuid(Uuid::from_u128(1));
+        let clean = BeliefId::from_uuid(Uuid::from_u128(2));
+        let simulation = || {
+            let ctx = SimulationContext::new(
+                SimulationBaseStores {
+                    entities: Arc::clone(&entities),
+                    beliefs: Arc::clone(&beliefs),
+                    patterns: Arc::clone(&patterns),
+                    conflicts: Arc::clone(&conflicts),
+                },
+                SimulateConstraints::default(),
+            )
+            .unwrap();
+            for (id, predicate, value) in [(conflicting, "p", 2), (clean, "q", 3)] {
+                let mut belief = Belief::builder()
+                    .subject(entity_id)
+                    .predicate(predicate)
+                    .value(Value::Int(value))
+                    .confidence(Confidence::from_agent(0.9, "sim").unwrap())
+                    .source(Source::Unknown { description: None })
+                    .build()
+                    .unwrap();
+                belief.id = id;
+                ctx.assert_hypothetical(belief).unwrap();
+            }
+            ctx
+        };
+
+        let err = simulation()
+            .commit_overlay(&engine, ConsistencyMode::Strict, SimulationCommitMode::AllOrNothing)
+            .unwrap_err();
+        let KyroError::Execution(ExecutionError::SimulationPartialCommit {
+            committed_len,
+            failed_belief_id,
+            ..
+        }) = err
+        else {
+            panic!("expected partial commit error, got {err:?}");
+        };
+        assert_eq!(committed_len, 0);
+        assert_eq!(failed_belief_id, conflicting);
+        assert!(beliefs.find_by_entity_predicate(entity_id, "q").unwrap().is_empty());
+
+        let res = simulation()
+            .commit_overlay(&engine, ConsistencyMode::Strict, SimulationCommitMode::BestEffort)
+            .unwrap();
+        assert_eq!(res.committed_beliefs, 1);
+        assert_eq!(res.belief_id_map[0].0, clean);
+        assert_eq!(res.rejected.len(), 1);
+        assert_eq!(res.rejected[0].belief_id, conflicting);
+        assert_eq!(res.rejected[0].error_code, ErrorCode::ConflictsDetected);
+        assert_eq!(beliefs.find_by_entity_predicate(entity_id, "q").unwrap().len(), 1);
+        assert_eq!(beliefs.find_by_entity_predicate(entity_id, "p").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn hypothetical_retraction_changes_simulated_resolve_but_not_base() {
+        let stores = crate::storage::InMemoryStores::default();
+        let entity = Entity::new("e", EntityType::Concept);
+        let entity_id = entity.id;
+        stores.entities.insert(entity).unwrap();
+
+        let entities: Arc<dyn EntityStore> = Arc::new(stores.entities);
+        let beliefs: Arc<dyn BeliefStore> = Arc::new(stores.beliefs);
+        let patterns: Arc<dyn PatternStore> = Arc::new(stores.patterns);
+        let conflicts: Arc<dyn ConflictStore> = Arc::new(stores.conflicts);
+        let engine = KyroEngine::new(
+            Arc::clone(&entities),
+            Arc::clone(&beliefs),
+            Arc::clone(&patterns),
+            Arc::clone(&conflicts),
+            Arc::new(stores.derivations),
+        );
+        let EngineResponse::Assert { belief_id, .. } = engine
+            .execute(KyroIR::new(Operation::Assert(crate::ir::AssertPayload {
+                entity_id,
+                predicate: "p".to_string(),
+                value: Value::Int(1),
+                confidence: Confidence::from_agent(0.8, "base").unwrap(),
+                source: Source::Unknown { description: None },
+                valid_time: TimeRange::forever(),
+                consistency_mode: ConsistencyMode::Eventual,
+                embedding: None,
+                idempotency_key: None,
+                namespace: None,
+            })))
+            .unwrap()
+        else {
+            panic!("expected assert");
+        };
+
+        let ctx = SimulationContext::new(
+            SimulationBaseStores {
+                entities: Arc::clone(&entities),
+                beliefs: Arc::clone(&beliefs),
+                patterns: Arc::clone(&patterns),
+                conflicts: Arc::clone(&conflicts),
+            },
+            SimulateConstraints::default(),
+        )
+        .unwrap();
+        let retraction_id = ctx
+            .retract_payload(RetractPayload {
+                belief_id,
+                reason: Some("what if".to_string()),
+                authorized_by: Source::agent("analyst", None::<String>),
+                namespace: None,
+            })
+            .unwrap();
+
+        let resolve = ResolvePayload {
+            entity_id: Some(entity_id),
+            predicate: Some("p".to_string()),
+            ..ResolvePayload::default()
+        };
+        let simulated = ctx.resolve_payload(resolve.clone()).unwrap();
+        let claim = simulated.best_supported_claim.unwrap();
+        assert_eq!(claim.belief.id, retraction_id);
+        assert_eq!(claim.belief.value, Value::Null);
+
+        assert!(beliefs.get(belief_id).unwrap().unwrap().superseded_by.is_none());
+        assert!(beliefs.get(retraction_id).unwrap().is_none());
+        let EngineResponse::Resolve { frame } = engine.execute(KyroIR::new(Operation::Resolve(resolve))).unwrap()
+        else {
+            panic!("expected resolve");
+        };
+        assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::Int(1));
+        assert_eq!(ctx.query_impact().unwrap().supersedes, vec![(belief_id, retraction_id)]);
+    }
 }
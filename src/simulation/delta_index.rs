@@ -58,7 +58,7 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> Result<f32, StorageError> {
 }
 
 /// Overlay vector index for hypothetical embeddings.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct DeltaVectorIndex {
     embedding_dim: Option<usize>,
     entries: HashMap<BeliefId, Entry>,
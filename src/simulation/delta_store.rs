@@ -12,10 +12,11 @@ use chrono::{DateTime, Utc};
 
 use crate::belief::Belief;
 use crate::confidence::BeliefId;
-use crate::entity::{Entity, EntityId};
+use crate::entity::{Entity, EntityId, EntityType};
 use crate::pattern::{Pattern, PatternId};
-use crate::storage::{BeliefStore, ConflictStore, EntityStore, PatternStore, StorageError};
+use crate::storage::{AmendFields, BeliefStore, ConflictStore, EntityStore, PatternStore, StorageError, StorageStats};
 use crate::time::TimeRange;
+use crate::value::Value;
 
 use super::constraints::SimulateConstraints;
 use super::delta_index::DeltaVectorIndex;
@@ -25,14 +26,28 @@ fn ro_err(op: &'static str) -> StorageError {
     StorageError::BackendError(format!("simulation store is read-only: {op}"))
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 struct DeltaBeliefState {
     inserted: HashMap<BeliefId, Belief>,
     affected_entities: HashSet<EntityId>,
     superseded: HashMap<BeliefId, BeliefId>,
+    /// Amendments to base beliefs, in the order they were made.
+    amended: HashMap<BeliefId, Vec<AmendFields>>,
     index: DeltaVectorIndex,
 }
 
+impl DeltaBeliefState {
+    /// Apply supersede markers and amendments recorded against a base belief.
+    fn overlay(&self, belief: &mut Belief) {
+        if let Some(new_id) = self.superseded.get(&belief.id).copied() {
+            belief.superseded_by = Some(new_id);
+        }
+        for fields in self.amended.get(&belief.id).into_iter().flatten() {
+            fields.apply(belief);
+        }
+    }
+}
+
 /// Read-only wrapper for `EntityStore`.
 #[derive(Clone)]
 pub struct ReadOnlyEntityStore {
@@ -50,6 +65,14 @@ impl EntityStore for ReadOnlyEntityStore {
         Err(ro_err("entity.insert"))
     }
 
+    fn insert_many(
+        &self,
+        _entities: Vec<Entity>,
+        _dedup_by_name: bool,
+    ) -> Result<HashMap<EntityId, EntityId>, StorageError> {
+        Err(ro_err("entity.insert_many"))
+    }
+
     fn get(&self, id: EntityId) -> Result<Option<Entity>, StorageError> {
         self.base.get(id)
     }
@@ -62,22 +85,38 @@ impl EntityStore for ReadOnlyEntityStore {
         Err(ro_err("entity.delete"))
     }
 
-    fn find_by_name(&self, name: &str) -> Result<Vec<Entity>, StorageError> {
-        self.base.find_by_name(name)
+    fn find_by_name(&self, namespace: Option<&str>, name: &str) -> Result<Vec<Entity>, StorageError> {
+        self.base.find_by_name(namespace, name)
+    }
+
+    fn find_by_type(&self, entity_type: &EntityType, include_subtypes: bool) -> Result<Vec<Entity>, StorageError> {
+        self.base.find_by_type(entity_type, include_subtypes)
     }
 
-    fn find_by_name_fuzzy(&self, query: &str, limit: usize) -> Result<Vec<Entity>, StorageError> {
-        self.base.find_by_name_fuzzy(query, limit)
+    fn list(&self) -> Result<Vec<Entity>, StorageError> {
+        self.base.list()
     }
 
-    fn find_by_embedding(&self, embedding: &[f32], limit: usize) -> Result<Vec<(Entity, f32)>, StorageError> {
-        self.base.find_by_embedding(embedding, limit)
+    fn find_by_name_fuzzy(&self, namespace: Option<&str>, query: &str, limit: usize) -> Result<Vec<Entity>, StorageError> {
+        self.base.find_by_name_fuzzy(namespace, query, limit)
+    }
+
+    fn find_by_embedding(&self, namespace: Option<&str>, embedding: &[f32], limit: usize) -> Result<Vec<(Entity, f32)>, StorageError> {
+        self.base.find_by_embedding(namespace, embedding, limit)
     }
 
     fn merge(&self, _primary: EntityId, _secondary: EntityId) -> Result<Entity, StorageError> {
         Err(ro_err("entity.merge"))
     }
 
+    fn unmerge(&self, _primary: EntityId, _secondary: EntityId) -> Result<(Entity, Entity), StorageError> {
+        Err(ro_err("entity.unmerge"))
+    }
+
+    fn merged_ids(&self, id: EntityId) -> Result<Vec<EntityId>, StorageError> {
+        self.base.merged_ids(id)
+    }
+
     fn get_at_version(&self, id: EntityId, version: u64) -> Result<Option<Entity>, StorageError> {
         self.base.get_at_version(id, version)
     }
@@ -85,6 +124,10 @@ impl EntityStore for ReadOnlyEntityStore {
     fn list_versions(&self, id: EntityId) -> Result<Vec<Entity>, StorageError> {
         self.base.list_versions(id)
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.base.stats()
+    }
 }
 
 /// Read-only wrapper for `PatternStore`.
@@ -123,6 +166,10 @@ impl PatternStore for ReadOnlyPatternStore {
     fn find_active(&self) -> Result<Vec<Pattern>, StorageError> {
         self.base.find_active()
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.base.stats()
+    }
 }
 
 /// Read-only wrapper for `ConflictStore`.
@@ -157,6 +204,14 @@ impl ConflictStore for ReadOnlyConflictStore {
     fn find_open(&self) -> Result<Vec<crate::conflict::Conflict>, StorageError> {
         self.base.find_open()
     }
+
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<crate::conflict::Conflict>, StorageError> {
+        self.base.find_by_entity(entity_id)
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.base.stats()
+    }
 }
 
 /// Read-only wrapper for `BeliefStore`.
@@ -183,10 +238,22 @@ impl BeliefStore for ReadOnlyBeliefStore {
         self.base.get(id)
     }
 
-    fn supersede(&self, _old_id: BeliefId, _new_id: BeliefId) -> Result<(), StorageError> {
+    fn supersede_all(&self, _old_ids: &[BeliefId], _new_id: BeliefId) -> Result<(), StorageError> {
         Err(ro_err("belief.supersede"))
     }
 
+    fn amend(&self, _id: BeliefId, _fields: AmendFields) -> Result<(), StorageError> {
+        Err(ro_err("belief.amend"))
+    }
+
+    fn find_superseded(&self, before: DateTime<Utc>) -> Result<Vec<Belief>, StorageError> {
+        self.base.find_superseded(before)
+    }
+
+    fn delete(&self, _id: BeliefId) -> Result<(), StorageError> {
+        Err(ro_err("belief.delete"))
+    }
+
     fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Belief>, StorageError> {
         self.base.find_by_entity(entity_id)
     }
@@ -199,6 +266,23 @@ impl BeliefStore for ReadOnlyBeliefStore {
         self.base.find_by_entity_predicate(entity_id, predicate)
     }
 
+    fn history(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError> {
+        self.base.history(entity_id, predicate)
+    }
+
+    fn coalesce(
+        &self,
+        _entity_id: EntityId,
+        _predicate: &str,
+        _pinned: &HashSet<BeliefId>,
+    ) -> Result<usize, StorageError> {
+        Err(ro_err("belief.coalesce"))
+    }
+
+    fn reembed(&self, _embed: &dyn Fn(&Belief) -> Result<Vec<f32>, StorageError>) -> Result<usize, StorageError> {
+        Err(ro_err("belief.reembed"))
+    }
+
     fn find_as_of(
         &self,
         entity_id: EntityId,
@@ -208,22 +292,42 @@ impl BeliefStore for ReadOnlyBeliefStore {
         self.base.find_as_of(entity_id, predicate, as_of)
     }
 
-    fn find_by_time_range(&self, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
-        self.base.find_by_time_range(range)
+    fn find_by_time_range(&self, namespace: Option<&str>, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
+        self.base.find_by_time_range(namespace, range)
     }
 
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
         min_confidence: Option<f32>,
     ) -> Result<Vec<(Belief, f32)>, StorageError> {
-        self.base.find_by_embedding(embedding, limit, min_confidence)
+        self.base.find_by_embedding(namespace, embedding, limit, min_confidence)
+    }
+
+    fn find_entities_by_predicate_value(
+        &self,
+        namespace: Option<&str>,
+        predicate: &str,
+        value: &Value,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<EntityId>, StorageError> {
+        self.base
+            .find_entities_by_predicate_value(namespace, predicate, value, as_of)
     }
 
     fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError> {
         self.base.count_by_entity(entity_id)
     }
+
+    fn subjects(&self) -> Result<Vec<EntityId>, StorageError> {
+        self.base.subjects()
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.base.stats()
+    }
 }
 
 /// Belief store overlay: writes land in-memory, reads merge base+delta.
@@ -242,6 +346,19 @@ impl DeltaBeliefStore {
         }
     }
 
+    /// A copy of this overlay over the same base; later writes to either copy stay local.
+    fn fork(&self) -> Self {
+        let state = match self.state.read() {
+            Ok(g) => g.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        Self {
+            base: Arc::clone(&self.base),
+            constraints: self.constraints,
+            state: RwLock::new(state),
+        }
+    }
+
     fn clear(&self) {
         let mut guard = match self.state.write() {
             Ok(g) => g,
@@ -281,11 +398,9 @@ impl DeltaBeliefStore {
             }
         }
 
-        // Apply supersede markers (best-effort; does not mutate base storage).
+        // Apply supersede markers and amendments (best-effort; does not mutate base storage).
         for belief in &mut base {
-            if let Some(new_id) = state.superseded.get(&belief.id).copied() {
-                belief.superseded_by = Some(new_id);
-            }
+            state.overlay(belief);
         }
 
         Ok(base)
@@ -335,22 +450,56 @@ impl BeliefStore for DeltaBeliefStore {
 
         let mut b = self.base.get(id)?;
         if let Some(ref mut belief) = b {
-            if let Some(new_id) = state.superseded.get(&id).copied() {
-                belief.superseded_by = Some(new_id);
-            }
+            state.overlay(belief);
         }
         Ok(b)
     }
 
-    fn supersede(&self, old_id: BeliefId, new_id: BeliefId) -> Result<(), StorageError> {
+    fn supersede_all(&self, old_ids: &[BeliefId], new_id: BeliefId) -> Result<(), StorageError> {
         let mut guard = self
             .state
             .write()
             .map_err(|_| StorageError::BackendError("poisoned lock: delta_beliefs.supersede".to_string()))?;
-        guard.superseded.insert(old_id, new_id);
+        for &old_id in old_ids {
+            guard.superseded.insert(old_id, new_id);
+        }
         Ok(())
     }
 
+    fn amend(&self, id: BeliefId, fields: AmendFields) -> Result<(), StorageError> {
+        fields.validate()?;
+        let mut guard = self
+            .state
+            .write()
+            .map_err(|_| StorageError::BackendError("poisoned lock: delta_beliefs.amend".to_string()))?;
+        if let Some(belief) = guard.inserted.get_mut(&id) {
+            fields.apply(belief);
+            return Ok(());
+        }
+        if self.base.get(id)?.is_none() {
+            return Err(StorageError::BeliefNotFound(id));
+        }
+        guard.amended.entry(id).or_default().push(fields);
+        Ok(())
+    }
+
+    /// Reports base beliefs only; supersessions made inside the simulation are not included.
+    fn find_superseded(&self, before: DateTime<Utc>) -> Result<Vec<Belief>, StorageError> {
+        let mut out = self.base.find_superseded(before)?;
+        let state = self
+            .state
+            .read()
+            .map_err(|_| StorageError::BackendError("poisoned lock: delta_beliefs.find_superseded".to_string()))?;
+        for belief in &mut out {
+            state.overlay(belief);
+        }
+        Ok(out)
+    }
+
+    fn delete(&self, _id: BeliefId) -> Result<(), StorageError> {
+        Err(ro_err("belief.delete"))
+    }
+
     fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Belief>, StorageError> {
         let mut out = self.base.find_by_entity(entity_id)?;
 
@@ -366,12 +515,10 @@ impl BeliefStore for DeltaBeliefStore {
         }
 
         for belief in &mut out {
-            if let Some(new_id) = state.superseded.get(&belief.id).copied() {
-                belief.superseded_by = Some(new_id);
-            }
+            state.overlay(belief);
         }
 
-        out.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        out.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
         Ok(out)
     }
 
@@ -382,6 +529,25 @@ impl BeliefStore for DeltaBeliefStore {
         Ok(merged)
     }
 
+    fn history(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError> {
+        let mut beliefs = self.find_by_entity_predicate(entity_id, predicate)?;
+        crate::storage::sort_history(&mut beliefs);
+        Ok(beliefs)
+    }
+
+    fn coalesce(
+        &self,
+        _entity_id: EntityId,
+        _predicate: &str,
+        _pinned: &HashSet<BeliefId>,
+    ) -> Result<usize, StorageError> {
+        Err(ro_err("belief.coalesce"))
+    }
+
+    fn reembed(&self, _embed: &dyn Fn(&Belief) -> Result<Vec<f32>, StorageError>) -> Result<usize, StorageError> {
+        Err(ro_err("belief.reembed"))
+    }
+
     fn find_as_of(
         &self,
         entity_id: EntityId,
@@ -394,23 +560,21 @@ impl BeliefStore for DeltaBeliefStore {
         Ok(merged)
     }
 
-    fn find_by_time_range(&self, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
-        let mut out = self.base.find_by_time_range(range)?;
+    fn find_by_time_range(&self, namespace: Option<&str>, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
+        let mut out = self.base.find_by_time_range(namespace, range)?;
 
         let state = self
             .state
             .read()
             .map_err(|_| StorageError::BackendError("poisoned lock: delta_beliefs.find_by_time_range".to_string()))?;
         for belief in state.inserted.values() {
-            if belief.valid_time.overlaps(range) {
+            if belief.namespace.as_deref() == namespace && belief.valid_time.overlaps(range) {
                 out.push(belief.clone());
             }
         }
 
         for belief in &mut out {
-            if let Some(new_id) = state.superseded.get(&belief.id).copied() {
-                belief.superseded_by = Some(new_id);
-            }
+            state.overlay(belief);
         }
 
         Ok(out)
@@ -418,28 +582,31 @@ impl BeliefStore for DeltaBeliefStore {
 
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
         min_confidence: Option<f32>,
     ) -> Result<Vec<(Belief, f32)>, StorageError> {
-        let mut out = self.base.find_by_embedding(embedding, limit, min_confidence)?;
+        let mut out = self.base.find_by_embedding(namespace, embedding, limit, min_confidence)?;
 
         let state = self
             .state
             .read()
             .map_err(|_| StorageError::BackendError("poisoned lock: delta_beliefs.find_by_embedding".to_string()))?;
 
-        let hits = state.index.search(embedding, limit, min_confidence)?;
+        // The overlay index is not namespaced; search all of it so filtering cannot starve `limit`.
+        let hits = state.index.search(embedding, limit.max(state.inserted.len()), min_confidence)?;
         for (id, sim) in hits {
             if let Some(belief) = state.inserted.get(&id) {
+                if belief.namespace.as_deref() != namespace {
+                    continue;
+                }
                 out.push((belief.clone(), sim));
             }
         }
 
         for (belief, _) in &mut out {
-            if let Some(new_id) = state.superseded.get(&belief.id).copied() {
-                belief.superseded_by = Some(new_id);
-            }
+            state.overlay(belief);
         }
 
         out.sort_by(|(a, sa), (b, sb)| {
@@ -453,6 +620,41 @@ impl BeliefStore for DeltaBeliefStore {
         Ok(out)
     }
 
+    fn find_entities_by_predicate_value(
+        &self,
+        namespace: Option<&str>,
+        predicate: &str,
+        value: &Value,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<EntityId>, StorageError> {
+        let mut entities = self
+            .base
+            .find_entities_by_predicate_value(namespace, predicate, value, as_of)?;
+
+        let state = self.state.read().map_err(|_| {
+            StorageError::BackendError(
+                "poisoned lock: delta_beliefs.find_entities_by_predicate_value".to_string(),
+            )
+        })?;
+        let predicate = predicate.trim();
+        let encoding = value.stable_encoding();
+        entities.extend(
+            state
+                .inserted
+                .values()
+                .filter(|b| {
+                    b.namespace.as_deref() == namespace
+                        && b.predicate == predicate
+                        && b.is_valid_at(as_of)
+                        && b.value.stable_encoding() == encoding
+                })
+                .map(|b| b.subject),
+        );
+        entities.sort_by_key(|id| *id.as_uuid());
+        entities.dedup();
+        Ok(entities)
+    }
+
     fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError> {
         let base = self.base.count_by_entity(entity_id)?;
         let state = self
@@ -466,6 +668,48 @@ impl BeliefStore for DeltaBeliefStore {
             .count();
         Ok(base + delta)
     }
+
+    fn subjects(&self) -> Result<Vec<EntityId>, StorageError> {
+        let mut subjects = self.base.subjects()?;
+        let state = self
+            .state
+            .read()
+            .map_err(|_| StorageError::BackendError("poisoned lock: delta_beliefs.subjects".to_string()))?;
+        let known: HashSet<EntityId> = subjects.iter().copied().collect();
+        let mut added: Vec<EntityId> = state
+            .inserted
+            .values()
+            .map(|b| b.subject)
+            .filter(|id| !known.contains(id))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        subjects.append(&mut added);
+        Ok(subjects)
+    }
+
+    /// Base counts plus the overlay. Distinct predicates are not reported, since overlay
+    /// predicates cannot be checked against the base without scanning it.
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let base = self.base.stats()?;
+        let state = self
+            .state
+            .read()
+            .map_err(|_| StorageError::BackendError("poisoned lock: delta_beliefs.stats".to_string()))?;
+
+        let mut new_entities = 0;
+        for entity_id in &state.affected_entities {
+            if self.base.count_by_entity(*entity_id)? == 0 {
+                new_entities += 1;
+            }
+        }
+
+        Ok(StorageStats {
+            records: base.records + state.inserted.len(),
+            distinct_entities: base.distinct_entities.map(|n| n + new_entities),
+            distinct_predicates: None,
+        })
+    }
 }
 
 /// Bundle of overlay stores for a simulation.
@@ -488,6 +732,20 @@ impl DeltaStore {
         }
     }
 
+    /// Copy this overlay over the same base stores.
+    ///
+    /// The copy starts with every hypothetical recorded here; afterwards the two overlays are
+    /// independent.
+    #[must_use]
+    pub fn fork(&self) -> Self {
+        Self {
+            entities: Arc::clone(&self.entities),
+            beliefs: Arc::new(self.beliefs.fork()),
+            patterns: Arc::clone(&self.patterns),
+            conflicts: Arc::clone(&self.conflicts),
+        }
+    }
+
     /// Access the entity store (read-only wrapper).
     #[must_use]
     pub fn entities(&self) -> Arc<dyn EntityStore> {
@@ -522,13 +780,14 @@ impl DeltaStore {
         };
 
         let mut entities: Vec<_> = state.affected_entities.iter().copied().collect();
-        entities.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        entities.sort_by_key(|a| a.to_string());
         Ok((entities, state.inserted.len()))
     }
 
     /// Return a richer snapshot of overlay impact.
     ///
     /// Includes belief-level diffs (inserted belief IDs and supersede pairs).
+    #[allow(clippy::type_complexity)]
     pub fn impact_details(
         &self,
     ) -> Result<(Vec<EntityId>, Vec<BeliefId>, Vec<(BeliefId, BeliefId)>), StorageError> {
@@ -538,10 +797,10 @@ impl DeltaStore {
         };
 
         let mut entities: Vec<_> = state.affected_entities.iter().copied().collect();
-        entities.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        entities.sort_by_key(|a| a.to_string());
 
         let mut inserted_belief_ids: Vec<_> = state.inserted.keys().copied().collect();
-        inserted_belief_ids.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        inserted_belief_ids.sort_by_key(|a| a.to_string());
 
         let mut supersedes: Vec<_> = state.superseded.iter().map(|(o, n)| (*o, *n)).collect();
         supersedes.sort_by(|(o1, n1), (o2, n2)| {
@@ -559,6 +818,7 @@ impl DeltaStore {
     /// This is intended for "commit overlay" workflows that graduate a simulation's delta into
     /// base storage. The returned values are derived solely from delta state and do not touch
     /// base stores.
+    #[allow(clippy::type_complexity)]
     pub fn overlay_snapshot(&self) -> Result<(Vec<Belief>, Vec<(BeliefId, BeliefId)>), StorageError> {
         let state = match self.beliefs.state.read() {
             Ok(g) => g,
@@ -566,7 +826,7 @@ impl DeltaStore {
         };
 
         let mut beliefs: Vec<Belief> = state.inserted.values().cloned().collect();
-        beliefs.sort_by(|a, b| a.id.to_string().cmp(&b.id.to_string()));
+        beliefs.sort_by_key(|a| a.id.to_string());
 
         let mut supersedes: Vec<(BeliefId, BeliefId)> = state.superseded.iter().map(|(o, n)| (*o, *n)).collect();
         supersedes.sort_by(|(o1, n1), (o2, n2)| {
@@ -633,9 +893,14 @@ mod tests {
             tx_time: Utc::now(),
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         delta.beliefs().insert(belief.clone()).unwrap();
@@ -663,6 +928,7 @@ mod tests {
             max_affected_entities: 1,
             max_depth: 1,
             max_duration_ms: 500,
+            seed: None,
         };
 
         let delta = DeltaStore::new(base, constraints);
@@ -682,9 +948,14 @@ mod tests {
             tx_time: Utc::now(),
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         delta.beliefs().insert(b1).unwrap();
@@ -700,9 +971,14 @@ mod tests {
             tx_time: Utc::now(),
             reason: None,
             consistency_status: ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         };
 
         let err = delta.beliefs().insert(b2).unwrap_err();
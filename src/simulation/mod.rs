@@ -5,13 +5,18 @@
 
 pub mod constraints;
 pub mod context;
+pub mod rng;
 
 // Implemented in later steps (Phase 3.1 / 3.2).
 pub mod delta_index;
 pub mod delta_store;
 
 pub use constraints::SimulateConstraints;
-pub use context::{SimulationCommitResult, SimulationContext, SimulationId, SimulationImpact};
+pub use context::{
+	SimulationCommitMode, SimulationCommitRejection, SimulationCommitResult, SimulationContext, SimulationId,
+	SimulationImpact,
+};
+pub use rng::SimulationRng;
 
 use std::sync::Arc;
 
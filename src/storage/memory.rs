@@ -3,21 +3,28 @@
 //! This module provides thread-safe in-memory implementations of the storage traits.
 //! It is intended for embedded usage, tests, and as a reference implementation.
 
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::sync::RwLock;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::{Arc, RwLock};
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Utc};
 
 use crate::belief::Belief;
 use crate::confidence::BeliefId;
 use crate::conflict::{Conflict, ConflictId, ConflictStatus};
 use crate::derivation::{DerivationId, DerivationRecord};
-use crate::entity::{Entity, EntityId};
+use crate::entity::{Entity, EntityId, EntityType, EntityTypeHierarchy, MergeProvenance};
 use crate::pattern::{Pattern, PatternId};
+use crate::storage::{
+    check_supersedable, check_supersede, close_superseded, distinct_ids, ensure_finite_embedding, ensure_name_unclaimed,
+    ensure_same_namespace, entity_name_key, merge_family, name_index_key, EmbeddingStorage, EntityTypeIndex,
+    QuantizedEmbedding,
+};
 use crate::storage::traits::{
-    BeliefStore, ConflictStore, DerivationStore, EntityStore, PatternStore, StorageError,
+    AmendFields, BeliefStore, ConflictStore, DerivationStore, EntityStore, IdempotencyReservation,
+    IdempotencyStore, PatternStore, StorageError, StorageStats,
 };
 use crate::time::TimeRange;
+use crate::value::Value;
 
 fn lock_err(context: &'static str) -> StorageError {
     StorageError::BackendError(format!("poisoned lock: {context}"))
@@ -71,9 +78,26 @@ struct EntityState {
     versions: HashMap<EntityId, BTreeMap<u64, Entity>>,
     merged_into: HashMap<EntityId, EntityId>,
     merged_from: HashMap<EntityId, HashSet<EntityId>>,
+    merge_provenance: HashMap<EntityId, MergeProvenance>,
+    by_type: EntityTypeIndex,
     embedding_dim: Option<usize>,
 }
 
+/// Store `entity` under its ID, keeping the type index in step.
+fn put_entity(state: &mut EntityState, entity: Entity) {
+    if let Some(prev) = state.by_id.get(&entity.id) {
+        state.by_type.remove(prev);
+    }
+    state.by_type.insert(&entity);
+    state.by_id.insert(entity.id, entity);
+}
+
+fn take_entity(state: &mut EntityState, id: EntityId) -> Option<Entity> {
+    let entity = state.by_id.remove(&id)?;
+    state.by_type.remove(&entity);
+    Some(entity)
+}
+
 fn resolve_canonical_id(state: &EntityState, id: EntityId) -> Result<EntityId, StorageError> {
     let mut current = id;
     for _ in 0..128 {
@@ -109,6 +133,36 @@ fn record_entity_version(
     Ok(())
 }
 
+/// Fold `secondary`'s names, metadata, and embedding into `primary`.
+fn absorb_entity(primary: &mut Entity, secondary: &Entity) -> Result<(), StorageError> {
+    ensure_same_namespace(primary, secondary)?;
+    let secondary_names = std::iter::once(secondary.canonical_name.as_str())
+        .chain(secondary.aliases.iter().map(String::as_str));
+    for name in secondary_names {
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        if name.eq_ignore_ascii_case(&primary.canonical_name) {
+            continue;
+        }
+        if primary.aliases.iter().any(|a| a.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+        primary.aliases.push(name.to_string());
+    }
+
+    primary.metadata = merge_metadata(&primary.metadata, &secondary.metadata);
+
+    primary.embedding = match (primary.embedding.as_ref(), secondary.embedding.as_ref()) {
+        (Some(a), Some(b)) => Some(merge_embeddings(a, b)?),
+        (Some(a), None) => Some(a.clone()),
+        (None, Some(b)) => Some(b.clone()),
+        (None, None) => None,
+    };
+    Ok(())
+}
+
 fn merge_metadata(primary: &serde_json::Value, secondary: &serde_json::Value) -> serde_json::Value {
     use serde_json::Value;
 
@@ -158,14 +212,16 @@ fn merge_embeddings(a: &[f32], b: &[f32]) -> Result<Vec<f32>, StorageError> {
 
 fn ensure_embedding_dim(
     expected: &mut Option<usize>,
-    actual: usize,
+    embedding: &[f32],
     context: &'static str,
 ) -> Result<(), StorageError> {
+    let actual = embedding.len();
     if actual == 0 {
         return Err(StorageError::BackendError(format!(
             "embedding dimension must be non-zero ({context})"
         )));
     }
+    ensure_finite_embedding(embedding, context)?;
 
     match expected {
         None => {
@@ -183,6 +239,8 @@ fn ensure_embedding_dim(
 #[derive(Debug, Default)]
 pub struct InMemoryEntityStore {
     state: RwLock<EntityState>,
+    unique_names: bool,
+    type_hierarchy: EntityTypeHierarchy,
 }
 
 impl InMemoryEntityStore {
@@ -191,6 +249,26 @@ impl InMemoryEntityStore {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Create a new empty store that enforces unique canonical names per namespace.
+    ///
+    /// Writes that would give two entities the same canonical name fail with
+    /// `StorageError::DuplicateKey`, so `find_by_name` returns at most one entity.
+    /// Aliases may still be shared.
+    #[must_use]
+    pub fn with_unique_names() -> Self {
+        Self {
+            unique_names: true,
+            ..Self::default()
+        }
+    }
+
+    /// Use `hierarchy` to expand `find_by_type` queries that include subtypes.
+    #[must_use]
+    pub fn with_type_hierarchy(mut self, hierarchy: EntityTypeHierarchy) -> Self {
+        self.type_hierarchy = hierarchy;
+        self
+    }
 }
 
 impl EntityStore for InMemoryEntityStore {
@@ -200,24 +278,127 @@ impl EntityStore for InMemoryEntityStore {
             return Err(StorageError::DuplicateKey(entity.id.to_string()));
         }
 
+        if self.unique_names {
+            ensure_name_unclaimed(&state.by_name, &entity)?;
+        }
+
         if let Some(emb) = entity.embedding.as_ref() {
-            ensure_embedding_dim(&mut state.embedding_dim, emb.len(), "entity.insert")?;
+            ensure_embedding_dim(&mut state.embedding_dim, emb, "entity.insert")?;
         }
 
         record_entity_version(&mut state, &entity, "entity.insert")?;
 
-        let name_key = normalize_key(&entity.canonical_name);
+        let name_key = entity_name_key(&entity);
         state.by_name.entry(name_key).or_default().insert(entity.id);
-        state.by_id.insert(entity.id, entity);
+        put_entity(&mut state, entity);
         Ok(())
     }
 
+    fn insert_many(
+        &self,
+        entities: Vec<Entity>,
+        dedup_by_name: bool,
+    ) -> Result<HashMap<EntityId, EntityId>, StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("entity.insert_many"))?;
+
+        let mut mapping = HashMap::with_capacity(entities.len());
+        // Entities to write, flagged with whether they already exist in the store.
+        let mut targets: Vec<(Entity, bool)> = Vec::new();
+        let mut by_key: HashMap<String, usize> = HashMap::new();
+
+        for entity in entities {
+            if state.by_id.contains_key(&entity.id)
+                || state.merged_into.contains_key(&entity.id)
+                || mapping.contains_key(&entity.id)
+            {
+                return Err(StorageError::DuplicateKey(entity.id.to_string()));
+            }
+
+            let key = entity_name_key(&entity);
+            if dedup_by_name && !entity.canonical_name.trim().is_empty() {
+                let slot = by_key.get(&key).copied().or_else(|| {
+                    let existing = state
+                        .by_name
+                        .get(&key)
+                        .and_then(|ids| ids.iter().min_by_key(|id| id.to_string()))
+                        .and_then(|id| state.by_id.get(id))?;
+                    targets.push((existing.clone(), true));
+                    by_key.insert(key.clone(), targets.len() - 1);
+                    Some(targets.len() - 1)
+                });
+                if let Some(i) = slot {
+                    absorb_entity(&mut targets[i].0, &entity)?;
+                    mapping.insert(entity.id, targets[i].0.id);
+                    continue;
+                }
+                by_key.insert(key, targets.len());
+            } else if self.unique_names {
+                ensure_name_unclaimed(&state.by_name, &entity)?;
+                if !key.is_empty() && by_key.insert(key, targets.len()).is_some() {
+                    return Err(StorageError::DuplicateKey(format!(
+                        "canonical name '{}'",
+                        entity.canonical_name.trim()
+                    )));
+                }
+            }
+
+            mapping.insert(entity.id, entity.id);
+            targets.push((entity, false));
+        }
+
+        let now = Utc::now();
+        let mut embedding_dim = state.embedding_dim;
+        for (entity, existing) in &mut targets {
+            if *existing {
+                entity.updated_at = now;
+                entity.version = entity
+                    .version
+                    .checked_add(1)
+                    .ok_or_else(|| StorageError::BackendError("entity version overflow".to_string()))?;
+            }
+            if let Some(emb) = entity.embedding.as_ref() {
+                ensure_embedding_dim(&mut embedding_dim, emb, "entity.insert_many")?;
+            }
+            if state
+                .versions
+                .get(&entity.id)
+                .is_some_and(|m| m.contains_key(&entity.version))
+            {
+                return Err(StorageError::BackendError(format!(
+                    "entity version already exists (entity.insert_many): id={} version={}",
+                    entity.id, entity.version
+                )));
+            }
+        }
+
+        state.embedding_dim = embedding_dim;
+        for (entity, existing) in targets {
+            record_entity_version(&mut state, &entity, "entity.insert_many")?;
+            if !existing {
+                state
+                    .by_name
+                    .entry(entity_name_key(&entity))
+                    .or_default()
+                    .insert(entity.id);
+            }
+            put_entity(&mut state, entity);
+        }
+
+        Ok(mapping)
+    }
+
     fn get(&self, id: EntityId) -> Result<Option<Entity>, StorageError> {
         let state = self.state.read().map_err(|_| lock_err("entity.get"))?;
         let canonical = resolve_canonical_id(&state, id)?;
         Ok(state.by_id.get(&canonical).cloned())
     }
 
+    fn merged_ids(&self, id: EntityId) -> Result<Vec<EntityId>, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("entity.merged_ids"))?;
+        let canonical = resolve_canonical_id(&state, id)?;
+        Ok(merge_family(canonical, &state.merged_from))
+    }
+
     fn update(&self, entity: Entity) -> Result<(), StorageError> {
         let mut state = self.state.write().map_err(|_| lock_err("entity.update"))?;
         let canonical = resolve_canonical_id(&state, entity.id)?;
@@ -239,12 +420,24 @@ impl EntityStore for InMemoryEntityStore {
             )));
         }
 
+        // Beliefs carry their subject's namespace, so an entity cannot move between tenants.
+        if entity.namespace != prev.namespace {
+            return Err(StorageError::BackendError(format!(
+                "entity namespace cannot change on update: id={}",
+                entity.id
+            )));
+        }
+
+        if self.unique_names {
+            ensure_name_unclaimed(&state.by_name, &entity)?;
+        }
+
         if let Some(emb) = entity.embedding.as_ref() {
-            ensure_embedding_dim(&mut state.embedding_dim, emb.len(), "entity.update")?;
+            ensure_embedding_dim(&mut state.embedding_dim, emb, "entity.update")?;
         }
 
-        let prev_key = normalize_key(&prev.canonical_name);
-        let new_key = normalize_key(&entity.canonical_name);
+        let prev_key = entity_name_key(&prev);
+        let new_key = entity_name_key(&entity);
         if prev_key != new_key {
             if let Some(set) = state.by_name.get_mut(&prev_key) {
                 set.remove(&entity.id);
@@ -256,7 +449,7 @@ impl EntityStore for InMemoryEntityStore {
         }
 
         record_entity_version(&mut state, &entity, "entity.update")?;
-        state.by_id.insert(entity.id, entity);
+        put_entity(&mut state, entity);
         Ok(())
     }
 
@@ -272,19 +465,16 @@ impl EntityStore for InMemoryEntityStore {
         if state
             .merged_from
             .get(&id)
-            .map_or(false, |s| !s.is_empty())
+            .is_some_and(|s| !s.is_empty())
         {
             return Err(StorageError::BackendError(
                 "cannot delete an entity that has other entities merged into it".to_string(),
             ));
         }
 
-        let prev = state
-            .by_id
-            .remove(&id)
-            .ok_or(StorageError::EntityNotFound(id))?;
+        let prev = take_entity(&mut state, id).ok_or(StorageError::EntityNotFound(id))?;
 
-        let prev_key = normalize_key(&prev.canonical_name);
+        let prev_key = entity_name_key(&prev);
         if let Some(set) = state.by_name.get_mut(&prev_key) {
             set.remove(&id);
             if set.is_empty() {
@@ -295,8 +485,8 @@ impl EntityStore for InMemoryEntityStore {
         Ok(())
     }
 
-    fn find_by_name(&self, name: &str) -> Result<Vec<Entity>, StorageError> {
-        let name_key = normalize_key(name);
+    fn find_by_name(&self, namespace: Option<&str>, name: &str) -> Result<Vec<Entity>, StorageError> {
+        let name_key = name_index_key(namespace, name);
         let state = self.state.read().map_err(|_| lock_err("entity.find_by_name"))?;
         let Some(ids) = state.by_name.get(&name_key) else {
             return Ok(Vec::new());
@@ -310,7 +500,26 @@ impl EntityStore for InMemoryEntityStore {
         Ok(results)
     }
 
-    fn find_by_name_fuzzy(&self, query: &str, limit: usize) -> Result<Vec<Entity>, StorageError> {
+    fn find_by_type(&self, entity_type: &EntityType, include_subtypes: bool) -> Result<Vec<Entity>, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("entity.find_by_type"))?;
+        Ok(state
+            .by_type
+            .find(&state.by_id, &self.type_hierarchy, entity_type, include_subtypes))
+    }
+
+    fn list(&self) -> Result<Vec<Entity>, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("entity.list"))?;
+        let mut entities: Vec<Entity> = state.by_id.values().cloned().collect();
+        super::sort_by_name(&mut entities);
+        Ok(entities)
+    }
+
+    fn find_by_name_fuzzy(
+        &self,
+        namespace: Option<&str>,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<Entity>, StorageError> {
         let query_key = normalize_key(query);
         if query_key.is_empty() || limit == 0 {
             return Ok(Vec::new());
@@ -320,23 +529,10 @@ impl EntityStore for InMemoryEntityStore {
 
         let mut scored: Vec<(u8, Entity)> = Vec::new();
         for entity in state.by_id.values() {
-            let canonical = normalize_key(&entity.canonical_name);
-            let mut score = 0u8;
-            if canonical.starts_with(&query_key) {
-                score = score.max(3);
-            } else if canonical.contains(&query_key) {
-                score = score.max(2);
-            }
-
-            for alias in &entity.aliases {
-                let alias_key = normalize_key(alias);
-                if alias_key.starts_with(&query_key) {
-                    score = score.max(2);
-                } else if alias_key.contains(&query_key) {
-                    score = score.max(1);
-                }
+            if entity.namespace.as_deref() != namespace {
+                continue;
             }
-
+            let score = super::fuzzy_name_score(&query_key, entity);
             if score > 0 {
                 scored.push((score, entity.clone()));
             }
@@ -357,6 +553,7 @@ impl EntityStore for InMemoryEntityStore {
 
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
     ) -> Result<Vec<(Entity, f32)>, StorageError> {
@@ -375,6 +572,9 @@ impl EntityStore for InMemoryEntityStore {
         }
         let mut scored: Vec<(Entity, f32)> = Vec::new();
         for entity in state.by_id.values() {
+            if entity.namespace.as_deref() != namespace {
+                continue;
+            }
             let Some(stored) = entity.embedding.as_ref() else {
                 continue;
             };
@@ -419,37 +619,7 @@ impl EntityStore for InMemoryEntityStore {
             .cloned()
             .ok_or(StorageError::EntityNotFound(secondary_canonical))?;
 
-        let secondary_names = std::iter::once(secondary_entity.canonical_name.clone())
-            .chain(secondary_entity.aliases.clone());
-        for name in secondary_names {
-            let name = name.trim();
-            if name.is_empty() {
-                continue;
-            }
-            if name.eq_ignore_ascii_case(&primary_entity.canonical_name) {
-                continue;
-            }
-            if primary_entity
-                .aliases
-                .iter()
-                .any(|a| a.eq_ignore_ascii_case(name))
-            {
-                continue;
-            }
-            primary_entity.aliases.push(name.to_string());
-        }
-
-        primary_entity.metadata = merge_metadata(&primary_entity.metadata, &secondary_entity.metadata);
-
-        primary_entity.embedding = match (
-            primary_entity.embedding.as_ref(),
-            secondary_entity.embedding.as_ref(),
-        ) {
-            (Some(a), Some(b)) => Some(merge_embeddings(a, b)?),
-            (Some(a), None) => Some(a.clone()),
-            (None, Some(b)) => Some(b.clone()),
-            (None, None) => None,
-        };
+        absorb_entity(&mut primary_entity, &secondary_entity)?;
 
         let now = Utc::now();
         primary_entity.updated_at = now;
@@ -459,20 +629,29 @@ impl EntityStore for InMemoryEntityStore {
             .ok_or_else(|| StorageError::BackendError("entity version overflow".to_string()))?;
 
         if let Some(emb) = primary_entity.embedding.as_ref() {
-            ensure_embedding_dim(&mut state.embedding_dim, emb.len(), "entity.merge")?;
+            ensure_embedding_dim(&mut state.embedding_dim, emb, "entity.merge")?;
         }
 
+        let provenance = MergeProvenance::capture(
+            state
+                .by_id
+                .get(&primary_canonical)
+                .ok_or(StorageError::EntityNotFound(primary_canonical))?,
+            &primary_entity,
+            &secondary_entity,
+        );
+
         record_entity_version(&mut state, &primary_entity, "entity.merge")?;
-        state.by_id.insert(primary_canonical, primary_entity.clone());
+        put_entity(&mut state, primary_entity.clone());
 
-        let prev_key = normalize_key(&secondary_entity.canonical_name);
+        let prev_key = entity_name_key(&secondary_entity);
         if let Some(set) = state.by_name.get_mut(&prev_key) {
             set.remove(&secondary_canonical);
             if set.is_empty() {
                 state.by_name.remove(&prev_key);
             }
         }
-        state.by_id.remove(&secondary_canonical);
+        take_entity(&mut state, secondary_canonical);
 
         state
             .merged_into
@@ -482,10 +661,81 @@ impl EntityStore for InMemoryEntityStore {
             .entry(primary_canonical)
             .or_default()
             .insert(secondary_canonical);
+        state.merge_provenance.insert(secondary_canonical, provenance);
 
         Ok(primary_entity)
     }
 
+    fn unmerge(&self, primary: EntityId, secondary: EntityId) -> Result<(Entity, Entity), StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("entity.unmerge"))?;
+
+        if state.merged_into.get(&secondary) != Some(&primary) {
+            return Err(StorageError::BackendError(format!(
+                "cannot unmerge: {secondary} is not merged into {primary}"
+            )));
+        }
+        let provenance = state.merge_provenance.get(&secondary).cloned().ok_or_else(|| {
+            StorageError::BackendError(format!(
+                "cannot unmerge: no merge provenance recorded for {secondary}"
+            ))
+        })?;
+
+        let mut primary_entity = state
+            .by_id
+            .get(&primary)
+            .cloned()
+            .ok_or(StorageError::EntityNotFound(primary))?;
+        let mut restored = state
+            .versions
+            .get(&secondary)
+            .and_then(|m| m.get(&provenance.secondary_version))
+            .cloned()
+            .ok_or(StorageError::EntityNotFound(secondary))?;
+
+        let now = Utc::now();
+        provenance.revert(&mut primary_entity);
+        primary_entity.updated_at = now;
+        primary_entity.version = primary_entity
+            .version
+            .checked_add(1)
+            .ok_or_else(|| StorageError::BackendError("entity version overflow".to_string()))?;
+
+        let latest_secondary = state
+            .versions
+            .get(&secondary)
+            .and_then(|m| m.keys().next_back().copied())
+            .unwrap_or(restored.version);
+        restored.updated_at = now;
+        restored.version = latest_secondary
+            .checked_add(1)
+            .ok_or_else(|| StorageError::BackendError("entity version overflow".to_string()))?;
+
+        if self.unique_names {
+            ensure_name_unclaimed(&state.by_name, &restored)?;
+        }
+
+        record_entity_version(&mut state, &primary_entity, "entity.unmerge")?;
+        record_entity_version(&mut state, &restored, "entity.unmerge")?;
+        put_entity(&mut state, primary_entity.clone());
+        state
+            .by_name
+            .entry(entity_name_key(&restored))
+            .or_default()
+            .insert(secondary);
+        put_entity(&mut state, restored.clone());
+
+        state.merged_into.remove(&secondary);
+        if let Some(set) = state.merged_from.get_mut(&primary) {
+            set.remove(&secondary);
+            if set.is_empty() {
+                state.merged_from.remove(&primary);
+            }
+        }
+        state.merge_provenance.remove(&secondary);
+
+        Ok((primary_entity, restored))
+    }
+
     fn get_at_version(&self, id: EntityId, version: u64) -> Result<Option<Entity>, StorageError> {
         let state = self.state.read().map_err(|_| lock_err("entity.get_at_version"))?;
         Ok(state
@@ -502,14 +752,28 @@ impl EntityStore for InMemoryEntityStore {
         };
         Ok(map.values().cloned().collect())
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("entity.stats"))?;
+        Ok(StorageStats {
+            records: state.by_id.len(),
+            ..StorageStats::default()
+        })
+    }
 }
 
-#[derive(Debug, Default)]
+/// Belief records and indexes, in persistent maps so a snapshot is a cheap clone.
+#[derive(Debug, Clone, Default)]
 struct BeliefState {
-    by_id: HashMap<BeliefId, Belief>,
-    by_entity: HashMap<EntityId, Vec<BeliefId>>,
-    by_entity_predicate: HashMap<(EntityId, String), Vec<BeliefId>>,
+    by_id: im::HashMap<BeliefId, Belief>,
+    by_entity: im::HashMap<EntityId, Vec<BeliefId>>,
+    by_entity_predicate: im::HashMap<(EntityId, String), Vec<BeliefId>>,
+    /// Inverted attribute index keyed by predicate and `Value::stable_hash`.
+    by_predicate_value: im::HashMap<(String, u64), Vec<BeliefId>>,
     embedding_dim: Option<usize>,
+    embedding_storage: EmbeddingStorage,
+    /// Embeddings held out of `by_id` when `embedding_storage` is `Int8`.
+    quantized: im::HashMap<BeliefId, QuantizedEmbedding>,
 }
 
 /// Thread-safe in-memory belief store.
@@ -524,109 +788,166 @@ impl InMemoryBeliefStore {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Create an empty store that keeps belief embeddings in the given representation.
+    ///
+    /// With [`EmbeddingStorage::Int8`], embeddings use roughly a quarter of the memory;
+    /// beliefs read back carry the dequantized approximation, and `find_by_embedding`
+    /// scores against the quantized vectors.
+    #[must_use]
+    pub fn with_embedding_storage(storage: EmbeddingStorage) -> Self {
+        Self {
+            state: RwLock::new(BeliefState {
+                embedding_storage: storage,
+                ..BeliefState::default()
+            }),
+        }
+    }
 }
 
 impl InMemoryBeliefStore {
-    fn index_insert(state: &mut BeliefState, belief: &Belief) {
-        state.by_entity.entry(belief.subject).or_default().push(belief.id);
-        state
-            .by_entity_predicate
-            .entry((belief.subject, belief.predicate.clone()))
-            .or_default()
-            .push(belief.id);
+    /// Clone a stored belief, restoring a quantized embedding.
+    fn materialize(state: &BeliefState, belief: &Belief) -> Belief {
+        let mut belief = belief.clone();
+        if let Some(quantized) = state.quantized.get(&belief.id) {
+            belief.embedding = Some(quantized.dequantize());
+        }
+        belief
     }
-}
 
-impl BeliefStore for InMemoryBeliefStore {
-    fn insert(&self, belief: Belief) -> Result<(), StorageError> {
-        let mut state = self.state.write().map_err(|_| lock_err("belief.insert"))?;
+    fn insert_locked(state: &mut BeliefState, mut belief: Belief) -> Result<(), StorageError> {
         if state.by_id.contains_key(&belief.id) {
             return Err(StorageError::DuplicateKey(belief.id.to_string()));
         }
 
         if let Some(emb) = belief.embedding.as_ref() {
-            ensure_embedding_dim(&mut state.embedding_dim, emb.len(), "belief.insert")?;
+            ensure_embedding_dim(&mut state.embedding_dim, emb, "belief.insert")?;
+        }
+
+        if state.embedding_storage == EmbeddingStorage::Int8 {
+            if let Some(emb) = belief.embedding.take() {
+                state.quantized.insert(belief.id, QuantizedEmbedding::quantize(&emb));
+            }
         }
 
-        Self::index_insert(&mut state, &belief);
+        Self::index_insert(state, &belief);
         state.by_id.insert(belief.id, belief);
         Ok(())
     }
 
-    fn get(&self, id: BeliefId) -> Result<Option<Belief>, StorageError> {
-        let state = self.state.read().map_err(|_| lock_err("belief.get"))?;
-        Ok(state.by_id.get(&id).cloned())
-    }
-
-    fn supersede(&self, old_id: BeliefId, new_id: BeliefId) -> Result<(), StorageError> {
-        if old_id == new_id {
-            return Err(StorageError::BackendError(
-                "cannot supersede a belief with itself".to_string(),
-            ));
+    fn supersede_locked(state: &mut BeliefState, old_ids: &[BeliefId], new_id: BeliefId) -> Result<(), StorageError> {
+        if !check_supersede(old_ids, new_id, |id| state.by_id.get(&id))? {
+            return Ok(());
         }
 
-        let mut state = self.state.write().map_err(|_| lock_err("belief.supersede"))?;
-        let new_tx = state
-            .by_id
-            .get(&new_id)
-            .ok_or(StorageError::BeliefNotFound(new_id))?
-            .tx_time;
-
-        {
-            let new_belief = state
-                .by_id
-                .get(&new_id)
-                .ok_or(StorageError::BeliefNotFound(new_id))?;
-            if let Some(existing) = new_belief.supersedes {
-                if existing != old_id {
-                    return Err(StorageError::BackendError(format!(
-                        "belief {new_id} already supersedes {existing}; cannot also supersede {old_id}"
-                    )));
-                }
+        let new_tx = state.by_id[&new_id].tx_time;
+        for &old_id in old_ids {
+            if let Some(old_belief) = state.by_id.get_mut(&old_id) {
+                close_superseded(old_belief, new_id, new_tx);
             }
         }
+        if let Some(new_belief) = state.by_id.get_mut(&new_id) {
+            new_belief.supersedes = distinct_ids(old_ids);
+        }
 
-        let old_belief = state
-            .by_id
-            .get_mut(&old_id)
-            .ok_or(StorageError::BeliefNotFound(old_id))?;
+        Ok(())
+    }
+
+    fn index_insert(state: &mut BeliefState, belief: &Belief) {
+        state.by_entity.entry(belief.subject).or_default().push(belief.id);
+        state
+            .by_entity_predicate
+            .entry((belief.subject, belief.predicate.clone()))
+            .or_default()
+            .push(belief.id);
+        state
+            .by_predicate_value
+            .entry((belief.predicate.clone(), belief.value.stable_hash()))
+            .or_default()
+            .push(belief.id);
+    }
 
-        if let Some(existing) = old_belief.superseded_by {
-            if existing == new_id {
-                // Idempotent supersession.
-                return Ok(());
+    fn index_remove(state: &mut BeliefState, belief: &Belief) {
+        if let Some(ids) = state.by_entity.get_mut(&belief.subject) {
+            ids.retain(|id| *id != belief.id);
+            if ids.is_empty() {
+                state.by_entity.remove(&belief.subject);
+            }
+        }
+        let key = (belief.subject, belief.predicate.clone());
+        if let Some(ids) = state.by_entity_predicate.get_mut(&key) {
+            ids.retain(|id| *id != belief.id);
+            if ids.is_empty() {
+                state.by_entity_predicate.remove(&key);
+            }
+        }
+        let key = (belief.predicate.clone(), belief.value.stable_hash());
+        if let Some(ids) = state.by_predicate_value.get_mut(&key) {
+            ids.retain(|id| *id != belief.id);
+            if ids.is_empty() {
+                state.by_predicate_value.remove(&key);
             }
-            return Err(StorageError::BackendError(format!(
-                "belief {old_id} is already superseded by {existing}"
-            )));
         }
+    }
+}
 
-        old_belief.superseded_by = Some(new_id);
+impl BeliefStore for InMemoryBeliefStore {
+    fn insert(&self, belief: Belief) -> Result<(), StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("belief.insert"))?;
+        Self::insert_locked(&mut state, belief)
+    }
 
-        // Close the old belief's valid time at the superseding belief's transaction time.
-        // Clamp to ensure we never create an invalid (empty) interval.
-        let end = if new_tx > old_belief.valid_time.from() {
-            new_tx
-        } else {
-            old_belief.valid_time.from() + Duration::microseconds(1)
-        };
+    fn get(&self, id: BeliefId) -> Result<Option<Belief>, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("belief.get"))?;
+        Ok(state.by_id.get(&id).map(|b| Self::materialize(&state, b)))
+    }
 
-        let end = match old_belief.valid_time.to() {
-            Some(existing) => existing.min(end),
-            None => end,
-        };
+    fn supersede_all(&self, old_ids: &[BeliefId], new_id: BeliefId) -> Result<(), StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("belief.supersede"))?;
+        Self::supersede_locked(&mut state, old_ids, new_id)
+    }
+
+    fn insert_superseding(&self, mut belief: Belief, old_ids: &[BeliefId]) -> Result<(), StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("belief.insert_superseding"))?;
+        check_supersedable(old_ids, belief.id, |id| state.by_id.get(&id))?;
+        let new_id = belief.id;
+        belief.supersedes.clear();
+        Self::insert_locked(&mut state, belief)?;
+        Self::supersede_locked(&mut state, old_ids, new_id)
+    }
 
-        old_belief.valid_time.set_to_clamped(end);
+    fn amend(&self, id: BeliefId, fields: AmendFields) -> Result<(), StorageError> {
+        fields.validate()?;
+        let mut state = self.state.write().map_err(|_| lock_err("belief.amend"))?;
+        let belief = state.by_id.get_mut(&id).ok_or(StorageError::BeliefNotFound(id))?;
+        fields.apply(belief);
+        Ok(())
+    }
 
-        // Update the forward link on the new belief.
-        let new_belief = state
+    fn find_superseded(&self, before: DateTime<Utc>) -> Result<Vec<Belief>, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("belief.find_superseded"))?;
+        let mut beliefs: Vec<Belief> = state
             .by_id
-            .get_mut(&new_id)
-            .ok_or(StorageError::BeliefNotFound(new_id))?;
-        if new_belief.supersedes.is_none() {
-            new_belief.supersedes = Some(old_id);
-        }
+            .values()
+            .filter(|b| {
+                b.superseded_by
+                    .and_then(|next| state.by_id.get(&next))
+                    .is_some_and(|next| next.tx_time < before)
+            })
+            .map(|b| Self::materialize(&state, b))
+            .collect();
+        beliefs.sort_by_key(|b| b.tx_time);
+        Ok(beliefs)
+    }
 
+    fn delete(&self, id: BeliefId) -> Result<(), StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("belief.delete"))?;
+        let belief = state.by_id.remove(&id).ok_or(StorageError::BeliefNotFound(id))?;
+        Self::index_remove(&mut state, &belief);
+        state.quantized.remove(&id);
+        if let Some(next) = belief.superseded_by.and_then(|next| state.by_id.get_mut(&next)) {
+            next.supersedes.retain(|prev| *prev != id);
+        }
         Ok(())
     }
 
@@ -641,9 +962,10 @@ impl BeliefStore for InMemoryBeliefStore {
 
         let mut beliefs: Vec<Belief> = ids
             .iter()
-            .filter_map(|id| state.by_id.get(id).cloned())
+            .filter_map(|id| state.by_id.get(id))
+            .map(|b| Self::materialize(&state, b))
             .collect();
-        beliefs.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
         Ok(beliefs)
     }
 
@@ -664,12 +986,79 @@ impl BeliefStore for InMemoryBeliefStore {
 
         let mut beliefs: Vec<Belief> = ids
             .iter()
-            .filter_map(|id| state.by_id.get(id).cloned())
+            .filter_map(|id| state.by_id.get(id))
+            .map(|b| Self::materialize(&state, b))
             .collect();
-        beliefs.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
+        Ok(beliefs)
+    }
+
+    fn history(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError> {
+        let mut beliefs = self.find_by_entity_predicate(entity_id, predicate)?;
+        super::sort_history(&mut beliefs);
         Ok(beliefs)
     }
 
+    fn coalesce(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+        pinned: &HashSet<BeliefId>,
+    ) -> Result<usize, StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("belief.coalesce"))?;
+        let key = (entity_id, predicate.trim().to_string());
+        let mut history: Vec<Belief> = state
+            .by_entity_predicate
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| state.by_id.get(id).cloned())
+            .collect();
+        super::sort_history(&mut history);
+
+        // `Belief::is_valid_at` hides a belief until its transaction time.
+        let plan = super::plan_coalesce(&history, pinned, |b| b.valid_time.from().max(b.tx_time));
+        for changed in plan.updated {
+            if let Some(belief) = state.by_id.get_mut(&changed.id) {
+                belief.valid_time = changed.valid_time;
+                belief.supersedes = changed.supersedes;
+                belief.superseded_by = changed.superseded_by;
+            }
+        }
+        for id in &plan.removed {
+            if let Some(belief) = state.by_id.remove(id) {
+                Self::index_remove(&mut state, &belief);
+                state.quantized.remove(id);
+            }
+        }
+        Ok(plan.removed.len())
+    }
+
+    fn reembed(&self, embed: &dyn Fn(&Belief) -> Result<Vec<f32>, StorageError>) -> Result<usize, StorageError> {
+        let mut state = self.state.write().map_err(|_| lock_err("belief.reembed"))?;
+        let mut embedding_dim = None;
+        let mut replaced = Vec::new();
+        for belief in state.by_id.values() {
+            if belief.embedding.is_none() && !state.quantized.contains_key(&belief.id) {
+                continue;
+            }
+            let embedding = embed(&Self::materialize(&state, belief))?;
+            ensure_embedding_dim(&mut embedding_dim, &embedding, "belief.reembed")?;
+            replaced.push((belief.id, embedding));
+        }
+
+        let count = replaced.len();
+        for (id, embedding) in replaced {
+            if state.embedding_storage == EmbeddingStorage::Int8 {
+                state.quantized.insert(id, QuantizedEmbedding::quantize(&embedding));
+            } else if let Some(belief) = state.by_id.get_mut(&id) {
+                belief.embedding = Some(embedding);
+            }
+        }
+        state.embedding_dim = embedding_dim;
+        Ok(count)
+    }
+
     fn find_as_of(
         &self,
         entity_id: EntityId,
@@ -683,7 +1072,11 @@ impl BeliefStore for InMemoryBeliefStore {
             .collect())
     }
 
-    fn find_by_time_range(&self, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
+    fn find_by_time_range(
+        &self,
+        namespace: Option<&str>,
+        range: &TimeRange,
+    ) -> Result<Vec<Belief>, StorageError> {
         let state = self
             .state
             .read()
@@ -692,16 +1085,17 @@ impl BeliefStore for InMemoryBeliefStore {
         let mut beliefs: Vec<Belief> = state
             .by_id
             .values()
-            .filter(|b| b.valid_time.overlaps(range))
-            .cloned()
+            .filter(|b| b.namespace.as_deref() == namespace && b.valid_time.overlaps(range))
+            .map(|b| Self::materialize(&state, b))
             .collect();
 
-        beliefs.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
         Ok(beliefs)
     }
 
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
         min_confidence: Option<f32>,
@@ -727,17 +1121,23 @@ impl BeliefStore for InMemoryBeliefStore {
 
         let mut scored: Vec<(Belief, f32)> = Vec::new();
         for belief in state.by_id.values() {
-            let Some(stored) = belief.embedding.as_ref() else {
+            if belief.namespace.as_deref() != namespace {
                 continue;
-            };
+            }
             if belief.confidence.value() < min_confidence {
                 continue;
             }
 
-            let sim = cosine_similarity(embedding, stored)?;
+            let sim = if let Some(quantized) = state.quantized.get(&belief.id) {
+                quantized.cosine_similarity(embedding)
+            } else if let Some(stored) = belief.embedding.as_ref() {
+                cosine_similarity(embedding, stored)?
+            } else {
+                continue;
+            };
 
             if sim > 0.0 {
-                scored.push((belief.clone(), sim));
+                scored.push((Self::materialize(&state, belief), sim));
             }
         }
 
@@ -746,6 +1146,38 @@ impl BeliefStore for InMemoryBeliefStore {
         Ok(scored)
     }
 
+    fn find_entities_by_predicate_value(
+        &self,
+        namespace: Option<&str>,
+        predicate: &str,
+        value: &Value,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<EntityId>, StorageError> {
+        let state = self
+            .state
+            .read()
+            .map_err(|_| lock_err("belief.find_entities_by_predicate_value"))?;
+        let key = (predicate.trim().to_string(), value.stable_hash());
+        let Some(ids) = state.by_predicate_value.get(&key) else {
+            return Ok(Vec::new());
+        };
+
+        let encoding = value.stable_encoding();
+        let mut entities: Vec<EntityId> = ids
+            .iter()
+            .filter_map(|id| state.by_id.get(id))
+            .filter(|b| {
+                b.namespace.as_deref() == namespace
+                    && b.is_valid_at(as_of)
+                    && b.value.stable_encoding() == encoding
+            })
+            .map(|b| b.subject)
+            .collect();
+        entities.sort_by_key(|id| *id.as_uuid());
+        entities.dedup();
+        Ok(entities)
+    }
+
     fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError> {
         let state = self
             .state
@@ -753,12 +1185,46 @@ impl BeliefStore for InMemoryBeliefStore {
             .map_err(|_| lock_err("belief.count_by_entity"))?;
         Ok(state.by_entity.get(&entity_id).map_or(0, Vec::len))
     }
-}
 
-#[derive(Debug, Default)]
-struct ConflictState {
+    fn subjects(&self) -> Result<Vec<EntityId>, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("belief.subjects"))?;
+        Ok(state
+            .by_entity
+            .iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(id, _)| *id)
+            .collect())
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("belief.stats"))?;
+        let predicates: HashSet<&str> = state
+            .by_entity_predicate
+            .keys()
+            .map(|(_, predicate)| predicate.as_str())
+            .collect();
+        Ok(StorageStats {
+            records: state.by_id.len(),
+            distinct_entities: Some(state.by_entity.len()),
+            distinct_predicates: Some(predicates.len()),
+        })
+    }
+
+    /// Shares the persistent maps with the snapshot; a write afterwards copies only the
+    /// paths it touches.
+    fn snapshot(&self) -> Result<Option<Arc<dyn BeliefStore>>, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("belief.snapshot"))?.clone();
+        Ok(Some(Arc::new(Self {
+            state: RwLock::new(state),
+        })))
+    }
+}
+
+#[derive(Debug, Default)]
+struct ConflictState {
     by_id: HashMap<ConflictId, Conflict>,
     by_belief: HashMap<BeliefId, Vec<ConflictId>>,
+    by_entity: HashMap<EntityId, Vec<ConflictId>>,
 }
 
 /// Thread-safe in-memory conflict store.
@@ -773,6 +1239,52 @@ impl InMemoryConflictStore {
     pub fn new() -> Self {
         Self::default()
     }
+
+    fn update_locked(state: &mut ConflictState, conflict: Conflict) -> Result<(), StorageError> {
+        let old = state
+            .by_id
+            .get(&conflict.id)
+            .cloned()
+            .ok_or(StorageError::ConflictNotFound(conflict.id))?;
+
+        let old_ids: HashSet<BeliefId> = old.belief_ids.iter().copied().collect();
+        let new_ids: HashSet<BeliefId> = conflict.belief_ids.iter().copied().collect();
+
+        if old_ids != new_ids {
+            for removed in old_ids.difference(&new_ids) {
+                if let Some(list) = state.by_belief.get_mut(removed) {
+                    list.retain(|cid| *cid != conflict.id);
+                    if list.is_empty() {
+                        state.by_belief.remove(removed);
+                    }
+                }
+            }
+
+            for added in new_ids.difference(&old_ids) {
+                let entry = state.by_belief.entry(*added).or_default();
+                if !entry.contains(&conflict.id) {
+                    entry.push(conflict.id);
+                }
+            }
+        }
+
+        if old.entity_id != conflict.entity_id {
+            if let Some(list) = state.by_entity.get_mut(&old.entity_id) {
+                list.retain(|cid| *cid != conflict.id);
+                if list.is_empty() {
+                    state.by_entity.remove(&old.entity_id);
+                }
+            }
+            state
+                .by_entity
+                .entry(conflict.entity_id)
+                .or_default()
+                .push(conflict.id);
+        }
+
+        state.by_id.insert(conflict.id, conflict);
+        Ok(())
+    }
 }
 
 impl ConflictStore for InMemoryConflictStore {
@@ -793,6 +1305,11 @@ impl ConflictStore for InMemoryConflictStore {
                 .or_default()
                 .push(conflict.id);
         }
+        state
+            .by_entity
+            .entry(conflict.entity_id)
+            .or_default()
+            .push(conflict.id);
 
         state.by_id.insert(conflict.id, conflict);
         Ok(())
@@ -811,36 +1328,21 @@ impl ConflictStore for InMemoryConflictStore {
             .state
             .write()
             .map_err(|_| lock_err("conflict.update"))?;
+        Self::update_locked(&mut state, conflict)
+    }
 
-        let old = state
-            .by_id
-            .get(&conflict.id)
-            .cloned()
-            .ok_or(StorageError::ConflictNotFound(conflict.id))?;
-
-        let old_ids: HashSet<BeliefId> = old.belief_ids.iter().copied().collect();
-        let new_ids: HashSet<BeliefId> = conflict.belief_ids.iter().copied().collect();
-
-        if old_ids != new_ids {
-            for removed in old_ids.difference(&new_ids) {
-                if let Some(list) = state.by_belief.get_mut(removed) {
-                    list.retain(|cid| *cid != conflict.id);
-                    if list.is_empty() {
-                        state.by_belief.remove(removed);
-                    }
-                }
-            }
-
-            for added in new_ids.difference(&old_ids) {
-                let entry = state.by_belief.entry(*added).or_default();
-                if !entry.iter().any(|cid| *cid == conflict.id) {
-                    entry.push(conflict.id);
-                }
-            }
+    fn update_if_open(&self, conflict: Conflict) -> Result<bool, StorageError> {
+        let mut state = self
+            .state
+            .write()
+            .map_err(|_| lock_err("conflict.update_if_open"))?;
+        match state.by_id.get(&conflict.id) {
+            None => return Err(StorageError::ConflictNotFound(conflict.id)),
+            Some(current) if !current.is_open() => return Ok(false),
+            Some(_) => {}
         }
-
-        state.by_id.insert(conflict.id, conflict);
-        Ok(())
+        Self::update_locked(&mut state, conflict)?;
+        Ok(true)
     }
 
     fn find_by_belief(&self, belief_id: BeliefId) -> Result<Vec<Conflict>, StorageError> {
@@ -864,13 +1366,39 @@ impl ConflictStore for InMemoryConflictStore {
             .state
             .read()
             .map_err(|_| lock_err("conflict.find_open"))?;
-        Ok(state
+        let mut open: Vec<Conflict> = state
             .by_id
             .values()
             .filter(|c| c.status == ConflictStatus::Open)
             .cloned()
+            .collect();
+        Conflict::sort_by_severity(&mut open);
+        Ok(open)
+    }
+
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Conflict>, StorageError> {
+        let state = self
+            .state
+            .read()
+            .map_err(|_| lock_err("conflict.find_by_entity"))?;
+
+        let Some(ids) = state.by_entity.get(&entity_id) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(ids
+            .iter()
+            .filter_map(|id| state.by_id.get(id).cloned())
             .collect())
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("conflict.stats"))?;
+        Ok(StorageStats {
+            records: state.by_id.len(),
+            ..StorageStats::default()
+        })
+    }
 }
 
 #[derive(Debug, Default)]
@@ -944,6 +1472,38 @@ impl DerivationStore for InMemoryDerivationStore {
         Ok(state.by_id.get(&id).cloned())
     }
 
+    fn update(&self, record: DerivationRecord) -> Result<(), StorageError> {
+        let mut state = self
+            .state
+            .write()
+            .map_err(|_| lock_err("derivation.update"))?;
+
+        let id = record.id;
+        let old = state
+            .by_id
+            .remove(&id)
+            .ok_or(StorageError::DerivationNotFound(id))?;
+        for premise in &old.premise_ids {
+            if let Some(ids) = state.by_premise.get_mut(premise) {
+                ids.remove(&id);
+            }
+        }
+        if let Some(derived) = old.derived_belief_id {
+            if let Some(ids) = state.by_derived.get_mut(&derived) {
+                ids.remove(&id);
+            }
+        }
+
+        for premise in &record.premise_ids {
+            state.by_premise.entry(*premise).or_default().insert(id);
+        }
+        if let Some(derived) = record.derived_belief_id {
+            state.by_derived.entry(derived).or_default().insert(id);
+        }
+        state.by_id.insert(id, record);
+        Ok(())
+    }
+
     fn find_by_premise(&self, premise_id: BeliefId) -> Result<Vec<DerivationRecord>, StorageError> {
         let state = self
             .state
@@ -976,6 +1536,14 @@ impl DerivationStore for InMemoryDerivationStore {
             .filter_map(|id| state.by_id.get(id).cloned())
             .collect())
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("derivation.stats"))?;
+        Ok(StorageStats {
+            records: state.by_id.len(),
+            ..StorageStats::default()
+        })
+    }
 }
 
 impl InMemoryPatternStore {
@@ -1095,7 +1663,177 @@ impl PatternStore for InMemoryPatternStore {
             .state
             .read()
             .map_err(|_| lock_err("pattern.find_active"))?;
-        Ok(state.by_id.values().filter(|p| p.active).cloned().collect())
+        Ok(state.by_id.values().filter(|p| p.is_active()).cloned().collect())
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let state = self.state.read().map_err(|_| lock_err("pattern.stats"))?;
+        Ok(StorageStats {
+            records: state.by_id.len(),
+            ..StorageStats::default()
+        })
+    }
+}
+
+/// Default number of idempotency keys retained before the oldest are evicted.
+pub const DEFAULT_IDEMPOTENCY_CAPACITY: usize = 10_000;
+
+/// An idempotency key scoped to its tenant namespace; `None` is the default namespace.
+type ScopedKey = (Option<String>, String);
+
+fn scoped_key(namespace: Option<&str>, key: &str) -> ScopedKey {
+    (namespace.map(str::to_string), key.to_string())
+}
+
+/// Bounded (namespace, key) -> belief map with FIFO eviction.
+///
+/// Reservations live only in `pending`: they are never persisted and do not count towards
+/// capacity.
+#[derive(Debug, Clone)]
+pub(crate) struct IdempotencyIndex {
+    by_key: HashMap<ScopedKey, BeliefId>,
+    order: VecDeque<ScopedKey>,
+    pending: HashSet<ScopedKey>,
+    capacity: usize,
+}
+
+impl IdempotencyIndex {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            by_key: HashMap::new(),
+            order: VecDeque::new(),
+            pending: HashSet::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub(crate) fn get(&self, namespace: Option<&str>, key: &str) -> Option<BeliefId> {
+        self.by_key.get(&scoped_key(namespace, key)).copied()
+    }
+
+    /// Number of retained keys.
+    pub(crate) fn len(&self) -> usize {
+        self.by_key.len()
+    }
+
+    /// Claim `key` unless it is recorded or already reserved.
+    pub(crate) fn reserve(
+        &mut self,
+        namespace: Option<&str>,
+        key: &str,
+    ) -> Result<IdempotencyReservation, StorageError> {
+        if let Some(belief_id) = self.get(namespace, key) {
+            return Ok(IdempotencyReservation::Existing(belief_id));
+        }
+        if !self.pending.insert(scoped_key(namespace, key)) {
+            return Err(StorageError::DuplicateKey(format!(
+                "idempotency key {key:?} is still in flight"
+            )));
+        }
+        Ok(IdempotencyReservation::Reserved)
+    }
+
+    /// Drop a reservation without recording it.
+    pub(crate) fn release(&mut self, namespace: Option<&str>, key: &str) {
+        self.pending.remove(&scoped_key(namespace, key));
+    }
+
+    /// Insert a key if absent, evicting the oldest keys beyond capacity.
+    pub(crate) fn insert(&mut self, namespace: Option<&str>, key: &str, belief_id: BeliefId) {
+        let scoped = scoped_key(namespace, key);
+        self.pending.remove(&scoped);
+        if self.by_key.contains_key(&scoped) {
+            return;
+        }
+        self.by_key.insert(scoped.clone(), belief_id);
+        self.order.push_back(scoped);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.by_key.remove(&oldest);
+            }
+        }
+    }
+
+    /// Retained `(namespace, key, belief)` entries, oldest first.
+    #[cfg(feature = "persistent")]
+    pub(crate) fn entries(&self) -> Vec<(Option<String>, String, BeliefId)> {
+        self.order
+            .iter()
+            .filter_map(|k| self.by_key.get(k).map(|id| (k.0.clone(), k.1.clone(), *id)))
+            .collect()
+    }
+}
+
+impl Default for IdempotencyIndex {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDEMPOTENCY_CAPACITY)
+    }
+}
+
+/// Thread-safe, bounded in-memory idempotency key store.
+#[derive(Debug, Default)]
+pub struct InMemoryIdempotencyStore {
+    state: RwLock<IdempotencyIndex>,
+}
+
+impl InMemoryIdempotencyStore {
+    /// Create a new empty store retaining `DEFAULT_IDEMPOTENCY_CAPACITY` keys.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new empty store retaining at most `capacity` keys.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            state: RwLock::new(IdempotencyIndex::new(capacity)),
+        }
+    }
+}
+
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    fn get(&self, namespace: Option<&str>, key: &str) -> Result<Option<BeliefId>, StorageError> {
+        let state = self
+            .state
+            .read()
+            .map_err(|_| lock_err("idempotency.get"))?;
+        Ok(state.get(namespace, key))
+    }
+
+    fn reserve(&self, namespace: Option<&str>, key: &str) -> Result<IdempotencyReservation, StorageError> {
+        self.state
+            .write()
+            .map_err(|_| lock_err("idempotency.reserve"))?
+            .reserve(namespace, key)
+    }
+
+    fn release(&self, namespace: Option<&str>, key: &str) -> Result<(), StorageError> {
+        self.state
+            .write()
+            .map_err(|_| lock_err("idempotency.release"))?
+            .release(namespace, key);
+        Ok(())
+    }
+
+    fn record(&self, namespace: Option<&str>, key: &str, belief_id: BeliefId) -> Result<(), StorageError> {
+        let mut state = self
+            .state
+            .write()
+            .map_err(|_| lock_err("idempotency.record"))?;
+        state.insert(namespace, key, belief_id);
+        Ok(())
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let state = self
+            .state
+            .read()
+            .map_err(|_| lock_err("idempotency.stats"))?;
+        Ok(StorageStats {
+            records: state.len(),
+            ..StorageStats::default()
+        })
     }
 }
 
@@ -1153,25 +1891,25 @@ mod tests {
         assert_eq!(got, e);
 
         // Exact name lookup is normalized.
-        let exact = store.find_by_name("  acme corp ").unwrap();
+        let exact = store.find_by_name(None, "  acme corp ").unwrap();
         assert_eq!(exact.len(), 1);
         assert_eq!(exact[0].id, id);
 
         // Fuzzy lookup works against canonical and aliases.
-        let fuzzy1 = store.find_by_name_fuzzy("acm", 10).unwrap();
+        let fuzzy1 = store.find_by_name_fuzzy(None, "acm", 10).unwrap();
         assert!(fuzzy1.iter().any(|x| x.id == id));
-        let fuzzy2 = store.find_by_name_fuzzy("corporation", 10).unwrap();
+        let fuzzy2 = store.find_by_name_fuzzy(None, "corporation", 10).unwrap();
         assert!(fuzzy2.iter().any(|x| x.id == id));
 
         // Embedding search matches.
-        let emb = store.find_by_embedding(&[1.0, 0.0, 0.0], 10).unwrap();
+        let emb = store.find_by_embedding(None, &[1.0, 0.0, 0.0], 10).unwrap();
         assert_eq!(emb.len(), 1);
         assert_eq!(emb[0].0.id, id);
         assert!(emb[0].1 > 0.99);
 
         // Dimension mismatch is rejected (caller must provide correct dimensionality).
         assert!(matches!(
-            store.find_by_embedding(&[1.0, 0.0], 10),
+            store.find_by_embedding(None, &[1.0, 0.0], 10),
             Err(StorageError::BackendError(_))
         ));
 
@@ -1179,16 +1917,146 @@ mod tests {
         let mut e2 = got.clone();
         e2.set_canonical_name("Acme Incorporated");
         store.update(e2.clone()).unwrap();
-        assert!(store.find_by_name("acme corp").unwrap().is_empty());
-        assert_eq!(store.find_by_name("acme incorporated").unwrap()[0].id, id);
+        assert!(store.find_by_name(None, "acme corp").unwrap().is_empty());
+        assert_eq!(store.find_by_name(None, "acme incorporated").unwrap()[0].id, id);
 
         // Delete removes from indexes.
         store.delete(id).unwrap();
         assert!(store.get(id).unwrap().is_none());
-        assert!(store.find_by_name("acme incorporated").unwrap().is_empty());
+        assert!(store.find_by_name(None, "acme incorporated").unwrap().is_empty());
         assert!(matches!(store.delete(id), Err(StorageError::EntityNotFound(_))));
     }
 
+    #[test]
+    fn unique_name_store_rejects_canonical_name_collisions() {
+        let org = crate::entity::EntityType::Organization;
+        let first = Entity::new("Acme Corp", org.clone());
+        let second = Entity::new("  acme corp", org.clone());
+
+        let lenient = InMemoryEntityStore::new();
+        lenient.insert(first.clone()).unwrap();
+        lenient.insert(second.clone()).unwrap();
+        assert_eq!(lenient.find_by_name(None, "acme corp").unwrap().len(), 2);
+
+        let strict = InMemoryEntityStore::with_unique_names();
+        strict.insert(first.clone()).unwrap();
+        assert!(matches!(strict.insert(second.clone()), Err(StorageError::DuplicateKey(_))));
+        assert!(matches!(
+            strict.insert_many(vec![second.clone()], false),
+            Err(StorageError::DuplicateKey(_))
+        ));
+        // Other namespaces and shared aliases are fine.
+        strict.insert(second.clone().with_namespace("other")).unwrap();
+        let mut aliased = Entity::new("Acme Holdings", org.clone());
+        aliased.add_alias("Acme Corp");
+        strict.insert(aliased.clone()).unwrap();
+
+        // Renaming onto a taken name is rejected; renaming an entity onto its own name is not.
+        let mut renamed = strict.get(aliased.id).unwrap().unwrap();
+        renamed.set_canonical_name("ACME CORP");
+        assert!(matches!(strict.update(renamed), Err(StorageError::DuplicateKey(_))));
+        let mut touched = strict.get(first.id).unwrap().unwrap();
+        touched.set_canonical_name("Acme corp");
+        strict.update(touched).unwrap();
+        assert_eq!(strict.find_by_name(None, "acme corp").unwrap().len(), 1);
+
+        let batch = vec![Entity::new("Initech", org.clone()), Entity::new("initech", org)];
+        assert!(matches!(strict.insert_many(batch, false), Err(StorageError::DuplicateKey(_))));
+    }
+
+    #[test]
+    fn find_by_type_includes_subtypes_from_the_hierarchy() {
+        use crate::entity::{EntityType, EntityTypeHierarchy};
+
+        let company = EntityType::Custom("company".to_string());
+        let hierarchy = EntityTypeHierarchy::new()
+            .with_subtype(EntityType::Organization, company.clone())
+            .unwrap();
+        let store = InMemoryEntityStore::new().with_type_hierarchy(hierarchy);
+        let un = Entity::new("United Nations", EntityType::Organization);
+        let acme = Entity::new("Acme", company.clone());
+        let alice = Entity::new("Alice", EntityType::Person);
+        for entity in [&un, &acme, &alice] {
+            store.insert(entity.clone()).unwrap();
+        }
+
+        let ids = |found: Vec<Entity>| found.into_iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(ids(store.find_by_type(&EntityType::Organization, true).unwrap()), vec![acme.id, un.id]);
+        assert_eq!(ids(store.find_by_type(&EntityType::Organization, false).unwrap()), vec![un.id]);
+        assert_eq!(ids(store.find_by_type(&company, true).unwrap()), vec![acme.id]);
+
+        // The index follows type changes, merges and deletes.
+        let mut person = store.get(alice.id).unwrap().unwrap();
+        person.entity_type = company;
+        person.version += 1;
+        store.update(person).unwrap();
+        assert_eq!(store.find_by_type(&EntityType::Organization, true).unwrap().len(), 3);
+        assert!(store.find_by_type(&EntityType::Person, true).unwrap().is_empty());
+
+        store.merge(acme.id, alice.id).unwrap();
+        store.delete(un.id).unwrap();
+        assert_eq!(ids(store.find_by_type(&EntityType::Organization, true).unwrap()), vec![acme.id]);
+    }
+
+    #[test]
+    fn entity_lookups_are_scoped_by_namespace() {
+        let store = InMemoryEntityStore::new();
+        let mut a = Entity::new("Acme Corp", crate::entity::EntityType::Organization).with_namespace("a");
+        a.embedding = Some(vec![1.0, 0.0, 0.0]);
+        let mut b = a.clone().with_namespace("b");
+        b.id = EntityId::new();
+        let default = Entity::new("Acme Corp", crate::entity::EntityType::Organization);
+        // Name dedup only folds entities within one namespace.
+        let assigned = store
+            .insert_many(vec![a.clone(), b.clone(), default.clone()], true)
+            .unwrap();
+        assert!(assigned.iter().all(|(from, to)| from == to));
+
+        let ids = |found: Vec<Entity>| found.into_iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(ids(store.find_by_name(Some("a"), "acme corp").unwrap()), vec![a.id]);
+        assert_eq!(ids(store.find_by_name(Some("b"), "acme corp").unwrap()), vec![b.id]);
+        assert_eq!(ids(store.find_by_name(None, "acme corp").unwrap()), vec![default.id]);
+        assert_eq!(ids(store.find_by_name_fuzzy(Some("b"), "acme", 10).unwrap()), vec![b.id]);
+        assert!(store.find_by_name(Some("c"), "acme corp").unwrap().is_empty());
+
+        let emb = store.find_by_embedding(Some("a"), &[1.0, 0.0, 0.0], 10).unwrap();
+        assert_eq!(emb.len(), 1);
+        assert_eq!(emb[0].0.id, a.id);
+
+        // Entities never cross namespaces.
+        assert!(store.merge(a.id, b.id).is_err());
+        let mut moved = store.get(a.id).unwrap().unwrap();
+        moved.namespace = Some("b".to_string());
+        assert!(store.update(moved).is_err());
+    }
+
+    #[test]
+    fn fuzzy_lookup_tolerates_typos_below_prefix_matches() {
+        let store = InMemoryEntityStore::new();
+        for name in ["Acme Corp", "Acne", "Globex Industries"] {
+            store
+                .insert(Entity::new(name, crate::entity::EntityType::Organization))
+                .unwrap();
+        }
+        let names = |query: &str| {
+            store
+                .find_by_name_fuzzy(None, query, 10)
+                .unwrap()
+                .into_iter()
+                .map(|e| e.canonical_name)
+                .collect::<Vec<_>>()
+        };
+
+        // A transposition is one edit.
+        assert_eq!(names("Acme Crop"), vec!["Acme Corp"]);
+        assert_eq!(names("globex industires"), vec!["Globex Industries"]);
+        assert!(names("Acme Xyzw").is_empty());
+        assert!(names("Initech").is_empty());
+        // Prefix matches outrank typos; short queries get no typo tolerance.
+        assert_eq!(names("acme"), vec!["Acme Corp", "Acne"]);
+        assert_eq!(names("acm"), vec!["Acme Corp"]);
+    }
+
     #[test]
     fn derivation_insert_get_and_indexes() {
         use chrono::Utc;
@@ -1359,6 +2227,85 @@ mod tests {
             .is_some());
     }
 
+    #[test]
+    fn entity_unmerge_restores_both_entities_and_their_aliases() {
+        let store = InMemoryEntityStore::new();
+
+        let mut primary = Entity::new("Acme Corp", crate::entity::EntityType::Organization);
+        primary.add_alias("ACME");
+        primary.metadata = serde_json::json!({ "hq": "Berlin" });
+        let primary_id = primary.id;
+
+        let mut secondary = Entity::new("Acme Labs", crate::entity::EntityType::Organization);
+        secondary.add_alias("AL");
+        secondary.metadata = serde_json::json!({ "hq": "Paris", "founded": 1999 });
+        let secondary_id = secondary.id;
+
+        let secondary_version = secondary.version;
+        store.insert(primary).unwrap();
+        store.insert(secondary).unwrap();
+        let merged = store.merge(primary_id, secondary_id).unwrap();
+
+        let (primary_after, restored) = store.unmerge(primary_id, secondary_id).unwrap();
+        assert_eq!(primary_after.aliases, vec!["ACME".to_string()]);
+        assert_eq!(primary_after.metadata, serde_json::json!({ "hq": "Berlin" }));
+        assert_eq!(primary_after.version, merged.version + 1);
+        assert_eq!(restored.id, secondary_id);
+        assert_eq!(restored.aliases, vec!["AL".to_string()]);
+        assert_eq!(restored.version, secondary_version + 1);
+
+        assert_eq!(store.get(primary_id).unwrap().unwrap().id, primary_id);
+        assert_eq!(store.get(secondary_id).unwrap().unwrap().id, secondary_id);
+        assert_eq!(store.find_by_name(None, "Acme Labs").unwrap()[0].id, secondary_id);
+
+        let err = store.unmerge(primary_id, secondary_id).unwrap_err();
+        assert!(matches!(err, StorageError::BackendError(_)));
+
+        // The restored entity can be merged again.
+        store.merge(primary_id, secondary_id).unwrap();
+        assert_eq!(store.get(secondary_id).unwrap().unwrap().id, primary_id);
+    }
+
+    #[test]
+    fn entity_insert_many_dedups_by_normalized_name() {
+        let store = InMemoryEntityStore::new();
+
+        let existing = Entity::new("Globex", crate::entity::EntityType::Organization);
+        let existing_id = existing.id;
+        store.insert(existing).unwrap();
+
+        let mut first = Entity::new("Acme Corp", crate::entity::EntityType::Organization);
+        first.add_alias("ACME");
+        first.metadata = serde_json::json!({ "hq": "Berlin" });
+        let mut second = Entity::new("  acme corp ", crate::entity::EntityType::Organization);
+        second.add_alias("Acme Inc.");
+        second.metadata = serde_json::json!({ "hq": "Paris", "founded": 1999 });
+        let globex = Entity::new("GLOBEX", crate::entity::EntityType::Organization);
+        let other = Entity::new("Initech", crate::entity::EntityType::Organization);
+        let ids = [first.id, second.id, globex.id, other.id];
+
+        let mapping = store
+            .insert_many(vec![first.clone(), second.clone(), globex, other], true)
+            .unwrap();
+        assert_eq!(mapping[&ids[0]], ids[0]);
+        assert_eq!(mapping[&ids[1]], ids[0]);
+        assert_eq!(mapping[&ids[2]], existing_id);
+        assert_eq!(mapping[&ids[3]], ids[3]);
+
+        let acme = store.find_by_name(None, "Acme Corp").unwrap();
+        assert_eq!(acme.len(), 1);
+        assert!(acme[0].aliases.iter().any(|a| a == "Acme Inc."));
+        assert_eq!(acme[0].metadata, serde_json::json!({ "hq": "Berlin", "founded": 1999 }));
+        assert!(store.get(ids[1]).unwrap().is_none());
+        assert_eq!(store.get(existing_id).unwrap().unwrap().version, 2);
+
+        // Without dedup, same-named entities keep their own ids.
+        let store = InMemoryEntityStore::new();
+        let mapping = store.insert_many(vec![first, second], false).unwrap();
+        assert_eq!(mapping[&ids[1]], ids[1]);
+        assert_eq!(store.find_by_name(None, "acme corp").unwrap().len(), 2);
+    }
+
     fn mk_belief(entity_id: EntityId, predicate: &str, value: Value, tx_time: DateTime<Utc>) -> Belief {
         Belief {
             id: BeliefId::new(),
@@ -1371,9 +2318,14 @@ mod tests {
             tx_time,
             reason: None,
             consistency_status: crate::belief::ConsistencyStatus::Provisional,
-            supersedes: None,
+            supersedes: Vec::new(),
             superseded_by: None,
             embedding: None,
+            namespace: None,
+            corroborating_sources: Vec::new(),
+            metadata: serde_json::Value::Null,
+            idempotency_key: None,
+            retraction: false,
         }
     }
 
@@ -1408,6 +2360,93 @@ mod tests {
         assert!(as_of_late.is_empty());
     }
 
+    #[test]
+    fn non_finite_embeddings_are_rejected_on_insert() {
+        let beliefs = InMemoryBeliefStore::new();
+        let mut belief = mk_belief(EntityId::new(), "status", Value::from("ok"), Utc::now());
+        belief.embedding = Some(vec![0.5, f32::NAN, 0.1]);
+        let err = beliefs.insert(belief.clone()).unwrap_err();
+        assert!(err.to_string().contains("not finite"), "{err}");
+        assert!(beliefs.get(belief.id).unwrap().is_none());
+
+        // A rejected vector must not pin the store's embedding dimension.
+        belief.embedding = Some(vec![0.5, 0.1]);
+        beliefs.insert(belief).unwrap();
+
+        let entities = InMemoryEntityStore::new();
+        let mut entity = Entity::new("Acme Corp", crate::entity::EntityType::Organization);
+        entity.embedding = Some(vec![f32::INFINITY, 0.0]);
+        assert!(entities.insert(entity.clone()).is_err());
+        assert!(entities.get(entity.id).unwrap().is_none());
+    }
+
+    #[test]
+    fn belief_snapshot_is_isolated_from_later_writes() {
+        let beliefs = InMemoryBeliefStore::new();
+        let eid = EntityId::new();
+        let first = mk_belief(eid, "status", Value::from("ok"), Utc::now());
+        beliefs.insert(first.clone()).unwrap();
+
+        let snapshot = beliefs.snapshot().unwrap().unwrap();
+        let second = mk_belief(eid, "status", Value::from("degraded"), Utc::now());
+        beliefs.insert(second.clone()).unwrap();
+        beliefs.supersede(first.id, second.id).unwrap();
+
+        let seen = snapshot.find_by_entity_predicate(eid, "status").unwrap();
+        assert_eq!(seen.len(), 1);
+        assert!(seen[0].is_active());
+        assert!(snapshot.get(second.id).unwrap().is_none());
+        assert_eq!(beliefs.find_by_entity_predicate(eid, "status").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn belief_amend_updates_metadata_in_place_and_rejects_semantic_changes() {
+        let beliefs = InMemoryBeliefStore::new();
+        let belief = mk_belief(EntityId::new(), "status", Value::from("ok"), Utc::now());
+        let id = belief.id;
+        beliefs.insert(belief).unwrap();
+
+        beliefs
+            .amend(id, AmendFields { reason: Some("typo in original reason".to_string()), ..AmendFields::default() })
+            .unwrap();
+        let amended = beliefs.get(id).unwrap().unwrap();
+        assert_eq!(amended.reason.as_deref(), Some("typo in original reason"));
+        assert_eq!(amended.value, Value::from("ok"));
+        assert!(amended.is_active());
+
+        let err = beliefs
+            .amend(id, AmendFields { value: Some(Value::from("bad")), ..AmendFields::default() })
+            .unwrap_err();
+        assert!(err.to_string().contains("supersede"), "{err}");
+        let err = beliefs
+            .amend(id, AmendFields { predicate: Some("health".to_string()), ..AmendFields::default() })
+            .unwrap_err();
+        assert!(err.to_string().contains("supersede"), "{err}");
+        assert_eq!(beliefs.get(id).unwrap().unwrap().predicate, "status");
+
+        // Confidence changes need an audit note.
+        let confidence = Confidence::from_agent(0.4, "agent").unwrap();
+        assert!(beliefs
+            .amend(id, AmendFields { confidence: Some(confidence.clone()), ..AmendFields::default() })
+            .is_err());
+        beliefs
+            .amend(
+                id,
+                AmendFields {
+                    confidence: Some(confidence),
+                    audit_note: Some("recalibrated".to_string()),
+                    ..AmendFields::default()
+                },
+            )
+            .unwrap();
+        assert!((beliefs.get(id).unwrap().unwrap().confidence.value() - 0.4).abs() < f32::EPSILON);
+
+        assert!(matches!(
+            beliefs.amend(BeliefId::new(), AmendFields { reason: Some("x".to_string()), ..AmendFields::default() }),
+            Err(StorageError::BeliefNotFound(_))
+        ));
+    }
+
     #[test]
     fn belief_supersede_invariants_and_valid_time_clamp() {
         let beliefs = InMemoryBeliefStore::new();
@@ -1433,7 +2472,7 @@ mod tests {
         assert!(old_after.valid_time.to().is_some());
 
         let new_after = beliefs.get(new_id).unwrap().unwrap();
-        assert_eq!(new_after.supersedes, Some(old_id));
+        assert_eq!(new_after.supersedes, vec![old_id]);
 
         // Idempotent supersession.
         beliefs.supersede(old_id, new_id).unwrap();
@@ -1451,6 +2490,188 @@ mod tests {
         assert!(beliefs.supersede(other_old_id, new_id).is_err());
     }
 
+    #[test]
+    fn belief_store_supersede_all_links_every_predecessor_at_once() {
+        let beliefs = InMemoryBeliefStore::new();
+        let eid = EntityId::new();
+        let base = Utc::now();
+
+        let olds: Vec<Belief> = ["a", "b"]
+            .iter()
+            .map(|v| mk_belief(eid, "status", Value::String(v.to_string()), base))
+            .collect();
+        let old_ids: Vec<BeliefId> = olds.iter().map(|b| b.id).collect();
+        for old in olds {
+            beliefs.insert(old).unwrap();
+        }
+        let new = mk_belief(eid, "status", Value::String("c".to_string()), base + Duration::seconds(10));
+        let new_id = new.id;
+        beliefs.insert(new).unwrap();
+
+        beliefs.supersede_all(&old_ids, new_id).unwrap();
+        beliefs.supersede_all(&old_ids, new_id).unwrap();
+        assert_eq!(beliefs.get(new_id).unwrap().unwrap().supersedes, old_ids);
+        for old_id in &old_ids {
+            let old = beliefs.get(*old_id).unwrap().unwrap();
+            assert_eq!(old.superseded_by, Some(new_id));
+            assert!(old.valid_time.to().is_some());
+        }
+        // The set is fixed once linked.
+        assert!(beliefs.supersede(old_ids[0], new_id).is_err());
+    }
+
+    #[test]
+    fn insert_superseding_writes_nothing_when_a_predecessor_is_taken() {
+        let beliefs = InMemoryBeliefStore::new();
+        let eid = EntityId::new();
+        let base = Utc::now();
+        let old = mk_belief(eid, "status", Value::String("a".to_string()), base);
+        let old_id = old.id;
+        beliefs.insert(old).unwrap();
+
+        let first = mk_belief(eid, "status", Value::String("b".to_string()), base + Duration::seconds(10));
+        let first_id = first.id;
+        beliefs.insert_superseding(first, &[old_id]).unwrap();
+        assert_eq!(beliefs.get(first_id).unwrap().unwrap().supersedes, vec![old_id]);
+        assert_eq!(beliefs.get(old_id).unwrap().unwrap().superseded_by, Some(first_id));
+
+        let second = mk_belief(eid, "status", Value::String("c".to_string()), base + Duration::seconds(20));
+        let second_id = second.id;
+        assert!(beliefs.insert_superseding(second, &[old_id]).is_err());
+        assert!(beliefs.get(second_id).unwrap().is_none());
+        assert_eq!(beliefs.find_by_entity_predicate(eid, "status").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn history_returns_superseded_beliefs_oldest_first() {
+        let beliefs = InMemoryBeliefStore::new();
+        let eid = EntityId::new();
+        let base = Utc::now() - Duration::minutes(5);
+
+        let chain: Vec<Belief> = ["draft", "review", "final"]
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let tx = base + Duration::seconds(i as i64 * 10);
+                let mut belief = mk_belief(eid, "status", Value::from(*value), tx);
+                belief.valid_time = TimeRange::starting_at(tx);
+                belief
+            })
+            .collect();
+        let ids: Vec<BeliefId> = chain.iter().map(|b| b.id).collect();
+        // Insertion order must not matter.
+        for belief in chain.into_iter().rev() {
+            beliefs.insert(belief).unwrap();
+        }
+        beliefs.insert(mk_belief(eid, "owner", Value::from("ops"), base)).unwrap();
+        beliefs.supersede(ids[0], ids[1]).unwrap();
+        beliefs.supersede(ids[1], ids[2]).unwrap();
+
+        assert_eq!(beliefs.find_as_of(eid, "status", Utc::now()).unwrap().len(), 1);
+        let history = beliefs.history(eid, "status").unwrap();
+        assert_eq!(history.iter().map(|b| b.id).collect::<Vec<_>>(), ids);
+        assert_eq!(history[0].superseded_by, Some(ids[1]));
+        assert!(beliefs.history(EntityId::new(), "status").unwrap().is_empty());
+    }
+
+    #[test]
+    fn coalesce_merges_identical_back_to_back_versions() {
+        let beliefs = InMemoryBeliefStore::new();
+        let eid = EntityId::new();
+        let base = Utc::now() - Duration::minutes(5);
+        let at = |s: i64| base + Duration::seconds(s);
+
+        // on, on, off, off, on: each recorded when it becomes valid and superseding the last.
+        let values = ["on", "on", "off", "off", "on"];
+        let mut ids = Vec::new();
+        for (i, value) in values.iter().enumerate() {
+            let tx = at(i as i64 * 10);
+            let mut belief = mk_belief(eid, "status", Value::from(*value), tx);
+            belief.valid_time = TimeRange::starting_at(tx);
+            if i == 3 {
+                belief.confidence = Confidence::from_agent(0.5, "agent").unwrap();
+            }
+            ids.push(belief.id);
+            beliefs.insert(belief).unwrap();
+            if i > 0 {
+                beliefs.supersede(ids[i - 1], ids[i]).unwrap();
+            }
+        }
+        let answers = |beliefs: &InMemoryBeliefStore| {
+            (0..50)
+                .map(|s| {
+                    beliefs
+                        .find_as_of(eid, "status", at(s))
+                        .unwrap()
+                        .into_iter()
+                        .map(|b| (b.value, b.confidence.value()))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let before = answers(&beliefs);
+
+        // Only the first pair qualifies: the "off" pair differs in confidence.
+        assert_eq!(beliefs.coalesce(eid, "status", &HashSet::new()).unwrap(), 1);
+        assert_eq!(answers(&beliefs), before);
+        assert!(beliefs.get(ids[1]).unwrap().is_none());
+        let merged = beliefs.get(ids[0]).unwrap().unwrap();
+        assert_eq!(merged.valid_time.to(), Some(at(20)));
+        assert_eq!(merged.superseded_by, Some(ids[2]));
+        assert_eq!(beliefs.get(ids[2]).unwrap().unwrap().supersedes, vec![ids[0]]);
+        assert_eq!(beliefs.history(eid, "status").unwrap().len(), 4);
+
+        assert_eq!(beliefs.coalesce(eid, "status", &HashSet::new()).unwrap(), 0);
+    }
+
+    #[test]
+    fn coalesce_keeps_versions_whose_metadata_differs() {
+        let beliefs = InMemoryBeliefStore::new();
+        let eid = EntityId::new();
+        let base = Utc::now() - Duration::minutes(5);
+        let first_tx = base;
+        let second_tx = base + Duration::seconds(10);
+
+        let mut first = mk_belief(eid, "status", Value::from("on"), first_tx);
+        first.valid_time = TimeRange::starting_at(first_tx);
+        first.metadata = serde_json::json!({"page": 1});
+        let mut second = mk_belief(eid, "status", Value::from("on"), second_tx);
+        second.valid_time = TimeRange::starting_at(second_tx);
+        second.metadata = serde_json::json!({"page": 2});
+        let (first_id, second_id) = (first.id, second.id);
+        beliefs.insert(first).unwrap();
+        beliefs.insert(second).unwrap();
+        beliefs.supersede(first_id, second_id).unwrap();
+
+        assert_eq!(beliefs.coalesce(eid, "status", &HashSet::new()).unwrap(), 0);
+        assert_eq!(beliefs.history(eid, "status").unwrap().len(), 2);
+        assert_eq!(
+            beliefs.get(second_id).unwrap().unwrap().metadata,
+            serde_json::json!({"page": 2})
+        );
+    }
+
+    #[test]
+    fn coalesce_keeps_versions_backed_by_different_sources() {
+        let beliefs = InMemoryBeliefStore::new();
+        let eid = EntityId::new();
+        let base = Utc::now() - Duration::minutes(5);
+        let second_tx = base + Duration::seconds(10);
+
+        let mut first = mk_belief(eid, "status", Value::from("on"), base);
+        first.valid_time = TimeRange::starting_at(base);
+        let mut second = mk_belief(eid, "status", Value::from("on"), second_tx);
+        second.valid_time = TimeRange::starting_at(second_tx);
+        second.corroborating_sources = vec![Source::agent("witness", Option::<String>::None)];
+        let (first_id, second_id) = (first.id, second.id);
+        beliefs.insert(first).unwrap();
+        beliefs.insert(second).unwrap();
+        beliefs.supersede(first_id, second_id).unwrap();
+
+        assert_eq!(beliefs.coalesce(eid, "status", &HashSet::new()).unwrap(), 0);
+        assert_eq!(beliefs.get(second_id).unwrap().unwrap().corroborating_sources.len(), 1);
+    }
+
     #[test]
     fn conflict_store_indexes_and_find_open() {
         let store = InMemoryConflictStore::new();
@@ -1502,6 +2723,66 @@ mod tests {
         assert_eq!(by_b2[0].id, cid);
     }
 
+    #[test]
+    fn conflict_store_find_by_entity() {
+        let store = InMemoryConflictStore::new();
+        let e1 = EntityId::new();
+        let e2 = EntityId::new();
+
+        let c1 = Conflict::value_contradiction(vec![BeliefId::new()], e1, "a");
+        let c2 = Conflict::value_contradiction(vec![BeliefId::new()], e1, "b");
+        let c3 = Conflict::value_contradiction(vec![BeliefId::new()], e2, "a");
+        let (c1_id, c2_id, c3_id) = (c1.id, c2.id, c3.id);
+        store.insert(c1).unwrap();
+        store.insert(c2).unwrap();
+        store.insert(c3.clone()).unwrap();
+
+        let mut for_e1: Vec<_> = store.find_by_entity(e1).unwrap().iter().map(|c| c.id).collect();
+        for_e1.sort_by_key(ToString::to_string);
+        let mut expected = vec![c1_id, c2_id];
+        expected.sort_by_key(ToString::to_string);
+        assert_eq!(for_e1, expected);
+        assert!(store.find_by_entity(EntityId::new()).unwrap().is_empty());
+
+        // Moving a conflict to another entity re-indexes it.
+        let mut moved = c3;
+        moved.entity_id = e1;
+        store.update(moved).unwrap();
+        assert!(store.find_by_entity(e2).unwrap().is_empty());
+        assert!(store.find_by_entity(e1).unwrap().iter().any(|c| c.id == c3_id));
+    }
+
+
+    #[test]
+    fn idempotency_store_keeps_first_record_and_evicts_oldest() {
+        let store = InMemoryIdempotencyStore::with_capacity(2);
+        let (b1, b2, b3) = (BeliefId::new(), BeliefId::new(), BeliefId::new());
+
+        store.record(None, "k1", b1).unwrap();
+        store.record(None, "k1", b2).unwrap();
+        assert_eq!(store.get(None, "k1").unwrap(), Some(b1));
+
+        store.record(None, "k2", b2).unwrap();
+        store.record(None, "k3", b3).unwrap();
+        assert_eq!(store.get(None, "k1").unwrap(), None);
+        assert_eq!(store.get(None, "k2").unwrap(), Some(b2));
+        assert_eq!(store.get(None, "k3").unwrap(), Some(b3));
+    }
+
+    #[test]
+    fn idempotency_reservations_block_a_second_claim_until_recorded_or_released() {
+        let store = InMemoryIdempotencyStore::new();
+        let belief_id = BeliefId::new();
+
+        assert_eq!(store.reserve(None, "k").unwrap(), IdempotencyReservation::Reserved);
+        assert!(matches!(store.reserve(None, "k"), Err(StorageError::DuplicateKey(_))));
+        store.release(None, "k").unwrap();
+
+        assert_eq!(store.reserve(None, "k").unwrap(), IdempotencyReservation::Reserved);
+        store.record(None, "k", belief_id).unwrap();
+        assert_eq!(store.reserve(None, "k").unwrap(), IdempotencyReservation::Existing(belief_id));
+    }
+
     #[test]
     fn pattern_store_primary_predicate_index_update_delete() {
         let store = InMemoryPatternStore::new();
@@ -1512,6 +2793,7 @@ mod tests {
                 predicate: "temperature".to_string(),
                 min: Some(-50.0),
                 max: Some(150.0),
+                coerce: false,
             },
             Confidence::from_agent(0.8, "agent").unwrap(),
         );
@@ -1540,4 +2822,212 @@ mod tests {
         assert!(store.find_by_predicate("pressure").unwrap().is_empty());
         assert!(matches!(store.delete(pid), Err(StorageError::PatternNotFound(_))));
     }
+
+    #[test]
+    fn pattern_store_find_active_excludes_inactive_and_future_dated() {
+        let store = InMemoryPatternStore::new();
+        let rule = || PatternRule::unique("status");
+        let conf = || Confidence::from_agent(0.8, "agent").unwrap();
+
+        let current = Pattern::new("current", rule(), conf());
+        let future = Pattern::new("future", rule(), conf())
+            .with_valid_time(TimeRange::starting_at(Utc::now() + Duration::hours(1)));
+        let mut disabled = Pattern::new("disabled", rule(), conf());
+        disabled.deactivate();
+        let current_id = current.id;
+        for p in [current, future, disabled] {
+            store.insert(p).unwrap();
+        }
+
+        let active = store.find_active().unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, current_id);
+        // Predicate lookups still return every registered pattern.
+        assert_eq!(store.find_by_predicate("status").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn int8_embeddings_keep_top_k_recall_close_to_f32() {
+        const DIM: usize = 32;
+        const K: usize = 10;
+
+        // Deterministic LCG so the fixture is identical on every run.
+        let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = move || {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            #[allow(clippy::cast_precision_loss)]
+            let unit = (seed >> 40) as f32 / (1u64 << 24) as f32;
+            unit * 2.0 - 1.0
+        };
+
+        let exact = InMemoryBeliefStore::new();
+        let compact = InMemoryBeliefStore::with_embedding_storage(EmbeddingStorage::Int8);
+        let entity = EntityId::new();
+        for _ in 0..500 {
+            let mut belief = mk_belief(entity, "embedding", Value::Null, Utc::now());
+            let embedding: Vec<f32> = (0..DIM).map(|_| next()).collect();
+            belief.embedding = Some(embedding);
+            exact.insert(belief.clone()).unwrap();
+            compact.insert(belief).unwrap();
+        }
+
+        let mut hits = 0usize;
+        let mut total = 0usize;
+        for _ in 0..20 {
+            let query: Vec<f32> = (0..DIM).map(|_| next()).collect();
+            let expected: HashSet<BeliefId> = exact
+                .find_by_embedding(None, &query, K, None)
+                .unwrap()
+                .into_iter()
+                .map(|(b, _)| b.id)
+                .collect();
+            let actual = compact.find_by_embedding(None, &query, K, None).unwrap();
+            hits += actual.iter().filter(|(b, _)| expected.contains(&b.id)).count();
+            total += expected.len();
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let recall = hits as f32 / total as f32;
+        assert!(recall >= 0.9, "int8 top-{K} recall {recall} fell below 0.9");
+
+        // Reads return the dequantized approximation, within the per-component bound.
+        let original = exact.find_by_entity(entity).unwrap();
+        let first = &original[0];
+        let restored = compact.get(first.id).unwrap().unwrap();
+        let bound = QuantizedEmbedding::quantize(first.embedding.as_ref().unwrap()).max_error();
+        for (a, b) in first.embedding.as_ref().unwrap().iter().zip(restored.embedding.unwrap()) {
+            assert!((a - b).abs() <= bound + 1e-6);
+        }
+    }
+
+    #[test]
+    fn belief_find_entities_by_predicate_value_filters_by_value_namespace_and_as_of() {
+        let beliefs = InMemoryBeliefStore::new();
+        let t0 = Utc::now() - Duration::hours(1);
+        let t1 = t0 + Duration::minutes(30);
+        let at = |entity_id: EntityId, value: &str, tx_time: DateTime<Utc>| {
+            let mut belief = mk_belief(entity_id, "industry", Value::from(value), tx_time);
+            belief.valid_time = TimeRange::starting_at(tx_time);
+            belief
+        };
+
+        let (a, b, c, d) = (EntityId::new(), EntityId::new(), EntityId::new(), EntityId::new());
+        beliefs.insert(at(a, "finance", t0)).unwrap();
+        beliefs.insert(at(b, "finance", t0)).unwrap();
+        beliefs.insert(at(d, "tech", t0)).unwrap();
+        let mut other_tenant = at(EntityId::new(), "finance", t0);
+        other_tenant.namespace = Some("other".to_string());
+        beliefs.insert(other_tenant).unwrap();
+
+        // c moved from finance to tech at t1.
+        let old = at(c, "finance", t0);
+        let new = at(c, "tech", t1);
+        let (old_id, new_id) = (old.id, new.id);
+        beliefs.insert(old).unwrap();
+        beliefs.insert(new).unwrap();
+        beliefs.supersede(old_id, new_id).unwrap();
+
+        let finance = Value::from("finance");
+        let mut expected = vec![a, b];
+        expected.sort_by_key(|id| *id.as_uuid());
+        assert_eq!(
+            beliefs.find_entities_by_predicate_value(None, "industry", &finance, Utc::now()).unwrap(),
+            expected
+        );
+
+        expected.push(c);
+        expected.sort_by_key(|id| *id.as_uuid());
+        assert_eq!(
+            beliefs
+                .find_entities_by_predicate_value(None, "industry", &finance, t0 + Duration::minutes(10))
+                .unwrap(),
+            expected
+        );
+
+        assert!(beliefs
+            .find_entities_by_predicate_value(None, "industry", &finance, t0 - Duration::minutes(1))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            beliefs
+                .find_entities_by_predicate_value(Some("other"), "industry", &finance, Utc::now())
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let mut tech = beliefs
+            .find_entities_by_predicate_value(None, "industry", &Value::from("tech"), Utc::now())
+            .unwrap();
+        tech.sort_by_key(|id| *id.as_uuid());
+        let mut expected_tech = vec![c, d];
+        expected_tech.sort_by_key(|id| *id.as_uuid());
+        assert_eq!(tech, expected_tech);
+
+        // Deleted beliefs leave the index.
+        beliefs.delete(old_id).unwrap();
+        assert!(!beliefs
+            .find_entities_by_predicate_value(None, "industry", &finance, t0 + Duration::minutes(10))
+            .unwrap()
+            .contains(&c));
+    }
+
+    #[test]
+    fn belief_metadata_round_trips() {
+        let beliefs = InMemoryBeliefStore::new();
+        let mut belief = mk_belief(EntityId::new(), "name", Value::from("Acme"), Utc::now());
+        belief.metadata = serde_json::json!({"document": "10-K", "offset": 512});
+        let id = belief.id;
+        beliefs.insert(belief).unwrap();
+
+        let stored = beliefs.get(id).unwrap().unwrap();
+        assert_eq!(stored.metadata["document"], "10-K");
+        assert_eq!(stored.metadata["offset"], 512);
+    }
+
+    #[test]
+    fn reembed_switches_every_embedding_to_the_new_dimension() {
+        let beliefs = InMemoryBeliefStore::with_embedding_storage(EmbeddingStorage::Int8);
+        let entity = EntityId::new();
+        let mut ids = Vec::new();
+        for (i, value) in ["red", "green"].into_iter().enumerate() {
+            let mut belief = mk_belief(entity, "color", Value::from(value), Utc::now());
+            belief.embedding = Some(if i == 0 { vec![1.0, 0.0, 0.0] } else { vec![0.0, 1.0, 0.0] });
+            ids.push(belief.id);
+            beliefs.insert(belief).unwrap();
+        }
+        beliefs.insert(mk_belief(entity, "owner", Value::from("ops"), Utc::now())).unwrap();
+
+        let lengths = |beliefs: &InMemoryBeliefStore| {
+            ids.iter()
+                .map(|id| beliefs.get(*id).unwrap().unwrap().embedding.unwrap().len())
+                .collect::<Vec<_>>()
+        };
+        // A failing or inconsistent embedder leaves the store as it was.
+        assert!(beliefs
+            .reembed(&|_| Err(StorageError::BackendError("model offline".to_string())))
+            .is_err());
+        assert!(beliefs
+            .reembed(&|belief| Ok(if belief.id == ids[0] { vec![1.0; 4] } else { vec![1.0; 5] }))
+            .is_err());
+        assert_eq!(lengths(&beliefs), vec![3, 3]);
+
+        let count = beliefs
+            .reembed(&|belief| {
+                let mut embedding = vec![0.0; 5];
+                embedding[if belief.value == Value::from("red") { 3 } else { 4 }] = 1.0;
+                Ok(embedding)
+            })
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(lengths(&beliefs), vec![5, 5]);
+
+        let found = beliefs
+            .find_by_embedding(None, &[0.0, 0.0, 0.0, 0.0, 1.0], 1, None)
+            .unwrap();
+        assert_eq!(found[0].0.id, ids[1]);
+        let mut stale = mk_belief(entity, "color", Value::from("blue"), Utc::now());
+        stale.embedding = Some(vec![0.0, 0.0, 1.0]);
+        assert!(beliefs.insert(stale).is_err());
+    }
 }
@@ -4,22 +4,479 @@
 //! Implementations will be provided in separate modules.
 
 mod traits;
+mod quantize;
 pub mod memory;
 
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+
+use crate::belief::Belief;
+use crate::confidence::BeliefId;
+use crate::entity::{Entity, EntityId, EntityType, EntityTypeHierarchy};
+use crate::time::TimeRange;
+
 #[cfg(feature = "persistent")]
 pub mod persistent;
 
 pub use traits::{
-	BeliefStore, ConflictStore, DerivationStore, EntityStore, PatternStore, StorageError,
+	AmendFields, BeliefStore, ConflictStore, Corroboration, DerivationStore, EntityStore,
+	IdempotencyReservation, IdempotencyStore, PatternStore, StorageError, StorageStats,
 };
 
 pub use memory::{
 	InMemoryBeliefStore, InMemoryConflictStore, InMemoryDerivationStore, InMemoryEntityStore,
-	InMemoryPatternStore, InMemoryStores,
+	InMemoryIdempotencyStore, InMemoryPatternStore, InMemoryStores,
+	DEFAULT_IDEMPOTENCY_CAPACITY,
 };
 
+pub use quantize::{EmbeddingStorage, QuantizedEmbedding};
+
+/// Key under which a name is indexed for `namespace`.
+///
+/// Scoping the key keeps equal names in different namespaces in separate index slots. The
+/// default namespace uses the bare normalized name, so indexes written before namespaces
+/// existed stay valid.
+pub(crate) fn name_index_key(namespace: Option<&str>, name: &str) -> String {
+	let name = name.trim().to_ascii_lowercase();
+	match namespace {
+		// Empty names are never indexed; keep them empty so callers can skip them.
+		_ if name.is_empty() => name,
+		None => name,
+		Some(ns) => format!("{ns}\u{0}{name}"),
+	}
+}
+
+/// Name index key for an entity's canonical name.
+pub(crate) fn entity_name_key(entity: &Entity) -> String {
+	name_index_key(entity.namespace.as_deref(), &entity.canonical_name)
+}
+
+/// `canonical` followed by every entity merged into it, directly or transitively.
+///
+/// Merged-away IDs are sorted so callers see a stable order.
+pub(crate) fn merge_family(
+	canonical: EntityId,
+	merged_from: &HashMap<EntityId, HashSet<EntityId>>,
+) -> Vec<EntityId> {
+	let mut family = vec![canonical];
+	let mut seen = HashSet::from([canonical]);
+	let mut next = 0;
+	while next < family.len() {
+		if let Some(children) = merged_from.get(&family[next]) {
+			family.extend(children.iter().copied().filter(|id| seen.insert(*id)));
+		}
+		next += 1;
+	}
+	family[1..].sort_unstable_by_key(|id| *id.as_uuid());
+	family
+}
+
+/// Reject embeddings with NaN or infinite components.
+///
+/// Such vectors would otherwise be stored and silently score 0 in every similarity search.
+pub(crate) fn ensure_finite_embedding(embedding: &[f32], context: &str) -> Result<(), StorageError> {
+	match embedding.iter().position(|v| !v.is_finite()) {
+		None => Ok(()),
+		Some(index) => Err(StorageError::BackendError(format!(
+			"embedding component {index} is not finite ({context})"
+		))),
+	}
+}
+
+/// Reject `entity` if another entity already holds its canonical name in `by_name`.
+///
+/// Only stores that enforce unique canonical names call this; aliases are not checked.
+pub(crate) fn ensure_name_unclaimed(
+	by_name: &HashMap<String, HashSet<EntityId>>,
+	entity: &Entity,
+) -> Result<(), StorageError> {
+	let key = entity_name_key(entity);
+	if key.is_empty() {
+		return Ok(());
+	}
+	match by_name.get(&key) {
+		Some(ids) if ids.iter().any(|id| *id != entity.id) => Err(StorageError::DuplicateKey(format!(
+			"canonical name '{}'",
+			entity.canonical_name.trim()
+		))),
+		_ => Ok(()),
+	}
+}
+
+/// Order `beliefs` oldest first by transaction time, as [`BeliefStore::history`] returns them.
+///
+/// Ties (beliefs committed in the same instant) fall back to the ID so the order is stable.
+pub(crate) fn sort_history(beliefs: &mut [Belief]) {
+	beliefs.sort_by_key(|b| (b.tx_time, *b.id.as_uuid()));
+}
+
+/// Keys longer than this (in characters) are never compared by edit distance.
+const MAX_TYPO_KEY_CHARS: usize = 64;
+
+/// Rank of `entity` in a fuzzy name search for the normalized `query_key`; 0 is no match.
+///
+/// From best to worst: canonical name prefix, canonical name substring or alias prefix,
+/// alias substring, and finally a canonical name or alias within a small edit distance of
+/// the query (a typo). Edit distance is only computed for names of similar length.
+pub(crate) fn fuzzy_name_score(query_key: &str, entity: &Entity) -> u8 {
+	let canonical = entity.canonical_name.trim().to_ascii_lowercase();
+	let mut score = 0u8;
+	if canonical.starts_with(query_key) {
+		score = score.max(6);
+	} else if canonical.contains(query_key) {
+		score = score.max(4);
+	}
+
+	let aliases: Vec<String> = entity.aliases.iter().map(|a| a.trim().to_ascii_lowercase()).collect();
+	for alias_key in &aliases {
+		if alias_key.starts_with(query_key) {
+			score = score.max(4);
+		} else if alias_key.contains(query_key) {
+			score = score.max(2);
+		}
+	}
+
+	if score == 0 {
+		let max = max_typo_distance(query_key);
+		let is_typo = |key: &str| max > 0 && bounded_edit_distance(query_key, key, max).is_some();
+		if is_typo(&canonical) || aliases.iter().any(|a| is_typo(a)) {
+			score = 1;
+		}
+	}
+	score
+}
+
+/// Edits tolerated between a query and a name: none below 4 characters, one below 8, else two.
+fn max_typo_distance(query_key: &str) -> usize {
+	match query_key.chars().count() {
+		0..=3 => 0,
+		4..=7 => 1,
+		_ => 2,
+	}
+}
+
+/// Optimal string alignment distance between `a` and `b` (insertions, deletions,
+/// substitutions and adjacent transpositions), or `None` if it exceeds `max`.
+///
+/// Strings whose lengths differ by more than `max`, or that exceed `MAX_TYPO_KEY_CHARS`,
+/// are rejected without filling the table.
+pub(crate) fn bounded_edit_distance(a: &str, b: &str, max: usize) -> Option<usize> {
+	let a: Vec<char> = a.chars().collect();
+	let b: Vec<char> = b.chars().collect();
+	if a.len().abs_diff(b.len()) > max || a.len() > MAX_TYPO_KEY_CHARS || b.len() > MAX_TYPO_KEY_CHARS {
+		return None;
+	}
+
+	let mut before_prev = vec![0; b.len() + 1];
+	let mut prev: Vec<usize> = (0..=b.len()).collect();
+	let mut cur = vec![0; b.len() + 1];
+	for i in 1..=a.len() {
+		cur[0] = i;
+		let mut row_min = i;
+		for j in 1..=b.len() {
+			let cost = usize::from(a[i - 1] != b[j - 1]);
+			let mut d = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				d = d.min(before_prev[j - 2] + 1);
+			}
+			cur[j] = d;
+			row_min = row_min.min(d);
+		}
+		// Distances never shrink down the table, so a row past `max` settles it.
+		if row_min > max {
+			return None;
+		}
+		std::mem::swap(&mut before_prev, &mut prev);
+		std::mem::swap(&mut prev, &mut cur);
+	}
+	Some(prev[b.len()]).filter(|d| *d <= max)
+}
+
+/// Check a [`BeliefStore::supersede_all`] call against the beliefs `get` finds.
+///
+/// Returns whether the links still have to be written; `false` means the call repeats one
+/// that already succeeded.
+pub(crate) fn check_supersede<'a>(
+	old_ids: &[BeliefId],
+	new_id: BeliefId,
+	get: impl Fn(BeliefId) -> Option<&'a Belief>,
+) -> Result<bool, StorageError> {
+	if old_ids.contains(&new_id) {
+		return Err(StorageError::BackendError(
+			"cannot supersede a belief with itself".to_string(),
+		));
+	}
+	let new = get(new_id).ok_or(StorageError::BeliefNotFound(new_id))?;
+	let requested: HashSet<BeliefId> = old_ids.iter().copied().collect();
+	if !new.supersedes.is_empty() && new.supersedes.iter().copied().collect::<HashSet<_>>() != requested {
+		return Err(StorageError::BackendError(format!(
+			"belief {new_id} already supersedes {:?}; cannot supersede {old_ids:?}",
+			new.supersedes
+		)));
+	}
+	let mut pending = new.supersedes.is_empty() && !old_ids.is_empty();
+	for &old_id in old_ids {
+		let old = get(old_id).ok_or(StorageError::BeliefNotFound(old_id))?;
+		match old.superseded_by {
+			Some(existing) if existing == new_id => {}
+			Some(existing) => {
+				return Err(StorageError::BackendError(format!(
+					"belief {old_id} is already superseded by {existing}"
+				)));
+			}
+			None => pending = true,
+		}
+	}
+	Ok(pending)
+}
+
+/// Check that a new belief `new_id` may supersede every belief in `old_ids`, as
+/// [`BeliefStore::insert_superseding`] requires: each must exist and have no successor yet.
+pub(crate) fn check_supersedable<'a>(
+	old_ids: &[BeliefId],
+	new_id: BeliefId,
+	get: impl Fn(BeliefId) -> Option<&'a Belief>,
+) -> Result<(), StorageError> {
+	if old_ids.contains(&new_id) {
+		return Err(StorageError::BackendError(
+			"cannot supersede a belief with itself".to_string(),
+		));
+	}
+	for &old_id in old_ids {
+		let old = get(old_id).ok_or(StorageError::BeliefNotFound(old_id))?;
+		if let Some(existing) = old.superseded_by {
+			return Err(StorageError::BackendError(format!(
+				"belief {old_id} is already superseded by {existing}"
+			)));
+		}
+	}
+	Ok(())
+}
+
+/// Link `old` to its successor and close its valid time at `new_tx`.
+///
+/// The end is clamped so the interval never becomes empty, and never moves an earlier end.
+pub(crate) fn close_superseded(old: &mut Belief, new_id: BeliefId, new_tx: DateTime<Utc>) {
+	old.superseded_by = Some(new_id);
+	let end = if new_tx > old.valid_time.from() {
+		new_tx
+	} else {
+		old.valid_time.from() + chrono::Duration::microseconds(1)
+	};
+	let end = match old.valid_time.to() {
+		Some(existing) => existing.min(end),
+		None => end,
+	};
+	old.valid_time.set_to_clamped(end);
+}
+
+/// `old_ids` without repeats, in their first order.
+pub(crate) fn distinct_ids(old_ids: &[BeliefId]) -> Vec<BeliefId> {
+	let mut seen = HashSet::new();
+	old_ids.iter().copied().filter(|id| seen.insert(*id)).collect()
+}
+
+/// Record changes that coalesce one entity-predicate history; see [`plan_coalesce`].
+#[derive(Debug, Default)]
+pub(crate) struct CoalescePlan {
+	/// Beliefs whose `valid_time`, `supersedes` or `superseded_by` changed.
+	pub(crate) updated: Vec<Belief>,
+	/// Beliefs folded into an earlier one, to be deleted.
+	pub(crate) removed: Vec<BeliefId>,
+}
+
+/// Plan [`BeliefStore::coalesce`] over `history`, ordered as [`sort_history`] leaves it.
+///
+/// `visible_from` is the first instant the store's `find_as_of` returns a belief. A belief `b`
+/// folds into `a` only if both say the same thing (value, source, confidence, embedding, reason,
+/// namespace), neither is contested or recurring, `b` is not `pinned`, nothing but `a` is
+/// superseded by `b`, and `b` becomes visible exactly when `a` stops being valid. The merged
+/// belief keeps `a`'s id and transaction time and takes `b`'s end and successor, so it is
+/// returned for precisely the instants one of the two was.
+pub(crate) fn plan_coalesce(
+	history: &[Belief],
+	pinned: &HashSet<BeliefId>,
+	visible_from: impl Fn(&Belief) -> DateTime<Utc>,
+) -> CoalescePlan {
+	let mut current: HashMap<BeliefId, Belief> = history.iter().map(|b| (b.id, b.clone())).collect();
+	// Reverse links, built once and kept up to date as beliefs fold.
+	let mut predecessors: HashMap<BeliefId, Vec<BeliefId>> = HashMap::new();
+	let mut superseded_in: HashMap<BeliefId, Vec<BeliefId>> = HashMap::new();
+	let mut starting_at: HashMap<DateTime<Utc>, Vec<BeliefId>> = HashMap::new();
+	for belief in history {
+		if let Some(next) = belief.superseded_by {
+			predecessors.entry(next).or_default().push(belief.id);
+		}
+		for old in &belief.supersedes {
+			superseded_in.entry(*old).or_default().push(belief.id);
+		}
+		starting_at.entry(visible_from(belief)).or_default().push(belief.id);
+	}
+	let mut updated = HashSet::new();
+	let mut removed = Vec::new();
+
+	for belief in history {
+		let a_id = belief.id;
+		if !current.contains_key(&a_id) {
+			continue;
+		}
+		loop {
+			let a = &current[&a_id];
+			let fits = |b: &&Belief| !pinned.contains(&b.id) && can_coalesce(a, b, &predecessors, &visible_from);
+			let candidate = match (a.superseded_by, a.valid_time.to()) {
+				(Some(next), _) => current.get(&next).filter(fits),
+				(None, Some(end)) => starting_at
+					.get(&end)
+					.into_iter()
+					.flatten()
+					.filter_map(|id| current.get(id))
+					.find(fits),
+				(None, None) => None,
+			};
+			let Some(b) = candidate.cloned() else {
+				break;
+			};
+			let valid_time = match b.valid_time.to() {
+				Some(to) => match TimeRange::new(a.valid_time.from(), to) {
+					Ok(range) => range,
+					Err(_) => break,
+				},
+				None => TimeRange::starting_at(a.valid_time.from()),
+			};
+
+			let a = current.get_mut(&a_id).expect("survivor is present");
+			a.valid_time = valid_time;
+			a.superseded_by = b.superseded_by;
+			updated.insert(a_id);
+			predecessors.remove(&b.id);
+			if let Some(next) = b.superseded_by {
+				for pred in predecessors.entry(next).or_default() {
+					if *pred == b.id {
+						*pred = a_id;
+					}
+				}
+			}
+			for other_id in superseded_in.remove(&b.id).unwrap_or_default() {
+				let Some(other) = current.get_mut(&other_id) else {
+					continue;
+				};
+				for link in other.supersedes.iter_mut().filter(|link| **link == b.id) {
+					*link = a_id;
+				}
+				updated.insert(other_id);
+				superseded_in.entry(a_id).or_default().push(other_id);
+			}
+			current.remove(&b.id);
+			updated.remove(&b.id);
+			removed.push(b.id);
+		}
+	}
+
+	CoalescePlan {
+		updated: history
+			.iter()
+			.filter(|b| updated.contains(&b.id))
+			.map(|b| current[&b.id].clone())
+			.collect(),
+		removed,
+	}
+}
+
+fn can_coalesce(
+	a: &Belief,
+	b: &Belief,
+	predecessors: &HashMap<BeliefId, Vec<BeliefId>>,
+	visible_from: &impl Fn(&Belief) -> DateTime<Utc>,
+) -> bool {
+	let b_start = visible_from(b);
+	a.id != b.id
+		&& a.superseded_by.is_none_or(|next| next == b.id)
+		&& predecessors.get(&b.id).is_none_or(|preds| preds.iter().all(|p| *p == a.id))
+		&& a.namespace == b.namespace
+		&& a.value == b.value
+		&& a.source == b.source
+		&& a.confidence == b.confidence
+		&& a.embedding == b.embedding
+		&& a.reason == b.reason
+		&& a.metadata == b.metadata
+		&& a.corroborating_sources == b.corroborating_sources
+		&& !a.is_contested()
+		&& !b.is_contested()
+		&& a.valid_time.recurrence().is_none()
+		&& b.valid_time.recurrence().is_none()
+		&& a.valid_time.to() == Some(b_start)
+		&& visible_from(a) <= b_start
+		&& b.valid_time.to().is_none_or(|to| b_start < to)
+}
+
+/// Reject merging entities that live in different namespaces.
+pub(crate) fn ensure_same_namespace(primary: &Entity, secondary: &Entity) -> Result<(), StorageError> {
+	if primary.namespace == secondary.namespace {
+		return Ok(());
+	}
+	Err(StorageError::BackendError(format!(
+		"cannot merge entities across namespaces: {} is in {:?}, {} is in {:?}",
+		primary.id, primary.namespace, secondary.id, secondary.namespace
+	)))
+}
+
+/// Live entity IDs by entity type, backing `EntityStore::find_by_type`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EntityTypeIndex(HashMap<EntityType, HashSet<EntityId>>);
+
+impl EntityTypeIndex {
+	pub(crate) fn insert(&mut self, entity: &Entity) {
+		self.0.entry(entity.entity_type.clone()).or_default().insert(entity.id);
+	}
+
+	pub(crate) fn remove(&mut self, entity: &Entity) {
+		if let Some(ids) = self.0.get_mut(&entity.entity_type) {
+			ids.remove(&entity.id);
+			if ids.is_empty() {
+				self.0.remove(&entity.entity_type);
+			}
+		}
+	}
+
+	/// Entities of `entity_type`, and of its subtypes in `hierarchy` if `include_subtypes`.
+	///
+	/// Sorted by canonical name, like `find_by_name`.
+	pub(crate) fn find(
+		&self,
+		by_id: &HashMap<EntityId, Entity>,
+		hierarchy: &EntityTypeHierarchy,
+		entity_type: &EntityType,
+		include_subtypes: bool,
+	) -> Vec<Entity> {
+		let types = if include_subtypes {
+			hierarchy.subtypes(entity_type)
+		} else {
+			vec![entity_type.clone()]
+		};
+		let mut results: Vec<Entity> = types
+			.iter()
+			.filter_map(|t| self.0.get(t))
+			.flatten()
+			.filter_map(|id| by_id.get(id).cloned())
+			.collect();
+		sort_by_name(&mut results);
+		results
+	}
+}
+
+/// Order `entities` by canonical name, then ID, as `find_by_type` and `list` return them.
+pub(crate) fn sort_by_name(entities: &mut [Entity]) {
+	entities.sort_by(|a, b| {
+		a.canonical_name
+			.cmp(&b.canonical_name)
+			.then_with(|| a.id.as_uuid().cmp(b.id.as_uuid()))
+	});
+}
+
 #[cfg(feature = "persistent")]
 pub use persistent::{
-	open_database, PersistentBeliefStore, PersistentConfig, PersistentConflictStore,
-	PersistentDerivationStore, PersistentEntityStore, PersistentPatternStore, PersistentStores,
+	open_database, open_database_read_only, GroupCommitConfig, PersistentBeliefStore, PersistentConfig, PersistentConflictStore,
+	PersistentDerivationStore, PersistentEntityStore, PersistentIdempotencyStore,
+	PersistentPatternStore, PersistentStats, PersistentStores,
 };
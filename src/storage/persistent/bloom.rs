@@ -0,0 +1,84 @@
+//! Bloom filters over record ids.
+//!
+//! Each segment carries filters over the ids it stores so point lookups can skip segments
+//! that cannot contain the key without reading them.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Bits allocated per expected key (~1% false positives with [`HASH_COUNT`] probes).
+const BITS_PER_KEY: usize = 10;
+
+/// Probes per key.
+const HASH_COUNT: u32 = 7;
+
+/// Fixed-size bloom filter keyed by UUID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    hashes: u32,
+}
+
+impl BloomFilter {
+    /// Create a filter sized for `expected` keys.
+    pub fn with_capacity(expected: usize) -> Self {
+        let words = (expected.max(1) * BITS_PER_KEY).div_ceil(64);
+        Self {
+            bits: vec![0; words],
+            hashes: HASH_COUNT,
+        }
+    }
+
+    /// Add a key.
+    pub fn insert(&mut self, key: &Uuid) {
+        for bit in self.positions(key) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns `false` only if `key` was never inserted.
+    pub fn may_contain(&self, key: &Uuid) -> bool {
+        // A filter decoded from a damaged file may be empty; never let it hide data.
+        if self.bits.is_empty() {
+            return true;
+        }
+        self.positions(key)
+            .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Bit positions probed for `key`, via double hashing.
+    fn positions(&self, key: &Uuid) -> impl Iterator<Item = usize> {
+        let (hi, lo) = key.as_u64_pair();
+        let h1 = mix(lo.wrapping_add(mix(hi)));
+        let h2 = mix(h1 ^ 0x9e37_79b9_7f4a_7c15) | 1;
+        let len = (self.bits.len() * 64) as u64;
+        (0..u64::from(self.hashes)).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % len) as usize)
+    }
+}
+
+/// SplitMix64 finalizer; ids are not assumed to be uniformly random.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_has_no_false_negatives() {
+        let keys: Vec<Uuid> = (0..1_000u128).map(Uuid::from_u128).collect();
+        let mut filter = BloomFilter::with_capacity(keys.len());
+        for key in &keys {
+            filter.insert(key);
+        }
+
+        assert!(keys.iter().all(|k| filter.may_contain(k)));
+        let false_positives = (1_000..11_000u128)
+            .filter(|k| filter.may_contain(&Uuid::from_u128(*k)))
+            .count();
+        assert!(false_positives < 300, "false positives: {false_positives}");
+    }
+}
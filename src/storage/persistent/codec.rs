@@ -5,26 +5,127 @@
 //! - Length-prefixed format for framing
 //! - CRC32 checksum for corruption detection
 //! - Version byte for forward compatibility
+//! - Optional AES-256-GCM encryption of each entry's data, under a caller-supplied key
 
-use std::io::{Read, Write, Result as IoResult, Error as IoError, ErrorKind};
+use std::io::{Read, Seek, SeekFrom, Write, Result as IoResult, Error as IoError, ErrorKind};
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
 use crc32fast::Hasher;
 use serde::{Serialize, de::DeserializeOwned};
 
 /// Current codec version.
 const CODEC_VERSION: u8 = 1;
 
+/// Version byte of entries whose data is encrypted.
+const ENCRYPTED_VERSION: u8 = 2;
+
+/// Length of the random nonce stored in front of each encrypted entry's ciphertext.
+const NONCE_LEN: usize = 12;
+
+/// A 256-bit key for encrypting storage entries at rest.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey([u8; 32]);
+
+impl EncryptionKey {
+    /// Wrap raw key bytes.
+    #[must_use]
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the key itself.
+        f.write_str("EncryptionKey(..)")
+    }
+}
+
+/// AES-256-GCM cipher for entry data, built from an [`EncryptionKey`].
+#[derive(Clone)]
+pub struct Cipher(Aes256Gcm);
+
+impl Cipher {
+    pub fn new(key: &EncryptionKey) -> Self {
+        Self(Aes256Gcm::new(&key.0.into()))
+    }
+
+    /// Returns `nonce || ciphertext`, where the ciphertext carries the authentication tag.
+    fn encrypt(&self, plaintext: &[u8]) -> IoResult<Vec<u8>> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .0
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| IoError::new(ErrorKind::InvalidData, "encryption failed"))?;
+        let mut out = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> IoResult<Vec<u8>> {
+        let decryption_failed = || {
+            key_error("authenticated decryption failed (wrong encryption key or tampered entry)")
+        };
+        if data.len() < NONCE_LEN {
+            return Err(decryption_failed());
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        self.0
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| decryption_failed())
+    }
+}
+
+impl std::fmt::Debug for Cipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Cipher(..)")
+    }
+}
+
+/// Payload of errors caused by the configured key rather than by the stored bytes.
+#[derive(Debug)]
+struct KeyError(&'static str);
+
+impl std::fmt::Display for KeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+fn key_error(message: &'static str) -> IoError {
+    IoError::new(ErrorKind::InvalidData, KeyError(message))
+}
+
+/// Returns `true` if `error` means the entry cannot be read with the configured key: the
+/// key is wrong, missing, or was configured for unencrypted data.
+///
+/// Unlike a torn or corrupted tail, such an error must fail the open.
+pub fn is_key_error(error: &IoError) -> bool {
+    error.get_ref().is_some_and(|inner| inner.is::<KeyError>())
+}
+
 /// Magic bytes to identify KyroQL files.
 pub const MAGIC: [u8; 4] = *b"KYRO";
 
-/// Serializes a value to bytes with checksum.
+/// Serializes a value to bytes with checksum, encrypting it when `cipher` is given.
 ///
 /// Format:
 /// ```text
 /// [version: 1 byte][length: 4 bytes LE][data: N bytes JSON][crc32: 4 bytes LE]
 /// ```
-pub fn encode<T: Serialize>(value: &T) -> IoResult<Vec<u8>> {
+///
+/// Encrypted entries use version 2, and their data is a 12-byte nonce followed by the
+/// AES-256-GCM ciphertext of the JSON. The CRC covers the ciphertext.
+pub fn encode<T: Serialize>(value: &T, cipher: Option<&Cipher>) -> IoResult<Vec<u8>> {
     let data = serde_json::to_vec(value)
         .map_err(|e| IoError::new(ErrorKind::InvalidData, format!("serialization failed: {}", e)))?;
+    let (version, data) = match cipher {
+        Some(cipher) => (ENCRYPTED_VERSION, cipher.encrypt(&data)?),
+        None => (CODEC_VERSION, data),
+    };
     
     let mut hasher = Hasher::new();
     hasher.update(&data);
@@ -33,7 +134,7 @@ pub fn encode<T: Serialize>(value: &T) -> IoResult<Vec<u8>> {
     let len = data.len() as u32;
     
     let mut out = Vec::with_capacity(1 + 4 + data.len() + 4);
-    out.push(CODEC_VERSION);
+    out.push(version);
     out.extend_from_slice(&len.to_le_bytes());
     out.extend_from_slice(&data);
     out.extend_from_slice(&crc.to_le_bytes());
@@ -41,23 +142,19 @@ pub fn encode<T: Serialize>(value: &T) -> IoResult<Vec<u8>> {
     Ok(out)
 }
 
-/// Deserializes a value from bytes, verifying checksum.
+/// Deserializes a value from bytes, verifying checksum and decrypting with `cipher`.
 ///
 /// # Errors
 /// - Returns error if checksum fails (corruption detected)
 /// - Returns error if version is unsupported
+/// - Returns error if decryption fails, or if the entry's encryption does not match
+///   whether `cipher` is given (see [`is_key_error`])
 /// - Returns error if deserialization fails
-pub fn decode<T: DeserializeOwned>(reader: &mut impl Read) -> IoResult<T> {
+pub fn decode<T: DeserializeOwned>(reader: &mut impl Read, cipher: Option<&Cipher>) -> IoResult<T> {
     // Read version
     let mut version = [0u8; 1];
     reader.read_exact(&mut version)?;
-    
-    if version[0] != CODEC_VERSION {
-        return Err(IoError::new(
-            ErrorKind::InvalidData,
-            format!("unsupported codec version: {} (expected {})", version[0], CODEC_VERSION),
-        ));
-    }
+    check_version(version[0])?;
     
     // Read length
     let mut len_bytes = [0u8; 4];
@@ -96,11 +193,50 @@ pub fn decode<T: DeserializeOwned>(reader: &mut impl Read) -> IoResult<T> {
         ));
     }
     
+    let data = match (version[0], cipher) {
+        (ENCRYPTED_VERSION, Some(cipher)) => cipher.decrypt(&data)?,
+        (ENCRYPTED_VERSION, None) => {
+            return Err(key_error("entry is encrypted but no encryption key is configured"));
+        }
+        (_, Some(_)) => {
+            return Err(key_error("entry is not encrypted but an encryption key is configured"));
+        }
+        (_, None) => data,
+    };
+    
     // Deserialize
     serde_json::from_slice(&data)
         .map_err(|e| IoError::new(ErrorKind::InvalidData, format!("deserialization failed: {}", e)))
 }
 
+fn check_version(version: u8) -> IoResult<()> {
+    if version != CODEC_VERSION && version != ENCRYPTED_VERSION {
+        return Err(IoError::new(
+            ErrorKind::InvalidData,
+            format!(
+                "unsupported codec version: {} (expected {} or {})",
+                version, CODEC_VERSION, ENCRYPTED_VERSION
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Skips over one encoded value without reading, verifying or decrypting its data.
+pub fn skip<R: Read + Seek>(reader: &mut R) -> IoResult<()> {
+    let mut version = [0u8; 1];
+    reader.read_exact(&mut version)?;
+    check_version(version[0])?;
+
+    let mut len_bytes = [0u8; 4];
+    reader.read_exact(&mut len_bytes)?;
+    let len = u32::from_le_bytes(len_bytes);
+
+    // Data plus trailing CRC.
+    reader.seek(SeekFrom::Current(i64::from(len) + 4))?;
+    Ok(())
+}
+
 /// Write the file header (magic + version).
 pub fn write_header(writer: &mut impl Write) -> IoResult<()> {
     writer.write_all(&MAGIC)?;
@@ -134,10 +270,10 @@ mod tests {
     #[test]
     fn test_roundtrip_simple() {
         let value = "hello, world!".to_string();
-        let encoded = encode(&value).unwrap();
+        let encoded = encode(&value, None).unwrap();
         
         let mut cursor = Cursor::new(encoded);
-        let decoded: String = decode(&mut cursor).unwrap();
+        let decoded: String = decode(&mut cursor, None).unwrap();
         
         assert_eq!(value, decoded);
     }
@@ -145,7 +281,7 @@ mod tests {
     #[test]
     fn test_detects_corruption() {
         let value = "test data".to_string();
-        let mut encoded = encode(&value).unwrap();
+        let mut encoded = encode(&value, None).unwrap();
         
         // Corrupt a byte in the data section
         if encoded.len() > 10 {
@@ -153,7 +289,7 @@ mod tests {
         }
         
         let mut cursor = Cursor::new(encoded);
-        let result: IoResult<String> = decode(&mut cursor);
+        let result: IoResult<String> = decode(&mut cursor, None);
         
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -167,12 +303,46 @@ mod tests {
         bad_data.extend_from_slice(&(200_000_000u32).to_le_bytes()); // 200 MB
         
         let mut cursor = Cursor::new(bad_data);
-        let result: IoResult<String> = decode(&mut cursor);
+        let result: IoResult<String> = decode(&mut cursor, None);
         
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("exceeds maximum"));
     }
     
+    #[test]
+    fn test_skip_lands_on_next_value() {
+        let mut buf = encode(&"first".to_string(), None).unwrap();
+        buf.extend(encode(&"second".to_string(), None).unwrap());
+
+        let mut cursor = Cursor::new(buf);
+        skip(&mut cursor).unwrap();
+        let decoded: String = decode(&mut cursor, None).unwrap();
+
+        assert_eq!(decoded, "second");
+    }
+
+    #[test]
+    fn test_encrypted_roundtrip_requires_the_right_key() {
+        let cipher = Cipher::new(&EncryptionKey::new([7; 32]));
+        let encoded = encode(&"secret".to_string(), Some(&cipher)).unwrap();
+        assert!(!encoded.windows(6).any(|w| w == b"secret"));
+
+        let decoded: String = decode(&mut Cursor::new(&encoded), Some(&cipher)).unwrap();
+        assert_eq!(decoded, "secret");
+
+        let wrong = Cipher::new(&EncryptionKey::new([8; 32]));
+        let err = decode::<String>(&mut Cursor::new(&encoded), Some(&wrong)).unwrap_err();
+        assert!(is_key_error(&err));
+        assert!(err.to_string().contains("authenticated decryption failed"));
+
+        let err = decode::<String>(&mut Cursor::new(&encoded), None).unwrap_err();
+        assert!(is_key_error(&err));
+
+        let plain = encode(&"secret".to_string(), None).unwrap();
+        let err = decode::<String>(&mut Cursor::new(&plain), Some(&cipher)).unwrap_err();
+        assert!(is_key_error(&err));
+    }
+
     #[test]
     fn test_header_roundtrip() {
         let mut buf = Vec::new();
@@ -197,10 +367,10 @@ mod tests {
             kind: WalEntryKind::EntityInsert(entity),
         };
         
-        let encoded = encode(&entry).unwrap();
+        let encoded = encode(&entry, None).unwrap();
         
         let mut cursor = Cursor::new(encoded);
-        let decoded: WalEntry = decode(&mut cursor).unwrap();
+        let decoded: WalEntry = decode(&mut cursor, None).unwrap();
         
         assert_eq!(decoded.sequence, 1);
         assert!(matches!(decoded.kind, WalEntryKind::EntityInsert(_)));
@@ -218,10 +388,10 @@ mod tests {
             kind: WalEntryKind::Checkpoint { up_to_sequence: 100 },
         };
         
-        let encoded = encode(&entry).unwrap();
+        let encoded = encode(&entry, None).unwrap();
         
         let mut cursor = Cursor::new(encoded);
-        let decoded: WalEntry = decode(&mut cursor).unwrap();
+        let decoded: WalEntry = decode(&mut cursor, None).unwrap();
         
         assert_eq!(decoded.sequence, 1);
         assert!(matches!(decoded.kind, WalEntryKind::Checkpoint { up_to_sequence: 100 }));
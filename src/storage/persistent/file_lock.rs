@@ -1,7 +1,8 @@
 //! File locking for single-process database access.
 //!
-//! This module provides cross-platform exclusive file locking to ensure
-//! only one process can access the database at a time.
+//! This module provides cross-platform file locking to ensure only one
+//! process can write the database at a time. Shared locks let any number of
+//! read-only handles coexist with each other.
 //!
 //! # Safety
 //! - Lock is released when FileLock is dropped
@@ -12,7 +13,16 @@ use std::fs::{File, OpenOptions};
 use std::io::{Error as IoError, ErrorKind, Result as IoResult};
 use std::path::{Path, PathBuf};
 
-/// Exclusive file lock for database access.
+/// How a [`FileLock`] is held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// No other holder of either mode.
+    Exclusive,
+    /// Any number of shared holders, but no exclusive one.
+    Shared,
+}
+
+/// File lock for database access.
 ///
 /// The lock is held for the lifetime of this struct and automatically
 /// released when dropped.
@@ -20,6 +30,7 @@ use std::path::{Path, PathBuf};
 pub struct FileLock {
     _file: File,
     path: PathBuf,
+    mode: LockMode,
 }
 
 impl FileLock {
@@ -35,19 +46,26 @@ impl FileLock {
     /// - `ErrorKind::WouldBlock` if another process holds the lock
     /// - `ErrorKind::PermissionDenied` if we don't have write access
     pub fn acquire(dir: &Path) -> IoResult<Self> {
-        let lock_path = dir.join(".lock");
-        
+        Self::acquire_path(&dir.join(".lock"), LockMode::Exclusive)
+    }
+
+    /// Attempt to lock an arbitrary lock file in the given mode.
+    ///
+    /// The file is created if it doesn't exist. Fails with `ErrorKind::WouldBlock`
+    /// if a conflicting lock is held.
+    pub fn acquire_path(lock_path: &Path, mode: LockMode) -> IoResult<Self> {
         let file = OpenOptions::new()
             .write(true)
             .create(true)
             .truncate(false)
-            .open(&lock_path)?;
+            .open(lock_path)?;
         
-        Self::try_lock(&file)?;
+        Self::try_lock(&file, mode)?;
         
         Ok(Self {
             _file: file,
-            path: lock_path,
+            path: lock_path.to_path_buf(),
+            mode,
         })
     }
     
@@ -56,15 +74,25 @@ impl FileLock {
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// Returns the mode this lock is held in.
+    #[must_use]
+    pub fn mode(&self) -> LockMode {
+        self.mode
+    }
     
     #[cfg(unix)]
-    fn try_lock(file: &File) -> IoResult<()> {
+    fn try_lock(file: &File, mode: LockMode) -> IoResult<()> {
         use std::os::unix::io::AsRawFd;
         
-        // Use non-blocking exclusive lock
+        // Use a non-blocking lock
         let fd = file.as_raw_fd();
+        let operation = match mode {
+            LockMode::Exclusive => libc::LOCK_EX,
+            LockMode::Shared => libc::LOCK_SH,
+        };
         let result = unsafe {
-            libc::flock(fd, libc::LOCK_EX | libc::LOCK_NB)
+            libc::flock(fd, operation | libc::LOCK_NB)
         };
         
         if result != 0 {
@@ -82,7 +110,7 @@ impl FileLock {
     }
     
     #[cfg(windows)]
-    fn try_lock(file: &File) -> IoResult<()> {
+    fn try_lock(file: &File, mode: LockMode) -> IoResult<()> {
         use std::os::windows::io::AsRawHandle;
         use windows_sys::Win32::Storage::FileSystem::{
             LockFileEx, LOCKFILE_EXCLUSIVE_LOCK, LOCKFILE_FAIL_IMMEDIATELY,
@@ -90,11 +118,15 @@ impl FileLock {
         use windows_sys::Win32::Foundation::HANDLE;
         
         let handle = file.as_raw_handle() as HANDLE;
+        let flags = match mode {
+            LockMode::Exclusive => LOCKFILE_EXCLUSIVE_LOCK | LOCKFILE_FAIL_IMMEDIATELY,
+            LockMode::Shared => LOCKFILE_FAIL_IMMEDIATELY,
+        };
         let result = unsafe {
             let mut overlapped = std::mem::zeroed::<windows_sys::Win32::System::IO::OVERLAPPED>();
             LockFileEx(
                 handle,
-                LOCKFILE_EXCLUSIVE_LOCK | LOCKFILE_FAIL_IMMEDIATELY,
+                flags,
                 0,
                 1,
                 0,
@@ -114,7 +146,7 @@ impl FileLock {
     }
     
     #[cfg(not(any(unix, windows)))]
-    fn try_lock(_file: &File) -> IoResult<()> {
+    fn try_lock(_file: &File, _mode: LockMode) -> IoResult<()> {
         #[cfg(feature = "allow_no_lock")]
         {
             eprintln!("warning: file locking not supported on this platform; proceeding without lock");
@@ -167,4 +199,20 @@ mod tests {
         let err = result.unwrap_err();
         assert_eq!(err.kind(), ErrorKind::WouldBlock);
     }
+
+    #[test]
+    fn test_shared_locks_coexist_but_exclude_exclusive() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(".readers");
+
+        let first = FileLock::acquire_path(&path, LockMode::Shared).unwrap();
+        let second = FileLock::acquire_path(&path, LockMode::Shared).unwrap();
+        assert_eq!(first.mode(), LockMode::Shared);
+
+        let err = FileLock::acquire_path(&path, LockMode::Exclusive).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WouldBlock);
+
+        drop((first, second));
+        FileLock::acquire_path(&path, LockMode::Exclusive).unwrap();
+    }
 }
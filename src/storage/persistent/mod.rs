@@ -2,9 +2,12 @@
 //!
 //! This module provides durable, crash-safe storage with:
 //! - Write-Ahead Logging (WAL) for crash recovery
-//! - File locking for single-process concurrency
+//! - File locking: one writer plus any number of read-only handles
 //! - CRC32 checksums for corruption detection
+//! - Optional AES-256-GCM encryption of WAL and segment entries at rest
 //! - Segmented storage for efficient reads
+//! - Per-segment bloom filters so beliefs are loaded on demand
+//! - Chunked belief sections, so exports can stream beliefs from segments
 //!
 //! # Architecture
 //!
@@ -27,19 +30,23 @@
 
 mod wal;
 mod file_lock;
+mod bloom;
 mod segment;
 mod codec;
 mod stores;
 
-pub use file_lock::FileLock;
-pub use wal::{WalEntry, WalEntryKind, WriteAheadLog};
+pub use codec::EncryptionKey;
+pub use file_lock::{FileLock, LockMode};
+pub use wal::{GroupCommitConfig, PendingAppend, WalEntry, WalEntryKind, WriteAheadLog};
 pub use segment::{Segment, SegmentManager};
 pub use stores::{
     PersistentEntityStore, PersistentBeliefStore, PersistentPatternStore,
-    PersistentConflictStore, PersistentDerivationStore, PersistentStores,
+    PersistentConflictStore, PersistentDerivationStore, PersistentIdempotencyStore,
+    PersistentStats, PersistentStores,
 };
 
 use std::path::Path;
+use crate::entity::EntityTypeHierarchy;
 use crate::error::{ExecutionError, KyroError};
 
 /// Configuration for persistent storage.
@@ -49,8 +56,26 @@ pub struct PersistentConfig {
     pub max_wal_size: u64,
     /// Whether to fsync after every write (slower but safer).
     pub sync_on_write: bool,
+    /// Share fsyncs between concurrent writers instead of one per write.
+    ///
+    /// Writes still return only once durable, and belief inserts only become visible to
+    /// readers then. A failed flush fails every pending write and all later
+    /// ones until the database is reopened. Ignored unless `sync_on_write` is set.
+    pub group_commit: Option<GroupCommitConfig>,
+    /// Reject entity writes that would give two entities the same canonical name.
+    ///
+    /// Applies per namespace; aliases may still be shared. Entities already in the log are
+    /// not re-checked when the database is opened.
+    pub unique_entity_names: bool,
     /// Maximum segment size (bytes).
     pub max_segment_size: u64,
+    /// Subtype relations used by `find_by_type` queries that include subtypes.
+    pub type_hierarchy: EntityTypeHierarchy,
+    /// Encrypt WAL and segment entries with this key.
+    ///
+    /// A database must always be opened with the key it was created with: opening it with
+    /// a different key, without one, or with one when it was created unencrypted fails.
+    pub encryption_key: Option<EncryptionKey>,
 }
 
 impl Default for PersistentConfig {
@@ -58,7 +83,11 @@ impl Default for PersistentConfig {
         Self {
             max_wal_size: 64 * 1024 * 1024,  // 64 MB
             sync_on_write: true,
+            group_commit: None,
+            unique_entity_names: false,
             max_segment_size: 256 * 1024 * 1024,  // 256 MB
+            type_hierarchy: EntityTypeHierarchy::default(),
+            encryption_key: None,
         }
     }
 }
@@ -122,3 +151,15 @@ pub fn open_database(
     let cfg = config.unwrap_or_default().validate()?;
     PersistentStores::open(path.as_ref(), cfg)
 }
+
+/// Open an existing persistent KyroQL database for queries only.
+///
+/// Coexists with a writer holding the database open; see
+/// [`PersistentStores::open_read_only`].
+pub fn open_database_read_only(
+    path: impl AsRef<Path>,
+    config: Option<PersistentConfig>,
+) -> Result<PersistentStores, KyroError> {
+    let cfg = config.unwrap_or_default().validate()?;
+    PersistentStores::open_read_only(path.as_ref(), cfg)
+}
@@ -7,6 +7,10 @@
 //! - Segments are numbered sequentially (segment_001.seg, segment_002.seg)
 //! - Each segment contains a header, index, and data section
 //! - Compaction merges WAL entries into new segments
+//! - Beliefs are stored in their own section behind bloom filters, so they can be
+//!   loaded on demand instead of at open
+//! - The belief section is split into chunks, so it can be streamed without decoding
+//!   it whole
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::{self, File, OpenOptions};
@@ -21,26 +25,34 @@ use crate::belief::Belief;
 use crate::confidence::BeliefId;
 use crate::conflict::{Conflict, ConflictId};
 use crate::derivation::{DerivationId, DerivationRecord};
-use crate::entity::{Entity, EntityId};
+use crate::entity::{Entity, EntityId, MergeProvenance};
 use crate::pattern::{Pattern, PatternId};
+use crate::trust::{CalibrationSnapshot, TrustSnapshot};
 
-use super::codec;
+use crate::storage::{entity_name_key, name_index_key, EntityTypeIndex};
 
-fn normalize_key(s: &str) -> String {
-    s.trim().to_ascii_lowercase()
-}
+use super::bloom::BloomFilter;
+use super::codec::{self, Cipher};
+
+/// Beliefs per chunk of a [`SegmentLayout::Chunked`] belief section.
+const BELIEF_CHUNK_LEN: usize = 1024;
 
 /// A single segment file.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Segment {
     path: PathBuf,
     /// Sequence range covered by this segment [inclusive, inclusive].
     pub sequence_range: (u64, u64),
+    layout: SegmentLayout,
+    /// Filters over the stored beliefs; `None` for segments written without them.
+    filters: Option<SegmentFilters>,
+    /// Cipher the segment's entries are encrypted with, if any.
+    cipher: Option<Cipher>,
 }
 
 impl Segment {
     /// Create a new segment file.
-    pub fn create(path: &Path, sequence_start: u64) -> IoResult<Self> {
+    pub fn create(path: &Path, sequence_start: u64, cipher: Option<Cipher>) -> IoResult<Self> {
         let file = OpenOptions::new()
             .write(true)
             .create_new(true)
@@ -53,30 +65,44 @@ impl Segment {
             sequence_end: sequence_start,
             entry_count: 0,
             created_at: Utc::now(),
+            layout: SegmentLayout::Combined,
         };
-        let header_bytes = codec::encode(&header)?;
+        let header_bytes = codec::encode(&header, cipher.as_ref())?;
         writer.write_all(&header_bytes)?;
         writer.flush()?;
         
         Ok(Self {
             path: path.to_path_buf(),
             sequence_range: (sequence_start, sequence_start),
+            layout: SegmentLayout::Combined,
+            filters: None,
+            cipher,
         })
     }
     
     /// Open an existing segment.
-    pub fn open(path: &Path) -> IoResult<Self> {
+    ///
+    /// Only the header and filters are read; data sections are read on demand, decrypted
+    /// with `cipher`.
+    pub fn open(path: &Path, cipher: Option<Cipher>) -> IoResult<Self> {
         let file = File::open(path)?;
         let mut reader = BufReader::new(file);
         
         let _version = codec::read_header(&mut reader)?;
         
         // Read the segment header to get sequence range
-        let header: SegmentHeader = codec::decode(&mut reader)?;
+        let header: SegmentHeader = codec::decode(&mut reader, cipher.as_ref())?;
+        let filters = match header.layout {
+            SegmentLayout::Combined => None,
+            SegmentLayout::Split | SegmentLayout::Chunked => Some(codec::decode(&mut reader, cipher.as_ref())?),
+        };
         
         Ok(Self {
             path: path.to_path_buf(),
             sequence_range: (header.sequence_start, header.sequence_end),
+            layout: header.layout,
+            filters,
+            cipher,
         })
     }
     
@@ -84,18 +110,170 @@ impl Segment {
     pub fn path(&self) -> &Path {
         &self.path
     }
-    
-    /// Read all data from this segment.
-    pub fn read_all(&self) -> IoResult<SegmentData> {
+
+    /// Returns `false` if this segment certainly does not store belief `id`.
+    pub fn may_contain_belief(&self, id: BeliefId) -> bool {
+        self.filters
+            .as_ref()
+            .is_none_or(|f| f.beliefs.may_contain(id.as_uuid()))
+    }
+
+    /// Returns `false` if this segment certainly stores no belief about `entity`.
+    pub fn may_contain_beliefs_of(&self, entity: EntityId) -> bool {
+        self.filters
+            .as_ref()
+            .is_none_or(|f| f.belief_subjects.may_contain(entity.as_uuid()))
+    }
+
+    /// Open the file positioned just past the header and filters.
+    fn open_body(&self) -> IoResult<BufReader<File>> {
         let file = File::open(&self.path)?;
         let mut reader = BufReader::new(file);
         
         let _version = codec::read_header(&mut reader)?;
-        let _header: SegmentHeader = codec::decode(&mut reader)?;
-        let data: SegmentData = codec::decode(&mut reader)?;
+        let _header: SegmentHeader = codec::decode(&mut reader, self.cipher.as_ref())?;
+        if self.layout != SegmentLayout::Combined {
+            codec::skip(&mut reader)?;
+        }
+        Ok(reader)
+    }
+    
+    /// Read all data from this segment.
+    pub fn read_all(&self) -> IoResult<SegmentData> {
+        let mut reader = self.open_body()?;
+        let mut data: SegmentData = codec::decode(&mut reader, self.cipher.as_ref())?;
+        match self.layout {
+            SegmentLayout::Combined => {}
+            SegmentLayout::Split => data.beliefs = codec::decode(&mut reader, self.cipher.as_ref())?,
+            SegmentLayout::Chunked => {
+                data.beliefs = self
+                    .chunks(reader)
+                    .map(|b| b.map(|b| (b.id, b)))
+                    .collect::<IoResult<_>>()?;
+            }
+        }
         
         Ok(data)
     }
+
+    /// Read everything except beliefs.
+    pub fn read_records(&self) -> IoResult<SegmentData> {
+        let mut reader = self.open_body()?;
+        let mut data: SegmentData = codec::decode(&mut reader, self.cipher.as_ref())?;
+        data.beliefs.clear();
+        Ok(data)
+    }
+
+    /// Read only the beliefs section.
+    pub fn read_beliefs(&self) -> IoResult<HashMap<BeliefId, Belief>> {
+        match self.layout {
+            SegmentLayout::Combined => Ok(self.read_all()?.beliefs),
+            SegmentLayout::Split => {
+                let mut reader = self.open_body()?;
+                codec::skip(&mut reader)?;
+                codec::decode(&mut reader, self.cipher.as_ref())
+            }
+            SegmentLayout::Chunked => self.beliefs()?.map(|b| b.map(|b| (b.id, b))).collect(),
+        }
+    }
+
+    /// Stream the beliefs section.
+    ///
+    /// Chunked segments are decoded one chunk at a time; older layouts store the section
+    /// as a single entry, which is decoded up front.
+    pub fn beliefs(&self) -> IoResult<SegmentBeliefs> {
+        match self.layout {
+            SegmentLayout::Chunked => {
+                let mut reader = self.open_body()?;
+                codec::skip(&mut reader)?;
+                Ok(self.chunks(reader))
+            }
+            SegmentLayout::Combined | SegmentLayout::Split => Ok(SegmentBeliefs {
+                reader: None,
+                cipher: None,
+                chunk: self.read_beliefs()?.into_values().collect::<Vec<_>>().into_iter(),
+            }),
+        }
+    }
+
+    /// Stream belief chunks from `reader`, positioned at the first chunk.
+    fn chunks(&self, reader: BufReader<File>) -> SegmentBeliefs {
+        SegmentBeliefs {
+            reader: Some(reader),
+            cipher: self.cipher.clone(),
+            chunk: Vec::new().into_iter(),
+        }
+    }
+}
+
+/// Iterator over a segment's beliefs; see [`Segment::beliefs`].
+pub struct SegmentBeliefs {
+    /// Positioned at the next chunk; `None` once the section is exhausted.
+    reader: Option<BufReader<File>>,
+    cipher: Option<Cipher>,
+    chunk: std::vec::IntoIter<Belief>,
+}
+
+impl Iterator for SegmentBeliefs {
+    type Item = IoResult<Belief>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(belief) = self.chunk.next() {
+                return Some(Ok(belief));
+            }
+            let reader = self.reader.as_mut()?;
+            match codec::decode::<Vec<Belief>>(reader, self.cipher.as_ref()) {
+                // An empty chunk ends the section.
+                Ok(chunk) if chunk.is_empty() => {
+                    self.reader = None;
+                    return None;
+                }
+                Ok(chunk) => self.chunk = chunk.into_iter(),
+                Err(e) => {
+                    self.reader = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// How a segment's body is laid out after the header.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentLayout {
+    /// A single [`SegmentData`] section (segments written before filters existed).
+    #[default]
+    Combined,
+    /// [`SegmentFilters`], then [`SegmentData`] without beliefs, then the beliefs.
+    Split,
+    /// As [`Split`](Self::Split), but the beliefs are written in chunks of at most
+    /// `BELIEF_CHUNK_LEN`, ending with an empty chunk.
+    Chunked,
+}
+
+/// Bloom filters over a segment's beliefs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentFilters {
+    /// Belief ids.
+    pub beliefs: BloomFilter,
+    /// Subject entity ids of the beliefs.
+    pub belief_subjects: BloomFilter,
+}
+
+impl SegmentFilters {
+    fn build(beliefs: &HashMap<BeliefId, Belief>) -> Self {
+        let mut filters = Self {
+            beliefs: BloomFilter::with_capacity(beliefs.len()),
+            belief_subjects: BloomFilter::with_capacity(beliefs.len()),
+        };
+        for (id, belief) in beliefs {
+            filters.beliefs.insert(id.as_uuid());
+            filters.belief_subjects.insert(belief.subject.as_uuid());
+        }
+        filters
+    }
 }
 
 /// Segment file header.
@@ -109,16 +287,61 @@ pub struct SegmentHeader {
     pub entry_count: u64,
     /// Timestamp when this segment was created.
     pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Body layout; absent in segments written before filters existed.
+    #[serde(default)]
+    pub layout: SegmentLayout,
 }
 
 /// All data stored in a segment.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SegmentData {
     pub entities: EntityIndex,
+    /// Empty in the data section of split segments; beliefs follow in their own section.
+    #[serde(default)]
     pub beliefs: HashMap<BeliefId, Belief>,
     pub patterns: HashMap<PatternId, Pattern>,
     pub conflicts: HashMap<ConflictId, Conflict>,
     pub derivations: HashMap<DerivationId, DerivationRecord>,
+    /// Retained ASSERT idempotency keys, oldest first.
+    #[serde(default, deserialize_with = "deserialize_idempotency_keys")]
+    pub idempotency_keys: Vec<IdempotencyEntry>,
+    /// Beliefs deleted since they were written, possibly into an older segment.
+    #[serde(default)]
+    pub deleted_beliefs: HashSet<BeliefId>,
+    /// Trust model configuration saved as of the segment, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust: Option<TrustSnapshot>,
+    /// FEEDBACK outcomes recorded as of the segment, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calibration: Option<CalibrationSnapshot>,
+}
+
+/// A retained idempotency key as `(namespace, key, belief)`.
+pub type IdempotencyEntry = (Option<String>, String, BeliefId);
+
+/// A stored idempotency key, in either of the layouts segments have used.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredIdempotencyKey {
+    Scoped(Option<String>, String, BeliefId),
+    /// Written before keys were scoped by namespace: always the default namespace.
+    Unscoped(String, BeliefId),
+}
+
+fn deserialize_idempotency_keys<'de, D>(
+    deserializer: D,
+) -> Result<Vec<IdempotencyEntry>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let keys = Vec::<StoredIdempotencyKey>::deserialize(deserializer)?;
+    Ok(keys
+        .into_iter()
+        .map(|key| match key {
+            StoredIdempotencyKey::Scoped(namespace, key, belief_id) => (namespace, key, belief_id),
+            StoredIdempotencyKey::Unscoped(key, belief_id) => (None, key, belief_id),
+        })
+        .collect())
 }
 
 /// Entity index snapshot persisted inside a segment.
@@ -129,7 +352,13 @@ pub struct EntityIndex {
     pub versions: HashMap<EntityId, BTreeMap<u64, Entity>>,
     pub merged_into: HashMap<EntityId, EntityId>,
     pub merged_from: HashMap<EntityId, HashSet<EntityId>>,
+    /// Provenance of active merges, keyed by secondary entity.
+    #[serde(default)]
+    pub merge_provenance: HashMap<EntityId, MergeProvenance>,
     pub embedding_dim: Option<usize>,
+    /// Derived from `by_id`; rebuilt on load instead of stored.
+    #[serde(skip)]
+    pub(crate) by_type: EntityTypeIndex,
 }
 
 impl SegmentData {
@@ -144,10 +373,26 @@ impl SegmentData {
             + self.beliefs.len() 
             + self.patterns.len() 
             + self.conflicts.len() 
-            + self.derivations.len()) as u64
+            + self.derivations.len()
+            + self.idempotency_keys.len()) as u64
     }
 }
 
+/// [`SegmentData`] minus beliefs, borrowed for writing split segments.
+#[derive(Serialize)]
+struct SegmentRecords<'a> {
+    entities: &'a EntityIndex,
+    patterns: &'a HashMap<PatternId, Pattern>,
+    conflicts: &'a HashMap<ConflictId, Conflict>,
+    derivations: &'a HashMap<DerivationId, DerivationRecord>,
+    idempotency_keys: &'a [IdempotencyEntry],
+    deleted_beliefs: &'a HashSet<BeliefId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trust: &'a Option<TrustSnapshot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    calibration: &'a Option<CalibrationSnapshot>,
+}
+
 /// Builder for creating segment files atomically.
 ///
 /// Uses write-to-temp-then-rename pattern for crash safety.
@@ -158,13 +403,14 @@ pub struct SegmentWriter {
     sequence_start: u64,
     sequence_end: u64,
     data_written: bool,
+    cipher: Option<Cipher>,
 }
 
 impl SegmentWriter {
     /// Create a new segment writer.
     ///
     /// Writes to a temporary file first, then atomically renames on finalize.
-    pub fn new(final_path: PathBuf, sequence_start: u64) -> IoResult<Self> {
+    pub fn new(final_path: PathBuf, sequence_start: u64, cipher: Option<Cipher>) -> IoResult<Self> {
         let temp_path = final_path.with_extension(format!("seg.tmp.{}", Uuid::new_v4()));
         
         let file = OpenOptions::new()
@@ -183,21 +429,21 @@ impl SegmentWriter {
             sequence_start,
             sequence_end: sequence_start,
             data_written: false,
+            cipher,
         })
     }
     
     /// Write segment data.
     pub fn write_data(&mut self, data: &SegmentData, sequence_end: u64) -> IoResult<()> {
         if self.data_written {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            return Err(std::io::Error::other(
                 "write_data can only be called once",
             ));
         }
         self.sequence_end = sequence_end;
         
         let writer = self.writer.as_mut().ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::Other, "writer already consumed")
+            std::io::Error::other("writer already consumed")
         })?;
         
         // Write header first
@@ -206,14 +452,32 @@ impl SegmentWriter {
             sequence_end: self.sequence_end,
             entry_count: data.entry_count(),
             created_at: Utc::now(),
+            layout: SegmentLayout::Chunked,
         };
         
-        let header_bytes = codec::encode(&header)?;
+        let cipher = self.cipher.as_ref();
+        let header_bytes = codec::encode(&header, cipher)?;
         writer.write_all(&header_bytes)?;
-        
-        // Write data
-        let data_bytes = codec::encode(data)?;
-        writer.write_all(&data_bytes)?;
+
+        writer.write_all(&codec::encode(&SegmentFilters::build(&data.beliefs), cipher)?)?;
+        
+        // Write data, then beliefs in their own section
+        let records = SegmentRecords {
+            entities: &data.entities,
+            patterns: &data.patterns,
+            conflicts: &data.conflicts,
+            derivations: &data.derivations,
+            idempotency_keys: &data.idempotency_keys,
+            deleted_beliefs: &data.deleted_beliefs,
+            trust: &data.trust,
+            calibration: &data.calibration,
+        };
+        writer.write_all(&codec::encode(&records, cipher)?)?;
+        let beliefs: Vec<&Belief> = data.beliefs.values().collect();
+        for chunk in beliefs.chunks(BELIEF_CHUNK_LEN) {
+            writer.write_all(&codec::encode(&chunk, cipher)?)?;
+        }
+        writer.write_all(&codec::encode(&Vec::<Belief>::new(), cipher)?)?;
         self.data_written = true;
         
         Ok(())
@@ -225,10 +489,10 @@ impl SegmentWriter {
     /// the segment is guaranteed to be durable.
     pub fn finalize(mut self) -> IoResult<Segment> {
         let mut writer = self.writer.take().ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::Other, "writer already consumed")
+            std::io::Error::other("writer already consumed")
         })?;
         let temp_path = self.temp_path.take().ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::Other, "temp_path already consumed")
+            std::io::Error::other("temp_path already consumed")
         })?;
         
         // Flush buffer
@@ -241,10 +505,8 @@ impl SegmentWriter {
         let final_path = self.final_path.clone();
         fs::rename(&temp_path, &final_path)?;
         
-        Ok(Segment {
-            path: final_path,
-            sequence_range: (self.sequence_start, self.sequence_end),
-        })
+        // Reopen to pick up the filters just written.
+        Segment::open(&final_path, self.cipher.take())
     }
     
     /// Abort the write (cleanup temp file).
@@ -276,11 +538,15 @@ pub struct SegmentManager {
     dir: PathBuf,
     segments: Vec<Segment>,
     next_segment_id: u32,
+    cipher: Option<Cipher>,
 }
 
 impl SegmentManager {
     /// Open or create a segment manager for the given directory.
-    pub fn open(dir: &Path) -> IoResult<Self> {
+    ///
+    /// Segments are read and written with `cipher`. A segment that fails to open is
+    /// skipped, unless it cannot be read with that cipher, which fails the whole open.
+    pub fn open(dir: &Path, cipher: Option<Cipher>) -> IoResult<Self> {
         fs::create_dir_all(dir)?;
         
         let mut segments = Vec::new();
@@ -291,13 +557,19 @@ impl SegmentManager {
             let entry = entry?;
             let path = entry.path();
             
-            if path.extension().map_or(false, |e| e == "seg") {
+            if path.extension().is_some_and(|e| e == "seg") {
                 if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
                     if let Ok(id) = stem.strip_prefix("segment_").unwrap_or("").parse::<u32>() {
                         next_segment_id = next_segment_id.max(id + 1);
                         
-                        match Segment::open(&path) {
+                        match Segment::open(&path, cipher.clone()) {
                             Ok(seg) => segments.push(seg),
+                            Err(e) if codec::is_key_error(&e) => {
+                                return Err(std::io::Error::new(
+                                    e.kind(),
+                                    format!("segment {}: {e}", path.display()),
+                                ));
+                            }
                             Err(e) => eprintln!("Warning: failed to open segment {:?}: {}", path, e),
                         }
                     }
@@ -305,7 +577,7 @@ impl SegmentManager {
             }
             
             // Clean up any stale temp files
-            if path.extension().map_or(false, |e| e == "tmp") {
+            if path.extension().is_some_and(|e| e == "tmp") {
                 let _ = fs::remove_file(&path);
             }
         }
@@ -317,6 +589,7 @@ impl SegmentManager {
             dir: dir.to_path_buf(),
             segments,
             next_segment_id,
+            cipher,
         })
     }
     
@@ -345,7 +618,7 @@ impl SegmentManager {
     /// Create a new segment writer.
     pub fn create_segment_writer(&mut self, sequence_start: u64) -> IoResult<SegmentWriter> {
         let path = self.next_segment_path();
-        SegmentWriter::new(path, sequence_start)
+        SegmentWriter::new(path, sequence_start, self.cipher.clone())
     }
     
     /// Register a newly created segment.
@@ -356,10 +629,19 @@ impl SegmentManager {
     
     /// Load all data from all segments.
     pub fn load_all_data(&self) -> IoResult<SegmentData> {
+        self.load(Segment::read_all)
+    }
+
+    /// Load all data except beliefs, which [`Segment::read_beliefs`] fetches on demand.
+    pub fn load_records(&self) -> IoResult<SegmentData> {
+        self.load(Segment::read_records)
+    }
+
+    fn load(&self, read: impl Fn(&Segment) -> IoResult<SegmentData>) -> IoResult<SegmentData> {
         let mut combined = SegmentData::new();
         
         for segment in &self.segments {
-            let data = segment.read_all()?;
+            let data = read(segment)?;
             
             // Merge data (later segments override earlier ones)
             combined.entities.by_id.extend(data.entities.by_id);
@@ -381,6 +663,11 @@ impl SegmentManager {
                     .extend(merged);
             }
 
+            combined
+                .entities
+                .merge_provenance
+                .extend(data.entities.merge_provenance);
+
             if let Some(dim) = data.entities.embedding_dim {
                 combined.entities.embedding_dim = Some(dim);
             }
@@ -388,12 +675,55 @@ impl SegmentManager {
             combined.patterns.extend(data.patterns);
             combined.conflicts.extend(data.conflicts);
             combined.derivations.extend(data.derivations);
+            combined.idempotency_keys.extend(data.idempotency_keys);
+            combined.deleted_beliefs.extend(data.deleted_beliefs);
+            if data.trust.is_some() {
+                combined.trust = data.trust;
+            }
+            if data.calibration.is_some() {
+                combined.calibration = data.calibration;
+            }
+        }
+        combined.beliefs.retain(|id, _| !combined.deleted_beliefs.contains(id));
+
+        // Drop merges that were undone later: an unmerge records a secondary version newer than
+        // the one captured when the merge happened.
+        let entities = &mut combined.entities;
+        let undone: Vec<(EntityId, EntityId)> = entities
+            .merged_into
+            .iter()
+            .filter(|(secondary, _)| {
+                let Some(provenance) = entities.merge_provenance.get(*secondary) else {
+                    return false;
+                };
+                entities
+                    .versions
+                    .get(*secondary)
+                    .and_then(|m| m.keys().next_back())
+                    .is_some_and(|latest| *latest > provenance.secondary_version)
+            })
+            .map(|(secondary, primary)| (*secondary, *primary))
+            .collect();
+        for (secondary, primary) in undone {
+            entities.merged_into.remove(&secondary);
+            entities.merge_provenance.remove(&secondary);
+            if let Some(set) = entities.merged_from.get_mut(&primary) {
+                set.remove(&secondary);
+                if set.is_empty() {
+                    entities.merged_from.remove(&primary);
+                }
+            }
+        }
+
+        combined.entities.by_type = EntityTypeIndex::default();
+        for entity in combined.entities.by_id.values() {
+            combined.entities.by_type.insert(entity);
         }
 
         // Rebuild name index from final entity state to avoid stale aliases.
         combined.entities.by_name.clear();
         for (id, entity) in &combined.entities.by_id {
-            let canon = normalize_key(&entity.canonical_name);
+            let canon = entity_name_key(entity);
             if !canon.is_empty() {
                 combined
                     .entities
@@ -404,7 +734,7 @@ impl SegmentManager {
             }
 
             for alias in &entity.aliases {
-                let key = normalize_key(alias);
+                let key = name_index_key(entity.namespace.as_deref(), alias);
                 if !key.is_empty() {
                     combined
                         .entities
@@ -429,7 +759,7 @@ mod tests {
     #[test]
     fn test_segment_manager_open_empty() {
         let dir = tempdir().unwrap();
-        let manager = SegmentManager::open(dir.path()).unwrap();
+        let manager = SegmentManager::open(dir.path(), None).unwrap();
         
         assert!(manager.segments().is_empty());
         assert_eq!(manager.persisted_sequence(), 0);
@@ -438,7 +768,7 @@ mod tests {
     #[test]
     fn test_segment_path_allocation() {
         let dir = tempdir().unwrap();
-        let mut manager = SegmentManager::open(dir.path()).unwrap();
+        let mut manager = SegmentManager::open(dir.path(), None).unwrap();
         
         let path1 = manager.next_segment_path();
         let path2 = manager.next_segment_path();
@@ -451,7 +781,7 @@ mod tests {
     #[test]
     fn test_segment_writer_roundtrip() {
         let dir = tempdir().unwrap();
-        let mut manager = SegmentManager::open(dir.path()).unwrap();
+        let mut manager = SegmentManager::open(dir.path(), None).unwrap();
         
         // Create test data
         let mut data = SegmentData::new();
@@ -471,10 +801,68 @@ mod tests {
         assert!(read_data.entities.by_id.contains_key(&entity.id));
     }
     
+    #[test]
+    fn test_segment_filters_skip_absent_beliefs() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let mut manager = SegmentManager::open(dir.path(), None).unwrap();
+
+        let subject = EntityId::from(Uuid::from_u128(1));
+        let belief = Belief::builder()
+            .id(BeliefId::from(Uuid::from_u128(2)))
+            .subject(subject)
+            .predicate("status")
+            .value("active")
+            .confidence(Confidence::from_agent(0.9, "a").unwrap())
+            .build()
+            .unwrap();
+        let mut data = SegmentData::new();
+        data.beliefs.insert(belief.id, belief.clone());
+
+        let mut writer = manager.create_segment_writer(1).unwrap();
+        writer.write_data(&data, 1).unwrap();
+        let path = writer.finalize().unwrap().path().to_path_buf();
+
+        // Cold reopen reads only the header and filters.
+        let segment = Segment::open(&path, None).unwrap();
+        assert!(segment.may_contain_belief(belief.id));
+        assert!(segment.may_contain_beliefs_of(subject));
+        assert!(!segment.may_contain_belief(BeliefId::from(Uuid::from_u128(3))));
+        assert!(!segment.may_contain_beliefs_of(EntityId::from(Uuid::from_u128(4))));
+
+        assert_eq!(segment.read_beliefs().unwrap().get(&belief.id), Some(&belief));
+        assert!(segment.read_records().unwrap().beliefs.is_empty());
+        assert_eq!(segment.read_all().unwrap().beliefs.len(), 1);
+    }
+
+    #[test]
+    fn test_segment_without_filters_is_always_searched() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("segment_00001.seg");
+
+        // Layout written before filters existed: header without `layout`, one data section.
+        let mut file = File::create(&path).unwrap();
+        codec::write_header(&mut file).unwrap();
+        let header = serde_json::json!({
+            "sequence_start": 1,
+            "sequence_end": 1,
+            "entry_count": 0,
+            "created_at": Utc::now(),
+        });
+        file.write_all(&codec::encode(&header, None).unwrap()).unwrap();
+        file.write_all(&codec::encode(&SegmentData::new(), None).unwrap()).unwrap();
+        drop(file);
+
+        let segment = Segment::open(&path, None).unwrap();
+        assert!(segment.may_contain_belief(BeliefId::new()));
+        assert!(segment.read_beliefs().unwrap().is_empty());
+    }
+
     #[test]
     fn test_segment_writer_abort() {
         let dir = tempdir().unwrap();
-        let mut manager = SegmentManager::open(dir.path()).unwrap();
+        let mut manager = SegmentManager::open(dir.path(), None).unwrap();
         
         let writer = manager.create_segment_writer(1).unwrap();
         let temp_path = writer.temp_path.clone().unwrap();
@@ -484,4 +872,26 @@ mod tests {
         
         assert!(!temp_path.exists());
     }
+
+    #[test]
+    fn test_unscoped_idempotency_keys_load_into_the_default_namespace() {
+        let belief_id = BeliefId::new();
+        let mut data = SegmentData::new();
+        data.idempotency_keys.push((Some("tenant".to_string()), "k1".to_string(), belief_id));
+        let mut json = serde_json::to_value(&data).unwrap();
+        // Segments written before keys were scoped hold bare `[key, belief]` pairs.
+        json["idempotency_keys"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!(["k2", belief_id]));
+
+        let loaded: SegmentData = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            loaded.idempotency_keys,
+            vec![
+                (Some("tenant".to_string()), "k1".to_string(), belief_id),
+                (None, "k2".to_string(), belief_id),
+            ]
+        );
+    }
 }
@@ -5,10 +5,11 @@
 //! - WAL integration for durable writes
 //! - Segment manager for long-term storage
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 
 use chrono::{DateTime, Utc};
 
@@ -16,16 +17,26 @@ use crate::belief::Belief;
 use crate::confidence::BeliefId;
 use crate::conflict::{Conflict, ConflictId, ConflictStatus};
 use crate::derivation::{DerivationId, DerivationRecord};
-use crate::entity::{Entity, EntityId};
+use crate::entity::{Entity, EntityId, EntityType, EntityTypeHierarchy, MergeProvenance};
 use crate::error::{ExecutionError, KyroError};
+use crate::ir::KyroIR;
 use crate::pattern::{Pattern, PatternId};
+use crate::storage::memory::IdempotencyIndex;
+use crate::storage::{
+    check_supersedable, check_supersede, close_superseded, distinct_ids, ensure_finite_embedding, ensure_name_unclaimed,
+    ensure_same_namespace, entity_name_key, merge_family, name_index_key,
+};
 use crate::storage::traits::{
-    BeliefStore, ConflictStore, DerivationStore, EntityStore, PatternStore, StorageError,
+    AmendFields, BeliefStore, ConflictStore, DerivationStore, EntityStore, IdempotencyReservation,
+    IdempotencyStore, PatternStore, StorageError, StorageStats,
 };
 use crate::time::TimeRange;
+use crate::trust::{CalibrationTracker, FeedbackRecord, TrustModel, TrustSnapshot};
+use crate::value::Value;
 
-use super::file_lock::FileLock;
-use super::segment::SegmentManager;
+use super::codec::Cipher;
+use super::file_lock::{FileLock, LockMode};
+use super::segment::{Segment, SegmentBeliefs, SegmentManager};
 use super::wal::{WalEntryKind, WriteAheadLog};
 use super::PersistentConfig;
 
@@ -33,6 +44,59 @@ fn lock_err(context: &'static str) -> StorageError {
     StorageError::BackendError(format!("poisoned lock: {context}"))
 }
 
+/// Record the idempotency key an ASSERT wrote `belief_id` with, if it carried one.
+///
+/// The key travels in the belief's own WAL entry, so replay recovers both or neither.
+fn record_idempotency_key(
+    keys: &RwLock<IdempotencyIndex>,
+    namespace: Option<&str>,
+    key: Option<&str>,
+    belief_id: BeliefId,
+) -> Result<(), StorageError> {
+    if let Some(key) = key {
+        keys.write()
+            .map_err(|_| lock_err("idempotency.record"))?
+            .insert(namespace, key, belief_id);
+    }
+    Ok(())
+}
+
+/// Log `snapshot` as the saved trust configuration.
+///
+/// It replaces the saved one even if the WAL write fails, so the next compaction still
+/// saves it.
+fn log_trust(
+    wal: &WriteAheadLog,
+    trust: &RwLock<Option<TrustSnapshot>>,
+    snapshot: TrustSnapshot,
+) -> Result<(), StorageError> {
+    // Held across the append so compaction sees the WAL and the saved state agree.
+    let mut trust = trust.write().map_err(|_| lock_err("trust"))?;
+    let logged = wal
+        .append(WalEntryKind::TrustState(snapshot.clone()))
+        .map(drop)
+        .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")));
+    *trust = Some(snapshot);
+    logged
+}
+
+/// Log a FEEDBACK outcome into the saved `calibration`, keeping it even if the WAL write
+/// fails like [`log_trust`].
+fn log_feedback(
+    wal: &WriteAheadLog,
+    trust: &RwLock<Option<TrustSnapshot>>,
+    calibration: &CalibrationTracker,
+    record: FeedbackRecord,
+) -> Result<(), StorageError> {
+    let _trust = trust.write().map_err(|_| lock_err("trust"))?;
+    let logged = wal
+        .append(WalEntryKind::Feedback(record))
+        .map(drop)
+        .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")));
+    calibration.apply(record);
+    logged
+}
+
 fn normalize_key(s: &str) -> String {
     s.trim().to_ascii_lowercase()
 }
@@ -73,6 +137,110 @@ fn record_entity_version(
     Ok(())
 }
 
+/// Store `entity` under its ID, keeping the type index in step.
+fn put_entity(index: &mut EntityIndex, entity: Entity) {
+    if let Some(prev) = index.by_id.get(&entity.id) {
+        index.by_type.remove(prev);
+    }
+    index.by_type.insert(&entity);
+    index.by_id.insert(entity.id, entity);
+}
+
+fn take_entity(index: &mut EntityIndex, id: EntityId) -> Option<Entity> {
+    let entity = index.by_id.remove(&id)?;
+    index.by_type.remove(&entity);
+    Some(entity)
+}
+
+fn validate_unmerge(index: &EntityIndex, primary: &Entity, restored: &Entity) -> Result<(), StorageError> {
+    if index.merged_into.get(&restored.id) != Some(&primary.id) {
+        return Err(StorageError::BackendError(format!(
+            "cannot unmerge: {} is not merged into {}",
+            restored.id, primary.id
+        )));
+    }
+
+    let prev_primary = index
+        .by_id
+        .get(&primary.id)
+        .ok_or(StorageError::EntityNotFound(primary.id))?;
+    if primary.version <= prev_primary.version {
+        return Err(StorageError::BackendError(format!(
+            "entity version must increase on unmerge: id={} prev={} new={}",
+            primary.id, prev_primary.version, primary.version
+        )));
+    }
+
+    for entity in [primary, restored] {
+        if index
+            .versions
+            .get(&entity.id)
+            .is_some_and(|m| m.contains_key(&entity.version))
+        {
+            return Err(StorageError::BackendError(format!(
+                "entity version already exists (entity.unmerge): id={} version={}",
+                entity.id, entity.version
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn apply_unmerge(index: &mut EntityIndex, primary: Entity, restored: Entity) -> Result<(), StorageError> {
+    let primary_id = primary.id;
+    let secondary_id = restored.id;
+
+    record_entity_version(index, &primary, "entity.unmerge")?;
+    record_entity_version(index, &restored, "entity.unmerge")?;
+    put_entity(index, primary);
+    index
+        .by_name
+        .entry(entity_name_key(&restored))
+        .or_default()
+        .insert(secondary_id);
+    put_entity(index, restored);
+
+    index.merged_into.remove(&secondary_id);
+    if let Some(set) = index.merged_from.get_mut(&primary_id) {
+        set.remove(&secondary_id);
+        if set.is_empty() {
+            index.merged_from.remove(&primary_id);
+        }
+    }
+    index.merge_provenance.remove(&secondary_id);
+    Ok(())
+}
+
+/// Fold `secondary`'s names, metadata, and embedding into `primary`.
+fn absorb_entity(primary: &mut Entity, secondary: &Entity) -> Result<(), StorageError> {
+    ensure_same_namespace(primary, secondary)?;
+    let secondary_names = std::iter::once(secondary.canonical_name.as_str())
+        .chain(secondary.aliases.iter().map(String::as_str));
+    for name in secondary_names {
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        if name.eq_ignore_ascii_case(&primary.canonical_name) {
+            continue;
+        }
+        if primary.aliases.iter().any(|a| a.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+        primary.aliases.push(name.to_string());
+    }
+
+    primary.metadata = merge_metadata(&primary.metadata, &secondary.metadata);
+
+    primary.embedding = match (primary.embedding.as_ref(), secondary.embedding.as_ref()) {
+        (Some(a), Some(b)) => Some(merge_embeddings(a, b)?),
+        (Some(a), None) => Some(a.clone()),
+        (None, Some(b)) => Some(b.clone()),
+        (None, None) => None,
+    };
+    Ok(())
+}
+
 fn merge_metadata(primary: &serde_json::Value, secondary: &serde_json::Value) -> serde_json::Value {
     use serde_json::Value;
 
@@ -130,6 +298,7 @@ fn validate_embedding_dim(
             "embedding dimension must be non-zero ({context})"
         )));
     }
+    ensure_finite_embedding(embedding, context)?;
 
     if let Some(exp) = expected {
         if exp != embedding.len() {
@@ -160,20 +329,38 @@ fn apply_embedding_dim(
 
 type EntityIndex = super::segment::EntityIndex;
 
+/// Lock file read-only handles hold shared; compaction takes it exclusively.
+const READERS_LOCK_FILE: &str = ".readers.lock";
+
+fn storage_error(message: impl Into<String>) -> KyroError {
+    KyroError::Execution(ExecutionError::Storage {
+        message: message.into(),
+    })
+}
+
 /// Aggregate type containing all persistent stores.
 ///
 /// This is the primary entry point for persistent storage.
 pub struct PersistentStores {
     /// The database directory.
     pub dir: std::path::PathBuf,
-    /// File lock preventing concurrent access.
+    /// File lock preventing concurrent writers (shared readers lock when read-only).
     _lock: FileLock,
+    /// Whether this handle was opened with [`PersistentStores::open_read_only`].
+    read_only: bool,
     /// Write-ahead log for durability.
     wal: Arc<WriteAheadLog>,
     /// Segment manager for compacted data.
     _segments: Arc<RwLock<SegmentManager>>,
     /// Configuration.
     _config: PersistentConfig,
+    /// Serializes compactions; writes proceed while one runs.
+    compaction: Mutex<()>,
+    /// Trust model configuration last saved with [`PersistentStores::save_trust`]. Its lock
+    /// is also held while logging FEEDBACK outcomes into `calibration`.
+    trust: Arc<RwLock<Option<TrustSnapshot>>>,
+    /// FEEDBACK outcomes saved through [`PersistentStores::open_with_trust`].
+    calibration: Arc<CalibrationTracker>,
     
     // Individual stores
     pub entities: PersistentEntityStore,
@@ -181,6 +368,7 @@ pub struct PersistentStores {
     pub patterns: PersistentPatternStore,
     pub conflicts: PersistentConflictStore,
     pub derivations: PersistentDerivationStore,
+    pub idempotency: PersistentIdempotencyStore,
 }
 
 impl PersistentStores {
@@ -202,16 +390,121 @@ impl PersistentStores {
         
         // Open WAL
         let wal_path = dir.join("kyro.wal");
-        let wal = Arc::new(WriteAheadLog::open(&wal_path, config.sync_on_write).map_err(|e| {
+        let cipher = config.encryption_key.as_ref().map(Cipher::new);
+        let wal = match config.group_commit {
+            Some(group) if config.sync_on_write => {
+                WriteAheadLog::open_with_group_commit(&wal_path, group, cipher)
+            }
+            _ => WriteAheadLog::open(&wal_path, config.sync_on_write, cipher),
+        }
+        .map_err(|e| {
             KyroError::Execution(ExecutionError::Storage {
                 message: format!("failed to open WAL: {}", e),
             })
-        })?);
+        })?;
+
+        Self::load(dir, config, lock, wal)
+    }
+
+    /// Open or create a persistent database like [`open`](Self::open), then restore the
+    /// trust configuration and FEEDBACK calibration saved in it into `model` and
+    /// `calibration` and keep saving both: every later change to either is logged as it
+    /// happens.
+    ///
+    /// Give the engine the same `model` and `calibration`, e.g. through
+    /// [`KyroEngine::with_trust_model`] and [`KyroEngine::with_calibration`]. Whatever
+    /// `model` and `calibration` hold is saved instead if the database has nothing saved
+    /// for them. A model without [`TrustModel::snapshot`]s is neither restored nor saved.
+    ///
+    /// A WAL write that fails while saving a later change is returned by the call that made
+    /// it, such as a trust setter or FEEDBACK. The change still applies in memory and is
+    /// saved by the next successful [`compact`](Self::compact).
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage error if the database cannot be opened or the initial state cannot
+    /// be saved, or a validation error if `model` cannot restore the saved configuration.
+    ///
+    /// [`KyroEngine::with_trust_model`]: crate::engine::KyroEngine::with_trust_model
+    /// [`KyroEngine::with_calibration`]: crate::engine::KyroEngine::with_calibration
+    pub fn open_with_trust(
+        dir: &Path,
+        config: PersistentConfig,
+        model: &dyn TrustModel,
+        calibration: &CalibrationTracker,
+    ) -> Result<Self, KyroError> {
+        let stores = Self::open(dir, config)?;
+        let saved_err = |e: StorageError| storage_error(format!("failed to save trust state: {e}"));
+
+        if !stores.restore_trust(model)? {
+            stores.save_trust(model).map_err(saved_err)?;
+        }
+        let saved = stores.calibration.snapshot();
+        if saved.outcomes.is_empty() {
+            for record in calibration.snapshot().outcomes {
+                log_feedback(&stores.wal, &stores.trust, &stores.calibration, record).map_err(saved_err)?;
+            }
+        } else {
+            calibration.restore(&saved);
+        }
+
+        let (wal, trust) = (Arc::clone(&stores.wal), Arc::clone(&stores.trust));
+        model.on_change(Box::new(move |snapshot| {
+            log_trust(&wal, &trust, snapshot.clone()).map_err(saved_err)
+        }));
+        let (wal, trust, saved) = (
+            Arc::clone(&stores.wal),
+            Arc::clone(&stores.trust),
+            Arc::clone(&stores.calibration),
+        );
+        calibration.on_record(Box::new(move |record| {
+            log_feedback(&wal, &trust, &saved, *record).map_err(saved_err)
+        }));
+        Ok(stores)
+    }
+
+    /// Open an existing database for queries alongside a live writer.
+    ///
+    /// Takes a shared lock rather than the writer's exclusive one, so any number of
+    /// read-only handles may coexist with one writer. The state is the WAL and segments
+    /// as of open; every write method fails with a `Storage` error and `compact` is refused.
+    pub fn open_read_only(dir: &Path, config: PersistentConfig) -> Result<Self, KyroError> {
+        let wal_path = dir.join("kyro.wal");
+        if !wal_path.exists() {
+            return Err(storage_error(format!(
+                "no database found at {}",
+                dir.display()
+            )));
+        }
+
+        let lock = FileLock::acquire_path(&dir.join(READERS_LOCK_FILE), LockMode::Shared)
+            .map_err(|e| storage_error(format!("failed to acquire shared lock: {e}")))?;
+
+        let cipher = config.encryption_key.as_ref().map(Cipher::new);
+        let wal = WriteAheadLog::open_read_only(&wal_path, cipher)
+            .map_err(|e| storage_error(format!("failed to open WAL: {e}")))?;
+
+        Self::load(dir, config, lock, wal)
+    }
+
+    /// Returns `true` if this handle rejects writes.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn load(
+        dir: &Path,
+        config: PersistentConfig,
+        lock: FileLock,
+        wal: WriteAheadLog,
+    ) -> Result<Self, KyroError> {
+        let read_only = wal.is_read_only();
+        let wal = Arc::new(wal);
         
         // Open segment manager
         let segments_dir = dir.join("segments");
         let segments = Arc::new(RwLock::new(
-            SegmentManager::open(&segments_dir).map_err(|e| {
+            SegmentManager::open(&segments_dir, config.encryption_key.as_ref().map(Cipher::new)).map_err(|e| {
                 KyroError::Execution(ExecutionError::Storage {
                     message: format!("failed to open segments: {}", e),
                 })
@@ -219,8 +512,13 @@ impl PersistentStores {
         ));
         
         // Create stores with shared WAL
-        let entities = PersistentEntityStore::new(wal.clone());
-        let beliefs = PersistentBeliefStore::new(wal.clone());
+        let entities = PersistentEntityStore::new(
+            wal.clone(),
+            config.unique_entity_names,
+            config.type_hierarchy.clone(),
+        );
+        let idempotency = PersistentIdempotencyStore::new(wal.clone());
+        let beliefs = PersistentBeliefStore::new(wal.clone(), idempotency.index.clone());
         let patterns = PersistentPatternStore::new(wal.clone());
         let conflicts = PersistentConflictStore::new(wal.clone());
         let derivations = PersistentDerivationStore::new(wal.clone());
@@ -228,14 +526,19 @@ impl PersistentStores {
         let mut stores = Self {
             dir: dir.to_path_buf(),
             _lock: lock,
+            read_only,
             wal,
             _segments: segments,
             _config: config,
+            compaction: Mutex::new(()),
+            trust: Arc::new(RwLock::new(None)),
+            calibration: Arc::new(CalibrationTracker::new()),
             entities,
             beliefs,
             patterns,
             conflicts,
             derivations,
+            idempotency,
         };
         
         // Load data from segments first (compacted data)
@@ -255,23 +558,49 @@ impl PersistentStores {
             return Ok(());
         }
         
-        let data = segments.load_all_data().map_err(|e| {
+        // Beliefs stay on disk until a lookup needs them.
+        let data = segments.load_records().map_err(|e| {
             KyroError::Execution(ExecutionError::Storage {
                 message: format!("failed to load segment data: {}", e),
             })
         })?;
+        let cold: Vec<Segment> = segments.segments().iter().rev().cloned().collect();
         drop(segments);
         
         // Populate in-memory indexes
         *self.entities.index.write().unwrap() = data.entities;
-        *self.beliefs.index.write().unwrap() = BeliefIndex::from_map(data.beliefs);
+        *self.beliefs.index.write().unwrap() = BeliefIndex {
+            deleted: data.deleted_beliefs,
+            ..BeliefIndex::default()
+        };
+        self.beliefs.cold.write().unwrap().segments = cold;
         *self.patterns.index.write().unwrap() = data.patterns;
-        *self.conflicts.index.write().unwrap() = data.conflicts;
+        *self.conflicts.index.write().unwrap() = ConflictIndex::from_map(data.conflicts);
         *self.derivations.index.write().unwrap() = data.derivations;
+        let mut keys = self.idempotency.index.write().unwrap();
+        for (namespace, key, belief_id) in data.idempotency_keys {
+            keys.insert(namespace.as_deref(), &key, belief_id);
+        }
+        drop(keys);
+        *self.trust.write().unwrap() = data.trust;
+        if let Some(calibration) = data.calibration {
+            self.calibration.restore(&calibration);
+        }
         
         Ok(())
     }
     
+    /// Recover the idempotency key a replayed belief was written with.
+    fn replay_idempotency_key(&self, belief: &Belief) -> Result<(), KyroError> {
+        record_idempotency_key(
+            &self.idempotency.index,
+            belief.namespace.as_deref(),
+            belief.idempotency_key.as_deref(),
+            belief.id,
+        )
+        .map_err(|e| KyroError::Execution(ExecutionError::Storage { message: e.to_string() }))
+    }
+
     /// Replay WAL entries to restore in-memory state.
     fn replay_wal(&mut self) -> Result<(), KyroError> {
         let iter = self.wal.iter().map_err(|e| {
@@ -292,7 +621,8 @@ impl PersistentStores {
                 WalEntryKind::EntityInsert(_)
                 | WalEntryKind::EntityUpdate(_)
                 | WalEntryKind::EntityDelete { .. }
-                | WalEntryKind::EntityMerge { .. } => {
+                | WalEntryKind::EntityMerge { .. }
+                | WalEntryKind::EntityUnmerge { .. } => {
                     self.entities.apply_wal(&entry.kind).map_err(|e| {
                         KyroError::Execution(ExecutionError::Storage {
                             message: format!("failed to apply WAL entity entry: {e}"),
@@ -300,6 +630,7 @@ impl PersistentStores {
                     })?;
                 }
                 WalEntryKind::BeliefInsert(belief) => {
+                    self.replay_idempotency_key(&belief)?;
                     self.beliefs
                         .index
                         .write()
@@ -308,7 +639,48 @@ impl PersistentStores {
                         }))?
                         .insert(belief);
                 }
-                WalEntryKind::BeliefSupersede { old_id, new_id } => {
+                WalEntryKind::BeliefInsertSuperseding(belief) => {
+                    for &id in &belief.supersedes {
+                        self.beliefs.fault_in_belief(id).map_err(|e| {
+                            KyroError::Execution(ExecutionError::Storage {
+                                message: format!("failed to load belief for WAL replay: {e}"),
+                            })
+                        })?;
+                    }
+                    let mut index = self
+                        .beliefs
+                        .index
+                        .write()
+                        .map_err(|_| KyroError::Execution(ExecutionError::Storage {
+                            message: "poisoned lock: belief.wal".to_string(),
+                        }))?;
+                    self.replay_idempotency_key(&belief)?;
+                    let (new_id, old_ids) = (belief.id, belief.supersedes.clone());
+                    index.insert(belief);
+                    index.apply_supersede(&old_ids, new_id);
+                }
+                WalEntryKind::BeliefSupersede { old_ids, new_id } => {
+                    for &id in old_ids.iter().chain([&new_id]) {
+                        self.beliefs.fault_in_belief(id).map_err(|e| {
+                            KyroError::Execution(ExecutionError::Storage {
+                                message: format!("failed to load belief for WAL replay: {e}"),
+                            })
+                        })?;
+                    }
+                    self.beliefs
+                        .index
+                        .write()
+                        .map_err(|_| KyroError::Execution(ExecutionError::Storage {
+                            message: "poisoned lock: belief.wal".to_string(),
+                        }))?
+                        .apply_supersede(&old_ids, new_id);
+                }
+                WalEntryKind::BeliefAmend { id, fields } => {
+                    self.beliefs.fault_in_belief(id).map_err(|e| {
+                        KyroError::Execution(ExecutionError::Storage {
+                            message: format!("failed to load belief for WAL replay: {e}"),
+                        })
+                    })?;
                     if let Some(belief) = self
                         .beliefs
                         .index
@@ -317,11 +689,61 @@ impl PersistentStores {
                             message: "poisoned lock: belief.wal".to_string(),
                         }))?
                         .by_id
-                        .get_mut(&old_id)
+                        .get_mut(&id)
                     {
-                        belief.superseded_by = Some(new_id);
+                        fields.apply(belief);
+                        let key = fields.corroboration.as_ref().and_then(|c| c.idempotency_key.as_deref());
+                        record_idempotency_key(&self.idempotency.index, belief.namespace.as_deref(), key, id)
+                            .map_err(|e| KyroError::Execution(ExecutionError::Storage { message: e.to_string() }))?;
                     }
                 }
+                WalEntryKind::BeliefCoalesce { updated, removed } => {
+                    for belief in &updated {
+                        self.beliefs.fault_in_belief(belief.id).map_err(|e| {
+                            KyroError::Execution(ExecutionError::Storage {
+                                message: format!("failed to load belief for WAL replay: {e}"),
+                            })
+                        })?;
+                    }
+                    self.beliefs
+                        .index
+                        .write()
+                        .map_err(|_| KyroError::Execution(ExecutionError::Storage {
+                            message: "poisoned lock: belief.wal".to_string(),
+                        }))?
+                        .apply_coalesce(updated, &removed);
+                }
+                WalEntryKind::BeliefReembed { embeddings } => {
+                    self.beliefs.fault_in_all().map_err(|e| {
+                        KyroError::Execution(ExecutionError::Storage {
+                            message: format!("failed to load beliefs for WAL replay: {e}"),
+                        })
+                    })?;
+                    self.beliefs
+                        .index
+                        .write()
+                        .map_err(|_| KyroError::Execution(ExecutionError::Storage {
+                            message: "poisoned lock: belief.wal".to_string(),
+                        }))?
+                        .apply_reembed(embeddings);
+                }
+                WalEntryKind::BeliefDelete { id } => {
+                    self.beliefs
+                        .fault_in_belief(id)
+                        .and_then(|()| self.beliefs.fault_in_successor(id))
+                        .map_err(|e| {
+                            KyroError::Execution(ExecutionError::Storage {
+                                message: format!("failed to load belief for WAL replay: {e}"),
+                            })
+                        })?;
+                    self.beliefs
+                        .index
+                        .write()
+                        .map_err(|_| KyroError::Execution(ExecutionError::Storage {
+                            message: "poisoned lock: belief.wal".to_string(),
+                        }))?
+                        .remove(id);
+                }
                 WalEntryKind::PatternInsert(pattern) => {
                     self.patterns.index.write().unwrap().insert(pattern.id, pattern);
                 }
@@ -331,17 +753,29 @@ impl PersistentStores {
                 WalEntryKind::PatternDelete { id } => {
                     self.patterns.index.write().unwrap().remove(&id);
                 }
-                WalEntryKind::ConflictInsert(conflict) => {
-                    self.conflicts.index.write().unwrap().insert(conflict.id, conflict);
+                WalEntryKind::ConflictInsert(conflict)
+                | WalEntryKind::ConflictUpdate(conflict) => {
+                    self.conflicts.index.write().unwrap().upsert(conflict);
                 }
-                WalEntryKind::ConflictUpdate(conflict) => {
-                    self.conflicts.index.write().unwrap().insert(conflict.id, conflict);
-                }
-                WalEntryKind::DerivationInsert(record) => {
+                WalEntryKind::DerivationInsert(record)
+                | WalEntryKind::DerivationUpdate(record) => {
                     self.derivations.index.write().unwrap().insert(record.id, record);
                 }
-                WalEntryKind::Checkpoint { .. } => {
-                    // Checkpoint markers are informational during replay
+                WalEntryKind::IdempotencyRecord { namespace, key, belief_id } => {
+                    self.idempotency
+                        .index
+                        .write()
+                        .unwrap()
+                        .insert(namespace.as_deref(), &key, belief_id);
+                }
+                WalEntryKind::TrustState(snapshot) => {
+                    *self.trust.write().unwrap() = Some(snapshot);
+                }
+                WalEntryKind::Feedback(record) => {
+                    self.calibration.apply(record);
+                }
+                WalEntryKind::Checkpoint { .. } | WalEntryKind::Operation(_) => {
+                    // Checkpoint markers and operation entries are informational during replay
                 }
             }
             
@@ -358,15 +792,31 @@ impl PersistentStores {
     /// Compact the WAL into a segment file.
     ///
     /// This operation:
-    /// 1. Snapshots all in-memory state
+    /// 1. Snapshots all in-memory state, with the WAL sequence it reflects
     /// 2. Writes it atomically to a new segment file
     /// 3. Writes a checkpoint marker to the WAL
-    /// 4. Truncates the WAL
+    /// 4. Truncates the WAL up to the snapshot's sequence
     ///
-    /// This is safe to call at any time - if it fails partway through,
-    /// the WAL still contains all data and will be replayed on next open.
-    pub fn compact(&mut self) -> Result<CompactionResult, KyroError> {
+    /// This is safe to call at any time, including while other threads write: writes that
+    /// land after the snapshot stay in the WAL. If it fails partway through, the WAL still
+    /// contains all data and will be replayed on next open.
+    ///
+    /// Fails while read-only handles are open, since one may still be replaying the
+    /// WAL this would truncate.
+    pub fn compact(&self) -> Result<CompactionResult, KyroError> {
         use super::segment::SegmentData;
+
+        if self.read_only {
+            return Err(storage_error("cannot compact: database is opened read-only"));
+        }
+        let _compacting = self.compaction.lock().map_err(|_| storage_error("poisoned lock: compaction"))?;
+        let _readers = FileLock::acquire_path(&self.dir.join(READERS_LOCK_FILE), LockMode::Exclusive)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::WouldBlock => {
+                    storage_error("cannot compact while read-only handles are open")
+                }
+                _ => storage_error(format!("failed to acquire readers lock: {e}")),
+            })?;
         
         let current_seq = self.wal.current_sequence();
         if current_seq == 0 {
@@ -379,19 +829,58 @@ impl PersistentStores {
         }
         
         let wal_size_before = self.wal.size_bytes().unwrap_or(0);
+
+        // The new segment must hold every belief, including ones never read since open.
+        self.beliefs.fault_in_all().map_err(|e| {
+            KyroError::Execution(ExecutionError::Storage {
+                message: format!("failed to load beliefs for compaction: {e}"),
+            })
+        })?;
         
-        // Snapshot current state. This assumes callers do not perform concurrent writes while
-        // holding &mut self; the public API enforces that, but the interior RwLocks would allow
-        // a misuse via borrowed sub-stores. If that misuse happens, WAL replay remains the source
-        // of truth and will reconcile any missing entries on reopen.
-        let data = SegmentData {
-            entities: self.entities.index.read().unwrap().clone(),
-            beliefs: self.beliefs.index.read().unwrap().by_id.clone(),
-            patterns: self.patterns.index.read().unwrap().clone(),
-            conflicts: self.conflicts.index.read().unwrap().clone(),
-            derivations: self.derivations.index.read().unwrap().clone(),
+        // Snapshot current state. Every write appends to the WAL while holding its store's
+        // index lock, so with all of them held for reading the sequence read here covers
+        // exactly the writes the snapshot reflects. Belief inserts still waiting on group
+        // commit are included, so the segment is only written once they are durable.
+        let (data, snapshot_seq) = {
+            let entities = self.entities.index.read().unwrap();
+            let beliefs = self.beliefs.index.read().unwrap();
+            let patterns = self.patterns.index.read().unwrap();
+            let conflicts = self.conflicts.index.read().unwrap();
+            let derivations = self.derivations.index.read().unwrap();
+            // Keys of beliefs still waiting on group commit are not in the index yet.
+            let mut idempotency = self.idempotency.index.read().unwrap().clone();
+            for belief in beliefs.pending.values() {
+                if let Some(key) = &belief.idempotency_key {
+                    idempotency.insert(belief.namespace.as_deref(), key, belief.id);
+                }
+            }
+            let trust = self.trust.read().unwrap();
+            let calibration = self.calibration.snapshot();
+            let data = SegmentData {
+                entities: entities.clone(),
+                beliefs: beliefs
+                    .by_id
+                    .iter()
+                    .chain(&beliefs.pending)
+                    .map(|(id, belief)| (*id, belief.clone()))
+                    .collect(),
+                deleted_beliefs: beliefs.deleted.clone(),
+                patterns: patterns.clone(),
+                conflicts: conflicts.by_id.clone(),
+                derivations: derivations.clone(),
+                idempotency_keys: idempotency.entries(),
+                trust: trust.clone(),
+                calibration: (!calibration.outcomes.is_empty()).then_some(calibration),
+            };
+            (data, self.wal.current_sequence())
         };
         
+        self.wal.wait_durable().map_err(|e| {
+            KyroError::Execution(ExecutionError::Storage {
+                message: format!("failed to sync WAL before compaction: {e}"),
+            })
+        })?;
+
         let entry_count = data.entry_count();
         
         // Write segment atomically
@@ -404,7 +893,7 @@ impl PersistentStores {
             })
         })?;
         
-        if let Err(e) = writer.write_data(&data, current_seq) {
+        if let Err(e) = writer.write_data(&data, snapshot_seq) {
             let _ = writer.abort();
             return Err(KyroError::Execution(ExecutionError::Storage {
                 message: format!("failed to write segment data: {}", e),
@@ -422,14 +911,14 @@ impl PersistentStores {
         drop(segments);
         
         // Write checkpoint marker to WAL
-        self.wal.append(WalEntryKind::Checkpoint { up_to_sequence: current_seq }).map_err(|e| {
+        self.wal.append(WalEntryKind::Checkpoint { up_to_sequence: snapshot_seq }).map_err(|e| {
             KyroError::Execution(ExecutionError::Storage {
                 message: format!("failed to write checkpoint: {}", e),
             })
         })?;
         
-        // Truncate WAL
-        self.wal.truncate().map_err(|e| {
+        // Truncate WAL, keeping whatever was written since the snapshot
+        self.wal.truncate_through(snapshot_seq).map_err(|e| {
             KyroError::Execution(ExecutionError::Storage {
                 message: format!("failed to truncate WAL: {}", e),
             })
@@ -445,6 +934,80 @@ impl PersistentStores {
         })
     }
     
+    /// Stream every live belief, for exporting the store to an external sink.
+    ///
+    /// Beliefs already in memory, including everything replayed from the WAL, come first;
+    /// beliefs only segments hold follow, newest segment first, read a chunk at a time and
+    /// without being loaded into the store. Only belief ids are retained across the stream.
+    /// Writes made while it runs may or may not be reflected.
+    ///
+    /// An item is an error if a segment cannot be read or a lock is poisoned.
+    pub fn iter_beliefs(&self) -> impl Iterator<Item = Result<Belief, StorageError>> + '_ {
+        let (stream, failed) = match self.beliefs.stream() {
+            Ok(stream) => (Some(stream), None),
+            Err(e) => (None, Some(Err(e))),
+        };
+        failed.into_iter().chain(stream.into_iter().flatten())
+    }
+
+    /// Save `model`'s configuration, replacing any saved before.
+    ///
+    /// Restore it after reopening with [`restore_trust`](Self::restore_trust), or open with
+    /// [`open_with_trust`](Self::open_with_trust) to do both automatically. Does nothing for
+    /// models without a [`TrustModel::snapshot`].
+    pub fn save_trust(&self, model: &dyn TrustModel) -> Result<(), StorageError> {
+        match model.snapshot() {
+            Some(snapshot) => log_trust(&self.wal, &self.trust, snapshot),
+            None => Ok(()),
+        }
+    }
+
+    /// Restore the configuration last saved with [`save_trust`](Self::save_trust) into
+    /// `model`. Returns `false`, leaving `model` unchanged, if none was saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `model` cannot restore the saved snapshot.
+    pub fn restore_trust(&self, model: &dyn TrustModel) -> Result<bool, KyroError> {
+        let trust = self
+            .trust
+            .read()
+            .map_err(|_| storage_error("poisoned lock: trust"))?;
+        match trust.as_ref() {
+            Some(snapshot) => {
+                model.restore(snapshot)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// The trust model configuration saved in this database, if any.
+    pub fn trust_snapshot(&self) -> Option<TrustSnapshot> {
+        self.trust.read().unwrap().clone()
+    }
+
+    /// Append `ir` to the WAL as an audit-only [`WalEntryKind::Operation`] entry.
+    ///
+    /// Engines log through this via the `OperationLog` implementation, before the operation
+    /// writes anything. Returns the entry's WAL sequence number.
+    pub fn log_operation(&self, ir: &KyroIR) -> Result<u64, StorageError> {
+        self.wal
+            .append(WalEntryKind::Operation(ir.clone()))
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")))
+    }
+
+    /// Operations logged with [`log_operation`](Self::log_operation), oldest first, with
+    /// their WAL sequence numbers.
+    ///
+    /// Only covers the WAL since the last compaction.
+    pub fn iter_operations(&self) -> Result<Vec<(u64, KyroIR)>, StorageError> {
+        self.wal
+            .iter_operations()
+            .and_then(Iterator::collect)
+            .map_err(|e| StorageError::BackendError(format!("failed to read WAL operations: {e}")))
+    }
+
     /// Get the current WAL size in bytes.
     pub fn wal_size(&self) -> u64 {
         self.wal.size_bytes().unwrap_or(0)
@@ -454,6 +1017,41 @@ impl PersistentStores {
     pub fn segment_count(&self) -> usize {
         self._segments.read().unwrap().segments().len()
     }
+
+    /// Record counts for every store plus WAL and segment footprint.
+    pub fn stats(&self) -> Result<PersistentStats, StorageError> {
+        Ok(PersistentStats {
+            entities: self.entities.stats()?,
+            beliefs: self.beliefs.stats()?,
+            patterns: self.patterns.stats()?,
+            conflicts: self.conflicts.stats()?,
+            derivations: self.derivations.stats()?,
+            idempotency_keys: self.idempotency.stats()?,
+            wal_bytes: self.wal_size(),
+            segment_count: self.segment_count(),
+        })
+    }
+}
+
+/// Snapshot of a persistent database's contents and on-disk footprint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PersistentStats {
+    /// Entity store counts.
+    pub entities: StorageStats,
+    /// Belief store counts.
+    pub beliefs: StorageStats,
+    /// Pattern store counts.
+    pub patterns: StorageStats,
+    /// Conflict store counts.
+    pub conflicts: StorageStats,
+    /// Derivation store counts.
+    pub derivations: StorageStats,
+    /// Idempotency store counts.
+    pub idempotency_keys: StorageStats,
+    /// Current WAL size in bytes.
+    pub wal_bytes: u64,
+    /// Number of segment files.
+    pub segment_count: usize,
 }
 
 /// Result of a compaction operation.
@@ -474,13 +1072,19 @@ pub struct CompactionResult {
 pub struct PersistentEntityStore {
     wal: Arc<WriteAheadLog>,
     index: RwLock<EntityIndex>,
+    /// Reject writes that would share a canonical name. Replay is never checked, so a
+    /// log written without the constraint still opens.
+    unique_names: bool,
+    type_hierarchy: EntityTypeHierarchy,
 }
 
 impl PersistentEntityStore {
-    fn new(wal: Arc<WriteAheadLog>) -> Self {
+    fn new(wal: Arc<WriteAheadLog>, unique_names: bool, type_hierarchy: EntityTypeHierarchy) -> Self {
         Self {
             wal,
             index: RwLock::new(EntityIndex::default()),
+            unique_names,
+            type_hierarchy,
         }
     }
 
@@ -491,6 +1095,10 @@ impl PersistentEntityStore {
             return Err(StorageError::DuplicateKey(entity.id.to_string()));
         }
 
+        if emit_wal && self.unique_names {
+            ensure_name_unclaimed(&index.by_name, &entity)?;
+        }
+
         if let Some(emb) = entity.embedding.as_ref() {
             validate_embedding_dim(index.embedding_dim, emb, "entity.insert")?;
         }
@@ -498,7 +1106,7 @@ impl PersistentEntityStore {
         if index
             .versions
             .get(&entity.id)
-            .map_or(false, |m| m.contains_key(&entity.version))
+            .is_some_and(|m| m.contains_key(&entity.version))
         {
             return Err(StorageError::BackendError(format!(
                 "entity version already exists (entity.insert): id={} version={}",
@@ -516,9 +1124,9 @@ impl PersistentEntityStore {
         apply_embedding_dim(&mut index.embedding_dim, entity.embedding.as_ref(), "entity.insert")?;
         record_entity_version(&mut index, &entity, "entity.insert")?;
 
-        let name_key = normalize_key(&entity.canonical_name);
+        let name_key = entity_name_key(&entity);
         index.by_name.entry(name_key).or_default().insert(entity.id);
-        index.by_id.insert(entity.id, entity);
+        put_entity(&mut index, entity);
         Ok(())
     }
 
@@ -545,6 +1153,18 @@ impl PersistentEntityStore {
             )));
         }
 
+        // Beliefs carry their subject's namespace, so an entity cannot move between tenants.
+        if entity.namespace != prev.namespace {
+            return Err(StorageError::BackendError(format!(
+                "entity namespace cannot change on update: id={}",
+                entity.id
+            )));
+        }
+
+        if emit_wal && self.unique_names {
+            ensure_name_unclaimed(&index.by_name, &entity)?;
+        }
+
         if let Some(emb) = entity.embedding.as_ref() {
             validate_embedding_dim(index.embedding_dim, emb, "entity.update")?;
         }
@@ -552,7 +1172,7 @@ impl PersistentEntityStore {
         if index
             .versions
             .get(&entity.id)
-            .map_or(false, |m| m.contains_key(&entity.version))
+            .is_some_and(|m| m.contains_key(&entity.version))
         {
             return Err(StorageError::BackendError(format!(
                 "entity version already exists (entity.update): id={} version={}",
@@ -569,8 +1189,8 @@ impl PersistentEntityStore {
 
         apply_embedding_dim(&mut index.embedding_dim, entity.embedding.as_ref(), "entity.update")?;
 
-        let prev_key = normalize_key(&prev.canonical_name);
-        let new_key = normalize_key(&entity.canonical_name);
+        let prev_key = entity_name_key(&prev);
+        let new_key = entity_name_key(&entity);
         if prev_key != new_key {
             if let Some(set) = index.by_name.get_mut(&prev_key) {
                 set.remove(&entity.id);
@@ -582,7 +1202,7 @@ impl PersistentEntityStore {
         }
 
         record_entity_version(&mut index, &entity, "entity.update")?;
-        index.by_id.insert(entity.id, entity);
+        put_entity(&mut index, entity);
         Ok(())
     }
 
@@ -599,7 +1219,7 @@ impl PersistentEntityStore {
         if index
             .merged_from
             .get(&id)
-            .map_or(false, |s| !s.is_empty())
+            .is_some_and(|s| !s.is_empty())
         {
             return Err(StorageError::BackendError(
                 "cannot delete an entity that has other entities merged into it".to_string(),
@@ -613,12 +1233,9 @@ impl PersistentEntityStore {
                 .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")))?;
         }
 
-        let prev = index
-            .by_id
-            .remove(&id)
-            .ok_or(StorageError::EntityNotFound(id))?;
+        let prev = take_entity(&mut index, id).ok_or(StorageError::EntityNotFound(id))?;
 
-        let prev_key = normalize_key(&prev.canonical_name);
+        let prev_key = entity_name_key(&prev);
         if let Some(set) = index.by_name.get_mut(&prev_key) {
             set.remove(&id);
             if set.is_empty() {
@@ -634,6 +1251,7 @@ impl PersistentEntityStore {
         merged: Entity,
         secondary_id: EntityId,
         secondary_canonical: String,
+        provenance: Option<MergeProvenance>,
         emit_wal: bool,
     ) -> Result<Entity, StorageError> {
         let mut index = self.index.write().map_err(|_| lock_err("entity.merge"))?;
@@ -671,7 +1289,7 @@ impl PersistentEntityStore {
         if index
             .versions
             .get(&merged.id)
-            .map_or(false, |m| m.contains_key(&merged.version))
+            .is_some_and(|m| m.contains_key(&merged.version))
         {
             return Err(StorageError::BackendError(format!(
                 "entity version already exists (entity.merge): id={} version={}",
@@ -686,14 +1304,15 @@ impl PersistentEntityStore {
                     merged: merged.clone(),
                     secondary_id: secondary_canonical_id,
                     secondary_canonical: secondary_canonical.clone(),
+                    provenance: provenance.clone(),
                 })
                 .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")))?;
         }
 
         apply_embedding_dim(&mut index.embedding_dim, merged.embedding.as_ref(), "entity.merge")?;
 
-        let prev_key = normalize_key(&prev_primary.canonical_name);
-        let new_key = normalize_key(&merged.canonical_name);
+        let prev_key = entity_name_key(&prev_primary);
+        let new_key = entity_name_key(&merged);
         if prev_key != new_key {
             if let Some(set) = index.by_name.get_mut(&prev_key) {
                 set.remove(&merged.id);
@@ -705,16 +1324,16 @@ impl PersistentEntityStore {
         }
 
         record_entity_version(&mut index, &merged, "entity.merge")?;
-        index.by_id.insert(primary_canonical, merged.clone());
+        put_entity(&mut index, merged.clone());
 
-        let secondary_key = normalize_key(&secondary_canonical);
+        let secondary_key = name_index_key(merged.namespace.as_deref(), &secondary_canonical);
         if let Some(set) = index.by_name.get_mut(&secondary_key) {
             set.remove(&secondary_canonical_id);
             if set.is_empty() {
                 index.by_name.remove(&secondary_key);
             }
         }
-        index.by_id.remove(&secondary_canonical_id);
+        take_entity(&mut index, secondary_canonical_id);
 
         index
             .merged_into
@@ -724,10 +1343,31 @@ impl PersistentEntityStore {
             .entry(primary_canonical)
             .or_default()
             .insert(secondary_canonical_id);
+        if let Some(provenance) = provenance {
+            index.merge_provenance.insert(secondary_canonical_id, provenance);
+        }
 
         Ok(merged)
     }
 
+    fn unmerge_internal(&self, primary: Entity, restored: Entity, emit_wal: bool) -> Result<(), StorageError> {
+        let mut index = self.index.write().map_err(|_| lock_err("entity.unmerge"))?;
+
+        validate_unmerge(&index, &primary, &restored)?;
+
+        if emit_wal {
+            self
+                .wal
+                .append(WalEntryKind::EntityUnmerge {
+                    primary: primary.clone(),
+                    restored: restored.clone(),
+                })
+                .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")))?;
+        }
+
+        apply_unmerge(&mut index, primary, restored)
+    }
+
     fn apply_wal(&self, kind: &WalEntryKind) -> Result<(), StorageError> {
         match kind {
             WalEntryKind::EntityInsert(entity) => self.insert_internal(entity.clone(), false),
@@ -737,7 +1377,19 @@ impl PersistentEntityStore {
                 merged,
                 secondary_id,
                 secondary_canonical,
-            } => self.merge_internal(merged.clone(), *secondary_id, secondary_canonical.clone(), false).map(|_| ()),
+                provenance,
+            } => self
+                .merge_internal(
+                    merged.clone(),
+                    *secondary_id,
+                    secondary_canonical.clone(),
+                    provenance.clone(),
+                    false,
+                )
+                .map(|_| ()),
+            WalEntryKind::EntityUnmerge { primary, restored } => {
+                self.unmerge_internal(primary.clone(), restored.clone(), false)
+            }
             _ => Ok(()),
         }
     }
@@ -748,11 +1400,124 @@ impl EntityStore for PersistentEntityStore {
         self.insert_internal(entity, true)
     }
     
+    fn insert_many(
+        &self,
+        entities: Vec<Entity>,
+        dedup_by_name: bool,
+    ) -> Result<HashMap<EntityId, EntityId>, StorageError> {
+        let mut index = self.index.write().map_err(|_| lock_err("entity.insert_many"))?;
+
+        let mut mapping = HashMap::with_capacity(entities.len());
+        // Entities to write, flagged with whether they already exist in the store.
+        let mut targets: Vec<(Entity, bool)> = Vec::new();
+        let mut by_key: HashMap<String, usize> = HashMap::new();
+
+        for entity in entities {
+            if index.by_id.contains_key(&entity.id)
+                || index.merged_into.contains_key(&entity.id)
+                || mapping.contains_key(&entity.id)
+            {
+                return Err(StorageError::DuplicateKey(entity.id.to_string()));
+            }
+
+            let key = entity_name_key(&entity);
+            if dedup_by_name && !entity.canonical_name.trim().is_empty() {
+                let slot = by_key.get(&key).copied().or_else(|| {
+                    let existing = index
+                        .by_name
+                        .get(&key)
+                        .and_then(|ids| ids.iter().min_by_key(|id| id.to_string()))
+                        .and_then(|id| index.by_id.get(id))?;
+                    targets.push((existing.clone(), true));
+                    by_key.insert(key.clone(), targets.len() - 1);
+                    Some(targets.len() - 1)
+                });
+                if let Some(i) = slot {
+                    absorb_entity(&mut targets[i].0, &entity)?;
+                    mapping.insert(entity.id, targets[i].0.id);
+                    continue;
+                }
+                by_key.insert(key, targets.len());
+            } else if self.unique_names {
+                ensure_name_unclaimed(&index.by_name, &entity)?;
+                if !key.is_empty() && by_key.insert(key, targets.len()).is_some() {
+                    return Err(StorageError::DuplicateKey(format!(
+                        "canonical name '{}'",
+                        entity.canonical_name.trim()
+                    )));
+                }
+            }
+
+            mapping.insert(entity.id, entity.id);
+            targets.push((entity, false));
+        }
+
+        let now = Utc::now();
+        let mut embedding_dim = index.embedding_dim;
+        for (entity, existing) in &mut targets {
+            if *existing {
+                entity.updated_at = now;
+                entity.version = entity
+                    .version
+                    .checked_add(1)
+                    .ok_or_else(|| StorageError::BackendError("entity version overflow".to_string()))?;
+            }
+            apply_embedding_dim(&mut embedding_dim, entity.embedding.as_ref(), "entity.insert_many")?;
+            if index
+                .versions
+                .get(&entity.id)
+                .is_some_and(|m| m.contains_key(&entity.version))
+            {
+                return Err(StorageError::BackendError(format!(
+                    "entity version already exists (entity.insert_many): id={} version={}",
+                    entity.id, entity.version
+                )));
+            }
+        }
+
+        // Each entry replays through the regular insert/update paths.
+        for (entity, existing) in &targets {
+            let kind = if *existing {
+                WalEntryKind::EntityUpdate(entity.clone())
+            } else {
+                WalEntryKind::EntityInsert(entity.clone())
+            };
+            self
+                .wal
+                .append(kind)
+                .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")))?;
+        }
+
+        index.embedding_dim = embedding_dim;
+        for (entity, existing) in targets {
+            record_entity_version(&mut index, &entity, "entity.insert_many")?;
+            if !existing {
+                index
+                    .by_name
+                    .entry(entity_name_key(&entity))
+                    .or_default()
+                    .insert(entity.id);
+            }
+            put_entity(&mut index, entity);
+        }
+
+        Ok(mapping)
+    }
+    
     fn get(&self, id: EntityId) -> Result<Option<Entity>, StorageError> {
         let index = self.index.read().map_err(|_| lock_err("entity.get"))?;
         let canonical = resolve_canonical_id(&index, id)?;
         Ok(index.by_id.get(&canonical).cloned())
     }
+
+    fn merged_ids(&self, id: EntityId) -> Result<Vec<EntityId>, StorageError> {
+        let index = self
+            .index
+            .read()
+            .map_err(|_| lock_err("entity.merged_ids"))?;
+        let canonical = resolve_canonical_id(&index, id)?;
+        Ok(merge_family(canonical, &index.merged_from))
+    }
     
     fn update(&self, entity: Entity) -> Result<(), StorageError> {
         self.update_internal(entity, true)
@@ -762,8 +1527,8 @@ impl EntityStore for PersistentEntityStore {
         self.delete_internal(id, true)
     }
     
-    fn find_by_name(&self, name: &str) -> Result<Vec<Entity>, StorageError> {
-        let name_key = normalize_key(name);
+    fn find_by_name(&self, namespace: Option<&str>, name: &str) -> Result<Vec<Entity>, StorageError> {
+        let name_key = name_index_key(namespace, name);
         let index = self.index.read().map_err(|_| lock_err("entity.find_by_name"))?;
         let Some(ids) = index.by_name.get(&name_key) else {
             return Ok(Vec::new());
@@ -776,8 +1541,22 @@ impl EntityStore for PersistentEntityStore {
         results.sort_by(|a, b| a.canonical_name.cmp(&b.canonical_name));
         Ok(results)
     }
+
+    fn find_by_type(&self, entity_type: &EntityType, include_subtypes: bool) -> Result<Vec<Entity>, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("entity.find_by_type"))?;
+        Ok(index
+            .by_type
+            .find(&index.by_id, &self.type_hierarchy, entity_type, include_subtypes))
+    }
+
+    fn list(&self) -> Result<Vec<Entity>, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("entity.list"))?;
+        let mut entities: Vec<Entity> = index.by_id.values().cloned().collect();
+        crate::storage::sort_by_name(&mut entities);
+        Ok(entities)
+    }
     
-    fn find_by_name_fuzzy(&self, query: &str, limit: usize) -> Result<Vec<Entity>, StorageError> {
+    fn find_by_name_fuzzy(&self, namespace: Option<&str>, query: &str, limit: usize) -> Result<Vec<Entity>, StorageError> {
         let query_key = normalize_key(query);
         if query_key.is_empty() || limit == 0 {
             return Ok(Vec::new());
@@ -790,23 +1569,10 @@ impl EntityStore for PersistentEntityStore {
 
         let mut scored: Vec<(u8, Entity)> = Vec::new();
         for entity in index.by_id.values() {
-            let canonical = normalize_key(&entity.canonical_name);
-            let mut score = 0u8;
-            if canonical.starts_with(&query_key) {
-                score = score.max(3);
-            } else if canonical.contains(&query_key) {
-                score = score.max(2);
+            if entity.namespace.as_deref() != namespace {
+                continue;
             }
-
-            for alias in &entity.aliases {
-                let alias_key = normalize_key(alias);
-                if alias_key.starts_with(&query_key) {
-                    score = score.max(2);
-                } else if alias_key.contains(&query_key) {
-                    score = score.max(1);
-                }
-            }
-
+            let score = crate::storage::fuzzy_name_score(&query_key, entity);
             if score > 0 {
                 scored.push((score, entity.clone()));
             }
@@ -825,7 +1591,7 @@ impl EntityStore for PersistentEntityStore {
             .collect())
     }
     
-    fn find_by_embedding(&self, embedding: &[f32], limit: usize) -> Result<Vec<(Entity, f32)>, StorageError> {
+    fn find_by_embedding(&self, namespace: Option<&str>, embedding: &[f32], limit: usize) -> Result<Vec<(Entity, f32)>, StorageError> {
         if embedding.is_empty() || limit == 0 {
             return Ok(Vec::new());
         }
@@ -846,6 +1612,9 @@ impl EntityStore for PersistentEntityStore {
 
         let mut scored: Vec<(Entity, f32)> = Vec::new();
         for entity in index.by_id.values() {
+            if entity.namespace.as_deref() != namespace {
+                continue;
+            }
             let Some(stored) = entity.embedding.as_ref() else {
                 continue;
             };
@@ -888,38 +1657,7 @@ impl EntityStore for PersistentEntityStore {
             .cloned()
             .ok_or(StorageError::EntityNotFound(secondary_canonical))?;
 
-        let secondary_names = std::iter::once(secondary_entity.canonical_name.clone())
-            .chain(secondary_entity.aliases.clone());
-        for name in secondary_names {
-            let name = name.trim();
-            if name.is_empty() {
-                continue;
-            }
-            if name.eq_ignore_ascii_case(&primary_entity.canonical_name) {
-                continue;
-            }
-            if primary_entity
-                .aliases
-                .iter()
-                .any(|a| a.eq_ignore_ascii_case(name))
-            {
-                continue;
-            }
-            primary_entity.aliases.push(name.to_string());
-        }
-
-        primary_entity.metadata =
-            merge_metadata(&primary_entity.metadata, &secondary_entity.metadata);
-
-        primary_entity.embedding = match (
-            primary_entity.embedding.as_ref(),
-            secondary_entity.embedding.as_ref(),
-        ) {
-            (Some(a), Some(b)) => Some(merge_embeddings(a, b)?),
-            (Some(a), None) => Some(a.clone()),
-            (None, Some(b)) => Some(b.clone()),
-            (None, None) => None,
-        };
+        absorb_entity(&mut primary_entity, &secondary_entity)?;
 
         let now = Utc::now();
         primary_entity.updated_at = now;
@@ -935,7 +1673,7 @@ impl EntityStore for PersistentEntityStore {
         if index
             .versions
             .get(&primary_entity.id)
-            .map_or(false, |m| m.contains_key(&primary_entity.version))
+            .is_some_and(|m| m.contains_key(&primary_entity.version))
         {
             return Err(StorageError::BackendError(format!(
                 "entity version already exists (entity.merge): id={} version={}",
@@ -943,18 +1681,28 @@ impl EntityStore for PersistentEntityStore {
             )));
         }
 
+        let provenance = MergeProvenance::capture(
+            index
+                .by_id
+                .get(&primary_canonical)
+                .ok_or(StorageError::EntityNotFound(primary_canonical))?,
+            &primary_entity,
+            &secondary_entity,
+        );
+
         self
             .wal
             .append(WalEntryKind::EntityMerge {
                 merged: primary_entity.clone(),
                 secondary_id: secondary_canonical,
                 secondary_canonical: secondary_entity.canonical_name.clone(),
+                provenance: Some(provenance.clone()),
             })
             .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")))?;
 
         apply_embedding_dim(&mut index.embedding_dim, primary_entity.embedding.as_ref(), "entity.merge")?;
 
-        let prev_key = normalize_key(&primary_entity.canonical_name);
+        let prev_key = entity_name_key(&primary_entity);
         index
             .by_name
             .entry(prev_key)
@@ -962,16 +1710,16 @@ impl EntityStore for PersistentEntityStore {
             .insert(primary_canonical);
 
         record_entity_version(&mut index, &primary_entity, "entity.merge")?;
-        index.by_id.insert(primary_canonical, primary_entity.clone());
+        put_entity(&mut index, primary_entity.clone());
 
-        let secondary_key = normalize_key(&secondary_entity.canonical_name);
+        let secondary_key = entity_name_key(&secondary_entity);
         if let Some(set) = index.by_name.get_mut(&secondary_key) {
             set.remove(&secondary_canonical);
             if set.is_empty() {
                 index.by_name.remove(&secondary_key);
             }
         }
-        index.by_id.remove(&secondary_canonical);
+        take_entity(&mut index, secondary_canonical);
 
         index
             .merged_into
@@ -981,9 +1729,63 @@ impl EntityStore for PersistentEntityStore {
             .entry(primary_canonical)
             .or_default()
             .insert(secondary_canonical);
+        index.merge_provenance.insert(secondary_canonical, provenance);
 
         Ok(primary_entity)
     }
+
+    fn unmerge(&self, primary: EntityId, secondary: EntityId) -> Result<(Entity, Entity), StorageError> {
+        let mut index = self.index.write().map_err(|_| lock_err("entity.unmerge"))?;
+
+        let provenance = index.merge_provenance.get(&secondary).cloned().ok_or_else(|| {
+            StorageError::BackendError(format!(
+                "cannot unmerge: no merge provenance recorded for {secondary}"
+            ))
+        })?;
+
+        let mut primary_entity = index
+            .by_id
+            .get(&primary)
+            .cloned()
+            .ok_or(StorageError::EntityNotFound(primary))?;
+        let versions = index
+            .versions
+            .get(&secondary)
+            .ok_or(StorageError::EntityNotFound(secondary))?;
+        let mut restored = versions
+            .get(&provenance.secondary_version)
+            .cloned()
+            .ok_or(StorageError::EntityNotFound(secondary))?;
+        let latest_secondary = versions.keys().next_back().copied().unwrap_or(restored.version);
+
+        let now = Utc::now();
+        provenance.revert(&mut primary_entity);
+        primary_entity.updated_at = now;
+        primary_entity.version = primary_entity
+            .version
+            .checked_add(1)
+            .ok_or_else(|| StorageError::BackendError("entity version overflow".to_string()))?;
+        restored.updated_at = now;
+        restored.version = latest_secondary
+            .checked_add(1)
+            .ok_or_else(|| StorageError::BackendError("entity version overflow".to_string()))?;
+
+        validate_unmerge(&index, &primary_entity, &restored)?;
+        if self.unique_names {
+            ensure_name_unclaimed(&index.by_name, &restored)?;
+        }
+
+        self
+            .wal
+            .append(WalEntryKind::EntityUnmerge {
+                primary: primary_entity.clone(),
+                restored: restored.clone(),
+            })
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {e}")))?;
+
+        apply_unmerge(&mut index, primary_entity.clone(), restored.clone())?;
+        Ok((primary_entity, restored))
+    }
     
     fn get_at_version(&self, id: EntityId, version: u64) -> Result<Option<Entity>, StorageError> {
         let index = self
@@ -1007,6 +1809,14 @@ impl EntityStore for PersistentEntityStore {
         };
         Ok(map.values().cloned().collect())
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("entity.stats"))?;
+        Ok(StorageStats {
+            records: index.by_id.len(),
+            ..StorageStats::default()
+        })
+    }
 }
 
 // --- Belief Store ---
@@ -1015,93 +1825,472 @@ impl EntityStore for PersistentEntityStore {
 struct BeliefIndex {
     by_id: HashMap<BeliefId, Belief>,
     by_entity: HashMap<EntityId, Vec<BeliefId>>,
+    /// Inverted attribute index keyed by predicate and `Value::stable_hash`.
+    by_predicate_value: HashMap<(String, u64), Vec<BeliefId>>,
+    /// Deleted beliefs, which segments written before the deletion may still hold.
+    deleted: HashSet<BeliefId>,
+    /// Inserted beliefs whose WAL entry is written but not yet durable. Reads do not see them;
+    /// compaction does, since their entries may fall within the WAL it truncates.
+    pending: HashMap<BeliefId, Belief>,
 }
 
 impl BeliefIndex {
-    fn from_map(map: HashMap<BeliefId, Belief>) -> Self {
-        let mut index = Self {
-            by_id: map,
-            by_entity: HashMap::new(),
-        };
-
-        for (id, belief) in index.by_id.iter() {
-            index
-                .by_entity
-                .entry(belief.subject)
-                .or_default()
-                .push(*id);
-        }
-
-        index
-    }
-
     fn insert(&mut self, belief: Belief) {
         let id = belief.id;
         let subject = belief.subject;
         self.by_entity.entry(subject).or_default().push(id);
+        self.by_predicate_value
+            .entry((belief.predicate.clone(), belief.value.stable_hash()))
+            .or_default()
+            .push(id);
         self.by_id.insert(id, belief);
     }
+
+    /// Link `old_ids` to their successor, as [`BeliefStore::supersede_all`] does.
+    ///
+    /// Replay also passes entries written before successors were linked back; their
+    /// predecessors are merged into `supersedes` rather than replacing it.
+    fn apply_supersede(&mut self, old_ids: &[BeliefId], new_id: BeliefId) {
+        let new_tx = self.by_id.get(&new_id).map(|b| b.tx_time);
+        for old_id in old_ids {
+            if let Some(old) = self.by_id.get_mut(old_id) {
+                match new_tx {
+                    Some(new_tx) => close_superseded(old, new_id, new_tx),
+                    None => old.superseded_by = Some(new_id),
+                }
+            }
+        }
+        if let Some(new) = self.by_id.get_mut(&new_id) {
+            for old_id in distinct_ids(old_ids) {
+                if !new.supersedes.contains(&old_id) {
+                    new.supersedes.push(old_id);
+                }
+            }
+        }
+    }
+
+    /// Replace the embeddings of the given beliefs.
+    fn apply_reembed(&mut self, embeddings: Vec<(BeliefId, Vec<f32>)>) {
+        for (id, embedding) in embeddings {
+            if let Some(belief) = self.by_id.get_mut(&id) {
+                belief.embedding = Some(embedding);
+            }
+        }
+    }
+
+    /// Overwrite the beliefs a coalesce rewrote and drop the ones it folded away.
+    ///
+    /// Coalescing never changes a belief's subject, predicate or value, so the secondary
+    /// indexes of `updated` stay valid.
+    fn apply_coalesce(&mut self, updated: Vec<Belief>, removed: &[BeliefId]) {
+        for belief in updated {
+            if let Some(slot) = self.by_id.get_mut(&belief.id) {
+                *slot = belief;
+            }
+        }
+        for id in removed {
+            self.remove(*id);
+        }
+    }
+
+    /// Insert a belief read from a segment unless a newer copy is already loaded or it was deleted.
+    fn insert_cold(&mut self, belief: Belief) {
+        if !self.by_id.contains_key(&belief.id) && !self.deleted.contains(&belief.id) {
+            self.insert(belief);
+        }
+    }
+
+    /// Drop a deleted belief, unlinking it from its successor's `supersedes`.
+    fn remove(&mut self, id: BeliefId) {
+        if let Some(belief) = self.by_id.remove(&id) {
+            if let Some(next) = belief.superseded_by.and_then(|next| self.by_id.get_mut(&next)) {
+                next.supersedes.retain(|prev| *prev != id);
+            }
+            if let Some(ids) = self.by_entity.get_mut(&belief.subject) {
+                ids.retain(|other| *other != id);
+                if ids.is_empty() {
+                    self.by_entity.remove(&belief.subject);
+                }
+            }
+            let key = (belief.predicate, belief.value.stable_hash());
+            if let Some(ids) = self.by_predicate_value.get_mut(&key) {
+                ids.retain(|other| *other != id);
+                if ids.is_empty() {
+                    self.by_predicate_value.remove(&key);
+                }
+            }
+        }
+        self.deleted.insert(id);
+    }
 }
 
+/// Segments whose beliefs have not all been loaded, newest first.
+#[derive(Default)]
+struct ColdBeliefs {
+    segments: Vec<Segment>,
+    /// Entities whose beliefs have all been loaded from `segments`.
+    loaded_entities: HashSet<EntityId>,
+}
+
+/// Belief store whose segment-backed beliefs are loaded lazily.
+///
+/// Beliefs written since open (or replayed from the WAL) are always in memory. Beliefs from
+/// segments are read on first lookup, newest segment first, skipping segments whose bloom
+/// filters rule the key out; the other beliefs of the section read are kept as well. Scans
+/// (time range, embedding, stats) load everything.
 pub struct PersistentBeliefStore {
     wal: Arc<WriteAheadLog>,
     index: RwLock<BeliefIndex>,
+    /// The idempotency store's keys, recorded with the beliefs that carry them.
+    keys: Arc<RwLock<IdempotencyIndex>>,
+    cold: RwLock<ColdBeliefs>,
+    /// Segment belief sections read so far.
+    segment_reads: AtomicU64,
 }
 
 impl PersistentBeliefStore {
-    fn new(wal: Arc<WriteAheadLog>) -> Self {
+    fn new(wal: Arc<WriteAheadLog>, keys: Arc<RwLock<IdempotencyIndex>>) -> Self {
         Self {
             wal,
             index: RwLock::new(BeliefIndex::default()),
+            keys,
+            cold: RwLock::new(ColdBeliefs::default()),
+            segment_reads: AtomicU64::new(0),
+        }
+    }
+
+    fn read_segment(&self, segment: &Segment) -> Result<HashMap<BeliefId, Belief>, StorageError> {
+        self.segment_reads.fetch_add(1, Ordering::Relaxed);
+        segment.read_beliefs().map_err(|e| segment_read_err(segment, &e))
+    }
+
+    /// Stream every live belief; see [`PersistentStores::iter_beliefs`].
+    fn stream(&self) -> Result<BeliefStream<'_>, StorageError> {
+        let cold = self.cold.read().map_err(|_| lock_err("belief.cold"))?;
+        let index = self.index.read().map_err(|_| lock_err("belief.stream"))?;
+        let loaded: Vec<BeliefId> = index.by_id.keys().copied().collect();
+        let mut seen: HashSet<BeliefId> = index.deleted.clone();
+        seen.extend(loaded.iter().copied());
+        Ok(BeliefStream {
+            store: self,
+            loaded: loaded.into_iter(),
+            seen,
+            segments: cold.segments.clone().into_iter(),
+            current: None,
+        })
+    }
+
+    /// Make sure belief `id` is in memory if any segment stores it.
+    fn fault_in_belief(&self, id: BeliefId) -> Result<(), StorageError> {
+        let cold = self.cold.read().map_err(|_| lock_err("belief.cold"))?;
+        if cold.segments.is_empty()
+            || self
+                .index
+                .read()
+                .map_err(|_| lock_err("belief.fault_in"))?
+                .by_id
+                .contains_key(&id)
+        {
+            return Ok(());
+        }
+
+        for (i, segment) in cold.segments.iter().enumerate() {
+            if !segment.may_contain_belief(id) {
+                continue;
+            }
+            let mut beliefs = self.read_segment(segment)?;
+            let Some(belief) = beliefs.remove(&id) else {
+                continue;
+            };
+            // Keep the rest of the read too, except beliefs a newer segment may hold a later
+            // copy of.
+            let newer = &cold.segments[..i];
+            let mut index = self.index.write().map_err(|_| lock_err("belief.fault_in"))?;
+            index.insert_cold(belief);
+            for belief in beliefs.into_values() {
+                if !newer.iter().any(|s| s.may_contain_belief(belief.id)) {
+                    index.insert_cold(belief);
+                }
+            }
+            break;
+        }
+        Ok(())
+    }
+
+    /// Make sure the belief superseding `id`, if any, is in memory.
+    fn fault_in_successor(&self, id: BeliefId) -> Result<(), StorageError> {
+        let next = self
+            .index
+            .read()
+            .map_err(|_| lock_err("belief.fault_in"))?
+            .by_id
+            .get(&id)
+            .and_then(|b| b.superseded_by);
+        match next {
+            Some(next) => self.fault_in_belief(next),
+            None => Ok(()),
+        }
+    }
+
+    /// Make sure every belief about `entity_id` is in memory.
+    fn fault_in_entity(&self, entity_id: EntityId) -> Result<(), StorageError> {
+        {
+            let cold = self.cold.read().map_err(|_| lock_err("belief.cold"))?;
+            if cold.segments.is_empty() || cold.loaded_entities.contains(&entity_id) {
+                return Ok(());
+            }
+        }
+
+        let mut cold = self.cold.write().map_err(|_| lock_err("belief.cold"))?;
+        if cold.loaded_entities.contains(&entity_id) {
+            return Ok(());
+        }
+        for segment in cold.segments.iter().filter(|s| s.may_contain_beliefs_of(entity_id)) {
+            let beliefs = self.read_segment(segment)?;
+            let mut index = self.index.write().map_err(|_| lock_err("belief.fault_in"))?;
+            for belief in beliefs.into_values().filter(|b| b.subject == entity_id) {
+                index.insert_cold(belief);
+            }
+        }
+        cold.loaded_entities.insert(entity_id);
+        Ok(())
+    }
+
+    /// Load every segment belief and stop consulting segments.
+    fn fault_in_all(&self) -> Result<(), StorageError> {
+        let mut cold = self.cold.write().map_err(|_| lock_err("belief.cold"))?;
+        for segment in &cold.segments {
+            let beliefs = self.read_segment(segment)?;
+            let mut index = self.index.write().map_err(|_| lock_err("belief.fault_in"))?;
+            for belief in beliefs.into_values() {
+                index.insert_cold(belief);
+            }
+        }
+        cold.segments.clear();
+        cold.loaded_entities.clear();
+        Ok(())
+    }
+}
+
+fn segment_read_err(segment: &Segment, e: &std::io::Error) -> StorageError {
+    StorageError::BackendError(format!(
+        "failed to read beliefs from segment {}: {e}",
+        segment.path().display()
+    ))
+}
+
+/// Iterator behind [`PersistentStores::iter_beliefs`].
+struct BeliefStream<'a> {
+    store: &'a PersistentBeliefStore,
+    /// Beliefs already in memory when the stream started, looked up one at a time.
+    loaded: std::vec::IntoIter<BeliefId>,
+    /// Ids yielded or deleted so far; older segment copies of them are skipped.
+    seen: HashSet<BeliefId>,
+    /// Segments not yet fully loaded, newest first.
+    segments: std::vec::IntoIter<Segment>,
+    current: Option<(Segment, SegmentBeliefs)>,
+}
+
+impl Iterator for BeliefStream<'_> {
+    type Item = Result<Belief, StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for id in self.loaded.by_ref() {
+            let index = match self.store.index.read() {
+                Ok(index) => index,
+                Err(_) => return Some(Err(lock_err("belief.stream"))),
+            };
+            // Deleted since the stream started.
+            if let Some(belief) = index.by_id.get(&id) {
+                return Some(Ok(belief.clone()));
+            }
+        }
+
+        loop {
+            if let Some((segment, beliefs)) = self.current.as_mut() {
+                match beliefs.next() {
+                    Some(Ok(belief)) if self.seen.insert(belief.id) => return Some(Ok(belief)),
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => {
+                        let err = segment_read_err(segment, &e);
+                        self.current = None;
+                        return Some(Err(err));
+                    }
+                    None => self.current = None,
+                }
+            }
+            let segment = self.segments.next()?;
+            self.store.segment_reads.fetch_add(1, Ordering::Relaxed);
+            match segment.beliefs() {
+                Ok(beliefs) => self.current = Some((segment, beliefs)),
+                Err(e) => return Some(Err(segment_read_err(&segment, &e))),
+            }
         }
     }
 }
 
 impl BeliefStore for PersistentBeliefStore {
     fn insert(&self, belief: Belief) -> Result<(), StorageError> {
+        self.fault_in_belief(belief.id)?;
         let mut index = self
             .index
             .write()
             .map_err(|_| lock_err("belief.insert"))?;
 
-        if index.by_id.contains_key(&belief.id) {
+        if index.by_id.contains_key(&belief.id) || index.pending.contains_key(&belief.id) {
             return Err(StorageError::DuplicateKey(format!("belief:{}", belief.id)));
         }
+        if let Some(emb) = belief.embedding.as_ref() {
+            ensure_finite_embedding(emb, "belief.insert")?;
+        }
 
-        self
+        let pending = self
             .wal
-            .append(WalEntryKind::BeliefInsert(belief.clone()))
+            .append_pending(WalEntryKind::BeliefInsert(belief.clone()))
             .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
 
-        index.insert(belief);
+        let id = belief.id;
+        index.pending.insert(id, belief);
+        drop(index);
+
+        // Wait for durability without blocking other writers, so group commit can batch them,
+        // and only then let readers see the belief.
+        let durable = pending.wait();
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("belief.insert"))?;
+        let belief = index.pending.remove(&id);
+        durable.map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+        if let Some(belief) = belief {
+            record_idempotency_key(&self.keys, belief.namespace.as_deref(), belief.idempotency_key.as_deref(), id)?;
+            index.insert(belief);
+        }
         Ok(())
     }
     
     fn get(&self, id: BeliefId) -> Result<Option<Belief>, StorageError> {
+        self.fault_in_belief(id)?;
         let index = self.index.read().map_err(|_| lock_err("belief.get"))?;
         Ok(index.by_id.get(&id).cloned())
     }
     
-    fn supersede(&self, old_id: BeliefId, new_id: BeliefId) -> Result<(), StorageError> {
+    fn supersede_all(&self, old_ids: &[BeliefId], new_id: BeliefId) -> Result<(), StorageError> {
+        for &id in old_ids.iter().chain([&new_id]) {
+            self.fault_in_belief(id)?;
+        }
         let mut index = self
             .index
             .write()
             .map_err(|_| lock_err("belief.supersede"))?;
-        
-        if !index.by_id.contains_key(&old_id) {
-            return Err(StorageError::BeliefNotFound(old_id));
+        if !check_supersede(old_ids, new_id, |id| index.by_id.get(&id))? {
+            return Ok(());
         }
-        
-        self.wal.append(WalEntryKind::BeliefSupersede { old_id, new_id })
+
+        self.wal
+            .append(WalEntryKind::BeliefSupersede {
+                old_ids: old_ids.to_vec(),
+                new_id,
+            })
             .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
-        
-        if let Some(belief) = index.by_id.get_mut(&old_id) {
-            belief.superseded_by = Some(new_id);
+
+        index.apply_supersede(old_ids, new_id);
+        Ok(())
+    }
+
+    fn insert_superseding(&self, mut belief: Belief, old_ids: &[BeliefId]) -> Result<(), StorageError> {
+        for &id in old_ids.iter().chain([&belief.id]) {
+            self.fault_in_belief(id)?;
+        }
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("belief.insert_superseding"))?;
+
+        if index.by_id.contains_key(&belief.id) || index.pending.contains_key(&belief.id) {
+            return Err(StorageError::DuplicateKey(format!("belief:{}", belief.id)));
+        }
+        if let Some(emb) = belief.embedding.as_ref() {
+            ensure_finite_embedding(emb, "belief.insert_superseding")?;
+        }
+        check_supersedable(old_ids, belief.id, |id| index.by_id.get(&id))?;
+
+        // One entry carries both the insert and the links, so replay never sees one without
+        // the other. It is written under the lock, unlike a plain insert, so no other writer
+        // can supersede the same beliefs before it lands.
+        belief.supersedes = distinct_ids(old_ids);
+        self.wal
+            .append(WalEntryKind::BeliefInsertSuperseding(belief.clone()))
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        let new_id = belief.id;
+        record_idempotency_key(&self.keys, belief.namespace.as_deref(), belief.idempotency_key.as_deref(), new_id)?;
+        index.insert(belief);
+        index.apply_supersede(old_ids, new_id);
+        Ok(())
+    }
+
+    fn amend(&self, id: BeliefId, fields: AmendFields) -> Result<(), StorageError> {
+        fields.validate()?;
+        self.fault_in_belief(id)?;
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("belief.amend"))?;
+        let Some(belief) = index.by_id.get_mut(&id) else {
+            return Err(StorageError::BeliefNotFound(id));
+        };
+
+        self.wal
+            .append(WalEntryKind::BeliefAmend { id, fields: fields.clone() })
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        fields.apply(belief);
+        let key = fields.corroboration.as_ref().and_then(|c| c.idempotency_key.as_deref());
+        record_idempotency_key(&self.keys, belief.namespace.as_deref(), key, id)
+    }
+
+    fn find_superseded(&self, before: DateTime<Utc>) -> Result<Vec<Belief>, StorageError> {
+        self.fault_in_all()?;
+        let index = self
+            .index
+            .read()
+            .map_err(|_| lock_err("belief.find_superseded"))?;
+        let mut beliefs: Vec<Belief> = index
+            .by_id
+            .values()
+            .filter(|b| {
+                b.superseded_by
+                    .and_then(|next| index.by_id.get(&next))
+                    .is_some_and(|next| next.tx_time < before)
+            })
+            .cloned()
+            .collect();
+        beliefs.sort_by_key(|b| b.tx_time);
+        Ok(beliefs)
+    }
+
+    fn delete(&self, id: BeliefId) -> Result<(), StorageError> {
+        self.fault_in_belief(id)?;
+        self.fault_in_successor(id)?;
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("belief.delete"))?;
+        if !index.by_id.contains_key(&id) {
+            return Err(StorageError::BeliefNotFound(id));
         }
+
+        self.wal
+            .append(WalEntryKind::BeliefDelete { id })
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        index.remove(id);
         Ok(())
     }
 
     fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Belief>, StorageError> {
+        self.fault_in_entity(entity_id)?;
         let index = self
             .index
             .read()
@@ -1114,11 +2303,12 @@ impl BeliefStore for PersistentBeliefStore {
             .iter()
             .filter_map(|id| index.by_id.get(id).cloned())
             .collect();
-        beliefs.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
         Ok(beliefs)
     }
     
     fn find_by_entity_predicate(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError> {
+        self.fault_in_entity(entity_id)?;
         let index = self
             .index
             .read()
@@ -1133,11 +2323,87 @@ impl BeliefStore for PersistentBeliefStore {
             .filter(|b| b.predicate == predicate)
             .cloned()
             .collect();
-        beliefs.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
+        Ok(beliefs)
+    }
+
+    fn history(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError> {
+        let mut beliefs = self.find_by_entity_predicate(entity_id, predicate)?;
+        crate::storage::sort_history(&mut beliefs);
         Ok(beliefs)
     }
+
+    fn coalesce(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+        pinned: &HashSet<BeliefId>,
+    ) -> Result<usize, StorageError> {
+        self.fault_in_entity(entity_id)?;
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("belief.coalesce"))?;
+        let predicate = predicate.trim();
+        let mut history: Vec<Belief> = index
+            .by_entity
+            .get(&entity_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| index.by_id.get(id))
+            .filter(|b| b.predicate == predicate)
+            .cloned()
+            .collect();
+        crate::storage::sort_history(&mut history);
+
+        // This store's `find_as_of` filters on valid time alone.
+        let plan = crate::storage::plan_coalesce(&history, pinned, |b| b.valid_time.from());
+        if plan.removed.is_empty() {
+            return Ok(0);
+        }
+
+        self.wal
+            .append(WalEntryKind::BeliefCoalesce {
+                updated: plan.updated.clone(),
+                removed: plan.removed.clone(),
+            })
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        index.apply_coalesce(plan.updated, &plan.removed);
+        Ok(plan.removed.len())
+    }
+
+    fn reembed(&self, embed: &dyn Fn(&Belief) -> Result<Vec<f32>, StorageError>) -> Result<usize, StorageError> {
+        self.fault_in_all()?;
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("belief.reembed"))?;
+        let mut embedding_dim = None;
+        let mut embeddings = Vec::new();
+        for belief in index.by_id.values().filter(|b| b.embedding.is_some()) {
+            let embedding = embed(belief)?;
+            apply_embedding_dim(&mut embedding_dim, Some(&embedding), "belief.reembed")?;
+            embeddings.push((belief.id, embedding));
+        }
+        if embeddings.is_empty() {
+            return Ok(0);
+        }
+
+        // One entry, so a crash never leaves a mix of old and new embeddings.
+        self.wal
+            .append(WalEntryKind::BeliefReembed {
+                embeddings: embeddings.clone(),
+            })
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        let count = embeddings.len();
+        index.apply_reembed(embeddings);
+        Ok(count)
+    }
     
     fn find_as_of(&self, entity_id: EntityId, predicate: &str, as_of: DateTime<Utc>) -> Result<Vec<Belief>, StorageError> {
+        self.fault_in_entity(entity_id)?;
         let index = self
             .index
             .read()
@@ -1152,11 +2418,12 @@ impl BeliefStore for PersistentBeliefStore {
             .filter(|b| b.predicate == predicate && b.valid_time.contains(as_of))
             .cloned()
             .collect();
-        beliefs.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
         Ok(beliefs)
     }
     
-    fn find_by_time_range(&self, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
+    fn find_by_time_range(&self, namespace: Option<&str>, range: &TimeRange) -> Result<Vec<Belief>, StorageError> {
+        self.fault_in_all()?;
         let index = self
             .index
             .read()
@@ -1165,18 +2432,19 @@ impl BeliefStore for PersistentBeliefStore {
         let mut beliefs: Vec<Belief> = index
             .by_id
             .values()
-            .filter(|b| b.valid_time.overlaps(range))
+            .filter(|b| b.namespace.as_deref() == namespace && b.valid_time.overlaps(range))
             .cloned()
             .collect();
-        beliefs.sort_by(|a, b| b.tx_time.cmp(&a.tx_time));
+        beliefs.sort_by_key(|b| std::cmp::Reverse(b.tx_time));
         Ok(beliefs)
     }
     
-    fn find_by_embedding(&self, embedding: &[f32], limit: usize, min_confidence: Option<f32>) -> Result<Vec<(Belief, f32)>, StorageError> {
+    fn find_by_embedding(&self, namespace: Option<&str>, embedding: &[f32], limit: usize, min_confidence: Option<f32>) -> Result<Vec<(Belief, f32)>, StorageError> {
         if embedding.is_empty() || limit == 0 {
             return Ok(Vec::new());
         }
 
+        self.fault_in_all()?;
         let index = self
             .index
             .read()
@@ -1185,7 +2453,7 @@ impl BeliefStore for PersistentBeliefStore {
 
         let mut scored: Vec<(Belief, f32)> = Vec::new();
         for belief in index.by_id.values() {
-            if belief.confidence.value() < min_conf {
+            if belief.namespace.as_deref() != namespace || belief.confidence.value() < min_conf {
                 continue;
             }
             let Some(stored) = belief.embedding.as_ref() else {
@@ -1202,15 +2470,71 @@ impl BeliefStore for PersistentBeliefStore {
         Ok(scored)
     }
     
-    fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError> {
+    fn find_entities_by_predicate_value(
+        &self,
+        namespace: Option<&str>,
+        predicate: &str,
+        value: &Value,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<EntityId>, StorageError> {
+        self.fault_in_all()?;
         let index = self
             .index
             .read()
-            .map_err(|_| lock_err("belief.count_by_entity"))?;
-        Ok(index.by_entity.get(&entity_id).map_or(0, Vec::len))
-    }
-}
-
+            .map_err(|_| lock_err("belief.find_entities_by_predicate_value"))?;
+        let key = (predicate.trim().to_string(), value.stable_hash());
+        let Some(ids) = index.by_predicate_value.get(&key) else {
+            return Ok(Vec::new());
+        };
+
+        let encoding = value.stable_encoding();
+        let mut entities: Vec<EntityId> = ids
+            .iter()
+            .filter_map(|id| index.by_id.get(id))
+            .filter(|b| {
+                b.namespace.as_deref() == namespace
+                    && b.is_valid_at(as_of)
+                    && b.value.stable_encoding() == encoding
+            })
+            .map(|b| b.subject)
+            .collect();
+        entities.sort_by_key(|id| *id.as_uuid());
+        entities.dedup();
+        Ok(entities)
+    }
+
+    fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError> {
+        self.fault_in_entity(entity_id)?;
+        let index = self
+            .index
+            .read()
+            .map_err(|_| lock_err("belief.count_by_entity"))?;
+        Ok(index.by_entity.get(&entity_id).map_or(0, Vec::len))
+    }
+
+    fn subjects(&self) -> Result<Vec<EntityId>, StorageError> {
+        self.fault_in_all()?;
+        let index = self.index.read().map_err(|_| lock_err("belief.subjects"))?;
+        Ok(index
+            .by_entity
+            .iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(id, _)| *id)
+            .collect())
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        self.fault_in_all()?;
+        let index = self.index.read().map_err(|_| lock_err("belief.stats"))?;
+        let predicates: HashSet<&str> = index.by_id.values().map(|b| b.predicate.as_str()).collect();
+        Ok(StorageStats {
+            records: index.by_id.len(),
+            distinct_entities: Some(index.by_entity.len()),
+            distinct_predicates: Some(predicates.len()),
+        })
+    }
+}
+
 // --- Pattern Store ---
 
 pub struct PersistentPatternStore {
@@ -1283,27 +2607,84 @@ impl PatternStore for PersistentPatternStore {
     }
     
     fn find_active(&self) -> Result<Vec<Pattern>, StorageError> {
-        let now = Utc::now();
-        let index = self.index.read().unwrap();
-        Ok(index.values()
-            .filter(|p| p.valid_time.contains(now))
-            .cloned()
-            .collect())
+        let index = self
+            .index
+            .read()
+            .map_err(|_| lock_err("pattern.find_active"))?;
+        Ok(index.values().filter(|p| p.is_active()).cloned().collect())
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("pattern.stats"))?;
+        Ok(StorageStats {
+            records: index.len(),
+            ..StorageStats::default()
+        })
     }
 }
 
 // --- Conflict Store ---
 
+#[derive(Debug, Default, Clone)]
+struct ConflictIndex {
+    by_id: HashMap<ConflictId, Conflict>,
+    by_entity: HashMap<EntityId, Vec<ConflictId>>,
+}
+
+impl ConflictIndex {
+    fn from_map(map: HashMap<ConflictId, Conflict>) -> Self {
+        let mut index = Self {
+            by_id: map,
+            by_entity: HashMap::new(),
+        };
+
+        for (id, conflict) in index.by_id.iter() {
+            index
+                .by_entity
+                .entry(conflict.entity_id)
+                .or_default()
+                .push(*id);
+        }
+
+        index
+    }
+
+    /// Insert or replace a conflict, keeping `by_entity` in sync.
+    fn upsert(&mut self, conflict: Conflict) {
+        let id = conflict.id;
+        let entity_id = conflict.entity_id;
+
+        if let Some(old) = self.by_id.get(&id) {
+            if old.entity_id == entity_id {
+                self.by_id.insert(id, conflict);
+                return;
+            }
+            // Conflicts should never move between entities, but keep the
+            // index honest if an update does it anyway.
+            let old_entity = old.entity_id;
+            if let Some(list) = self.by_entity.get_mut(&old_entity) {
+                list.retain(|cid| *cid != id);
+                if list.is_empty() {
+                    self.by_entity.remove(&old_entity);
+                }
+            }
+        }
+
+        self.by_entity.entry(entity_id).or_default().push(id);
+        self.by_id.insert(id, conflict);
+    }
+}
+
 pub struct PersistentConflictStore {
     wal: Arc<WriteAheadLog>,
-    index: RwLock<HashMap<ConflictId, Conflict>>,
+    index: RwLock<ConflictIndex>,
 }
 
 impl PersistentConflictStore {
     fn new(wal: Arc<WriteAheadLog>) -> Self {
         Self {
             wal,
-            index: RwLock::new(HashMap::new()),
+            index: RwLock::new(ConflictIndex::default()),
         }
     }
 }
@@ -1312,38 +2693,57 @@ impl ConflictStore for PersistentConflictStore {
     fn insert(&self, conflict: Conflict) -> Result<(), StorageError> {
         let mut index = self.index.write().unwrap();
         
-        if index.contains_key(&conflict.id) {
+        if index.by_id.contains_key(&conflict.id) {
             return Err(StorageError::DuplicateKey(format!("conflict:{}", conflict.id)));
         }
         
         self.wal.append(WalEntryKind::ConflictInsert(conflict.clone()))
             .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
         
-        index.insert(conflict.id, conflict);
+        index.upsert(conflict);
         Ok(())
     }
     
     fn get(&self, id: ConflictId) -> Result<Option<Conflict>, StorageError> {
-        Ok(self.index.read().unwrap().get(&id).cloned())
+        Ok(self.index.read().unwrap().by_id.get(&id).cloned())
     }
     
     fn update(&self, conflict: Conflict) -> Result<(), StorageError> {
         let mut index = self.index.write().unwrap();
         
-        if !index.contains_key(&conflict.id) {
+        if !index.by_id.contains_key(&conflict.id) {
             return Err(StorageError::ConflictNotFound(conflict.id));
         }
         
         self.wal.append(WalEntryKind::ConflictUpdate(conflict.clone()))
             .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
         
-        index.insert(conflict.id, conflict);
+        index.upsert(conflict);
         Ok(())
     }
+
+    fn update_if_open(&self, conflict: Conflict) -> Result<bool, StorageError> {
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("conflict.update_if_open"))?;
+        match index.by_id.get(&conflict.id) {
+            None => return Err(StorageError::ConflictNotFound(conflict.id)),
+            Some(current) if !current.is_open() => return Ok(false),
+            Some(_) => {}
+        }
+
+        self.wal
+            .append(WalEntryKind::ConflictUpdate(conflict.clone()))
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        index.upsert(conflict);
+        Ok(true)
+    }
     
     fn find_by_belief(&self, belief_id: BeliefId) -> Result<Vec<Conflict>, StorageError> {
         let index = self.index.read().unwrap();
-        Ok(index.values()
+        Ok(index.by_id.values()
             .filter(|c| c.involves_belief(belief_id))
             .cloned()
             .collect())
@@ -1351,11 +2751,32 @@ impl ConflictStore for PersistentConflictStore {
     
     fn find_open(&self) -> Result<Vec<Conflict>, StorageError> {
         let index = self.index.read().unwrap();
-        Ok(index.values()
+        let mut open: Vec<Conflict> = index.by_id.values()
             .filter(|c| c.status == ConflictStatus::Open)
             .cloned()
+            .collect();
+        Conflict::sort_by_severity(&mut open);
+        Ok(open)
+    }
+
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Conflict>, StorageError> {
+        let index = self.index.read().unwrap();
+        let Some(ids) = index.by_entity.get(&entity_id) else {
+            return Ok(Vec::new());
+        };
+        Ok(ids
+            .iter()
+            .filter_map(|id| index.by_id.get(id).cloned())
             .collect())
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("conflict.stats"))?;
+        Ok(StorageStats {
+            records: index.by_id.len(),
+            ..StorageStats::default()
+        })
+    }
 }
 
 // --- Derivation Store ---
@@ -1392,6 +2813,20 @@ impl DerivationStore for PersistentDerivationStore {
     fn get(&self, id: DerivationId) -> Result<Option<DerivationRecord>, StorageError> {
         Ok(self.index.read().unwrap().get(&id).cloned())
     }
+
+    fn update(&self, record: DerivationRecord) -> Result<(), StorageError> {
+        let mut index = self.index.write().unwrap();
+
+        if !index.contains_key(&record.id) {
+            return Err(StorageError::DerivationNotFound(record.id));
+        }
+
+        self.wal.append(WalEntryKind::DerivationUpdate(record.clone()))
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        index.insert(record.id, record);
+        Ok(())
+    }
     
     fn find_by_premise(&self, premise_id: BeliefId) -> Result<Vec<DerivationRecord>, StorageError> {
         let index = self.index.read().unwrap();
@@ -1408,6 +2843,14 @@ impl DerivationStore for PersistentDerivationStore {
             .cloned()
             .collect())
     }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("derivation.stats"))?;
+        Ok(StorageStats {
+            records: index.len(),
+            ..StorageStats::default()
+        })
+    }
 }
 
 // --- Utility Functions ---
@@ -1440,10 +2883,81 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> Result<f32, StorageError> {
     }
 }
 
+// --- Idempotency Store ---
+
+pub struct PersistentIdempotencyStore {
+    wal: Arc<WriteAheadLog>,
+    /// Shared with the belief store, which records the keys its beliefs carry.
+    index: Arc<RwLock<IdempotencyIndex>>,
+}
+
+impl PersistentIdempotencyStore {
+    fn new(wal: Arc<WriteAheadLog>) -> Self {
+        Self {
+            wal,
+            index: Arc::new(RwLock::new(IdempotencyIndex::default())),
+        }
+    }
+}
+
+impl IdempotencyStore for PersistentIdempotencyStore {
+    fn get(&self, namespace: Option<&str>, key: &str) -> Result<Option<BeliefId>, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("idempotency.get"))?;
+        Ok(index.get(namespace, key))
+    }
+
+    fn reserve(&self, namespace: Option<&str>, key: &str) -> Result<IdempotencyReservation, StorageError> {
+        self.index
+            .write()
+            .map_err(|_| lock_err("idempotency.reserve"))?
+            .reserve(namespace, key)
+    }
+
+    fn release(&self, namespace: Option<&str>, key: &str) -> Result<(), StorageError> {
+        self.index
+            .write()
+            .map_err(|_| lock_err("idempotency.release"))?
+            .release(namespace, key);
+        Ok(())
+    }
+
+    fn record(&self, namespace: Option<&str>, key: &str, belief_id: BeliefId) -> Result<(), StorageError> {
+        let mut index = self
+            .index
+            .write()
+            .map_err(|_| lock_err("idempotency.record"))?;
+
+        // Usually already there: the belief store records the key its belief carries.
+        if index.get(namespace, key).is_some() {
+            return Ok(());
+        }
+
+        self.wal
+            .append(WalEntryKind::IdempotencyRecord {
+                namespace: namespace.map(str::to_string),
+                key: key.to_string(),
+                belief_id,
+            })
+            .map_err(|e| StorageError::BackendError(format!("WAL write failed: {}", e)))?;
+
+        index.insert(namespace, key, belief_id);
+        Ok(())
+    }
+
+    fn stats(&self) -> Result<StorageStats, StorageError> {
+        let index = self.index.read().map_err(|_| lock_err("idempotency.stats"))?;
+        Ok(StorageStats {
+            records: index.len(),
+            ..StorageStats::default()
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::entity::EntityType;
+    use crate::pattern::PatternRule;
     use tempfile::tempdir;
     
     #[test]
@@ -1459,16 +2973,78 @@ mod tests {
         // Reopen and verify persistence
         {
             let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
-            let entities: Vec<_> = stores.entities.find_by_name("test").unwrap();
+            let entities: Vec<_> = stores.entities.find_by_name(None, "test").unwrap();
             assert_eq!(entities.len(), 1);
         }
     }
     
+    #[test]
+    fn test_type_index_survives_replay_and_compaction() {
+        use crate::entity::EntityTypeHierarchy;
+
+        let dir = tempdir().unwrap();
+        let company = EntityType::Custom("company".to_string());
+        let config = PersistentConfig {
+            type_hierarchy: EntityTypeHierarchy::new()
+                .with_subtype(EntityType::Organization, company.clone())
+                .unwrap(),
+            ..PersistentConfig::default()
+        };
+        let org = Entity::new("United Nations", EntityType::Organization);
+        let acme = Entity::new("Acme", company);
+        let compacted = Entity::new("Initech", EntityType::Organization);
+
+        {
+            let stores = PersistentStores::open(dir.path(), config.clone()).unwrap();
+            stores.entities.insert(compacted.clone()).unwrap();
+            stores.compact().unwrap();
+            stores.entities.insert(org.clone()).unwrap();
+            stores.entities.insert(acme.clone()).unwrap();
+            stores.entities.insert(Entity::new("Alice", EntityType::Person)).unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), config).unwrap();
+        let ids = |found: Vec<Entity>| found.into_iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(stores.entities.find_by_type(&EntityType::Organization, true).unwrap()),
+            vec![acme.id, compacted.id, org.id]
+        );
+        assert_eq!(
+            ids(stores.entities.find_by_type(&EntityType::Organization, false).unwrap()),
+            vec![compacted.id, org.id]
+        );
+    }
+
+    #[test]
+    fn test_unique_entity_names_config() {
+        let dir = tempdir().unwrap();
+        let config = PersistentConfig {
+            unique_entity_names: true,
+            ..PersistentConfig::default()
+        };
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.entities.insert(Entity::new("twin", EntityType::Concept)).unwrap();
+            stores.entities.insert(Entity::new("twin", EntityType::Concept)).unwrap();
+        }
+
+        // Existing collisions still replay; new ones are rejected.
+        let stores = PersistentStores::open(dir.path(), config).unwrap();
+        assert_eq!(stores.entities.find_by_name(None, "twin").unwrap().len(), 2);
+        stores.entities.insert(Entity::new("solo", EntityType::Concept)).unwrap();
+        assert!(matches!(
+            stores.entities.insert(Entity::new("Solo", EntityType::Concept)),
+            Err(StorageError::DuplicateKey(_))
+        ));
+        assert_eq!(stores.entities.find_by_name(None, "solo").unwrap().len(), 1);
+    }
+
     #[test]
     fn test_compaction_creates_segment() {
         let dir = tempdir().unwrap();
         
-        let mut stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
         
         // Insert some data
         for i in 0..10 {
@@ -1493,7 +3069,7 @@ mod tests {
     fn test_compaction_empty_wal() {
         let dir = tempdir().unwrap();
         
-        let mut stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
         
         // Compact empty database
         let result = stores.compact().unwrap();
@@ -1509,7 +3085,7 @@ mod tests {
         
         // Write, compact, close
         {
-            let mut stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
             let entity = Entity::new("survive_compaction", EntityType::Concept);
             entity_id = entity.id;
             stores.entities.insert(entity).unwrap();
@@ -1525,6 +3101,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_only_handle_reads_alongside_writer() {
+        let dir = tempdir().unwrap();
+        let writer = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let entity = Entity::new("shared", EntityType::Concept);
+        let entity_id = entity.id;
+        writer.entities.insert(entity).unwrap();
+        writer.compact().unwrap();
+        writer
+            .entities
+            .insert(Entity::new("after_compaction", EntityType::Concept))
+            .unwrap();
+
+        let reader =
+            PersistentStores::open_read_only(dir.path(), PersistentConfig::default()).unwrap();
+        assert!(reader.is_read_only());
+        assert!(reader.entities.get(entity_id).unwrap().is_some());
+        assert_eq!(reader.entities.find_by_name(None, "after_compaction").unwrap().len(), 1);
+
+        let err = reader
+            .entities
+            .insert(Entity::new("rejected", EntityType::Concept))
+            .unwrap_err();
+        assert!(err.to_string().contains("read-only"), "{err}");
+        assert!(reader.compact().is_err());
+
+        // The writer keeps working, but may not truncate the WAL under a reader.
+        writer
+            .entities
+            .insert(Entity::new("still_writable", EntityType::Concept))
+            .unwrap();
+        assert!(writer.compact().is_err());
+        drop(reader);
+        writer.compact().unwrap();
+    }
+
+    #[test]
+    fn test_read_only_open_requires_existing_database() {
+        let dir = tempdir().unwrap();
+        assert!(PersistentStores::open_read_only(&dir.path().join("missing"), PersistentConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_find_active_patterns_respects_flag_and_valid_time() {
+        let dir = tempdir().unwrap();
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let conf = || crate::Confidence::from_agent(0.8, "agent").unwrap();
+
+        let current = Pattern::new("current", PatternRule::unique("status"), conf());
+        let future = Pattern::new("future", PatternRule::unique("status"), conf())
+            .with_valid_time(TimeRange::starting_at(Utc::now() + chrono::Duration::hours(1)));
+        let mut disabled = Pattern::new("disabled", PatternRule::unique("status"), conf());
+        disabled.deactivate();
+        let current_id = current.id;
+        for p in [current, future, disabled] {
+            stores.patterns.insert(p).unwrap();
+        }
+
+        let active = stores.patterns.find_active().unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, current_id);
+    }
+
     #[test]
     fn test_entity_merge_records_versions_and_redirects() {
         let dir = tempdir().unwrap();
@@ -1557,13 +3196,121 @@ mod tests {
         assert_eq!(secondary_versions.len(), 1);
     }
 
+    #[test]
+    fn test_entity_unmerge_survives_replay_and_compaction() {
+        let dir = tempdir().unwrap();
+        let mut primary = Entity::new("primary", EntityType::Concept);
+        primary.add_alias("p");
+        let mut secondary = Entity::new("secondary", EntityType::Concept);
+        secondary.add_alias("s");
+        let (primary_id, secondary_id) = (primary.id, secondary.id);
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.entities.insert(primary).unwrap();
+            stores.entities.insert(secondary).unwrap();
+            stores.entities.merge(primary_id, secondary_id).unwrap();
+
+            // The merge (and its provenance) now lives in a segment.
+            stores.compact().unwrap();
+
+            let (primary_after, restored) = stores.entities.unmerge(primary_id, secondary_id).unwrap();
+            assert_eq!(primary_after.aliases, vec!["p".to_string()]);
+            assert_eq!(restored.aliases, vec!["s".to_string()]);
+        }
+
+        let check = |stores: &PersistentStores| {
+            let p = stores.entities.get(primary_id).unwrap().unwrap();
+            let s = stores.entities.get(secondary_id).unwrap().unwrap();
+            assert_eq!(p.id, primary_id);
+            assert_eq!(p.aliases, vec!["p".to_string()]);
+            assert_eq!(s.id, secondary_id);
+            assert_eq!(s.aliases, vec!["s".to_string()]);
+        };
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            check(&stores);
+            stores.compact().unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        check(&stores);
+        assert!(stores.entities.unmerge(primary_id, secondary_id).is_err());
+    }
+
+    #[test]
+    fn test_entity_insert_many_survives_reopen() {
+        let dir = tempdir().unwrap();
+        let first = Entity::new("acme", EntityType::Organization);
+        let mut second = Entity::new("ACME ", EntityType::Organization);
+        second.add_alias("acme inc");
+        let (first_id, second_id) = (first.id, second.id);
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let mapping = stores.entities.insert_many(vec![first, second], true).unwrap();
+            assert_eq!(mapping[&second_id], first_id);
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let found = stores.entities.find_by_name(None, "acme").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, first_id);
+        assert!(found[0].aliases.iter().any(|a| a == "acme inc"));
+        assert!(stores.entities.get(second_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_stats_report_counts_and_footprint() {
+        let dir = tempdir().unwrap();
+        let entity = Entity::new("stats", EntityType::Concept);
+        let subject = entity.id;
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.entities.insert(entity).unwrap();
+
+            let mut ids = Vec::new();
+            for predicate in ["a", "a", "b"] {
+                let belief = Belief::builder()
+                    .subject(subject)
+                    .predicate(predicate)
+                    .value(true)
+                    .confidence(crate::confidence::Confidence::from_agent(0.9, "agent").unwrap())
+                    .build()
+                    .unwrap();
+                ids.push(belief.id);
+                stores.beliefs.insert(belief).unwrap();
+            }
+            stores.beliefs.supersede(ids[0], ids[1]).unwrap();
+
+            let stats = stores.stats().unwrap();
+            assert_eq!(stats.entities.records, 1);
+            assert_eq!(stats.beliefs.records, 3);
+            assert_eq!(stats.beliefs.distinct_entities, Some(1));
+            assert_eq!(stats.beliefs.distinct_predicates, Some(2));
+            assert!(stats.wal_bytes > 0);
+            assert_eq!(stats.segment_count, 0);
+
+            stores.compact().unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let stats = stores.stats().unwrap();
+        assert_eq!(stats.beliefs.records, 3);
+        assert_eq!(stats.entities.records, 1);
+        assert_eq!(stats.segment_count, 1);
+        assert_eq!(stats.wal_bytes, stores.wal_size());
+    }
+
     #[test]
     fn test_entity_versions_survive_reopen() {
         let dir = tempdir().unwrap();
         let entity_id;
 
         {
-            let mut stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
             let entity = Entity::new("versioned", EntityType::Concept);
             entity_id = entity.id;
             stores.entities.insert(entity.clone()).unwrap();
@@ -1592,4 +3339,815 @@ mod tests {
             assert_eq!(versions.len(), 2);
         }
     }
+
+    #[test]
+    fn test_conflict_entity_index_survives_replay_and_compaction() {
+        let dir = tempdir().unwrap();
+        let e1 = EntityId::new();
+        let e2 = EntityId::new();
+        let resolved_id;
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let c1 = Conflict::value_contradiction(vec![BeliefId::new()], e1, "a");
+            let c2 = Conflict::value_contradiction(vec![BeliefId::new()], e1, "b");
+            let c3 = Conflict::value_contradiction(vec![BeliefId::new()], e2, "a");
+            resolved_id = c2.id;
+            stores.conflicts.insert(c1).unwrap();
+            stores.conflicts.insert(c2.clone()).unwrap();
+            stores.conflicts.insert(c3).unwrap();
+
+            let mut dismissed = c2;
+            dismissed.dismiss();
+            stores.conflicts.update(dismissed).unwrap();
+
+            assert_eq!(stores.conflicts.find_by_entity(e1).unwrap().len(), 2);
+            assert_eq!(stores.conflicts.find_by_entity(e2).unwrap().len(), 1);
+        }
+
+        // WAL replay rebuilds the index without duplicating updated conflicts.
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let for_e1 = stores.conflicts.find_by_entity(e1).unwrap();
+            assert_eq!(for_e1.len(), 2);
+            let open: Vec<_> = for_e1.iter().filter(|c| c.is_open()).collect();
+            assert_eq!(open.len(), 1);
+            assert_ne!(open[0].id, resolved_id);
+            stores.compact().unwrap();
+        }
+
+        // Segment load rebuilds it as well.
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            assert_eq!(stores.conflicts.find_by_entity(e1).unwrap().len(), 2);
+            assert_eq!(stores.conflicts.find_by_entity(e2).unwrap().len(), 1);
+            assert!(stores.conflicts.find_by_entity(EntityId::new()).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_beliefs_load_lazily_from_segments() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let subject = EntityId::new();
+        let belief = |predicate: &str| {
+            Belief::builder()
+                .subject(subject)
+                .predicate(predicate)
+                .value("x")
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .build()
+                .unwrap()
+        };
+        let first = belief("first");
+        let second = belief("second");
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(first.clone()).unwrap();
+            stores.compact().unwrap();
+            stores.beliefs.insert(second.clone()).unwrap();
+            stores.compact().unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let reads = || stores.beliefs.segment_reads.load(Ordering::Relaxed);
+        assert_eq!(reads(), 0, "opening must not read belief sections");
+
+        // Keys no segment stores are ruled out by the filters.
+        assert!(stores.beliefs.get(BeliefId::new()).unwrap().is_none());
+        assert!(stores.beliefs.find_by_entity(EntityId::new()).unwrap().is_empty());
+        assert_eq!(reads(), 0);
+
+        // Present keys are found; the newest segment holds both, and a lookup keeps every
+        // belief it read, so one read serves both.
+        assert_eq!(stores.beliefs.get(second.id).unwrap().unwrap().predicate, "second");
+        assert_eq!(reads(), 1);
+        assert_eq!(stores.beliefs.get(first.id).unwrap().unwrap().predicate, "first");
+        assert_eq!(stores.beliefs.get(first.id).unwrap().unwrap().predicate, "first");
+        assert_eq!(reads(), 1);
+
+        let about_subject = stores.beliefs.find_by_entity(subject).unwrap();
+        assert_eq!(about_subject.len(), 2);
+        stores.beliefs.find_by_entity(subject).unwrap();
+        assert_eq!(reads(), 3, "entity lookups are cached after the first load");
+
+        // Supersede of a cold belief survives another reopen.
+        stores.beliefs.supersede(first.id, second.id).unwrap();
+        drop(stores);
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let first_after = stores.beliefs.get(first.id).unwrap().unwrap();
+        assert_eq!(first_after.superseded_by, Some(second.id));
+        assert_eq!(stores.beliefs.stats().unwrap().records, 2);
+    }
+
+    #[test]
+    fn test_segment_reads_never_load_a_copy_a_newer_segment_replaces() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let belief = |predicate: &str| {
+            Belief::builder()
+                .subject(EntityId::new())
+                .predicate(predicate)
+                .value("x")
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .build()
+                .unwrap()
+        };
+        let kept = belief("kept");
+        let deleted = belief("deleted");
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(kept.clone()).unwrap();
+            stores.beliefs.insert(deleted.clone()).unwrap();
+            stores.compact().unwrap();
+            stores.beliefs.delete(deleted.id).unwrap();
+            let fields = AmendFields {
+                reason: Some("amended".to_string()),
+                ..AmendFields::default()
+            };
+            stores.beliefs.amend(kept.id, fields).unwrap();
+            stores.compact().unwrap();
+        }
+
+        // Only the older segment holds the deleted belief; the stale copy of `kept` read with
+        // it must not shadow the amended one.
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        assert!(stores.beliefs.get(deleted.id).unwrap().is_none());
+        let kept = stores.beliefs.get(kept.id).unwrap().unwrap();
+        assert_eq!(kept.reason.as_deref(), Some("amended"));
+    }
+
+    #[test]
+    fn test_iter_beliefs_streams_segments_without_loading_them() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let config = PersistentConfig {
+            sync_on_write: false,
+            ..PersistentConfig::default()
+        };
+        let belief = |i: usize| {
+            Belief::builder()
+                .subject(EntityId::new())
+                .predicate("rank")
+                .value(i as i64)
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .build()
+                .unwrap()
+        };
+        let mut ids = HashSet::new();
+        let mut compacted = Vec::new();
+
+        {
+            let stores = PersistentStores::open(dir.path(), config.clone()).unwrap();
+            for i in 0..2500 {
+                let b = belief(i);
+                compacted.push(b.id);
+                stores.beliefs.insert(b).unwrap();
+            }
+            stores.compact().unwrap();
+        }
+        ids.extend(compacted.iter().copied());
+
+        let stores = PersistentStores::open(dir.path(), config).unwrap();
+        // Some beliefs live only in the WAL, one was faulted in, one was deleted.
+        for i in 2500..2510 {
+            let b = belief(i);
+            ids.insert(b.id);
+            stores.beliefs.insert(b).unwrap();
+        }
+        assert!(stores.beliefs.get(compacted[0]).unwrap().is_some());
+        stores.beliefs.delete(compacted[1]).unwrap();
+        ids.remove(&compacted[1]);
+        let loaded = stores.beliefs.index.read().unwrap().by_id.len();
+
+        let mut streamed = HashSet::new();
+        for belief in stores.iter_beliefs() {
+            assert!(streamed.insert(belief.unwrap().id), "beliefs are yielded once");
+        }
+        assert_eq!(streamed.len(), 2509);
+        assert_eq!(streamed, ids);
+        assert_eq!(
+            stores.beliefs.index.read().unwrap().by_id.len(),
+            loaded,
+            "streaming must not load segment beliefs into the store"
+        );
+    }
+
+    #[test]
+    fn test_belief_delete_is_not_resurrected_from_older_segments() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let subject = EntityId::new();
+        let belief = |value: &str| {
+            Belief::builder()
+                .subject(subject)
+                .predicate("status")
+                .value(value)
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .build()
+                .unwrap()
+        };
+        let old = belief("old");
+        let new = belief("new");
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(old.clone()).unwrap();
+            stores.beliefs.insert(new.clone()).unwrap();
+            stores.beliefs.supersede(old.id, new.id).unwrap();
+            stores.compact().unwrap();
+        }
+
+        // Delete a belief that only the first segment holds, then check replay.
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let cutoff = Utc::now() + chrono::Duration::seconds(1);
+            let superseded = stores.beliefs.find_superseded(cutoff).unwrap();
+            assert_eq!(superseded.iter().map(|b| b.id).collect::<Vec<_>>(), vec![old.id]);
+            stores.beliefs.delete(old.id).unwrap();
+            assert!(matches!(stores.beliefs.delete(old.id), Err(StorageError::BeliefNotFound(_))));
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        assert!(stores.beliefs.get(old.id).unwrap().is_none());
+        assert_eq!(stores.beliefs.find_by_entity(subject).unwrap().len(), 1);
+        assert!(stores.beliefs.get(new.id).unwrap().unwrap().supersedes.is_empty());
+
+        // The next compaction omits the belief; the older segment still holds it.
+        stores.compact().unwrap();
+        assert_eq!(stores.segment_count(), 2);
+        drop(stores);
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        assert!(stores.beliefs.get(old.id).unwrap().is_none());
+        assert_eq!(stores.beliefs.find_by_entity(subject).unwrap().len(), 1);
+        assert_eq!(stores.beliefs.stats().unwrap().records, 1);
+        assert!(stores.beliefs.get(new.id).unwrap().unwrap().supersedes.is_empty());
+    }
+
+    #[test]
+    fn test_belief_amend_survives_replay_and_compaction() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let belief = Belief::builder()
+            .subject(EntityId::new())
+            .predicate("status")
+            .value("ok")
+            .confidence(Confidence::from_agent(0.9, "a").unwrap())
+            .build()
+            .unwrap();
+        let id = belief.id;
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(belief).unwrap();
+            stores
+                .beliefs
+                .amend(id, AmendFields { reason: Some("corrected".to_string()), ..AmendFields::default() })
+                .unwrap();
+            assert!(stores
+                .beliefs
+                .amend(id, AmendFields { value: Some(crate::value::Value::from("bad")), ..AmendFields::default() })
+                .is_err());
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let replayed = stores.beliefs.get(id).unwrap().unwrap();
+        assert_eq!(replayed.reason.as_deref(), Some("corrected"));
+        assert_eq!(replayed.value, crate::value::Value::from("ok"));
+
+        stores.compact().unwrap();
+        drop(stores);
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        assert_eq!(stores.beliefs.get(id).unwrap().unwrap().reason.as_deref(), Some("corrected"));
+    }
+
+    #[test]
+    fn test_belief_coalesce_survives_replay() {
+        use crate::confidence::Confidence;
+        use crate::time::TimeRange;
+
+        let dir = tempdir().unwrap();
+        let subject = EntityId::new();
+        let start = Utc::now() - chrono::Duration::days(3);
+        let day = |d: i64| start + chrono::Duration::days(d);
+        let belief = |from: i64, to: Option<i64>| {
+            Belief::builder()
+                .subject(subject)
+                .predicate("status")
+                .value("ok")
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .valid_time(match to {
+                    Some(to) => TimeRange::new(day(from), day(to)).unwrap(),
+                    None => TimeRange::starting_at(day(from)),
+                })
+                .build()
+                .unwrap()
+        };
+        let first = belief(0, Some(1));
+        let first_id = first.id;
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(first).unwrap();
+            stores.beliefs.insert(belief(1, Some(2))).unwrap();
+            stores.beliefs.insert(belief(2, None)).unwrap();
+            assert_eq!(stores.beliefs.coalesce(subject, "status", &HashSet::new()).unwrap(), 2);
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let history = stores.beliefs.history(subject, "status").unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].id, first_id);
+        assert_eq!(history[0].valid_time, TimeRange::starting_at(day(0)));
+    }
+
+    #[test]
+    fn test_belief_reembed_survives_replay() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let belief = |embedding: Option<Vec<f32>>| {
+            let mut belief = Belief::builder()
+                .subject(EntityId::new())
+                .predicate("status")
+                .value("ok")
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .build()
+                .unwrap();
+            belief.embedding = embedding;
+            belief
+        };
+        let embedded = belief(Some(vec![1.0, 0.0]));
+        let plain = belief(None);
+        let (embedded_id, plain_id) = (embedded.id, plain.id);
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(embedded).unwrap();
+            stores.beliefs.insert(plain).unwrap();
+            assert_eq!(stores.beliefs.reembed(&|_| Ok(vec![0.0, 0.0, 1.0])).unwrap(), 1);
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        assert_eq!(
+            stores.beliefs.get(embedded_id).unwrap().unwrap().embedding,
+            Some(vec![0.0, 0.0, 1.0])
+        );
+        assert!(stores.beliefs.get(plain_id).unwrap().unwrap().embedding.is_none());
+        let found = stores
+            .beliefs
+            .find_by_embedding(None, &[0.0, 0.0, 1.0], 1, None)
+            .unwrap();
+        assert_eq!(found[0].0.id, embedded_id);
+    }
+
+    #[test]
+    fn test_derivation_update_survives_replay() {
+        use crate::derivation::DerivationRecord;
+
+        let dir = tempdir().unwrap();
+        let record = DerivationRecord::new(
+            Utc::now(),
+            None,
+            vec![BeliefId::new()],
+            "product",
+            Vec::new(),
+            Some(0.5),
+            None,
+            None,
+        )
+        .unwrap();
+        let id = record.id;
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.derivations.insert(record.clone()).unwrap();
+            let mut updated = record;
+            updated.propagated_confidence = Some(0.25);
+            stores.derivations.update(updated).unwrap();
+
+            let mut missing = stores.derivations.get(id).unwrap().unwrap();
+            missing.id = DerivationId::new();
+            assert!(matches!(
+                stores.derivations.update(missing),
+                Err(StorageError::DerivationNotFound(_))
+            ));
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let replayed = stores.derivations.get(id).unwrap().unwrap();
+        assert_eq!(replayed.propagated_confidence, Some(0.25));
+    }
+
+    #[test]
+    fn test_encrypted_database_requires_its_key() {
+        use super::super::EncryptionKey;
+
+        let dir = tempdir().unwrap();
+        let keyed = |key: u8| PersistentConfig {
+            encryption_key: Some(EncryptionKey::new([key; 32])),
+            ..PersistentConfig::default()
+        };
+        let open_error = |config: PersistentConfig| match PersistentStores::open(dir.path(), config) {
+            Ok(_) => panic!("open should fail"),
+            Err(e) => e.to_string(),
+        };
+        let compacted = Entity::new("compacted_secret", EntityType::Concept);
+        let logged = Entity::new("logged_secret", EntityType::Concept);
+
+        {
+            let stores = PersistentStores::open(dir.path(), keyed(1)).unwrap();
+            stores.entities.insert(compacted.clone()).unwrap();
+            stores.compact().unwrap();
+            stores.entities.insert(logged.clone()).unwrap();
+        }
+
+        for file in [dir.path().join("kyro.wal"), dir.path().join("segments/segment_00001.seg")] {
+            let bytes = fs::read(&file).unwrap();
+            assert!(!bytes.windows(6).any(|w| w == b"secret"), "{} holds plaintext", file.display());
+        }
+
+        {
+            let stores = PersistentStores::open(dir.path(), keyed(1)).unwrap();
+            assert!(stores.entities.get(compacted.id).unwrap().is_some());
+            assert!(stores.entities.get(logged.id).unwrap().is_some());
+        }
+
+        assert!(open_error(keyed(2)).contains("authenticated decryption failed"));
+        assert!(open_error(PersistentConfig::default()).contains("no encryption key"));
+
+        // The WAL fails first; with it compacted away the segment must fail on its own.
+        PersistentStores::open(dir.path(), keyed(1)).unwrap().compact().unwrap();
+        assert!(open_error(keyed(2)).contains("authenticated decryption failed"));
+    }
+
+    #[test]
+    fn test_find_entities_by_predicate_value_after_reopen() {
+        use crate::confidence::Confidence;
+        use crate::value::Value;
+
+        let dir = tempdir().unwrap();
+        let belief = |subject: EntityId, value: f64| {
+            Belief::builder()
+                .subject(subject)
+                .predicate("score")
+                .value(value)
+                .confidence(Confidence::from_agent(0.9, "a").unwrap())
+                .build()
+                .unwrap()
+        };
+        let (a, b) = (EntityId::new(), EntityId::new());
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(belief(a, 0.0)).unwrap();
+            stores.compact().unwrap();
+            stores.beliefs.insert(belief(b, 1.5)).unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let now = Utc::now();
+        // -0.0 and 0.0 index to the same key.
+        assert_eq!(
+            stores.beliefs.find_entities_by_predicate_value(None, "score", &Value::Float(-0.0), now).unwrap(),
+            vec![a]
+        );
+        assert_eq!(
+            stores.beliefs.find_entities_by_predicate_value(None, "score", &Value::Float(1.5), now).unwrap(),
+            vec![b]
+        );
+        assert!(stores
+            .beliefs
+            .find_entities_by_predicate_value(None, "score", &Value::Int(1), now)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_belief_metadata_survives_replay_and_compaction() {
+        use crate::confidence::Confidence;
+
+        let dir = tempdir().unwrap();
+        let belief = Belief::builder()
+            .subject(EntityId::new())
+            .predicate("status")
+            .value("ok")
+            .confidence(Confidence::from_agent(0.9, "a").unwrap())
+            .metadata(serde_json::json!({"document": "10-K", "model": "extractor-3"}))
+            .build()
+            .unwrap();
+        let id = belief.id;
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.beliefs.insert(belief).unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        assert_eq!(stores.beliefs.get(id).unwrap().unwrap().metadata["model"], "extractor-3");
+
+        stores.compact().unwrap();
+        drop(stores);
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        assert_eq!(stores.beliefs.get(id).unwrap().unwrap().metadata["document"], "10-K");
+    }
+
+    #[test]
+    fn test_compaction_keeps_writes_made_while_it_runs() {
+        use std::sync::atomic::AtomicBool;
+
+        const WRITES: usize = 300;
+        let dir = tempdir().unwrap();
+        let ids: Vec<EntityId> = {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let done = AtomicBool::new(false);
+            std::thread::scope(|scope| {
+                let writer = scope.spawn(|| {
+                    let ids: Vec<EntityId> = (0..WRITES)
+                        .map(|i| {
+                            let entity = Entity::new(format!("concurrent_{i}"), EntityType::Concept);
+                            let id = entity.id;
+                            stores.entities.insert(entity).unwrap();
+                            id
+                        })
+                        .collect();
+                    done.store(true, Ordering::Release);
+                    ids
+                });
+                let mut compactions = 0;
+                while !done.load(Ordering::Acquire) || compactions == 0 {
+                    stores.compact().unwrap();
+                    compactions += 1;
+                }
+                writer.join().unwrap()
+            })
+        };
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        for id in &ids {
+            assert!(stores.entities.get(*id).unwrap().is_some(), "lost entity {id}");
+        }
+        assert_eq!(stores.entities.stats().unwrap().records, WRITES);
+    }
+
+    #[test]
+    fn test_operation_entries_are_audited_but_not_replayed() {
+        use crate::confidence::Confidence;
+        use crate::engine::OperationLog;
+        use crate::ir::{AssertPayload, ConsistencyMode, Operation, ResolvePayload};
+        use crate::source::Source;
+        use crate::time::TimeRange;
+
+        let dir = tempdir().unwrap();
+        let belief = Belief::builder()
+            .subject(EntityId::new())
+            .predicate("status")
+            .value("ok")
+            .confidence(Confidence::from_agent(0.9, "a").unwrap())
+            .build()
+            .unwrap();
+        let assert = KyroIR::new(Operation::Assert(AssertPayload {
+            entity_id: belief.subject,
+            predicate: "status".to_string(),
+            value: Value::from("ok"),
+            confidence: belief.confidence.clone(),
+            source: Source::agent("a", Option::<String>::None),
+            valid_time: TimeRange::from_now(),
+            consistency_mode: ConsistencyMode::Force,
+            embedding: None,
+            idempotency_key: None,
+            namespace: None,
+        }));
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            stores.begin(&assert).unwrap();
+            stores.beliefs.insert(belief.clone()).unwrap();
+            // Reads are not logged.
+            stores.begin(&KyroIR::new(Operation::Resolve(ResolvePayload::default()))).unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let operations = stores.iter_operations().unwrap();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].1, assert);
+        // The operation precedes the store entry it produced.
+        let kinds: Vec<_> = stores.wal.iter().unwrap().map(|entry| entry.unwrap().kind).collect();
+        assert!(matches!(kinds.as_slice(), [WalEntryKind::Operation(_), WalEntryKind::BeliefInsert(_)]));
+
+        // Replay ignored the operation entry: the store holds only the belief it wrote.
+        assert_eq!(stores.beliefs.stats().unwrap().records, 1);
+        assert_eq!(stores.beliefs.get(belief.id).unwrap().unwrap().value, Value::from("ok"));
+    }
+
+    #[test]
+    fn test_trust_state_survives_reopen_and_compaction() {
+        use crate::confidence::Confidence;
+        use crate::engine::{EngineResponse, KyroEngine};
+        use crate::ir::{AssertPayload, ConsistencyMode, Operation, ResolvePayload};
+        use crate::source::Source;
+        use crate::trust::SimpleTrustModel;
+
+        let dir = tempdir().unwrap();
+        let source_a = Source::agent("a", Option::<String>::None);
+        let source_b = Source::agent("b", Option::<String>::None);
+        let entity = Entity::new("lamp", EntityType::Concept);
+        let id = entity.id;
+        let engine = |stores: PersistentStores, trust: Arc<SimpleTrustModel>| {
+            KyroEngine::with_trust_model(
+                Arc::new(stores.entities),
+                Arc::new(stores.beliefs),
+                Arc::new(stores.patterns),
+                Arc::new(stores.conflicts),
+                Arc::new(stores.derivations),
+                trust,
+            )
+        };
+
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            let trust = Arc::new(SimpleTrustModel::new());
+            trust.set_domain("status", source_a.source_id(), 0.0).unwrap();
+            trust.set_domain("status", source_b.source_id(), 1.0).unwrap();
+            stores.save_trust(trust.as_ref()).unwrap();
+            stores.entities.insert(entity).unwrap();
+
+            let eng = engine(stores, trust);
+            for (value, confidence, source) in [("off", 0.9, &source_a), ("on", 0.2, &source_b)] {
+                eng.execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    entity_id: id,
+                    predicate: "status".to_string(),
+                    value: Value::from(value),
+                    confidence: Confidence::from_agent(confidence, "agent").unwrap(),
+                    source: source.clone(),
+                    valid_time: TimeRange::forever(),
+                    consistency_mode: ConsistencyMode::Eventual,
+                    embedding: None,
+                    idempotency_key: None,
+                    namespace: None,
+                })))
+                .unwrap();
+            }
+        }
+        {
+            let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+            assert!(stores.trust_snapshot().is_some());
+            stores.compact().unwrap();
+        }
+
+        let stores = PersistentStores::open(dir.path(), PersistentConfig::default()).unwrap();
+        let trust = Arc::new(SimpleTrustModel::new());
+        assert!(stores.restore_trust(trust.as_ref()).unwrap());
+        let eng = engine(stores, trust);
+        let EngineResponse::Resolve { frame } = eng
+            .execute(KyroIR::new(Operation::Resolve(ResolvePayload {
+                entity_id: Some(id),
+                predicate: Some("status".to_string()),
+                ..ResolvePayload::default()
+            })))
+            .unwrap()
+        else {
+            panic!("expected resolve");
+        };
+        // A's higher confidence is outweighed by the restored domain weights.
+        assert_eq!(frame.best_supported_claim.unwrap().belief.value, Value::from("on"));
+    }
+
+    #[test]
+    fn test_open_with_trust_saves_changes_and_feedback_as_they_happen() {
+        use crate::confidence::Confidence;
+        use crate::engine::{EngineResponse, KyroEngine};
+        use crate::ir::{AssertPayload, ConsistencyMode, FeedbackPayload, Operation};
+        use crate::source::Source;
+        use crate::trust::{CalibrationTracker, FeedbackOutcome, SimpleTrustModel, TrustModel};
+
+        let dir = tempdir().unwrap();
+        let source = Source::agent("a", Option::<String>::None);
+        let open = || {
+            let trust = Arc::new(SimpleTrustModel::new());
+            let calibration = Arc::new(CalibrationTracker::new());
+            let stores =
+                PersistentStores::open_with_trust(dir.path(), PersistentConfig::default(), trust.as_ref(), &calibration)
+                    .unwrap();
+            (stores, trust, calibration)
+        };
+
+        {
+            let (stores, trust, calibration) = open();
+            let entity = Entity::new("lamp", EntityType::Concept);
+            let id = entity.id;
+            stores.entities.insert(entity).unwrap();
+            let eng = KyroEngine::builder()
+                .entity_store(Arc::new(stores.entities))
+                .belief_store(Arc::new(stores.beliefs))
+                .pattern_store(Arc::new(stores.patterns))
+                .conflict_store(Arc::new(stores.conflicts))
+                .derivation_store(Arc::new(stores.derivations))
+                .trust_model(trust.clone())
+                .calibration(calibration)
+                .build()
+                .unwrap();
+
+            // Changed after opening, with no explicit save.
+            trust.set_domain("status", source.source_id(), 0.3).unwrap();
+            let EngineResponse::Assert { belief_id, .. } = eng
+                .execute(KyroIR::new(Operation::Assert(AssertPayload {
+                    entity_id: id,
+                    predicate: "status".to_string(),
+                    value: Value::from("on"),
+                    confidence: Confidence::from_agent(0.9, "agent").unwrap(),
+                    source: source.clone(),
+                    valid_time: TimeRange::forever(),
+                    consistency_mode: ConsistencyMode::Eventual,
+                    embedding: None,
+                    idempotency_key: None,
+                    namespace: None,
+                })))
+                .unwrap()
+            else {
+                panic!("expected assert");
+            };
+            eng.execute(KyroIR::new(Operation::Feedback(FeedbackPayload {
+                belief_id,
+                outcome: FeedbackOutcome::Incorrect,
+                namespace: None,
+            })))
+            .unwrap();
+        }
+
+        for compact in [false, true] {
+            let (stores, trust, calibration) = open();
+            assert_eq!(trust.assess(&source, Some("status")).weight(), 0.3);
+            assert_eq!(calibration.accuracy(source.source_id()).incorrect, 1);
+            if compact {
+                stores.compact().unwrap();
+            }
+        }
+        let (_stores, trust, calibration) = open();
+        assert_eq!(trust.assess(&source, Some("status")).weight(), 0.3);
+        assert_eq!(calibration.accuracy(source.source_id()).incorrect, 1);
+    }
+
+    #[test]
+    fn test_open_with_trust_reports_a_failed_save() {
+        use crate::source::Source;
+        use crate::storage::GroupCommitConfig;
+        use crate::trust::{CalibrationTracker, FeedbackOutcome, SimpleTrustModel, TrustModel};
+
+        let dir = tempdir().unwrap();
+        let config = PersistentConfig {
+            group_commit: Some(GroupCommitConfig::default()),
+            ..PersistentConfig::default()
+        };
+        let trust = SimpleTrustModel::new();
+        let calibration = Arc::new(CalibrationTracker::new());
+        let stores = PersistentStores::open_with_trust(dir.path(), config, &trust, &calibration).unwrap();
+        let source = Source::agent("a", Option::<String>::None);
+
+        stores.wal.fail_next_sync();
+        assert!(trust.set_global(source.source_id(), 0.2).is_err());
+        // The change still applies in memory.
+        assert_eq!(trust.assess(&source, None).weight(), 0.2);
+
+        // The log stays failed until reopened, so FEEDBACK reports it too.
+        let recorded = calibration.record(BeliefId::new(), source.source_id(), FeedbackOutcome::Correct);
+        assert!(recorded.is_err());
+        assert_eq!(calibration.accuracy(source.source_id()).correct, 1);
+    }
+
+    #[test]
+    fn test_belief_is_not_visible_when_its_group_commit_fails() {
+        use crate::confidence::Confidence;
+        use crate::storage::GroupCommitConfig;
+
+        let dir = tempdir().unwrap();
+        let config = PersistentConfig {
+            group_commit: Some(GroupCommitConfig::default()),
+            ..PersistentConfig::default()
+        };
+        let stores = PersistentStores::open(dir.path(), config).unwrap();
+        let belief = Belief::builder()
+            .subject(EntityId::new())
+            .predicate("status")
+            .value("ok")
+            .confidence(Confidence::from_agent(0.9, "a").unwrap())
+            .build()
+            .unwrap();
+
+        stores.wal.fail_next_sync();
+        assert!(stores.beliefs.insert(belief.clone()).is_err());
+        assert!(stores.beliefs.get(belief.id).unwrap().is_none());
+        assert!(stores.beliefs.find_by_entity(belief.subject).unwrap().is_empty());
+
+        // The log stays failed until reopened, but the retry is not mistaken for a duplicate.
+        let retry = stores.beliefs.insert(belief).unwrap_err();
+        assert!(!matches!(retry, StorageError::DuplicateKey(_)), "{retry:?}");
+    }
 }
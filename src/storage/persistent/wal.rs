@@ -14,9 +14,11 @@
 //! ```
 
 use std::fs::{File, OpenOptions};
-use std::io::{BufReader, BufWriter, Seek, Write, Result as IoResult, ErrorKind};
+use std::io::{BufReader, BufWriter, Seek, Write, Error as IoError, Result as IoResult, ErrorKind};
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
@@ -24,11 +26,14 @@ use serde::{Serialize, Deserialize};
 use crate::belief::Belief;
 use crate::conflict::Conflict;
 use crate::derivation::DerivationRecord;
-use crate::entity::{Entity, EntityId};
+use crate::entity::{Entity, EntityId, MergeProvenance};
 use crate::pattern::Pattern;
 use crate::confidence::BeliefId;
+use crate::ir::KyroIR;
+use crate::trust::{FeedbackRecord, TrustSnapshot};
+use crate::storage::AmendFields;
 
-use super::codec;
+use super::codec::{self, Cipher};
 
 /// A single entry in the write-ahead log.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,11 +60,34 @@ pub enum WalEntryKind {
         secondary_id: EntityId,
         /// Secondary's canonical name for index cleanup during replay.
         secondary_canonical: String,
+        /// Contributions of the secondary, needed to undo the merge.
+        #[serde(default)]
+        provenance: Option<MergeProvenance>,
+    },
+    EntityUnmerge {
+        /// The primary entity with the secondary's contributions stripped.
+        primary: Entity,
+        /// The restored secondary entity.
+        restored: Entity,
     },
     
     // Belief operations
     BeliefInsert(Belief),
-    BeliefSupersede { old_id: BeliefId, new_id: BeliefId },
+    /// A belief inserted as the successor of every belief in its `supersedes`, in one step.
+    BeliefInsertSuperseding(Belief),
+    BeliefSupersede {
+        /// Entries written before a belief could supersede several hold a single `old_id`.
+        #[serde(alias = "old_id", deserialize_with = "crate::belief::belief::deserialize_belief_ids")]
+        old_ids: Vec<BeliefId>,
+        new_id: BeliefId,
+    },
+    /// In-place correction of non-semantic fields; `fields` doubles as the audit record.
+    BeliefAmend { id: BeliefId, fields: AmendFields },
+    BeliefDelete { id: BeliefId },
+    /// Result of `BeliefStore::coalesce`: rewritten survivors and successors, then deletions.
+    BeliefCoalesce { updated: Vec<Belief>, removed: Vec<BeliefId> },
+    /// Result of `BeliefStore::reembed`: the new embedding of every belief that had one.
+    BeliefReembed { embeddings: Vec<(BeliefId, Vec<f32>)> },
     
     // Pattern operations
     PatternInsert(Pattern),
@@ -72,9 +100,191 @@ pub enum WalEntryKind {
     
     // Derivation operations
     DerivationInsert(DerivationRecord),
+    DerivationUpdate(DerivationRecord),
+
+    // Idempotency operations
+    IdempotencyRecord {
+        /// Tenant namespace of the key; absent in entries written before keys were scoped.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        namespace: Option<String>,
+        key: String,
+        belief_id: BeliefId,
+    },
+
+    /// Trust model configuration, replacing any saved before.
+    TrustState(TrustSnapshot),
+
+    /// A FEEDBACK outcome, replacing any recorded before for its belief.
+    Feedback(FeedbackRecord),
     
     // Checkpoint marker (all entries before this are persisted to segments)
     Checkpoint { up_to_sequence: u64 },
+
+    /// An operation about to run, logged ahead of the store entries it produces.
+    ///
+    /// Audit only: replay skips it, and it is kept even if the operation then fails.
+    Operation(KyroIR),
+}
+
+/// Group commit tuning: how many writes may share one fsync and how long one may wait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupCommitConfig {
+    /// Flush as soon as this many writes are pending.
+    pub max_batch: usize,
+    /// Flush pending writes at most this long after the first one arrived.
+    pub max_delay: Duration,
+}
+
+impl Default for GroupCommitConfig {
+    fn default() -> Self {
+        Self {
+            max_batch: 128,
+            max_delay: Duration::from_millis(2),
+        }
+    }
+}
+
+/// Commit tickets shared between writers and the group commit coordinator.
+///
+/// Tickets count appends since open; unlike WAL sequences they survive `truncate`.
+#[derive(Debug, Default)]
+struct GroupState {
+    /// Last ticket handed to a writer.
+    appended: u64,
+    /// Last ticket known to be on disk.
+    durable: u64,
+    /// Set once an fsync fails; every pending and later writer fails with it.
+    poisoned: Option<String>,
+    shutdown: bool,
+    /// Fail the next fsync, as a failing disk would.
+    #[cfg(test)]
+    fail_next_sync: bool,
+}
+
+struct GroupShared {
+    state: Mutex<GroupState>,
+    /// Signals the coordinator that writes are pending.
+    work: Condvar,
+    /// Signals writers that `durable` advanced or the WAL was poisoned.
+    done: Condvar,
+}
+
+impl GroupShared {
+    fn poisoned_error(message: &str) -> IoError {
+        IoError::other(format!("WAL group commit failed: {message}"))
+    }
+
+    /// Block until `ticket` is durable.
+    fn wait_durable(&self, ticket: u64) -> IoResult<()> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if state.durable >= ticket {
+                return Ok(());
+            }
+            if let Some(message) = &state.poisoned {
+                return Err(Self::poisoned_error(message));
+            }
+            state = self.done.wait(state).unwrap();
+        }
+    }
+}
+
+/// Background thread that fsyncs pending WAL writes in batches.
+struct GroupCommitter {
+    shared: Arc<GroupShared>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl GroupCommitter {
+    fn start(writer: Arc<Mutex<BufWriter<File>>>, config: GroupCommitConfig) -> Self {
+        let shared = Arc::new(GroupShared {
+            state: Mutex::new(GroupState::default()),
+            work: Condvar::new(),
+            done: Condvar::new(),
+        });
+        let coordinator = Arc::clone(&shared);
+        let handle = std::thread::Builder::new()
+            .name("kyroql-wal-commit".to_string())
+            .spawn(move || Self::run(&coordinator, &writer, config))
+            .expect("failed to spawn WAL group commit thread");
+        Self {
+            shared,
+            handle: Some(handle),
+        }
+    }
+
+    fn run(shared: &GroupShared, writer: &Mutex<BufWriter<File>>, config: GroupCommitConfig) {
+        let max_batch = config.max_batch.max(1) as u64;
+        loop {
+            let target = {
+                let mut state = shared.state.lock().unwrap();
+                // Idle while nothing is pending; once poisoned, nothing more can succeed.
+                let idle = |s: &GroupState| s.appended == s.durable || s.poisoned.is_some();
+                while idle(&state) && !state.shutdown {
+                    state = shared.work.wait(state).unwrap();
+                }
+                if idle(&state) {
+                    return;
+                }
+                let (state, _) = shared
+                    .work
+                    .wait_timeout_while(state, config.max_delay, |s| {
+                        s.appended - s.durable < max_batch && !s.shutdown
+                    })
+                    .unwrap();
+                state.appended
+            };
+
+            // Entries up to `target` were written to the file before their ticket was issued.
+            let synced = writer
+                .lock()
+                .unwrap()
+                .get_ref()
+                .try_clone()
+                .and_then(|file| file.sync_all());
+
+            let mut state = shared.state.lock().unwrap();
+            #[cfg(test)]
+            let synced = match std::mem::take(&mut state.fail_next_sync) {
+                true => Err(IoError::other("injected fsync failure")),
+                false => synced,
+            };
+            match synced {
+                Ok(()) => state.durable = state.durable.max(target),
+                Err(e) => state.poisoned = Some(e.to_string()),
+            }
+            shared.done.notify_all();
+        }
+    }
+}
+
+impl Drop for GroupCommitter {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().shutdown = true;
+        self.shared.work.notify_all();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A WAL entry that has been written but may not be durable yet.
+///
+/// Returned by [`WriteAheadLog::append_pending`].
+#[must_use = "the entry is only durable once `wait` returns Ok"]
+pub struct PendingAppend {
+    sequence: u64,
+    ticket: Option<(Arc<GroupShared>, u64)>,
+}
+
+impl PendingAppend {
+    /// Block until the entry is durable; returns its sequence number.
+    pub fn wait(self) -> IoResult<u64> {
+        if let Some((shared, ticket)) = self.ticket {
+            shared.wait_durable(ticket)?;
+        }
+        Ok(self.sequence)
+    }
 }
 
 /// Write-Ahead Log for crash recovery.
@@ -82,9 +292,12 @@ pub enum WalEntryKind {
 /// Thread-safe via internal mutex.
 pub struct WriteAheadLog {
     path: PathBuf,
-    writer: Mutex<BufWriter<File>>,
+    writer: Arc<Mutex<BufWriter<File>>>,
     current_sequence: Mutex<u64>,
     sync_on_write: bool,
+    read_only: bool,
+    group: Option<GroupCommitter>,
+    cipher: Option<Cipher>,
 }
 
 impl WriteAheadLog {
@@ -92,7 +305,32 @@ impl WriteAheadLog {
     ///
     /// If the file exists, reads the last sequence number.
     /// If the file doesn't exist, creates it with the header.
-    pub fn open(path: &Path, sync_on_write: bool) -> IoResult<Self> {
+    ///
+    /// With a `cipher`, entries are encrypted on append and must decrypt on read; an
+    /// existing log written under another key (or none) fails to open.
+    pub fn open(path: &Path, sync_on_write: bool, cipher: Option<Cipher>) -> IoResult<Self> {
+        Self::open_internal(path, sync_on_write, None, cipher)
+    }
+
+    /// Open or create a WAL whose appends are made durable by group commit.
+    ///
+    /// Appends are written immediately but fsynced in batches by a background thread; each
+    /// `append` still returns only once its entry is on disk. If an fsync fails, every pending
+    /// append fails and so does every later one: the log must be reopened.
+    pub fn open_with_group_commit(
+        path: &Path,
+        config: GroupCommitConfig,
+        cipher: Option<Cipher>,
+    ) -> IoResult<Self> {
+        Self::open_internal(path, true, Some(config), cipher)
+    }
+
+    fn open_internal(
+        path: &Path,
+        sync_on_write: bool,
+        group: Option<GroupCommitConfig>,
+        cipher: Option<Cipher>,
+    ) -> IoResult<Self> {
         let exists = path.exists();
         
         let file = OpenOptions::new()
@@ -104,7 +342,7 @@ impl WriteAheadLog {
         
         let current_sequence = if exists && file.metadata()?.len() >= 5 {
             // Read existing entries to find last sequence
-            Self::find_last_sequence(path)?
+            Self::find_last_sequence(path, cipher.as_ref())?
         } else {
             // New file, write header
             let mut file = file;
@@ -120,18 +358,95 @@ impl WriteAheadLog {
             .append(true)
             .open(path)?;
         
+        let writer = Arc::new(Mutex::new(BufWriter::new(file)));
+        let group = group.map(|config| GroupCommitter::start(Arc::clone(&writer), config));
+
         Ok(Self {
             path: path.to_path_buf(),
-            writer: Mutex::new(BufWriter::new(file)),
+            writer,
             current_sequence: Mutex::new(current_sequence),
             sync_on_write,
+            read_only: false,
+            group,
+            cipher,
         })
     }
+
+    /// Open an existing WAL for reading only.
+    ///
+    /// The file is never written; `append` and `truncate` fail with
+    /// `ErrorKind::PermissionDenied`.
+    pub fn open_read_only(path: &Path, cipher: Option<Cipher>) -> IoResult<Self> {
+        let file = File::open(path)?;
+        let current_sequence = if file.metadata()?.len() >= 5 {
+            Self::find_last_sequence(path, cipher.as_ref())?
+        } else {
+            0
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: Arc::new(Mutex::new(BufWriter::new(file))),
+            current_sequence: Mutex::new(current_sequence),
+            sync_on_write: false,
+            read_only: true,
+            group: None,
+            cipher,
+        })
+    }
+
+    /// Returns `true` if this WAL was opened with [`WriteAheadLog::open_read_only`].
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn ensure_writable(&self) -> IoResult<()> {
+        if self.read_only {
+            return Err(IoError::new(
+                ErrorKind::PermissionDenied,
+                "database is opened read-only",
+            ));
+        }
+        Ok(())
+    }
     
     /// Append an entry to the WAL.
     ///
-    /// Returns the sequence number assigned to this entry.
+    /// Returns the sequence number assigned to this entry once it is durable.
     pub fn append(&self, kind: WalEntryKind) -> IoResult<u64> {
+        self.append_pending(kind)?.wait()
+    }
+
+    /// Block until every entry appended so far is durable.
+    ///
+    /// Without group commit entries are durable (per `sync_on_write`) once appended.
+    pub fn wait_durable(&self) -> IoResult<()> {
+        let Some(group) = &self.group else {
+            return Ok(());
+        };
+        let ticket = group.shared.state.lock().unwrap().appended;
+        group.shared.wait_durable(ticket)
+    }
+
+    /// Make the next group commit fsync fail.
+    #[cfg(test)]
+    pub(crate) fn fail_next_sync(&self) {
+        if let Some(group) = &self.group {
+            group.shared.state.lock().unwrap().fail_next_sync = true;
+        }
+    }
+
+    /// Append an entry without waiting for group commit to make it durable.
+    ///
+    /// Lets a caller release its own locks before blocking in [`PendingAppend::wait`]. Without
+    /// group commit the entry is already durable (per `sync_on_write`) when this returns.
+    pub fn append_pending(&self, kind: WalEntryKind) -> IoResult<PendingAppend> {
+        self.ensure_writable()?;
+        if let Some(group) = &self.group {
+            if let Some(message) = &group.shared.state.lock().unwrap().poisoned {
+                return Err(GroupShared::poisoned_error(message));
+            }
+        }
         let mut writer = self.writer.lock().unwrap();
         let mut seq_guard = self.current_sequence.lock().unwrap();
 
@@ -142,27 +457,58 @@ impl WriteAheadLog {
             kind,
         };
 
-        let encoded = codec::encode(&entry)?;
+        let encoded = codec::encode(&entry, self.cipher.as_ref())?;
 
         writer.write_all(&encoded)?;
         writer.flush()?;
 
-        if self.sync_on_write {
-            writer.get_ref().sync_all()?;
-        }
+        let Some(group) = &self.group else {
+            if self.sync_on_write {
+                writer.get_ref().sync_all()?;
+            }
+            *seq_guard = candidate;
+            return Ok(PendingAppend {
+                sequence: candidate,
+                ticket: None,
+            });
+        };
 
         *seq_guard = candidate;
-
-        Ok(candidate)
+        let ticket = {
+            let mut state = group.shared.state.lock().unwrap();
+            state.appended += 1;
+            state.appended
+        };
+        group.shared.work.notify_one();
+        Ok(PendingAppend {
+            sequence: candidate,
+            ticket: Some((Arc::clone(&group.shared), ticket)),
+        })
     }
     
     /// Iterate over all entries in the WAL.
     ///
     /// Used during recovery to replay mutations.
     pub fn iter(&self) -> IoResult<WalIterator> {
-        WalIterator::new(&self.path)
+        WalIterator::new(&self.path, self.cipher.clone())
     }
     
+    /// Iterate over the [`WalEntryKind::Operation`] entries, with their sequence numbers.
+    ///
+    /// The store entries an operation produced follow its own entry. Entries truncated by
+    /// compaction are gone, so this covers operations since the last compaction.
+    pub fn iter_operations(&self) -> IoResult<impl Iterator<Item = IoResult<(u64, KyroIR)>>> {
+        Ok(self.iter()?.filter_map(|entry| match entry {
+            Ok(WalEntry {
+                sequence,
+                kind: WalEntryKind::Operation(ir),
+                ..
+            }) => Some(Ok((sequence, ir))),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        }))
+    }
+
     /// Get the current sequence number.
     pub fn current_sequence(&self) -> u64 {
         *self.current_sequence.lock().unwrap()
@@ -181,56 +527,68 @@ impl WriteAheadLog {
     /// # Safety
     /// Only call this after successfully writing a checkpoint.
     pub fn truncate(&self) -> IoResult<()> {
-        {
-            // Flush pending writes, then drop the existing writer to release the handle.
-            let mut writer = self.writer.lock().unwrap();
-            writer.flush()?;
-            let placeholder_path = std::env::temp_dir().join("kyroql_wal_truncate_placeholder");
-            let placeholder = OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(placeholder_path)?;
-            let _old = std::mem::replace(&mut *writer, BufWriter::new(placeholder));
-            // _old is dropped here, releasing the WAL file handle.
-        }
+        self.truncate_through(self.current_sequence())
+    }
 
-        // Truncate and rewrite header with a fresh handle.
-        {
-            let mut file = OpenOptions::new()
-                .write(true)
-                .truncate(true)
-                .open(&self.path)?;
+    /// Drop every entry up to and including `sequence`, keeping later ones.
+    ///
+    /// Used by compaction: entries up to the sequence its snapshot was taken at are in the
+    /// new segment, while entries appended during the compaction are not and must survive.
+    /// Kept entries are renumbered from 1, so a log with nothing left restarts at 0.
+    /// Checkpoint markers are informational and never kept. The log is rewritten to a
+    /// temporary file that replaces it atomically, holding off appends meanwhile.
+    ///
+    /// # Safety
+    /// Only call this once every entry up to `sequence` is persisted in a segment.
+    pub fn truncate_through(&self, sequence: u64) -> IoResult<()> {
+        self.ensure_writable()?;
+        let mut writer = self.writer.lock().unwrap();
+        writer.flush()?;
 
-            codec::write_header(&mut file)?;
-            if self.sync_on_write {
-                file.sync_all()?;
+        let mut kept = Vec::new();
+        for entry in WalIterator::new(&self.path, self.cipher.clone())? {
+            let entry = entry?;
+            if entry.sequence > sequence && !matches!(entry.kind, WalEntryKind::Checkpoint { .. }) {
+                kept.push(entry);
             }
         }
 
-        // Reset sequence to the base of the fresh WAL.
+        let temp_path = self.path.with_extension("truncate");
         {
-            let mut seq = self.current_sequence.lock().unwrap();
-            *seq = 0;
+            let mut file = BufWriter::new(
+                OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(&temp_path)?,
+            );
+            codec::write_header(&mut file)?;
+            for (entry, renumbered) in kept.iter_mut().zip(1..) {
+                entry.sequence = renumbered;
+                file.write_all(&codec::encode(&*entry, self.cipher.as_ref())?)?;
+            }
+            file.flush()?;
+            if self.sync_on_write {
+                // Kept entries include pending group-commit ones, which must not be lost.
+                file.get_ref().sync_all()?;
+            }
         }
+        std::fs::rename(&temp_path, &self.path)?;
 
-        // Reopen for appending and replace writer in place.
-        let file = OpenOptions::new()
-            .append(true)
-            .open(&self.path)?;
-
-        let mut writer = self.writer.lock().unwrap();
-        *writer = BufWriter::new(file);
-
+        // Appends wait on the writer lock held above, so the new sequence cannot race them.
+        *self.current_sequence.lock().unwrap() = kept.len() as u64;
+        *writer = BufWriter::new(OpenOptions::new().append(true).open(&self.path)?);
         Ok(())
     }
     
-    fn find_last_sequence(path: &Path) -> IoResult<u64> {
+    fn find_last_sequence(path: &Path, cipher: Option<&Cipher>) -> IoResult<u64> {
         let mut last_seq = 0;
         
-        for entry_result in WalIterator::new(path)? {
+        for entry_result in WalIterator::new(path, cipher.cloned())? {
             match entry_result {
                 Ok(entry) => last_seq = entry.sequence,
+                // Appending after entries we cannot read would bury them for good.
+                Err(e) if codec::is_key_error(&e) => return Err(e),
                 Err(e) => {
                     // Log corruption but continue - we'll replay up to the valid point
                     eprintln!("WAL: corruption detected at sequence {}: {}", last_seq + 1, e);
@@ -247,10 +605,11 @@ impl WriteAheadLog {
 pub struct WalIterator {
     reader: BufReader<File>,
     file_size: u64,
+    cipher: Option<Cipher>,
 }
 
 impl WalIterator {
-    fn new(path: &Path) -> IoResult<Self> {
+    fn new(path: &Path, cipher: Option<Cipher>) -> IoResult<Self> {
         let file = File::open(path)?;
         let file_size = file.metadata()?.len();
         let mut reader = BufReader::new(file);
@@ -258,7 +617,7 @@ impl WalIterator {
         // Skip header
         let _version = codec::read_header(&mut reader)?;
         
-        Ok(Self { reader, file_size })
+        Ok(Self { reader, file_size, cipher })
     }
     
     fn at_eof(&mut self) -> IoResult<bool> {
@@ -278,7 +637,7 @@ impl Iterator for WalIterator {
             Err(e) => return Some(Err(e)),
         }
         
-        match codec::decode(&mut self.reader) {
+        match codec::decode(&mut self.reader, self.cipher.as_ref()) {
             Ok(entry) => Some(Ok(entry)),
             Err(e) if e.kind() == ErrorKind::UnexpectedEof => None,
             Err(e) => Some(Err(e)),
@@ -297,7 +656,7 @@ mod tests {
         let dir = tempdir().unwrap();
         let wal_path = dir.path().join("test.wal");
         
-        let wal = WriteAheadLog::open(&wal_path, false).unwrap();
+        let wal = WriteAheadLog::open(&wal_path, false, None).unwrap();
         
         // Append some entries
         let entity = Entity::new("test", EntityType::Concept);
@@ -309,7 +668,7 @@ mod tests {
         // Drop and reopen to ensure file is properly flushed
         drop(wal);
         
-        let wal = WriteAheadLog::open(&wal_path, false).unwrap();
+        let wal = WriteAheadLog::open(&wal_path, false, None).unwrap();
         
         // Iterate and verify
         let entries: Vec<_> = wal.iter().unwrap().collect();
@@ -327,18 +686,84 @@ mod tests {
         
         // Write some entries
         {
-            let wal = WriteAheadLog::open(&wal_path, true).unwrap();
+            let wal = WriteAheadLog::open(&wal_path, true, None).unwrap();
             let entity = Entity::new("persist", EntityType::Concept);
             wal.append(WalEntryKind::EntityInsert(entity)).unwrap();
         }
         
         // Reopen and verify
         {
-            let wal = WriteAheadLog::open(&wal_path, true).unwrap();
+            let wal = WriteAheadLog::open(&wal_path, true, None).unwrap();
             assert_eq!(wal.current_sequence(), 1);
             
             let entries: Vec<_> = wal.iter().unwrap().collect();
             assert_eq!(entries.len(), 1);
         }
     }
+
+    #[test]
+    fn test_group_commit_appends_are_durable_and_ordered() {
+        let dir = tempdir().unwrap();
+        let wal_path = dir.path().join("group.wal");
+
+        {
+            let config = GroupCommitConfig {
+                max_batch: 16,
+                max_delay: Duration::from_millis(1),
+            };
+            let wal = WriteAheadLog::open_with_group_commit(&wal_path, config, None).unwrap();
+            let mut sequences: Vec<u64> = std::thread::scope(|scope| {
+                let handles: Vec<_> = (0..4)
+                    .map(|_| {
+                        scope.spawn(|| {
+                            (0..25)
+                                .map(|_| {
+                                    let entity = Entity::new("e", EntityType::Concept);
+                                    wal.append(WalEntryKind::EntityInsert(entity)).unwrap()
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+                handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+            });
+            sequences.sort_unstable();
+            assert_eq!(sequences, (1..=100).collect::<Vec<_>>());
+        }
+
+        let wal = WriteAheadLog::open(&wal_path, true, None).unwrap();
+        assert_eq!(wal.current_sequence(), 100);
+        assert_eq!(wal.iter().unwrap().count(), 100);
+    }
+
+    #[test]
+    fn test_truncate_through_keeps_later_entries() {
+        let dir = tempdir().unwrap();
+        let wal_path = dir.path().join("partial.wal");
+        let wal = WriteAheadLog::open(&wal_path, true, None).unwrap();
+
+        let names = ["a", "b", "c", "d"];
+        for name in names {
+            wal.append(WalEntryKind::EntityInsert(Entity::new(name, EntityType::Concept))).unwrap();
+        }
+        wal.append(WalEntryKind::Checkpoint { up_to_sequence: 2 }).unwrap();
+        wal.truncate_through(2).unwrap();
+        assert_eq!(wal.current_sequence(), 2);
+        assert_eq!(wal.append(WalEntryKind::EntityInsert(Entity::new("e", EntityType::Concept))).unwrap(), 3);
+        drop(wal);
+
+        let wal = WriteAheadLog::open(&wal_path, true, None).unwrap();
+        let kept: Vec<(u64, String)> = wal
+            .iter()
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let WalEntryKind::EntityInsert(entity) = entry.kind else {
+                    panic!("unexpected entry {:?}", entry.kind);
+                };
+                (entry.sequence, entity.canonical_name)
+            })
+            .collect();
+        assert_eq!(kept, vec![(1, "c".to_string()), (2, "d".to_string()), (3, "e".to_string())]);
+    }
 }
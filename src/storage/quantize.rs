@@ -0,0 +1,129 @@
+//! Compact in-memory representations of embedding vectors.
+//!
+//! Quantization is internal to a store: callers insert and receive `f32` vectors of the
+//! store's pinned dimension, and similarity search takes `f32` queries.
+
+/// How a store keeps embedding vectors in memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmbeddingStorage {
+    /// Full-precision `f32` components (4 bytes each).
+    #[default]
+    F32,
+    /// Symmetric per-vector `i8` components plus one `f32` scale (1 byte each).
+    ///
+    /// Each component is off by at most half its vector's scale, `max(|v|) / 254`;
+    /// embeddings read back from the store are the dequantized approximation.
+    Int8,
+}
+
+/// An embedding quantized to `i8` with a single per-vector scale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedEmbedding {
+    scale: f32,
+    values: Box<[i8]>,
+}
+
+impl QuantizedEmbedding {
+    /// Quantize `embedding` so its largest-magnitude component maps to ±127.
+    #[must_use]
+    pub fn quantize(embedding: &[f32]) -> Self {
+        let max = embedding
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold(0.0f32, |acc, v| acc.max(v.abs()));
+        let scale = if max > 0.0 { max / 127.0 } else { 0.0 };
+        let values = embedding
+            .iter()
+            .map(|&v| {
+                if scale == 0.0 || !v.is_finite() {
+                    return 0;
+                }
+                #[allow(clippy::cast_possible_truncation)]
+                let q = (v / scale).round().clamp(-127.0, 127.0) as i8;
+                q
+            })
+            .collect();
+        Self { scale, values }
+    }
+
+    /// Reconstruct the approximate `f32` embedding.
+    #[must_use]
+    pub fn dequantize(&self) -> Vec<f32> {
+        self.values.iter().map(|&q| f32::from(q) * self.scale).collect()
+    }
+
+    /// Number of components.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the embedding has no components.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Upper bound on the per-component reconstruction error.
+    #[must_use]
+    pub fn max_error(&self) -> f32 {
+        self.scale / 2.0
+    }
+
+    /// Cosine similarity against an `f32` query, computed on the quantized components.
+    ///
+    /// The per-vector scale cancels out of the cosine, so no dequantization is needed.
+    /// Returns 0.0 on a dimension mismatch or a zero vector.
+    #[must_use]
+    pub fn cosine_similarity(&self, query: &[f32]) -> f32 {
+        if query.len() != self.values.len() {
+            return 0.0;
+        }
+        let mut dot = 0.0f64;
+        let mut norm_q = 0.0f64;
+        let mut norm_v = 0.0f64;
+        for (&x, &q) in query.iter().zip(self.values.iter()) {
+            let xf = f64::from(x);
+            let qf = f64::from(q);
+            dot += xf * qf;
+            norm_q += xf * xf;
+            norm_v += qf * qf;
+        }
+        if norm_q <= 0.0 || norm_v <= 0.0 {
+            return 0.0;
+        }
+        let sim = dot / (norm_q.sqrt() * norm_v.sqrt());
+        if sim.is_finite() {
+            #[allow(clippy::cast_possible_truncation)]
+            let sim = sim as f32;
+            sim
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantization_error_is_bounded_by_half_scale() {
+        let embedding = vec![0.9, -0.35, 0.001, 0.0, -1.27, 0.5];
+        let q = QuantizedEmbedding::quantize(&embedding);
+        assert_eq!(q.len(), embedding.len());
+        assert!((q.max_error() - 1.27 / 254.0).abs() < 1e-6);
+
+        for (orig, restored) in embedding.iter().zip(q.dequantize()) {
+            assert!((orig - restored).abs() <= q.max_error() + 1e-6, "{orig} vs {restored}");
+        }
+        assert!((q.cosine_similarity(&embedding) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_zero_vector_quantizes_to_zero() {
+        let q = QuantizedEmbedding::quantize(&[0.0, 0.0]);
+        assert_eq!(q.dequantize(), vec![0.0, 0.0]);
+        assert_eq!(q.cosine_similarity(&[1.0, 0.0]), 0.0);
+    }
+}
@@ -6,16 +6,23 @@
 //! - Persistent backends for production
 //! - Distributed backends for scale
 
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::belief::Belief;
-use crate::confidence::BeliefId;
+use crate::confidence::{BeliefId, Confidence, ConfidenceSource};
 use crate::conflict::{Conflict, ConflictId};
 use crate::derivation::{DerivationId, DerivationRecord};
-use crate::entity::{Entity, EntityId};
+use crate::entity::{Entity, EntityId, EntityType};
+use crate::inference::EvidenceCombination;
 use crate::pattern::{Pattern, PatternId};
+use crate::source::Source;
 use crate::time::TimeRange;
+use crate::value::Value;
 
 /// Errors that can occur during storage operations.
 #[derive(Debug, Error)]
@@ -36,6 +43,10 @@ pub enum StorageError {
     #[error("Pattern not found: {0}")]
     PatternNotFound(PatternId),
 
+    /// Derivation record not found.
+    #[error("Derivation not found: {0}")]
+    DerivationNotFound(DerivationId),
+
     /// Key already exists.
     #[error("Duplicate key: {0}")]
     DuplicateKey(String),
@@ -53,8 +64,152 @@ pub enum StorageError {
     ConnectionError(String),
 }
 
+/// Record counts reported by a store's `stats()`.
+///
+/// Counts come from the store's indexes; nothing is cloned to compute them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StorageStats {
+    /// Number of records held (for beliefs, superseded ones included).
+    pub records: usize,
+    /// Distinct subject entities referenced (belief stores only).
+    pub distinct_entities: Option<usize>,
+    /// Distinct predicates referenced (belief stores only).
+    pub distinct_predicates: Option<usize>,
+}
+
+/// An in-place correction of a belief's non-semantic fields, applied by [`BeliefStore::amend`].
+///
+/// Only `reason`, `source`, `corroborating_sources` and `confidence` can be amended; a
+/// confidence change, including one made by `corroboration`, must carry an `audit_note`.
+/// `value` and `predicate` are accepted so that attempts to change them fail loudly: changing
+/// what a belief asserts requires superseding it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AmendFields {
+    /// Replacement audit reason.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Replacement provenance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    /// Replacement list of corroborating sources.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corroborating_sources: Option<Vec<Source>>,
+    /// Replacement confidence; requires `audit_note`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<Confidence>,
+    /// Another source asserting the belief, appended under the store's lock so concurrent
+    /// corroborations cannot drop each other; requires `audit_note`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corroboration: Option<Corroboration>,
+    /// Why the belief was amended.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_note: Option<String>,
+    /// Always rejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    /// Always rejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+}
+
+impl AmendFields {
+    /// Reject semantic changes, empty amendments and unexplained confidence changes.
+    pub(crate) fn validate(&self) -> Result<(), StorageError> {
+        if self.value.is_some() || self.predicate.is_some() {
+            return Err(StorageError::BackendError(
+                "cannot amend a belief's value or predicate; supersede it instead".to_string(),
+            ));
+        }
+        if self.reason.is_none()
+            && self.source.is_none()
+            && self.corroborating_sources.is_none()
+            && self.confidence.is_none()
+            && self.corroboration.is_none()
+        {
+            return Err(StorageError::BackendError("belief amendment changes nothing".to_string()));
+        }
+        if (self.confidence.is_some() || self.corroboration.is_some())
+            && self.audit_note.as_deref().is_none_or(|n| n.trim().is_empty())
+        {
+            return Err(StorageError::BackendError(
+                "amending a belief's confidence requires an audit note".to_string(),
+            ));
+        }
+        if self
+            .corroboration
+            .as_ref()
+            .is_some_and(|c| !(0.0..=1.0).contains(&c.confidence))
+        {
+            return Err(StorageError::BackendError(
+                "corroborating confidence must be within [0.0, 1.0]".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Overwrite the amended fields of `belief`. Call [`AmendFields::validate`] first.
+    pub(crate) fn apply(&self, belief: &mut Belief) {
+        if let Some(reason) = &self.reason {
+            belief.reason = Some(reason.clone());
+        }
+        if let Some(source) = &self.source {
+            belief.source = source.clone();
+        }
+        if let Some(sources) = &self.corroborating_sources {
+            belief.corroborating_sources = sources.clone();
+        }
+        if let Some(confidence) = &self.confidence {
+            belief.confidence = confidence.clone();
+        }
+        if let Some(corroboration) = &self.corroboration {
+            corroboration.apply(belief);
+        }
+    }
+}
+
+/// A source that independently asserted an existing belief, folded in by
+/// [`AmendFields::corroboration`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Corroboration {
+    /// The corroborating source.
+    pub source: Source,
+    /// The confidence that source asserted the belief with.
+    pub confidence: f32,
+    /// Idempotency key of the ASSERT that was folded in, recovered with the corroboration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+}
+
+impl Corroboration {
+    /// Append the source and noisy-OR its confidence into the belief's. A source the belief
+    /// already counts is ignored, so replaying a corroboration is harmless.
+    fn apply(&self, belief: &mut Belief) {
+        let source_id = self.source.source_id();
+        if belief.sources().any(|s| s.source_id() == source_id) {
+            return;
+        }
+        belief.corroborating_sources.push(self.source.clone());
+        let merged = EvidenceCombination::aggregate([belief.confidence.value(), self.confidence]);
+        let source_ids = belief.sources().map(Source::source_id).collect();
+        if let Ok(confidence) = Confidence::heuristic(
+            merged,
+            ConfidenceSource::AggregatedFromSources {
+                source_ids,
+                aggregation_method: "noisy_or".to_string(),
+            },
+        ) {
+            belief.confidence = confidence;
+        }
+    }
+}
+
 /// Storage trait for Entity operations.
 ///
+/// # Namespaces
+/// Lookups not keyed by id (`find_by_name`, `find_by_name_fuzzy`, `find_by_embedding`) only
+/// return entities of the given namespace; `None` is the default namespace. Entities cannot
+/// change namespace on update, and entities of different namespaces cannot be merged.
+///
 /// # Safety Considerations
 /// - All mutations should be atomic where possible
 /// - Implementations should handle concurrent access safely
@@ -62,6 +217,21 @@ pub trait EntityStore: Send + Sync {
     /// Insert a new entity. Returns error if ID already exists.
     fn insert(&self, entity: Entity) -> Result<(), StorageError>;
 
+    /// Insert a batch of entities, returning a map of input id → canonical id.
+    ///
+    /// With `dedup_by_name`, an entity whose normalized canonical name matches an earlier entry
+    /// in the batch (or an entity already stored) is folded into that entity instead of being
+    /// inserted: aliases and metadata are unioned and embeddings averaged, as in `merge`.
+    /// Without it, every entity is inserted under its own id.
+    ///
+    /// The batch is validated up front; if any entry fails (duplicate id, embedding dimension
+    /// mismatch) nothing is written.
+    fn insert_many(
+        &self,
+        entities: Vec<Entity>,
+        dedup_by_name: bool,
+    ) -> Result<HashMap<EntityId, EntityId>, StorageError>;
+
     /// Get an entity by ID.
     fn get(&self, id: EntityId) -> Result<Option<Entity>, StorageError>;
 
@@ -71,15 +241,34 @@ pub trait EntityStore: Send + Sync {
     /// Delete an entity by ID. Returns error if not found.
     fn delete(&self, id: EntityId) -> Result<(), StorageError>;
 
-    /// Find entities by canonical name (exact match).
-    fn find_by_name(&self, name: &str) -> Result<Vec<Entity>, StorageError>;
+    /// Find entities in `namespace` by canonical name (exact match).
+    fn find_by_name(&self, namespace: Option<&str>, name: &str) -> Result<Vec<Entity>, StorageError>;
+
+    /// Find entities of `entity_type`, in every namespace, sorted by canonical name.
+    ///
+    /// With `include_subtypes`, entities of its subtypes in the store's configured
+    /// [`EntityTypeHierarchy`](crate::entity::EntityTypeHierarchy) match too. Merged-away
+    /// entities are never returned.
+    fn find_by_type(&self, entity_type: &EntityType, include_subtypes: bool) -> Result<Vec<Entity>, StorageError>;
 
-    /// Find entities by name (fuzzy/prefix match).
-    fn find_by_name_fuzzy(&self, query: &str, limit: usize) -> Result<Vec<Entity>, StorageError>;
+    /// Every entity, in every namespace, sorted by canonical name.
+    ///
+    /// Merged-away entities are never returned. Meant for offline scans such as
+    /// [`MetaAnalyzer::suggest_merges`](crate::meta::MetaAnalyzer::suggest_merges).
+    fn list(&self) -> Result<Vec<Entity>, StorageError>;
 
-    /// Find entities by embedding similarity (requires vector index).
+    /// Find entities in `namespace` by name (fuzzy/prefix match).
+    fn find_by_name_fuzzy(
+        &self,
+        namespace: Option<&str>,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<Entity>, StorageError>;
+
+    /// Find entities in `namespace` by embedding similarity (requires vector index).
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
     ) -> Result<Vec<(Entity, f32)>, StorageError>;
@@ -96,6 +285,25 @@ pub trait EntityStore: Send + Sync {
     /// - `BackendError`: If `primary == secondary` or other merge conflicts occur
     fn merge(&self, primary: EntityId, secondary: EntityId) -> Result<Entity, StorageError>;
 
+    /// Undo a previous `merge(primary, secondary)`, returning `(primary, secondary)`.
+    ///
+    /// The redirect from `secondary` is removed, the aliases and metadata the secondary
+    /// contributed are stripped from the primary (per the provenance recorded at merge time),
+    /// and the secondary is restored from its last pre-merge version. Both entities get a new
+    /// version.
+    ///
+    /// # Errors
+    /// - `EntityNotFound`: If `primary` does not exist or the secondary's history is missing
+    /// - `BackendError`: If `secondary` is not directly merged into `primary`, or the merge
+    ///   predates provenance tracking
+    fn unmerge(&self, primary: EntityId, secondary: EntityId) -> Result<(Entity, Entity), StorageError>;
+
+    /// IDs that beliefs about `id`'s canonical entity may carry as their subject.
+    ///
+    /// Returns the canonical ID first, followed by every entity merged into it (directly or
+    /// transitively). An unknown ID yields just itself.
+    fn merged_ids(&self, id: EntityId) -> Result<Vec<EntityId>, StorageError>;
+
     /// Retrieve the entity snapshot for an exact version.
     ///
     /// Versions start at 1 on insert and increment on every update/merge. Implementations should
@@ -105,6 +313,9 @@ pub trait EntityStore: Send + Sync {
 
     /// List all stored versions for an entity (ascending by version).
     fn list_versions(&self, id: EntityId) -> Result<Vec<Entity>, StorageError>;
+
+    /// Report record counts. Merged-away entities are not counted.
+    fn stats(&self) -> Result<StorageStats, StorageError>;
 }
 
 /// Storage trait for Belief operations.
@@ -112,6 +323,10 @@ pub trait EntityStore: Send + Sync {
 /// # Bitemporal Semantics
 /// - `valid_time`: When the belief is/was true in the world
 /// - `tx_time`: When the belief was recorded in the system
+///
+/// # Namespaces
+/// Lookups by entity are implicitly scoped, since an entity lives in one namespace.
+/// `find_by_time_range` and `find_by_embedding` only return beliefs of the given namespace.
 pub trait BeliefStore: Send + Sync {
     /// Insert a new belief. Returns error if ID already exists.
     fn insert(&self, belief: Belief) -> Result<(), StorageError>;
@@ -119,8 +334,46 @@ pub trait BeliefStore: Send + Sync {
     /// Get a belief by ID.
     fn get(&self, id: BeliefId) -> Result<Option<Belief>, StorageError>;
 
-    /// Mark a belief as superseded by another.
-    fn supersede(&self, old_id: BeliefId, new_id: BeliefId) -> Result<(), StorageError>;
+    /// Mark a belief as superseded by another; shorthand for a one-belief [`Self::supersede_all`].
+    fn supersede(&self, old_id: BeliefId, new_id: BeliefId) -> Result<(), StorageError> {
+        self.supersede_all(&[old_id], new_id)
+    }
+
+    /// Mark every belief in `old_ids` as superseded by `new_id`, closing their valid time at
+    /// `new_id`'s transaction time.
+    ///
+    /// A belief's predecessors are linked in one call: this fails if `new_id` already
+    /// supersedes a different set of beliefs or if one of `old_ids` is already superseded by
+    /// another belief. Repeating a call is a no-op.
+    fn supersede_all(&self, old_ids: &[BeliefId], new_id: BeliefId) -> Result<(), StorageError>;
+
+    /// Insert `belief` as the successor of every belief in `old_ids`, in one step.
+    ///
+    /// Fails, writing nothing, if `belief`'s id exists or one of `old_ids` is missing or
+    /// already superseded, so that of two concurrent successors of the same belief only one
+    /// is stored. The default, an [`insert`](Self::insert) followed by
+    /// [`supersede_all`](Self::supersede_all), is not atomic; stores shared between writers
+    /// override it.
+    fn insert_superseding(&self, belief: Belief, old_ids: &[BeliefId]) -> Result<(), StorageError> {
+        let new_id = belief.id;
+        self.insert(belief)?;
+        self.supersede_all(old_ids, new_id)
+    }
+
+    /// Correct a belief's non-semantic fields in place, without creating a new version.
+    ///
+    /// Fails if `fields` does not pass [`AmendFields`] validation.
+    fn amend(&self, id: BeliefId, fields: AmendFields) -> Result<(), StorageError>;
+
+    /// Find beliefs, in any namespace, whose superseding belief was recorded before `before`.
+    fn find_superseded(&self, before: DateTime<Utc>) -> Result<Vec<Belief>, StorageError>;
+
+    /// Permanently remove a belief and its index entries.
+    ///
+    /// Meant for retention pruning of superseded history; corrections should supersede instead.
+    /// The belief's successor stops listing it in `supersedes`; a predecessor's `superseded_by`
+    /// is left pointing at it.
+    fn delete(&self, id: BeliefId) -> Result<(), StorageError>;
 
     /// Find all beliefs for an entity (any predicate).
     fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Belief>, StorageError>;
@@ -132,6 +385,39 @@ pub trait BeliefStore: Send + Sync {
         predicate: &str,
     ) -> Result<Vec<Belief>, StorageError>;
 
+    /// Every belief ever recorded for an entity and predicate, oldest first by `tx_time`.
+    ///
+    /// Unlike [`find_as_of`](Self::find_as_of) there is no validity filter: superseded and
+    /// expired beliefs are included, so the result is the full revision history.
+    fn history(&self, entity_id: EntityId, predicate: &str) -> Result<Vec<Belief>, StorageError>;
+
+    /// Merge back-to-back versions of `predicate` that assert the same thing, returning how
+    /// many beliefs were removed.
+    ///
+    /// Each run of identical beliefs (same value, sources, confidence, embedding, reason and
+    /// metadata) whose valid times meet end to start becomes its earliest belief, widened to
+    /// cover the run and linked to the run's successor. [`find_as_of`](Self::find_as_of) answers
+    /// are unchanged at every instant except that the earliest belief's id stands in for the
+    /// removed ones.
+    /// Contested beliefs are never merged, and beliefs in `pinned` are never removed (they may
+    /// still absorb later ones). Callers pin the beliefs that records outside the store, such
+    /// as conflicts and derivations, refer to; see `KyroEngine::coalesce`.
+    fn coalesce(
+        &self,
+        entity_id: EntityId,
+        predicate: &str,
+        pinned: &HashSet<BeliefId>,
+    ) -> Result<usize, StorageError>;
+
+    /// Replace the embedding of every belief that has one with `embed(belief)`, returning how
+    /// many were replaced.
+    ///
+    /// This is the migration path when switching embedding models: the new embeddings may
+    /// have another dimension, which becomes the store's dimension. It is all or nothing;
+    /// if `embed` fails, or the new embeddings are not finite or disagree in dimension,
+    /// nothing changes. Writes wait until it finishes.
+    fn reembed(&self, embed: &dyn Fn(&Belief) -> Result<Vec<f32>, StorageError>) -> Result<usize, StorageError>;
+
     /// Find beliefs valid at a specific time (AS OF query).
     fn find_as_of(
         &self,
@@ -140,19 +426,52 @@ pub trait BeliefStore: Send + Sync {
         as_of: DateTime<Utc>,
     ) -> Result<Vec<Belief>, StorageError>;
 
-    /// Find beliefs within a time range.
-    fn find_by_time_range(&self, range: &TimeRange) -> Result<Vec<Belief>, StorageError>;
+    /// Find beliefs in `namespace` within a time range.
+    fn find_by_time_range(
+        &self,
+        namespace: Option<&str>,
+        range: &TimeRange,
+    ) -> Result<Vec<Belief>, StorageError>;
 
-    /// Find beliefs by embedding similarity (semantic search).
+    /// Find beliefs in `namespace` by embedding similarity (semantic search).
     fn find_by_embedding(
         &self,
+        namespace: Option<&str>,
         embedding: &[f32],
         limit: usize,
         min_confidence: Option<f32>,
     ) -> Result<Vec<(Belief, f32)>, StorageError>;
 
+    /// Find entities in `namespace` with a belief `predicate = value` valid at `as_of`.
+    ///
+    /// Values match by [`Value::stable_encoding`], so `-0.0` matches `0.0` and NaN matches NaN.
+    /// Returns each entity once.
+    fn find_entities_by_predicate_value(
+        &self,
+        namespace: Option<&str>,
+        predicate: &str,
+        value: &Value,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<EntityId>, StorageError>;
+
     /// Count beliefs for an entity.
     fn count_by_entity(&self, entity_id: EntityId) -> Result<usize, StorageError>;
+
+    /// Every entity that is the subject of at least one stored belief, in any namespace.
+    ///
+    /// Subjects are returned as stored, so merged-away and deleted entities are included.
+    fn subjects(&self) -> Result<Vec<EntityId>, StorageError>;
+
+    /// Report record counts, including distinct subject entities and predicates.
+    fn stats(&self) -> Result<StorageStats, StorageError>;
+
+    /// A copy of the store as it is now, unaffected by later writes to this store.
+    ///
+    /// Stores that can take such a copy cheaply return it; writes to the copy do not reach
+    /// this store. The default returns `None`, meaning snapshots are not supported.
+    fn snapshot(&self) -> Result<Option<Arc<dyn BeliefStore>>, StorageError> {
+        Ok(None)
+    }
 }
 
 /// Storage trait for Conflict operations.
@@ -166,11 +485,33 @@ pub trait ConflictStore: Send + Sync {
     /// Update conflict status/resolution.
     fn update(&self, conflict: Conflict) -> Result<(), StorageError>;
 
+    /// Update a conflict only if the stored one is still open; returns whether it was
+    /// written. Two resolutions of one conflict cannot both succeed.
+    ///
+    /// The default reads and then updates, so it is not atomic; stores that can hold a
+    /// lock across both should override it.
+    fn update_if_open(&self, conflict: Conflict) -> Result<bool, StorageError> {
+        let current = self
+            .get(conflict.id)?
+            .ok_or(StorageError::ConflictNotFound(conflict.id))?;
+        if !current.is_open() {
+            return Ok(false);
+        }
+        self.update(conflict)?;
+        Ok(true)
+    }
+
     /// Find conflicts involving a specific belief.
     fn find_by_belief(&self, belief_id: BeliefId) -> Result<Vec<Conflict>, StorageError>;
 
-    /// Find all open (unresolved) conflicts.
+    /// Find all open (unresolved) conflicts, most severe first.
     fn find_open(&self) -> Result<Vec<Conflict>, StorageError>;
+
+    /// Find all conflicts (any status) recorded against an entity.
+    fn find_by_entity(&self, entity_id: EntityId) -> Result<Vec<Conflict>, StorageError>;
+
+    /// Report record counts.
+    fn stats(&self) -> Result<StorageStats, StorageError>;
 }
 
 /// Storage trait for Pattern operations.
@@ -190,8 +531,13 @@ pub trait PatternStore: Send + Sync {
     /// Find patterns that apply to a specific predicate.
     fn find_by_predicate(&self, predicate: &str) -> Result<Vec<Pattern>, StorageError>;
 
-    /// Find all active patterns.
+    /// Find all patterns in force now: `active` and with `valid_time` containing the current time.
+    ///
+    /// Future-dated patterns are excluded until their `valid_time` starts.
     fn find_active(&self) -> Result<Vec<Pattern>, StorageError>;
+
+    /// Report record counts.
+    fn stats(&self) -> Result<StorageStats, StorageError>;
 }
 
 /// Storage trait for derivation records.
@@ -204,6 +550,10 @@ pub trait DerivationStore: Send + Sync {
     /// Get a derivation record by ID.
     fn get(&self, id: DerivationId) -> Result<Option<DerivationRecord>, StorageError>;
 
+    /// Replace an existing derivation record, e.g. after recomputing its propagated
+    /// confidence. Returns error if not found.
+    fn update(&self, record: DerivationRecord) -> Result<(), StorageError>;
+
     /// Find derivations that cite a given premise belief.
     fn find_by_premise(&self, premise_id: BeliefId) -> Result<Vec<DerivationRecord>, StorageError>;
 
@@ -212,6 +562,44 @@ pub trait DerivationStore: Send + Sync {
         &self,
         derived_belief_id: BeliefId,
     ) -> Result<Vec<DerivationRecord>, StorageError>;
+
+    /// Report record counts.
+    fn stats(&self) -> Result<StorageStats, StorageError>;
+}
+
+/// Outcome of [`IdempotencyStore::reserve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdempotencyReservation {
+    /// The key was unclaimed and is now held by the caller until it is recorded or released.
+    Reserved,
+    /// The key already produced this belief.
+    Existing(BeliefId),
+}
+
+/// Storage trait for ASSERT idempotency keys.
+///
+/// Maps a client-chosen key to the belief it produced. Keys are scoped by tenant namespace
+/// (`None` is the default namespace), so equal keys of different tenants never collide.
+/// Implementations are expected to stay bounded and may forget the oldest keys.
+pub trait IdempotencyStore: Send + Sync {
+    /// Get the belief recorded for a key, if it is still retained.
+    fn get(&self, namespace: Option<&str>, key: &str) -> Result<Option<BeliefId>, StorageError>;
+
+    /// Atomically claim a key before the write it guards.
+    ///
+    /// A key reserved by another caller that has neither recorded nor released it yet fails
+    /// with [`StorageError::DuplicateKey`], so concurrent retries cannot both write.
+    fn reserve(&self, namespace: Option<&str>, key: &str) -> Result<IdempotencyReservation, StorageError>;
+
+    /// Drop a reservation whose write failed, so the key can be retried.
+    fn release(&self, namespace: Option<&str>, key: &str) -> Result<(), StorageError>;
+
+    /// Record the belief produced for a key, completing its reservation if there is one.
+    /// Recording an existing key is a no-op.
+    fn record(&self, namespace: Option<&str>, key: &str, belief_id: BeliefId) -> Result<(), StorageError>;
+
+    /// Report record counts.
+    fn stats(&self) -> Result<StorageStats, StorageError>;
 }
 
 #[cfg(test)]
@@ -224,6 +612,7 @@ mod tests {
     fn _assert_conflict_store_object_safe(_: &dyn ConflictStore) {}
     fn _assert_pattern_store_object_safe(_: &dyn PatternStore) {}
     fn _assert_derivation_store_object_safe(_: &dyn DerivationStore) {}
+    fn _assert_idempotency_store_object_safe(_: &dyn IdempotencyStore) {}
 
     #[test]
     fn test_storage_error_display() {
@@ -0,0 +1,284 @@
+//! Authentication and per-call authorization for the gRPC service.
+//!
+//! [`AuthInterceptor`] maps the API key a client sends, as `authorization: Bearer <key>` or
+//! `x-api-key: <key>` metadata, to a [`Principal`] and attaches it to the request. A service
+//! configured with [`KyroServiceImpl::with_authorizer`](super::KyroServiceImpl::with_authorizer)
+//! then rejects calls without a principal (`UNAUTHENTICATED`) and asks the [`Authorizer`]
+//! about every operation they carry (`PERMISSION_DENIED` when refused). A monitor
+//! subscription may only be resumed or acknowledged by the principal that created it, and
+//! resuming checks its MONITOR operation again. Likewise only the principal that created a
+//! simulation may use, fork, commit or close it. Without an authorizer the service accepts
+//! every call.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tonic::metadata::MetadataMap;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use crate::confidence::BeliefId;
+use crate::engine::KyroEngine;
+use crate::entity::EntityId;
+use crate::ir::{Operation, ResolvePayload};
+use crate::source::Source;
+
+use super::{status_from_kyro_error, status_from_storage_error};
+
+/// Metadata key carrying a bare API key.
+pub const API_KEY_METADATA: &str = "x-api-key";
+
+/// An authenticated caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    /// Identity the caller acts as. RETRACT must be `authorized_by` an agent or human with
+    /// this id.
+    pub id: String,
+    /// Limits the caller to operations that do not write.
+    pub read_only: bool,
+}
+
+impl Principal {
+    /// A principal that may read and write.
+    #[must_use]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            read_only: false,
+        }
+    }
+
+    /// A principal limited to reads.
+    #[must_use]
+    pub fn read_only(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            read_only: true,
+        }
+    }
+
+    /// Returns `true` if `source` is an agent or human source carrying this principal's id.
+    #[must_use]
+    pub fn is(&self, source: &Source) -> bool {
+        match source {
+            Source::Agent { agent_id: id, .. } | Source::Human { user_id: id, .. } => *id == self.id,
+            _ => false,
+        }
+    }
+}
+
+/// Decides whether a principal may perform an operation.
+pub trait Authorizer: Send + Sync {
+    /// Returns `true` if `principal` may perform `operation`.
+    ///
+    /// `entity_id` is the entity the operation targets, when it targets one: the subject of
+    /// an ASSERT, the entity of a RESOLVE (named by `entity_id` or resolved from its query),
+    /// or the subject of the belief a RETRACT or FEEDBACK refers to. An operation reaching
+    /// several entities is asked about once per entity and runs only if every one is allowed:
+    /// each entity of a multi-entity RESOLVE, and each entity a compound RESOLVE matches.
+    /// The operations of a TRANSACTION are authorized one by one, and so are the ASSERTs
+    /// that commit a simulation's hypotheticals. Reading a derivation is asked as a RESOLVE
+    /// of the subject and predicate of each belief the derivation links.
+    fn authorize(&self, principal: &Principal, operation: &Operation, entity_id: Option<EntityId>) -> bool;
+
+    /// Returns `true` if `principal` may commit a simulation into the engine.
+    fn authorize_commit(&self, principal: &Principal) -> bool {
+        !principal.read_only
+    }
+}
+
+/// Lets read-only principals perform reads only, and every other principal anything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AccessAuthorizer;
+
+impl Authorizer for AccessAuthorizer {
+    fn authorize(&self, principal: &Principal, operation: &Operation, _entity_id: Option<EntityId>) -> bool {
+        !principal.read_only || !writes(operation)
+    }
+}
+
+/// Returns `true` if `operation` can change stored state.
+///
+/// SIMULATE and MONITOR only read; committing a simulation is authorized separately.
+#[must_use]
+pub fn writes(operation: &Operation) -> bool {
+    operation.writes()
+}
+
+/// API keys accepted by [`AuthInterceptor`] and the principals they authenticate.
+#[derive(Clone, Default)]
+pub struct ApiKeys {
+    keys: HashMap<String, Principal>,
+}
+
+impl ApiKeys {
+    /// An empty key set, which rejects every call.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept `key` as `principal`, replacing any principal it was mapped to before.
+    #[must_use]
+    pub fn with_key(mut self, key: impl Into<String>, principal: Principal) -> Self {
+        self.keys.insert(key.into(), principal);
+        self
+    }
+
+    /// Returns the principal for the key in `metadata`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UNAUTHENTICATED` if no key is present or the key is unknown.
+    pub fn authenticate(&self, metadata: &MetadataMap) -> Result<Principal, Status> {
+        let bearer = metadata
+            .get("authorization")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        let key = bearer
+            .or_else(|| metadata.get(API_KEY_METADATA).and_then(|v| v.to_str().ok()))
+            .map(str::trim)
+            .ok_or_else(|| Status::unauthenticated("missing API key"))?;
+        self.keys
+            .get(key)
+            .cloned()
+            .ok_or_else(|| Status::unauthenticated("invalid API key"))
+    }
+}
+
+impl std::fmt::Debug for ApiKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the keys themselves.
+        f.debug_struct("ApiKeys").field("keys", &self.keys.len()).finish()
+    }
+}
+
+/// Interceptor attaching the [`Principal`] authenticated by [`ApiKeys`] to each request.
+#[derive(Debug, Clone)]
+pub struct AuthInterceptor {
+    keys: Arc<ApiKeys>,
+}
+
+impl AuthInterceptor {
+    /// Authenticate calls against `keys`.
+    #[must_use]
+    pub fn new(keys: ApiKeys) -> Self {
+        Self { keys: Arc::new(keys) }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let principal = self.keys.authenticate(request.metadata())?;
+        request.extensions_mut().insert(principal);
+        Ok(request)
+    }
+}
+
+/// The principal behind one RPC, with the authorizer that vets what it runs.
+#[derive(Clone)]
+pub(crate) struct Caller {
+    pub(crate) authorizer: Arc<dyn Authorizer>,
+    pub(crate) principal: Principal,
+}
+
+impl Caller {
+    /// Authorize `operation`, and each operation inside it for a TRANSACTION.
+    pub(crate) fn check(&self, engine: &KyroEngine, operation: &Operation) -> Result<(), Status> {
+        if let Operation::Transaction(operations) = operation {
+            return operations.iter().try_for_each(|op| self.check(engine, op));
+        }
+        if let Operation::Retract(payload) = operation {
+            if !self.principal.is(&payload.authorized_by) {
+                return Err(Status::permission_denied(format!(
+                    "retract must be authorized_by the authenticated principal '{}'",
+                    self.principal.id
+                )));
+            }
+        }
+        let entity_ids = target_entities(engine, operation)?;
+        let allowed = if entity_ids.is_empty() {
+            self.authorizer.authorize(&self.principal, operation, None)
+        } else {
+            entity_ids
+                .iter()
+                .all(|id| self.authorizer.authorize(&self.principal, operation, Some(*id)))
+        };
+        if allowed {
+            Ok(())
+        } else {
+            Err(self.denied())
+        }
+    }
+
+    /// Returns `true` if the principal may read each of `belief_ids`, as a RESOLVE of the
+    /// belief's subject and predicate. Unknown beliefs are skipped.
+    pub(crate) fn may_read(&self, engine: &KyroEngine, belief_ids: &[BeliefId]) -> Result<bool, Status> {
+        for &belief_id in belief_ids {
+            let Some(belief) = engine.belief_store().get(belief_id).map_err(status_from_storage_error)? else {
+                continue;
+            };
+            let read = Operation::Resolve(ResolvePayload {
+                entity_id: Some(belief.subject),
+                predicate: Some(belief.predicate),
+                namespace: belief.namespace,
+                ..ResolvePayload::default()
+            });
+            if !self.authorizer.authorize(&self.principal, &read, Some(belief.subject)) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Authorize reading `belief_ids`; see [`may_read`](Self::may_read).
+    pub(crate) fn check_read(&self, engine: &KyroEngine, belief_ids: &[BeliefId]) -> Result<(), Status> {
+        if self.may_read(engine, belief_ids)? {
+            Ok(())
+        } else {
+            Err(self.denied())
+        }
+    }
+
+    /// Authorize committing a simulation.
+    pub(crate) fn check_commit(&self) -> Result<(), Status> {
+        if self.authorizer.authorize_commit(&self.principal) {
+            Ok(())
+        } else {
+            Err(self.denied())
+        }
+    }
+
+    fn denied(&self) -> Status {
+        Status::permission_denied(format!(
+            "principal '{}' is not authorized for this operation",
+            self.principal.id
+        ))
+    }
+}
+
+/// The entities `operation` targets; see [`Authorizer::authorize`].
+fn target_entities(engine: &KyroEngine, operation: &Operation) -> Result<Vec<EntityId>, Status> {
+    let belief_id = match operation {
+        Operation::Assert(payload) => return Ok(vec![payload.entity_id]),
+        Operation::Resolve(payload) => {
+            let target = engine.resolve_target(payload).map_err(status_from_kyro_error)?;
+            return Ok(target.into_iter().collect());
+        }
+        Operation::ResolveMulti(payload) => return Ok(payload.entity_ids.clone()),
+        Operation::ResolveCompound(payload) => {
+            return engine.compound_matches(payload).map_err(status_from_kyro_error);
+        }
+        Operation::Retract(payload) => payload.belief_id,
+        Operation::Feedback(payload) => payload.belief_id,
+        _ => return Ok(Vec::new()),
+    };
+    // An unknown belief is reported by the engine once the call is allowed to run.
+    Ok(engine
+        .belief_store()
+        .get(belief_id)
+        .map_err(status_from_storage_error)?
+        .map(|belief| belief.subject)
+        .into_iter()
+        .collect())
+}
@@ -3,29 +3,45 @@
 //! Vision constraint: the canonical protocol surface is `KyroIR`.
 //! This transport therefore carries `KyroIR` as JSON bytes and returns
 //! JSON-serialized response objects.
+//!
+//! Authentication and authorization are opt-in; see [`auth`].
+
+// `tonic::Status` is large by design; boxing it everywhere buys nothing here.
+#![allow(clippy::result_large_err)]
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use serde::Serialize;
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock, Semaphore};
+use tokio::task::JoinHandle;
 use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
 use tonic::{Request, Response, Status};
 use std::time::Duration;
 
 use crate::belief::{Belief, ConsistencyStatus};
 use crate::confidence::BeliefId;
+use crate::derivation::{DerivationId, DerivationRecord};
 use crate::engine::{EngineResponse, KyroEngine};
-use crate::error::{ExecutionError, KyroError, ValidationError};
+use crate::error::{ExecutionError, KyroError, TransportError, ValidationError};
+use crate::frame::BeliefFrame;
 use crate::ir::{ConsistencyMode, KyroIR, Operation};
-use crate::monitor::MonitorStream;
-use crate::simulation::{SimulationCommitResult, SimulationContext, SimulationImpact};
+use crate::monitor::{MonitorStream, SubscriptionId};
+use crate::simulation::{SimulationCommitMode, SimulationCommitResult, SimulationContext, SimulationImpact};
+use crate::storage::StorageError;
+
+pub mod auth;
 
 pub mod proto {
     tonic::include_proto!("kyroql");
 }
 
 use proto::kyro_service_server::{KyroService, KyroServiceServer};
+pub use proto::kyro_service_client::KyroServiceClient;
+
+pub use auth::{AccessAuthorizer, ApiKeys, AuthInterceptor, Authorizer, Principal};
+use auth::Caller;
 
 // ----------------------------------------------------------------------------
 // Limits (DoS protection)
@@ -43,10 +59,45 @@ const MAX_EVENT_JSON_BYTES: usize = 1024 * 1024; // 1 MiB
 /// In-memory simulation registry cap (server-side safety).
 const MAX_OPEN_SIMULATIONS: usize = 4096;
 
+/// Cap on monitor subscriptions whose owner the server tracks (server-side safety).
+const MAX_OWNED_SUBSCRIPTIONS: usize = 4096;
+
+/// Maximum number of ExecuteStream requests executing at once per stream.
+const MAX_STREAM_IN_FLIGHT: usize = 16;
+
 /// gRPC service implementation for KyroQL.
 pub struct KyroServiceImpl {
     engine: Arc<KyroEngine>,
-    simulations: RwLock<HashMap<uuid::Uuid, Arc<SimulationContext>>>,
+    simulations: RwLock<HashMap<uuid::Uuid, OpenSimulation>>,
+    authorizer: Option<Arc<dyn Authorizer>>,
+    /// Who created each monitor subscription, when calls are authorized.
+    subscriptions: RwLock<HashMap<SubscriptionId, SubscriptionOwner>>,
+}
+
+/// A simulation the server holds open, with the principal that created it when calls are
+/// authorized.
+struct OpenSimulation {
+    simulation: Arc<SimulationContext>,
+    owner: Option<Principal>,
+}
+
+impl OpenSimulation {
+    /// Reject `caller` unless it created this simulation.
+    fn check_owner(&self, caller: Option<&Caller>) -> Result<(), Status> {
+        match (caller, &self.owner) {
+            (Some(caller), Some(owner)) if owner.id != caller.principal.id => Err(Status::permission_denied(format!(
+                "simulation belongs to another principal than '{}'",
+                caller.principal.id
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The principal that created a monitor subscription and the MONITOR it ran.
+struct SubscriptionOwner {
+    principal: Principal,
+    operation: Operation,
 }
 
 impl KyroServiceImpl {
@@ -55,34 +106,154 @@ impl KyroServiceImpl {
         Self {
             engine,
             simulations: RwLock::new(HashMap::new()),
+            authorizer: None,
+            subscriptions: RwLock::new(HashMap::new()),
         }
     }
 
+    /// Require an authenticated [`Principal`] on every call and check each operation with
+    /// `authorizer`. Serve with [`into_server_with_auth`](Self::into_server_with_auth) so
+    /// that requests carry a principal.
+    #[must_use]
+    pub fn with_authorizer(mut self, authorizer: Arc<dyn Authorizer>) -> Self {
+        self.authorizer = Some(authorizer);
+        self
+    }
+
     #[must_use]
     pub fn into_server(self) -> KyroServiceServer<Self> {
         KyroServiceServer::new(self)
     }
+
+    /// Serve behind an [`AuthInterceptor`] that authenticates callers against `keys`.
+    #[must_use]
+    pub fn into_server_with_auth(
+        self,
+        keys: ApiKeys,
+    ) -> tonic::service::interceptor::InterceptedService<KyroServiceServer<Self>, AuthInterceptor> {
+        KyroServiceServer::with_interceptor(self, AuthInterceptor::new(keys))
+    }
+
+    /// The caller of `request`, or `None` when the service does not authorize calls.
+    fn caller<T>(&self, request: &Request<T>) -> Result<Option<Caller>, Status> {
+        let Some(authorizer) = &self.authorizer else {
+            return Ok(None);
+        };
+        let principal = request
+            .extensions()
+            .get::<Principal>()
+            .cloned()
+            .ok_or_else(|| Status::unauthenticated("missing API key"))?;
+        Ok(Some(Caller {
+            authorizer: Arc::clone(authorizer),
+            principal,
+        }))
+    }
+
+    /// Remember that `caller` created `subscription_id` by running `operation`.
+    ///
+    /// When the registry is full, subscriptions the monitor system no longer holds are
+    /// forgotten first.
+    async fn record_owner(
+        &self,
+        caller: &Caller,
+        subscription_id: SubscriptionId,
+        operation: Operation,
+    ) -> Result<(), Status> {
+        let mut owners = self.subscriptions.write().await;
+        if owners.len() >= MAX_OWNED_SUBSCRIPTIONS {
+            let live = self
+                .engine
+                .monitor_system()
+                .subscription_ids()
+                .map_err(status_from_kyro_error)?;
+            owners.retain(|id, _| live.contains(id));
+        }
+        if owners.len() >= MAX_OWNED_SUBSCRIPTIONS {
+            return Err(Status::resource_exhausted("server monitor registry is full"));
+        }
+        owners.insert(
+            subscription_id,
+            SubscriptionOwner {
+                principal: caller.principal.clone(),
+                operation,
+            },
+        );
+        Ok(())
+    }
+
+    /// Reject `caller` unless it created `subscription_id`, returning the MONITOR it ran.
+    async fn check_owner(&self, caller: &Caller, subscription_id: SubscriptionId) -> Result<Operation, Status> {
+        let owners = self.subscriptions.read().await;
+        let owner = owners
+            .get(&subscription_id)
+            .ok_or_else(|| Status::not_found(format!("monitor subscription not found: {subscription_id}")))?;
+        if owner.principal.id != caller.principal.id {
+            return Err(Status::permission_denied(format!(
+                "monitor subscription belongs to another principal than '{}'",
+                caller.principal.id
+            )));
+        }
+        Ok(owner.operation.clone())
+    }
+
+    /// The open simulation `id`, rejecting `caller` unless it created the simulation.
+    async fn simulation(&self, caller: Option<&Caller>, id: uuid::Uuid) -> Result<Arc<SimulationContext>, Status> {
+        let sims = self.simulations.read().await;
+        let open = sims.get(&id).ok_or_else(|| Status::not_found("simulation not found"))?;
+        open.check_owner(caller)?;
+        Ok(Arc::clone(&open.simulation))
+    }
 }
 
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
 enum TransportResponse {
     Assert {
         belief_id: BeliefId,
         conflict_ids: Vec<crate::conflict::ConflictId>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        conflict_warnings: Vec<String>,
     },
     Resolve {
         frame: crate::frame::BeliefFrame,
     },
+    ResolveCompound {
+        frame: crate::frame::CompoundFrame,
+    },
+    ResolveMulti {
+        frames: std::collections::HashMap<crate::entity::EntityId, crate::frame::BeliefFrame>,
+    },
     Retract {
         retraction_belief_id: BeliefId,
     },
     DefinePattern {
         pattern_id: crate::pattern::PatternId,
     },
+    UpdatePattern {
+        pattern_id: crate::pattern::PatternId,
+    },
+    DeactivatePattern {
+        pattern_id: crate::pattern::PatternId,
+    },
+    CheckPatternSet {
+        warnings: Vec<crate::pattern::PatternSetWarning>,
+    },
     Derive {
         derivation_id: crate::derivation::DerivationId,
     },
+    Feedback {
+        source_accuracy: crate::trust::SourceAccuracy,
+    },
+    Transaction {
+        responses: Vec<TransportResponse>,
+    },
+    /// A failed request inside `ExecuteStream`.
+    Error {
+        code: i32,
+        message: String,
+    },
 }
 
 fn invalid_argument(msg: impl Into<String>) -> Status {
@@ -118,18 +289,31 @@ fn encode_json<T: Serialize>(value: &T, max: usize) -> Result<Vec<u8>, Status> {
     Ok(bytes)
 }
 
+/// Map an engine error to a gRPC status.
+///
+/// A [`ValidationError::Multiple`] also carries its individual failures as a JSON array of
+/// `{error_code, message}` objects in the status details.
 fn status_from_kyro_error(err: KyroError) -> Status {
     match err {
+        KyroError::Validation(v) if !v.errors().is_empty() => {
+            let details = serde_json::to_vec(v.errors()).unwrap_or_default();
+            Status::with_details(tonic::Code::InvalidArgument, v.to_string(), details.into())
+        }
         KyroError::Validation(v) => Status::invalid_argument(v.to_string()),
         KyroError::Transport(t) => Status::unavailable(t.to_string()),
         KyroError::Internal { message } => Status::internal(message),
         KyroError::Execution(e) => match e {
             ExecutionError::EntityNotFound { .. }
             | ExecutionError::BeliefNotFound { .. }
-            | ExecutionError::SimulationNotFound { .. } => Status::not_found(e.to_string()),
+            | ExecutionError::SimulationNotFound { .. }
+            | ExecutionError::MonitorNotFound { .. } => Status::not_found(e.to_string()),
 
-            ExecutionError::Timeout { .. } => Status::deadline_exceeded(e.to_string()),
-            ExecutionError::QueueFull { .. } | ExecutionError::SimulationLimitExceeded { .. } => {
+            ExecutionError::Timeout { .. } | ExecutionError::MonitorExpired { .. } => {
+                Status::deadline_exceeded(e.to_string())
+            }
+            ExecutionError::QueueFull { .. }
+            | ExecutionError::SimulationLimitExceeded { .. }
+            | ExecutionError::MonitorOverflow { .. } => {
                 Status::resource_exhausted(e.to_string())
             }
 
@@ -141,6 +325,7 @@ fn status_from_kyro_error(err: KyroError) -> Status {
             | ExecutionError::ConflictResolutionFailed { .. }
             | ExecutionError::SimulationCommitNotAllowed { .. }
             | ExecutionError::SimulationPartialCommit { .. } => Status::failed_precondition(e.to_string()),
+            ExecutionError::TransactionAborted { .. } => Status::aborted(e.to_string()),
 
             ExecutionError::InvalidDerivation { .. } => Status::invalid_argument(e.to_string()),
             ExecutionError::Storage { .. } | ExecutionError::Index { .. } | ExecutionError::Disconnected { .. } => {
@@ -150,23 +335,71 @@ fn status_from_kyro_error(err: KyroError) -> Status {
     }
 }
 
+fn status_from_storage_error(err: StorageError) -> Status {
+    status_from_kyro_error(KyroError::Execution(ExecutionError::Storage {
+        message: err.to_string(),
+    }))
+}
+
+/// The beliefs `record` links: its premises and the belief it derived.
+fn derivation_beliefs(record: &DerivationRecord) -> Vec<BeliefId> {
+    record.premise_ids.iter().copied().chain(record.derived_belief_id).collect()
+}
+
+/// The records of `records` whose every belief `caller` may read.
+fn readable_derivations(
+    engine: &KyroEngine,
+    caller: Option<&Caller>,
+    records: Vec<DerivationRecord>,
+) -> Result<Vec<DerivationRecord>, Status> {
+    let Some(caller) = caller else {
+        return Ok(records);
+    };
+    let mut readable = Vec::with_capacity(records.len());
+    for record in records {
+        if caller.may_read(engine, &derivation_beliefs(&record))? {
+            readable.push(record);
+        }
+    }
+    Ok(readable)
+}
+
+fn encode_derivations(records: &[DerivationRecord]) -> Result<proto::FindDerivationsResponse, Status> {
+    let derivations_json = encode_json(&records, MAX_RESPONSE_JSON_BYTES)?;
+    Ok(proto::FindDerivationsResponse { derivations_json })
+}
+
 fn to_transport_response(resp: EngineResponse) -> Result<TransportResponse, Status> {
     match resp {
         EngineResponse::Assert {
             belief_id,
             conflict_ids,
+            conflict_warnings,
         } => Ok(TransportResponse::Assert {
             belief_id,
             conflict_ids,
+            conflict_warnings,
         }),
         EngineResponse::Resolve { frame } => Ok(TransportResponse::Resolve { frame }),
+        EngineResponse::ResolveCompound { frame } => Ok(TransportResponse::ResolveCompound { frame }),
+        EngineResponse::ResolveMulti { frames } => Ok(TransportResponse::ResolveMulti { frames }),
         EngineResponse::Retract {
             retraction_belief_id,
         } => Ok(TransportResponse::Retract {
             retraction_belief_id,
         }),
         EngineResponse::DefinePattern { pattern_id } => Ok(TransportResponse::DefinePattern { pattern_id }),
+        EngineResponse::UpdatePattern { pattern_id } => Ok(TransportResponse::UpdatePattern { pattern_id }),
+        EngineResponse::DeactivatePattern { pattern_id } => Ok(TransportResponse::DeactivatePattern { pattern_id }),
+        EngineResponse::CheckPatternSet { warnings } => Ok(TransportResponse::CheckPatternSet { warnings }),
         EngineResponse::Derive { derivation_id } => Ok(TransportResponse::Derive { derivation_id }),
+        EngineResponse::Feedback { source_accuracy } => Ok(TransportResponse::Feedback { source_accuracy }),
+        EngineResponse::Transaction { responses } => Ok(TransportResponse::Transaction {
+            responses: responses
+                .into_iter()
+                .map(to_transport_response)
+                .collect::<Result<_, _>>()?,
+        }),
         EngineResponse::Simulate { .. } => Err(Status::invalid_argument(
             "simulate responses are only returned via SimulateCreate",
         )),
@@ -176,6 +409,180 @@ fn to_transport_response(resp: EngineResponse) -> Result<TransportResponse, Stat
     }
 }
 
+/// Execute a single `ExecuteRequest`; shared by `Execute` and `ExecuteStream`.
+fn execute_request(
+    engine: &KyroEngine,
+    caller: Option<&Caller>,
+    req: &proto::ExecuteRequest,
+) -> Result<proto::ExecuteResponse, Status> {
+    let ir = parse_ir(&req.ir_json)?;
+
+    match ir.operation {
+        Operation::Monitor(_) => {
+            return Err(invalid_argument("monitor operation must use Monitor RPC"));
+        }
+        Operation::Simulate(_) => {
+            return Err(invalid_argument("simulate operation must use SimulateCreate RPC"));
+        }
+        _ => {}
+    }
+    if let Some(caller) = caller {
+        caller.check(engine, &ir.operation)?;
+    }
+
+    let resp = engine.execute(ir).map_err(status_from_kyro_error)?;
+    let out = to_transport_response(resp)?;
+    let response_json = encode_json(&out, MAX_RESPONSE_JSON_BYTES)?;
+    Ok(proto::ExecuteResponse { response_json })
+}
+
+/// Decode a `BeliefFrame` received over the transport (the `frame` of a `resolve` response).
+///
+/// Only frames of the current `BeliefFrame::SCHEMA_VERSION` are accepted; older frames
+/// must go through `frame::migrate`, and newer ones need a newer client.
+pub fn decode_belief_frame(bytes: &[u8]) -> Result<BeliefFrame, TransportError> {
+    let deserialize_err = |message: String| TransportError::DeserializationFailed { message };
+    if bytes.len() > MAX_RESPONSE_JSON_BYTES {
+        return Err(deserialize_err("frame JSON exceeds size limit".to_string()));
+    }
+
+    let value: serde_json::Value =
+        serde_json::from_slice(bytes).map_err(|e| deserialize_err(format!("invalid BeliefFrame JSON: {e}")))?;
+    let version = crate::frame::schema_version_of(&value).map_err(|e| deserialize_err(e.to_string()))?;
+    if version != BeliefFrame::SCHEMA_VERSION {
+        return Err(deserialize_err(format!(
+            "unsupported BeliefFrame schema_version {version} (expected {})",
+            BeliefFrame::SCHEMA_VERSION
+        )));
+    }
+    serde_json::from_value(value).map_err(|e| deserialize_err(format!("invalid BeliefFrame JSON: {e}")))
+}
+
+fn error_response(status: &Status) -> Result<proto::ExecuteResponse, Status> {
+    let out = TransportResponse::Error {
+        code: status.code() as i32,
+        message: status.message().to_string(),
+    };
+    let response_json = encode_json(&out, MAX_RESPONSE_JSON_BYTES)?;
+    Ok(proto::ExecuteResponse { response_json })
+}
+
+/// Drive an `ExecuteStream` request stream, returning the ordered response stream.
+///
+/// Each request is executed on a blocking worker as soon as it arrives, with at most
+/// `MAX_STREAM_IN_FLIGHT` running at once. Responses are emitted in request order.
+/// Per-request failures become `error` response items; only a broken input stream
+/// ends the response stream with a status.
+fn spawn_execute_stream<S>(
+    engine: Arc<KyroEngine>,
+    caller: Option<Caller>,
+    mut input: S,
+) -> ReceiverStream<Result<proto::ExecuteResponse, Status>>
+where
+    S: Stream<Item = Result<proto::ExecuteRequest, Status>> + Send + Unpin + 'static,
+{
+    let (tx, rx) = mpsc::channel::<Result<proto::ExecuteResponse, Status>>(MAX_STREAM_IN_FLIGHT);
+    let (pending_tx, mut pending_rx) =
+        mpsc::channel::<JoinHandle<Result<proto::ExecuteResponse, Status>>>(MAX_STREAM_IN_FLIGHT);
+    let permits = Arc::new(Semaphore::new(MAX_STREAM_IN_FLIGHT));
+
+    tokio::spawn(async move {
+        while let Some(item) = input.next().await {
+            let job = match item {
+                Ok(req) => {
+                    let Ok(permit) = Arc::clone(&permits).acquire_owned().await else {
+                        break;
+                    };
+                    let engine = Arc::clone(&engine);
+                    let caller = caller.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let _permit = permit;
+                        execute_request(&engine, caller.as_ref(), &req).or_else(|status| error_response(&status))
+                    })
+                }
+                // Queue the transport error behind the in-flight requests so it stays in order.
+                Err(status) => {
+                    let _ = pending_tx.send(tokio::spawn(async move { Err(status) })).await;
+                    break;
+                }
+            };
+            if pending_tx.send(job).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        while let Some(job) = pending_rx.recv().await {
+            let item = job
+                .await
+                .unwrap_or_else(|e| Err(Status::internal(format!("execute worker failed: {e}"))));
+            let terminal = item.is_err();
+            if tx.send(item).await.is_err() || terminal {
+                break;
+            }
+        }
+    });
+
+    ReceiverStream::new(rx)
+}
+
+/// Forward events from `stream` to a gRPC response stream.
+///
+/// When the client goes away the stream is dropped rather than unsubscribed, so an
+/// at-least-once subscription stays resumable.
+fn spawn_monitor_stream(stream: MonitorStream) -> ReceiverStream<Result<proto::MonitorEvent, Status>> {
+    let subscription_id = stream.subscription_id().to_string();
+    let (tx, rx) = mpsc::channel::<Result<proto::MonitorEvent, Status>>(128);
+    tokio::task::spawn_blocking(move || loop {
+        match stream.recv_timeout(Duration::from_secs(5)) {
+            Ok(event) => {
+                let encoded = match serde_json::to_vec(&event) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        let _ = tx.blocking_send(Err(Status::internal(format!(
+                            "failed to serialize monitor event: {e}"
+                        ))));
+                        stream.unsubscribe();
+                        break;
+                    }
+                };
+
+                if encoded.len() > MAX_EVENT_JSON_BYTES {
+                    let _ = tx.blocking_send(Err(Status::resource_exhausted(
+                        "monitor event exceeds maximum size",
+                    )));
+                    stream.unsubscribe();
+                    break;
+                }
+
+                let message = proto::MonitorEvent {
+                    event_json: encoded,
+                    subscription_id: subscription_id.clone(),
+                    sequence: event.sequence,
+                };
+                if tx.blocking_send(Ok(message)).is_err() {
+                    break;
+                }
+            }
+            Err(err) => {
+                // Timeout: check for client disconnect, otherwise keep polling.
+                if matches!(err, KyroError::Execution(ExecutionError::Timeout { .. })) {
+                    if tx.is_closed() {
+                        break;
+                    }
+                    continue;
+                }
+
+                // The dispatcher already dropped the subscription, or resumed it elsewhere.
+                let _ = tx.blocking_send(Err(status_from_kyro_error(err)));
+                break;
+            }
+        }
+    });
+    ReceiverStream::new(rx)
+}
+
 fn parse_consistency_mode(mode: &str) -> Result<ConsistencyMode, Status> {
     if mode.len() > 64 {
         return Err(invalid_argument("consistency_mode too long"));
@@ -185,6 +592,14 @@ fn parse_consistency_mode(mode: &str) -> Result<ConsistencyMode, Status> {
         .map_err(|_| invalid_argument("invalid consistency_mode"))
 }
 
+fn parse_commit_mode(mode: &str) -> Result<SimulationCommitMode, Status> {
+    match mode {
+        "" | "all_or_nothing" => Ok(SimulationCommitMode::AllOrNothing),
+        "best_effort" => Ok(SimulationCommitMode::BestEffort),
+        _ => Err(invalid_argument("invalid commit_mode")),
+    }
+}
+
 fn build_hypothetical_belief(ir: &KyroIR) -> Result<Belief, Status> {
     let Operation::Assert(payload) = &ir.operation else {
         return Err(invalid_argument("expected assert operation"));
@@ -203,9 +618,14 @@ fn build_hypothetical_belief(ir: &KyroIR) -> Result<Belief, Status> {
         tx_time: ir.timestamp,
         reason: None,
         consistency_status: ConsistencyStatus::Provisional,
-        supersedes: None,
+        supersedes: Vec::new(),
         superseded_by: None,
         embedding: payload.embedding.clone(),
+        namespace: payload.namespace.clone(),
+        corroborating_sources: Vec::new(),
+        metadata: serde_json::Value::Null,
+        idempotency_key: None,
+        retraction: false,
     })
 }
 
@@ -215,23 +635,20 @@ impl KyroService for KyroServiceImpl {
         &self,
         request: Request<proto::ExecuteRequest>,
     ) -> Result<Response<proto::ExecuteResponse>, Status> {
+        let caller = self.caller(&request)?;
         let req = request.into_inner();
-        let ir = parse_ir(&req.ir_json)?;
+        execute_request(&self.engine, caller.as_ref(), &req).map(Response::new)
+    }
 
-        match ir.operation {
-            Operation::Monitor(_) => {
-                return Err(invalid_argument("monitor operation must use Monitor RPC"));
-            }
-            Operation::Simulate(_) => {
-                return Err(invalid_argument("simulate operation must use SimulateCreate RPC"));
-            }
-            _ => {}
-        }
+    type ExecuteStreamStream = ReceiverStream<Result<proto::ExecuteResponse, Status>>;
 
-        let resp = self.engine.execute(ir).map_err(status_from_kyro_error)?;
-        let out = to_transport_response(resp)?;
-        let response_json = encode_json(&out, MAX_RESPONSE_JSON_BYTES)?;
-        Ok(Response::new(proto::ExecuteResponse { response_json }))
+    async fn execute_stream(
+        &self,
+        request: Request<tonic::Streaming<proto::ExecuteRequest>>,
+    ) -> Result<Response<Self::ExecuteStreamStream>, Status> {
+        let caller = self.caller(&request)?;
+        let input = request.into_inner();
+        Ok(Response::new(spawn_execute_stream(Arc::clone(&self.engine), caller, input)))
     }
 
     type MonitorStream = ReceiverStream<Result<proto::MonitorEvent, Status>>;
@@ -240,81 +657,79 @@ impl KyroService for KyroServiceImpl {
         &self,
         request: Request<proto::MonitorRequest>,
     ) -> Result<Response<Self::MonitorStream>, Status> {
+        let caller = self.caller(&request)?;
         let req = request.into_inner();
-        let ir = parse_ir(&req.ir_json)?;
-
-        if !matches!(ir.operation, Operation::Monitor(_)) {
-            return Err(invalid_argument("MonitorRequest must contain op=monitor"));
-        }
-
-        let resp = self.engine.execute(ir).map_err(status_from_kyro_error)?;
-        let EngineResponse::Monitor { registration } = resp else {
-            return Err(Status::internal("engine returned non-monitor response"));
-        };
-
-        let stream: MonitorStream = registration.stream;
-
-        let (tx, rx) = tokio::sync::mpsc::channel::<Result<proto::MonitorEvent, Status>>(128);
-        tokio::task::spawn_blocking(move || loop {
-            match stream.recv_timeout(Duration::from_secs(5)) {
-                Ok(event) => {
-                    let encoded = match serde_json::to_vec(&event) {
-                        Ok(v) => v,
-                        Err(e) => {
-                            let _ = tx.blocking_send(Err(Status::internal(format!(
-                                "failed to serialize monitor event: {e}"
-                            ))));
-                            stream.unsubscribe();
-                            break;
-                        }
-                    };
 
-                    if encoded.len() > MAX_EVENT_JSON_BYTES {
-                        let _ = tx.blocking_send(Err(Status::resource_exhausted(
-                            "monitor event exceeds maximum size",
-                        )));
-                        stream.unsubscribe();
-                        break;
-                    }
-
-                    if tx
-                        .blocking_send(Ok(proto::MonitorEvent { event_json: encoded }))
-                        .is_err()
-                    {
-                        stream.unsubscribe();
-                        break;
-                    }
-                }
-                Err(err) => {
-                    // Timeout: check for client disconnect, otherwise keep polling.
-                    if matches!(err, KyroError::Execution(ExecutionError::Timeout { .. })) {
-                        if tx.is_closed() {
-                            stream.unsubscribe();
-                            break;
-                        }
-                        continue;
-                    }
-
-                    let _ = tx.blocking_send(Err(status_from_kyro_error(err)));
-                    stream.unsubscribe();
-                    break;
+        let stream = if req.resume_subscription_id.is_empty() {
+            let ir = parse_ir(&req.ir_json)?;
+            if !matches!(ir.operation, Operation::Monitor(_)) {
+                return Err(invalid_argument("MonitorRequest must contain op=monitor"));
+            }
+            if let Some(caller) = &caller {
+                caller.check(&self.engine, &ir.operation)?;
+            }
+            let operation = caller.as_ref().map(|_| ir.operation.clone());
+
+            let resp = self.engine.execute(ir).map_err(status_from_kyro_error)?;
+            let EngineResponse::Monitor { registration } = resp else {
+                return Err(Status::internal("engine returned non-monitor response"));
+            };
+            if let (Some(caller), Some(operation)) = (&caller, operation) {
+                if let Err(status) = self.record_owner(caller, registration.subscription_id, operation).await {
+                    // Nobody could resume or ack a subscription without a recorded owner.
+                    registration.stream.unsubscribe();
+                    return Err(status);
                 }
             }
-        });
+            registration.stream
+        } else {
+            let subscription_id = SubscriptionId::from_uuid(parse_uuid(&req.resume_subscription_id)?);
+            if let Some(caller) = &caller {
+                let operation = self.check_owner(caller, subscription_id).await?;
+                caller.check(&self.engine, &operation)?;
+            }
+            let resumed = self.engine.monitor_system().resume(subscription_id);
+            if matches!(resumed, Err(KyroError::Execution(ExecutionError::MonitorNotFound { .. }))) {
+                self.subscriptions.write().await.remove(&subscription_id);
+            }
+            resumed.map_err(status_from_kyro_error)?
+        };
+
+        Ok(Response::new(spawn_monitor_stream(stream)))
+    }
+
+    async fn ack_monitor(
+        &self,
+        request: Request<proto::AckMonitorRequest>,
+    ) -> Result<Response<proto::AckMonitorResponse>, Status> {
+        let caller = self.caller(&request)?;
+        let req = request.into_inner();
+        let subscription_id = SubscriptionId::from_uuid(parse_uuid(&req.subscription_id)?);
+        if let Some(caller) = &caller {
+            self.check_owner(caller, subscription_id).await?;
+        }
 
-        Ok(Response::new(ReceiverStream::new(rx)))
+        self.engine
+            .monitor_system()
+            .ack(subscription_id, req.sequence)
+            .map_err(status_from_kyro_error)?;
+        Ok(Response::new(proto::AckMonitorResponse {}))
     }
 
     async fn simulate_create(
         &self,
         request: Request<proto::SimulateCreateRequest>,
     ) -> Result<Response<proto::SimulateCreateResponse>, Status> {
+        let caller = self.caller(&request)?;
         let req = request.into_inner();
         let ir = parse_ir(&req.ir_json)?;
 
         if !matches!(ir.operation, Operation::Simulate(_)) {
             return Err(invalid_argument("SimulateCreateRequest must contain op=simulate"));
         }
+        if let Some(caller) = &caller {
+            caller.check(&self.engine, &ir.operation)?;
+        }
 
         let resp = self.engine.execute(ir).map_err(status_from_kyro_error)?;
         let EngineResponse::Simulate { simulation } = resp else {
@@ -328,7 +743,13 @@ impl KyroService for KyroServiceImpl {
         if sims.len() >= MAX_OPEN_SIMULATIONS {
             return Err(Status::resource_exhausted("server simulation registry is full"));
         }
-        sims.insert(id, simulation);
+        sims.insert(
+            id,
+            OpenSimulation {
+                simulation,
+                owner: caller.map(|caller| caller.principal),
+            },
+        );
 
         Ok(Response::new(proto::SimulateCreateResponse {
             simulation_id,
@@ -339,29 +760,39 @@ impl KyroService for KyroServiceImpl {
         &self,
         request: Request<proto::SimulateExecuteRequest>,
     ) -> Result<Response<proto::SimulateExecuteResponse>, Status> {
+        let caller = self.caller(&request)?;
         let req = request.into_inner();
         let sim_uuid = parse_uuid(&req.simulation_id)?;
-
-        let sim = {
-            let sims = self.simulations.read().await;
-            sims.get(&sim_uuid).cloned()
-        }
-        .ok_or_else(|| Status::not_found("simulation not found"))?;
+        let sim = self.simulation(caller.as_ref(), sim_uuid).await?;
 
         let ir = parse_ir(&req.ir_json)?;
 
         match &ir.operation {
-            Operation::Assert(_) | Operation::Resolve(_) | Operation::Derive(_) => {}
+            Operation::Assert(_) | Operation::Resolve(_) | Operation::Derive(_) | Operation::Retract(_) => {}
             Operation::Simulate(_) => {
                 return Err(invalid_argument("nested simulate not supported via transport"));
             }
             Operation::Monitor(_) => {
                 return Err(invalid_argument("monitor not supported inside simulation via transport"));
             }
-            Operation::Retract(_) | Operation::DefinePattern(_) => {
+            Operation::DefinePattern(_)
+            | Operation::UpdatePattern(_)
+            | Operation::DeactivatePattern(_)
+            | Operation::CheckPatternSet(_)
+            | Operation::Feedback(_)
+            | Operation::ResolveCompound(_)
+            | Operation::ResolveMulti(_)
+            | Operation::Transaction(_) => {
                 return Err(invalid_argument("operation not supported inside simulation"));
             }
         }
+        if let Some(caller) = &caller {
+            caller.check(&self.engine, &ir.operation)?;
+        }
+        // The simulation runs its own engine, so apply this engine's limits here.
+        ir.operation
+            .validate_with(self.engine.validation_limits())
+            .map_err(|e| status_from_kyro_error(KyroError::from(e)))?;
 
         let response = match ir.operation {
             Operation::Assert(_) => {
@@ -371,6 +802,7 @@ impl KyroService for KyroServiceImpl {
                     .map(|belief_id| TransportResponse::Assert {
                         belief_id,
                         conflict_ids: Vec::new(),
+                        conflict_warnings: Vec::new(),
                     })?
             }
             Operation::Resolve(_) => {
@@ -381,6 +813,10 @@ impl KyroService for KyroServiceImpl {
                 let derivation_id = sim.derive_ir(ir).map_err(status_from_kyro_error)?;
                 TransportResponse::Derive { derivation_id }
             }
+            Operation::Retract(_) => {
+                let retraction_belief_id = sim.retract_ir(ir).map_err(status_from_kyro_error)?;
+                TransportResponse::Retract { retraction_belief_id }
+            }
             _ => {
                 return Err(Status::invalid_argument("operation not supported"));
             }
@@ -394,14 +830,10 @@ impl KyroService for KyroServiceImpl {
         &self,
         request: Request<proto::SimulateImpactRequest>,
     ) -> Result<Response<proto::SimulateImpactResponse>, Status> {
+        let caller = self.caller(&request)?;
         let req = request.into_inner();
         let sim_uuid = parse_uuid(&req.simulation_id)?;
-
-        let sim = {
-            let sims = self.simulations.read().await;
-            sims.get(&sim_uuid).cloned()
-        }
-        .ok_or_else(|| Status::not_found("simulation not found"))?;
+        let sim = self.simulation(caller.as_ref(), sim_uuid).await?;
 
         let impact: SimulationImpact = sim.query_impact().map_err(status_from_kyro_error)?;
         let impact_json = encode_json(&impact, MAX_RESPONSE_JSON_BYTES)?;
@@ -412,34 +844,138 @@ impl KyroService for KyroServiceImpl {
         &self,
         request: Request<proto::SimulateCommitRequest>,
     ) -> Result<Response<proto::SimulateCommitResponse>, Status> {
+        let caller = self.caller(&request)?;
+        if let Some(caller) = &caller {
+            caller.check_commit()?;
+        }
         let req = request.into_inner();
         let sim_uuid = parse_uuid(&req.simulation_id)?;
         let mode = parse_consistency_mode(&req.consistency_mode)?;
+        let commit_mode = parse_commit_mode(&req.commit_mode)?;
+        let sim = self.simulation(caller.as_ref(), sim_uuid).await?;
 
-        let sim = {
-            let sims = self.simulations.read().await;
-            sims.get(&sim_uuid).cloned()
+        if let Some(caller) = &caller {
+            for operation in sim.commit_operations(mode).map_err(status_from_kyro_error)? {
+                caller.check(&self.engine, &operation)?;
+            }
         }
-        .ok_or_else(|| Status::not_found("simulation not found"))?;
 
         let result: SimulationCommitResult = sim
-            .commit_overlay(&self.engine, mode)
+            .commit_overlay(&self.engine, mode, commit_mode)
             .map_err(status_from_kyro_error)?;
 
         let commit_json = encode_json(&result, MAX_RESPONSE_JSON_BYTES)?;
         Ok(Response::new(proto::SimulateCommitResponse { commit_json }))
     }
 
+    async fn simulate_fork(
+        &self,
+        request: Request<proto::SimulateForkRequest>,
+    ) -> Result<Response<proto::SimulateForkResponse>, Status> {
+        let caller = self.caller(&request)?;
+        let req = request.into_inner();
+        let sim_uuid = parse_uuid(&req.simulation_id)?;
+
+        let mut sims = self.simulations.write().await;
+        let parent = sims
+            .get(&sim_uuid)
+            .ok_or_else(|| Status::not_found("simulation not found"))?;
+        parent.check_owner(caller.as_ref())?;
+        if sims.len() >= MAX_OPEN_SIMULATIONS {
+            return Err(Status::resource_exhausted("server simulation registry is full"));
+        }
+
+        let fork = parent.simulation.fork().map_err(status_from_kyro_error)?;
+        let simulation_id = fork.id.to_string();
+        let id = parse_uuid(&simulation_id)?;
+        sims.insert(
+            id,
+            OpenSimulation {
+                simulation: Arc::new(fork),
+                owner: caller.map(|caller| caller.principal),
+            },
+        );
+
+        Ok(Response::new(proto::SimulateForkResponse { simulation_id }))
+    }
+
     async fn simulate_close(
         &self,
         request: Request<proto::SimulateCloseRequest>,
     ) -> Result<Response<proto::SimulateCloseResponse>, Status> {
+        let caller = self.caller(&request)?;
         let req = request.into_inner();
         let sim_uuid = parse_uuid(&req.simulation_id)?;
 
-        let closed = self.simulations.write().await.remove(&sim_uuid).is_some();
+        let mut sims = self.simulations.write().await;
+        if let Some(open) = sims.get(&sim_uuid) {
+            open.check_owner(caller.as_ref())?;
+        }
+        let closed = sims.remove(&sim_uuid).is_some();
         Ok(Response::new(proto::SimulateCloseResponse { closed }))
     }
+
+    async fn get_derivation(
+        &self,
+        request: Request<proto::GetDerivationRequest>,
+    ) -> Result<Response<proto::GetDerivationResponse>, Status> {
+        let caller = self.caller(&request)?;
+        let req = request.into_inner();
+        let id = DerivationId::from_uuid(parse_uuid(&req.derivation_id)?);
+
+        let record = self
+            .engine
+            .derivation_store()
+            .get(id)
+            .map_err(status_from_storage_error)?
+            .ok_or_else(|| Status::not_found(format!("derivation not found: {id}")))?;
+        if let Some(caller) = &caller {
+            caller.check_read(&self.engine, &derivation_beliefs(&record))?;
+        }
+
+        let derivation_json = encode_json(&record, MAX_RESPONSE_JSON_BYTES)?;
+        Ok(Response::new(proto::GetDerivationResponse { derivation_json }))
+    }
+
+    async fn find_derivations_by_premise(
+        &self,
+        request: Request<proto::FindDerivationsRequest>,
+    ) -> Result<Response<proto::FindDerivationsResponse>, Status> {
+        let caller = self.caller(&request)?;
+        let req = request.into_inner();
+        let belief_id = BeliefId::from_uuid(parse_uuid(&req.belief_id)?);
+        if let Some(caller) = &caller {
+            caller.check_read(&self.engine, &[belief_id])?;
+        }
+
+        let records = self
+            .engine
+            .derivation_store()
+            .find_by_premise(belief_id)
+            .map_err(status_from_storage_error)?;
+        let records = readable_derivations(&self.engine, caller.as_ref(), records)?;
+        encode_derivations(&records).map(Response::new)
+    }
+
+    async fn find_derivations_by_derived(
+        &self,
+        request: Request<proto::FindDerivationsRequest>,
+    ) -> Result<Response<proto::FindDerivationsResponse>, Status> {
+        let caller = self.caller(&request)?;
+        let req = request.into_inner();
+        let belief_id = BeliefId::from_uuid(parse_uuid(&req.belief_id)?);
+        if let Some(caller) = &caller {
+            caller.check_read(&self.engine, &[belief_id])?;
+        }
+
+        let records = self
+            .engine
+            .derivation_store()
+            .find_by_derived_belief(belief_id)
+            .map_err(status_from_storage_error)?;
+        let records = readable_derivations(&self.engine, caller.as_ref(), records)?;
+        encode_derivations(&records).map(Response::new)
+    }
 }
 
 #[cfg(test)]
@@ -492,6 +1028,8 @@ mod tests {
                 valid_time: TimeRange::from_now(),
                 consistency_mode: crate::ir::ConsistencyMode::default(),
                 embedding: None,
+                idempotency_key: None,
+                namespace: None,
             }),
         }
     }
@@ -513,6 +1051,93 @@ mod tests {
         assert!(v.get("belief_id").is_some());
     }
 
+    #[tokio::test]
+    async fn execute_lists_every_validation_failure_in_status_details() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+        let mut blank = make_assert_ir(entity_id);
+        if let Operation::Assert(payload) = &mut blank.operation {
+            payload.predicate = String::new();
+        }
+        let mut ir = make_assert_ir(entity_id);
+        ir.operation = Operation::Transaction(vec![
+            make_assert_ir(entity_id).operation,
+            blank.operation,
+            Operation::Simulate(SimulatePayload::default()),
+        ]);
+
+        let svc = KyroServiceImpl::new(engine);
+        let status = svc
+            .execute(Request::new(proto::ExecuteRequest {
+                ir_json: serde_json::to_vec(&ir).unwrap(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().starts_with("2 validation errors"), "{}", status.message());
+        let details: serde_json::Value = serde_json::from_slice(status.details()).unwrap();
+        let codes: Vec<&str> = details
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["error_code"].as_str().unwrap())
+            .collect();
+        assert_eq!(codes, ["MISSING_FIELD", "INVALID_FIELD"]);
+    }
+
+    #[test]
+    fn decode_belief_frame_rejects_other_schema_versions() {
+        let mut frame = serde_json::to_value(BeliefFrame::empty()).unwrap();
+        let decoded = decode_belief_frame(&serde_json::to_vec(&frame).unwrap()).unwrap();
+        assert_eq!(decoded.schema_version, BeliefFrame::SCHEMA_VERSION);
+
+        frame["schema_version"] = serde_json::json!(BeliefFrame::SCHEMA_VERSION + 1);
+        let err = decode_belief_frame(&serde_json::to_vec(&frame).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("unsupported BeliefFrame schema_version"));
+
+        // An unversioned (v1) frame must be migrated rather than read as current.
+        frame.as_object_mut().unwrap().remove("schema_version");
+        assert!(decode_belief_frame(&serde_json::to_vec(&frame).unwrap()).is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_stream_answers_in_order_and_reports_errors_inline() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+
+        let encode = |ir: &KyroIR| proto::ExecuteRequest {
+            ir_json: serde_json::to_vec(ir).unwrap(),
+        };
+        let simulate_ir = KyroIR {
+            version: KyroIR::CURRENT_VERSION.to_string(),
+            request_id: uuid::Uuid::new_v4(),
+            timestamp: Utc::now(),
+            operation: Operation::Simulate(SimulatePayload::default()),
+        };
+
+        let requests = vec![
+            Ok(encode(&make_assert_ir(entity_id))),
+            Ok(proto::ExecuteRequest {
+                ir_json: b"not json".to_vec(),
+            }),
+            Ok(encode(&make_assert_ir(entity_id))),
+            Ok(encode(&simulate_ir)),
+            Ok(encode(&make_assert_ir(entity_id))),
+        ];
+
+        let responses: Vec<serde_json::Value> =
+            spawn_execute_stream(engine, None, tokio_stream::iter(requests))
+                .map(|item| serde_json::from_slice(&item.unwrap().response_json).unwrap())
+                .collect()
+                .await;
+
+        let types: Vec<&str> = responses.iter().map(|v| v["type"].as_str().unwrap()).collect();
+        assert_eq!(types, ["assert", "error", "assert", "error", "assert"]);
+        assert_eq!(responses[1]["code"], tonic::Code::InvalidArgument as i32);
+        assert!(responses[1]["message"].as_str().unwrap().contains("invalid KyroIR JSON"));
+        assert!(responses[3]["message"].as_str().unwrap().contains("SimulateCreate"));
+    }
+
     #[tokio::test]
     async fn simulate_create_execute_and_impact_work() {
         let engine = make_engine();
@@ -559,7 +1184,670 @@ mod tests {
         let impact: serde_json::Value = serde_json::from_slice(&impact_resp.impact_json).unwrap();
         assert_eq!(impact["inserted_beliefs"], 1);
     }
-}
 
-pub use proto::kyro_service_client::KyroServiceClient;
+    #[tokio::test]
+    async fn simulate_execute_retracts_hypothetically() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+        let EngineResponse::Assert { belief_id, .. } = engine.execute(make_assert_ir(entity_id)).unwrap() else {
+            panic!("expected assert");
+        };
+        let svc = KyroServiceImpl::new(engine.clone());
+
+        let simulation_id = svc
+            .simulate_create(Request::new(proto::SimulateCreateRequest {
+                ir_json: serde_json::to_vec(&KyroIR::new(Operation::Simulate(SimulatePayload::default()))).unwrap(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .simulation_id;
+        let retract_ir = KyroIR::new(Operation::Retract(crate::ir::RetractPayload {
+            belief_id,
+            reason: None,
+            authorized_by: Source::agent("agent", None::<String>),
+            namespace: None,
+        }));
+        let resp = svc
+            .simulate_execute(Request::new(proto::SimulateExecuteRequest {
+                simulation_id,
+                ir_json: serde_json::to_vec(&retract_ir).unwrap(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let v: serde_json::Value = serde_json::from_slice(&resp.response_json).unwrap();
+        assert_eq!(v["type"], "retract");
+        let base = engine.belief_store().get(belief_id).unwrap().unwrap();
+        assert!(base.superseded_by.is_none());
+    }
+
+    #[tokio::test]
+    async fn simulate_fork_branches_an_open_simulation() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+        let svc = KyroServiceImpl::new(engine);
+
+        let sim_ir = KyroIR::new(Operation::Simulate(SimulatePayload::default()));
+        let parent_id = svc
+            .simulate_create(Request::new(proto::SimulateCreateRequest {
+                ir_json: serde_json::to_vec(&sim_ir).unwrap(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .simulation_id;
+        let assert_in = |simulation_id: String| {
+            let svc = &svc;
+            async move {
+                svc.simulate_execute(Request::new(proto::SimulateExecuteRequest {
+                    simulation_id,
+                    ir_json: serde_json::to_vec(&make_assert_ir(entity_id)).unwrap(),
+                }))
+                .await
+                .unwrap();
+            }
+        };
+        let inserted = |simulation_id: String| {
+            let svc = &svc;
+            async move {
+                let resp = svc
+                    .simulate_impact(Request::new(proto::SimulateImpactRequest { simulation_id }))
+                    .await
+                    .unwrap()
+                    .into_inner();
+                let impact: serde_json::Value = serde_json::from_slice(&resp.impact_json).unwrap();
+                impact["inserted_beliefs"].as_u64().unwrap()
+            }
+        };
+
+        assert_in(parent_id.clone()).await;
+        let fork_id = svc
+            .simulate_fork(Request::new(proto::SimulateForkRequest {
+                simulation_id: parent_id.clone(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .simulation_id;
+        assert_ne!(fork_id, parent_id);
+
+        assert_in(fork_id.clone()).await;
+        assert_eq!(inserted(parent_id.clone()).await, 1);
+        assert_eq!(inserted(fork_id).await, 2);
+
+        let missing = svc
+            .simulate_fork(Request::new(proto::SimulateForkRequest {
+                simulation_id: uuid::Uuid::new_v4().to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn derivation_rpcs_fetch_records_by_id_premise_and_derived_belief() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+        let svc = KyroServiceImpl::new(engine);
+
+        let execute = |ir: KyroIR| {
+            let svc = &svc;
+            async move {
+                let resp = svc
+                    .execute(Request::new(proto::ExecuteRequest {
+                        ir_json: serde_json::to_vec(&ir).unwrap(),
+                    }))
+                    .await
+                    .unwrap()
+                    .into_inner();
+                serde_json::from_slice::<serde_json::Value>(&resp.response_json).unwrap()
+            }
+        };
+        let premise = execute(make_assert_ir(entity_id)).await["belief_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let derived = execute(make_assert_ir(entity_id)).await["belief_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let derive_ir = crate::DeriveBuilder::new()
+            .rule("copy")
+            .add_source(BeliefId::from_uuid(premise.parse().unwrap()))
+            .derived_belief(BeliefId::from_uuid(derived.parse().unwrap()))
+            .build()
+            .unwrap();
+        let derivation_id = execute(derive_ir).await["derivation_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let got = svc
+            .get_derivation(Request::new(proto::GetDerivationRequest {
+                derivation_id: derivation_id.clone(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let record: DerivationRecord = serde_json::from_slice(&got.derivation_json).unwrap();
+        assert_eq!(record.id.to_string(), derivation_id);
+
+        let by_premise = svc
+            .find_derivations_by_premise(Request::new(proto::FindDerivationsRequest {
+                belief_id: premise,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let records: Vec<DerivationRecord> = serde_json::from_slice(&by_premise.derivations_json).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].id, record.id);
+
+        let by_derived = svc
+            .find_derivations_by_derived(Request::new(proto::FindDerivationsRequest {
+                belief_id: derived,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let records: Vec<DerivationRecord> = serde_json::from_slice(&by_derived.derivations_json).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].id, record.id);
+
+        let missing = svc
+            .get_derivation(Request::new(proto::GetDerivationRequest {
+                derivation_id: uuid::Uuid::new_v4().to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::NotFound);
+        let malformed = svc
+            .find_derivations_by_premise(Request::new(proto::FindDerivationsRequest {
+                belief_id: "not-a-uuid".to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(malformed.code(), tonic::Code::InvalidArgument);
+    }
+
+    fn authed_service(engine: Arc<KyroEngine>) -> (KyroServiceImpl, AuthInterceptor) {
+        let keys = ApiKeys::new()
+            .with_key("ro-key", Principal::read_only("viewer"))
+            .with_key("rw-key", Principal::new("writer"));
+        let svc = KyroServiceImpl::new(engine).with_authorizer(Arc::new(AccessAuthorizer));
+        (svc, AuthInterceptor::new(keys))
+    }
+
+    /// Run `message` through the interceptor as a call made with `key`.
+    fn with_key<T>(interceptor: &mut AuthInterceptor, key: &str, message: T) -> Result<Request<T>, Status> {
+        use tonic::service::Interceptor;
+
+        let mut probe = Request::new(());
+        probe
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {key}").parse().unwrap());
+        let (metadata, extensions, ()) = interceptor.call(probe)?.into_parts();
+        Ok(Request::from_parts(metadata, extensions, message))
+    }
+
+    #[tokio::test]
+    async fn read_only_key_may_resolve_but_not_assert() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+        let (svc, mut interceptor) = authed_service(engine);
+        let assert_req = || proto::ExecuteRequest {
+            ir_json: serde_json::to_vec(&make_assert_ir(entity_id)).unwrap(),
+        };
+        let resolve_req = proto::ExecuteRequest {
+            ir_json: serde_json::to_vec(&KyroIR::new(Operation::Resolve(crate::ir::ResolvePayload {
+                entity_id: Some(entity_id),
+                predicate: Some("p".to_string()),
+                ..crate::ir::ResolvePayload::default()
+            })))
+            .unwrap(),
+        };
+
+        let denied = svc
+            .execute(with_key(&mut interceptor, "ro-key", assert_req()).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+        let resolved = svc
+            .execute(with_key(&mut interceptor, "ro-key", resolve_req).unwrap())
+            .await
+            .unwrap()
+            .into_inner();
+        let v: serde_json::Value = serde_json::from_slice(&resolved.response_json).unwrap();
+        assert_eq!(v["type"], "resolve");
+        svc.execute(with_key(&mut interceptor, "rw-key", assert_req()).unwrap())
+            .await
+            .unwrap();
+
+        let unknown = with_key(&mut interceptor, "stolen", ()).unwrap_err();
+        assert_eq!(unknown.code(), tonic::Code::Unauthenticated);
+        let anonymous = svc.execute(Request::new(assert_req())).await.unwrap_err();
+        assert_eq!(anonymous.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn retract_must_be_authorized_by_the_calling_principal() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+        let EngineResponse::Assert { belief_id, .. } = engine.execute(make_assert_ir(entity_id)).unwrap() else {
+            panic!("expected assert");
+        };
+        let (svc, mut interceptor) = authed_service(engine);
+        let retract = |authorized_by: &str| proto::ExecuteRequest {
+            ir_json: serde_json::to_vec(&KyroIR::new(Operation::Retract(crate::ir::RetractPayload {
+                belief_id,
+                reason: None,
+                authorized_by: Source::agent(authorized_by, None::<String>),
+                namespace: None,
+            })))
+            .unwrap(),
+        };
+
+        let impersonated = svc
+            .execute(with_key(&mut interceptor, "rw-key", retract("admin")).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(impersonated.code(), tonic::Code::PermissionDenied);
+        let read_only = svc
+            .execute(with_key(&mut interceptor, "ro-key", retract("viewer")).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(read_only.code(), tonic::Code::PermissionDenied);
+        svc.execute(with_key(&mut interceptor, "rw-key", retract("writer")).unwrap())
+            .await
+            .unwrap();
+    }
+
+    /// Admits the principal to one entity, and to operations that target none.
+    struct SingleEntityAuthorizer(crate::EntityId);
+
+    impl Authorizer for SingleEntityAuthorizer {
+        fn authorize(&self, _principal: &Principal, _operation: &Operation, entity_id: Option<crate::EntityId>) -> bool {
+            entity_id.is_none_or(|id| id == self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn operations_reaching_several_entities_are_authorized_for_each() {
+        let engine = make_engine();
+        let allowed = make_entity(&engine);
+        let secret = Entity::new("vault", EntityType::Concept);
+        let secret_id = secret.id;
+        engine.entity_store().insert(secret).unwrap();
+        for entity_id in [allowed, secret_id] {
+            engine.execute(make_assert_ir(entity_id)).unwrap();
+        }
+        let svc = KyroServiceImpl::new(engine).with_authorizer(Arc::new(SingleEntityAuthorizer(allowed)));
+        let mut interceptor = AuthInterceptor::new(ApiKeys::new().with_key("key", Principal::read_only("viewer")));
+        let mut request = |operation: Operation| {
+            let request = proto::ExecuteRequest {
+                ir_json: serde_json::to_vec(&KyroIR::new(operation)).unwrap(),
+            };
+            with_key(&mut interceptor, "key", request).unwrap()
+        };
+        let code = |result: Result<Response<proto::ExecuteResponse>, Status>| result.map(drop).map_err(|s| s.code());
+        let multi = |entity_ids: Vec<crate::EntityId>| {
+            Operation::ResolveMulti(crate::ir::ResolveMultiPayload {
+                entity_ids,
+                predicate: "p".to_string(),
+                options: crate::ir::ResolvePayload::default(),
+            })
+        };
+        let by_name = |name: &str| {
+            Operation::Resolve(crate::ir::ResolvePayload {
+                query: Some(name.to_string()),
+                predicate: Some("p".to_string()),
+                ..crate::ir::ResolvePayload::default()
+            })
+        };
+
+        assert_eq!(code(svc.execute(request(multi(vec![allowed]))).await), Ok(()));
+        assert_eq!(
+            code(svc.execute(request(multi(vec![allowed, secret_id]))).await),
+            Err(tonic::Code::PermissionDenied)
+        );
+        assert_eq!(code(svc.execute(request(by_name("e"))).await), Ok(()));
+        assert_eq!(code(svc.execute(request(by_name("vault"))).await), Err(tonic::Code::PermissionDenied));
+        let compound = Operation::ResolveCompound(crate::ir::ResolveCompoundPayload {
+            conditions: vec![crate::ir::CompoundCondition {
+                predicate: "p".to_string(),
+                matcher: crate::monitor::ValueMatcher::Equals { value: Value::Bool(true) },
+            }],
+            as_of: None,
+            min_confidence: None,
+            limit: 10,
+            namespace: None,
+        });
+        assert_eq!(code(svc.execute(request(compound)).await), Err(tonic::Code::PermissionDenied));
+    }
+
+    fn at_least_once_engine() -> Arc<KyroEngine> {
+        let stores = InMemoryStores::default();
+        Arc::new(
+            KyroEngine::new(
+                Arc::new(stores.entities),
+                Arc::new(stores.beliefs),
+                Arc::new(stores.patterns),
+                Arc::new(stores.conflicts),
+                Arc::new(stores.derivations),
+            )
+            .with_monitor_config(crate::monitor::MonitorSystemConfig {
+                delivery: crate::monitor::MonitorDelivery::AtLeastOnce {
+                    unacked_capacity: 16,
+                    resume_window: Duration::from_secs(30),
+                },
+                ..crate::monitor::MonitorSystemConfig::default()
+            }),
+        )
+    }
+
+    fn monitor_request(entity_id: crate::EntityId) -> proto::MonitorRequest {
+        let trigger = crate::monitor::Trigger::ValueMatch {
+            entity_filter: Some(entity_id),
+            predicate: "p".to_string(),
+            matcher: crate::monitor::ValueMatcher::Equals { value: Value::Bool(true) },
+        };
+        let monitor_ir = KyroIR {
+            version: KyroIR::CURRENT_VERSION.to_string(),
+            request_id: uuid::Uuid::new_v4(),
+            timestamp: Utc::now(),
+            operation: Operation::Monitor(crate::ir::MonitorPayload {
+                description: None,
+                predicates: None,
+                entity_filter: None,
+                pattern_filter: None,
+                threshold: Some(Value::Structured(serde_json::to_value(&trigger).unwrap())),
+                expires_at: None,
+                callback: None,
+                namespace: None,
+            }),
+        };
+        proto::MonitorRequest {
+            ir_json: serde_json::to_vec(&monitor_ir).unwrap(),
+            resume_subscription_id: String::new(),
+        }
+    }
+
+    fn resume_request(subscription_id: String) -> proto::MonitorRequest {
+        proto::MonitorRequest {
+            ir_json: Vec::new(),
+            resume_subscription_id: subscription_id,
+        }
+    }
+
+    async fn next_event(stream: &mut ReceiverStream<Result<proto::MonitorEvent, Status>>) -> proto::MonitorEvent {
+        tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("monitor event")
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn monitor_redelivers_unacked_events_when_resumed() {
+        let engine = at_least_once_engine();
+        let entity_id = make_entity(&engine);
+        let svc = KyroServiceImpl::new(engine.clone());
+
+        let mut stream = svc
+            .monitor(Request::new(monitor_request(entity_id)))
+            .await
+            .unwrap()
+            .into_inner();
+        engine.execute(make_assert_ir(entity_id)).unwrap();
+        let first = next_event(&mut stream).await;
+        assert_eq!(first.sequence, 1);
+
+        // The connection drops before the client acknowledges the event.
+        drop(stream);
+        let resume = |subscription_id: String| svc.monitor(Request::new(resume_request(subscription_id)));
+        let mut stream = resume(first.subscription_id.clone()).await.unwrap().into_inner();
+        let redelivered = next_event(&mut stream).await;
+        assert_eq!(redelivered.sequence, 1);
+        assert_eq!(redelivered.event_json, first.event_json);
+
+        svc.ack_monitor(Request::new(proto::AckMonitorRequest {
+            subscription_id: first.subscription_id.clone(),
+            sequence: 1,
+        }))
+        .await
+        .unwrap();
+        engine.execute(make_assert_ir(entity_id)).unwrap();
+        assert_eq!(next_event(&mut stream).await.sequence, 2);
+
+        let unknown = resume(uuid::Uuid::new_v4().to_string()).await.unwrap_err();
+        assert_eq!(unknown.code(), tonic::Code::NotFound);
+    }
+
+    /// Allows everything until revoked, then refuses MONITOR.
+    struct RevocableMonitorAuthorizer(std::sync::atomic::AtomicBool);
+
+    impl Authorizer for RevocableMonitorAuthorizer {
+        fn authorize(&self, _principal: &Principal, operation: &Operation, _entity_id: Option<crate::EntityId>) -> bool {
+            let revoked = self.0.load(std::sync::atomic::Ordering::SeqCst);
+            !(revoked && matches!(operation, Operation::Monitor(_)))
+        }
+    }
+
+    #[tokio::test]
+    async fn monitor_subscriptions_are_resumed_and_acked_only_by_their_owner() {
+        let engine = at_least_once_engine();
+        let entity_id = make_entity(&engine);
+        let authorizer = Arc::new(RevocableMonitorAuthorizer(std::sync::atomic::AtomicBool::new(false)));
+        let svc = KyroServiceImpl::new(engine.clone()).with_authorizer(authorizer.clone());
+        let mut interceptor = AuthInterceptor::new(
+            ApiKeys::new()
+                .with_key("alice-key", Principal::new("alice"))
+                .with_key("mallory-key", Principal::new("mallory")),
+        );
+
+        let mut stream = svc
+            .monitor(with_key(&mut interceptor, "alice-key", monitor_request(entity_id)).unwrap())
+            .await
+            .unwrap()
+            .into_inner();
+        engine.execute(make_assert_ir(entity_id)).unwrap();
+        let first = next_event(&mut stream).await;
+        drop(stream);
+        let ack = proto::AckMonitorRequest {
+            subscription_id: first.subscription_id.clone(),
+            sequence: first.sequence,
+        };
 
+        let hijack = svc
+            .monitor(with_key(&mut interceptor, "mallory-key", resume_request(first.subscription_id.clone())).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(hijack.code(), tonic::Code::PermissionDenied);
+        let foreign_ack = svc
+            .ack_monitor(with_key(&mut interceptor, "mallory-key", ack.clone()).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(foreign_ack.code(), tonic::Code::PermissionDenied);
+
+        // The owner still gets the event the other principal tried to acknowledge.
+        let mut stream = svc
+            .monitor(with_key(&mut interceptor, "alice-key", resume_request(first.subscription_id.clone())).unwrap())
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(next_event(&mut stream).await.sequence, first.sequence);
+        svc.ack_monitor(with_key(&mut interceptor, "alice-key", ack).unwrap())
+            .await
+            .unwrap();
+        drop(stream);
+
+        // Resuming authorizes the MONITOR again.
+        authorizer.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        let revoked = svc
+            .monitor(with_key(&mut interceptor, "alice-key", resume_request(first.subscription_id)).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(revoked.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn simulations_are_used_only_by_their_owner() {
+        let engine = make_engine();
+        let entity_id = make_entity(&engine);
+        let svc = KyroServiceImpl::new(engine).with_authorizer(Arc::new(AccessAuthorizer));
+        let mut interceptor = AuthInterceptor::new(
+            ApiKeys::new()
+                .with_key("alice-key", Principal::new("alice"))
+                .with_key("mallory-key", Principal::new("mallory")),
+        );
+        let create = proto::SimulateCreateRequest {
+            ir_json: serde_json::to_vec(&KyroIR::new(Operation::Simulate(SimulatePayload::default()))).unwrap(),
+        };
+        let simulation_id = svc
+            .simulate_create(with_key(&mut interceptor, "alice-key", create).unwrap())
+            .await
+            .unwrap()
+            .into_inner()
+            .simulation_id;
+        let execute = || proto::SimulateExecuteRequest {
+            simulation_id: simulation_id.clone(),
+            ir_json: serde_json::to_vec(&make_assert_ir(entity_id)).unwrap(),
+        };
+        let commit = || proto::SimulateCommitRequest {
+            simulation_id: simulation_id.clone(),
+            consistency_mode: "eventual".to_string(),
+            commit_mode: String::new(),
+        };
+        svc.simulate_execute(with_key(&mut interceptor, "alice-key", execute()).unwrap())
+            .await
+            .unwrap();
+
+        let impact = proto::SimulateImpactRequest {
+            simulation_id: simulation_id.clone(),
+        };
+        let fork = proto::SimulateForkRequest {
+            simulation_id: simulation_id.clone(),
+        };
+        let close = proto::SimulateCloseRequest {
+            simulation_id: simulation_id.clone(),
+        };
+        let key = "mallory-key";
+        let codes = [
+            svc.simulate_execute(with_key(&mut interceptor, key, execute()).unwrap()).await.map(drop),
+            svc.simulate_impact(with_key(&mut interceptor, key, impact).unwrap()).await.map(drop),
+            svc.simulate_commit(with_key(&mut interceptor, key, commit()).unwrap()).await.map(drop),
+            svc.simulate_fork(with_key(&mut interceptor, key, fork).unwrap()).await.map(drop),
+            svc.simulate_close(with_key(&mut interceptor, key, close).unwrap()).await.map(drop),
+        ]
+        .map(|result| result.unwrap_err().code());
+        assert_eq!(codes, [tonic::Code::PermissionDenied; 5]);
+
+        let committed = svc
+            .simulate_commit(with_key(&mut interceptor, "alice-key", commit()).unwrap())
+            .await
+            .unwrap()
+            .into_inner();
+        let result: SimulationCommitResult = serde_json::from_slice(&committed.commit_json).unwrap();
+        assert_eq!(result.committed_beliefs, 1);
+    }
+
+    #[tokio::test]
+    async fn simulate_commit_authorizes_each_hypothetical() {
+        let engine = make_engine();
+        let allowed = make_entity(&engine);
+        let secret = Entity::new("vault", EntityType::Concept);
+        let secret_id = secret.id;
+        engine.entity_store().insert(secret).unwrap();
+        let svc = KyroServiceImpl::new(Arc::clone(&engine)).with_authorizer(Arc::new(SingleEntityAuthorizer(allowed)));
+        let mut interceptor = AuthInterceptor::new(ApiKeys::new().with_key("key", Principal::new("writer")));
+        let create = proto::SimulateCreateRequest {
+            ir_json: serde_json::to_vec(&KyroIR::new(Operation::Simulate(SimulatePayload::default()))).unwrap(),
+        };
+        let simulation_id = svc
+            .simulate_create(with_key(&mut interceptor, "key", create).unwrap())
+            .await
+            .unwrap()
+            .into_inner()
+            .simulation_id;
+
+        // A hypothetical about the secret entity, recorded while the caller could still write it.
+        let sim = svc.simulation(None, parse_uuid(&simulation_id).unwrap()).await.unwrap();
+        sim.assert_hypothetical(build_hypothetical_belief(&make_assert_ir(secret_id)).unwrap())
+            .unwrap();
+
+        let commit = proto::SimulateCommitRequest {
+            simulation_id,
+            consistency_mode: "eventual".to_string(),
+            commit_mode: String::new(),
+        };
+        let denied = svc
+            .simulate_commit(with_key(&mut interceptor, "key", commit).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+        assert_eq!(engine.belief_store().count_by_entity(secret_id).unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn derivations_are_readable_only_through_authorized_beliefs() {
+        let engine = make_engine();
+        let allowed = make_entity(&engine);
+        let secret = Entity::new("vault", EntityType::Concept);
+        let secret_id = secret.id;
+        engine.entity_store().insert(secret).unwrap();
+        let assert = |entity_id| {
+            let EngineResponse::Assert { belief_id, .. } = engine.execute(make_assert_ir(entity_id)).unwrap() else {
+                panic!("expected assert");
+            };
+            belief_id
+        };
+        let derive = |premise: BeliefId, derived: BeliefId| {
+            let ir = crate::DeriveBuilder::new()
+                .rule("copy")
+                .add_source(premise)
+                .derived_belief(derived)
+                .build()
+                .unwrap();
+            let EngineResponse::Derive { derivation_id } = engine.execute(ir).unwrap() else {
+                panic!("expected derive");
+            };
+            derivation_id
+        };
+        let premise = assert(allowed);
+        let open = derive(premise, assert(allowed));
+        let secret_belief = assert(secret_id);
+        let hidden = derive(premise, secret_belief);
+
+        let svc = KyroServiceImpl::new(Arc::clone(&engine)).with_authorizer(Arc::new(SingleEntityAuthorizer(allowed)));
+        let mut interceptor = AuthInterceptor::new(ApiKeys::new().with_key("key", Principal::read_only("viewer")));
+        let mut get = |id: crate::derivation::DerivationId| {
+            let request = proto::GetDerivationRequest {
+                derivation_id: id.to_string(),
+            };
+            with_key(&mut interceptor, "key", request).unwrap()
+        };
+        let (open_req, hidden_req) = (get(open), get(hidden));
+        svc.get_derivation(open_req).await.unwrap();
+        let denied = svc.get_derivation(hidden_req).await.unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+
+        let find = |belief_id: BeliefId| proto::FindDerivationsRequest {
+            belief_id: belief_id.to_string(),
+        };
+        let by_premise = svc
+            .find_derivations_by_premise(with_key(&mut interceptor, "key", find(premise)).unwrap())
+            .await
+            .unwrap()
+            .into_inner();
+        let records: Vec<DerivationRecord> = serde_json::from_slice(&by_premise.derivations_json).unwrap();
+        assert_eq!(records.iter().map(|r| r.id).collect::<Vec<_>>(), [open]);
+        let denied = svc
+            .find_derivations_by_derived(with_key(&mut interceptor, "key", find(secret_belief)).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    }
+}
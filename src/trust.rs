@@ -3,14 +3,25 @@
 //! Trust is modeled separately from epistemic confidence and retrieval relevance.
 //! A trust weight (0.0-1.0) scales how much a source should influence ranking
 //! without mutating the stored belief confidence.
+//!
+//! [`CalibrationTracker`] learns a second, per-source weight from FEEDBACK outcomes.
+//! Models may also scale confidence by who assigned it (see
+//! [`TrustModel::confidence_source_weight`]), e.g. to favour human-verified values.
+//!
+//! A model's configuration can be captured as a [`TrustSnapshot`] and restored later, e.g.
+//! from a persistent database after a restart. Hooks registered with
+//! [`TrustModel::on_change`] and [`CalibrationTracker::on_record`] see every later change,
+//! so it can be saved as it happens.
 
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::fmt;
+use std::sync::{Mutex, RwLock};
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::{KyroResult, ValidationError};
 use crate::source::Source;
-use crate::confidence::SourceId;
+use crate::confidence::{BeliefId, ConfidenceSource, ConfidenceSourceKind, SourceId};
 
 /// Result of a trust evaluation.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -33,6 +44,63 @@ impl TrustAssessment {
     }
 }
 
+/// Serialized configuration of a [`TrustModel`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustSnapshot {
+    /// [`TrustModel::name`] of the model the snapshot was taken from.
+    pub model: String,
+    /// Model-specific state.
+    pub state: serde_json::Value,
+}
+
+/// Called with a model's new [`TrustSnapshot`] after each change; see
+/// [`TrustModel::on_change`].
+pub type TrustChangeHook = Box<dyn Fn(&TrustSnapshot) -> KyroResult<()> + Send + Sync>;
+
+/// Called with each outcome a [`CalibrationTracker`] records; see
+/// [`CalibrationTracker::on_record`].
+pub type FeedbackHook = Box<dyn Fn(&FeedbackRecord) -> KyroResult<()> + Send + Sync>;
+
+type Hook<T> = Box<dyn Fn(&T) -> KyroResult<()> + Send + Sync>;
+
+/// Registered change hooks.
+struct Hooks<T>(Mutex<Vec<Hook<T>>>);
+
+impl<T> Default for Hooks<T> {
+    fn default() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+}
+
+impl<T> fmt::Debug for Hooks<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.0.lock().map_or(0, |hooks| hooks.len());
+        f.debug_struct("Hooks").field("len", &len).finish()
+    }
+}
+
+impl<T> Hooks<T> {
+    fn add(&self, hook: Hook<T>) {
+        self.0.lock().expect("trust hook lock poisoned").push(hook);
+    }
+
+    /// Run every hook on `value()`, which is only computed if there are hooks, and return
+    /// the first error. A failing hook does not keep the others from running.
+    ///
+    /// Notifications are serialized, so the last value a hook sees is never older than an
+    /// earlier one.
+    fn notify(&self, value: impl FnOnce() -> Option<T>) -> KyroResult<()> {
+        let hooks = self.0.lock().expect("trust hook lock poisoned");
+        if hooks.is_empty() {
+            return Ok(());
+        }
+        let Some(value) = value() else {
+            return Ok(());
+        };
+        hooks.iter().map(|hook| hook(&value)).fold(Ok(()), Result::and)
+    }
+}
+
 /// Trust evaluation interface.
 pub trait TrustModel: Send + Sync {
     /// Name of the trust model (for audit/debugging).
@@ -40,16 +108,117 @@ pub trait TrustModel: Send + Sync {
 
     /// Compute trust for a source within an optional domain (predicate, topic, etc.).
     fn assess(&self, source: &Source, domain: Option<&str>) -> TrustAssessment;
+
+    /// Map a confidence reported by `source` onto the scale shared by all sources.
+    ///
+    /// Applied first, before [`clamp_confidence`](Self::clamp_confidence) and trust
+    /// weighting; the result is clamped to [0.0, 1.0]. The default leaves confidence unchanged.
+    fn normalize_confidence(&self, source: &Source, confidence: f32) -> f32 {
+        let _ = source;
+        confidence
+    }
+
+    /// Bound the confidence a source self-reports before trust weighting.
+    ///
+    /// The default leaves confidence unchanged.
+    fn clamp_confidence(&self, source: &Source, confidence: f32) -> f32 {
+        let _ = source;
+        confidence
+    }
+
+    /// Record how credible a paper is (e.g. from its citation count), in [0.0, 1.0].
+    ///
+    /// `paper_id` is the ArXiv ID or DOI, as returned by [`Source::paper_id`]. Models that
+    /// weigh paper credibility scale the trust of beliefs citing that paper by `score`; the
+    /// default ignores it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of an [`on_change`](Self::on_change) hook that failed to save the
+    /// change. The change still applies.
+    fn set_paper_credibility(&self, paper_id: &str, score: f32) -> KyroResult<()> {
+        let _ = (paper_id, score);
+        Ok(())
+    }
+
+    /// Multiplier applied to a confidence assigned by `source`, before trust weighting.
+    ///
+    /// Unlike trust weights this may exceed 1.0; the weighted confidence is capped at 1.0.
+    /// The default is 1.0 for every source.
+    fn confidence_source_weight(&self, source: &ConfidenceSource) -> f32 {
+        let _ = source;
+        1.0
+    }
+
+    /// Capture the model's configuration, to restore it after a restart.
+    ///
+    /// The default returns `None`: the model has nothing to persist.
+    fn snapshot(&self) -> Option<TrustSnapshot> {
+        None
+    }
+
+    /// Replace the model's configuration with a [`snapshot`](Self::snapshot).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidField` if the snapshot was taken from another model or its state is
+    /// malformed. The default rejects every snapshot. Also returns the error of an
+    /// [`on_change`](Self::on_change) hook that failed to save the restored configuration.
+    fn restore(&self, snapshot: &TrustSnapshot) -> KyroResult<()> {
+        Err(ValidationError::InvalidField {
+            field: "trust_snapshot".to_string(),
+            reason: format!("trust model '{}' cannot restore '{}' snapshots", self.name(), snapshot.model),
+        }
+        .into())
+    }
+
+    /// Call `hook` with the model's new [`snapshot`](Self::snapshot) after every change to
+    /// its configuration, e.g. to persist it.
+    ///
+    /// An error from `hook` is returned by the call that made the change, which still
+    /// applies.
+    ///
+    /// The default drops the hook: a model without snapshots has nothing to save.
+    fn on_change(&self, hook: TrustChangeHook) {
+        let _ = hook;
+    }
 }
 
 /// Simple trust model backed by in-memory weights.
 ///
 /// - Global weights apply to all domains.
 /// - Domain-specific weights override global weights when present.
+/// - Confidence remaps put each source's confidences on a common scale.
+/// - Confidence bounds cap (or floor) what a source self-reports.
+/// - Paper credibility scales the weight of paper sources; unscored papers keep 1.0.
+/// - Confidence source weights scale confidences by the kind of their assigner; unset
+///   kinds keep 1.0.
 #[derive(Debug, Default)]
 pub struct SimpleTrustModel {
     global: RwLock<HashMap<SourceId, f32>>,
     domain_overrides: RwLock<HashMap<String, HashMap<SourceId, f32>>>,
+    confidence_remaps: RwLock<HashMap<SourceId, (f32, f32)>>,
+    confidence_bounds: RwLock<HashMap<SourceId, (f32, f32)>>,
+    paper_credibility: RwLock<HashMap<String, f32>>,
+    confidence_source_weights: RwLock<HashMap<ConfidenceSourceKind, f32>>,
+    hooks: Hooks<TrustSnapshot>,
+}
+
+/// State of a [`SimpleTrustModel`] as stored in its [`TrustSnapshot`].
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SimpleTrustState {
+    #[serde(default)]
+    global: HashMap<SourceId, f32>,
+    #[serde(default)]
+    domain_overrides: HashMap<String, HashMap<SourceId, f32>>,
+    #[serde(default)]
+    confidence_remaps: HashMap<SourceId, (f32, f32)>,
+    #[serde(default)]
+    confidence_bounds: HashMap<SourceId, (f32, f32)>,
+    #[serde(default)]
+    paper_credibility: HashMap<String, f32>,
+    #[serde(default)]
+    confidence_source_weights: HashMap<ConfidenceSourceKind, f32>,
 }
 
 impl SimpleTrustModel {
@@ -60,21 +229,131 @@ impl SimpleTrustModel {
     }
 
     /// Set a global trust weight for a source.
-    pub fn set_global(&self, source: SourceId, weight: f32) {
-        let mut guard = self.global.write().expect("trust global lock poisoned");
-        guard.insert(source, weight.clamp(0.0, 1.0));
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of an [`on_change`](TrustModel::on_change) hook that failed to save
+    /// the change. The change still applies.
+    pub fn set_global(&self, source: SourceId, weight: f32) -> KyroResult<()> {
+        self.global
+            .write()
+            .expect("trust global lock poisoned")
+            .insert(source, weight.clamp(0.0, 1.0));
+        self.changed()
     }
 
     /// Set a domain-specific trust weight for a source.
-    pub fn set_domain(&self, domain: impl Into<String>, source: SourceId, weight: f32) {
-        let mut guard = self
-            .domain_overrides
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`set_global`](Self::set_global).
+    pub fn set_domain(&self, domain: impl Into<String>, source: SourceId, weight: f32) -> KyroResult<()> {
+        self.domain_overrides
             .write()
-            .expect("trust domain lock poisoned");
-        guard
+            .expect("trust domain lock poisoned")
             .entry(domain.into())
             .or_default()
             .insert(source, weight.clamp(0.0, 1.0));
+        self.changed()
+    }
+
+    /// Read a source's confidence `c` as `scale * c + offset`, clamped to [0.0, 1.0], when
+    /// ranking its beliefs.
+    ///
+    /// Use it to line up sources whose scales differ, e.g. `scale = 1.5` for a source whose
+    /// 0.6 means what others call 0.9. Stored beliefs keep their raw confidence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidField` if `scale` or `offset` is not finite, if `scale` is not
+    /// positive (the remap must preserve the source's own ordering), or if every
+    /// confidence would land outside [0.0, 1.0] and clamp to the same bound. Otherwise
+    /// fails like [`set_global`](Self::set_global).
+    pub fn set_confidence_remap(&self, source: SourceId, scale: f32, offset: f32) -> KyroResult<()> {
+        let invalid = |reason: &str| ValidationError::InvalidField {
+            field: "confidence_remap".to_string(),
+            reason: reason.to_string(),
+        };
+        if !scale.is_finite() || !offset.is_finite() {
+            return Err(invalid("scale and offset must be finite").into());
+        }
+        if scale <= 0.0 {
+            return Err(invalid("scale must be positive").into());
+        }
+        if offset >= 1.0 || scale + offset <= 0.0 {
+            return Err(invalid("remap sends every confidence outside [0, 1]").into());
+        }
+        self.confidence_remaps
+            .write()
+            .expect("trust confidence remap lock poisoned")
+            .insert(source, (scale, offset));
+        self.changed()
+    }
+
+    /// Clamp a source's reported confidence into `[min, max]` when ranking its beliefs.
+    ///
+    /// Both bounds are clamped to [0.0, 1.0], and `min` to at most `max`. Stored beliefs keep
+    /// their raw confidence.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`set_global`](Self::set_global).
+    pub fn set_confidence_bounds(&self, source: SourceId, min: f32, max: f32) -> KyroResult<()> {
+        let max = max.clamp(0.0, 1.0);
+        let min = min.clamp(0.0, max);
+        self.confidence_bounds
+            .write()
+            .expect("trust confidence bounds lock poisoned")
+            .insert(source, (min, max));
+        self.changed()
+    }
+
+    /// Scale confidences assigned by sources of `kind` by `weight` when ranking.
+    ///
+    /// Negative or non-finite weights are treated as 0.0.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`set_global`](Self::set_global).
+    pub fn set_confidence_source_weight(&self, kind: ConfidenceSourceKind, weight: f32) -> KyroResult<()> {
+        let weight = if weight.is_finite() { weight.max(0.0) } else { 0.0 };
+        self.confidence_source_weights
+            .write()
+            .expect("trust confidence source lock poisoned")
+            .insert(kind, weight);
+        self.changed()
+    }
+
+    /// Report the new configuration to the [`on_change`](TrustModel::on_change) hooks.
+    fn changed(&self) -> KyroResult<()> {
+        self.hooks.notify(|| self.snapshot())
+    }
+
+    /// A model configured from `state` through the setters, so restored values are
+    /// clamped and validated like fresh ones.
+    fn from_state(state: SimpleTrustState) -> KyroResult<Self> {
+        let model = Self::new();
+        for (source, weight) in state.global {
+            model.set_global(source, weight)?;
+        }
+        for (domain, weights) in state.domain_overrides {
+            for (source, weight) in weights {
+                model.set_domain(domain.clone(), source, weight)?;
+            }
+        }
+        for (source, (scale, offset)) in state.confidence_remaps {
+            model.set_confidence_remap(source, scale, offset)?;
+        }
+        for (source, (min, max)) in state.confidence_bounds {
+            model.set_confidence_bounds(source, min, max)?;
+        }
+        for (paper_id, score) in state.paper_credibility {
+            model.set_paper_credibility(&paper_id, score)?;
+        }
+        for (kind, weight) in state.confidence_source_weights {
+            model.set_confidence_source_weight(kind, weight)?;
+        }
+        Ok(model)
     }
 
     fn lookup(&self, source: SourceId, domain: Option<&str>) -> Option<f32> {
@@ -92,6 +371,17 @@ impl SimpleTrustModel {
         let guard = self.global.read().expect("trust global lock poisoned");
         guard.get(&source).copied()
     }
+
+    fn credibility(&self, source: &Source) -> f32 {
+        let Some(paper_id) = source.paper_id() else {
+            return 1.0;
+        };
+        let guard = self
+            .paper_credibility
+            .read()
+            .expect("trust paper credibility lock poisoned");
+        guard.get(paper_id.trim()).copied().unwrap_or(1.0)
+    }
 }
 
 impl TrustModel for SimpleTrustModel {
@@ -102,13 +392,308 @@ impl TrustModel for SimpleTrustModel {
     fn assess(&self, source: &Source, domain: Option<&str>) -> TrustAssessment {
         let source_id = source.source_id();
         let weight = self.lookup(source_id, domain).unwrap_or(1.0);
-        TrustAssessment::new(weight)
+        TrustAssessment::new(weight * self.credibility(source))
+    }
+
+    fn normalize_confidence(&self, source: &Source, confidence: f32) -> f32 {
+        let guard = self
+            .confidence_remaps
+            .read()
+            .expect("trust confidence remap lock poisoned");
+        match guard.get(&source.source_id()) {
+            Some(&(scale, offset)) => (scale * confidence + offset).clamp(0.0, 1.0),
+            None => confidence,
+        }
+    }
+
+    fn clamp_confidence(&self, source: &Source, confidence: f32) -> f32 {
+        let guard = self
+            .confidence_bounds
+            .read()
+            .expect("trust confidence bounds lock poisoned");
+        match guard.get(&source.source_id()) {
+            Some(&(min, max)) => confidence.clamp(min, max),
+            None => confidence,
+        }
+    }
+
+    fn set_paper_credibility(&self, paper_id: &str, score: f32) -> KyroResult<()> {
+        self.paper_credibility
+            .write()
+            .expect("trust paper credibility lock poisoned")
+            .insert(paper_id.trim().to_string(), score.clamp(0.0, 1.0));
+        self.changed()
+    }
+
+    fn confidence_source_weight(&self, source: &ConfidenceSource) -> f32 {
+        let guard = self
+            .confidence_source_weights
+            .read()
+            .expect("trust confidence source lock poisoned");
+        guard.get(&source.kind()).copied().unwrap_or(1.0)
+    }
+
+    fn snapshot(&self) -> Option<TrustSnapshot> {
+        let state = SimpleTrustState {
+            global: self.global.read().expect("trust global lock poisoned").clone(),
+            domain_overrides: self.domain_overrides.read().expect("trust domain lock poisoned").clone(),
+            confidence_remaps: self
+                .confidence_remaps
+                .read()
+                .expect("trust confidence remap lock poisoned")
+                .clone(),
+            confidence_bounds: self
+                .confidence_bounds
+                .read()
+                .expect("trust confidence bounds lock poisoned")
+                .clone(),
+            paper_credibility: self
+                .paper_credibility
+                .read()
+                .expect("trust paper credibility lock poisoned")
+                .clone(),
+            confidence_source_weights: self
+                .confidence_source_weights
+                .read()
+                .expect("trust confidence source lock poisoned")
+                .clone(),
+        };
+        Some(TrustSnapshot {
+            model: self.name().to_string(),
+            state: serde_json::to_value(state).expect("trust state serializes to JSON"),
+        })
+    }
+
+    fn restore(&self, snapshot: &TrustSnapshot) -> KyroResult<()> {
+        let invalid = |reason: String| ValidationError::InvalidField {
+            field: "trust_snapshot".to_string(),
+            reason,
+        };
+        if snapshot.model != self.name() {
+            return Err(invalid(format!(
+                "snapshot of trust model '{}' cannot be restored into '{}'",
+                snapshot.model,
+                self.name()
+            ))
+            .into());
+        }
+        let state: SimpleTrustState =
+            serde_json::from_value(snapshot.state.clone()).map_err(|e| invalid(format!("malformed state: {e}")))?;
+        let restored = Self::from_state(state)?;
+
+        *self.global.write().expect("trust global lock poisoned") =
+            restored.global.into_inner().expect("trust global lock poisoned");
+        *self.domain_overrides.write().expect("trust domain lock poisoned") =
+            restored.domain_overrides.into_inner().expect("trust domain lock poisoned");
+        *self
+            .confidence_remaps
+            .write()
+            .expect("trust confidence remap lock poisoned") = restored
+            .confidence_remaps
+            .into_inner()
+            .expect("trust confidence remap lock poisoned");
+        *self
+            .confidence_bounds
+            .write()
+            .expect("trust confidence bounds lock poisoned") = restored
+            .confidence_bounds
+            .into_inner()
+            .expect("trust confidence bounds lock poisoned");
+        *self
+            .paper_credibility
+            .write()
+            .expect("trust paper credibility lock poisoned") = restored
+            .paper_credibility
+            .into_inner()
+            .expect("trust paper credibility lock poisoned");
+        *self
+            .confidence_source_weights
+            .write()
+            .expect("trust confidence source lock poisoned") = restored
+            .confidence_source_weights
+            .into_inner()
+            .expect("trust confidence source lock poisoned");
+        self.changed()
+    }
+
+    fn on_change(&self, hook: TrustChangeHook) {
+        self.hooks.add(hook);
+    }
+}
+
+/// Whether a previously asserted belief turned out to be true.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackOutcome {
+    /// The belief was confirmed.
+    Correct,
+    /// The belief was refuted.
+    Incorrect,
+}
+
+/// Feedback tallies for one source.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceAccuracy {
+    /// Beliefs reported correct.
+    pub correct: u64,
+    /// Beliefs reported incorrect.
+    pub incorrect: u64,
+}
+
+impl SourceAccuracy {
+    /// Posterior mean accuracy under a uniform prior; 0.5 without feedback.
+    #[must_use]
+    pub fn accuracy(&self) -> f32 {
+        let correct = self.correct as f64 + 1.0;
+        let total = (self.correct + self.incorrect) as f64 + 2.0;
+        (correct / total) as f32
+    }
+
+    /// Trust multiplier in [0.0, 1.0].
+    ///
+    /// 1.0 until a source is wrong more often than right, then proportional to its accuracy.
+    #[must_use]
+    pub fn weight(&self) -> f32 {
+        (2.0 * self.accuracy()).min(1.0)
+    }
+
+    fn count(&mut self, outcome: FeedbackOutcome, delta: i8) {
+        let slot = match outcome {
+            FeedbackOutcome::Correct => &mut self.correct,
+            FeedbackOutcome::Incorrect => &mut self.incorrect,
+        };
+        *slot = if delta < 0 {
+            slot.saturating_sub(1)
+        } else {
+            slot.saturating_add(1)
+        };
+    }
+}
+
+/// One FEEDBACK outcome as a [`CalibrationTracker`] records it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackRecord {
+    /// The belief the feedback is about.
+    pub belief: BeliefId,
+    /// The source that asserted it.
+    pub source: SourceId,
+    /// Whether it turned out to be true.
+    pub outcome: FeedbackOutcome,
+}
+
+/// State of a [`CalibrationTracker`]: the last outcome reported for each belief.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalibrationSnapshot {
+    /// Outcomes in no particular order, one per belief.
+    pub outcomes: Vec<FeedbackRecord>,
+}
+
+#[derive(Debug, Default)]
+struct CalibrationState {
+    sources: HashMap<SourceId, SourceAccuracy>,
+    /// Last outcome per belief, so repeated feedback replaces rather than accumulates.
+    outcomes: HashMap<BeliefId, (SourceId, FeedbackOutcome)>,
+}
+
+impl CalibrationState {
+    /// Count `outcome` for `source`, replacing the belief's earlier outcome, if any.
+    fn apply(&mut self, belief: BeliefId, source: SourceId, outcome: FeedbackOutcome) -> SourceAccuracy {
+        if let Some((prev_source, prev)) = self.outcomes.insert(belief, (source, outcome)) {
+            self.sources.entry(prev_source).or_default().count(prev, -1);
+        }
+        let accuracy = self.sources.entry(source).or_default();
+        accuracy.count(outcome, 1);
+        *accuracy
+    }
+}
+
+/// Per-source accuracy learned from FEEDBACK outcomes.
+#[derive(Debug, Default)]
+pub struct CalibrationTracker {
+    state: RwLock<CalibrationState>,
+    hooks: Hooks<FeedbackRecord>,
+}
+
+impl CalibrationTracker {
+    /// Create an empty tracker.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `outcome` for `belief`, asserted by `source`.
+    ///
+    /// Reporting a belief again replaces its earlier outcome. Returns the source's updated tally.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of an [`on_record`](Self::on_record) hook that failed to save the
+    /// outcome. The outcome is still counted.
+    pub fn record(&self, belief: BeliefId, source: SourceId, outcome: FeedbackOutcome) -> KyroResult<SourceAccuracy> {
+        let mut state = self.state.write().expect("calibration lock poisoned");
+        let accuracy = state.apply(belief, source, outcome);
+        // Still under the state lock, so hooks see the records in the order they applied.
+        let notified = self.hooks.notify(|| Some(FeedbackRecord { belief, source, outcome }));
+        drop(state);
+        notified.map(|()| accuracy)
+    }
+
+    /// Count `record` without reporting it to [`on_record`](Self::on_record) hooks.
+    #[cfg(feature = "persistent")]
+    pub(crate) fn apply(&self, record: FeedbackRecord) -> SourceAccuracy {
+        let mut state = self.state.write().expect("calibration lock poisoned");
+        state.apply(record.belief, record.source, record.outcome)
+    }
+
+    /// Call `hook` with every outcome recorded from now on, e.g. to persist it.
+    ///
+    /// An error from `hook` is returned by the [`record`](Self::record) call.
+    pub fn on_record(&self, hook: FeedbackHook) {
+        self.hooks.add(hook);
+    }
+
+    /// Capture the recorded outcomes, to restore them after a restart.
+    #[must_use]
+    pub fn snapshot(&self) -> CalibrationSnapshot {
+        let guard = self.state.read().expect("calibration lock poisoned");
+        CalibrationSnapshot {
+            outcomes: guard
+                .outcomes
+                .iter()
+                .map(|(&belief, &(source, outcome))| FeedbackRecord { belief, source, outcome })
+                .collect(),
+        }
+    }
+
+    /// Replace the recorded outcomes with a [`snapshot`](Self::snapshot).
+    ///
+    /// The restored outcomes are not reported to [`on_record`](Self::on_record) hooks.
+    pub fn restore(&self, snapshot: &CalibrationSnapshot) {
+        let mut state = CalibrationState::default();
+        for record in &snapshot.outcomes {
+            state.apply(record.belief, record.source, record.outcome);
+        }
+        *self.state.write().expect("calibration lock poisoned") = state;
+    }
+
+    /// Feedback tally for `source`.
+    #[must_use]
+    pub fn accuracy(&self, source: SourceId) -> SourceAccuracy {
+        let guard = self.state.read().expect("calibration lock poisoned");
+        guard.sources.get(&source).copied().unwrap_or_default()
+    }
+
+    /// Trust multiplier for `source`; see [`SourceAccuracy::weight`].
+    #[must_use]
+    pub fn weight(&self, source: SourceId) -> f32 {
+        self.accuracy(source).weight()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::KyroError;
 
     #[test]
     fn default_weight_is_one() {
@@ -123,12 +708,164 @@ mod tests {
         let model = SimpleTrustModel::new();
         let source = Source::agent("agent-1", None::<String>);
         let sid = source.source_id();
-        model.set_global(sid, 0.8);
-        model.set_domain("science", sid, 0.2);
+        model.set_global(sid, 0.8).unwrap();
+        model.set_domain("science", sid, 0.2).unwrap();
 
         let global = model.assess(&source, None);
         let domain = model.assess(&source, Some("science"));
         assert_eq!(global.weight(), 0.8);
         assert_eq!(domain.weight(), 0.2);
     }
+
+    #[test]
+    fn confidence_bounds_clamp_only_the_configured_source() {
+        let model = SimpleTrustModel::new();
+        let capped = Source::agent("capped", Option::<String>::None);
+        let other = Source::agent("other", Option::<String>::None);
+        model.set_confidence_bounds(capped.source_id(), 0.2, 0.7).unwrap();
+
+        assert!((model.clamp_confidence(&capped, 0.99) - 0.7).abs() < f32::EPSILON);
+        assert!((model.clamp_confidence(&capped, 0.05) - 0.2).abs() < f32::EPSILON);
+        assert!((model.clamp_confidence(&capped, 0.5) - 0.5).abs() < f32::EPSILON);
+        assert!((model.clamp_confidence(&other, 0.99) - 0.99).abs() < f32::EPSILON);
+
+        // An inverted range collapses to `max`.
+        model.set_confidence_bounds(capped.source_id(), 0.9, 0.3).unwrap();
+        assert!((model.clamp_confidence(&capped, 0.1) - 0.3).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn confidence_remaps_are_validated_and_clamped() {
+        let model = SimpleTrustModel::new();
+        let timid = Source::agent("timid", Option::<String>::None);
+        let other = Source::agent("other", Option::<String>::None);
+        model.set_confidence_remap(timid.source_id(), 1.5, 0.0).unwrap();
+
+        assert!((model.normalize_confidence(&timid, 0.6) - 0.9).abs() < 1e-6);
+        assert_eq!(model.normalize_confidence(&timid, 0.8), 1.0);
+        assert_eq!(model.normalize_confidence(&other, 0.6), 0.6);
+
+        for (scale, offset) in [(0.0, 0.5), (-1.0, 1.0), (f32::NAN, 0.0), (1.0, 1.0), (0.5, -0.5)] {
+            assert!(model.set_confidence_remap(timid.source_id(), scale, offset).is_err());
+        }
+        assert!((model.normalize_confidence(&timid, 0.6) - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn paper_credibility_scales_paper_sources_only() {
+        let model = SimpleTrustModel::new();
+        let cited = Source::paper("2307.12008", "LK-99 Initial Report");
+        let by_doi = Source::paper_doi("10.1000/xyz", "Replication");
+        let agent = Source::agent("agent-1", None::<String>);
+        model.set_global(cited.source_id(), 0.8).unwrap();
+        model.set_paper_credibility("2307.12008", 0.5).unwrap();
+        model.set_paper_credibility("10.1000/xyz", 3.0).unwrap();
+
+        assert!((model.assess(&cited, None).weight() - 0.4).abs() < f32::EPSILON);
+        assert_eq!(model.assess(&by_doi, None).weight(), 1.0);
+        assert_eq!(model.assess(&agent, None).weight(), 1.0);
+        assert_eq!(model.assess(&Source::paper("unscored", "x"), None).weight(), 1.0);
+    }
+
+    #[test]
+    fn confidence_source_weights_default_to_one_and_apply_per_kind() {
+        let model = SimpleTrustModel::new();
+        let human = ConfidenceSource::AssertedByHuman { user_id: "u".to_string() };
+        let curator = ConfidenceSource::AssertedByHuman { user_id: "v".to_string() };
+        let agent = ConfidenceSource::AssertedByAgent { agent_id: "a".to_string() };
+        assert_eq!(model.confidence_source_weight(&human), 1.0);
+
+        model.set_confidence_source_weight(ConfidenceSourceKind::Human, 1.5).unwrap();
+        model.set_confidence_source_weight(ConfidenceSourceKind::Model, -1.0).unwrap();
+        assert_eq!(model.confidence_source_weight(&human), 1.5);
+        assert_eq!(model.confidence_source_weight(&curator), 1.5);
+        assert_eq!(model.confidence_source_weight(&agent), 1.0);
+        let gpt = ConfidenceSource::ComputedByModel {
+            model_id: "m".to_string(),
+            model_version: "1".to_string(),
+        };
+        assert_eq!(model.confidence_source_weight(&gpt), 0.0);
+    }
+
+    #[test]
+    fn calibration_downweights_sources_that_are_mostly_wrong() {
+        let tracker = CalibrationTracker::new();
+        let source = SourceId::new();
+        assert_eq!(tracker.weight(source), 1.0);
+
+        tracker.record(BeliefId::new(), source, FeedbackOutcome::Correct).unwrap();
+        assert_eq!(tracker.weight(source), 1.0);
+
+        for _ in 0..4 {
+            tracker.record(BeliefId::new(), source, FeedbackOutcome::Incorrect).unwrap();
+        }
+        // 2 of 7 under the uniform prior.
+        assert!((tracker.weight(source) - 4.0 / 7.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn calibration_feedback_for_a_belief_replaces_earlier_feedback() {
+        let tracker = CalibrationTracker::new();
+        let source = SourceId::new();
+        let belief = BeliefId::new();
+
+        tracker.record(belief, source, FeedbackOutcome::Incorrect).unwrap();
+        tracker.record(belief, source, FeedbackOutcome::Incorrect).unwrap();
+        let tally = tracker.record(belief, source, FeedbackOutcome::Correct).unwrap();
+        assert_eq!(tally, SourceAccuracy { correct: 1, incorrect: 0 });
+    }
+
+    #[test]
+    fn snapshots_restore_the_configuration_into_a_fresh_model() {
+        let source = Source::agent("agent-1", None::<String>);
+        let sid = source.source_id();
+        let model = SimpleTrustModel::new();
+        model.set_global(sid, 0.8).unwrap();
+        model.set_domain("science", sid, 0.2).unwrap();
+        model.set_confidence_bounds(sid, 0.1, 0.7).unwrap();
+        model.set_paper_credibility("arxiv:1234.5678", 0.5).unwrap();
+        model.set_confidence_source_weight(ConfidenceSourceKind::Human, 1.5).unwrap();
+
+        let snapshot = model.snapshot().unwrap();
+        let restored = SimpleTrustModel::new();
+        restored.set_global(sid, 0.1).unwrap();
+        restored.restore(&snapshot).unwrap();
+
+        assert_eq!(restored.assess(&source, None).weight(), 0.8);
+        assert_eq!(restored.assess(&source, Some("science")).weight(), 0.2);
+        assert_eq!(restored.clamp_confidence(&source, 0.9), 0.7);
+        assert_eq!(restored.snapshot(), Some(snapshot.clone()));
+
+        let foreign = TrustSnapshot {
+            model: "other_trust".to_string(),
+            ..snapshot
+        };
+        assert!(matches!(
+            restored.restore(&foreign),
+            Err(KyroError::Validation(ValidationError::InvalidField { .. }))
+        ));
+    }
+
+    #[test]
+    fn calibration_snapshots_restore_the_tallies_and_hooks_see_each_record() {
+        let tracker = CalibrationTracker::new();
+        let seen = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&seen);
+        tracker.on_record(Box::new(move |record| {
+            sink.lock().unwrap().push(*record);
+            Ok(())
+        }));
+
+        let source = SourceId::new();
+        let belief = BeliefId::new();
+        tracker.record(belief, source, FeedbackOutcome::Incorrect).unwrap();
+        tracker.record(belief, source, FeedbackOutcome::Correct).unwrap();
+        tracker.record(BeliefId::new(), source, FeedbackOutcome::Incorrect).unwrap();
+        assert_eq!(seen.lock().unwrap().len(), 3);
+
+        let restored = CalibrationTracker::new();
+        restored.restore(&tracker.snapshot());
+        assert_eq!(restored.accuracy(source), SourceAccuracy { correct: 1, incorrect: 1 });
+        assert_eq!(seen.lock().unwrap().len(), 3);
+    }
 }
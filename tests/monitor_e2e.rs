@@ -6,7 +6,9 @@ use uuid::Uuid;
 
 use kyroql::engine::EngineResponse;
 use kyroql::ir::{AssertPayload, ConsistencyMode, KyroIR, MonitorPayload, Operation};
-use kyroql::monitor::{MonitorSystem, MonitorSystemConfig};
+use kyroql::monitor::{
+    EventPayload, MonitorDelivery, MonitorEventError, MonitorOverflowPolicy, MonitorStream, MonitorSystem, MonitorSystemConfig,
+};
 use kyroql::monitor::matcher::AssertObservation;
 use kyroql::storage::InMemoryStores;
 use kyroql::conflict::ConflictType;
@@ -40,6 +42,8 @@ fn monitor_confidence_shift_streams_event() {
             valid_time: TimeRange::starting_at(t0),
             consistency_mode: ConsistencyMode::Force,
             embedding: None,
+            idempotency_key: None,
+            namespace: None,
         }),
     };
 
@@ -59,6 +63,7 @@ fn monitor_confidence_shift_streams_event() {
             threshold: Some(Value::Float(0.5)),
             expires_at: Some(t0 + ChronoDuration::seconds(30)),
             callback: None,
+            namespace: None,
         }),
     };
 
@@ -80,6 +85,8 @@ fn monitor_confidence_shift_streams_event() {
             valid_time: TimeRange::starting_at(t1),
             consistency_mode: ConsistencyMode::Force,
             embedding: None,
+            idempotency_key: None,
+            namespace: None,
         }),
     };
 
@@ -118,6 +125,7 @@ fn monitor_pattern_violation_streams_event() {
             predicate: "temp".to_string(),
             min: Some(0.0),
             max: Some(10.0),
+            coerce: false,
         },
         Confidence::from_agent(0.99, "system").unwrap(),
     );
@@ -139,6 +147,7 @@ fn monitor_pattern_violation_streams_event() {
             threshold: Some(Value::Null),
             expires_at: Some(t0 + ChronoDuration::seconds(30)),
             callback: None,
+            namespace: None,
         }),
     };
 
@@ -160,6 +169,8 @@ fn monitor_pattern_violation_streams_event() {
             valid_time: TimeRange::starting_at(t1),
             consistency_mode: ConsistencyMode::Eventual,
             embedding: None,
+            idempotency_key: None,
+            namespace: None,
         }),
     };
 
@@ -208,6 +219,7 @@ fn monitor_expiry_disconnects_stream() {
             threshold: Some(Value::Float(0.1)),
             expires_at: Some(t0 + ChronoDuration::milliseconds(500)),
             callback: None,
+            namespace: None,
         }),
     };
 
@@ -218,12 +230,78 @@ fn monitor_expiry_disconnects_stream() {
     // Wait long enough for expiry + worker cleanup tick.
     std::thread::sleep(Duration::from_millis(700));
 
-    // The stream should become disconnected once the subscription is removed.
+    // The stream should report expiry once the subscription is removed.
     let err = registration.stream.recv_timeout(Duration::from_millis(50)).unwrap_err();
-    let kyroql::KyroError::Execution(kyroql::error::ExecutionError::Disconnected { path }) = err else {
-        panic!("expected disconnected, got {err:?}");
+    let kyroql::KyroError::Execution(kyroql::error::ExecutionError::MonitorExpired { subscription_id, .. }) = err
+    else {
+        panic!("expected monitor expired, got {err:?}");
     };
-    assert_eq!(path, "monitor_stream");
+    assert_eq!(subscription_id, registration.subscription_id.to_string());
+}
+
+#[test]
+fn monitor_expired_trigger_stops_delivering_and_is_swept() {
+    let stores = InMemoryStores::default();
+    let entities = Arc::new(stores.entities);
+    let beliefs = Arc::new(stores.beliefs);
+    let patterns = Arc::new(stores.patterns);
+    let conflicts = Arc::new(stores.conflicts);
+    let derivations = Arc::new(stores.derivations);
+
+    let entity = Entity::new("e", EntityType::Concept);
+    entities.insert(entity.clone()).unwrap();
+
+    let engine = kyroql::KyroEngine::new(entities, beliefs, patterns, conflicts, derivations);
+    let assert = |confidence: f32| {
+        let ir = KyroIR::new(Operation::Assert(AssertPayload {
+            entity_id: entity.id,
+            predicate: "p".to_string(),
+            value: Value::Int(1),
+            confidence: Confidence::from_agent(confidence, "a").unwrap(),
+            source: Source::Unknown { description: None },
+            valid_time: TimeRange::starting_at(Utc::now()),
+            consistency_mode: ConsistencyMode::Force,
+            embedding: None,
+            idempotency_key: None,
+            namespace: None,
+        }));
+        let EngineResponse::Assert { .. } = engine.execute(ir).unwrap() else {
+            panic!("expected assert response");
+        };
+    };
+
+    assert(0.2);
+    let monitor = KyroIR::new(Operation::Monitor(MonitorPayload {
+        description: Some("short lived".to_string()),
+        predicates: Some(vec!["p".to_string()]),
+        entity_filter: Some(vec![entity.id]),
+        pattern_filter: None,
+        threshold: Some(Value::Float(0.5)),
+        expires_at: Some(Utc::now() + ChronoDuration::milliseconds(400)),
+        callback: None,
+        namespace: None,
+    }));
+    let EngineResponse::Monitor { registration } = engine.execute(monitor).unwrap() else {
+        panic!("expected monitor response");
+    };
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(engine.monitor_system().active_subscriptions(), 1);
+
+    // Before expiry the trigger delivers.
+    assert(0.9);
+    registration.stream.recv_timeout(Duration::from_secs(1)).unwrap();
+
+    // After expiry it is removed from the dispatcher and a matching assert delivers nothing.
+    std::thread::sleep(Duration::from_millis(500));
+    assert(0.1);
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(engine.monitor_system().active_subscriptions(), 0);
+
+    let err = registration.stream.recv_timeout(Duration::from_millis(200)).unwrap_err();
+    assert!(
+        matches!(err, kyroql::KyroError::Execution(kyroql::error::ExecutionError::MonitorExpired { .. })),
+        "expected monitor expired, got {err:?}"
+    );
 }
 
 #[test]
@@ -253,6 +331,7 @@ fn monitor_drop_stream_unregisters_subscription() {
             threshold: Some(Value::Float(0.1)),
             expires_at: Some(t0 + ChronoDuration::seconds(30)),
             callback: None,
+            namespace: None,
         }),
     };
 
@@ -285,6 +364,7 @@ fn monitor_backpressure_increments_dropped_events() {
         observation_queue_capacity: 1024,
         control_queue_capacity: 64,
         stream_capacity: 1,
+        ..MonitorSystemConfig::default()
     };
     let monitor = MonitorSystem::new(cfg, Arc::clone(&beliefs));
 
@@ -294,7 +374,7 @@ fn monitor_backpressure_increments_dropped_events() {
     }];
 
     let reg = monitor
-        .register(triggers, Some(Utc::now() + ChronoDuration::seconds(30)))
+        .register(triggers, Some(Utc::now() + ChronoDuration::seconds(30)), None)
         .unwrap();
 
     // Intentionally do not read from the stream to force backpressure.
@@ -311,6 +391,7 @@ fn monitor_backpressure_increments_dropped_events() {
                 pattern_id: "x".to_string(),
                 pattern_name: "m".to_string(),
             }],
+            namespace: None,
         });
     }
 
@@ -329,3 +410,474 @@ fn monitor_backpressure_increments_dropped_events() {
 
     assert!(dropped > 0, "expected dropped_events > 0 due to backpressure");
 }
+
+#[test]
+fn monitor_value_match_streams_only_matching_asserts() {
+    let stores = InMemoryStores::default();
+    let entities = Arc::new(stores.entities);
+    let beliefs = Arc::new(stores.beliefs);
+    let patterns = Arc::new(stores.patterns);
+    let conflicts = Arc::new(stores.conflicts);
+    let derivations = Arc::new(stores.derivations);
+
+    let entity = Entity::new("job", EntityType::Concept);
+    entities.insert(entity.clone()).unwrap();
+
+    let engine = kyroql::KyroEngine::new(entities, beliefs, patterns, conflicts, derivations);
+
+    let t0 = Utc::now();
+    let trigger = kyroql::Trigger::ValueMatch {
+        entity_filter: Some(entity.id),
+        predicate: "status".to_string(),
+        matcher: kyroql::ValueMatcher::Equals {
+            value: Value::String("failed".to_string()),
+        },
+    };
+    let monitor = KyroIR {
+        version: KyroIR::CURRENT_VERSION.to_string(),
+        request_id: Uuid::new_v4(),
+        timestamp: t0,
+        operation: Operation::Monitor(MonitorPayload {
+            description: Some("status became failed".to_string()),
+            predicates: None,
+            entity_filter: None,
+            pattern_filter: None,
+            threshold: Some(Value::Structured(serde_json::to_value(&trigger).unwrap())),
+            expires_at: Some(t0 + ChronoDuration::seconds(30)),
+            callback: None,
+            namespace: None,
+        }),
+    };
+
+    let EngineResponse::Monitor { registration } = engine.execute(monitor).unwrap() else {
+        panic!("expected monitor response");
+    };
+
+    let mut failed_id = None;
+    for (i, status) in ["running", "failed", "done"].into_iter().enumerate() {
+        let t = t0 + ChronoDuration::milliseconds(i as i64 + 1);
+        let assert = KyroIR {
+            version: KyroIR::CURRENT_VERSION.to_string(),
+            request_id: Uuid::new_v4(),
+            timestamp: t,
+            operation: Operation::Assert(AssertPayload {
+                entity_id: entity.id,
+                predicate: "status".to_string(),
+                value: Value::String(status.to_string()),
+                confidence: Confidence::from_agent(0.9, "a").unwrap(),
+                source: Source::Unknown { description: None },
+                valid_time: TimeRange::starting_at(t),
+                consistency_mode: ConsistencyMode::Force,
+                embedding: None,
+                idempotency_key: None,
+                namespace: None,
+            }),
+        };
+        let EngineResponse::Assert { belief_id, .. } = engine.execute(assert).unwrap() else {
+            panic!("expected assert response");
+        };
+        if status == "failed" {
+            failed_id = Some(belief_id);
+        }
+    }
+
+    let ev = registration
+        .stream
+        .recv_timeout(Duration::from_secs(1))
+        .unwrap();
+    match ev.payload {
+        kyroql::EventPayload::ValueMatch { belief_id, value, .. } => {
+            assert_eq!(Some(belief_id), failed_id);
+            assert_eq!(value, Value::String("failed".to_string()));
+        }
+        other => panic!("expected value match event, got {other:?}"),
+    }
+
+    assert!(registration
+        .stream
+        .recv_timeout(Duration::from_millis(200))
+        .is_err());
+}
+
+#[test]
+fn monitor_sees_only_its_own_namespace() {
+    let stores = InMemoryStores::default();
+    let entities = Arc::new(stores.entities);
+    let beliefs = Arc::new(stores.beliefs);
+    let patterns = Arc::new(stores.patterns);
+    let conflicts = Arc::new(stores.conflicts);
+    let derivations = Arc::new(stores.derivations);
+
+    let job_a = Entity::new("job", EntityType::Concept).with_namespace("tenant-a");
+    let job_b = Entity::new("job", EntityType::Concept).with_namespace("tenant-b");
+    entities.insert(job_a.clone()).unwrap();
+    entities.insert(job_b.clone()).unwrap();
+
+    let engine = kyroql::KyroEngine::new(entities, beliefs, patterns, conflicts, derivations);
+
+    // No entity filter: without the namespace, every tenant's asserts would match.
+    let trigger = kyroql::Trigger::ValueMatch {
+        entity_filter: None,
+        predicate: "status".to_string(),
+        matcher: kyroql::ValueMatcher::Equals {
+            value: Value::String("failed".to_string()),
+        },
+    };
+    let monitor = KyroIR::new(Operation::Monitor(MonitorPayload {
+        description: None,
+        predicates: None,
+        entity_filter: None,
+        pattern_filter: None,
+        threshold: Some(Value::Structured(serde_json::to_value(&trigger).unwrap())),
+        expires_at: Some(Utc::now() + ChronoDuration::seconds(30)),
+        callback: None,
+        namespace: Some("tenant-a".to_string()),
+    }));
+    let EngineResponse::Monitor { registration } = engine.execute(monitor).unwrap() else {
+        panic!("expected monitor response");
+    };
+
+    let assert = |entity: &Entity| {
+        let ir = KyroIR::new(Operation::Assert(AssertPayload {
+            entity_id: entity.id,
+            predicate: "status".to_string(),
+            value: Value::String("failed".to_string()),
+            confidence: Confidence::from_agent(0.9, "a").unwrap(),
+            source: Source::Unknown { description: None },
+            valid_time: TimeRange::forever(),
+            consistency_mode: ConsistencyMode::Force,
+            embedding: None,
+            idempotency_key: None,
+            namespace: entity.namespace.clone(),
+        }));
+        let EngineResponse::Assert { belief_id, .. } = engine.execute(ir).unwrap() else {
+            panic!("expected assert response");
+        };
+        belief_id
+    };
+
+    assert(&job_b);
+    let in_a = assert(&job_a);
+
+    let ev = registration.stream.recv_timeout(Duration::from_secs(1)).unwrap();
+    match ev.payload {
+        kyroql::EventPayload::ValueMatch { belief_id, .. } => assert_eq!(belief_id, in_a),
+        other => panic!("expected value match event, got {other:?}"),
+    }
+    assert!(registration
+        .stream
+        .recv_timeout(Duration::from_millis(200))
+        .is_err());
+}
+
+#[test]
+fn monitor_rejects_invalid_value_matcher_at_registration() {
+    let stores = InMemoryStores::default();
+    let beliefs: Arc<dyn kyroql::storage::BeliefStore> = Arc::new(stores.beliefs);
+    let monitor = MonitorSystem::new(MonitorSystemConfig::default(), beliefs);
+
+    let triggers = vec![kyroql::Trigger::ValueMatch {
+        entity_filter: None,
+        predicate: "status".to_string(),
+        matcher: kyroql::ValueMatcher::Regex {
+            pattern: "fail(".to_string(),
+        },
+    }];
+
+    let err = monitor.register(triggers, None, None).unwrap_err();
+    let kyroql::KyroError::Validation(kyroql::ValidationError::InvalidTrigger(err)) = err else {
+        panic!("expected invalid trigger, got {err:?}");
+    };
+    let MonitorEventError::InvalidMatcher { reason } = *err else {
+        panic!("expected invalid matcher, got {err:?}");
+    };
+    assert!(reason.contains("fail("));
+}
+
+const SLOW_STREAM_CAPACITY: usize = 4;
+const FLOOD_EVENTS: usize = 16;
+
+fn overflow_monitor(policy: MonitorOverflowPolicy) -> MonitorSystem {
+    let stores = InMemoryStores::default();
+    let beliefs: Arc<dyn kyroql::storage::BeliefStore> = Arc::new(stores.beliefs);
+    let cfg = MonitorSystemConfig {
+        stream_capacity: SLOW_STREAM_CAPACITY,
+        overflow_policy: policy,
+        ..MonitorSystemConfig::default()
+    };
+    MonitorSystem::new(cfg, beliefs)
+}
+
+fn conflict_subscription(monitor: &MonitorSystem) -> MonitorStream {
+    let triggers = vec![kyroql::Trigger::ConflictCreated {
+        entity_id: None,
+        conflict_types: Vec::new(),
+    }];
+    monitor.register(triggers, None, None).unwrap().stream
+}
+
+fn conflict_observation(belief_id: kyroql::BeliefId) -> AssertObservation {
+    AssertObservation {
+        tx_time: Utc::now(),
+        belief_id,
+        entity_id: kyroql::EntityId::new(),
+        predicate: "p".to_string(),
+        value: Value::Int(1),
+        confidence: 0.5,
+        conflict_types: vec![ConflictType::PatternViolation {
+            pattern_id: "x".to_string(),
+            pattern_name: "m".to_string(),
+        }],
+        namespace: None,
+    }
+}
+
+fn event_belief_id(stream: &MonitorStream) -> kyroql::BeliefId {
+    match stream.recv_timeout(Duration::from_secs(5)).unwrap().payload {
+        EventPayload::ConflictCreated { belief_id, .. } => belief_id,
+        other => panic!("unexpected payload: {other:?}"),
+    }
+}
+
+/// Observes `FLOOD_EVENTS` conflicts while `fast` reads each event as it arrives.
+fn flood_with_fast_reader(monitor: &MonitorSystem, fast: &MonitorStream) -> Vec<kyroql::BeliefId> {
+    let mut sent = Vec::with_capacity(FLOOD_EVENTS);
+    for _ in 0..FLOOD_EVENTS {
+        let belief_id = kyroql::BeliefId::new();
+        monitor.observe_assert(conflict_observation(belief_id));
+        assert_eq!(event_belief_id(fast), belief_id);
+        sent.push(belief_id);
+    }
+    sent
+}
+
+fn wait_for_dropped_events(monitor: &MonitorSystem, expected: u64) {
+    for _ in 0..200 {
+        if monitor.dropped_events() >= expected {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(monitor.dropped_events(), expected);
+}
+
+#[test]
+fn monitor_drop_newest_keeps_earliest_events_for_slow_subscriber() {
+    let monitor = overflow_monitor(MonitorOverflowPolicy::DropNewest);
+    let fast = conflict_subscription(&monitor);
+    let slow = conflict_subscription(&monitor);
+
+    let sent = flood_with_fast_reader(&monitor, &fast);
+    wait_for_dropped_events(&monitor, (FLOOD_EVENTS - SLOW_STREAM_CAPACITY) as u64);
+
+    for expected in &sent[..SLOW_STREAM_CAPACITY] {
+        assert_eq!(event_belief_id(&slow), *expected);
+    }
+    assert_eq!(slow.dropped_events(), (FLOOD_EVENTS - SLOW_STREAM_CAPACITY) as u64);
+    assert_eq!(fast.dropped_events(), 0);
+    assert_eq!(monitor.active_subscriptions(), 2);
+}
+
+#[test]
+fn monitor_drop_oldest_keeps_latest_events_for_slow_subscriber() {
+    let monitor = overflow_monitor(MonitorOverflowPolicy::DropOldest);
+    let fast = conflict_subscription(&monitor);
+    let slow = conflict_subscription(&monitor);
+
+    let sent = flood_with_fast_reader(&monitor, &fast);
+    wait_for_dropped_events(&monitor, (FLOOD_EVENTS - SLOW_STREAM_CAPACITY) as u64);
+
+    for expected in &sent[FLOOD_EVENTS - SLOW_STREAM_CAPACITY..] {
+        assert_eq!(event_belief_id(&slow), *expected);
+    }
+    assert_eq!(slow.dropped_events(), (FLOOD_EVENTS - SLOW_STREAM_CAPACITY) as u64);
+
+    // The slow subscriber stays registered and keeps receiving new events.
+    let belief_id = kyroql::BeliefId::new();
+    monitor.observe_assert(conflict_observation(belief_id));
+    assert_eq!(event_belief_id(&fast), belief_id);
+    assert_eq!(event_belief_id(&slow), belief_id);
+    assert_eq!(monitor.active_subscriptions(), 2);
+}
+
+#[test]
+fn monitor_disconnect_policy_drops_slow_subscriber_with_overflow_error() {
+    let monitor = overflow_monitor(MonitorOverflowPolicy::Disconnect);
+    let fast = conflict_subscription(&monitor);
+    let slow = conflict_subscription(&monitor);
+
+    let sent = flood_with_fast_reader(&monitor, &fast);
+    wait_for_dropped_events(&monitor, 1);
+
+    for expected in &sent[..SLOW_STREAM_CAPACITY] {
+        assert_eq!(event_belief_id(&slow), *expected);
+    }
+    let err = slow.recv_timeout(Duration::from_secs(5)).unwrap_err();
+    match err {
+        kyroql::KyroError::Execution(kyroql::error::ExecutionError::MonitorOverflow {
+            subscription_id,
+            dropped_events,
+        }) => {
+            assert_eq!(subscription_id, slow.subscription_id().to_string());
+            assert_eq!(dropped_events, 1);
+        }
+        other => panic!("expected MonitorOverflow, got {other:?}"),
+    }
+    assert_eq!(monitor.active_subscriptions(), 1);
+}
+
+#[test]
+fn monitor_block_policy_delivers_everything_once_slow_subscriber_catches_up() {
+    let monitor = overflow_monitor(MonitorOverflowPolicy::Block);
+    let fast = conflict_subscription(&monitor);
+    let slow = conflict_subscription(&monitor);
+
+    let slow_reader = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(200));
+        (0..FLOOD_EVENTS).map(|_| event_belief_id(&slow)).collect::<Vec<_>>()
+    });
+
+    let sent = flood_with_fast_reader(&monitor, &fast);
+    assert_eq!(slow_reader.join().unwrap(), sent);
+    assert_eq!(monitor.dropped_events(), 0);
+}
+
+#[test]
+fn monitor_rate_fires_once_for_a_burst_but_not_for_a_trickle() {
+    let stores = InMemoryStores::default();
+    let beliefs: Arc<dyn kyroql::storage::BeliefStore> = Arc::new(stores.beliefs);
+    let monitor = MonitorSystem::new(MonitorSystemConfig::default(), beliefs);
+    let rate = |predicate: &str| kyroql::Trigger::Rate {
+        predicate: predicate.to_string(),
+        count: 5,
+        window_seconds: 60,
+    };
+    let burst = monitor.register(vec![rate("login_failed")], None, None).unwrap().stream;
+    let trickle = monitor.register(vec![rate("heartbeat")], None, None).unwrap().stream;
+
+    let t0 = Utc::now();
+    let observe = |predicate: &str, secs: i64| {
+        let belief_id = kyroql::BeliefId::new();
+        monitor.observe_assert(AssertObservation {
+            tx_time: t0 + ChronoDuration::seconds(secs),
+            belief_id,
+            entity_id: kyroql::EntityId::new(),
+            predicate: predicate.to_string(),
+            value: Value::Bool(true),
+            confidence: 0.9,
+            conflict_types: Vec::new(),
+            namespace: None,
+        });
+        belief_id
+    };
+
+    let crossing: Vec<_> = (0..8).map(|i| observe("login_failed", i)).collect();
+    for i in 0..8 {
+        observe("heartbeat", i * 20);
+    }
+
+    match burst.recv_timeout(Duration::from_secs(1)).unwrap().payload {
+        EventPayload::Rate { belief_id, count, .. } => {
+            assert_eq!(belief_id, crossing[4]);
+            assert_eq!(count, 5);
+        }
+        other => panic!("expected rate event, got {other:?}"),
+    }
+    assert!(burst.recv_timeout(Duration::from_millis(200)).is_err());
+    assert!(trickle.recv_timeout(Duration::from_millis(200)).is_err());
+}
+
+#[test]
+fn monitor_rejects_rate_without_a_count() {
+    let stores = InMemoryStores::default();
+    let beliefs: Arc<dyn kyroql::storage::BeliefStore> = Arc::new(stores.beliefs);
+    let monitor = MonitorSystem::new(MonitorSystemConfig::default(), beliefs);
+
+    let trigger = kyroql::Trigger::Rate {
+        predicate: "login_failed".to_string(),
+        count: 0,
+        window_seconds: 60,
+    };
+    let err = monitor.register(vec![trigger], None, None).unwrap_err();
+    assert!(err.to_string().contains("rate count must be at least 1"));
+}
+
+fn at_least_once_monitor(resume_window: Duration) -> MonitorSystem {
+    let stores = InMemoryStores::default();
+    let beliefs: Arc<dyn kyroql::storage::BeliefStore> = Arc::new(stores.beliefs);
+    let cfg = MonitorSystemConfig {
+        delivery: MonitorDelivery::AtLeastOnce {
+            unacked_capacity: 8,
+            resume_window,
+        },
+        ..MonitorSystemConfig::default()
+    };
+    MonitorSystem::new(cfg, beliefs)
+}
+
+#[test]
+fn monitor_at_least_once_redelivers_unacked_events_on_resume() {
+    let monitor = at_least_once_monitor(Duration::from_secs(30));
+    let stream = conflict_subscription(&monitor);
+    let subscription_id = stream.subscription_id();
+
+    let sent: Vec<_> = (0..3).map(|_| kyroql::BeliefId::new()).collect();
+    for belief_id in &sent {
+        monitor.observe_assert(conflict_observation(*belief_id));
+    }
+    let sequences: Vec<u64> = (0..3)
+        .map(|_| stream.recv_timeout(Duration::from_secs(5)).unwrap().sequence)
+        .collect();
+    assert_eq!(sequences, [1, 2, 3]);
+
+    // The subscriber processes the first event, then crashes before acking the others.
+    stream.ack(1);
+    drop(stream);
+
+    // Events fired while detached are kept for the resumed stream.
+    let missed = kyroql::BeliefId::new();
+    monitor.observe_assert(conflict_observation(missed));
+
+    let resumed = monitor.resume(subscription_id).unwrap();
+    let redelivered: Vec<_> = (0..3)
+        .map(|_| {
+            let event = resumed.recv_timeout(Duration::from_secs(5)).unwrap();
+            let EventPayload::ConflictCreated { belief_id, .. } = event.payload else {
+                panic!("unexpected payload: {:?}", event.payload);
+            };
+            (event.sequence, belief_id)
+        })
+        .collect();
+    assert_eq!(redelivered, [(2, sent[1]), (3, sent[2]), (4, missed)]);
+
+    // Acknowledged events are not delivered again.
+    monitor.ack(subscription_id, 4).unwrap();
+    drop(resumed);
+    let resumed = monitor.resume(subscription_id).unwrap();
+    assert!(resumed.recv_timeout(Duration::from_millis(100)).is_err());
+}
+
+#[test]
+fn monitor_resume_fails_for_unsubscribed_abandoned_and_at_most_once_subscriptions() {
+    let not_found = |result: kyroql::error::KyroResult<MonitorStream>| {
+        let err = result.unwrap_err();
+        assert!(
+            matches!(err, kyroql::KyroError::Execution(kyroql::error::ExecutionError::MonitorNotFound { .. })),
+            "expected monitor not found, got {err:?}"
+        );
+    };
+
+    let monitor = at_least_once_monitor(Duration::from_millis(50));
+    let stream = conflict_subscription(&monitor);
+    let unsubscribed = stream.subscription_id();
+    stream.unsubscribe();
+    not_found(monitor.resume(unsubscribed));
+
+    let abandoned = conflict_subscription(&monitor).subscription_id();
+    std::thread::sleep(Duration::from_millis(200));
+    not_found(monitor.resume(abandoned));
+
+    let at_most_once = overflow_monitor(MonitorOverflowPolicy::DropNewest);
+    let stream = conflict_subscription(&at_most_once);
+    not_found(at_most_once.resume(stream.subscription_id()));
+}
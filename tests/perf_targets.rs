@@ -72,6 +72,7 @@ fn resolve_perf_out_path(raw: &str) -> PathBuf {
 /// - `KYRO_ENFORCE_VISION_PERF=1 cargo test --release --test perf_targets -- --ignored --nocapture`
 #[test]
 #[ignore]
+#[allow(clippy::assertions_on_constants)]
 fn vision_perf_targets_report() {
     assert!(
         !cfg!(debug_assertions),
@@ -8,7 +8,8 @@
 #![cfg(feature = "persistent")]
 
 use kyroql::entity::{Entity, EntityType};
-use kyroql::storage::{open_database, EntityStore};
+use kyroql::storage::{open_database, EntityStore, IdempotencyStore};
+use kyroql::{AssertBuilder, Confidence, EngineResponse, KyroEngine, Source, TimeRange};
 
 use std::fs;
 use std::io::{Read, Write};
@@ -50,7 +51,7 @@ fn test_partial_wal_entry_recovery() {
     // Expected WAL layout: 5 entity inserts, then truncation mid-entry. Depending on alignment,
     // recovery may yield any non-zero subset that fully parsed before the truncation point.
     // Accept any count in [1,4] to tolerate encoding layout variation.
-    let count = stores.entities.find_by_name_fuzzy("entity_", 10).unwrap().len();
+    let count = stores.entities.find_by_name_fuzzy(None, "entity_", 10).unwrap().len();
     assert!((1..=4).contains(&count), "Recovered count should be between 1 and 4, got {count}");
 }
 
@@ -84,7 +85,7 @@ fn test_wal_replay_idempotency() {
         assert_eq!(entity.unwrap().canonical_name, "unique_entity");
         
         // Should still have exactly one entity with this name
-        let matches = stores.entities.find_by_name("unique_entity").unwrap();
+        let matches = stores.entities.find_by_name(None, "unique_entity").unwrap();
         assert_eq!(matches.len(), 1);
     }
 }
@@ -141,7 +142,7 @@ fn test_compaction_recovery() {
     
     // Create data and compact
     {
-        let mut stores = open_database(dir.path(), None).unwrap();
+        let stores = open_database(dir.path(), None).unwrap();
         
         for i in 0..10 {
             let entity = Entity::new(format!("compacted_{}", i), EntityType::Artifact);
@@ -179,7 +180,7 @@ fn test_multiple_compactions() {
     let dir = tempdir().unwrap();
     
     {
-        let mut stores = open_database(dir.path(), None).unwrap();
+        let stores = open_database(dir.path(), None).unwrap();
         
         // First batch
         for i in 0..5 {
@@ -203,10 +204,247 @@ fn test_multiple_compactions() {
     {
         let stores = open_database(dir.path(), None).unwrap();
         
-        let batch1 = stores.entities.find_by_name_fuzzy("batch1_", 10).unwrap();
-        let batch2 = stores.entities.find_by_name_fuzzy("batch2_", 10).unwrap();
+        let batch1 = stores.entities.find_by_name_fuzzy(None, "batch1_", 10).unwrap();
+        let batch2 = stores.entities.find_by_name_fuzzy(None, "batch2_", 10).unwrap();
         
         assert_eq!(batch1.len(), 5);
         assert_eq!(batch2.len(), 5);
     }
 }
+
+/// Test that ASSERT idempotency keys survive restart and compaction.
+#[test]
+fn test_idempotency_keys_survive_restart() {
+    let dir = tempdir().unwrap();
+
+    let entity = Entity::new("retried", EntityType::Concept);
+    let entity_id = entity.id;
+
+    let assert_once = |dir: &std::path::Path| {
+        let stores = open_database(dir, None).unwrap();
+        if stores.entities.get(entity_id).unwrap().is_none() {
+            stores.entities.insert(entity.clone()).unwrap();
+        }
+        let engine = KyroEngine::new(
+            std::sync::Arc::new(stores.entities),
+            std::sync::Arc::new(stores.beliefs),
+            std::sync::Arc::new(stores.patterns),
+            std::sync::Arc::new(stores.conflicts),
+            std::sync::Arc::new(stores.derivations),
+        )
+        .with_idempotency_store(std::sync::Arc::new(stores.idempotency));
+
+        let ir = AssertBuilder::new()
+            .entity(entity_id)
+            .predicate("status")
+            .value("on")
+            .confidence(Confidence::from_agent(0.9, "agent").unwrap())
+            .source(Source::agent("agent", None::<String>))
+            .valid_time(TimeRange::forever())
+            .idempotency_key("req-1")
+            .build()
+            .unwrap();
+        let EngineResponse::Assert { belief_id, .. } = engine.execute(ir).unwrap() else {
+            panic!("expected assert");
+        };
+        (belief_id, engine.belief_store().count_by_entity(entity_id).unwrap())
+    };
+
+    let (first, count) = assert_once(dir.path());
+    assert_eq!(count, 1);
+
+    // Retry after a restart (WAL replay).
+    let (second, count) = assert_once(dir.path());
+    assert_eq!(second, first);
+    assert_eq!(count, 1);
+
+    // Retry after compaction (segment load). An equal key of another namespace is kept apart.
+    let tenant_belief = kyroql::BeliefId::new();
+    {
+        let stores = open_database(dir.path(), None).unwrap();
+        stores.idempotency.record(Some("tenant-a"), "req-1", tenant_belief).unwrap();
+        stores.compact().unwrap();
+        assert_eq!(stores.idempotency.get(None, "req-1").unwrap(), Some(first));
+    }
+    let (third, count) = assert_once(dir.path());
+    assert_eq!(third, first);
+    assert_eq!(count, 1);
+    let stores = open_database(dir.path(), None).unwrap();
+    assert_eq!(stores.idempotency.get(Some("tenant-a"), "req-1").unwrap(), Some(tenant_belief));
+    assert_eq!(stores.idempotency.get(Some("tenant-b"), "req-1").unwrap(), None);
+}
+
+/// A crash after the belief write but before the key is recorded must not let a retry
+/// write the belief again.
+#[test]
+fn test_idempotency_key_is_recovered_with_its_belief() {
+    use kyroql::storage::BeliefStore;
+    use kyroql::Belief;
+
+    let dir = tempdir().unwrap();
+    let entity = Entity::new("retried", EntityType::Concept);
+    let entity_id = entity.id;
+
+    // What the ASSERT wrote before the crash: the belief, and no separate key record.
+    let written = {
+        let stores = open_database(dir.path(), None).unwrap();
+        stores.entities.insert(entity).unwrap();
+        let mut belief = Belief::builder()
+            .subject(entity_id)
+            .predicate("status")
+            .value("on")
+            .confidence(Confidence::from_agent(0.9, "agent").unwrap())
+            .source(Source::agent("agent", None::<String>))
+            .build()
+            .unwrap();
+        belief.idempotency_key = Some("req-1".to_string());
+        stores.beliefs.insert(belief.clone()).unwrap();
+        belief.id
+    };
+
+    let stores = open_database(dir.path(), None).unwrap();
+    assert_eq!(stores.idempotency.get(None, "req-1").unwrap(), Some(written));
+    let engine = KyroEngine::new(
+        std::sync::Arc::new(stores.entities),
+        std::sync::Arc::new(stores.beliefs),
+        std::sync::Arc::new(stores.patterns),
+        std::sync::Arc::new(stores.conflicts),
+        std::sync::Arc::new(stores.derivations),
+    )
+    .with_idempotency_store(std::sync::Arc::new(stores.idempotency));
+    let retry = AssertBuilder::new()
+        .entity(entity_id)
+        .predicate("status")
+        .value("on")
+        .confidence(Confidence::from_agent(0.9, "agent").unwrap())
+        .source(Source::agent("agent", None::<String>))
+        .valid_time(TimeRange::forever())
+        .idempotency_key("req-1")
+        .build()
+        .unwrap();
+    let EngineResponse::Assert { belief_id, .. } = engine.execute(retry).unwrap() else {
+        panic!("expected assert");
+    };
+    assert_eq!(belief_id, written);
+    assert_eq!(engine.belief_store().count_by_entity(entity_id).unwrap(), 1);
+}
+
+/// Many concurrent writers under group commit: every insert that returned is durable.
+#[test]
+fn test_group_commit_concurrent_inserts_survive_reopen() {
+    use kyroql::storage::{BeliefStore, GroupCommitConfig, PersistentConfig};
+    use kyroql::Belief;
+
+    const WRITERS: usize = 8;
+    const PER_WRITER: usize = 100;
+
+    let dir = tempdir().unwrap();
+    let config = PersistentConfig {
+        group_commit: Some(GroupCommitConfig::default()),
+        ..PersistentConfig::default()
+    };
+    let subject = Entity::new("sensor", EntityType::Artifact).id;
+
+    let ids: Vec<_> = {
+        let stores = open_database(dir.path(), Some(config.clone())).unwrap();
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..WRITERS)
+                .map(|writer| {
+                    let beliefs = &stores.beliefs;
+                    scope.spawn(move || {
+                        (0..PER_WRITER)
+                            .map(|i| {
+                                let belief = Belief::builder()
+                                    .subject(subject)
+                                    .predicate(format!("reading_{writer}"))
+                                    .value(i as i64)
+                                    .confidence(Confidence::from_agent(0.9, "agent").unwrap())
+                                    .build()
+                                    .unwrap();
+                                let id = belief.id;
+                                beliefs.insert(belief).unwrap();
+                                id
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+        })
+    };
+
+    let stores = open_database(dir.path(), Some(config)).unwrap();
+    assert_eq!(stores.beliefs.count_by_entity(subject).unwrap(), WRITERS * PER_WRITER);
+    for id in ids {
+        assert!(stores.beliefs.get(id).unwrap().is_some(), "belief {id} lost");
+    }
+}
+
+/// A Replace assert on the persistent backend links every replaced belief and closes it.
+#[test]
+fn test_replace_lineage_survives_reopen() {
+    use kyroql::storage::BeliefStore;
+    use kyroql::ConsistencyMode;
+
+    let dir = tempdir().unwrap();
+    let entity = Entity::new("replaced", EntityType::Concept);
+    let entity_id = entity.id;
+
+    let engine_on = |stores: kyroql::storage::PersistentStores| {
+        KyroEngine::new(
+            std::sync::Arc::new(stores.entities),
+            std::sync::Arc::new(stores.beliefs),
+            std::sync::Arc::new(stores.patterns),
+            std::sync::Arc::new(stores.conflicts),
+            std::sync::Arc::new(stores.derivations),
+        )
+    };
+    let assert_status = |engine: &KyroEngine, value: &str, mode: ConsistencyMode| {
+        let ir = AssertBuilder::new()
+            .entity(entity_id)
+            .predicate("status")
+            .value(value)
+            .confidence(Confidence::from_agent(0.9, "agent").unwrap())
+            .source(Source::agent("agent", None::<String>))
+            .valid_time(TimeRange::forever())
+            .consistency_mode(mode)
+            .build()
+            .unwrap();
+        let EngineResponse::Assert { belief_id, .. } = engine.execute(ir).unwrap() else {
+            panic!("expected assert");
+        };
+        belief_id
+    };
+
+    let (olds, replacement) = {
+        let stores = open_database(dir.path(), None).unwrap();
+        stores.entities.insert(entity.clone()).unwrap();
+        let engine = engine_on(stores);
+        let draft = assert_status(&engine, "draft", ConsistencyMode::Force);
+        let review = assert_status(&engine, "review", ConsistencyMode::Force);
+        let replacement = assert_status(&engine, "final", ConsistencyMode::Replace);
+
+        // Superseding twice, or by another belief, is rejected.
+        assert!(engine.belief_store().supersede(draft, review).is_err());
+        (vec![review, draft], replacement)
+    };
+
+    let stores = open_database(dir.path(), None).unwrap();
+    let new = stores.beliefs.get(replacement).unwrap().unwrap();
+    assert_eq!(new.supersedes, olds);
+    for old_id in &olds {
+        let old = stores.beliefs.get(*old_id).unwrap().unwrap();
+        assert_eq!(old.superseded_by, Some(replacement));
+        assert!(old.valid_time.to().is_some_and(|to| to <= new.tx_time));
+    }
+    assert_eq!(
+        stores
+            .beliefs
+            .find_as_of(entity_id, "status", chrono::Utc::now())
+            .unwrap()
+            .iter()
+            .map(|b| b.id)
+            .collect::<Vec<_>>(),
+        vec![replacement]
+    );
+}
@@ -49,6 +49,7 @@ fn simulate_creates_isolated_overlay_and_does_not_mutate_base() {
             max_affected_entities: 10,
             max_depth: 2,
             max_duration_ms: 500,
+            seed: None,
         })
         .build()
         .unwrap();
@@ -149,6 +150,7 @@ fn simulate_can_record_derivations_without_mutating_base() {
             max_affected_entities: 10,
             max_depth: 2,
             max_duration_ms: 500,
+            seed: None,
         })
         .build()
         .unwrap();